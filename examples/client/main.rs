@@ -5,11 +5,25 @@
 use futures::{future, Stream, stream};
 #[allow(unused_imports)]
 use openapi_client::{Api, ApiNoContext, Client, ContextWrapperExt, models,
+                      CreateCatalogResponse,
+                      DropCatalogResponse,
+                      DropRoleResponse,
+                      GetCatalogConfigResponse,
+                      ListCatalogsResponse,
+                      ListGrantsResponse,
+                      ListRolesResponse,
+                      LoadCatalogResponse,
+                      UpdateCatalogResponse,
+                      UpdateCatalogConfigResponse,
+                      UpdateGrantsResponse,
+                      UpdateRoleResponse,
                       CreateNamespaceResponse,
                       CreateTableResponse,
                       DropNamespaceResponse,
                       DropTableResponse,
                       ListNamespacesResponse,
+                      ListTableCommitsResponse,
+                      ListTableMetricsResponse,
                       ListTablesResponse,
                       LoadNamespaceMetadataResponse,
                       LoadTableResponse,
@@ -41,11 +55,20 @@ fn main() {
         .arg(Arg::with_name("operation")
             .help("Sets the operation to run")
             .possible_values(&[
+                "DropCatalog",
+                "DropRole",
+                "GetCatalogConfig",
+                "ListCatalogs",
+                "ListGrants",
+                "ListRoles",
+                "LoadCatalog",
                 "CreateNamespace",
                 "CreateTable",
                 "DropNamespace",
                 "DropTable",
                 "ListNamespaces",
+                "ListTableCommits",
+                "ListTableMetrics",
                 "ListTables",
                 "LoadNamespaceMetadata",
                 "LoadTable",
@@ -97,6 +120,89 @@ fn main() {
     let mut rt = tokio::runtime::Runtime::new().unwrap();
 
     match matches.value_of("operation") {
+        /* Disabled because there's no example.
+        Some("CreateCatalog") => {
+            let result = rt.block_on(client.create_catalog(
+                  ???
+            ));
+            info!("{:?} (X-Span-ID: {:?})", result, (client.context() as &dyn Has<XSpanIdString>).get().clone());
+        },
+        */
+        Some("DropCatalog") => {
+            let result = rt.block_on(client.drop_catalog(
+                  "warehouse".to_string()
+            ));
+            info!("{:?} (X-Span-ID: {:?})", result, (client.context() as &dyn Has<XSpanIdString>).get().clone());
+        },
+        Some("DropRole") => {
+            let result = rt.block_on(client.drop_role(
+                  "analysts".to_string()
+            ));
+            info!("{:?} (X-Span-ID: {:?})", result, (client.context() as &dyn Has<XSpanIdString>).get().clone());
+        },
+        Some("GetCatalogConfig") => {
+            let result = rt.block_on(client.get_catalog_config(
+                  Some("warehouse".to_string())
+            ));
+            info!("{:?} (X-Span-ID: {:?})", result, (client.context() as &dyn Has<XSpanIdString>).get().clone());
+        },
+        Some("ListCatalogs") => {
+            let result = rt.block_on(client.list_catalogs(
+            ));
+            info!("{:?} (X-Span-ID: {:?})", result, (client.context() as &dyn Has<XSpanIdString>).get().clone());
+        },
+        Some("ListGrants") => {
+            let result = rt.block_on(client.list_grants(
+                  "analysts".to_string()
+            ));
+            info!("{:?} (X-Span-ID: {:?})", result, (client.context() as &dyn Has<XSpanIdString>).get().clone());
+        },
+        Some("ListRoles") => {
+            let result = rt.block_on(client.list_roles(
+            ));
+            info!("{:?} (X-Span-ID: {:?})", result, (client.context() as &dyn Has<XSpanIdString>).get().clone());
+        },
+        Some("LoadCatalog") => {
+            let result = rt.block_on(client.load_catalog(
+                  "warehouse".to_string()
+            ));
+            info!("{:?} (X-Span-ID: {:?})", result, (client.context() as &dyn Has<XSpanIdString>).get().clone());
+        },
+        /* Disabled because there's no example.
+        Some("UpdateCatalog") => {
+            let result = rt.block_on(client.update_catalog(
+                  "warehouse".to_string(),
+                  ???
+            ));
+            info!("{:?} (X-Span-ID: {:?})", result, (client.context() as &dyn Has<XSpanIdString>).get().clone());
+        },
+        */
+        /* Disabled because there's no example.
+        Some("UpdateCatalogConfig") => {
+            let result = rt.block_on(client.update_catalog_config(
+                  ???
+            ));
+            info!("{:?} (X-Span-ID: {:?})", result, (client.context() as &dyn Has<XSpanIdString>).get().clone());
+        },
+        */
+        /* Disabled because there's no example.
+        Some("UpdateGrants") => {
+            let result = rt.block_on(client.update_grants(
+                  "analysts".to_string(),
+                  ???
+            ));
+            info!("{:?} (X-Span-ID: {:?})", result, (client.context() as &dyn Has<XSpanIdString>).get().clone());
+        },
+        */
+        /* Disabled because there's no example.
+        Some("UpdateRole") => {
+            let result = rt.block_on(client.update_role(
+                  "analysts".to_string(),
+                  ???
+            ));
+            info!("{:?} (X-Span-ID: {:?})", result, (client.context() as &dyn Has<XSpanIdString>).get().clone());
+        },
+        */
         Some("CreateNamespace") => {
             let result = rt.block_on(client.create_namespace(
                   "prefix_example".to_string(),
@@ -115,7 +221,8 @@ fn main() {
         Some("DropNamespace") => {
             let result = rt.block_on(client.drop_namespace(
                   "prefix_example".to_string(),
-                  "accounting".to_string()
+                  "accounting".to_string(),
+                  Some(true)
             ));
             info!("{:?} (X-Span-ID: {:?})", result, (client.context() as &dyn Has<XSpanIdString>).get().clone());
         },
@@ -135,6 +242,26 @@ fn main() {
             ));
             info!("{:?} (X-Span-ID: {:?})", result, (client.context() as &dyn Has<XSpanIdString>).get().clone());
         },
+        Some("ListTableCommits") => {
+            let result = rt.block_on(client.list_table_commits(
+                  "prefix_example".to_string(),
+                  "accounting".to_string(),
+                  "sales".to_string(),
+                  Some("pageToken_example".to_string()),
+                  Some(56)
+            ));
+            info!("{:?} (X-Span-ID: {:?})", result, (client.context() as &dyn Has<XSpanIdString>).get().clone());
+        },
+        Some("ListTableMetrics") => {
+            let result = rt.block_on(client.list_table_metrics(
+                  "prefix_example".to_string(),
+                  "accounting".to_string(),
+                  Some("table_example".to_string()),
+                  Some(789),
+                  Some(789)
+            ));
+            info!("{:?} (X-Span-ID: {:?})", result, (client.context() as &dyn Has<XSpanIdString>).get().clone());
+        },
         Some("ListTables") => {
             let result = rt.block_on(client.list_tables(
                   "prefix_example".to_string(),
@@ -204,6 +331,7 @@ fn main() {
         },
         Some("GetConfig") => {
             let result = rt.block_on(client.get_config(
+                  Some("warehouse".to_string())
             ));
             info!("{:?} (X-Span-ID: {:?})", result, (client.context() as &dyn Has<XSpanIdString>).get().clone());
         },
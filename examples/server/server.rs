@@ -102,10 +102,14 @@ impl<C> Server<C> {
 
 use iceberg_catalog_rest_rdbms_server::server::MakeService;
 use iceberg_catalog_rest_rdbms_server::{
-    Api, CreateNamespaceResponse, CreateTableResponse, DropNamespaceResponse, DropTableResponse,
-    GetConfigResponse, GetTokenResponse, ListNamespacesResponse, ListTablesResponse,
-    LoadNamespaceMetadataResponse, LoadTableResponse, RenameTableResponse, ReportMetricsResponse,
-    TableExistsResponse, UpdatePropertiesResponse, UpdateTableResponse,
+    Api, CreateCatalogResponse, CreateNamespaceResponse, CreateTableResponse, DropCatalogResponse,
+    DropNamespaceResponse, DropRoleResponse, DropTableResponse, GetCatalogConfigResponse,
+    GetConfigResponse, GetTokenResponse, ListCatalogsResponse, ListGrantsResponse,
+    ListNamespacesResponse, ListRolesResponse, ListTableCommitsResponse, ListTableMetricsResponse,
+    ListTablesResponse, LoadCatalogResponse, LoadNamespaceMetadataResponse, LoadTableResponse,
+    RenameTableResponse, ReportMetricsResponse, TableExistsResponse, UpdateCatalogConfigResponse,
+    UpdateCatalogResponse, UpdateGrantsResponse, UpdatePropertiesResponse, UpdateRoleResponse,
+    UpdateTableResponse,
 };
 use std::error::Error;
 use swagger::ApiError;
@@ -115,6 +119,168 @@ impl<C> Api<C> for Server<C>
 where
     C: Has<XSpanIdString> + Send + Sync,
 {
+    /// Create a catalog
+    async fn create_catalog(
+        &self,
+        create_catalog_request: models::CreateCatalogRequest,
+        context: &C,
+    ) -> Result<CreateCatalogResponse, ApiError> {
+        let context = context.clone();
+        info!(
+            "create_catalog({:?}) - X-Span-ID: {:?}",
+            create_catalog_request,
+            context.get().0.clone()
+        );
+        Err(ApiError("Generic failure".into()))
+    }
+
+    /// Drop an empty catalog
+    async fn drop_catalog(
+        &self,
+        catalog: String,
+        context: &C,
+    ) -> Result<DropCatalogResponse, ApiError> {
+        let context = context.clone();
+        info!(
+            "drop_catalog(\"{}\") - X-Span-ID: {:?}",
+            catalog,
+            context.get().0.clone()
+        );
+        Err(ApiError("Generic failure".into()))
+    }
+
+    /// Drop a role and its grants
+    async fn drop_role(&self, role: String, context: &C) -> Result<DropRoleResponse, ApiError> {
+        let context = context.clone();
+        info!(
+            "drop_role(\"{}\") - X-Span-ID: {:?}",
+            role,
+            context.get().0.clone()
+        );
+        Err(ApiError("Generic failure".into()))
+    }
+
+    /// Get the configuration a catalog pushes to clients
+    async fn get_catalog_config(
+        &self,
+        catalog: Option<String>,
+        context: &C,
+    ) -> Result<GetCatalogConfigResponse, ApiError> {
+        let context = context.clone();
+        info!(
+            "get_catalog_config({:?}) - X-Span-ID: {:?}",
+            catalog,
+            context.get().0.clone()
+        );
+        Err(ApiError("Generic failure".into()))
+    }
+
+    /// List the catalogs
+    async fn list_catalogs(&self, context: &C) -> Result<ListCatalogsResponse, ApiError> {
+        let context = context.clone();
+        info!("list_catalogs() - X-Span-ID: {:?}", context.get().0.clone());
+        Err(ApiError("Generic failure".into()))
+    }
+
+    /// List the grants of a role
+    async fn list_grants(&self, role: String, context: &C) -> Result<ListGrantsResponse, ApiError> {
+        let context = context.clone();
+        info!(
+            "list_grants(\"{}\") - X-Span-ID: {:?}",
+            role,
+            context.get().0.clone()
+        );
+        Err(ApiError("Generic failure".into()))
+    }
+
+    /// List the roles and their principals
+    async fn list_roles(&self, context: &C) -> Result<ListRolesResponse, ApiError> {
+        let context = context.clone();
+        info!("list_roles() - X-Span-ID: {:?}", context.get().0.clone());
+        Err(ApiError("Generic failure".into()))
+    }
+
+    /// Describe a catalog
+    async fn load_catalog(
+        &self,
+        catalog: String,
+        context: &C,
+    ) -> Result<LoadCatalogResponse, ApiError> {
+        let context = context.clone();
+        info!(
+            "load_catalog(\"{}\") - X-Span-ID: {:?}",
+            catalog,
+            context.get().0.clone()
+        );
+        Err(ApiError("Generic failure".into()))
+    }
+
+    /// Update the storage root or properties of a catalog
+    async fn update_catalog(
+        &self,
+        catalog: String,
+        update_catalog_request: models::UpdateCatalogRequest,
+        context: &C,
+    ) -> Result<UpdateCatalogResponse, ApiError> {
+        let context = context.clone();
+        info!(
+            "update_catalog(\"{}\", {:?}) - X-Span-ID: {:?}",
+            catalog,
+            update_catalog_request,
+            context.get().0.clone()
+        );
+        Err(ApiError("Generic failure".into()))
+    }
+
+    /// Replace the configuration a catalog pushes to clients
+    async fn update_catalog_config(
+        &self,
+        update_catalog_config_request: models::UpdateCatalogConfigRequest,
+        context: &C,
+    ) -> Result<UpdateCatalogConfigResponse, ApiError> {
+        let context = context.clone();
+        info!(
+            "update_catalog_config({:?}) - X-Span-ID: {:?}",
+            update_catalog_config_request,
+            context.get().0.clone()
+        );
+        Err(ApiError("Generic failure".into()))
+    }
+
+    /// Grant privileges to or revoke privileges from a role
+    async fn update_grants(
+        &self,
+        role: String,
+        update_grants_request: models::UpdateGrantsRequest,
+        context: &C,
+    ) -> Result<UpdateGrantsResponse, ApiError> {
+        let context = context.clone();
+        info!(
+            "update_grants(\"{}\", {:?}) - X-Span-ID: {:?}",
+            role,
+            update_grants_request,
+            context.get().0.clone()
+        );
+        Err(ApiError("Generic failure".into()))
+    }
+
+    /// Create a role or replace its principals
+    async fn update_role(
+        &self,
+        role: String,
+        update_role_request: models::UpdateRoleRequest,
+        context: &C,
+    ) -> Result<UpdateRoleResponse, ApiError> {
+        let context = context.clone();
+        info!(
+            "update_role(\"{}\", {:?}) - X-Span-ID: {:?}",
+            role,
+            update_role_request,
+            context.get().0.clone()
+        );
+        Err(ApiError("Generic failure".into()))
+    }
+
     /// Create a namespace
     async fn create_namespace(
         &self,
@@ -156,13 +322,15 @@ where
         &self,
         prefix: String,
         namespace: String,
+        cascade: Option<bool>,
         context: &C,
     ) -> Result<DropNamespaceResponse, ApiError> {
         let context = context.clone();
         info!(
-            "drop_namespace(\"{}\", \"{}\") - X-Span-ID: {:?}",
+            "drop_namespace(\"{}\", \"{}\", {:?}) - X-Span-ID: {:?}",
             prefix,
             namespace,
+            cascade,
             context.get().0.clone()
         );
         Err(ApiError("Generic failure".into()))
@@ -206,6 +374,52 @@ where
         Err(ApiError("Generic failure".into()))
     }
 
+    /// List the commits of a table
+    async fn list_table_commits(
+        &self,
+        prefix: String,
+        namespace: String,
+        table: String,
+        page_token: Option<String>,
+        page_size: Option<i32>,
+        context: &C,
+    ) -> Result<ListTableCommitsResponse, ApiError> {
+        let context = context.clone();
+        info!(
+            "list_table_commits(\"{}\", \"{}\", \"{}\", {:?}, {:?}) - X-Span-ID: {:?}",
+            prefix,
+            namespace,
+            table,
+            page_token,
+            page_size,
+            context.get().0.clone()
+        );
+        Err(ApiError("Generic failure".into()))
+    }
+
+    /// Aggregate the metrics reports of the tables of a namespace
+    async fn list_table_metrics(
+        &self,
+        prefix: String,
+        namespace: String,
+        table: Option<String>,
+        from: Option<i64>,
+        to: Option<i64>,
+        context: &C,
+    ) -> Result<ListTableMetricsResponse, ApiError> {
+        let context = context.clone();
+        info!(
+            "list_table_metrics(\"{}\", \"{}\", {:?}, {:?}, {:?}) - X-Span-ID: {:?}",
+            prefix,
+            namespace,
+            table,
+            from,
+            to,
+            context.get().0.clone()
+        );
+        Err(ApiError("Generic failure".into()))
+    }
+
     /// List all table identifiers underneath a given namespace
     async fn list_tables(
         &self,
@@ -357,9 +571,17 @@ where
     }
 
     /// List all catalog configuration settings
-    async fn get_config(&self, context: &C) -> Result<GetConfigResponse, ApiError> {
+    async fn get_config(
+        &self,
+        warehouse: Option<String>,
+        context: &C,
+    ) -> Result<GetConfigResponse, ApiError> {
         let context = context.clone();
-        info!("get_config() - X-Span-ID: {:?}", context.get().0.clone());
+        info!(
+            "get_config({:?}) - X-Span-ID: {:?}",
+            warehouse,
+            context.get().0.clone()
+        );
         Err(ApiError("Generic failure".into()))
     }
 
@@ -0,0 +1,428 @@
+//! Authorization of catalog operations.
+//!
+//! Principals, the subjects of access tokens, are members of roles, and roles are
+//! granted privileges on catalogs, namespaces and tables. A privilege granted on a
+//! catalog or namespace applies to all namespaces and tables inside it. Principals
+//! configured as admins hold every privilege and are the only ones that manage roles.
+//!
+//! A namespace or table is visible to a principal that holds any privilege on it,
+//! on something containing it or on something inside it. Operations on objects that
+//! aren't visible fail as if the objects didn't exist, so their names aren't
+//! disclosed; operations on visible objects that lack a privilege are forbidden.
+//!
+//! The scopes of the access token limit what the principal may do with it: without
+//! the [`CATALOG_SCOPE`] it holds no privileges at all, and admins only act as
+//! admins with the [`ADMIN_SCOPE`].
+
+use std::collections::HashSet;
+use std::fmt;
+
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter};
+use swagger::auth::{Authorization, Scopes};
+use swagger::Has;
+
+use iceberg_catalog_rest_rdbms_server::models::Privilege;
+
+use crate::database::entities::{prelude::*, *};
+
+use super::error::CatalogError;
+
+/// Scope a token needs for any privilege.
+pub const CATALOG_SCOPE: &str = "catalog";
+
+/// Scope a token of an admin needs to act as admin.
+pub const ADMIN_SCOPE: &str = "admin";
+
+/// The principal of a request.
+#[derive(Clone, Debug)]
+pub struct Caller {
+    pub principal: Option<String>,
+    /// Whether the principal is configured as admin
+    pub admin: bool,
+    /// Scopes of the access token
+    pub scopes: Scopes,
+}
+
+impl Caller {
+    pub fn from_context<C: Has<Option<Authorization>>>(
+        context: &C,
+        admins: &HashSet<String>,
+    ) -> Self {
+        let authorization = Has::<Option<Authorization>>::get(context).as_ref();
+        let principal = authorization.map(|authorization| authorization.subject.clone());
+        Caller {
+            admin: principal
+                .as_ref()
+                .is_some_and(|principal| admins.contains(principal)),
+            principal,
+            scopes: authorization.map_or_else(
+                || Scopes::Some(Default::default()),
+                |authorization| authorization.scopes.clone(),
+            ),
+        }
+    }
+
+    /// Whether the access token carries the scope.
+    pub fn has_scope(&self, scope: &str) -> bool {
+        match &self.scopes {
+            Scopes::All => true,
+            Scopes::Some(scopes) => scopes.contains(scope),
+        }
+    }
+
+    /// Fails unless the access token carries the scope.
+    fn require_scope(&self, scope: &str) -> Result<(), CatalogError> {
+        if self.has_scope(scope) {
+            Ok(())
+        } else {
+            Err(CatalogError::Forbidden(format!(
+                "The access token lacks the scope {}.",
+                scope
+            )))
+        }
+    }
+
+    /// Fails unless the caller is an admin whose token carries the admin scope.
+    pub fn require_admin(&self) -> Result<(), CatalogError> {
+        if !self.admin {
+            return Err(CatalogError::Forbidden(
+                "Only admins can perform this operation.".into(),
+            ));
+        }
+        self.require_scope(CATALOG_SCOPE)?;
+        self.require_scope(ADMIN_SCOPE)
+    }
+
+    /// Loads the privileges the roles of the caller hold in a catalog, whose id is
+    /// `None` for the default catalog.
+    ///
+    /// Fails unless the token carries the catalog scope. Admins hold every
+    /// privilege only with the admin scope, otherwise those of their roles.
+    pub async fn grants<C: ConnectionTrait>(
+        &self,
+        db: &C,
+        catalog_id: Option<i32>,
+    ) -> Result<Grants, CatalogError> {
+        self.require_scope(CATALOG_SCOPE)?;
+        if self.admin && self.has_scope(ADMIN_SCOPE) {
+            return Ok(Grants::admin());
+        }
+        let principal = match &self.principal {
+            Some(principal) => principal,
+            None => return Ok(Grants::default()),
+        };
+
+        let roles = RoleMember::find()
+            .filter(role_member::Column::Principal.eq(principal.as_str()))
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|member| member.role_id)
+            .collect::<Vec<_>>();
+        if roles.is_empty() {
+            return Ok(Grants::default());
+        }
+
+        let in_catalog = match catalog_id {
+            None => privilege_grant::Column::CatalogId.is_null(),
+            Some(catalog_id) => privilege_grant::Column::CatalogId.eq(catalog_id),
+        };
+        let held = PrivilegeGrant::find()
+            .filter(privilege_grant::Column::RoleId.is_in(roles))
+            .filter(in_catalog)
+            .find_also_related(Namespace)
+            .all(db)
+            .await?
+            .into_iter()
+            // Rows with privileges this version doesn't know grant nothing
+            .filter_map(|(grant, namespace)| {
+                Some(Held {
+                    privilege: grant.privilege.parse().ok()?,
+                    namespace: namespace.map(|namespace| namespace.name),
+                    table_id: grant.table_id,
+                })
+            })
+            .collect();
+
+        Ok(Grants { admin: false, held })
+    }
+}
+
+/// The object of a privilege.
+#[derive(Clone, Copy, Debug)]
+pub enum Target<'a> {
+    Catalog,
+    Namespace(&'a namespace::Model),
+    Table(&'a namespace::Model, &'a iceberg_table::Model),
+}
+
+impl fmt::Display for Target<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Target::Catalog => f.write_str("the catalog"),
+            Target::Namespace(namespace) => write!(f, "the namespace {}", namespace.name),
+            Target::Table(namespace, table) => {
+                write!(f, "the table {}.{}", namespace.name, table.name)
+            }
+        }
+    }
+}
+
+/// Whether a privilege can be granted on a single table.
+pub fn is_table_privilege(privilege: Privilege) -> bool {
+    matches!(
+        privilege,
+        Privilege::TableRead
+            | Privilege::TableWrite
+            | Privilege::TableDrop
+            | Privilege::ManageGrants
+    )
+}
+
+/// A privilege held in a catalog.
+#[derive(Clone, Debug)]
+struct Held {
+    privilege: Privilege,
+    /// Name of the namespace of the grant, `None` for the whole catalog
+    namespace: Option<String>,
+    /// The table of the grant, whose namespace is `namespace`
+    table_id: Option<i32>,
+}
+
+impl Held {
+    /// Whether the privilege applies to the target.
+    fn covers(&self, target: Target) -> bool {
+        match (&self.namespace, self.table_id, target) {
+            (None, _, _) => true,
+            (Some(_), None, Target::Catalog) => false,
+            (Some(name), None, Target::Namespace(namespace))
+            | (Some(name), None, Target::Table(namespace, _)) => within(&namespace.name, name),
+            (Some(_), Some(id), Target::Table(_, table)) => id == table.id,
+            (Some(_), Some(_), _) => false,
+        }
+    }
+
+    /// Whether the privilege applies to something inside the target.
+    fn inside(&self, target: Target) -> bool {
+        match (&self.namespace, target) {
+            (Some(_), Target::Catalog) => true,
+            (Some(name), Target::Namespace(namespace)) => within(name, &namespace.name),
+            _ => false,
+        }
+    }
+}
+
+/// Whether the namespace `name` is `ancestor` or nested below it.
+fn within(name: &str, ancestor: &str) -> bool {
+    name.strip_prefix(ancestor)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+}
+
+/// The privileges a caller holds in a catalog.
+#[derive(Clone, Debug, Default)]
+pub struct Grants {
+    admin: bool,
+    held: Vec<Held>,
+}
+
+impl Grants {
+    fn admin() -> Self {
+        Grants {
+            admin: true,
+            held: Vec::new(),
+        }
+    }
+
+    /// Whether the caller holds the privilege on the target.
+    pub fn allows(&self, privilege: Privilege, target: Target) -> bool {
+        self.admin
+            || self
+                .held
+                .iter()
+                .any(|held| held.privilege == privilege && held.covers(target))
+    }
+
+    /// Whether the caller may learn that the target exists.
+    pub fn sees(&self, target: Target) -> bool {
+        self.admin
+            || self
+                .held
+                .iter()
+                .any(|held| held.covers(target) || held.inside(target))
+    }
+
+    /// Fails as if the target didn't exist unless the caller can see it.
+    pub fn require_visible(&self, target: Target) -> Result<(), CatalogError> {
+        if self.sees(target) {
+            return Ok(());
+        }
+        Err(match target {
+            Target::Catalog => {
+                CatalogError::Forbidden("The principal holds no privileges in the catalog.".into())
+            }
+            Target::Namespace(namespace) => CatalogError::NoSuchNamespace(namespace.name.clone()),
+            Target::Table(namespace, table) => {
+                CatalogError::NoSuchTable(format!("{}.{}", namespace.name, table.name))
+            }
+        })
+    }
+
+    /// Fails unless the caller holds the privilege on the target.
+    pub fn require(&self, privilege: Privilege, target: Target) -> Result<(), CatalogError> {
+        if self.allows(privilege, target) {
+            return Ok(());
+        }
+        self.require_visible(target)?;
+        Err(CatalogError::Forbidden(format!(
+            "The principal lacks the privilege {} on {}.",
+            privilege, target
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iceberg_catalog_rest_rdbms_server::models::Privilege;
+    use swagger::auth::Scopes;
+
+    use crate::database::entities::*;
+
+    use super::{Caller, CatalogError, Grants, Held, Target};
+
+    fn namespace(id: i32, name: &str) -> namespace::Model {
+        namespace::Model {
+            id,
+            name: name.to_owned(),
+            catalog_id: None,
+            parent_id: None,
+        }
+    }
+
+    fn table(id: i32, name: &str, namespace_id: i32) -> iceberg_table::Model {
+        iceberg_table::Model {
+            id,
+            name: name.to_owned(),
+            metadata_location: String::new(),
+            previous_metadata_location: None,
+            namespace_id,
+        }
+    }
+
+    fn grants(held: Vec<(Privilege, Option<&str>, Option<i32>)>) -> Grants {
+        Grants {
+            admin: false,
+            held: held
+                .into_iter()
+                .map(|(privilege, namespace, table_id)| Held {
+                    privilege,
+                    namespace: namespace.map(str::to_owned),
+                    table_id,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn inherited_privileges() {
+        let db = namespace(1, "db");
+        let nested = namespace(2, "db.nested");
+        let sibling = namespace(3, "dbx");
+        let orders = table(1, "orders", 2);
+
+        let catalog_grants = grants(vec![(Privilege::TableRead, None, None)]);
+        assert!(catalog_grants.allows(Privilege::TableRead, Target::Table(&nested, &orders)));
+        assert!(!catalog_grants.allows(Privilege::TableWrite, Target::Table(&nested, &orders)));
+
+        let namespace_grants = grants(vec![(Privilege::TableRead, Some("db"), None)]);
+        assert!(namespace_grants.allows(Privilege::TableRead, Target::Namespace(&db)));
+        assert!(namespace_grants.allows(Privilege::TableRead, Target::Table(&nested, &orders)));
+        assert!(!namespace_grants.allows(Privilege::TableRead, Target::Namespace(&sibling)));
+        assert!(!namespace_grants.allows(Privilege::TableRead, Target::Catalog));
+
+        let table_grants = grants(vec![(Privilege::TableRead, Some("db.nested"), Some(1))]);
+        assert!(table_grants.allows(Privilege::TableRead, Target::Table(&nested, &orders)));
+        assert!(!table_grants.allows(Privilege::TableRead, Target::Namespace(&nested)));
+        assert!(!table_grants.allows(
+            Privilege::TableRead,
+            Target::Table(&nested, &table(2, "customers", 2))
+        ));
+    }
+
+    #[test]
+    fn visibility() {
+        let db = namespace(1, "db");
+        let nested = namespace(2, "db.nested");
+        let other = namespace(3, "other");
+        let orders = table(1, "orders", 2);
+        let customers = table(2, "customers", 2);
+
+        let grants = grants(vec![(Privilege::TableRead, Some("db.nested"), Some(1))]);
+        assert!(grants.sees(Target::Catalog));
+        assert!(grants.sees(Target::Namespace(&db)));
+        assert!(grants.sees(Target::Namespace(&nested)));
+        assert!(grants.sees(Target::Table(&nested, &orders)));
+        assert!(!grants.sees(Target::Table(&nested, &customers)));
+        assert!(!grants.sees(Target::Namespace(&other)));
+
+        assert!(matches!(
+            grants.require(Privilege::TableDrop, Target::Table(&nested, &orders)),
+            Err(CatalogError::Forbidden(_))
+        ));
+        assert!(matches!(
+            grants.require(Privilege::TableRead, Target::Table(&nested, &customers)),
+            Err(CatalogError::NoSuchTable(_))
+        ));
+        assert!(matches!(
+            grants.require(Privilege::NamespaceDrop, Target::Namespace(&other)),
+            Err(CatalogError::NoSuchNamespace(_))
+        ));
+    }
+
+    #[test]
+    fn admins() {
+        let other = namespace(3, "other");
+        let grants = Grants::admin();
+        assert!(grants.allows(Privilege::NamespaceDrop, Target::Namespace(&other)));
+        assert!(grants.sees(Target::Namespace(&other)));
+        assert!(Grants::default()
+            .require(Privilege::CatalogRead, Target::Catalog)
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn scopes() {
+        let db = sea_orm::Database::connect("sqlite::memory:").await.unwrap();
+        let caller = |admin: bool, scopes: &[&str]| Caller {
+            principal: Some("alice".to_owned()),
+            admin,
+            scopes: Scopes::Some(scopes.iter().map(|scope| scope.to_string()).collect()),
+        };
+
+        let admin = caller(true, &["catalog", "admin"]);
+        assert!(admin.require_admin().is_ok());
+        assert!(admin
+            .grants(&db, None)
+            .await
+            .unwrap()
+            .allows(Privilege::CatalogRead, Target::Catalog));
+
+        // Admins only act as admins with the admin scope
+        let unscoped_admin = caller(true, &["catalog"]);
+        assert!(matches!(
+            unscoped_admin.require_admin(),
+            Err(CatalogError::Forbidden(_))
+        ));
+        assert!(caller(false, &["catalog", "admin"])
+            .require_admin()
+            .is_err());
+
+        // Without the catalog scope no privileges are held
+        for caller in [caller(true, &["admin"]), caller(false, &[])] {
+            assert!(caller.require_admin().is_err());
+            assert!(matches!(
+                caller.grants(&db, None).await,
+                Err(CatalogError::Forbidden(_))
+            ));
+        }
+    }
+}
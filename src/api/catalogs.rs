@@ -0,0 +1,195 @@
+//! Catalogs as managed by the admin endpoints.
+//!
+//! A catalog is named by the prefix of the paths of its namespaces and tables. Its
+//! storage root is the location below which its tables are created if no location
+//! is requested, and replaces the warehouse of the server. The default catalog of
+//! the empty prefix has no row and can't be managed.
+
+use std::collections::HashMap;
+
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, ConnectionTrait, DbErr, EntityTrait, ModelTrait,
+    PaginatorTrait, QueryFilter, QueryOrder,
+};
+
+use iceberg_catalog_rest_rdbms_server::models;
+
+use crate::database::entities::{prelude::*, *};
+
+use super::error::CatalogError;
+use super::resolver;
+
+/// Lists all catalogs with their properties.
+pub async fn list<C: ConnectionTrait>(db: &C) -> Result<Vec<models::Catalog>, DbErr> {
+    let catalogs = Catalog::find()
+        .order_by_asc(catalog::Column::Name)
+        .find_with_related(CatalogProperty)
+        .all(db)
+        .await?;
+
+    Ok(catalogs
+        .into_iter()
+        .map(|(catalog, properties)| describe(catalog, properties))
+        .collect())
+}
+
+/// Resolves a catalog by its name.
+pub async fn catalog<C: ConnectionTrait>(
+    db: &C,
+    name: &str,
+) -> Result<catalog::Model, CatalogError> {
+    resolver::catalog(db, name)
+        .await?
+        .ok_or_else(|| CatalogError::NoSuchCatalog(name.to_owned()))
+}
+
+/// Loads the storage root and properties of a catalog.
+pub async fn load<C: ConnectionTrait>(
+    db: &C,
+    catalog: catalog::Model,
+) -> Result<models::Catalog, DbErr> {
+    let properties = catalog.find_related(CatalogProperty).all(db).await?;
+    Ok(describe(catalog, properties))
+}
+
+/// Creates a catalog with a storage root and properties.
+pub async fn create<C: ConnectionTrait>(
+    db: &C,
+    request: models::CreateCatalogRequest,
+) -> Result<models::Catalog, CatalogError> {
+    let models::CreateCatalogRequest {
+        name,
+        storage_root,
+        properties,
+    } = request;
+    if name.is_empty() || name.contains('/') {
+        return Err(CatalogError::BadRequest(
+            "Catalog names have to be non-empty and must not contain \"/\".".into(),
+        ));
+    }
+    let storage_root = storage_root.map(normalize).transpose()?;
+
+    if Catalog::find()
+        .filter(catalog::Column::Name.eq(name.as_str()))
+        .one(db)
+        .await?
+        .is_some()
+    {
+        return Err(CatalogError::AlreadyExists(format!(
+            "The catalog {} already exists.",
+            name
+        )));
+    }
+
+    let new_catalog = catalog::ActiveModel {
+        id: ActiveValue::NotSet,
+        name: ActiveValue::Set(name),
+        storage_root: ActiveValue::Set(storage_root),
+    };
+    let catalog = new_catalog.insert(db).await?;
+    store(db, catalog.id, properties.unwrap_or_default()).await?;
+
+    Ok(load(db, catalog).await?)
+}
+
+/// Replaces the storage root or the properties of a catalog. Missing attributes are
+/// kept.
+pub async fn update<C: ConnectionTrait>(
+    db: &C,
+    catalog: catalog::Model,
+    request: models::UpdateCatalogRequest,
+) -> Result<models::Catalog, CatalogError> {
+    let catalog = match request.storage_root {
+        Some(storage_root) => {
+            let mut active: catalog::ActiveModel = catalog.into();
+            active.storage_root = ActiveValue::Set(Some(normalize(storage_root)?));
+            active.update(db).await?
+        }
+        None => catalog,
+    };
+
+    if let Some(properties) = request.properties {
+        CatalogProperty::delete_many()
+            .filter(catalog_property::Column::CatalogId.eq(catalog.id))
+            .exec(db)
+            .await?;
+        store(db, catalog.id, properties).await?;
+    }
+
+    Ok(load(db, catalog).await?)
+}
+
+/// Drops a catalog together with its properties, configuration and grants. The
+/// catalog must not contain namespaces.
+pub async fn remove<C: ConnectionTrait>(
+    db: &C,
+    catalog: catalog::Model,
+) -> Result<(), CatalogError> {
+    if catalog.find_related(Namespace).count(db).await? > 0 {
+        return Err(CatalogError::NamespaceNotEmpty(format!(
+            "The catalog {} still contains namespaces.",
+            catalog.name
+        )));
+    }
+
+    CatalogConfig::delete_many()
+        .filter(catalog_config::Column::CatalogId.eq(catalog.id))
+        .exec(db)
+        .await?;
+    CatalogProperty::delete_many()
+        .filter(catalog_property::Column::CatalogId.eq(catalog.id))
+        .exec(db)
+        .await?;
+    PrivilegeGrant::delete_many()
+        .filter(privilege_grant::Column::CatalogId.eq(catalog.id))
+        .exec(db)
+        .await?;
+
+    catalog.delete(db).await?;
+
+    Ok(())
+}
+
+/// Inserts the properties of a catalog.
+async fn store<C: ConnectionTrait>(
+    db: &C,
+    catalog_id: i32,
+    properties: HashMap<String, String>,
+) -> Result<(), DbErr> {
+    if properties.is_empty() {
+        return Ok(());
+    }
+    CatalogProperty::insert_many(properties.into_iter().map(|(key, value)| {
+        catalog_property::ActiveModel {
+            id: ActiveValue::NotSet,
+            key: ActiveValue::Set(key),
+            value: ActiveValue::Set(value),
+            catalog_id: ActiveValue::Set(catalog_id),
+        }
+    }))
+    .exec(db)
+    .await?;
+    Ok(())
+}
+
+/// Strips the trailing slashes of a storage root, which has to be non-empty.
+fn normalize(storage_root: String) -> Result<String, CatalogError> {
+    let normalized = storage_root.trim_end_matches('/');
+    if normalized.is_empty() {
+        return Err(CatalogError::BadRequest(
+            "The storage root has to be non-empty.".into(),
+        ));
+    }
+    Ok(normalized.to_owned())
+}
+
+fn describe(catalog: catalog::Model, properties: Vec<catalog_property::Model>) -> models::Catalog {
+    models::Catalog {
+        name: catalog.name,
+        storage_root: catalog.storage_root,
+        properties: properties
+            .into_iter()
+            .map(|property| (property.key, property.value))
+            .collect(),
+    }
+}
@@ -0,0 +1,158 @@
+//! Configuration the catalogs push to clients with `getConfig`.
+//!
+//! The server is configured with defaults and overrides that apply to every catalog.
+//! Each catalog stores its own defaults and overrides, which take precedence over
+//! those of the server and are managed by admins. Clients that name a warehouse
+//! instead of a prefix are told the prefix of its catalog in the overrides.
+
+use std::collections::HashMap;
+
+use sea_orm::sea_query::SimpleExpr;
+use sea_orm::{ActiveValue, ColumnTrait, ConnectionTrait, DbErr, EntityTrait, QueryFilter};
+use serde_json::json;
+
+use iceberg_catalog_rest_rdbms_server::models;
+
+use crate::database::entities::{prelude::*, *};
+
+/// Override that routes the requests of a client to a catalog
+pub const PREFIX: &str = "prefix";
+
+/// Kind of the stored properties applied before the client configuration
+const DEFAULTS: &str = "defaults";
+/// Kind of the stored properties applied after the client configuration
+const OVERRIDES: &str = "overrides";
+
+/// Properties pushed to clients.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ClientConfig {
+    /// Properties applied before the client configuration
+    pub defaults: HashMap<String, String>,
+    /// Properties applied after the client configuration
+    pub overrides: HashMap<String, String>,
+}
+
+impl ClientConfig {
+    /// Loads the configuration stored for a catalog, whose id is `None` for the
+    /// default catalog.
+    pub async fn load<C: ConnectionTrait>(db: &C, catalog_id: Option<i32>) -> Result<Self, DbErr> {
+        let mut config = ClientConfig::default();
+        for property in CatalogConfig::find()
+            .filter(in_catalog(catalog_id))
+            .all(db)
+            .await?
+        {
+            match property.kind.as_str() {
+                DEFAULTS => config.defaults.insert(property.key, property.value),
+                OVERRIDES => config.overrides.insert(property.key, property.value),
+                _ => None,
+            };
+        }
+        Ok(config)
+    }
+
+    /// Replaces the defaults and overrides stored for a catalog. A missing map keeps
+    /// the stored properties of its kind.
+    pub async fn store<C: ConnectionTrait>(
+        db: &C,
+        catalog_id: Option<i32>,
+        defaults: Option<HashMap<String, String>>,
+        overrides: Option<HashMap<String, String>>,
+    ) -> Result<(), DbErr> {
+        for (kind, properties) in [(DEFAULTS, defaults), (OVERRIDES, overrides)] {
+            let properties = match properties {
+                Some(properties) => properties,
+                None => continue,
+            };
+
+            CatalogConfig::delete_many()
+                .filter(in_catalog(catalog_id))
+                .filter(catalog_config::Column::Kind.eq(kind))
+                .exec(db)
+                .await?;
+
+            if !properties.is_empty() {
+                CatalogConfig::insert_many(properties.into_iter().map(|(key, value)| {
+                    catalog_config::ActiveModel {
+                        id: ActiveValue::NotSet,
+                        kind: ActiveValue::Set(kind.to_owned()),
+                        key: ActiveValue::Set(key),
+                        value: ActiveValue::Set(value),
+                        catalog_id: ActiveValue::Set(catalog_id),
+                    }
+                }))
+                .exec(db)
+                .await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns this configuration with the properties of `catalog` taking precedence.
+    pub fn merge(&self, catalog: ClientConfig) -> ClientConfig {
+        let mut defaults = self.defaults.clone();
+        defaults.extend(catalog.defaults);
+        let mut overrides = self.overrides.clone();
+        overrides.extend(catalog.overrides);
+        ClientConfig {
+            defaults,
+            overrides,
+        }
+    }
+}
+
+impl From<ClientConfig> for models::CatalogConfig {
+    fn from(config: ClientConfig) -> Self {
+        models::CatalogConfig {
+            defaults: json!(config.defaults),
+            overrides: json!(config.overrides),
+        }
+    }
+}
+
+/// Matches the properties of a catalog, whose id is `None` for the default catalog.
+fn in_catalog(catalog_id: Option<i32>) -> SimpleExpr {
+    match catalog_id {
+        None => catalog_config::Column::CatalogId.is_null(),
+        Some(catalog_id) => catalog_config::Column::CatalogId.eq(catalog_id),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::ClientConfig;
+
+    fn properties(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn catalog_takes_precedence() {
+        let server = ClientConfig {
+            defaults: properties(&[("io-impl", "org.apache.iceberg.aws.s3.S3FileIO")]),
+            overrides: properties(&[("s3.endpoint", "http://minio:9000")]),
+        };
+        let catalog = ClientConfig {
+            defaults: properties(&[("write.format.default", "orc")]),
+            overrides: properties(&[("s3.endpoint", "http://localhost:9000")]),
+        };
+
+        let merged = server.merge(catalog);
+        assert_eq!(
+            merged.defaults,
+            properties(&[
+                ("io-impl", "org.apache.iceberg.aws.s3.S3FileIO"),
+                ("write.format.default", "orc"),
+            ])
+        );
+        assert_eq!(
+            merged.overrides,
+            properties(&[("s3.endpoint", "http://localhost:9000")])
+        );
+    }
+}
@@ -0,0 +1,490 @@
+//! Errors of catalog operations.
+//!
+//! Handlers fail with a [`CatalogError`]. Its variant determines the HTTP status
+//! code and the Iceberg exception type of the response, which clients use to decide
+//! whether a request can be retried. Details of server-side failures, such as the
+//! SQL of a failed statement, are only logged and never sent to clients.
+
+use std::fmt;
+
+use log::error;
+use sea_orm::{DbErr, TransactionError};
+
+use iceberg_catalog_rest_rdbms_server::models;
+use iceberg_catalog_rest_rdbms_server::{
+    CreateCatalogResponse, CreateNamespaceResponse, CreateTableResponse, DropCatalogResponse,
+    DropNamespaceResponse, DropRoleResponse, DropTableResponse, GetCatalogConfigResponse,
+    GetConfigResponse, ListCatalogsResponse, ListGrantsResponse, ListNamespacesResponse,
+    ListRolesResponse, ListTableCommitsResponse, ListTableMetricsResponse, ListTablesResponse,
+    LoadCatalogResponse, LoadNamespaceMetadataResponse, LoadTableResponse, RenameTableResponse,
+    ReportMetricsResponse, TableExistsResponse, UpdateCatalogConfigResponse, UpdateCatalogResponse,
+    UpdateGrantsResponse, UpdatePropertiesResponse, UpdateRoleResponse, UpdateTableResponse,
+};
+
+/// Error of a catalog operation.
+#[derive(Debug)]
+pub enum CatalogError {
+    /// The request is malformed or invalid
+    BadRequest(String),
+    /// The principal lacks a privilege on an object it can see
+    Forbidden(String),
+    /// No catalog is registered for the prefix
+    NoSuchCatalog(String),
+    /// No catalog is registered for the warehouse
+    NoSuchWarehouse(String),
+    /// The namespace does not exist in the catalog
+    NoSuchNamespace(String),
+    /// The table does not exist in the namespace
+    NoSuchTable(String),
+    /// No role has the name
+    NoSuchRole(String),
+    /// The catalog, namespace or table to create already exists
+    AlreadyExists(String),
+    /// The catalog or namespace to drop still contains namespaces or tables
+    NamespaceNotEmpty(String),
+    /// A requirement of a commit is not met
+    CommitFailed(String),
+    /// The request is well-formed but its content can't be processed
+    UnprocessableEntity(String),
+    /// A commit may or may not have been applied
+    CommitStateUnknown(String),
+    /// The database can't be reached
+    ServiceUnavailable(String),
+    /// Any other server-side failure
+    Internal(String),
+}
+
+impl CatalogError {
+    /// The HTTP status code of the error.
+    pub fn code(&self) -> u16 {
+        match self {
+            CatalogError::BadRequest(_) => 400,
+            CatalogError::Forbidden(_) => 403,
+            CatalogError::NoSuchCatalog(_)
+            | CatalogError::NoSuchWarehouse(_)
+            | CatalogError::NoSuchNamespace(_)
+            | CatalogError::NoSuchTable(_)
+            | CatalogError::NoSuchRole(_) => 404,
+            CatalogError::AlreadyExists(_)
+            | CatalogError::NamespaceNotEmpty(_)
+            | CatalogError::CommitFailed(_) => 409,
+            CatalogError::UnprocessableEntity(_) => 422,
+            CatalogError::CommitStateUnknown(_) | CatalogError::Internal(_) => 500,
+            CatalogError::ServiceUnavailable(_) => 503,
+        }
+    }
+
+    /// The Iceberg exception type of the error.
+    pub fn r#type(&self) -> &'static str {
+        match self {
+            CatalogError::BadRequest(_) => "BadRequestException",
+            CatalogError::Forbidden(_) => "ForbiddenException",
+            CatalogError::NoSuchCatalog(_) => "NoSuchCatalogException",
+            CatalogError::NoSuchWarehouse(_) => "NoSuchWarehouseException",
+            CatalogError::NoSuchNamespace(_) => "NoSuchNamespaceException",
+            CatalogError::NoSuchTable(_) => "NoSuchTableException",
+            CatalogError::NoSuchRole(_) => "NoSuchRoleException",
+            CatalogError::AlreadyExists(_) => "AlreadyExistsException",
+            CatalogError::NamespaceNotEmpty(_) => "NamespaceNotEmptyException",
+            CatalogError::CommitFailed(_) => "CommitFailedException",
+            CatalogError::UnprocessableEntity(_) => "UnprocessableEntityException",
+            CatalogError::CommitStateUnknown(_) => "CommitStateUnknownException",
+            CatalogError::ServiceUnavailable(_) => "ServiceUnavailableException",
+            CatalogError::Internal(_) => "ServiceFailureException",
+        }
+    }
+
+    /// The message sent to the client, which omits the details of server-side failures.
+    pub fn message(&self) -> String {
+        match self {
+            CatalogError::CommitStateUnknown(_) => {
+                "The commit failed on the server; its state is unknown.".into()
+            }
+            CatalogError::ServiceUnavailable(_) => "The catalog is temporarily unavailable.".into(),
+            CatalogError::Internal(_) => "An internal error occurred.".into(),
+            _ => self.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for CatalogError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CatalogError::NoSuchCatalog(name) => write!(f, "The catalog {} does not exist.", name),
+            CatalogError::NoSuchWarehouse(name) => {
+                write!(f, "The warehouse {} does not exist.", name)
+            }
+            CatalogError::NoSuchNamespace(name) => {
+                write!(f, "The namespace {} does not exist.", name)
+            }
+            CatalogError::NoSuchTable(name) => write!(f, "The table {} does not exist.", name),
+            CatalogError::NoSuchRole(name) => write!(f, "The role {} does not exist.", name),
+            CatalogError::BadRequest(message)
+            | CatalogError::Forbidden(message)
+            | CatalogError::AlreadyExists(message)
+            | CatalogError::NamespaceNotEmpty(message)
+            | CatalogError::CommitFailed(message)
+            | CatalogError::UnprocessableEntity(message)
+            | CatalogError::CommitStateUnknown(message)
+            | CatalogError::ServiceUnavailable(message)
+            | CatalogError::Internal(message) => f.write_str(message),
+        }
+    }
+}
+
+impl std::error::Error for CatalogError {}
+
+impl From<DbErr> for CatalogError {
+    fn from(err: DbErr) -> Self {
+        match err {
+            DbErr::Conn(_) => CatalogError::ServiceUnavailable(err.to_string()),
+            _ => CatalogError::Internal(err.to_string()),
+        }
+    }
+}
+
+/// Converts the error of an insert. The violation of a unique index, as when a
+/// concurrent request created the same object first, becomes the error returned by
+/// `conflict`.
+pub fn unique_violation(err: DbErr, conflict: impl FnOnce() -> CatalogError) -> CatalogError {
+    let message = match &err {
+        DbErr::Exec(message) | DbErr::Query(message) => message,
+        _ => return err.into(),
+    };
+    // The messages of PostgreSQL, MySQL and SQLite
+    if message.contains("duplicate key value violates unique constraint")
+        || message.contains("Duplicate entry")
+        || message.contains("UNIQUE constraint failed")
+    {
+        conflict()
+    } else {
+        err.into()
+    }
+}
+
+impl From<TransactionError<CatalogError>> for CatalogError {
+    fn from(err: TransactionError<CatalogError>) -> Self {
+        match err {
+            TransactionError::Connection(err) => err.into(),
+            TransactionError::Transaction(err) => err,
+        }
+    }
+}
+
+impl From<CatalogError> for models::ErrorModel {
+    fn from(err: CatalogError) -> Self {
+        if err.code() >= 500 {
+            error!("{}: {}", err.r#type(), err);
+        }
+        models::ErrorModel::new(err.message(), err.r#type().into(), err.code())
+    }
+}
+
+/// Response of an operation that can carry a [`CatalogError`].
+pub trait ErrorResponse {
+    /// Returns the response variant matching the status code of the error.
+    fn from_error(err: CatalogError) -> Self;
+}
+
+macro_rules! error_response {
+    ($($response:ident { $($code:pat => $variant:ident),+ $(,)? })+) => {
+        $(
+            impl ErrorResponse for $response {
+                fn from_error(err: CatalogError) -> Self {
+                    match err.code() {
+                        $($code => $response::$variant(err.into()),)+
+                    }
+                }
+            }
+        )+
+    };
+}
+
+error_response! {
+    CreateNamespaceResponse {
+        400 => IndicatesABadRequestError,
+        403 => Forbidden,
+        404 => NotFound,
+        409 => Conflict,
+        503 => TheServiceIsNotReadyToHandleTheRequest,
+        _ => AServer,
+    }
+    CreateTableResponse {
+        400 => IndicatesABadRequestError,
+        403 => Forbidden,
+        404 => NotFound,
+        409 => Conflict,
+        503 => TheServiceIsNotReadyToHandleTheRequest,
+        _ => AServer,
+    }
+    DropNamespaceResponse {
+        400 => IndicatesABadRequestError,
+        403 => Forbidden,
+        404 => NotFound,
+        409 => Conflict,
+        503 => TheServiceIsNotReadyToHandleTheRequest,
+        _ => AServer,
+    }
+    DropTableResponse {
+        400 => IndicatesABadRequestError,
+        403 => Forbidden,
+        404 => NotFound,
+        409 => Conflict,
+        503 => TheServiceIsNotReadyToHandleTheRequest,
+        _ => AServer,
+    }
+    ListNamespacesResponse {
+        400 => IndicatesABadRequestError,
+        403 => Forbidden,
+        404 => NotFound,
+        503 => TheServiceIsNotReadyToHandleTheRequest,
+        _ => AServer,
+    }
+    ListTableCommitsResponse {
+        400 => IndicatesABadRequestError,
+        403 => Forbidden,
+        404 => NotFound,
+        503 => TheServiceIsNotReadyToHandleTheRequest,
+        _ => AServer,
+    }
+    ListTableMetricsResponse {
+        400 => IndicatesABadRequestError,
+        403 => Forbidden,
+        404 => NotFound,
+        503 => TheServiceIsNotReadyToHandleTheRequest,
+        _ => AServer,
+    }
+    ListTablesResponse {
+        400 => IndicatesABadRequestError,
+        403 => Forbidden,
+        404 => NotFound,
+        503 => TheServiceIsNotReadyToHandleTheRequest,
+        _ => AServer,
+    }
+    LoadNamespaceMetadataResponse {
+        400 => IndicatesABadRequestError,
+        403 => Forbidden,
+        404 => NotFound,
+        503 => TheServiceIsNotReadyToHandleTheRequest,
+        _ => AServer,
+    }
+    LoadTableResponse {
+        400 => IndicatesABadRequestError,
+        403 => Forbidden,
+        404 => NotFound,
+        503 => TheServiceIsNotReadyToHandleTheRequest,
+        _ => AServer,
+    }
+    RenameTableResponse {
+        400 => IndicatesABadRequestError,
+        403 => Forbidden,
+        404 => NotFound,
+        409 => Conflict,
+        503 => TheServiceIsNotReadyToHandleTheRequest,
+        _ => AServer,
+    }
+    ReportMetricsResponse {
+        400 => IndicatesABadRequestError,
+        403 => Forbidden,
+        404 => NotFound,
+        503 => TheServiceIsNotReadyToHandleTheRequest,
+        _ => AServer,
+    }
+    UpdatePropertiesResponse {
+        400 => IndicatesABadRequestError,
+        403 => Forbidden,
+        404 => NotFound,
+        422 => UnprocessableEntity,
+        503 => TheServiceIsNotReadyToHandleTheRequest,
+        _ => AServer,
+    }
+    UpdateTableResponse {
+        400 => IndicatesABadRequestError,
+        403 => Forbidden,
+        404 => NotFound,
+        409 => Conflict,
+        500 => AnUnknownServer,
+        503 => TheServiceIsNotReadyToHandleTheRequest,
+        _ => AServer_2,
+    }
+    GetConfigResponse {
+        400 => IndicatesABadRequestError,
+        403 => Forbidden,
+        404 => NotFound,
+        503 => TheServiceIsNotReadyToHandleTheRequest,
+        _ => AServer,
+    }
+    CreateCatalogResponse {
+        400 => IndicatesABadRequestError,
+        403 => Forbidden,
+        409 => Conflict,
+        503 => TheServiceIsNotReadyToHandleTheRequest,
+        _ => AServer,
+    }
+    DropCatalogResponse {
+        400 => IndicatesABadRequestError,
+        403 => Forbidden,
+        404 => NotFound,
+        409 => Conflict,
+        503 => TheServiceIsNotReadyToHandleTheRequest,
+        _ => AServer,
+    }
+    DropRoleResponse {
+        400 => IndicatesABadRequestError,
+        403 => Forbidden,
+        404 => NotFound,
+        503 => TheServiceIsNotReadyToHandleTheRequest,
+        _ => AServer,
+    }
+    GetCatalogConfigResponse {
+        400 => IndicatesABadRequestError,
+        403 => Forbidden,
+        404 => NotFound,
+        503 => TheServiceIsNotReadyToHandleTheRequest,
+        _ => AServer,
+    }
+    ListCatalogsResponse {
+        400 => IndicatesABadRequestError,
+        403 => Forbidden,
+        503 => TheServiceIsNotReadyToHandleTheRequest,
+        _ => AServer,
+    }
+    ListGrantsResponse {
+        400 => IndicatesABadRequestError,
+        403 => Forbidden,
+        404 => NotFound,
+        503 => TheServiceIsNotReadyToHandleTheRequest,
+        _ => AServer,
+    }
+    ListRolesResponse {
+        400 => IndicatesABadRequestError,
+        403 => Forbidden,
+        503 => TheServiceIsNotReadyToHandleTheRequest,
+        _ => AServer,
+    }
+    LoadCatalogResponse {
+        400 => IndicatesABadRequestError,
+        403 => Forbidden,
+        404 => NotFound,
+        503 => TheServiceIsNotReadyToHandleTheRequest,
+        _ => AServer,
+    }
+    UpdateCatalogResponse {
+        400 => IndicatesABadRequestError,
+        403 => Forbidden,
+        404 => NotFound,
+        503 => TheServiceIsNotReadyToHandleTheRequest,
+        _ => AServer,
+    }
+    UpdateCatalogConfigResponse {
+        400 => IndicatesABadRequestError,
+        403 => Forbidden,
+        404 => NotFound,
+        503 => TheServiceIsNotReadyToHandleTheRequest,
+        _ => AServer,
+    }
+    UpdateGrantsResponse {
+        400 => IndicatesABadRequestError,
+        403 => Forbidden,
+        404 => NotFound,
+        503 => TheServiceIsNotReadyToHandleTheRequest,
+        _ => AServer,
+    }
+    UpdateRoleResponse {
+        400 => IndicatesABadRequestError,
+        403 => Forbidden,
+        503 => TheServiceIsNotReadyToHandleTheRequest,
+        _ => AServer,
+    }
+}
+
+impl ErrorResponse for TableExistsResponse {
+    fn from_error(err: CatalogError) -> Self {
+        match err.code() {
+            400 => TableExistsResponse::BadRequest,
+            403 => TableExistsResponse::Forbidden,
+            404 => TableExistsResponse::NotFound,
+            503 => TableExistsResponse::TheServiceIsNotReadyToHandleTheRequest(err.into()),
+            _ => TableExistsResponse::AServer(err.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sea_orm::DbErr;
+
+    use iceberg_catalog_rest_rdbms_server::{
+        models, DropCatalogResponse, DropTableResponse, GetConfigResponse, UpdateTableResponse,
+    };
+
+    use super::{unique_violation, CatalogError, ErrorResponse};
+
+    #[test]
+    fn database_errors() {
+        let err: CatalogError = DbErr::Conn("connection refused".into()).into();
+        assert_eq!(err.code(), 503);
+        assert_eq!(err.r#type(), "ServiceUnavailableException");
+
+        let err: CatalogError = DbErr::Exec("INSERT INTO \"namespace\" failed".into()).into();
+        let model = models::ErrorModel::from(err);
+        assert_eq!(model.code, 500);
+        assert_eq!(model.r#type, "ServiceFailureException");
+        assert!(!model.message.contains("INSERT"));
+    }
+
+    #[test]
+    fn unique_violations() {
+        let err = DbErr::Exec(
+            "error returned from database: duplicate key value violates unique constraint \"idx-namespace-catalog_id-name\"".into(),
+        );
+        assert!(matches!(
+            unique_violation(err, || CatalogError::AlreadyExists("exists".into())),
+            CatalogError::AlreadyExists(_)
+        ));
+        let err = DbErr::Exec(
+            "error returned from database: UNIQUE constraint failed: namespace.name".into(),
+        );
+        assert!(matches!(
+            unique_violation(err, || CatalogError::AlreadyExists("exists".into())),
+            CatalogError::AlreadyExists(_)
+        ));
+        let err = DbErr::Conn("connection refused".into());
+        assert!(matches!(
+            unique_violation(err, || CatalogError::AlreadyExists("exists".into())),
+            CatalogError::ServiceUnavailable(_)
+        ));
+    }
+
+    #[test]
+    fn responses() {
+        assert!(matches!(
+            DropTableResponse::from_error(CatalogError::NoSuchTable("db.table".into())),
+            DropTableResponse::NotFound(models::ErrorModel { code: 404, .. })
+        ));
+        assert!(matches!(
+            DropTableResponse::from_error(CatalogError::Forbidden("denied".into())),
+            DropTableResponse::Forbidden(models::ErrorModel { code: 403, .. })
+        ));
+        assert!(matches!(
+            DropTableResponse::from_error(CatalogError::CommitFailed("conflict".into())),
+            DropTableResponse::Conflict(models::ErrorModel { code: 409, .. })
+        ));
+        assert!(matches!(
+            UpdateTableResponse::from_error(CatalogError::CommitFailed("conflict".into())),
+            UpdateTableResponse::Conflict(models::ErrorModel { code: 409, .. })
+        ));
+        assert!(matches!(
+            UpdateTableResponse::from_error(CatalogError::CommitStateUnknown("timeout".into())),
+            UpdateTableResponse::AnUnknownServer(models::ErrorModel { code: 500, .. })
+        ));
+        assert!(matches!(
+            GetConfigResponse::from_error(CatalogError::NoSuchWarehouse("analytics".into())),
+            GetConfigResponse::NotFound(models::ErrorModel { code: 404, .. })
+        ));
+        assert!(matches!(
+            DropCatalogResponse::from_error(CatalogError::NamespaceNotEmpty("not empty".into())),
+            DropCatalogResponse::Conflict(models::ErrorModel { code: 409, .. })
+        ));
+    }
+}
@@ -0,0 +1,152 @@
+//! Commit history of tables.
+//!
+//! Every successful commit appends a row to `table_commit` recording the metadata
+//! location the table pointer was swapped to, when and by whom. The history is
+//! never updated, so it can be used to find the last known-good version of a
+//! table after an incident. Dropping a table keeps its commits and marks them
+//! with the name of the table and the time of the drop.
+
+use sea_orm::sea_query::Expr;
+use sea_orm::{
+    ActiveValue, ColumnTrait, ConnectionTrait, DbErr, EntityTrait, QueryFilter, QueryOrder,
+    QuerySelect,
+};
+use swagger::auth::Authorization;
+use swagger::{Has, XSpanIdString};
+
+use iceberg_catalog_rest_rdbms_server::models;
+
+use crate::database::entities::{prelude::*, *};
+
+/// Number of commits returned by a page if the request does not set a page size.
+pub const DEFAULT_PAGE_SIZE: i32 = 100;
+/// Largest number of commits returned by a page.
+pub const MAX_PAGE_SIZE: i32 = 1000;
+
+/// The request a commit is made by.
+#[derive(Clone, Debug)]
+pub struct Committer {
+    pub span_id: String,
+    pub principal: Option<String>,
+    /// The service acting on behalf of the principal
+    pub actor: Option<String>,
+}
+
+impl Committer {
+    pub fn from_context<C: Has<XSpanIdString> + Has<Option<Authorization>>>(context: &C) -> Self {
+        let authorization = Has::<Option<Authorization>>::get(context).as_ref();
+        Committer {
+            span_id: Has::<XSpanIdString>::get(context).0.clone(),
+            principal: authorization.map(|authorization| authorization.subject.clone()),
+            actor: authorization.and_then(|authorization| authorization.issuer.clone()),
+        }
+    }
+}
+
+/// Appends a commit that swapped the pointer of the table to `metadata_location`.
+pub async fn record<C: ConnectionTrait>(
+    db: &C,
+    table_id: i32,
+    metadata_location: &str,
+    previous_metadata_location: Option<&str>,
+    committer: &Committer,
+) -> Result<(), DbErr> {
+    let commit = table_commit::ActiveModel {
+        id: ActiveValue::NotSet,
+        metadata_location: ActiveValue::Set(metadata_location.to_owned()),
+        previous_metadata_location: ActiveValue::Set(previous_metadata_location.map(str::to_owned)),
+        committed_at: ActiveValue::Set(chrono::Utc::now().into()),
+        span_id: ActiveValue::Set(Some(committer.span_id.clone())),
+        principal: ActiveValue::Set(committer.principal.clone()),
+        actor: ActiveValue::Set(committer.actor.clone()),
+        table_id: ActiveValue::Set(table_id),
+        table_name: ActiveValue::NotSet,
+        dropped_at: ActiveValue::NotSet,
+    };
+
+    TableCommit::insert(commit).exec(db).await?;
+
+    Ok(())
+}
+
+/// Marks the commits of a table that is dropped. The name identifies the table, as
+/// the id of the table is meaningless once its row is deleted.
+pub async fn mark_dropped<C: ConnectionTrait>(
+    db: &C,
+    table_id: i32,
+    table_name: &str,
+) -> Result<(), DbErr> {
+    TableCommit::update_many()
+        .col_expr(table_commit::Column::TableName, Expr::value(table_name))
+        .col_expr(
+            table_commit::Column::DroppedAt,
+            Expr::value(chrono::DateTime::<chrono::FixedOffset>::from(
+                chrono::Utc::now(),
+            )),
+        )
+        .filter(table_commit::Column::TableId.eq(table_id))
+        .filter(table_commit::Column::DroppedAt.is_null())
+        .exec(db)
+        .await?;
+
+    Ok(())
+}
+
+/// Returns a page of the commits of a table, newest first.
+///
+/// The page token is the id of the last commit of the previous page. Returns an
+/// error message if the token or the page size is invalid.
+pub async fn page<C: ConnectionTrait>(
+    db: &C,
+    table_id: i32,
+    page_token: Option<&str>,
+    page_size: Option<i32>,
+) -> Result<Result<models::ListTableCommits200Response, String>, DbErr> {
+    let page_size = match page_size {
+        None => DEFAULT_PAGE_SIZE,
+        Some(page_size) if page_size < 1 => {
+            return Ok(Err("The page size has to be positive.".into()))
+        }
+        Some(page_size) => page_size.min(MAX_PAGE_SIZE),
+    };
+    let before = match page_token.map(str::parse::<i32>) {
+        None => None,
+        Some(Ok(id)) => Some(id),
+        Some(Err(_)) => return Ok(Err("The page token is invalid.".into())),
+    };
+
+    let mut query = TableCommit::find()
+        .filter(table_commit::Column::TableId.eq(table_id))
+        .filter(table_commit::Column::DroppedAt.is_null());
+    if let Some(before) = before {
+        query = query.filter(table_commit::Column::Id.lt(before));
+    }
+    // One more commit than requested tells whether there is a next page
+    let mut commits = query
+        .order_by_desc(table_commit::Column::Id)
+        .limit(page_size as u64 + 1)
+        .all(db)
+        .await?;
+
+    let next_page_token = if commits.len() > page_size as usize {
+        commits.truncate(page_size as usize);
+        commits.last().map(|commit| commit.id.to_string())
+    } else {
+        None
+    };
+
+    Ok(Ok(models::ListTableCommits200Response {
+        commits: commits
+            .into_iter()
+            .map(|commit| models::TableCommit {
+                metadata_location: commit.metadata_location,
+                previous_metadata_location: commit.previous_metadata_location,
+                timestamp_ms: commit.committed_at.timestamp_millis(),
+                span_id: commit.span_id,
+                principal: commit.principal,
+                actor: commit.actor,
+            })
+            .collect(),
+        next_page_token,
+    }))
+}
@@ -0,0 +1,459 @@
+//! Table metadata documents.
+//!
+//! The metadata of new tables is created by the server. Each metadata location a
+//! table has pointed to is stored together with the full metadata document, so
+//! that `load_table` and `update_table` can return the metadata without reading
+//! the metadata file.
+
+use std::collections::HashMap;
+
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DbErr, EntityTrait, PaginatorTrait, QueryFilter,
+};
+use serde_json::json;
+
+use iceberg_catalog_rest_rdbms_server::models;
+
+use crate::database::entities::{prelude::*, *};
+use crate::io::{FileIO, IoError};
+
+use super::error::CatalogError;
+
+/// Id of the first partition field. Partition field ids start after the ids reserved for columns.
+pub(crate) const PARTITION_DATA_ID_START: isize = 1000;
+
+/// Creates the metadata of a new table from the request that creates it.
+///
+/// The columns of the schema get fresh ids, and the partition spec and the sort
+/// order are rewritten to refer to them.
+pub fn new_table_metadata(
+    request: &models::CreateTableRequest,
+    location: &str,
+) -> Result<models::TableMetadata, String> {
+    let mut schema = request.schema.clone();
+    let mut ids = HashMap::new();
+    let mut last_column_id = 0;
+    assign_fresh_ids(&mut schema.fields, &mut last_column_id, &mut ids);
+    schema.schema_id = Some(0);
+    if let Some(identifier_field_ids) = schema.identifier_field_ids.as_mut() {
+        for id in identifier_field_ids.iter_mut() {
+            *id = fresh_id(&ids, *id as isize)? as i32;
+        }
+    }
+
+    let mut spec = request
+        .partition_spec
+        .clone()
+        .unwrap_or_else(|| models::PartitionSpec::new(vec![]));
+    spec.spec_id = Some(0);
+    let mut last_partition_id = PARTITION_DATA_ID_START - 1;
+    for field in spec.fields.iter_mut() {
+        last_partition_id += 1;
+        field.field_id = Some(last_partition_id);
+        field.source_id = fresh_id(&ids, field.source_id)?;
+    }
+
+    let mut sort_order = request
+        .write_order
+        .clone()
+        .unwrap_or_else(|| models::SortOrder::new(0, vec![]));
+    // The order id 0 is reserved for the unsorted order
+    sort_order.order_id = if sort_order.fields.is_empty() { 0 } else { 1 };
+    for field in sort_order.fields.iter_mut() {
+        field.source_id = fresh_id(&ids, field.source_id)?;
+    }
+
+    Ok(models::TableMetadata {
+        format_version: 2,
+        table_uuid: uuid::Uuid::new_v4().to_string(),
+        location: Some(location.to_owned()),
+        last_updated_ms: Some(chrono::Utc::now().timestamp_millis() as isize),
+        last_sequence_number: Some(0),
+        properties: Some(request.properties.clone().unwrap_or_default()),
+        schemas: Some(vec![schema]),
+        current_schema_id: Some(0),
+        last_column_id: Some(last_column_id),
+        default_spec_id: Some(0),
+        partition_specs: Some(vec![spec]),
+        last_partition_id: Some(last_partition_id),
+        default_sort_order_id: Some(sort_order.order_id),
+        sort_orders: Some(vec![sort_order]),
+        snapshots: Some(vec![]),
+        refs: Some(HashMap::new()),
+        current_snapshot_id: None,
+        snapshot_log: Some(vec![]),
+        metadata_log: Some(vec![]),
+    })
+}
+
+/// Returns the storage root tables are confined to: the one of their catalog or
+/// else the warehouse.
+pub fn storage_root<'a>(
+    catalog: Option<&'a catalog::Model>,
+    warehouse: Option<&'a str>,
+) -> Option<&'a str> {
+    catalog
+        .and_then(|catalog| catalog.storage_root.as_deref())
+        .or(warehouse)
+}
+
+/// Checks that a location requested for a table lies below the storage root, so
+/// that clients can't make the catalog write or delete files elsewhere. Returns the
+/// location without trailing slashes.
+pub fn confine_location(location: &str, root: Option<&str>) -> Result<String, String> {
+    let location = location.trim_end_matches('/');
+    let root = root.ok_or("Table locations can't be requested if no warehouse is configured.")?;
+    let inside = location
+        .strip_prefix(root.trim_end_matches('/'))
+        .and_then(|path| path.strip_prefix('/'))
+        .is_some_and(|path| !path.is_empty() && path.split('/').all(|segment| segment != ".."));
+    if inside {
+        Ok(location.to_owned())
+    } else {
+        Err(format!(
+            "The table location {} is outside of the storage root {}.",
+            location, root
+        ))
+    }
+}
+
+/// Returns the location of a new metadata file of a table.
+pub fn metadata_file_location(location: &str, version: usize) -> String {
+    format!(
+        "{}/metadata/{:05}-{}.metadata.json",
+        location.trim_end_matches('/'),
+        version,
+        uuid::Uuid::new_v4()
+    )
+}
+
+/// Returns the version of a metadata file named like the files of `metadata_file_location`.
+pub fn metadata_file_version(metadata_location: &str) -> Option<usize> {
+    metadata_location
+        .rsplit('/')
+        .next()?
+        .split_once('-')?
+        .0
+        .parse()
+        .ok()
+}
+
+/// Returns the version of the next metadata file of the table.
+///
+/// The version is parsed from the current metadata location. Locations not named
+/// like the files of `metadata_file_location` count the stored metadata documents
+/// instead, as the metadata log may have been trimmed.
+pub async fn next_version<C: ConnectionTrait>(
+    db: &C,
+    table: &iceberg_table::Model,
+) -> Result<usize, DbErr> {
+    if let Some(version) = metadata_file_version(&table.metadata_location) {
+        return Ok(version + 1);
+    }
+    let versions = TableMetadata::find()
+        .filter(table_metadata::Column::TableId.eq(table.id))
+        .count(db)
+        .await?;
+    Ok(versions)
+}
+
+/// Writes the metadata file of a table version.
+pub async fn write(
+    file_io: &dyn FileIO,
+    metadata_location: &str,
+    metadata: &models::TableMetadata,
+) -> Result<(), IoError> {
+    let bytes = serde_json::to_vec(metadata).map_err(std::io::Error::from)?;
+    file_io.write(metadata_location, &bytes).await
+}
+
+/// Assigns ids to the fields of a struct and then to the types nested in them, like
+/// Iceberg does when a table is created. Records the new id of every old id.
+fn assign_fresh_ids(
+    fields: &mut [models::StructField],
+    last_id: &mut isize,
+    ids: &mut HashMap<isize, isize>,
+) {
+    for field in fields.iter_mut() {
+        *last_id += 1;
+        ids.insert(field.id, *last_id);
+        field.id = *last_id;
+    }
+    for field in fields.iter_mut() {
+        assign_fresh_type_ids(&mut field.r#type, last_id, ids);
+    }
+}
+
+fn assign_fresh_type_ids(
+    r#type: &mut models::Type,
+    last_id: &mut isize,
+    ids: &mut HashMap<isize, isize>,
+) {
+    match r#type {
+        models::Type::Primitive(_) => (),
+        models::Type::Struct(r#struct) => assign_fresh_ids(&mut r#struct.fields, last_id, ids),
+        models::Type::List(list) => {
+            *last_id += 1;
+            ids.insert(list.element_id, *last_id);
+            list.element_id = *last_id;
+            assign_fresh_type_ids(&mut list.element, last_id, ids);
+        }
+        models::Type::Map(map) => {
+            *last_id += 2;
+            ids.insert(map.key_id, *last_id - 1);
+            ids.insert(map.value_id, *last_id);
+            map.key_id = *last_id - 1;
+            map.value_id = *last_id;
+            assign_fresh_type_ids(&mut map.key, last_id, ids);
+            assign_fresh_type_ids(&mut map.value, last_id, ids);
+        }
+    }
+}
+
+fn fresh_id(ids: &HashMap<isize, isize>, id: isize) -> Result<isize, String> {
+    ids.get(&id)
+        .copied()
+        .ok_or_else(|| format!("The field {} does not exist in the schema.", id))
+}
+
+/// Loads the metadata document the table currently points to.
+pub async fn load<C: ConnectionTrait>(
+    db: &C,
+    table: &iceberg_table::Model,
+) -> Result<models::TableMetadata, CatalogError> {
+    let metadata = TableMetadata::find()
+        .filter(table_metadata::Column::TableId.eq(table.id))
+        .filter(table_metadata::Column::MetadataLocation.eq(table.metadata_location.as_str()))
+        .one(db)
+        .await?
+        .ok_or_else(|| {
+            DbErr::RecordNotFound(format!(
+                "No metadata stored for {}.",
+                table.metadata_location
+            ))
+        })?;
+
+    Ok(serde_json::from_value(metadata.metadata).map_err(|err| DbErr::Json(err.to_string()))?)
+}
+
+/// Stores the metadata document of a metadata location of the table.
+pub async fn store<C: ConnectionTrait>(
+    db: &C,
+    table_id: i32,
+    metadata_location: &str,
+    metadata: &models::TableMetadata,
+) -> Result<(), CatalogError> {
+    let new_metadata = table_metadata::ActiveModel::from_json(json!({
+        "table_id": table_id,
+        "metadata_location": metadata_location,
+        "metadata": metadata,
+    }))?;
+
+    TableMetadata::insert(new_metadata).exec(db).await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use iceberg_catalog_rest_rdbms_server::models;
+
+    #[test]
+    fn fresh_ids() {
+        let request: models::CreateTableRequest = serde_json::from_value(serde_json::json!({
+            "name": "events",
+            "schema": {
+                "type": "struct",
+                "identifier-field-ids": [20],
+                "fields": [
+                    {
+                        "id": 10, "name": "point", "required": false,
+                        "type": {
+                            "type": "struct",
+                            "fields": [
+                                { "id": 11, "name": "x", "required": true, "type": "double" },
+                                { "id": 12, "name": "y", "required": true, "type": "double" }
+                            ]
+                        }
+                    },
+                    {
+                        "id": 13, "name": "tags", "required": false,
+                        "type": {
+                            "type": "map", "key-id": 14, "key": "string", "value-id": 15,
+                            "value": {
+                                "type": "list", "element-id": 16, "element": "string",
+                                "element-required": false
+                            },
+                            "value-required": true
+                        }
+                    },
+                    { "id": 20, "name": "id", "required": true, "type": "long" }
+                ]
+            },
+            "partition-spec": {
+                "fields": [ { "source-id": 20, "name": "id_bucket", "transform": "bucket[8]" } ]
+            },
+            "write-order": {
+                "order-id": 5,
+                "fields": [
+                    { "source-id": 12, "transform": "identity", "direction": "asc", "null-order": "nulls-first" }
+                ]
+            }
+        }))
+        .unwrap();
+
+        let metadata = super::new_table_metadata(&request, "file:///tmp/events").unwrap();
+        let metadata = serde_json::to_value(&metadata).unwrap();
+
+        let schema = &metadata["schemas"][0];
+        assert_eq!(schema["schema-id"], 0);
+        assert_eq!(schema["identifier-field-ids"], serde_json::json!([3]));
+        assert_eq!(schema["fields"][0]["id"], 1);
+        assert_eq!(schema["fields"][1]["id"], 2);
+        assert_eq!(schema["fields"][2]["id"], 3);
+        assert_eq!(schema["fields"][0]["type"]["fields"][0]["id"], 4);
+        assert_eq!(schema["fields"][0]["type"]["fields"][1]["id"], 5);
+        assert_eq!(schema["fields"][1]["type"]["key-id"], 6);
+        assert_eq!(schema["fields"][1]["type"]["value-id"], 7);
+        assert_eq!(schema["fields"][1]["type"]["value"]["element-id"], 8);
+        assert_eq!(metadata["last-column-id"], 8);
+
+        assert_eq!(
+            metadata["partition-specs"][0]["fields"][0],
+            serde_json::json!({
+                "field-id": 1000, "source-id": 3, "name": "id_bucket", "transform": "bucket[8]"
+            })
+        );
+        assert_eq!(metadata["last-partition-id"], 1000);
+        assert_eq!(metadata["sort-orders"][0]["order-id"], 1);
+        assert_eq!(metadata["sort-orders"][0]["fields"][0]["source-id"], 5);
+        assert_eq!(metadata["default-sort-order-id"], 1);
+        assert_eq!(metadata["format-version"], 2);
+    }
+
+    #[test]
+    fn unknown_source_id() {
+        let request: models::CreateTableRequest = serde_json::from_value(serde_json::json!({
+            "name": "events",
+            "schema": {
+                "type": "struct",
+                "fields": [ { "id": 1, "name": "id", "required": true, "type": "long" } ]
+            },
+            "partition-spec": {
+                "fields": [ { "source-id": 2, "name": "ts_day", "transform": "day" } ]
+            }
+        }))
+        .unwrap();
+
+        assert!(super::new_table_metadata(&request, "file:///tmp/events").is_err());
+    }
+
+    #[test]
+    fn confined_locations() {
+        let root = Some("s3://bucket/warehouse/");
+        assert_eq!(
+            super::confine_location("s3://bucket/warehouse/db/events/", root).unwrap(),
+            "s3://bucket/warehouse/db/events"
+        );
+        assert!(super::confine_location("s3://bucket/warehouse", root).is_err());
+        assert!(super::confine_location("s3://bucket/warehouse2/events", root).is_err());
+        assert!(super::confine_location("s3://bucket/warehouse/../events", root).is_err());
+        assert!(super::confine_location("s3://other/events", root).is_err());
+        assert!(super::confine_location("s3://bucket/warehouse/events", None).is_err());
+    }
+
+    #[test]
+    fn metadata_round_trip() {
+        let document = serde_json::json!({
+            "format-version": 2,
+            "table-uuid": "9c12d441-03fe-4693-9a96-a0705ddf69c1",
+            "location": "s3://bucket/test/location",
+            "last-sequence-number": 34,
+            "last-updated-ms": 1602638573590i64,
+            "last-column-id": 3,
+            "current-schema-id": 1,
+            "schemas": [
+                {
+                    "type": "struct",
+                    "schema-id": 0,
+                    "fields": [
+                        { "id": 1, "name": "x", "required": true, "type": "long" }
+                    ]
+                },
+                {
+                    "type": "struct",
+                    "schema-id": 1,
+                    "identifier-field-ids": [1, 2],
+                    "fields": [
+                        { "id": 1, "name": "x", "required": true, "type": "long" },
+                        { "id": 2, "name": "y", "required": true, "type": "long", "doc": "comment" },
+                        { "id": 3, "name": "z", "required": true, "type": "long" }
+                    ]
+                }
+            ],
+            "default-spec-id": 0,
+            "partition-specs": [
+                {
+                    "spec-id": 0,
+                    "fields": [
+                        { "name": "x", "transform": "identity", "source-id": 1, "field-id": 1000 }
+                    ]
+                }
+            ],
+            "last-partition-id": 1000,
+            "default-sort-order-id": 3,
+            "sort-orders": [
+                {
+                    "order-id": 3,
+                    "fields": [
+                        { "transform": "identity", "source-id": 2, "direction": "asc", "null-order": "nulls-first" },
+                        { "transform": "bucket[4]", "source-id": 3, "direction": "desc", "null-order": "nulls-last" }
+                    ]
+                }
+            ],
+            "properties": { "read.split.target.size": "134217728" },
+            "current-snapshot-id": 3055729675574597004i64,
+            "snapshots": [
+                {
+                    "snapshot-id": 3051729675574597004i64,
+                    "timestamp-ms": 1515100955770i64,
+                    "sequence-number": 0,
+                    "summary": { "operation": "append" },
+                    "manifest-list": "s3://a/b/1.avro"
+                },
+                {
+                    "snapshot-id": 3055729675574597004i64,
+                    "parent-snapshot-id": 3051729675574597004i64,
+                    "timestamp-ms": 1555100955770i64,
+                    "sequence-number": 1,
+                    "summary": {
+                        "operation": "append",
+                        "added-data-files": "4",
+                        "total-records": "100"
+                    },
+                    "manifest-list": "s3://a/b/2.avro",
+                    "schema-id": 1
+                }
+            ],
+            "refs": {
+                "main": { "type": "branch", "snapshot-id": 3055729675574597004i64 },
+                "audit": {
+                    "type": "tag",
+                    "snapshot-id": 3051729675574597004i64,
+                    "max-ref-age-ms": 86400000
+                }
+            },
+            "snapshot-log": [
+                { "snapshot-id": 3051729675574597004i64, "timestamp-ms": 1515100955770i64 },
+                { "snapshot-id": 3055729675574597004i64, "timestamp-ms": 1555100955770i64 }
+            ],
+            "metadata-log": [
+                { "metadata-file": "s3://bucket/.../v1.json", "timestamp-ms": 1515100 }
+            ]
+        });
+
+        let metadata: models::TableMetadata = serde_json::from_value(document.clone()).unwrap();
+
+        assert_eq!(serde_json::to_value(&metadata).unwrap(), document);
+    }
+}
@@ -0,0 +1,375 @@
+//! Metrics reports sent by engines.
+//!
+//! Scan and commit reports are stored as they are received, linked to the table
+//! they were reported for, and their counters and timers additionally as rows of
+//! `report_metric`. They are only aggregated when they are queried, so the same
+//! reports can be aggregated over any time window. The database sums them up, so
+//! a query doesn't load the reports themselves.
+
+use std::collections::HashMap;
+
+use chrono::TimeZone;
+use sea_orm::sea_query::{Expr, SimpleExpr};
+use sea_orm::{
+    ActiveValue, ColumnTrait, ConnectionTrait, DbBackend, EntityTrait, FromQueryResult,
+    QueryFilter, QuerySelect,
+};
+
+use iceberg_catalog_rest_rdbms_server::models;
+
+use crate::database::entities::{prelude::*, *};
+use crate::reporter;
+
+use super::error::CatalogError;
+use super::resolver;
+
+/// Report type of scan reports.
+pub const SCAN_REPORT: &str = "scan-report";
+/// Report type of commit reports.
+pub const COMMIT_REPORT: &str = "commit-report";
+
+/// Stores a report sent for the table.
+pub async fn record<C: ConnectionTrait>(
+    db: &C,
+    table_id: i32,
+    report: models::ReportMetricsRequest,
+) -> Result<(), CatalogError> {
+    let reported_at = chrono::Utc::now();
+    let report_type = report.report_type.clone();
+    let measurements = reporter::measurements(&report.metrics);
+    let metrics = serde_json::json!(report.metrics);
+    let metadata = report.metadata.map(|metadata| serde_json::json!(metadata));
+
+    match report.report_type.as_str() {
+        SCAN_REPORT => {
+            let scan_report = scan_report::ActiveModel {
+                id: ActiveValue::NotSet,
+                snapshot_id: ActiveValue::Set(report.snapshot_id),
+                filter: ActiveValue::Set(report.filter),
+                schema_id: ActiveValue::Set(report.schema_id),
+                projected_field_ids: ActiveValue::Set(
+                    report.projected_field_ids.map(|ids| serde_json::json!(ids)),
+                ),
+                projected_field_names: ActiveValue::Set(
+                    report
+                        .projected_field_names
+                        .map(|names| serde_json::json!(names)),
+                ),
+                projection: ActiveValue::Set(
+                    report.projection.map(|schema| serde_json::json!(schema)),
+                ),
+                metrics: ActiveValue::Set(metrics),
+                metadata: ActiveValue::Set(metadata),
+                reported_at: ActiveValue::Set(reported_at.into()),
+                table_id: ActiveValue::Set(table_id),
+            };
+            ScanReport::insert(scan_report).exec(db).await?;
+        }
+        COMMIT_REPORT => {
+            let (sequence_number, operation) = match (report.sequence_number, report.operation) {
+                (Some(sequence_number), Some(operation)) => (sequence_number, operation),
+                _ => {
+                    return Err(CatalogError::BadRequest(
+                        "Commit reports require a sequence-number and an operation.".into(),
+                    ))
+                }
+            };
+            let commit_report = commit_report::ActiveModel {
+                id: ActiveValue::NotSet,
+                snapshot_id: ActiveValue::Set(report.snapshot_id),
+                sequence_number: ActiveValue::Set(sequence_number),
+                operation: ActiveValue::Set(operation),
+                metrics: ActiveValue::Set(metrics),
+                metadata: ActiveValue::Set(metadata),
+                reported_at: ActiveValue::Set(reported_at.into()),
+                table_id: ActiveValue::Set(table_id),
+            };
+            CommitReport::insert(commit_report).exec(db).await?;
+        }
+        report_type => {
+            return Err(CatalogError::BadRequest(format!(
+                "Unknown report type {}.",
+                report_type
+            )))
+        }
+    }
+
+    if !measurements.is_empty() {
+        ReportMetric::insert_many(measurements.into_iter().map(|measurement| {
+            report_metric::ActiveModel {
+                id: ActiveValue::NotSet,
+                report_type: ActiveValue::Set(report_type.clone()),
+                name: ActiveValue::Set(measurement.name),
+                value: ActiveValue::Set(measurement.value),
+                count: ActiveValue::Set(measurement.count),
+                total_duration: ActiveValue::Set(measurement.total_duration),
+                reported_at: ActiveValue::Set(reported_at.into()),
+                table_id: ActiveValue::Set(table_id),
+            }
+        }))
+        .exec(db)
+        .await?;
+    }
+
+    Ok(())
+}
+
+/// Aggregates the reports of the tables of a namespace that were received between
+/// `from` and `to`, given in milliseconds since the epoch. The most scanned table
+/// comes first.
+pub async fn aggregate<C: ConnectionTrait>(
+    db: &C,
+    namespace: &namespace::Model,
+    tables: Vec<iceberg_table::Model>,
+    from: Option<i64>,
+    to: Option<i64>,
+) -> Result<Vec<models::TableMetrics>, CatalogError> {
+    let from = from.map(timestamp).transpose()?;
+    let to = to.map(timestamp).transpose()?;
+    if let (Some(from), Some(to)) = (from, to) {
+        if from > to {
+            return Err(CatalogError::BadRequest(
+                "The time window ends before it starts.".into(),
+            ));
+        }
+    }
+    let ids: Vec<i32> = tables.iter().map(|table| table.id).collect();
+    let backend = db.get_database_backend();
+
+    let mut scan_reports = ScanReport::find()
+        .select_only()
+        .column(scan_report::Column::TableId)
+        .filter(scan_report::Column::TableId.is_in(ids.clone()));
+    let mut commit_reports = CommitReport::find()
+        .select_only()
+        .column(commit_report::Column::TableId)
+        .filter(commit_report::Column::TableId.is_in(ids.clone()));
+    let mut report_metrics = ReportMetric::find()
+        .select_only()
+        .column(report_metric::Column::TableId)
+        .filter(report_metric::Column::TableId.is_in(ids));
+    if let Some(from) = from {
+        scan_reports = scan_reports.filter(scan_report::Column::ReportedAt.gte(from));
+        commit_reports = commit_reports.filter(commit_report::Column::ReportedAt.gte(from));
+        report_metrics = report_metrics.filter(report_metric::Column::ReportedAt.gte(from));
+    }
+    if let Some(to) = to {
+        scan_reports = scan_reports.filter(scan_report::Column::ReportedAt.lt(to));
+        commit_reports = commit_reports.filter(commit_report::Column::ReportedAt.lt(to));
+        report_metrics = report_metrics.filter(report_metric::Column::ReportedAt.lt(to));
+    }
+
+    let mut aggregates: HashMap<i32, Aggregate> = HashMap::new();
+    let scan_counts = scan_reports
+        .clone()
+        .column_as(scan_report::Column::Id.count(), "reports")
+        .group_by(scan_report::Column::TableId)
+        .into_model::<ReportCount>()
+        .all(db)
+        .await?;
+    for count in scan_counts {
+        aggregates.entry(count.table_id).or_default().scan_count = count.reports;
+    }
+    let commit_counts = commit_reports
+        .column_as(commit_report::Column::Id.count(), "reports")
+        .group_by(commit_report::Column::TableId)
+        .into_model::<ReportCount>()
+        .all(db)
+        .await?;
+    for count in commit_counts {
+        aggregates.entry(count.table_id).or_default().commit_count = count.reports;
+    }
+
+    // JSON values can't be compared in every database, so filters are grouped by
+    // their text
+    let filter = Expr::cust(match backend {
+        DbBackend::MySql => "CAST(`filter` AS CHAR)",
+        DbBackend::Postgres | DbBackend::Sqlite => r#"CAST("filter" AS TEXT)"#,
+    });
+    let filter_counts = scan_reports
+        .column_as(filter.clone(), "filter")
+        .column_as(scan_report::Column::Id.count(), "reports")
+        .filter(scan_report::Column::Filter.is_not_null())
+        .group_by(scan_report::Column::TableId)
+        .group_by(filter)
+        .into_model::<FilterCount>()
+        .all(db)
+        .await?;
+    for count in filter_counts {
+        let filter = serde_json::from_str(&count.filter)
+            .map_err(|err| CatalogError::Internal(err.to_string()))?;
+        aggregates
+            .entry(count.table_id)
+            .or_default()
+            .filters
+            .push((filter, count.reports));
+    }
+
+    let sums = report_metrics
+        .column(report_metric::Column::Name)
+        .column_as(sum(backend, "value"), "value")
+        .column_as(sum(backend, "count"), "count")
+        .column_as(sum(backend, "total_duration"), "total_duration")
+        .group_by(report_metric::Column::TableId)
+        .group_by(report_metric::Column::Name)
+        .into_model::<MetricSum>()
+        .all(db)
+        .await?;
+    for sum in sums {
+        let aggregate = aggregates.entry(sum.table_id).or_default();
+        if let Some(value) = sum.value {
+            aggregate.counters.insert(sum.name, value);
+        } else if let (Some(count), Some(total_duration)) = (sum.count, sum.total_duration) {
+            aggregate.timers.insert(sum.name, (count, total_duration));
+        }
+    }
+
+    let mut metrics: Vec<models::TableMetrics> = tables
+        .into_iter()
+        .map(|table| {
+            let identifier = models::TableIdentifier {
+                namespace: resolver::namespace_levels(&namespace.name),
+                name: table.name,
+            };
+            aggregates
+                .remove(&table.id)
+                .unwrap_or_default()
+                .into_table_metrics(identifier)
+        })
+        .collect();
+    metrics.sort_by(|a, b| {
+        b.scan_count
+            .cmp(&a.scan_count)
+            .then_with(|| a.identifier.name.cmp(&b.identifier.name))
+    });
+    Ok(metrics)
+}
+
+/// Number of reports of a table.
+#[derive(FromQueryResult)]
+struct ReportCount {
+    table_id: i32,
+    reports: i64,
+}
+
+/// Number of scans of a table with a filter, given as JSON text.
+#[derive(FromQueryResult)]
+struct FilterCount {
+    table_id: i32,
+    filter: String,
+    reports: i64,
+}
+
+/// A counter or timer of a table summed up.
+#[derive(FromQueryResult)]
+struct MetricSum {
+    table_id: i32,
+    name: String,
+    value: Option<i64>,
+    count: Option<i64>,
+    total_duration: Option<i64>,
+}
+
+/// Returns the sum of a 64 bit integer column as a 64 bit integer, which the sum is
+/// widened from by PostgreSQL and MySQL.
+fn sum(backend: DbBackend, column: &str) -> SimpleExpr {
+    Expr::cust(&match backend {
+        DbBackend::Postgres => format!(r#"CAST(SUM("{}") AS BIGINT)"#, column),
+        DbBackend::MySql => format!("CAST(SUM(`{}`) AS SIGNED)", column),
+        DbBackend::Sqlite => format!(r#"SUM("{}")"#, column),
+    })
+}
+
+/// Reports of a table summed up.
+#[derive(Default)]
+struct Aggregate {
+    scan_count: i64,
+    commit_count: i64,
+    counters: HashMap<String, i64>,
+    /// Count and total duration in nanoseconds by timer name
+    timers: HashMap<String, (i64, i64)>,
+    /// Filters and their number of scans
+    filters: Vec<(serde_json::Value, i64)>,
+}
+
+impl Aggregate {
+    fn into_table_metrics(self, identifier: models::TableIdentifier) -> models::TableMetrics {
+        let mut filters: Vec<models::FilterUsage> = self
+            .filters
+            .into_iter()
+            .map(|(filter, count)| models::FilterUsage { filter, count })
+            .collect();
+        filters.sort_by(|a, b| {
+            b.count
+                .cmp(&a.count)
+                .then_with(|| a.filter.to_string().cmp(&b.filter.to_string()))
+        });
+
+        models::TableMetrics {
+            identifier,
+            scan_count: self.scan_count,
+            commit_count: self.commit_count,
+            counters: self.counters,
+            timers: self
+                .timers
+                .into_iter()
+                .map(|(name, (count, total_duration))| {
+                    (
+                        name,
+                        models::TimerResult {
+                            time_unit: "nanoseconds".into(),
+                            count,
+                            total_duration,
+                        },
+                    )
+                })
+                .collect(),
+            filters,
+        }
+    }
+}
+
+fn timestamp(millis: i64) -> Result<chrono::DateTime<chrono::FixedOffset>, CatalogError> {
+    chrono::Utc
+        .timestamp_millis_opt(millis)
+        .single()
+        .map(Into::into)
+        .ok_or_else(|| CatalogError::BadRequest(format!("Invalid timestamp {}.", millis)))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use iceberg_catalog_rest_rdbms_server::models;
+
+    use super::Aggregate;
+
+    #[test]
+    fn aggregate() {
+        let aggregate = Aggregate {
+            scan_count: 3,
+            commit_count: 1,
+            counters: HashMap::from([("result-data-files".into(), 7)]),
+            timers: HashMap::from([("total-planning-duration".into(), (2, 2_000_500))]),
+            filters: vec![
+                (serde_json::json!(true), 1),
+                (serde_json::json!({ "type": "not-null", "term": "id" }), 2),
+            ],
+        };
+
+        let metrics = aggregate.into_table_metrics(models::TableIdentifier {
+            namespace: vec!["db".into()],
+            name: "table".into(),
+        });
+        assert_eq!(metrics.scan_count, 3);
+        assert_eq!(metrics.commit_count, 1);
+        assert_eq!(metrics.counters["result-data-files"], 7);
+        let timer = &metrics.timers["total-planning-duration"];
+        assert_eq!(timer.count, 2);
+        assert_eq!(timer.total_duration, 2_000_500);
+        assert_eq!(timer.time_unit, "nanoseconds");
+        assert_eq!(metrics.filters[0].count, 2);
+        assert_eq!(metrics.filters[1].filter, serde_json::json!(true));
+    }
+}
@@ -1,144 +1,236 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
 use async_trait::async_trait;
-use log::info;
-use sea_orm::sea_query::OnConflict;
+use sea_orm::sea_query::Expr;
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, ModelTrait, QueryFilter,
-    TransactionTrait,
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, DbErr, EntityTrait,
+    ModelTrait, PaginatorTrait, QueryFilter, TransactionError, TransactionTrait,
 };
 use serde_json::json;
+use swagger::auth::Authorization;
 use swagger::{Has, XSpanIdString};
 
-use iceberg_catalog_rest_rdbms_server::models::{self, TableMetadata};
+use iceberg_catalog_rest_rdbms_server::models::{self, Privilege};
 
 use iceberg_catalog_rest_rdbms_server::{
-    Api, CreateNamespaceResponse, CreateTableResponse, DropNamespaceResponse, DropTableResponse,
-    GetConfigResponse, GetTokenResponse, ListNamespacesResponse, ListTablesResponse,
-    LoadNamespaceMetadataResponse, LoadTableResponse, RenameTableResponse, ReportMetricsResponse,
-    TableExistsResponse, UpdatePropertiesResponse, UpdateTableResponse,
+    Api, CreateCatalogResponse, CreateNamespaceResponse, CreateTableResponse, DropCatalogResponse,
+    DropNamespaceResponse, DropRoleResponse, DropTableResponse, GetCatalogConfigResponse,
+    GetConfigResponse, GetTokenResponse, ListCatalogsResponse, ListGrantsResponse,
+    ListNamespacesResponse, ListRolesResponse, ListTableCommitsResponse, ListTableMetricsResponse,
+    ListTablesResponse, LoadCatalogResponse, LoadNamespaceMetadataResponse, LoadTableResponse,
+    RenameTableResponse, ReportMetricsResponse, TableExistsResponse, UpdateCatalogConfigResponse,
+    UpdateCatalogResponse, UpdateGrantsResponse, UpdatePropertiesResponse, UpdateRoleResponse,
+    UpdateTableResponse,
 };
 
 use swagger::ApiError;
 
+use crate::auth::{TokenIssuer, Verifier};
 use crate::database::entities::{prelude::*, *};
+use crate::io;
+use crate::reporter::{Report, Reporters};
+
+use self::access::{Caller, Target};
+use self::error::{CatalogError, ErrorResponse};
+use self::history::Committer;
+use self::oauth::OAuthError;
+use self::requirement::RequirementError;
+
+pub use self::config::ClientConfig;
+pub use self::oauth::{register_client, DEFAULT_SCOPE};
+
+mod access;
+mod catalogs;
+mod config;
+mod error;
+mod history;
+mod metadata;
+mod metrics;
+mod oauth;
+mod purge;
+mod requirement;
+mod resolver;
+mod roles;
+mod staged;
+mod update;
 
 #[derive(Clone)]
 pub struct Server {
     db: DatabaseConnection,
+    warehouse: Option<String>,
+    reporters: Reporters,
+    tokens: Arc<TokenIssuer>,
+    /// Verifies the tokens of identity providers exchanged for delegated tokens
+    verifier: Arc<Verifier>,
+    /// Principals that hold every privilege and manage the roles
+    admins: Arc<HashSet<String>>,
+    /// Configuration pushed to the clients of every catalog
+    config: Arc<ClientConfig>,
+    /// Whether requests for prefixes without a catalog are rejected instead of
+    /// creating the catalog
+    strict_catalogs: bool,
 }
 
 impl Server {
-    pub fn new(db: DatabaseConnection) -> Self {
-        Server { db }
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        db: DatabaseConnection,
+        warehouse: Option<String>,
+        reporters: Reporters,
+        tokens: Arc<TokenIssuer>,
+        verifier: Arc<Verifier>,
+        admins: HashSet<String>,
+        config: ClientConfig,
+        strict_catalogs: bool,
+    ) -> Self {
+        Server {
+            db,
+            warehouse,
+            reporters,
+            tokens,
+            verifier,
+            admins: Arc::new(admins),
+            config: Arc::new(config),
+            strict_catalogs,
+        }
+    }
+
+    /// Resumes the purges of dropped tables that were interrupted by a restart.
+    pub async fn resume_purges(&self) -> Result<(), DbErr> {
+        purge::resume(self.db.clone()).await
     }
 }
 
 #[async_trait]
 impl<C> Api<C> for Server
 where
-    C: Has<XSpanIdString> + Send + Sync,
+    C: Has<XSpanIdString> + Has<Option<Authorization>> + Send + Sync,
 {
     /// Create a namespace
     async fn create_namespace(
         &self,
         prefix: String,
         create_namespace_request: Option<models::CreateNamespaceRequest>,
-        _context: &C,
+        context: &C,
     ) -> Result<CreateNamespaceResponse, ApiError> {
-        let name = iter_tools::intersperse(
-            create_namespace_request
-                .ok_or(ApiError("Missing CreateNamespaceRequest.".into()))?
-                .namespace
-                .into_iter(),
-            ".".to_owned(),
-        )
-        .collect::<String>();
+        let create_namespace_request = match create_namespace_request {
+            Some(create_namespace_request) => create_namespace_request,
+            None => {
+                return Ok(CreateNamespaceResponse::from_error(
+                    CatalogError::BadRequest("Missing CreateNamespaceRequest.".into()),
+                ))
+            }
+        };
+        let levels = create_namespace_request.namespace;
+        if levels.is_empty()
+            || levels
+                .iter()
+                .any(|level| level.is_empty() || level.contains('.'))
+        {
+            return Ok(CreateNamespaceResponse::from_error(
+                CatalogError::BadRequest(
+                    "Namespace levels have to be non-empty and must not contain \".\".".into(),
+                ),
+            ));
+        }
+        let name = levels.join(".");
+        let parent_name = levels[..levels.len() - 1].join(".");
+        let properties = match namespace_properties(create_namespace_request.properties) {
+            Ok(properties) => properties,
+            Err(message) => {
+                return Ok(CreateNamespaceResponse::from_error(
+                    CatalogError::BadRequest(message),
+                ))
+            }
+        };
+        let caller = Caller::from_context(context, &self.admins);
+        let strict_catalogs = self.strict_catalogs;
         self.db
-            .transaction::<_, CreateNamespaceResponse, DbErr>(|txn| {
+            .transaction::<_, CreateNamespaceResponse, CatalogError>(|txn| {
                 Box::pin(async move {
-                    let catalog = if prefix != "" {
-                        match Catalog::find()
-                            .filter(catalog::Column::Name.contains(&prefix))
-                            .one(txn)
-                            .await?
-                        {
-                            None => {
-                                let new_catalog = catalog::ActiveModel::from_json(json!({
-                                    "name": &prefix,
-                                }))?;
-
-                                let result = Catalog::insert(new_catalog.clone())
-                                    .on_conflict(
-                                        // on conflict update
-                                        OnConflict::column(catalog::Column::Name)
-                                            .do_nothing()
-                                            .to_owned(),
-                                    )
-                                    .exec(txn)
-                                    .await?;
-
-                                Catalog::find_by_id(result.last_insert_id).one(txn).await?
-                            }
-                            x => x,
-                        }
+                    let catalog = if strict_catalogs {
+                        resolver::catalog(txn, &prefix).await?
+                    } else {
+                        resolver::catalog_or_insert(txn, &prefix).await?
+                    };
+
+                    // Nested namespaces can only be created below an existing parent
+                    let parent = if !parent_name.is_empty() {
+                        Some(resolver::namespace(txn, catalog.as_ref(), &parent_name).await?)
                     } else {
                         None
                     };
 
+                    let grants = caller
+                        .grants(txn, catalog.as_ref().map(|catalog| catalog.id))
+                        .await?;
+                    match &parent {
+                        Some(parent) => {
+                            grants.require(Privilege::NamespaceCreate, Target::Namespace(parent))?
+                        }
+                        None => grants.require(Privilege::NamespaceCreate, Target::Catalog)?,
+                    }
+
+                    if resolver::find_namespace(txn, catalog.as_ref(), &name)
+                        .await?
+                        .is_some()
+                    {
+                        return Err(CatalogError::AlreadyExists(format!(
+                            "The namespace {} already exists.",
+                            name
+                        )));
+                    }
+
                     let new_namespace = namespace::ActiveModel::from_json(json!({
                         "name": &name,
-                        "catalog_id": catalog.as_ref().map(|catalog| catalog.id)
+                        "catalog_id": catalog.as_ref().map(|catalog| catalog.id),
+                        "parent_id": parent.as_ref().map(|parent| parent.id)
                     }))?;
 
-                    let namespace = match catalog {
-                        None => {
-                            Namespace::find()
-                                .filter(namespace::Column::Name.contains(&name))
-                                .one(txn)
-                                .await?
-                        }
-                        Some(catalog) => {
-                            catalog
-                                .find_related(Namespace)
-                                .filter(namespace::Column::Name.contains(&name))
-                                .one(txn)
-                                .await?
-                        }
-                    };
-
-                    match namespace {
-                        None => {
-                            Namespace::insert(new_namespace)
-                            .on_conflict(
-                                // on conflict update
-                                OnConflict::column(namespace::Column::Name)
-                                    .do_nothing()
-                                    .to_owned(),
-                            )
+                    // A concurrent request may have created the namespace meanwhile
+                    let result =
+                        Namespace::insert(new_namespace)
+                            .exec(txn)
+                            .await
+                            .map_err(|err| {
+                                error::unique_violation(err, || {
+                                    CatalogError::AlreadyExists(format!(
+                                        "The namespace {} already exists.",
+                                        name
+                                    ))
+                                })
+                            })?;
+
+                    if !properties.is_empty() {
+                        let new_properties = properties
+                            .iter()
+                            .map(|(key, value)| {
+                                namespace_property::ActiveModel::from_json(json!({
+                                    "key": key,
+                                    "value": value,
+                                    "namespace_id": result.last_insert_id
+                                }))
+                            })
+                            .collect::<Result<Vec<_>, _>>()?;
+
+                        NamespaceProperty::insert_many(new_properties)
                             .exec(txn)
                             .await?;
-                            
-                            Ok(
-                            CreateNamespaceResponse::RepresentsASuccessfulCallToCreateANamespace(
-                                models::CreateNamespace200Response {
-                                    namespace: vec![name],
-                                    properties: None,
-                                },
-                            ),
-                        )},
-                        Some(_) => {
-                            Ok(CreateNamespaceResponse::Conflict(models::ErrorModel::new(
-                                "The namespace already exists".into(),
-                                "Conflict".into(),
-                                500,
-                            )))
-                        }
                     }
 
-                    
+                    Ok(
+                        CreateNamespaceResponse::RepresentsASuccessfulCallToCreateANamespace(
+                            models::CreateNamespace200Response {
+                                namespace: levels,
+                                properties: Some(properties),
+                            },
+                        ),
+                    )
                 })
             })
             .await
-            .map_err(|err| ApiError(err.to_string()))
+            .or_else(|err| Ok(CreateNamespaceResponse::from_error(err.into())))
     }
 
     /// Create a table in the given namespace
@@ -147,114 +239,143 @@ where
         prefix: String,
         namespace: String,
         create_table_request: Option<models::CreateTableRequest>,
-        _context: &C,
+        context: &C,
     ) -> Result<CreateTableResponse, ApiError> {
-        let create_table_request =
-            create_table_request.ok_or(ApiError("Missing CreateNamespaceRequest.".into()))?;
-        let name = create_table_request.name;
-        let metadata_location = create_table_request
-            .location
-            .ok_or(ApiError("Missing metadata_location.".into()))?;
+        let create_table_request = match create_table_request {
+            Some(create_table_request) => create_table_request,
+            None => {
+                return Ok(CreateTableResponse::from_error(CatalogError::BadRequest(
+                    "Missing CreateTableRequest.".into(),
+                )))
+            }
+        };
+        let name = create_table_request.name.clone();
+        let stage_create = create_table_request.stage_create.unwrap_or(false);
+        let warehouse = self.warehouse.clone();
+        let strict_catalogs = self.strict_catalogs;
+        let committer = Committer::from_context(context);
+        let caller = Caller::from_context(context, &self.admins);
         self.db
-            .transaction::<_, CreateTableResponse, DbErr>(|txn| {
+            .transaction::<_, CreateTableResponse, CatalogError>(|txn| {
                 Box::pin(async move {
-                    let catalog = if prefix != "" {
-                        match Catalog::find()
-                            .filter(catalog::Column::Name.contains(&prefix))
-                            .one(txn)
-                            .await?
-                        {
-                            None => {
-                                let new_catalog = catalog::ActiveModel::from_json(json!({
-                                    "name": &prefix,
-                                }))?;
-
-                                let result = Catalog::insert(new_catalog.clone())
-                                    .on_conflict(
-                                        // on conflict update
-                                        OnConflict::column(catalog::Column::Name)
-                                            .do_nothing()
-                                            .to_owned(),
-                                    )
-                                    .exec(txn)
-                                    .await?;
-
-                                Catalog::find_by_id(result.last_insert_id).one(txn).await?
-                            }
-                            x => x,
-                        }
+                    let catalog = if strict_catalogs {
+                        resolver::catalog(txn, &prefix).await?
                     } else {
-                        None
+                        resolver::catalog_or_insert(txn, &prefix).await?
                     };
 
-                    let namespace = match catalog {
-                        None => {
-                            Namespace::find()
-                                .filter(namespace::Column::Name.contains(&namespace))
-                                .one(txn)
-                                .await?
-                        }
-                        Some(catalog) => {
-                            catalog
-                                .find_related(Namespace)
-                                .filter(namespace::Column::Name.contains(&namespace))
-                                .one(txn)
-                                .await?
+                    let namespace = resolver::namespace(
+                        txn,
+                        catalog.as_ref(),
+                        &resolver::namespace_name(&namespace),
+                    )
+                    .await?;
+
+                    caller
+                        .grants(txn, namespace.catalog_id)
+                        .await?
+                        .require(Privilege::TableCreate, Target::Namespace(&namespace))?;
+
+                    // Tables are placed below the storage root of their catalog or
+                    // else below the warehouse
+                    let root = metadata::storage_root(catalog.as_ref(), warehouse.as_deref());
+                    let location = match (&create_table_request.location, root) {
+                        (Some(location), root) => metadata::confine_location(location, root)
+                            .map_err(CatalogError::BadRequest)?,
+                        (None, Some(root)) => format!(
+                            "{}/{}/{}",
+                            root.trim_end_matches('/'),
+                            resolver::namespace_levels(&namespace.name).join("/"),
+                            name
+                        ),
+                        (None, None) => {
+                            return Err(CatalogError::BadRequest(
+                                "The table has no location and no warehouse is configured.".into(),
+                            ))
                         }
                     };
+                    let metadata = metadata::new_table_metadata(&create_table_request, &location)
+                        .map_err(CatalogError::BadRequest)?;
+                    let metadata_location = metadata::metadata_file_location(&location, 0);
+                    let file_io = io::file_io(&location)
+                        .map_err(|err| CatalogError::BadRequest(err.to_string()))?;
+
+                    if resolver::find_table(txn, &namespace, &name)
+                        .await?
+                        .is_some()
+                    {
+                        return Err(CatalogError::AlreadyExists(format!(
+                            "The table {}.{} already exists.",
+                            namespace.name, name
+                        )));
+                    }
 
-                    // Check if namespace exists
-                    match namespace {
-                        None => Ok(CreateTableResponse::NotFound(models::ErrorModel::new(
-                            "The namespace specified does not exist".into(),
-                            "NotFound".into(),
-                            500,
-                        ))),
-                        Some(namespace) => {
-                            let table = namespace
-                            .find_related(IcebergTable)
-                            .filter(iceberg_table::Column::Name.contains(&name))
-                            .one(txn)
-                            .await?;
-                            match table {
-                                Some(_) => Ok(CreateTableResponse::Conflict(models::ErrorModel::new(
-                                    "The table already exists.".into(),
-                                    "Conflict".into(),
-                                    500,
-                                ))),
-                                None => {
-                                    let new_table = iceberg_table::ActiveModel::from_json(json!({
-                                        "name": &name,
-                                        "namespace_id": namespace.id,
-                                        "metadata_location": metadata_location,
-                                        "previous_metadata_location": None::<String>
-                                    }))?;
-        
-                                    IcebergTable::insert(new_table)
-                                        .on_conflict(
-                                            // on conflict update
-                                            OnConflict::column(iceberg_table::Column::Name)
-                                                .do_nothing()
-                                                .to_owned(),
-                                        )
-                                        .exec(txn)
-                                        .await?;
-        
-                                    Ok(CreateTableResponse::TableMetadataResultAfterCreatingATable(
-                                        models::LoadTableResult {
-                                            metadata_location: Some(metadata_location.to_string()),
-                                            config: None,
-                                            metadata: TableMetadata::new(2, "".into()),
-                                        },
-                                    ))
-                                }
-                            }
-                        }
+                    // Staged tables are only created by a later commit
+                    if stage_create {
+                        staged::stage(txn, &namespace, &name, &metadata).await?;
+
+                        return Ok(CreateTableResponse::TableMetadataResultAfterCreatingATable(
+                            models::LoadTableResult {
+                                metadata_location: None,
+                                config: None,
+                                metadata,
+                            },
+                        ));
+                    }
+
+                    if let Err(err) =
+                        metadata::write(file_io.as_ref(), &metadata_location, &metadata).await
+                    {
+                        return Err(CatalogError::Internal(format!(
+                            "Failed to write the metadata file: {}",
+                            err
+                        )));
                     }
+
+                    let new_table = iceberg_table::ActiveModel::from_json(json!({
+                        "name": &name,
+                        "namespace_id": namespace.id,
+                        "metadata_location": metadata_location,
+                        "previous_metadata_location": None::<String>
+                    }))?;
+
+                    // A concurrent request may have created the table meanwhile
+                    let result =
+                        IcebergTable::insert(new_table)
+                            .exec(txn)
+                            .await
+                            .map_err(|err| {
+                                error::unique_violation(err, || {
+                                    CatalogError::AlreadyExists(format!(
+                                        "The table {}.{} already exists.",
+                                        namespace.name, name
+                                    ))
+                                })
+                            })?;
+
+                    metadata::store(txn, result.last_insert_id, &metadata_location, &metadata)
+                        .await?;
+                    history::record(
+                        txn,
+                        result.last_insert_id,
+                        &metadata_location,
+                        None,
+                        &committer,
+                    )
+                    .await?;
+                    staged::remove(txn, &namespace, &name).await?;
+
+                    Ok(CreateTableResponse::TableMetadataResultAfterCreatingATable(
+                        models::LoadTableResult {
+                            metadata_location: Some(metadata_location.to_string()),
+                            config: None,
+                            metadata,
+                        },
+                    ))
                 })
             })
             .await
-            .map_err(|err| ApiError(err.to_string()))
+            .or_else(|err| Ok(CreateTableResponse::from_error(err.into())))
     }
 
     /// Drop a namespace from the catalog. Namespace must be empty.
@@ -262,52 +383,73 @@ where
         &self,
         prefix: String,
         namespace: String,
-        _context: &C,
+        cascade: Option<bool>,
+        context: &C,
     ) -> Result<DropNamespaceResponse, ApiError> {
+        let caller = Caller::from_context(context, &self.admins);
         self.db
-            .transaction::<_, DropNamespaceResponse, DbErr>(|txn| {
+            .transaction::<_, DropNamespaceResponse, CatalogError>(|txn| {
                 Box::pin(async move {
-                    let catalog = if prefix != "" {
-                        Catalog::find()
-                            .filter(catalog::Column::Name.contains(&prefix))
-                            .one(txn)
-                            .await?
-                    } else {
-                        None
-                    };
-
-                    let namespace = match catalog {
-                        None => {
-                            Namespace::find()
-                                .filter(namespace::Column::Name.contains(&namespace))
-                                .one(txn)
-                                .await?
+                    let namespace = resolver::resolve_namespace(txn, &prefix, &namespace).await?;
+
+                    let grants = caller.grants(txn, namespace.catalog_id).await?;
+                    grants.require(Privilege::NamespaceDrop, Target::Namespace(&namespace))?;
+
+                    let mut namespaces = vec![namespace];
+                    if cascade.unwrap_or(false) {
+                        // Dropping the tables inside takes the privilege to drop them
+                        grants.require(Privilege::TableDrop, Target::Namespace(&namespaces[0]))?;
+
+                        // The namespace and all namespaces below it, parents first
+                        let mut i = 0;
+                        while i < namespaces.len() {
+                            let children = Namespace::find()
+                                .filter(namespace::Column::ParentId.eq(namespaces[i].id))
+                                .all(txn)
+                                .await?;
+                            namespaces.extend(children);
+                            i += 1;
                         }
-                        Some(catalog) => {
-                            catalog
-                                .find_related(Namespace)
-                                .filter(namespace::Column::Name.contains(&namespace))
-                                .one(txn)
-                                .await?
+                    } else {
+                        let namespace = &namespaces[0];
+                        let tables = namespace.find_related(IcebergTable).count(txn).await?;
+                        let children = Namespace::find()
+                            .filter(namespace::Column::ParentId.eq(namespace.id))
+                            .count(txn)
+                            .await?;
+                        if tables > 0 || children > 0 {
+                            return Err(CatalogError::NamespaceNotEmpty(format!(
+                                "The namespace {} is not empty.",
+                                namespace.name
+                            )));
                         }
-                    };
-
-                    match namespace {
-                        None => Ok(DropNamespaceResponse::NotFound(models::ErrorModel::new(
-                            "Namespace to delete does not exist.".into(),
-                            "NotFound".into(),
-                            500,
-                        ))),
-                        Some(namespace) => {
-                            namespace.delete(txn).await?;
+                    }
 
-                            Ok(DropNamespaceResponse::Success)
+                    for namespace in namespaces.into_iter().rev() {
+                        for table in namespace.find_related(IcebergTable).all(txn).await? {
+                            delete_table(txn, &namespace, table).await?;
                         }
+                        NamespaceProperty::delete_many()
+                            .filter(namespace_property::Column::NamespaceId.eq(namespace.id))
+                            .exec(txn)
+                            .await?;
+                        StagedTable::delete_many()
+                            .filter(staged_table::Column::NamespaceId.eq(namespace.id))
+                            .exec(txn)
+                            .await?;
+                        PrivilegeGrant::delete_many()
+                            .filter(privilege_grant::Column::NamespaceId.eq(namespace.id))
+                            .exec(txn)
+                            .await?;
+
+                        namespace.delete(txn).await?;
                     }
+
+                    Ok(DropNamespaceResponse::Success)
                 })
             })
             .await
-            .map_err(|err| ApiError(err.to_string()))
+            .or_else(|err| Ok(DropNamespaceResponse::from_error(err.into())))
     }
 
     /// Drop a table from the catalog
@@ -316,106 +458,202 @@ where
         prefix: String,
         namespace: String,
         table: String,
-        _purge_requested: Option<bool>,
-        _context: &C,
+        purge_requested: Option<bool>,
+        context: &C,
     ) -> Result<DropTableResponse, ApiError> {
+        let warehouse = self.warehouse.clone();
+        let caller = Caller::from_context(context, &self.admins);
         self.db
-            .transaction::<_, DropTableResponse, DbErr>(|txn| {
+            .transaction::<_, Option<i32>, CatalogError>(|txn| {
                 Box::pin(async move {
-                    let catalog = if prefix != "" {
-                        Catalog::find()
-                            .filter(catalog::Column::Name.contains(&prefix))
-                            .one(txn)
-                            .await?
-                    } else {
-                        None
-                    };
-
-                    let namespace = match catalog {
-                        None => {
-                            Namespace::find()
-                                .filter(namespace::Column::Name.contains(&namespace))
-                                .one(txn)
-                                .await?
-                        }
-                        Some(catalog) => {
-                            catalog
-                                .find_related(Namespace)
-                                .filter(namespace::Column::Name.contains(&namespace))
-                                .one(txn)
-                                .await?
+                    let catalog = resolver::catalog(txn, &prefix).await?;
+                    let namespace = resolver::namespace(
+                        txn,
+                        catalog.as_ref(),
+                        &resolver::namespace_name(&namespace),
+                    )
+                    .await?;
+                    let table = resolver::table(txn, &namespace, &table).await?;
+
+                    caller
+                        .grants(txn, namespace.catalog_id)
+                        .await?
+                        .require(Privilege::TableDrop, Target::Table(&namespace, &table))?;
+
+                    // The files are deleted once the table is dropped
+                    let purge = match purge_requested {
+                        Some(true) => {
+                            let table_name = format!("{}.{}", namespace.name, table.name);
+                            let metadata = metadata::load(txn, &table).await?;
+                            let root =
+                                metadata::storage_root(catalog.as_ref(), warehouse.as_deref());
+                            let live = purge::live_locations(txn, table.id).await?;
+                            let locations = purge::locations(&table_name, &metadata, root, &live);
+                            Some(purge::schedule(txn, &table_name, &locations).await?)
                         }
+                        _ => None,
                     };
 
-                    let table = match namespace {
-                        None => {
-                            IcebergTable::find()
-                                .filter(iceberg_table::Column::Name.contains(&table))
-                                .one(txn)
-                                .await?
-                        }
-                        Some(namespace) => {
-                            namespace
-                                .find_related(IcebergTable)
-                                .filter(iceberg_table::Column::Name.contains(&table))
-                                .one(txn)
-                                .await?
-                        }
-                    };
-                    match table {
-                        None => Ok(DropTableResponse::NotFound(models::ErrorModel::new(
-                            "Namespace to delete does not exist.".into(),
-                            "NotFound".into(),
-                            500,
-                        ))),
-                        Some(table) => {
-                            table.delete(txn).await?;
+                    delete_table(txn, &namespace, table).await?;
 
-                            Ok(DropTableResponse::Success)
-                        }
-                    }
+                    Ok(purge)
                 })
             })
             .await
-            .map_err(|err| ApiError(err.to_string()))
+            .map(|purge| {
+                if let Some(purge) = purge {
+                    purge::spawn(self.db.clone(), purge);
+                }
+                DropTableResponse::Success
+            })
+            .or_else(|err| Ok(DropTableResponse::from_error(err.into())))
     }
 
     /// List namespaces, optionally providing a parent namespace to list underneath
     async fn list_namespaces(
         &self,
         prefix: String,
-        _parent: Option<String>,
-        _context: &C,
+        parent: Option<String>,
+        context: &C,
     ) -> Result<ListNamespacesResponse, ApiError> {
+        let caller = Caller::from_context(context, &self.admins);
         self.db
-            .transaction::<_, ListNamespacesResponse, DbErr>(|txn| {
+            .transaction::<_, ListNamespacesResponse, CatalogError>(|txn| {
                 Box::pin(async move {
-                    let catalog = if prefix != "" {
-                        Catalog::find()
-                            .filter(catalog::Column::Name.contains(&prefix))
-                            .one(txn)
-                            .await?
-                    } else {
-                        None
+                    let catalog = resolver::catalog(txn, &prefix).await?;
+
+                    let parent = match parent.filter(|parent| !parent.is_empty()) {
+                        None => None,
+                        Some(parent) => Some(
+                            resolver::namespace(
+                                txn,
+                                catalog.as_ref(),
+                                &resolver::namespace_name(&parent),
+                            )
+                            .await?,
+                        ),
                     };
 
-                    let namespaces = match catalog {
-                        None => Namespace::find().all(txn).await?,
-                        Some(catalog) => catalog.find_related(Namespace).all(txn).await?,
+                    let grants = caller
+                        .grants(txn, catalog.as_ref().map(|catalog| catalog.id))
+                        .await?;
+                    if let Some(parent) = &parent {
+                        grants.require_visible(Target::Namespace(parent))?;
+                    }
+
+                    // Only the direct children of the parent are listed
+                    let children = match &parent {
+                        None => namespace::Column::ParentId.is_null(),
+                        Some(parent) => namespace::Column::ParentId.eq(parent.id),
                     };
+                    let in_catalog = match &catalog {
+                        None => namespace::Column::CatalogId.is_null(),
+                        Some(catalog) => namespace::Column::CatalogId.eq(catalog.id),
+                    };
+
+                    let namespaces = Namespace::find()
+                        .filter(in_catalog)
+                        .filter(children)
+                        .all(txn)
+                        .await?;
 
                     Ok(ListNamespacesResponse::AListOfNamespaces(
                         models::ListNamespaces200Response {
-                            namespaces: Some(namespaces
-                                .into_iter()
-                                .map(|x| x.name.split(".").map(|x|x.to_owned()).collect())
-                                .collect()),
+                            namespaces: Some(
+                                namespaces
+                                    .into_iter()
+                                    .filter(|x| grants.sees(Target::Namespace(x)))
+                                    .map(|x| resolver::namespace_levels(&x.name))
+                                    .collect(),
+                            ),
                         },
                     ))
                 })
             })
             .await
-            .map_err(|err| ApiError(err.to_string()))
+            .or_else(|err| Ok(ListNamespacesResponse::from_error(err.into())))
+    }
+
+    /// List the commits of a table
+    async fn list_table_commits(
+        &self,
+        prefix: String,
+        namespace: String,
+        table: String,
+        page_token: Option<String>,
+        page_size: Option<i32>,
+        context: &C,
+    ) -> Result<ListTableCommitsResponse, ApiError> {
+        let caller = Caller::from_context(context, &self.admins);
+        self.db
+            .transaction::<_, ListTableCommitsResponse, CatalogError>(|txn| {
+                Box::pin(async move {
+                    let (namespace, table) =
+                        resolver::resolve_table(txn, &prefix, &namespace, &table).await?;
+
+                    caller
+                        .grants(txn, namespace.catalog_id)
+                        .await?
+                        .require(Privilege::TableRead, Target::Table(&namespace, &table))?;
+
+                    let page = history::page(txn, table.id, page_token.as_deref(), page_size)
+                        .await?
+                        .map_err(CatalogError::BadRequest)?;
+
+                    Ok(ListTableCommitsResponse::APageOfTheCommitHistoryOfTheTable(
+                        page,
+                    ))
+                })
+            })
+            .await
+            .or_else(|err| Ok(ListTableCommitsResponse::from_error(err.into())))
+    }
+
+    /// Aggregate the metrics reports of the tables of a namespace
+    async fn list_table_metrics(
+        &self,
+        prefix: String,
+        namespace: String,
+        table: Option<String>,
+        from: Option<i64>,
+        to: Option<i64>,
+        context: &C,
+    ) -> Result<ListTableMetricsResponse, ApiError> {
+        let caller = Caller::from_context(context, &self.admins);
+        self.db
+            .transaction::<_, ListTableMetricsResponse, CatalogError>(|txn| {
+                Box::pin(async move {
+                    let namespace = resolver::resolve_namespace(txn, &prefix, &namespace).await?;
+
+                    let grants = caller.grants(txn, namespace.catalog_id).await?;
+                    grants.require_visible(Target::Namespace(&namespace))?;
+                    let tables = match table {
+                        Some(table) => {
+                            let table = resolver::table(txn, &namespace, &table).await?;
+                            grants
+                                .require(Privilege::TableRead, Target::Table(&namespace, &table))?;
+                            vec![table]
+                        }
+                        None => namespace
+                            .find_related(IcebergTable)
+                            .all(txn)
+                            .await?
+                            .into_iter()
+                            .filter(|table| {
+                                grants
+                                    .allows(Privilege::TableRead, Target::Table(&namespace, table))
+                            })
+                            .collect(),
+                    };
+                    let tables = metrics::aggregate(txn, &namespace, tables, from, to).await?;
+
+                    Ok(ListTableMetricsResponse::TheAggregatedMetricsOfTheTables(
+                        models::ListTableMetrics200Response { tables },
+                    ))
+                })
+            })
+            .await
+            .or_else(|err| Ok(ListTableMetricsResponse::from_error(err.into())))
     }
 
     /// List all table identifiers underneath a given namespace
@@ -423,55 +661,28 @@ where
         &self,
         prefix: String,
         namespace: String,
-        _context: &C,
+        context: &C,
     ) -> Result<ListTablesResponse, ApiError> {
+        let caller = Caller::from_context(context, &self.admins);
         self.db
-            .transaction::<_, ListTablesResponse, DbErr>(|txn| {
+            .transaction::<_, ListTablesResponse, CatalogError>(|txn| {
                 Box::pin(async move {
-                    let catalog = if prefix != "" {
-                        Catalog::find()
-                            .filter(catalog::Column::Name.contains(&prefix))
-                            .one(txn)
-                            .await?
-                    } else {
-                        None
-                    };
+                    let namespace = resolver::resolve_namespace(txn, &prefix, &namespace).await?;
 
-                    let namespace = match catalog {
-                        None => {
-                            Namespace::find()
-                                .filter(namespace::Column::Name.contains(&namespace))
-                                .one(txn)
-                                .await?
-                        }
-                        Some(catalog) => {
-                            catalog
-                                .find_related(Namespace)
-                                .filter(namespace::Column::Name.contains(&namespace))
-                                .one(txn)
-                                .await?
-                        }
-                    };
+                    let grants = caller.grants(txn, namespace.catalog_id).await?;
+                    grants.require_visible(Target::Namespace(&namespace))?;
+
+                    let tables = namespace.find_related(IcebergTable).all(txn).await?;
 
-                    let tables = match namespace.as_ref() {
-                        None => IcebergTable::find().all(txn).await?,
-                        Some(namespace) => namespace.find_related(IcebergTable).all(txn).await?,
-                    };
                     Ok(ListTablesResponse::AListOfTableIdentifiers(
                         models::ListTables200Response {
                             identifiers: Some(
                                 tables
                                     .into_iter()
+                                    .filter(|x| grants.sees(Target::Table(&namespace, x)))
                                     .map(|x| models::TableIdentifier {
                                         name: x.name,
-                                        namespace: match namespace.as_ref() {
-                                            Some(namespace) => namespace
-                                                .name
-                                                .split('.')
-                                                .map(|s| s.to_owned())
-                                                .collect(),
-                                            None => vec![],
-                                        },
+                                        namespace: resolver::namespace_levels(&namespace.name),
                                     })
                                     .collect(),
                             ),
@@ -480,7 +691,7 @@ where
                 })
             })
             .await
-            .map_err(|err| ApiError(err.to_string()))
+            .or_else(|err| Ok(ListTablesResponse::from_error(err.into())))
     }
 
     /// Load the metadata properties for a namespace
@@ -490,14 +701,35 @@ where
         namespace: String,
         context: &C,
     ) -> Result<LoadNamespaceMetadataResponse, ApiError> {
-        let context = context.clone();
-        info!(
-            "load_namespace_metadata(\"{}\", \"{}\") - X-Span-ID: {:?}",
-            prefix,
-            namespace,
-            context.get().0.clone()
-        );
-        Err(ApiError("Generic failure".into()))
+        let caller = Caller::from_context(context, &self.admins);
+        self.db
+            .transaction::<_, LoadNamespaceMetadataResponse, CatalogError>(|txn| {
+                Box::pin(async move {
+                    let namespace = resolver::resolve_namespace(txn, &prefix, &namespace).await?;
+
+                    caller
+                        .grants(txn, namespace.catalog_id)
+                        .await?
+                        .require(Privilege::CatalogRead, Target::Namespace(&namespace))?;
+
+                    let properties = namespace
+                        .find_related(NamespaceProperty)
+                        .all(txn)
+                        .await?
+                        .into_iter()
+                        .map(|property| (property.key, property.value))
+                        .collect::<HashMap<_, _>>();
+
+                    Ok(LoadNamespaceMetadataResponse::ReturnsANamespace(
+                        models::LoadNamespaceMetadata200Response {
+                            namespace: resolver::namespace_levels(&namespace.name),
+                            properties: Some(json!(properties)),
+                        },
+                    ))
+                })
+            })
+            .await
+            .or_else(|err| Ok(LoadNamespaceMetadataResponse::from_error(err.into())))
     }
 
     /// Load a table from the catalog
@@ -506,69 +738,33 @@ where
         prefix: String,
         namespace: String,
         table: String,
-        _context: &C,
+        context: &C,
     ) -> Result<LoadTableResponse, ApiError> {
+        let caller = Caller::from_context(context, &self.admins);
         self.db
-            .transaction::<_, LoadTableResponse, DbErr>(|txn| {
+            .transaction::<_, LoadTableResponse, CatalogError>(|txn| {
                 Box::pin(async move {
-                    let catalog = if prefix != "" {
-                        Catalog::find()
-                            .filter(catalog::Column::Name.contains(&prefix))
-                            .one(txn)
-                            .await?
-                    } else {
-                        None
-                    };
+                    let (namespace, table) =
+                        resolver::resolve_table(txn, &prefix, &namespace, &table).await?;
 
-                    let namespace = match catalog {
-                        None => {
-                            Namespace::find()
-                                .filter(namespace::Column::Name.contains(&namespace))
-                                .one(txn)
-                                .await?
-                        }
-                        Some(catalog) => {
-                            catalog
-                                .find_related(Namespace)
-                                .filter(namespace::Column::Name.contains(&namespace))
-                                .one(txn)
-                                .await?
-                        }
-                    };
+                    caller
+                        .grants(txn, namespace.catalog_id)
+                        .await?
+                        .require(Privilege::TableRead, Target::Table(&namespace, &table))?;
 
-                    let table = match namespace {
-                        None => {
-                            IcebergTable::find()
-                                .filter(iceberg_table::Column::Name.contains(&table))
-                                .one(txn)
-                                .await?
-                        }
-                        Some(namespace) => {
-                            namespace
-                                .find_related(IcebergTable)
-                                .filter(iceberg_table::Column::Name.contains(&table))
-                                .one(txn)
-                                .await?
-                        }
-                    };
-                    match table {
-                        None => Ok(LoadTableResponse::NotFound(models::ErrorModel::new(
-                            "Namespace to delete does not exist.".into(),
-                            "NotFound".into(),
-                            500,
-                        ))),
-                        Some(table) => Ok(LoadTableResponse::TableMetadataResultWhenLoadingATable(
-                            models::LoadTableResult {
-                                metadata_location: Some(table.metadata_location.to_string()),
-                                config: None,
-                                metadata: TableMetadata::new(2, "".into()),
-                            },
-                        )),
-                    }
+                    let metadata = metadata::load(txn, &table).await?;
+
+                    Ok(LoadTableResponse::TableMetadataResultWhenLoadingATable(
+                        models::LoadTableResult {
+                            metadata_location: Some(table.metadata_location.to_string()),
+                            config: None,
+                            metadata,
+                        },
+                    ))
                 })
             })
             .await
-            .map_err(|err| ApiError(err.to_string()))
+            .or_else(|err| Ok(LoadTableResponse::from_error(err.into())))
     }
 
     /// Rename a table from its current name to a new name
@@ -576,107 +772,64 @@ where
         &self,
         prefix: String,
         rename_table_request: models::RenameTableRequest,
-        _context: &C,
+        context: &C,
     ) -> Result<RenameTableResponse, ApiError> {
+        let caller = Caller::from_context(context, &self.admins);
         self.db
-            .transaction::<_, RenameTableResponse, DbErr>(|txn| {
+            .transaction::<_, RenameTableResponse, CatalogError>(|txn| {
                 Box::pin(async move {
-                    let old_namespace_name = iter_tools::intersperse(
-                        rename_table_request.source.namespace.into_iter(),
-                        ".".into(),
-                    )
-                    .collect::<String>();
+                    let old_namespace_name = rename_table_request.source.namespace.join(".");
                     let old_name = rename_table_request.source.name;
-                    let new_namespace_name = iter_tools::intersperse(
-                        rename_table_request.destination.namespace.into_iter(),
-                        ".".into(),
-                    )
-                    .collect::<String>();
+                    let new_namespace_name = rename_table_request.destination.namespace.join(".");
                     let new_name = rename_table_request.destination.name;
-                    let catalog = if prefix != "" {
-                        Catalog::find()
-                            .filter(catalog::Column::Name.contains(&prefix))
-                            .one(txn)
-                            .await?
-                    } else {
-                        None
-                    };
 
-                    let old_namespace = match &catalog {
-                        None => {
-                            Namespace::find()
-                                .filter(namespace::Column::Name.contains(&old_namespace_name))
-                                .one(txn)
-                                .await?
-                        }
-                        Some(catalog) => {
-                            catalog
-                                .find_related(Namespace)
-                                .filter(namespace::Column::Name.contains(&old_namespace_name))
-                                .one(txn)
-                                .await?
-                        }
-                    };
+                    let catalog = resolver::catalog(txn, &prefix).await?;
+
+                    let old_namespace =
+                        resolver::namespace(txn, catalog.as_ref(), &old_namespace_name).await?;
+                    let table = resolver::table(txn, &old_namespace, &old_name).await?;
+
+                    let grants = caller
+                        .grants(txn, catalog.as_ref().map(|catalog| catalog.id))
+                        .await?;
+                    grants.require(Privilege::TableDrop, Target::Table(&old_namespace, &table))?;
 
                     let new_namespace = if old_namespace_name == new_namespace_name {
-                        old_namespace.clone()
+                        old_namespace
                     } else {
-                        match &catalog {
-                            None => {
-                                Namespace::find()
-                                    .filter(namespace::Column::Name.contains(&new_namespace_name))
-                                    .one(txn)
-                                    .await?
-                            }
-                            Some(catalog) => {
-                                catalog
-                                    .find_related(Namespace)
-                                    .filter(namespace::Column::Name.contains(&new_namespace_name))
-                                    .one(txn)
-                                    .await?
-                            }
-                        }
+                        resolver::namespace(txn, catalog.as_ref(), &new_namespace_name).await?
                     };
-
-                    let table = match old_namespace {
-                        None => {
-                            IcebergTable::find()
-                                .filter(iceberg_table::Column::Name.contains(&old_name))
-                                .one(txn)
-                                .await?
-                        }
-                        Some(old_namespace) => {
-                            old_namespace
-                                .find_related(IcebergTable)
-                                .filter(iceberg_table::Column::Name.contains(&old_name))
-                                .one(txn)
-                                .await?
-                        }
-                    };
-                    match (table, new_namespace) {
-                        (None, _) => Ok(RenameTableResponse::NotFound(models::ErrorModel::new(
-                            "Table to rename does not exist.".into(),
-                            "Not Found - NoSuchTableException".into(),
-                            500,
-                        ))),
-                        (_, None) => Ok(RenameTableResponse::NotFound(models::ErrorModel::new(
-                            "The target namespace of the new table identifier does not exist."
-                                .into(),
-                            "NoSuchNamespaceException".into(),
-                            500,
-                        ))),
-                        (Some(table), Some(namespace)) => {
-                            let mut new_table: iceberg_table::ActiveModel = table.into();
-                            new_table.set(iceberg_table::Column::Name, new_name.into());
-                            new_table.set(iceberg_table::Column::NamespaceId, namespace.id.into());
-                            new_table.update(txn).await?;
-                            Ok(RenameTableResponse::OK)
-                        }
+                    grants.require(Privilege::TableCreate, Target::Namespace(&new_namespace))?;
+
+                    if resolver::find_table(txn, &new_namespace, &new_name)
+                        .await?
+                        .is_some()
+                    {
+                        return Err(CatalogError::AlreadyExists(format!(
+                            "The table {}.{} already exists.",
+                            new_namespace.name, new_name
+                        )));
                     }
+
+                    // Grants on the table move with it
+                    PrivilegeGrant::update_many()
+                        .col_expr(
+                            privilege_grant::Column::NamespaceId,
+                            Expr::value(new_namespace.id),
+                        )
+                        .filter(privilege_grant::Column::TableId.eq(table.id))
+                        .exec(txn)
+                        .await?;
+
+                    let mut new_table: iceberg_table::ActiveModel = table.into();
+                    new_table.set(iceberg_table::Column::Name, new_name.into());
+                    new_table.set(iceberg_table::Column::NamespaceId, new_namespace.id.into());
+                    new_table.update(txn).await?;
+                    Ok(RenameTableResponse::OK)
                 })
             })
             .await
-            .map_err(|err| ApiError(err.to_string()))
+            .or_else(|err| Ok(RenameTableResponse::from_error(err.into())))
     }
 
     /// Send a metrics report to this endpoint to be processed by the backend
@@ -688,77 +841,69 @@ where
         report_metrics_request: models::ReportMetricsRequest,
         context: &C,
     ) -> Result<ReportMetricsResponse, ApiError> {
-        let context = context.clone();
-        info!(
-            "report_metrics(\"{}\", \"{}\", \"{}\", {:?}) - X-Span-ID: {:?}",
-            prefix,
-            namespace,
-            table,
-            report_metrics_request,
-            context.get().0.clone()
-        );
-        Err(ApiError("Generic failure".into()))
-    }
+        let caller = Caller::from_context(context, &self.admins);
+        let report = Report {
+            catalog: prefix.clone(),
+            namespace: resolver::namespace_levels(&resolver::namespace_name(&namespace)),
+            table: table.clone(),
+            received_at_ms: chrono::Utc::now().timestamp_millis(),
+            report: report_metrics_request.clone(),
+        };
 
-    /// Check if a table exists
-    async fn table_exists(
-        &self,
-        prefix: String,
-        namespace: String,
+        let response = self
+            .db
+            .transaction::<_, ReportMetricsResponse, CatalogError>(|txn| {
+                Box::pin(async move {
+                    let (namespace, table) =
+                        resolver::resolve_table(txn, &prefix, &namespace, &table).await?;
+
+                    caller
+                        .grants(txn, namespace.catalog_id)
+                        .await?
+                        .require(Privilege::TableRead, Target::Table(&namespace, &table))?;
+
+                    metrics::record(txn, table.id, report_metrics_request).await?;
+
+                    Ok(ReportMetricsResponse::Success)
+                })
+            })
+            .await;
+
+        match response {
+            Ok(response) => {
+                // The sinks only get reports of existing tables
+                self.reporters.report(report);
+                Ok(response)
+            }
+            Err(err) => Ok(ReportMetricsResponse::from_error(err.into())),
+        }
+    }
+
+    /// Check if a table exists
+    async fn table_exists(
+        &self,
+        prefix: String,
+        namespace: String,
         table: String,
-        _context: &C,
+        context: &C,
     ) -> Result<TableExistsResponse, ApiError> {
+        let caller = Caller::from_context(context, &self.admins);
         self.db
-            .transaction::<_, TableExistsResponse, DbErr>(|txn| {
+            .transaction::<_, TableExistsResponse, CatalogError>(|txn| {
                 Box::pin(async move {
-                    let catalog = if prefix != "" {
-                        Catalog::find()
-                            .filter(catalog::Column::Name.contains(&prefix))
-                            .one(txn)
-                            .await?
-                    } else {
-                        None
-                    };
+                    let (namespace, table) =
+                        resolver::resolve_table(txn, &prefix, &namespace, &table).await?;
 
-                    let namespace = match catalog {
-                        None => {
-                            Namespace::find()
-                                .filter(namespace::Column::Name.contains(&namespace))
-                                .one(txn)
-                                .await?
-                        }
-                        Some(catalog) => {
-                            catalog
-                                .find_related(Namespace)
-                                .filter(namespace::Column::Name.contains(&namespace))
-                                .one(txn)
-                                .await?
-                        }
-                    };
+                    caller
+                        .grants(txn, namespace.catalog_id)
+                        .await?
+                        .require(Privilege::TableRead, Target::Table(&namespace, &table))?;
 
-                    let table = match namespace {
-                        None => {
-                            IcebergTable::find()
-                                .filter(iceberg_table::Column::Name.contains(&table))
-                                .one(txn)
-                                .await?
-                        }
-                        Some(namespace) => {
-                            namespace
-                                .find_related(IcebergTable)
-                                .filter(iceberg_table::Column::Name.contains(&table))
-                                .one(txn)
-                                .await?
-                        }
-                    };
-                    match table {
-                        None => Ok(TableExistsResponse::NotFound),
-                        Some(_) => Ok(TableExistsResponse::OK),
-                    }
+                    Ok(TableExistsResponse::OK)
                 })
             })
             .await
-            .map_err(|err| ApiError(err.to_string()))
+            .or_else(|err| Ok(TableExistsResponse::from_error(err.into())))
     }
 
     /// Set or remove properties on a namespace
@@ -769,15 +914,98 @@ where
         update_namespace_properties_request: Option<models::UpdateNamespacePropertiesRequest>,
         context: &C,
     ) -> Result<UpdatePropertiesResponse, ApiError> {
-        let context = context.clone();
-        info!(
-            "update_properties(\"{}\", \"{}\", {:?}) - X-Span-ID: {:?}",
-            prefix,
-            namespace,
-            update_namespace_properties_request,
-            context.get().0.clone()
-        );
-        Err(ApiError("Generic failure".into()))
+        let update_namespace_properties_request = update_namespace_properties_request
+            .unwrap_or_else(models::UpdateNamespacePropertiesRequest::new);
+        let updates = update_namespace_properties_request
+            .updates
+            .unwrap_or_default();
+        let removals = update_namespace_properties_request
+            .removals
+            .unwrap_or_default();
+
+        let conflicting = removals
+            .iter()
+            .filter(|key| updates.contains_key(*key))
+            .cloned()
+            .collect::<Vec<_>>();
+        if !conflicting.is_empty() {
+            return Ok(UpdatePropertiesResponse::from_error(
+                CatalogError::UnprocessableEntity(format!(
+                    "Properties can not be updated and removed at the same time: {}",
+                    conflicting.join(", ")
+                )),
+            ));
+        }
+
+        let caller = Caller::from_context(context, &self.admins);
+        self.db
+            .transaction::<_, UpdatePropertiesResponse, CatalogError>(|txn| {
+                Box::pin(async move {
+                    let namespace = resolver::resolve_namespace(txn, &prefix, &namespace).await?;
+
+                    caller
+                        .grants(txn, namespace.catalog_id)
+                        .await?
+                        .require(Privilege::NamespaceWrite, Target::Namespace(&namespace))?;
+
+                    let mut properties = namespace
+                        .find_related(NamespaceProperty)
+                        .all(txn)
+                        .await?
+                        .into_iter()
+                        .map(|property| (property.key.clone(), property))
+                        .collect::<HashMap<_, _>>();
+
+                    let mut removed = Vec::new();
+                    let mut missing = Vec::new();
+                    for key in removals {
+                        match properties.remove(&key) {
+                            Some(property) => {
+                                property.delete(txn).await?;
+                                removed.push(key);
+                            }
+                            None => missing.push(key),
+                        }
+                    }
+
+                    let mut updated = Vec::new();
+                    for (key, value) in updates {
+                        match properties.remove(&key) {
+                            Some(property) => {
+                                let mut property: namespace_property::ActiveModel =
+                                    property.into();
+                                property.set(
+                                    namespace_property::Column::Value,
+                                    value.into(),
+                                );
+                                property.update(txn).await?;
+                            }
+                            None => {
+                                let property =
+                                    namespace_property::ActiveModel::from_json(json!({
+                                        "key": &key,
+                                        "value": value,
+                                        "namespace_id": namespace.id
+                                    }))?;
+                                NamespaceProperty::insert(property).exec(txn).await?;
+                            }
+                        }
+                        updated.push(key);
+                    }
+
+                    Ok(
+                        UpdatePropertiesResponse::JSONDataResponseForASynchronousUpdatePropertiesRequest(
+                            models::UpdateProperties200Response {
+                                updated,
+                                removed,
+                                missing: Some(swagger::Nullable::Present(missing)),
+                            },
+                        ),
+                    )
+                })
+            })
+            .await
+            .or_else(|err| Ok(UpdatePropertiesResponse::from_error(err.into())))
     }
 
     /// Commit updates to a table
@@ -787,103 +1015,256 @@ where
         namespace: String,
         table: String,
         commit_table_request: Option<models::CommitTableRequest>,
-        _context: &C,
+        context: &C,
     ) -> Result<UpdateTableResponse, ApiError> {
-        self.db.transaction::<_, UpdateTableResponse, DbErr>(|txn| {
-            Box::pin(async move {
-                match commit_table_request {
-                    None => Ok(UpdateTableResponse::IndicatesABadRequestError(
-                        models::ErrorModel::new("No CommitTableRequest".into(), "BadRequest".into(), 500),
-                    )),
-                    Some(commit_table_request) => {
-                        let catalog = if prefix != "" {
-                            Catalog::find()
-                                .filter(catalog::Column::Name.contains(&prefix))
-                                .one(txn)
-                                .await
-                                ?
-                        } else {
-                            None
-                        };
-        
-                        let namespace = match catalog {
-                            None => Namespace::find()
-                                .filter(namespace::Column::Name.contains(&namespace))
-                                .one(txn)
-                                .await
-                                ?,
-                            Some(catalog) => catalog
-                                .find_related(Namespace)
-                                .filter(namespace::Column::Name.contains(&namespace))
-                                .one(txn)
-                                .await
-                                ?,
-                        };
-        
-                        let table = match namespace {
-                            None => IcebergTable::find()
-                                .filter(iceberg_table::Column::Name.contains(&table))
-                                .one(txn)
-                                .await
-                                ?,
-                            Some(namespace) => namespace
-                                .find_related(IcebergTable)
-                                .filter(iceberg_table::Column::Name.contains(&table))
-                                .one(txn)
-                                .await
-                                ?,
-                        };
-                        match table {
-                            None => Ok(UpdateTableResponse::NotFound(models::ErrorModel::new(
-                                "Namespace to delete does not exist.".into(),
-                                "NotFound".into(),
-                                500,
-                            ))),
-                            Some(table) => {
-                                let old_metadata_location = table.metadata_location.clone();
-                                let mut new_table: iceberg_table::ActiveModel = table.into();
-                                new_table.set(
-                                    iceberg_table::Column::MetadataLocation,
-                                    commit_table_request
-                                        .updates
-                                        .iter()
-                                        .last()
-                                        .unwrap()
-                                        .location
-                                        .as_str()
-                                        .into(),
-                                );
-                                new_table.set(
-                                    iceberg_table::Column::PreviousMetadataLocation,
-                                    Some(old_metadata_location).into(),
-                                );
-                                let new_table = new_table.update(txn).await?;
-                                Ok(
+        let warehouse = self.warehouse.clone();
+        let committer = Committer::from_context(context);
+        let caller = Caller::from_context(context, &self.admins);
+        self.db
+            .transaction::<_, UpdateTableResponse, CatalogError>(|txn| {
+                Box::pin(async move {
+                    match commit_table_request {
+                        None => Err(CatalogError::BadRequest(
+                            "Missing CommitTableRequest.".into(),
+                        )),
+                        Some(commit_table_request) => {
+                            let catalog = resolver::catalog(txn, &prefix).await?;
+                            let namespace = resolver::namespace(
+                                txn,
+                                catalog.as_ref(),
+                                &resolver::namespace_name(&namespace),
+                            )
+                            .await?;
+                            let no_such_table = || {
+                                CatalogError::NoSuchTable(format!("{}.{}", namespace.name, table))
+                            };
+
+                            // A commit asserting that the table doesn't exist yet creates a
+                            // staged table
+                            let current = resolver::find_table(txn, &namespace, &table).await?;
+                            let creates = current.is_none()
+                                && commit_table_request
+                                    .requirements
+                                    .iter()
+                                    .any(|requirement| requirement.requirement == "assert-create");
+
+                            let grants = caller.grants(txn, namespace.catalog_id).await?;
+                            match &current {
+                                Some(current) => grants.require(
+                                    Privilege::TableWrite,
+                                    Target::Table(&namespace, current),
+                                )?,
+                                None if creates => grants.require(
+                                    Privilege::TableCreate,
+                                    Target::Namespace(&namespace),
+                                )?,
+                                None => grants.require_visible(Target::Namespace(&namespace))?,
+                            }
+                            let metadata = match &current {
+                                Some(current) => metadata::load(txn, current).await?,
+                                None if creates => staged::find(txn, &namespace, &table)
+                                    .await?
+                                    .ok_or_else(no_such_table)?,
+                                None => return Err(no_such_table()),
+                            };
+
+                            for requirement in &commit_table_request.requirements {
+                                if creates && requirement.requirement == "assert-create" {
+                                    continue;
+                                }
+                                match requirement::check(requirement, &metadata) {
+                                    Ok(()) => (),
+                                    Err(RequirementError::Failed(message)) => {
+                                        return Err(CatalogError::CommitFailed(message))
+                                    }
+                                    Err(RequirementError::Invalid(message)) => {
+                                        return Err(CatalogError::BadRequest(message))
+                                    }
+                                }
+                            }
+
+                            let old_metadata_location =
+                                current.as_ref().map(|current| current.metadata_location.clone());
+                            if let (Some(current), true) =
+                                (&current, commit_table_request.updates.is_empty())
+                            {
+                                return Ok(
                                     UpdateTableResponse::ResponseUsedWhenATableIsSuccessfullyUpdated(
                                         models::UpdateTable200Response {
-                                            metadata_location: new_table.metadata_location,
-                                            metadata: TableMetadata::new(2, "".into()),
+                                            metadata_location: current.metadata_location.clone(),
+                                            metadata,
                                         },
                                     ),
-                                )
+                                );
+                            }
+
+                            let last_updated_ms = metadata.last_updated_ms;
+                            let old_location = metadata.location.clone();
+                            let mut builder = update::MetadataBuilder::new(metadata);
+                            for update in &commit_table_request.updates {
+                                builder.apply(update).map_err(CatalogError::BadRequest)?;
+                            }
+                            let metadata =
+                                builder.build(old_metadata_location.as_deref(), last_updated_ms);
+
+                            let location = metadata.location.clone().unwrap_or_default();
+                            if metadata.location != old_location {
+                                let root =
+                                    metadata::storage_root(catalog.as_ref(), warehouse.as_deref());
+                                metadata::confine_location(&location, root)
+                                    .map_err(CatalogError::BadRequest)?;
+                            }
+                            let version = match &current {
+                                Some(current) => metadata::next_version(txn, current).await?,
+                                None => 0,
+                            };
+                            let metadata_location =
+                                metadata::metadata_file_location(&location, version);
+
+                            let table_id = match &current {
+                                Some(current) => {
+                                    // Swap the pointer only if no other commit has swapped it
+                                    // since the metadata was loaded
+                                    let result = IcebergTable::update_many()
+                                        .col_expr(
+                                            iceberg_table::Column::MetadataLocation,
+                                            Expr::value(metadata_location.as_str()),
+                                        )
+                                        .col_expr(
+                                            iceberg_table::Column::PreviousMetadataLocation,
+                                            Expr::value(current.metadata_location.as_str()),
+                                        )
+                                        .filter(iceberg_table::Column::Id.eq(current.id))
+                                        .filter(
+                                            iceberg_table::Column::MetadataLocation
+                                                .eq(current.metadata_location.as_str()),
+                                        )
+                                        .exec(txn)
+                                        .await?;
+                                    if result.rows_affected == 0 {
+                                        return Err(CatalogError::CommitFailed(
+                                            "The table has been updated concurrently.".into(),
+                                        ));
+                                    }
+                                    current.id
+                                }
+                                None => {
+                                    let new_table = iceberg_table::ActiveModel::from_json(json!({
+                                        "name": &table,
+                                        "namespace_id": namespace.id,
+                                        "metadata_location": metadata_location,
+                                        "previous_metadata_location": None::<String>
+                                    }))?;
+                                    let result = IcebergTable::insert(new_table)
+                                        .exec(txn)
+                                        .await
+                                        .map_err(|err| {
+                                            error::unique_violation(err, || {
+                                                CatalogError::CommitFailed(
+                                                    "The table has been created concurrently."
+                                                        .into(),
+                                                )
+                                            })
+                                        })?;
+                                    staged::remove(txn, &namespace, &table).await?;
+                                    result.last_insert_id
+                                }
+                            };
+
+                            metadata::store(txn, table_id, &metadata_location, &metadata).await?;
+                            history::record(
+                                txn,
+                                table_id,
+                                &metadata_location,
+                                old_metadata_location.as_deref(),
+                                &committer,
+                            )
+                            .await?;
+
+                            // The file is written last, so that failed commits leave no file
+                            // behind
+                            let written = match io::file_io(&location) {
+                                Ok(file_io) => {
+                                    metadata::write(file_io.as_ref(), &metadata_location, &metadata)
+                                        .await
+                                }
+                                Err(err) => Err(err),
+                            };
+                            if let Err(err) = written {
+                                return Err(CatalogError::Internal(format!(
+                                    "Failed to write the metadata file: {}",
+                                    err
+                                )));
                             }
+
+                            Ok(
+                                UpdateTableResponse::ResponseUsedWhenATableIsSuccessfullyUpdated(
+                                    models::UpdateTable200Response {
+                                        metadata_location,
+                                        metadata,
+                                    },
+                                ),
+                            )
                         }
                     }
-                }
+                })
+            })
+            .await
+            .or_else(|err| {
+                let err = match err {
+                    // Failing to commit the transaction leaves open whether it was applied
+                    TransactionError::Connection(err) if !matches!(err, DbErr::Conn(_)) => {
+                        CatalogError::CommitStateUnknown(err.to_string())
+                    }
+                    err => err.into(),
+                };
+                Ok(UpdateTableResponse::from_error(err))
             })
-        })
-        .await.map_err(|err| ApiError(err.to_string()))
-        
     }
 
     /// List all catalog configuration settings
-    async fn get_config(&self, _context: &C) -> Result<GetConfigResponse, ApiError> {
-        Ok(GetConfigResponse::ServerSpecifiedConfigurationValues(
-            models::CatalogConfig {
-                overrides: serde_json::from_str("{}").map_err(|err| ApiError(err.to_string()))?,
-                defaults: serde_json::from_str("{}").map_err(|err| ApiError(err.to_string()))?,
-            },
-        ))
+    async fn get_config(
+        &self,
+        warehouse: Option<String>,
+        context: &C,
+    ) -> Result<GetConfigResponse, ApiError> {
+        let caller = Caller::from_context(context, &self.admins);
+        let server_config = self.config.clone();
+        self.db
+            .transaction::<_, GetConfigResponse, CatalogError>(|txn| {
+                Box::pin(async move {
+                    let catalog = match &warehouse {
+                        Some(warehouse) => {
+                            let catalog = resolver::warehouse(txn, warehouse).await?;
+                            // Warehouses the caller holds no privileges in aren't disclosed
+                            if !caller
+                                .grants(txn, Some(catalog.id))
+                                .await?
+                                .sees(Target::Catalog)
+                            {
+                                return Err(CatalogError::NoSuchWarehouse(warehouse.clone()));
+                            }
+                            Some(catalog)
+                        }
+                        None => None,
+                    };
+
+                    let catalog_config =
+                        ClientConfig::load(txn, catalog.as_ref().map(|catalog| catalog.id)).await?;
+                    let mut merged = server_config.merge(catalog_config);
+                    if let Some(catalog) = catalog {
+                        merged
+                            .overrides
+                            .insert(config::PREFIX.to_owned(), catalog.name);
+                    }
+
+                    Ok(GetConfigResponse::ServerSpecifiedConfigurationValues(
+                        merged.into(),
+                    ))
+                })
+            })
+            .await
+            .or_else(|err| Ok(GetConfigResponse::from_error(err.into())))
     }
 
     /// Get a token using an OAuth2 flow
@@ -898,84 +1279,1199 @@ where
         subject_token_type: Option<models::TokenType>,
         actor_token: Option<String>,
         actor_token_type: Option<models::TokenType>,
-        context: &C,
+        _context: &C,
     ) -> Result<GetTokenResponse, ApiError> {
-        let context = context.clone();
-        info!(
-            "get_token({:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}, {:?}) - X-Span-ID: {:?}",
-            grant_type,
-            scope,
-            client_id,
-            client_secret,
-            requested_token_type,
-            subject_token,
-            subject_token_type,
-            actor_token,
-            actor_token_type,
-            context.get().0.clone()
-        );
-        Err(ApiError("Generic failure".into()))
+        let result = match grant_type.as_deref() {
+            Some(oauth::CLIENT_CREDENTIALS) => {
+                oauth::client_credentials(&self.db, &self.tokens, client_id, client_secret, scope)
+                    .await
+            }
+            Some(oauth::TOKEN_EXCHANGE) => oauth::token_exchange(
+                &self.tokens,
+                &self.verifier,
+                scope,
+                requested_token_type,
+                subject_token,
+                subject_token_type,
+                actor_token,
+                actor_token_type,
+            ),
+            Some(grant_type) => Err(OAuthError::UnsupportedGrantType(format!(
+                "Grant type {} is not supported",
+                grant_type
+            ))),
+            None => Err(OAuthError::InvalidRequest(
+                "Missing parameter grant_type".to_owned(),
+            )),
+        };
+
+        Ok(result.map_or_else(GetTokenResponse::from, GetTokenResponse::OAuth))
     }
-}
 
-#[cfg(test)]
+    /// Create a catalog
+    async fn create_catalog(
+        &self,
+        create_catalog_request: models::CreateCatalogRequest,
+        context: &C,
+    ) -> Result<CreateCatalogResponse, ApiError> {
+        let caller = Caller::from_context(context, &self.admins);
+        self.db
+            .transaction::<_, CreateCatalogResponse, CatalogError>(|txn| {
+                Box::pin(async move {
+                    caller.require_admin()?;
 
-pub mod tests {
-    use std::collections::HashMap;
+                    let catalog = catalogs::create(txn, create_catalog_request).await?;
 
-    use iceberg_catalog_rest_rdbms_client::{
-        apis::{self, configuration::Configuration},
-        models::{self, schema, Schema},
-    };
+                    Ok(CreateCatalogResponse::TheCatalog(catalog))
+                })
+            })
+            .await
+            .or_else(|err| Ok(CreateCatalogResponse::from_error(err.into())))
+    }
 
-    fn configuration() -> Configuration {
-        Configuration {
-            base_path: "http://localhost:8080".to_string(),
-            user_agent: None,
-            client: reqwest::Client::new(),
-            basic_auth: None,
-            oauth_access_token: None,
-            bearer_access_token: None,
-            api_key: None,
-        }
+    /// Drop an empty catalog
+    async fn drop_catalog(
+        &self,
+        catalog: String,
+        context: &C,
+    ) -> Result<DropCatalogResponse, ApiError> {
+        let caller = Caller::from_context(context, &self.admins);
+        self.db
+            .transaction::<_, DropCatalogResponse, CatalogError>(|txn| {
+                Box::pin(async move {
+                    caller.require_admin()?;
+
+                    let catalog = catalogs::catalog(txn, &catalog).await?;
+                    catalogs::remove(txn, catalog).await?;
+
+                    Ok(DropCatalogResponse::Success)
+                })
+            })
+            .await
+            .or_else(|err| Ok(DropCatalogResponse::from_error(err.into())))
+    }
+
+    /// Drop a role and its grants
+    async fn drop_role(&self, role: String, context: &C) -> Result<DropRoleResponse, ApiError> {
+        let caller = Caller::from_context(context, &self.admins);
+        self.db
+            .transaction::<_, DropRoleResponse, CatalogError>(|txn| {
+                Box::pin(async move {
+                    caller.require_admin()?;
+
+                    let role = roles::role(txn, &role).await?;
+                    roles::remove(txn, role).await?;
+
+                    Ok(DropRoleResponse::Success)
+                })
+            })
+            .await
+            .or_else(|err| Ok(DropRoleResponse::from_error(err.into())))
+    }
+
+    /// Get the configuration a catalog pushes to clients
+    async fn get_catalog_config(
+        &self,
+        catalog: Option<String>,
+        context: &C,
+    ) -> Result<GetCatalogConfigResponse, ApiError> {
+        let caller = Caller::from_context(context, &self.admins);
+        self.db
+            .transaction::<_, GetCatalogConfigResponse, CatalogError>(|txn| {
+                Box::pin(async move {
+                    caller.require_admin()?;
+
+                    let catalog = resolver::catalog(txn, &catalog.unwrap_or_default()).await?;
+                    let config = ClientConfig::load(txn, catalog.map(|catalog| catalog.id)).await?;
+
+                    Ok(GetCatalogConfigResponse::TheConfigurationOfTheCatalog(
+                        config.into(),
+                    ))
+                })
+            })
+            .await
+            .or_else(|err| Ok(GetCatalogConfigResponse::from_error(err.into())))
+    }
+
+    /// List the catalogs
+    async fn list_catalogs(&self, context: &C) -> Result<ListCatalogsResponse, ApiError> {
+        let caller = Caller::from_context(context, &self.admins);
+        self.db
+            .transaction::<_, ListCatalogsResponse, CatalogError>(|txn| {
+                Box::pin(async move {
+                    caller.require_admin()?;
+
+                    let catalogs = catalogs::list(txn).await?;
+
+                    Ok(ListCatalogsResponse::TheCatalogs(
+                        models::ListCatalogs200Response { catalogs },
+                    ))
+                })
+            })
+            .await
+            .or_else(|err| Ok(ListCatalogsResponse::from_error(err.into())))
+    }
+
+    /// List the grants of a role
+    async fn list_grants(&self, role: String, context: &C) -> Result<ListGrantsResponse, ApiError> {
+        let caller = Caller::from_context(context, &self.admins);
+        self.db
+            .transaction::<_, ListGrantsResponse, CatalogError>(|txn| {
+                Box::pin(async move {
+                    let role = roles::role(txn, &role).await?;
+                    let grants = roles::grants(txn, &role, &caller).await?;
+
+                    Ok(ListGrantsResponse::TheGrantsOfTheRole(
+                        models::ListGrants200Response { grants },
+                    ))
+                })
+            })
+            .await
+            .or_else(|err| Ok(ListGrantsResponse::from_error(err.into())))
+    }
+
+    /// List the roles and their principals
+    async fn list_roles(&self, context: &C) -> Result<ListRolesResponse, ApiError> {
+        let caller = Caller::from_context(context, &self.admins);
+        self.db
+            .transaction::<_, ListRolesResponse, CatalogError>(|txn| {
+                Box::pin(async move {
+                    caller.require_admin()?;
+
+                    let roles = roles::list(txn).await?;
+
+                    Ok(ListRolesResponse::TheRolesAndTheirPrincipals(
+                        models::ListRoles200Response { roles },
+                    ))
+                })
+            })
+            .await
+            .or_else(|err| Ok(ListRolesResponse::from_error(err.into())))
+    }
+
+    /// Describe a catalog
+    async fn load_catalog(
+        &self,
+        catalog: String,
+        context: &C,
+    ) -> Result<LoadCatalogResponse, ApiError> {
+        let caller = Caller::from_context(context, &self.admins);
+        self.db
+            .transaction::<_, LoadCatalogResponse, CatalogError>(|txn| {
+                Box::pin(async move {
+                    caller.require_admin()?;
+
+                    let catalog = catalogs::catalog(txn, &catalog).await?;
+                    let catalog = catalogs::load(txn, catalog).await?;
+
+                    Ok(LoadCatalogResponse::TheCatalog(catalog))
+                })
+            })
+            .await
+            .or_else(|err| Ok(LoadCatalogResponse::from_error(err.into())))
+    }
+
+    /// Update the storage root or properties of a catalog
+    async fn update_catalog(
+        &self,
+        catalog: String,
+        update_catalog_request: models::UpdateCatalogRequest,
+        context: &C,
+    ) -> Result<UpdateCatalogResponse, ApiError> {
+        let caller = Caller::from_context(context, &self.admins);
+        self.db
+            .transaction::<_, UpdateCatalogResponse, CatalogError>(|txn| {
+                Box::pin(async move {
+                    caller.require_admin()?;
+
+                    let catalog = catalogs::catalog(txn, &catalog).await?;
+                    let catalog = catalogs::update(txn, catalog, update_catalog_request).await?;
+
+                    Ok(UpdateCatalogResponse::TheCatalog(catalog))
+                })
+            })
+            .await
+            .or_else(|err| Ok(UpdateCatalogResponse::from_error(err.into())))
+    }
+
+    /// Replace the configuration a catalog pushes to clients
+    async fn update_catalog_config(
+        &self,
+        update_catalog_config_request: models::UpdateCatalogConfigRequest,
+        context: &C,
+    ) -> Result<UpdateCatalogConfigResponse, ApiError> {
+        let caller = Caller::from_context(context, &self.admins);
+        self.db
+            .transaction::<_, UpdateCatalogConfigResponse, CatalogError>(|txn| {
+                Box::pin(async move {
+                    caller.require_admin()?;
+
+                    let models::UpdateCatalogConfigRequest {
+                        catalog,
+                        defaults,
+                        overrides,
+                    } = update_catalog_config_request;
+                    let catalog_id = resolver::catalog(txn, &catalog)
+                        .await?
+                        .map(|catalog| catalog.id);
+                    ClientConfig::store(txn, catalog_id, defaults, overrides).await?;
+                    let config = ClientConfig::load(txn, catalog_id).await?;
+
+                    Ok(UpdateCatalogConfigResponse::TheConfigurationOfTheCatalog(
+                        config.into(),
+                    ))
+                })
+            })
+            .await
+            .or_else(|err| Ok(UpdateCatalogConfigResponse::from_error(err.into())))
+    }
+
+    /// Grant privileges to or revoke privileges from a role
+    async fn update_grants(
+        &self,
+        role: String,
+        update_grants_request: models::UpdateGrantsRequest,
+        context: &C,
+    ) -> Result<UpdateGrantsResponse, ApiError> {
+        let caller = Caller::from_context(context, &self.admins);
+        self.db
+            .transaction::<_, UpdateGrantsResponse, CatalogError>(|txn| {
+                Box::pin(async move {
+                    let role = roles::role(txn, &role).await?;
+                    roles::update(txn, &role, &caller, update_grants_request).await?;
+                    let grants = roles::grants(txn, &role, &caller).await?;
+
+                    Ok(UpdateGrantsResponse::TheGrantsOfTheRole(
+                        models::ListGrants200Response { grants },
+                    ))
+                })
+            })
+            .await
+            .or_else(|err| Ok(UpdateGrantsResponse::from_error(err.into())))
+    }
+
+    /// Create a role or replace its principals
+    async fn update_role(
+        &self,
+        role: String,
+        update_role_request: models::UpdateRoleRequest,
+        context: &C,
+    ) -> Result<UpdateRoleResponse, ApiError> {
+        let caller = Caller::from_context(context, &self.admins);
+        self.db
+            .transaction::<_, UpdateRoleResponse, CatalogError>(|txn| {
+                Box::pin(async move {
+                    caller.require_admin()?;
+
+                    let role = roles::put(txn, &role, update_role_request.principals).await?;
+
+                    Ok(UpdateRoleResponse::TheRoleAndItsPrincipals(role))
+                })
+            })
+            .await
+            .or_else(|err| Ok(UpdateRoleResponse::from_error(err.into())))
+    }
+}
+
+/// Deletes a table together with its stored metadata, metrics reports and grants.
+/// Its commit history is kept and marked as the history of a dropped table.
+async fn delete_table<C: ConnectionTrait>(
+    db: &C,
+    namespace: &namespace::Model,
+    table: iceberg_table::Model,
+) -> Result<(), DbErr> {
+    TableMetadata::delete_many()
+        .filter(table_metadata::Column::TableId.eq(table.id))
+        .exec(db)
+        .await?;
+    history::mark_dropped(db, table.id, &format!("{}.{}", namespace.name, table.name)).await?;
+    ScanReport::delete_many()
+        .filter(scan_report::Column::TableId.eq(table.id))
+        .exec(db)
+        .await?;
+    CommitReport::delete_many()
+        .filter(commit_report::Column::TableId.eq(table.id))
+        .exec(db)
+        .await?;
+    ReportMetric::delete_many()
+        .filter(report_metric::Column::TableId.eq(table.id))
+        .exec(db)
+        .await?;
+    PrivilegeGrant::delete_many()
+        .filter(privilege_grant::Column::TableId.eq(table.id))
+        .exec(db)
+        .await?;
+
+    table.delete(db).await?;
+
+    Ok(())
+}
+
+/// Converts the `properties` object of a request into a string to string map.
+fn namespace_properties(
+    properties: Option<serde_json::Value>,
+) -> Result<HashMap<String, String>, String> {
+    match properties {
+        None | Some(serde_json::Value::Null) => Ok(HashMap::new()),
+        Some(serde_json::Value::Object(properties)) => properties
+            .into_iter()
+            .map(|(key, value)| match value {
+                serde_json::Value::String(value) => Ok((key, value)),
+                _ => Err(format!(
+                    "The value of property \"{}\" is not a string.",
+                    key
+                )),
+            })
+            .collect(),
+        Some(_) => Err("Namespace properties have to be a JSON object.".into()),
+    }
+}
+
+/// Integration tests against a server listening on localhost:8080 whose warehouse is
+/// the directory `iceberg_catalog_rest_rdbms_server` in the temporary directory.
+///
+/// The tests sign their own access tokens, so the server has to be started with
+/// the `TOKEN_SIGNING_KEY` of the tests' environment and `--admin integration-tests`.
+#[cfg(test)]
+
+pub mod tests {
+    use iceberg_catalog_rest_rdbms_client::{
+        apis::{self, configuration::Configuration},
+        models::{self, schema, Schema},
+    };
+
+    use crate::auth::TokenIssuer;
+    use crate::config::Config;
+
+    /// Principal the tests run as, which the server has to be started with as admin.
+    const ADMIN: &str = "integration-tests";
+
+    /// Returns an issuer of tokens the server accepts, valid for `lifetime` seconds.
+    fn issuer(lifetime: i64) -> TokenIssuer {
+        let mut config = Config::default();
+        config
+            .apply_env(|name| std::env::var(name).ok())
+            .expect("Invalid environment");
+        let auth = config.auth;
+        let signing_key = auth
+            .token_signing_key
+            .expect("TOKEN_SIGNING_KEY has to be set");
+        TokenIssuer::new(
+            signing_key.as_bytes(),
+            auth.token_issuer,
+            auth.token_audience,
+            lifetime,
+        )
+    }
+
+    fn token(subject: &str, scope: &str) -> String {
+        let issuer = issuer(3600);
+        issuer.sign(&issuer.claims(subject, scope)).unwrap()
+    }
+
+    fn configuration() -> Configuration {
+        configuration_as(ADMIN, "catalog admin")
+    }
+
+    /// Returns a configuration whose client sends a token of the principal with
+    /// every request.
+    fn configuration_as(principal: &str, scope: &str) -> Configuration {
+        let token = token(principal, scope);
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(
+            reqwest::header::AUTHORIZATION,
+            format!("Bearer {}", token).parse().unwrap(),
+        );
+        Configuration {
+            base_path: "http://localhost:8080".to_string(),
+            user_agent: None,
+            client: reqwest::Client::builder()
+                .default_headers(headers)
+                .build()
+                .unwrap(),
+            basic_auth: None,
+            oauth_access_token: None,
+            bearer_access_token: Some(token),
+            api_key: None,
+        }
+    }
+
+    fn location(name: &str) -> String {
+        format!(
+            "file://{}/{}",
+            std::env::temp_dir()
+                .join("iceberg_catalog_rest_rdbms_server")
+                .display(),
+            name
+        )
+    }
+
+    // Update in the form the server currently parses every table update
+    fn set_location_update(location: &str) -> serde_json::Value {
+        serde_json::json!({ "action": "set-location", "location": location })
+    }
+
+    // The client models can't represent complete table metadata, so table results
+    // are returned as JSON.
+    async fn table_request(
+        request: reqwest::RequestBuilder,
+    ) -> Result<serde_json::Value, reqwest::StatusCode> {
+        let response = request
+            .header("Content-Type", "application/json")
+            .send()
+            .await
+            .expect("Failed to send request");
+        if !response.status().is_success() {
+            return Err(response.status());
+        }
+        Ok(serde_json::from_str(&response.text().await.unwrap()).unwrap())
+    }
+
+    async fn request_create_table(
+        prefix: &str,
+        namespace: &str,
+        request: Option<models::CreateTableRequest>,
+    ) -> Result<serde_json::Value, reqwest::StatusCode> {
+        table_request(
+            configuration()
+                .client
+                .post(format!(
+                    "http://localhost:8080/v1/{}/namespaces/{}/tables",
+                    prefix, namespace
+                ))
+                .body(serde_json::to_string(&request).unwrap()),
+        )
+        .await
+    }
+
+    async fn request_load_table(
+        prefix: &str,
+        namespace: &str,
+        table: &str,
+    ) -> Result<serde_json::Value, reqwest::StatusCode> {
+        table_request(configuration().client.get(format!(
+            "http://localhost:8080/v1/{}/namespaces/{}/tables/{}",
+            prefix, namespace, table
+        )))
+        .await
+    }
+
+    async fn request_update_table(
+        prefix: &str,
+        namespace: &str,
+        table: &str,
+        request: serde_json::Value,
+    ) -> Result<serde_json::Value, reqwest::StatusCode> {
+        table_request(
+            configuration()
+                .client
+                .post(format!(
+                    "http://localhost:8080/v1/{}/namespaces/{}/tables/{}",
+                    prefix, namespace, table
+                ))
+                .body(request.to_string()),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn create_namespace() {
+        let request = models::CreateNamespaceRequest {
+            namespace: vec!["create_namespace".to_owned()],
+            properties: None,
+        };
+        let response =
+            apis::catalog_api_api::create_namespace(&configuration(), "my_catalog", Some(request))
+                .await
+                .expect("Failed to create namespace");
+        assert_eq!(response.namespace[0], "create_namespace");
+
+        apis::catalog_api_api::drop_namespace(&configuration(), "my_catalog", "create_namespace")
+            .await
+            .expect("Failed to drop namespace");
+    }
+
+    #[tokio::test]
+    async fn drop_namespace() {
+        let request = models::CreateNamespaceRequest {
+            namespace: vec!["drop_namespace".to_owned()],
+            properties: None,
+        };
+        apis::catalog_api_api::create_namespace(&configuration(), "my_catalog", Some(request))
+            .await
+            .expect("Failed to create namespace");
+
+        apis::catalog_api_api::drop_namespace(&configuration(), "my_catalog", "drop_namespace")
+            .await
+            .expect("Failed to drop namespace");
+    }
+
+    #[tokio::test]
+    async fn drop_non_empty_namespace() {
+        for namespace in [vec!["non_empty"], vec!["non_empty", "child"]] {
+            let request = models::CreateNamespaceRequest {
+                namespace: namespace.into_iter().map(|x| x.to_owned()).collect(),
+                properties: None,
+            };
+            apis::catalog_api_api::create_namespace(&configuration(), "my_catalog", Some(request))
+                .await
+                .expect("Failed to create namespace");
+        }
+        for namespace in ["non_empty", "non_empty.child"] {
+            let mut request = models::CreateTableRequest::new(
+                "table".to_owned(),
+                Schema::new(schema::RHashType::default(), vec![]),
+            );
+            request.location = Some(location(namespace));
+            request_create_table("my_catalog", namespace, Some(request))
+                .await
+                .expect("Failed to create table");
+        }
+
+        let drop_namespace = |namespace: &str, cascade: Option<bool>| {
+            let mut request = configuration().client.delete(format!(
+                "http://localhost:8080/v1/my_catalog/namespaces/{}",
+                namespace
+            ));
+            if let Some(cascade) = cascade {
+                request = request.query(&[("cascade", cascade)]);
+            }
+            request.send()
+        };
+
+        // Neither tables nor child namespaces are dropped implicitly
+        for namespace in ["non_empty", "non_empty.child"] {
+            let response = drop_namespace(namespace, None)
+                .await
+                .expect("Failed to send request");
+            assert_eq!(response.status(), 409);
+            let error: serde_json::Value = response.json().await.unwrap();
+            assert_eq!(error["type"], "NamespaceNotEmptyException");
+        }
+        let response = drop_namespace("non_empty", Some(false))
+            .await
+            .expect("Failed to send request");
+        assert_eq!(response.status(), 409);
+        request_load_table("my_catalog", "non_empty.child", "table")
+            .await
+            .expect("Failed to load table");
+
+        let response = drop_namespace("non_empty", Some(true))
+            .await
+            .expect("Failed to send request");
+        assert_eq!(response.status(), 204);
+        for namespace in ["non_empty", "non_empty.child"] {
+            apis::catalog_api_api::load_namespace_metadata(
+                &configuration(),
+                "my_catalog",
+                namespace,
+            )
+            .await
+            .expect_err("Namespace was not dropped");
+        }
+    }
+
+    #[tokio::test]
+    async fn load_namespace_metadata() {
+        let request = models::CreateNamespaceRequest {
+            namespace: vec!["load_namespace_metadata".to_owned()],
+            properties: Some(serde_json::json!({ "owner": "Hank Bendickson" })),
+        };
+        apis::catalog_api_api::create_namespace(&configuration(), "my_catalog", Some(request))
+            .await
+            .expect("Failed to create namespace");
+
+        let response = apis::catalog_api_api::load_namespace_metadata(
+            &configuration(),
+            "my_catalog",
+            "load_namespace_metadata",
+        )
+        .await
+        .expect("Failed to load namespace metadata");
+        assert_eq!(response.namespace, vec!["load_namespace_metadata"]);
+        assert_eq!(
+            response.properties.unwrap(),
+            serde_json::json!({ "owner": "Hank Bendickson" })
+        );
+
+        apis::catalog_api_api::drop_namespace(
+            &configuration(),
+            "my_catalog",
+            "load_namespace_metadata",
+        )
+        .await
+        .expect("Failed to drop namespace");
+    }
+
+    #[tokio::test]
+    async fn update_properties() {
+        let request = models::CreateNamespaceRequest {
+            namespace: vec!["update_properties".to_owned()],
+            properties: Some(serde_json::json!({
+                "owner": "Hank Bendickson",
+                "department": "accounting"
+            })),
+        };
+        apis::catalog_api_api::create_namespace(&configuration(), "my_catalog", Some(request))
+            .await
+            .expect("Failed to create namespace");
+
+        // The updates are sent as a JSON object, which is what Iceberg clients send
+        let response = configuration()
+            .client
+            .post("http://localhost:8080/v1/my_catalog/namespaces/update_properties/properties")
+            .header("Content-Type", "application/json")
+            .body(
+                serde_json::json!({
+                    "updates": { "owner": "Jane Doe", "retention": "30d" },
+                    "removals": ["department", "access_group"]
+                })
+                .to_string(),
+            )
+            .send()
+            .await
+            .expect("Failed to update properties");
+        assert_eq!(response.status(), 200);
+        let mut response: models::UpdateProperties200Response =
+            serde_json::from_str(&response.text().await.unwrap()).unwrap();
+        response.updated.sort();
+        assert_eq!(response.updated, vec!["owner", "retention"]);
+        assert_eq!(response.removed, vec!["department"]);
+        assert_eq!(response.missing.unwrap(), vec!["access_group"]);
+
+        let response = apis::catalog_api_api::load_namespace_metadata(
+            &configuration(),
+            "my_catalog",
+            "update_properties",
+        )
+        .await
+        .expect("Failed to load namespace metadata");
+        assert_eq!(
+            response.properties.unwrap(),
+            serde_json::json!({ "owner": "Jane Doe", "retention": "30d" })
+        );
+
+        let response = configuration()
+            .client
+            .post("http://localhost:8080/v1/my_catalog/namespaces/update_properties/properties")
+            .header("Content-Type", "application/json")
+            .body(
+                serde_json::json!({
+                    "updates": { "owner": "Jane Doe" },
+                    "removals": ["owner"]
+                })
+                .to_string(),
+            )
+            .send()
+            .await
+            .expect("Failed to update properties");
+        assert_eq!(response.status(), 422);
+
+        apis::catalog_api_api::drop_namespace(&configuration(), "my_catalog", "update_properties")
+            .await
+            .expect("Failed to drop namespace");
+    }
+
+    #[tokio::test]
+    async fn create_table() {
+        let namespace_request = models::CreateNamespaceRequest {
+            namespace: vec!["create_table".to_owned()],
+            properties: None,
+        };
+        let _ = apis::catalog_api_api::create_namespace(
+            &configuration(),
+            "my_catalog",
+            Some(namespace_request),
+        )
+        .await;
+
+        let mut request = models::CreateTableRequest::new(
+            "create_table".to_owned(),
+            Schema::new(schema::RHashType::default(), vec![]),
+        );
+        request.location = Some(location("create_table"));
+        let response = request_create_table("my_catalog", "create_table", Some(request))
+            .await
+            .expect("Failed to create table");
+        let metadata_location = response["metadata-location"].as_str().unwrap();
+        assert!(metadata_location.starts_with(&(location("create_table") + "/metadata/00000-")));
+        assert!(metadata_location.ends_with(".metadata.json"));
+
+        // The metadata file is written by the server
+        let metadata_file = std::fs::read(metadata_location.strip_prefix("file://").unwrap())
+            .expect("Failed to read metadata file");
+        let metadata_file: serde_json::Value = serde_json::from_slice(&metadata_file).unwrap();
+        assert_eq!(metadata_file, response["metadata"]);
+
+        apis::catalog_api_api::drop_table(
+            &configuration(),
+            "my_catalog",
+            "create_table",
+            "create_table",
+            Some(true),
+        )
+        .await
+        .expect("Failed to create namespace");
+    }
+
+    #[tokio::test]
+    async fn drop_table() {
+        let namespace_request = models::CreateNamespaceRequest {
+            namespace: vec!["drop_table".to_owned()],
+            properties: None,
+        };
+        let _ = apis::catalog_api_api::create_namespace(
+            &configuration(),
+            "my_catalog",
+            Some(namespace_request),
+        )
+        .await;
+
+        let mut request = models::CreateTableRequest::new(
+            "drop_table".to_owned(),
+            Schema::new(schema::RHashType::default(), vec![]),
+        );
+        request.location = Some(location("location"));
+        request_create_table("my_catalog", "drop_table", Some(request))
+            .await
+            .expect("Failed to create table");
+
+        apis::catalog_api_api::drop_table(
+            &configuration(),
+            "my_catalog",
+            "drop_table",
+            "drop_table",
+            Some(true),
+        )
+        .await
+        .expect("Failed to create namespace");
+    }
+
+    #[tokio::test]
+    async fn purge_table() {
+        let namespace_request = models::CreateNamespaceRequest {
+            namespace: vec!["purge_table".to_owned()],
+            properties: None,
+        };
+        let _ = apis::catalog_api_api::create_namespace(
+            &configuration(),
+            "my_catalog",
+            Some(namespace_request),
+        )
+        .await;
+
+        let path = |location: String| {
+            std::path::PathBuf::from(location.strip_prefix("file://").unwrap().to_owned())
+        };
+        for (name, purge_requested) in [("purged", true), ("kept", false)] {
+            let mut request = models::CreateTableRequest::new(
+                name.to_owned(),
+                Schema::new(schema::RHashType::default(), vec![]),
+            );
+            request.location = Some(location(name));
+            request.properties = Some(std::collections::HashMap::from([(
+                "write.data.path".to_owned(),
+                location(&format!("{}_data", name)),
+            )]));
+            request_create_table("my_catalog", "purge_table", Some(request))
+                .await
+                .expect("Failed to create table");
+            let data_file = path(location(&format!("{}_data", name))).join("00000.parquet");
+            std::fs::create_dir_all(data_file.parent().unwrap()).unwrap();
+            std::fs::write(&data_file, b"data").unwrap();
+
+            apis::catalog_api_api::drop_table(
+                &configuration(),
+                "my_catalog",
+                "purge_table",
+                name,
+                Some(purge_requested),
+            )
+            .await
+            .expect("Failed to drop table");
+        }
+
+        // Files are deleted in the background
+        let purged = [path(location("purged")), path(location("purged_data"))];
+        for _ in 0..50 {
+            if purged.iter().all(|path| !path.exists()) {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        }
+        assert!(purged.iter().all(|path| !path.exists()));
+        assert!(path(location("kept")).join("metadata").exists());
+        assert!(path(location("kept_data")).join("00000.parquet").exists());
+    }
+
+    #[tokio::test]
+    async fn purge_spares_foreign_locations() {
+        let namespace_request = models::CreateNamespaceRequest {
+            namespace: vec!["purge_foreign".to_owned()],
+            properties: None,
+        };
+        let _ = apis::catalog_api_api::create_namespace(
+            &configuration(),
+            "my_catalog",
+            Some(namespace_request),
+        )
+        .await;
+
+        let path = |location: String| {
+            std::path::PathBuf::from(location.strip_prefix("file://").unwrap().to_owned())
+        };
+        let outside = format!(
+            "file://{}/purge_foreign_outside",
+            std::env::temp_dir().display()
+        );
+        // The data of "dropped" lies outside of the warehouse and its metadata path
+        // inside the data of "live"
+        for (name, data_path) in [
+            ("live", location("purge_foreign_live_data")),
+            ("dropped", outside.clone()),
+        ] {
+            let mut request = models::CreateTableRequest::new(
+                name.to_owned(),
+                Schema::new(schema::RHashType::default(), vec![]),
+            );
+            request.location = Some(location(&format!("purge_foreign_{}", name)));
+            request.properties = Some(std::collections::HashMap::from([
+                ("write.data.path".to_owned(), data_path),
+                (
+                    "write.metadata.path".to_owned(),
+                    location("purge_foreign_live_data/metadata"),
+                ),
+            ]));
+            request_create_table("my_catalog", "purge_foreign", Some(request))
+                .await
+                .expect("Failed to create table");
+        }
+        for location in [outside.clone(), location("purge_foreign_live_data")] {
+            let data_file = path(location).join("00000.parquet");
+            std::fs::create_dir_all(data_file.parent().unwrap()).unwrap();
+            std::fs::write(&data_file, b"data").unwrap();
+        }
+
+        apis::catalog_api_api::drop_table(
+            &configuration(),
+            "my_catalog",
+            "purge_foreign",
+            "dropped",
+            Some(true),
+        )
+        .await
+        .expect("Failed to drop table");
+
+        let purged = path(location("purge_foreign_dropped"));
+        for _ in 0..50 {
+            if !purged.exists() {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        }
+        assert!(!purged.exists());
+        assert!(path(outside).join("00000.parquet").exists());
+        assert!(path(location("purge_foreign_live_data"))
+            .join("00000.parquet")
+            .exists());
+
+        apis::catalog_api_api::drop_table(
+            &configuration(),
+            "my_catalog",
+            "purge_foreign",
+            "live",
+            Some(false),
+        )
+        .await
+        .expect("Failed to drop table");
+    }
+
+    #[tokio::test]
+    async fn list_namespaces() {
+        let request1 = models::CreateNamespaceRequest {
+            namespace: vec!["list_namespaces1".to_owned()],
+            properties: None,
+        };
+        let _ =
+            apis::catalog_api_api::create_namespace(&configuration(), "my_catalog", Some(request1))
+                .await;
+
+        let request2 = models::CreateNamespaceRequest {
+            namespace: vec!["list_namespaces2".to_owned()],
+            properties: None,
+        };
+        let _ =
+            apis::catalog_api_api::create_namespace(&configuration(), "my_catalog", Some(request2))
+                .await;
+
+        let response = apis::catalog_api_api::list_namespaces(&configuration(), "my_catalog", None)
+            .await
+            .expect("Failed to list namespace");
+        assert!(response
+            .namespaces
+            .as_ref()
+            .unwrap()
+            .iter()
+            .any(|x| x[0] == "list_namespaces1"));
+        assert!(response
+            .namespaces
+            .as_ref()
+            .unwrap()
+            .iter()
+            .any(|x| x[0] == "list_namespaces2"));
+    }
+
+    #[tokio::test]
+    async fn list_nested_namespaces() {
+        for namespace in [
+            vec!["nested"],
+            vec!["nested", "domain"],
+            vec!["nested", "domain", "team"],
+        ] {
+            let request = models::CreateNamespaceRequest {
+                namespace: namespace.into_iter().map(|x| x.to_owned()).collect(),
+                properties: None,
+            };
+            apis::catalog_api_api::create_namespace(&configuration(), "my_catalog", Some(request))
+                .await
+                .expect("Failed to create namespace");
+        }
+
+        let request = models::CreateNamespaceRequest {
+            namespace: vec!["missing_parent".to_owned(), "child".to_owned()],
+            properties: None,
+        };
+        apis::catalog_api_api::create_namespace(&configuration(), "my_catalog", Some(request))
+            .await
+            .expect_err("Created namespace without parent");
+
+        let response = apis::catalog_api_api::list_namespaces(&configuration(), "my_catalog", None)
+            .await
+            .expect("Failed to list namespace");
+        let namespaces = response.namespaces.unwrap();
+        assert!(namespaces.iter().any(|x| x == &vec!["nested"]));
+        assert!(namespaces.iter().all(|x| x.len() == 1));
+
+        let response =
+            apis::catalog_api_api::list_namespaces(&configuration(), "my_catalog", Some("nested"))
+                .await
+                .expect("Failed to list namespace");
+        assert_eq!(response.namespaces.unwrap(), vec![vec!["nested", "domain"]]);
+
+        let response = apis::catalog_api_api::list_namespaces(
+            &configuration(),
+            "my_catalog",
+            Some("nested.domain"),
+        )
+        .await
+        .expect("Failed to list namespace");
+        assert_eq!(
+            response.namespaces.unwrap(),
+            vec![vec!["nested", "domain", "team"]]
+        );
+
+        for namespace in ["nested.domain.team", "nested.domain", "nested"] {
+            apis::catalog_api_api::drop_namespace(&configuration(), "my_catalog", namespace)
+                .await
+                .expect("Failed to drop namespace");
+        }
+    }
+
+    #[tokio::test]
+    async fn list_tables() {
+        let namespace_request1 = models::CreateNamespaceRequest {
+            namespace: vec!["list_tables".to_owned()],
+            properties: None,
+        };
+        let _ = apis::catalog_api_api::create_namespace(
+            &configuration(),
+            "my_catalog",
+            Some(namespace_request1),
+        )
+        .await;
+
+        let mut request1 = models::CreateTableRequest::new(
+            "list_tables1".to_owned(),
+            Schema::new(schema::RHashType::default(), vec![]),
+        );
+        request1.location = Some(location("location"));
+        request_create_table("my_catalog", "list_tables", Some(request1))
+            .await
+            .expect("Failed to create table");
+        let mut request2 = models::CreateTableRequest::new(
+            "list_tables2".to_owned(),
+            Schema::new(schema::RHashType::default(), vec![]),
+        );
+        request2.location = Some(location("location"));
+        request_create_table("my_catalog", "list_tables", Some(request2))
+            .await
+            .expect("Failed to create table");
+        let response =
+            apis::catalog_api_api::list_tables(&configuration(), "my_catalog", "list_tables")
+                .await
+                .expect("Failed to create table");
+        assert_eq!(
+            response
+                .identifiers
+                .unwrap()
+                .into_iter()
+                .map(|x| x.name)
+                .collect::<Vec<_>>(),
+            vec!["list_tables1", "list_tables2"]
+        );
     }
 
     #[tokio::test]
-    async fn create_namespace() {
-        let request = models::CreateNamespaceRequest {
-            namespace: vec!["create_namespace".to_owned()],
+    async fn load_table() {
+        let namespace_request = models::CreateNamespaceRequest {
+            namespace: vec!["load_table".to_owned()],
             properties: None,
         };
-        let response =
-            apis::catalog_api_api::create_namespace(&configuration(), "my_catalog", Some(request))
-                .await
-                .expect("Failed to create namespace");
-        assert_eq!(response.namespace[0], "create_namespace");
+        let _ = apis::catalog_api_api::create_namespace(
+            &configuration(),
+            "my_catalog",
+            Some(namespace_request),
+        )
+        .await;
 
-        apis::catalog_api_api::drop_namespace(&configuration(), "my_catalog", "create_namespace")
+        let mut request = models::CreateTableRequest::new(
+            "load_table".to_owned(),
+            Schema::new(schema::RHashType::default(), vec![]),
+        );
+        request.location = Some(location("location"));
+        let create_response = request_create_table("my_catalog", "load_table", Some(request))
             .await
-            .expect("Failed to drop namespace");
+            .expect("Failed to create table");
+
+        let response = request_load_table("my_catalog", "load_table", "load_table")
+            .await
+            .expect("Failed to create namespace");
+
+        assert_eq!(
+            response["metadata-location"],
+            create_response["metadata-location"]
+        );
     }
 
     #[tokio::test]
-    async fn drop_namespace() {
-        let request = models::CreateNamespaceRequest {
-            namespace: vec!["drop_namespace".to_owned()],
+    async fn table_metadata() {
+        let namespace_request = models::CreateNamespaceRequest {
+            namespace: vec!["table_metadata".to_owned()],
             properties: None,
         };
-        apis::catalog_api_api::create_namespace(&configuration(), "my_catalog", Some(request))
-            .await
-            .expect("Failed to create namespace");
+        apis::catalog_api_api::create_namespace(
+            &configuration(),
+            "my_catalog",
+            Some(namespace_request),
+        )
+        .await
+        .expect("Failed to create namespace");
 
-        apis::catalog_api_api::drop_namespace(&configuration(), "my_catalog", "drop_namespace")
+        let schema = serde_json::json!({
+            "type": "struct",
+            "schema-id": 0,
+            "identifier-field-ids": [1],
+            "fields": [
+                { "id": 1, "name": "id", "required": true, "type": "long" },
+                { "id": 2, "name": "ts", "required": false, "type": "timestamptz" },
+                {
+                    "id": 3, "name": "location", "required": false, "doc": "Where it happened",
+                    "type": {
+                        "type": "struct",
+                        "fields": [
+                            { "id": 6, "name": "lat", "required": true, "type": "double" },
+                            { "id": 7, "name": "long", "required": true, "type": "double" }
+                        ]
+                    }
+                },
+                {
+                    "id": 4, "name": "tags", "required": false,
+                    "type": {
+                        "type": "list", "element-id": 8, "element": "string", "element-required": true
+                    }
+                },
+                {
+                    "id": 5, "name": "attributes", "required": false,
+                    "type": {
+                        "type": "map", "key-id": 9, "key": "string", "value-id": 10,
+                        "value": "decimal(9,2)", "value-required": false
+                    }
+                }
+            ]
+        });
+        let partition_spec = serde_json::json!({
+            "spec-id": 0,
+            "fields": [
+                { "field-id": 1000, "source-id": 2, "name": "ts_day", "transform": "day" },
+                { "field-id": 1001, "source-id": 1, "name": "id_bucket", "transform": "bucket[16]" }
+            ]
+        });
+        let write_order = serde_json::json!({
+            "order-id": 1,
+            "fields": [
+                { "source-id": 2, "transform": "identity", "direction": "desc", "null-order": "nulls-last" }
+            ]
+        });
+
+        let response = configuration()
+            .client
+            .post("http://localhost:8080/v1/my_catalog/namespaces/table_metadata/tables")
+            .header("Content-Type", "application/json")
+            .body(
+                serde_json::json!({
+                    "name": "events",
+                    "location": location("events"),
+                    "schema": schema,
+                    "partition-spec": partition_spec,
+                    "write-order": write_order,
+                    "properties": { "write.format.default": "parquet" }
+                })
+                .to_string(),
+            )
+            .send()
             .await
-            .expect("Failed to drop namespace");
+            .expect("Failed to create table");
+        assert_eq!(response.status(), 200);
+        let created: serde_json::Value =
+            serde_json::from_str(&response.text().await.unwrap()).unwrap();
+
+        let response = configuration()
+            .client
+            .get("http://localhost:8080/v1/my_catalog/namespaces/table_metadata/tables/events")
+            .send()
+            .await
+            .expect("Failed to load table");
+        assert_eq!(response.status(), 200);
+        let loaded: serde_json::Value =
+            serde_json::from_str(&response.text().await.unwrap()).unwrap();
+
+        assert_eq!(loaded["metadata"], created["metadata"]);
+        let metadata = &loaded["metadata"];
+        assert_eq!(metadata["format-version"], 2);
+        assert_eq!(metadata["location"], location("events"));
+        assert_eq!(metadata["schemas"][0], schema);
+        assert_eq!(metadata["partition-specs"][0], partition_spec);
+        assert_eq!(metadata["sort-orders"][0], write_order);
+        assert_eq!(metadata["default-sort-order-id"], 1);
+        assert_eq!(metadata["last-column-id"], 10);
+        assert_eq!(metadata["last-partition-id"], 1001);
+        assert_eq!(metadata["properties"]["write.format.default"], "parquet");
+
+        apis::catalog_api_api::drop_table(
+            &configuration(),
+            "my_catalog",
+            "table_metadata",
+            "events",
+            Some(false),
+        )
+        .await
+        .expect("Failed to drop table");
     }
 
     #[tokio::test]
-    async fn create_table() {
+    async fn update_table() {
         let namespace_request = models::CreateNamespaceRequest {
-            namespace: vec!["create_table".to_owned()],
+            namespace: vec!["update_table".to_owned()],
             properties: None,
         };
         let _ = apis::catalog_api_api::create_namespace(
@@ -985,36 +2481,53 @@ pub mod tests {
         )
         .await;
 
-        let mut request = models::CreateTableRequest::new(
-            "create_table".to_owned(),
+        let mut create_request = models::CreateTableRequest::new(
+            "update_table".to_owned(),
             Schema::new(schema::RHashType::default(), vec![]),
         );
-        request.location = Some("s3://path/to/location".into());
-        let response = apis::catalog_api_api::create_table(
-            &configuration(),
-            "my_catalog",
-            "create_table",
-            Some(request),
-        )
-        .await
-        .expect("Failed to create table");
-        assert_eq!(response.metadata_location.unwrap(), "s3://path/to/location");
+        create_request.location = Some(location("location1"));
+        let create_response =
+            request_create_table("my_catalog", "update_table", Some(create_request))
+                .await
+                .expect("Failed to create table");
+
+        let request = serde_json::json!({
+            "requirements": [],
+            "updates": [set_location_update(&location("location2"))]
+        });
+        let response = request_update_table("my_catalog", "update_table", "update_table", request)
+            .await
+            .expect("Failed to create table");
+
+        assert_eq!(response["metadata"]["location"], location("location2"));
+        assert!(response["metadata-location"]
+            .as_str()
+            .unwrap()
+            .starts_with(&format!("{}/metadata/00001-", location("location2"))));
+        assert_eq!(
+            response["metadata"]["table-uuid"],
+            create_response["metadata"]["table-uuid"]
+        );
+        assert_eq!(
+            response["metadata"]["metadata-log"][0]["metadata-file"],
+            create_response["metadata-location"]
+        );
 
         apis::catalog_api_api::drop_table(
             &configuration(),
             "my_catalog",
-            "create_table",
-            "create_table",
+            "update_table",
+            "update_table",
             Some(true),
         )
         .await
-        .expect("Failed to create namespace");
+        .expect("Failed to delete table");
     }
 
     #[tokio::test]
-    async fn drop_table() {
+    async fn confined_locations() {
         let namespace_request = models::CreateNamespaceRequest {
-            namespace: vec!["drop_table".to_owned()],
+            namespace: vec!["confined_locations".to_owned()],
             properties: None,
         };
         let _ = apis::catalog_api_api::create_namespace(
@@ -1024,116 +2537,373 @@ pub mod tests {
         )
         .await;
 
-        let mut request = models::CreateTableRequest::new(
-            "drop_table".to_owned(),
+        let outside = format!("file://{}/outside", std::env::temp_dir().display());
+        let mut create_request = models::CreateTableRequest::new(
+            "confined_locations".to_owned(),
             Schema::new(schema::RHashType::default(), vec![]),
         );
-        request.location = Some("s3://path/to/location".into());
-        apis::catalog_api_api::create_table(
-            &configuration(),
-            "my_catalog",
-            "drop_table",
-            Some(request),
-        )
-        .await
-        .expect("Failed to create table");
+        create_request.location = Some(outside.clone());
+        assert_eq!(
+            request_create_table(
+                "my_catalog",
+                "confined_locations",
+                Some(create_request.clone())
+            )
+            .await
+            .unwrap_err(),
+            reqwest::StatusCode::BAD_REQUEST
+        );
+
+        create_request.location = Some(location("confined_locations"));
+        request_create_table("my_catalog", "confined_locations", Some(create_request))
+            .await
+            .expect("Failed to create table");
+        let request = serde_json::json!({
+            "requirements": [],
+            "updates": [set_location_update(&outside)]
+        });
+        assert_eq!(
+            request_update_table(
+                "my_catalog",
+                "confined_locations",
+                "confined_locations",
+                request
+            )
+            .await
+            .unwrap_err(),
+            reqwest::StatusCode::BAD_REQUEST
+        );
 
         apis::catalog_api_api::drop_table(
             &configuration(),
             "my_catalog",
-            "drop_table",
-            "drop_table",
+            "confined_locations",
+            "confined_locations",
             Some(true),
         )
         .await
-        .expect("Failed to create namespace");
+        .expect("Failed to delete table");
     }
 
     #[tokio::test]
-    async fn list_namespaces() {
-        let request1 = models::CreateNamespaceRequest {
-            namespace: vec!["list_namespaces1".to_owned()],
-            properties: None,
-        };
-        let _ = apis::catalog_api_api::create_namespace(&configuration(), "my_catalog", Some(request1))
-            .await;
-
-        let request2 = models::CreateNamespaceRequest {
-            namespace: vec!["list_namespaces2".to_owned()],
+    async fn update_table_requirements() {
+        let namespace_request = models::CreateNamespaceRequest {
+            namespace: vec!["update_table_requirements".to_owned()],
             properties: None,
         };
-        let _ = apis::catalog_api_api::create_namespace(&configuration(), "my_catalog", Some(request2))
-            .await;
+        apis::catalog_api_api::create_namespace(
+            &configuration(),
+            "my_catalog",
+            Some(namespace_request),
+        )
+        .await
+        .expect("Failed to create namespace");
 
-        let response = apis::catalog_api_api::list_namespaces(&configuration(), "my_catalog", None)
-            .await
-            .expect("Failed to list namespace");
-        assert!(
-            response.namespaces.as_ref().unwrap().iter().any(|x| x[0] == "list_namespaces1")
+        let mut create_request = models::CreateTableRequest::new(
+            "events".to_owned(),
+            Schema::new(schema::RHashType::default(), vec![]),
         );
-        assert!(
-            response.namespaces.as_ref().unwrap().iter().any(|x| x[0] == "list_namespaces2")
+        create_request.location = Some(location("requirements"));
+        let create_response = request_create_table(
+            "my_catalog",
+            "update_table_requirements",
+            Some(create_request),
+        )
+        .await
+        .expect("Failed to create table");
+        let metadata = &create_response["metadata"];
+
+        let request = serde_json::json!({
+            "requirements": [
+                { "requirement": "assert-current-schema-id", "current-schema-id": 0 },
+                { "requirement": "assert-table-uuid", "uuid": "00000000-0000-0000-0000-000000000000" }
+            ],
+            "updates": [set_location_update(&location("requirements2"))]
+        });
+        let response = configuration()
+            .client
+            .post("http://localhost:8080/v1/my_catalog/namespaces/update_table_requirements/tables/events")
+            .header("Content-Type", "application/json")
+            .body(request.to_string())
+            .send()
+            .await
+            .expect("Failed to update table");
+        assert_eq!(response.status(), 409);
+        let error: serde_json::Value =
+            serde_json::from_str(&response.text().await.unwrap()).unwrap();
+        assert_eq!(error["type"], "CommitFailedException");
+        assert!(error["message"]
+            .as_str()
+            .unwrap()
+            .contains("assert-table-uuid"));
+
+        let response = request_load_table("my_catalog", "update_table_requirements", "events")
+            .await
+            .expect("Failed to load table");
+        assert_eq!(
+            response["metadata-location"],
+            create_response["metadata-location"]
         );
+
+        let request = serde_json::json!({
+            "requirements": [
+                { "requirement": "assert-table-uuid", "uuid": metadata["table-uuid"] },
+                { "requirement": "assert-ref-snapshot-id", "ref": "main" },
+                { "requirement": "assert-last-assigned-field-id", "last-assigned-field-id": 0 },
+                { "requirement": "assert-current-schema-id", "current-schema-id": 0 },
+                { "requirement": "assert-last-assigned-partition-id", "last-assigned-partition-id": 999 },
+                { "requirement": "assert-default-spec-id", "default-spec-id": 0 },
+                { "requirement": "assert-default-sort-order-id", "default-sort-order-id": 0 }
+            ],
+            "updates": [set_location_update(&location("requirements2"))]
+        });
+        let response =
+            request_update_table("my_catalog", "update_table_requirements", "events", request)
+                .await
+                .expect("Failed to update table");
+        assert_eq!(response["metadata"]["location"], location("requirements2"));
+
+        let request = serde_json::json!({
+            "requirements": [{ "requirement": "assert-create" }],
+            "updates": [set_location_update(&location("requirements3"))]
+        });
+        let response =
+            request_update_table("my_catalog", "update_table_requirements", "events", request)
+                .await;
+        assert_eq!(response, Err(reqwest::StatusCode::CONFLICT));
+
+        apis::catalog_api_api::drop_table(
+            &configuration(),
+            "my_catalog",
+            "update_table_requirements",
+            "events",
+            Some(false),
+        )
+        .await
+        .expect("Failed to drop table");
     }
 
     #[tokio::test]
-    async fn list_tables() {
-        let namespace_request1 = models::CreateNamespaceRequest {
-            namespace: vec!["list_tables".to_owned()],
+    async fn update_table_actions() {
+        let namespace_request = models::CreateNamespaceRequest {
+            namespace: vec!["update_table_actions".to_owned()],
             properties: None,
         };
-        let _ = apis::catalog_api_api::create_namespace(
+        apis::catalog_api_api::create_namespace(
             &configuration(),
             "my_catalog",
-            Some(namespace_request1),
+            Some(namespace_request),
         )
-        .await;
+        .await
+        .expect("Failed to create namespace");
 
-        let mut request1 = models::CreateTableRequest::new(
-            "list_tables1".to_owned(),
+        let mut create_request = models::CreateTableRequest::new(
+            "events".to_owned(),
             Schema::new(schema::RHashType::default(), vec![]),
         );
-        request1.location = Some("s3://path/to/location".into());
-        apis::catalog_api_api::create_table(
+        create_request.location = Some(location("actions"));
+        let create_response =
+            request_create_table("my_catalog", "update_table_actions", Some(create_request))
+                .await
+                .expect("Failed to create table");
+
+        // A commit without updates leaves the table unchanged
+        let request = serde_json::json!({ "requirements": [], "updates": [] });
+        let response =
+            request_update_table("my_catalog", "update_table_actions", "events", request)
+                .await
+                .expect("Failed to update table");
+        assert_eq!(
+            response["metadata-location"],
+            create_response["metadata-location"]
+        );
+
+        let request = serde_json::json!({
+            "requirements": [{ "requirement": "assert-current-schema-id", "current-schema-id": 0 }],
+            "updates": [
+                {
+                    "action": "add-schema",
+                    "schema": {
+                        "type": "struct",
+                        "fields": [
+                            { "id": 1, "name": "id", "required": true, "type": "long" },
+                            { "id": 2, "name": "data", "required": false, "type": "string" }
+                        ]
+                    }
+                },
+                { "action": "set-current-schema", "schema-id": -1 },
+                {
+                    "action": "add-spec",
+                    "spec": { "fields": [
+                        { "source-id": 1, "transform": "bucket[16]", "name": "id_bucket" }
+                    ] }
+                },
+                { "action": "set-default-spec", "spec-id": -1 },
+                {
+                    "action": "add-snapshot",
+                    "snapshot": {
+                        "snapshot-id": 3051729675574597004i64,
+                        "sequence-number": 1,
+                        "timestamp-ms": 1515100955770i64,
+                        "manifest-list": "s3://a/b/1.avro",
+                        "summary": { "operation": "append" },
+                        "schema-id": 1
+                    }
+                },
+                {
+                    "action": "set-snapshot-ref",
+                    "ref-name": "main",
+                    "type": "branch",
+                    "snapshot-id": 3051729675574597004i64
+                },
+                { "action": "set-properties", "updates": { "owner": "me", "comment": "events" } },
+                { "action": "remove-properties", "removals": ["comment"] }
+            ]
+        });
+        let response =
+            request_update_table("my_catalog", "update_table_actions", "events", request)
+                .await
+                .expect("Failed to update table");
+        let metadata = &response["metadata"];
+        assert_eq!(metadata["current-schema-id"], 1);
+        assert_eq!(metadata["last-column-id"], 2);
+        assert_eq!(metadata["default-spec-id"], 1);
+        assert_eq!(
+            metadata["partition-specs"][1]["fields"][0]["field-id"],
+            1000
+        );
+        assert_eq!(metadata["last-partition-id"], 1000);
+        assert_eq!(metadata["current-snapshot-id"], 3051729675574597004i64);
+        assert_eq!(metadata["last-sequence-number"], 1);
+        assert_eq!(metadata["last-updated-ms"], 1515100955770i64);
+        assert_eq!(
+            metadata["refs"]["main"]["snapshot-id"],
+            3051729675574597004i64
+        );
+        assert_eq!(
+            metadata["snapshot-log"][0]["snapshot-id"],
+            3051729675574597004i64
+        );
+        assert_eq!(metadata["properties"], serde_json::json!({ "owner": "me" }));
+
+        // The new metadata file is written next to the previous one
+        let metadata_location = response["metadata-location"].as_str().unwrap();
+        assert!(metadata_location.starts_with(&(location("actions") + "/metadata/00001-")));
+        let metadata_file = std::fs::read(metadata_location.strip_prefix("file://").unwrap())
+            .expect("Failed to read metadata file");
+        let metadata_file: serde_json::Value = serde_json::from_slice(&metadata_file).unwrap();
+        assert_eq!(&metadata_file, metadata);
+
+        let response = request_load_table("my_catalog", "update_table_actions", "events")
+            .await
+            .expect("Failed to load table");
+        assert_eq!(response["metadata-location"], metadata_location);
+
+        // Invalid updates are rejected without changing the table
+        let request = serde_json::json!({
+            "requirements": [],
+            "updates": [{ "action": "set-current-schema", "schema-id": 7 }]
+        });
+        let response =
+            request_update_table("my_catalog", "update_table_actions", "events", request).await;
+        assert_eq!(response, Err(reqwest::StatusCode::BAD_REQUEST));
+
+        apis::catalog_api_api::drop_table(
             &configuration(),
             "my_catalog",
-            "list_tables",
-            Some(request1),
+            "update_table_actions",
+            "events",
+            Some(false),
         )
         .await
-        .expect("Failed to create table");
-        let mut request2 = models::CreateTableRequest::new(
-            "list_tables2".to_owned(),
-            Schema::new(schema::RHashType::default(), vec![]),
-        );
-        request2.location = Some("s3://path/to/location".into());
-        apis::catalog_api_api::create_table(
+        .expect("Failed to drop table");
+    }
+
+    #[tokio::test]
+    async fn concurrent_updates() {
+        let namespace_request = models::CreateNamespaceRequest {
+            namespace: vec!["concurrent_updates".to_owned()],
+            properties: None,
+        };
+        apis::catalog_api_api::create_namespace(
             &configuration(),
             "my_catalog",
-            "list_tables",
-            Some(request2),
+            Some(namespace_request),
         )
         .await
-        .expect("Failed to create table");
-        let response =
-            apis::catalog_api_api::list_tables(&configuration(), "my_catalog", "list_tables")
-                .await
-                .expect("Failed to create table");
+        .expect("Failed to create namespace");
+
+        let mut create_request = models::CreateTableRequest::new(
+            "events".to_owned(),
+            Schema::new(schema::RHashType::default(), vec![]),
+        );
+        create_request.location = Some(location("concurrent"));
+        request_create_table("my_catalog", "concurrent_updates", Some(create_request))
+            .await
+            .expect("Failed to create table");
+
+        let responses = futures::future::join_all((0..8).map(|i| {
+            let request = serde_json::json!({
+                "requirements": [],
+                "updates": [{ "action": "set-properties", "updates": { "writer": i.to_string() } }]
+            });
+            request_update_table("my_catalog", "concurrent_updates", "events", request)
+        }))
+        .await;
+
+        // Commits that lost the race fail instead of overwriting the winner
+        let committed = responses
+            .into_iter()
+            .filter_map(|response| match response {
+                Ok(response) => Some(response),
+                Err(status) => {
+                    assert_eq!(status, reqwest::StatusCode::CONFLICT);
+                    None
+                }
+            })
+            .collect::<Vec<_>>();
+        assert!(!committed.is_empty());
+
+        let response = request_load_table("my_catalog", "concurrent_updates", "events")
+            .await
+            .expect("Failed to load table");
         assert_eq!(
-            response
-                .identifiers
+            response["metadata"]["metadata-log"]
+                .as_array()
                 .unwrap()
-                .into_iter()
-                .map(|x| x.name)
-                .collect::<Vec<_>>(),
-            vec!["list_tables1", "list_tables2"]
+                .len(),
+            committed.len()
         );
+        let mut previous = committed
+            .iter()
+            .map(|response| {
+                response["metadata"]["metadata-log"]
+                    .as_array()
+                    .unwrap()
+                    .last()
+                    .unwrap()["metadata-file"]
+                    .clone()
+            })
+            .collect::<Vec<_>>();
+        previous.sort_by_key(|location| location.to_string());
+        previous.dedup();
+        assert_eq!(previous.len(), committed.len());
+
+        apis::catalog_api_api::drop_table(
+            &configuration(),
+            "my_catalog",
+            "concurrent_updates",
+            "events",
+            Some(false),
+        )
+        .await
+        .expect("Failed to drop table");
     }
 
     #[tokio::test]
-    async fn load_table() {
+    async fn table_commits() {
         let namespace_request = models::CreateNamespaceRequest {
-            namespace: vec!["load_table".to_owned()],
+            namespace: vec!["table_commits".to_owned()],
             properties: None,
         };
         let _ = apis::catalog_api_api::create_namespace(
@@ -1143,36 +2913,100 @@ pub mod tests {
         )
         .await;
 
-        let mut request = models::CreateTableRequest::new(
-            "load_table".to_owned(),
+        let mut create_request = models::CreateTableRequest::new(
+            "table_commits".to_owned(),
             Schema::new(schema::RHashType::default(), vec![]),
         );
-        request.location = Some("s3://path/to/location".into());
-        apis::catalog_api_api::create_table(
-            &configuration(),
-            "my_catalog",
-            "load_table",
-            Some(request),
-        )
-        .await
-        .expect("Failed to create table");
+        create_request.location = Some(location("table_commits"));
+        let create_response =
+            request_create_table("my_catalog", "table_commits", Some(create_request))
+                .await
+                .expect("Failed to create table");
+
+        let mut metadata_locations = vec![create_response["metadata-location"].clone()];
+        for i in 0..4 {
+            let request = serde_json::json!({
+                "requirements": [],
+                "updates": [{"action": "set-properties", "updates": {"commit": i.to_string()}}]
+            });
+            let response =
+                request_update_table("my_catalog", "table_commits", "table_commits", request)
+                    .await
+                    .expect("Failed to update table");
+            metadata_locations.push(response["metadata-location"].clone());
+        }
+
+        let commits_url =
+            "http://localhost:8080/v1/my_catalog/namespaces/table_commits/tables/table_commits/commits";
+        let mut commits = vec![];
+        let mut page_token = None;
+        loop {
+            let mut request = configuration()
+                .client
+                .get(commits_url)
+                .query(&[("pageSize", "2")]);
+            if let Some(page_token) = &page_token {
+                request = request.query(&[("pageToken", page_token)]);
+            }
+            let page = table_request(request)
+                .await
+                .expect("Failed to list commits");
+            let page_commits = page["commits"].as_array().unwrap();
+            assert!(page_commits.len() <= 2);
+            commits.extend(page_commits.iter().cloned());
+            match page["next-page-token"].as_str() {
+                Some(next_page_token) => page_token = Some(next_page_token.to_owned()),
+                None => break,
+            }
+        }
+
+        // Newest first, each commit pointing back to the one before
+        metadata_locations.reverse();
+        assert_eq!(commits.len(), metadata_locations.len());
+        for (i, commit) in commits.iter().enumerate() {
+            assert_eq!(commit["metadata-location"], metadata_locations[i]);
+            assert_eq!(
+                commit.get("previous-metadata-location"),
+                metadata_locations.get(i + 1)
+            );
+            assert!(commit["timestamp-ms"].as_i64().unwrap() > 0);
+            assert!(commit["span-id"].is_string());
+            assert_eq!(commit["principal"], "cosmo");
+        }
+
+        assert_eq!(
+            table_request(
+                configuration()
+                    .client
+                    .get(commits_url)
+                    .query(&[("pageToken", "invalid")])
+            )
+            .await,
+            Err(reqwest::StatusCode::BAD_REQUEST)
+        );
+        assert_eq!(
+            table_request(configuration().client.get(
+                "http://localhost:8080/v1/my_catalog/namespaces/table_commits/tables/missing/commits"
+            ))
+            .await,
+            Err(reqwest::StatusCode::NOT_FOUND)
+        );
 
-        let response = apis::catalog_api_api::load_table(
+        apis::catalog_api_api::drop_table(
             &configuration(),
             "my_catalog",
-            "load_table",
-            "load_table",
+            "table_commits",
+            "table_commits",
+            Some(true),
         )
         .await
-        .expect("Failed to create namespace");
-
-        assert_eq!(response.metadata_location.unwrap(), "s3://path/to/location")
+        .expect("Failed to delete table");
     }
 
     #[tokio::test]
-    async fn update_table() {
+    async fn stage_create() {
         let namespace_request = models::CreateNamespaceRequest {
-            namespace: vec!["update_table".to_owned()],
+            namespace: vec!["stage_create".to_owned()],
             properties: None,
         };
         let _ = apis::catalog_api_api::create_namespace(
@@ -1182,74 +3016,85 @@ pub mod tests {
         )
         .await;
 
-        let mut create_request = models::CreateTableRequest::new(
-            "update_table".to_owned(),
-            Schema::new(schema::RHashType::default(), vec![]),
+        let mut staged = vec![];
+        for name in ["staged", "abandoned"] {
+            let mut create_request = models::CreateTableRequest::new(
+                name.to_owned(),
+                Schema::new(schema::RHashType::default(), vec![]),
+            );
+            create_request.location = Some(location(name));
+            create_request.stage_create = Some(true);
+            let response = request_create_table("my_catalog", "stage_create", Some(create_request))
+                .await
+                .expect("Failed to stage table");
+            assert!(response.get("metadata-location").is_none());
+            assert_eq!(response["metadata"]["location"], location(name));
+            staged.push(response);
+        }
+
+        // Staged tables are not visible
+        let tables =
+            apis::catalog_api_api::list_tables(&configuration(), "my_catalog", "stage_create")
+                .await
+                .expect("Failed to list tables");
+        assert!(tables.identifiers.unwrap_or_default().is_empty());
+        assert_eq!(
+            request_load_table("my_catalog", "stage_create", "staged").await,
+            Err(reqwest::StatusCode::NOT_FOUND)
         );
-        create_request.location = Some("s3://path/to/location1".into());
-        let create_response = apis::catalog_api_api::create_table(
-            &configuration(),
-            "my_catalog",
-            "update_table",
-            Some(create_request),
-        )
-        .await
-        .expect("Failed to create table");
 
-        let request = models::CommitTableRequest::new(
-            vec![],
-            vec![models::TableUpdate::new(
-                models::table_update::Action::SetLocation,
-                create_response.metadata.format_version,
-                create_response
-                    .metadata
-                    .schemas
-                    .map(|x| x[0].clone())
-                    .unwrap_or_else(|| models::Schema::new(schema::RHashType::default(), vec![])),
-                create_response.metadata.current_schema_id.unwrap_or(0),
-                create_response
-                    .metadata
-                    .partition_specs
-                    .map(|x| x[0].clone())
-                    .unwrap_or_else(|| models::PartitionSpec::default()),
-                create_response.metadata.default_spec_id.unwrap_or(0),
-                create_response
-                    .metadata
-                    .sort_orders
-                    .map(|x| x[0].clone())
-                    .unwrap_or_else(|| models::SortOrder::default()),
-                create_response.metadata.default_sort_order_id.unwrap_or(0),
-                create_response
-                    .metadata
-                    .snapshots
-                    .map(|x| x[0].clone())
-                    .unwrap_or_else(|| models::Snapshot::default()),
-                models::table_update::RHashType::default(),
-                create_response.metadata.current_snapshot_id.unwrap_or(0) as i64,
-                "update_table".into(),
-                vec![],
-                "s3://path/to/location2".into(),
-                HashMap::new(),
-                vec![],
-            )],
+        let request = serde_json::json!({
+            "requirements": [{"requirement": "assert-create"}],
+            "updates": [{"action": "set-properties", "updates": {"created-by": "ctas"}}]
+        });
+        let response =
+            request_update_table("my_catalog", "stage_create", "staged", request.clone())
+                .await
+                .expect("Failed to create staged table");
+        assert!(response["metadata-location"]
+            .as_str()
+            .unwrap()
+            .starts_with(&format!("{}/metadata/00000-", location("staged"))));
+        assert_eq!(
+            response["metadata"]["table-uuid"],
+            staged[0]["metadata"]["table-uuid"]
+        );
+        assert_eq!(response["metadata"]["properties"]["created-by"], "ctas");
+
+        let loaded = request_load_table("my_catalog", "stage_create", "staged")
+            .await
+            .expect("Failed to load table");
+        assert_eq!(loaded["metadata-location"], response["metadata-location"]);
+
+        // The table exists now and can't be created again
+        assert_eq!(
+            request_update_table("my_catalog", "stage_create", "staged", request.clone()).await,
+            Err(reqwest::StatusCode::CONFLICT)
+        );
+        assert_eq!(
+            request_update_table("my_catalog", "stage_create", "missing", request).await,
+            Err(reqwest::StatusCode::NOT_FOUND)
         );
-        let response = apis::catalog_api_api::update_table(
-            &configuration(),
-            "my_catalog",
-            "update_table",
-            "update_table",
-            Some(request),
-        )
-        .await
-        .expect("Failed to create table");
 
-        assert_eq!(response.metadata_location, "s3://path/to/location2");
+        let tables =
+            apis::catalog_api_api::list_tables(&configuration(), "my_catalog", "stage_create")
+                .await
+                .expect("Failed to list tables");
+        assert_eq!(
+            tables
+                .identifiers
+                .unwrap()
+                .into_iter()
+                .map(|identifier| identifier.name)
+                .collect::<Vec<_>>(),
+            vec!["staged".to_owned()]
+        );
 
         apis::catalog_api_api::drop_table(
             &configuration(),
             "my_catalog",
-            "update_table",
-            "update_table",
+            "stage_create",
+            "staged",
             Some(true),
         )
         .await
@@ -1273,15 +3118,10 @@ pub mod tests {
             "rename_table1".to_owned(),
             Schema::new(schema::RHashType::default(), vec![]),
         );
-        create_request.location = Some("s3://path/to/location1".into());
-        apis::catalog_api_api::create_table(
-            &configuration(),
-            "my_catalog",
-            "rename_table",
-            Some(create_request),
-        )
-        .await
-        .expect("Failed to create table");
+        create_request.location = Some(location("location1"));
+        request_create_table("my_catalog", "rename_table", Some(create_request))
+            .await
+            .expect("Failed to create table");
 
         let request = models::RenameTableRequest::new(
             models::TableIdentifier {
@@ -1317,6 +3157,124 @@ pub mod tests {
         .expect("Failed to delete table");
     }
 
+    #[tokio::test]
+    async fn exact_identifiers() {
+        for namespace in ["exact_identifiers_ab", "exact_identifiers_a"] {
+            let request = models::CreateNamespaceRequest {
+                namespace: vec![namespace.to_owned()],
+                properties: None,
+            };
+            apis::catalog_api_api::create_namespace(&configuration(), "my_catalog", Some(request))
+                .await
+                .expect("Failed to create namespace");
+        }
+
+        let mut create_request = models::CreateTableRequest::new(
+            "sales_archive".to_owned(),
+            Schema::new(schema::RHashType::default(), vec![]),
+        );
+        create_request.location = Some(location("location1"));
+        request_create_table("my_catalog", "exact_identifiers_a", Some(create_request))
+            .await
+            .expect("Failed to create table");
+
+        request_load_table("my_catalog", "exact_identifiers_a", "sales")
+            .await
+            .expect_err("Loaded a table with a different name");
+
+        apis::catalog_api_api::table_exists(
+            &configuration(),
+            "my_catalog",
+            "exact_identifiers_ab",
+            "sales_archive",
+        )
+        .await
+        .expect_err("Found a table in a different namespace");
+
+        apis::catalog_api_api::list_tables(&configuration(), "my_catalog_", "exact_identifiers_a")
+            .await
+            .expect_err("Listed tables of a different catalog");
+
+        apis::catalog_api_api::drop_table(
+            &configuration(),
+            "my_catalog",
+            "exact_identifiers_a",
+            "sales_archive",
+            Some(false),
+        )
+        .await
+        .expect("Failed to drop table");
+
+        for namespace in ["exact_identifiers_ab", "exact_identifiers_a"] {
+            apis::catalog_api_api::drop_namespace(&configuration(), "my_catalog", namespace)
+                .await
+                .expect("Failed to drop namespace");
+        }
+    }
+
+    #[tokio::test]
+    async fn scoped_names() {
+        for prefix in ["my_catalog", "other_catalog"] {
+            for namespace in ["scoped_names1", "scoped_names2"] {
+                let request = models::CreateNamespaceRequest {
+                    namespace: vec![namespace.to_owned()],
+                    properties: None,
+                };
+                apis::catalog_api_api::create_namespace(&configuration(), prefix, Some(request))
+                    .await
+                    .expect("Failed to create namespace");
+            }
+        }
+
+        for namespace in ["scoped_names1", "scoped_names2"] {
+            let mut create_request = models::CreateTableRequest::new(
+                "events".to_owned(),
+                Schema::new(schema::RHashType::default(), vec![]),
+            );
+            create_request.location = Some(location("location"));
+            request_create_table("my_catalog", namespace, Some(create_request))
+                .await
+                .expect("Failed to create table");
+        }
+
+        apis::catalog_api_api::drop_table(
+            &configuration(),
+            "my_catalog",
+            "scoped_names1",
+            "events",
+            Some(false),
+        )
+        .await
+        .expect("Failed to drop table");
+
+        apis::catalog_api_api::table_exists(
+            &configuration(),
+            "my_catalog",
+            "scoped_names2",
+            "events",
+        )
+        .await
+        .expect("Dropped a table of a different namespace");
+
+        apis::catalog_api_api::drop_table(
+            &configuration(),
+            "my_catalog",
+            "scoped_names2",
+            "events",
+            Some(false),
+        )
+        .await
+        .expect("Failed to drop table");
+
+        for prefix in ["my_catalog", "other_catalog"] {
+            for namespace in ["scoped_names1", "scoped_names2"] {
+                apis::catalog_api_api::drop_namespace(&configuration(), prefix, namespace)
+                    .await
+                    .expect("Failed to drop namespace");
+            }
+        }
+    }
+
     #[tokio::test]
     async fn table_exists() {
         let namespace_request = models::CreateNamespaceRequest {
@@ -1334,23 +3292,562 @@ pub mod tests {
             "table_exists1".to_owned(),
             Schema::new(schema::RHashType::default(), vec![]),
         );
-        create_request.location = Some("s3://path/to/location1".into());
-        apis::catalog_api_api::create_table(
+        create_request.location = Some(location("location1"));
+        request_create_table("my_catalog", "table_exists", Some(create_request))
+            .await
+            .expect("Failed to create table");
+
+        apis::catalog_api_api::table_exists(
             &configuration(),
             "my_catalog",
             "table_exists",
-            Some(create_request),
+            "table_exists1",
         )
         .await
         .expect("Failed to create table");
+    }
 
-        apis::catalog_api_api::table_exists(
+    #[tokio::test]
+    async fn error_types() {
+        async fn error(request: reqwest::RequestBuilder) -> (u16, serde_json::Value) {
+            let response = request
+                .header("Content-Type", "application/json")
+                .send()
+                .await
+                .expect("Failed to send request");
+            let status = response.status().as_u16();
+            (
+                status,
+                serde_json::from_str(&response.text().await.unwrap()).unwrap(),
+            )
+        }
+        let client = configuration().client;
+
+        let (status, body) = error(
+            client
+                .post("http://localhost:8080/v1/my_catalog/namespaces")
+                .body(r#"{"namespace": ["error.types"]}"#),
+        )
+        .await;
+        assert_eq!(status, 400);
+        assert_eq!(body["type"], "BadRequestException");
+        assert_eq!(body["code"], 400);
+
+        let request = models::CreateNamespaceRequest {
+            namespace: vec!["error_types".to_owned()],
+            properties: None,
+        };
+        let _ =
+            apis::catalog_api_api::create_namespace(&configuration(), "my_catalog", Some(request))
+                .await;
+        let (status, body) = error(
+            client
+                .post("http://localhost:8080/v1/my_catalog/namespaces")
+                .body(r#"{"namespace": ["error_types"]}"#),
+        )
+        .await;
+        assert_eq!(status, 409);
+        assert_eq!(body["type"], "AlreadyExistsException");
+
+        let (status, body) = error(
+            client.get("http://localhost:8080/v1/my_catalog/namespaces/error_types/tables/missing"),
+        )
+        .await;
+        assert_eq!(status, 404);
+        assert_eq!(body["type"], "NoSuchTableException");
+        assert_eq!(
+            body["message"],
+            "The table error_types.missing does not exist."
+        );
+
+        let (status, body) =
+            error(client.get("http://localhost:8080/v1/my_catalog/namespaces/missing/tables"))
+                .await;
+        assert_eq!(status, 404);
+        assert_eq!(body["type"], "NoSuchNamespaceException");
+    }
+
+    #[tokio::test]
+    async fn report_metrics() {
+        let namespace_request = models::CreateNamespaceRequest {
+            namespace: vec!["report_metrics".to_owned()],
+            properties: None,
+        };
+        let _ = apis::catalog_api_api::create_namespace(
             &configuration(),
             "my_catalog",
-            "table_exists",
-            "table_exists1",
+            Some(namespace_request),
+        )
+        .await;
+        for name in ["events", "logs"] {
+            let mut create_request = models::CreateTableRequest::new(
+                name.to_owned(),
+                Schema::new(schema::RHashType::default(), vec![]),
+            );
+            create_request.location = Some(location(&format!("report_metrics_{}", name)));
+            let _ =
+                request_create_table("my_catalog", "report_metrics", Some(create_request)).await;
+        }
+
+        let report = |table: &str, report: serde_json::Value| {
+            let request = configuration()
+                .client
+                .post(format!(
+                    "http://localhost:8080/v1/my_catalog/namespaces/report_metrics/tables/{}/metrics",
+                    table
+                ))
+                .header("Content-Type", "application/json")
+                .body(report.to_string());
+            async move {
+                request
+                    .send()
+                    .await
+                    .expect("Failed to report metrics")
+                    .status()
+            }
+        };
+        let scan_report = |filter: serde_json::Value| {
+            serde_json::json!({
+                "report-type": "scan-report",
+                "table-name": "report_metrics.events",
+                "snapshot-id": 1,
+                "filter": filter,
+                "schema-id": 0,
+                "projected-field-ids": [1],
+                "projected-field-names": ["id"],
+                "metrics": {
+                    "result-data-files": { "unit": "count", "value": 2 },
+                    "total-planning-duration": {
+                        "time-unit": "nanoseconds", "count": 1, "total-duration": 100
+                    }
+                }
+            })
+        };
+        let eq = serde_json::json!({ "type": "eq", "term": "id", "value": 1 });
+        for filter in [eq.clone(), eq.clone(), serde_json::json!(true)] {
+            assert_eq!(report("events", scan_report(filter)).await, 204);
+        }
+        assert_eq!(report("logs", scan_report(eq.clone())).await, 204);
+        let commit_report = serde_json::json!({
+            "report-type": "commit-report",
+            "table-name": "report_metrics.events",
+            "snapshot-id": 2,
+            "sequence-number": 2,
+            "operation": "append",
+            "metrics": { "added-data-files": { "unit": "count", "value": 5 } }
+        });
+        assert_eq!(report("events", commit_report).await, 204);
+        assert_eq!(
+            report(
+                "events",
+                serde_json::json!({
+                    "report-type": "unknown-report",
+                    "table-name": "report_metrics.events",
+                    "snapshot-id": 1,
+                    "metrics": {}
+                })
+            )
+            .await,
+            400
+        );
+        assert_eq!(report("missing", scan_report(eq.clone())).await, 404);
+
+        let metrics_url = "http://localhost:8080/v1/my_catalog/namespaces/report_metrics/metrics";
+        let metrics = table_request(configuration().client.get(metrics_url))
+            .await
+            .expect("Failed to aggregate metrics");
+        let tables = metrics["tables"].as_array().unwrap();
+        assert_eq!(tables.len(), 2);
+        let events = &tables[0];
+        assert_eq!(events["identifier"]["name"], "events");
+        assert_eq!(events["scan-count"], 3);
+        assert_eq!(events["commit-count"], 1);
+        assert_eq!(events["counters"]["result-data-files"], 6);
+        assert_eq!(events["counters"]["added-data-files"], 5);
+        assert_eq!(events["timers"]["total-planning-duration"]["count"], 3);
+        assert_eq!(
+            events["timers"]["total-planning-duration"]["total-duration"],
+            300
+        );
+        assert_eq!(events["filters"][0]["filter"], eq);
+        assert_eq!(events["filters"][0]["count"], 2);
+        assert_eq!(tables[1]["scan-count"], 1);
+
+        let later = chrono::Utc::now().timestamp_millis() + 60_000;
+        let metrics = table_request(
+            configuration()
+                .client
+                .get(metrics_url)
+                .query(&[("table", "events".to_owned()), ("from", later.to_string())]),
         )
         .await
-        .expect("Failed to create table");
+        .expect("Failed to aggregate metrics");
+        assert_eq!(metrics["tables"].as_array().unwrap().len(), 1);
+        assert_eq!(metrics["tables"][0]["scan-count"], 0);
+
+        assert_eq!(
+            table_request(
+                configuration()
+                    .client
+                    .get(metrics_url)
+                    .query(&[("from", "2"), ("to", "1")]),
+            )
+            .await,
+            Err(reqwest::StatusCode::BAD_REQUEST)
+        );
+    }
+
+    #[tokio::test]
+    async fn get_token_errors() {
+        async fn error(form: &[(&str, &str)]) -> (u16, serde_json::Value) {
+            let response = configuration()
+                .client
+                .post("http://localhost:8080/v1/oauth/tokens")
+                .form(form)
+                .send()
+                .await
+                .expect("Failed to send request");
+            let status = response.status().as_u16();
+            (
+                status,
+                serde_json::from_str(&response.text().await.unwrap()).unwrap(),
+            )
+        }
+
+        let (status, body) = error(&[("client_id", "spark")]).await;
+        assert_eq!(status, 400);
+        assert_eq!(body["error"], "invalid_request");
+
+        let (status, body) = error(&[("grant_type", "password")]).await;
+        assert_eq!(status, 400);
+        assert_eq!(body["error"], "unsupported_grant_type");
+
+        let (status, body) = error(&[
+            ("grant_type", "client_credentials"),
+            ("client_id", "get_token_errors"),
+            ("client_secret", "secret"),
+            ("scope", "catalog"),
+        ])
+        .await;
+        assert_eq!(status, 401);
+        assert_eq!(body["error"], "invalid_client");
+
+        let (status, body) = error(&[("grant_type", "client_credentials")]).await;
+        assert_eq!(status, 401);
+        assert_eq!(body["error"], "invalid_client");
+
+        // Tokens of identity providers are only exchanged on behalf of an actor
+        let (status, body) = error(&[
+            (
+                "grant_type",
+                "urn:ietf:params:oauth:grant-type:token-exchange",
+            ),
+            ("subject_token", "eyJhbGciOiJub25lIn0.eyJzdWIiOiJhbGljZSJ9."),
+            (
+                "subject_token_type",
+                "urn:ietf:params:oauth:token-type:id_token",
+            ),
+        ])
+        .await;
+        assert_eq!(status, 400);
+        assert_eq!(body["error"], "invalid_request");
+    }
+
+    #[tokio::test]
+    async fn bearer_tokens() {
+        async fn error(request: reqwest::RequestBuilder) -> (u16, serde_json::Value) {
+            let response = request.send().await.expect("Failed to send request");
+            let status = response.status().as_u16();
+            (
+                status,
+                serde_json::from_str(&response.text().await.unwrap()).unwrap(),
+            )
+        }
+
+        let client = reqwest::Client::new();
+        let url = "http://localhost:8080/v1/my_catalog/namespaces";
+        let expired = {
+            let issuer = issuer(-3600);
+            issuer.sign(&issuer.claims(ADMIN, "catalog admin")).unwrap()
+        };
+        for request in [
+            client.get(url),
+            client.get(url).bearer_auth("not a token"),
+            client.get(url).bearer_auth(expired),
+            client
+                .get(url)
+                .bearer_auth(token(ADMIN, "catalog admin").replace('.', "x")),
+        ] {
+            let (status, body) = error(request).await;
+            assert_eq!(status, 401);
+            assert_eq!(body["type"], "NotAuthorizedException");
+            assert_eq!(body["code"], 401);
+        }
+
+        // Clients obtain their tokens without one
+        let (status, body) = error(
+            client
+                .post("http://localhost:8080/v1/oauth/tokens")
+                .form(&[("client_id", "spark")]),
+        )
+        .await;
+        assert_eq!(status, 400);
+        assert_eq!(body["error"], "invalid_request");
+    }
+
+    /// Sends a request and returns the status and the body, `Null` if empty.
+    async fn send(request: reqwest::RequestBuilder) -> (u16, serde_json::Value) {
+        let response = request
+            .header("Content-Type", "application/json")
+            .send()
+            .await
+            .expect("Failed to send request");
+        let status = response.status().as_u16();
+        let body = response.text().await.unwrap();
+        (status, serde_json::from_str(&body).unwrap_or_default())
+    }
+
+    /// Creates the namespaces and the tables of the namespaces as admin.
+    async fn create_tables(namespaces: &[(&str, &[&str])]) {
+        let client = configuration().client;
+        for (namespace, tables) in namespaces {
+            let levels = namespace.split('.').collect::<Vec<_>>();
+            let (status, _) = send(
+                client
+                    .post("http://localhost:8080/v1/my_catalog/namespaces")
+                    .body(serde_json::json!({ "namespace": levels }).to_string()),
+            )
+            .await;
+            assert_eq!(status, 200);
+            for table in *tables {
+                let schema = serde_json::json!({
+                    "type": "struct",
+                    "fields": [{ "id": 1, "name": "id", "required": true, "type": "long" }]
+                });
+                let (status, _) = send(
+                    client
+                        .post(format!(
+                            "http://localhost:8080/v1/my_catalog/namespaces/{}/tables",
+                            namespace
+                        ))
+                        .body(serde_json::json!({ "name": table, "schema": schema }).to_string()),
+                )
+                .await;
+                assert_eq!(status, 200);
+            }
+        }
+    }
+
+    /// Drops the tables and namespaces as admin, innermost namespace first.
+    async fn drop_tables(namespaces: &[(&str, &[&str])]) {
+        let client = configuration().client;
+        for (namespace, tables) in namespaces.iter().rev() {
+            for table in *tables {
+                client
+                    .delete(format!(
+                        "http://localhost:8080/v1/my_catalog/namespaces/{}/tables/{}",
+                        namespace, table
+                    ))
+                    .send()
+                    .await
+                    .expect("Failed to drop table");
+            }
+            client
+                .delete(format!(
+                    "http://localhost:8080/v1/my_catalog/namespaces/{}",
+                    namespace
+                ))
+                .send()
+                .await
+                .expect("Failed to drop namespace");
+        }
+    }
+
+    /// Makes the principal the only member of the role.
+    async fn put_role(role: &str, principal: &str) {
+        let (status, _) = send(
+            configuration()
+                .client
+                .put(format!("http://localhost:8080/v1/admin/roles/{}", role))
+                .body(serde_json::json!({ "principals": [principal] }).to_string()),
+        )
+        .await;
+        assert_eq!(status, 200);
+    }
+
+    /// Grants or revokes a privilege on the catalog, a namespace or a table.
+    async fn update_grant(
+        role: &str,
+        action: &str,
+        privilege: &str,
+        namespace: Option<&str>,
+        table: Option<&str>,
+    ) {
+        let mut grant = serde_json::json!({ "privilege": privilege, "catalog": "my_catalog" });
+        if let Some(namespace) = namespace {
+            grant["namespace"] = serde_json::json!(namespace.split('.').collect::<Vec<_>>());
+        }
+        if let Some(table) = table {
+            grant["table"] = serde_json::json!(table);
+        }
+        let (status, body) = send(
+            configuration()
+                .client
+                .post(format!(
+                    "http://localhost:8080/v1/admin/roles/{}/grants",
+                    role
+                ))
+                .body(serde_json::json!({ action: [grant] }).to_string()),
+        )
+        .await;
+        assert_eq!(status, 200, "{}", body);
+    }
+
+    #[tokio::test]
+    async fn grant_inheritance() {
+        let namespaces: &[(&str, &[&str])] = &[
+            ("grant_inheritance", &["orders"]),
+            ("grant_inheritance.nested", &["events", "customers"]),
+        ];
+        create_tables(namespaces).await;
+        put_role("grant_inheritance", "grant_inheritance_reader").await;
+
+        let reader = configuration_as("grant_inheritance_reader", "catalog").client;
+        let load = |namespace: &str, table: &str| {
+            send(reader.get(format!(
+                "http://localhost:8080/v1/my_catalog/namespaces/{}/tables/{}",
+                namespace, table
+            )))
+        };
+        let readable = || async {
+            let mut readable = Vec::new();
+            for (namespace, tables) in namespaces {
+                for table in *tables {
+                    if load(namespace, table).await.0 == 200 {
+                        readable.push(*table);
+                    }
+                }
+            }
+            readable
+        };
+
+        assert!(readable().await.is_empty());
+
+        let grants: [(Option<&str>, Option<&str>, &[&str]); 3] = [
+            (
+                Some("grant_inheritance.nested"),
+                Some("events"),
+                &["events"],
+            ),
+            (
+                Some("grant_inheritance.nested"),
+                None,
+                &["events", "customers"],
+            ),
+            (None, None, &["orders", "events", "customers"]),
+        ];
+        for (namespace, table, expected) in grants {
+            update_grant(
+                "grant_inheritance",
+                "grants",
+                "TABLE_READ",
+                namespace,
+                table,
+            )
+            .await;
+            assert_eq!(readable().await, expected);
+            update_grant(
+                "grant_inheritance",
+                "revokes",
+                "TABLE_READ",
+                namespace,
+                table,
+            )
+            .await;
+        }
+
+        // A grant on a namespace applies to its nested namespaces
+        update_grant(
+            "grant_inheritance",
+            "grants",
+            "TABLE_READ",
+            Some("grant_inheritance"),
+            None,
+        )
+        .await;
+        assert_eq!(readable().await, ["orders", "events", "customers"]);
+
+        send(
+            configuration()
+                .client
+                .delete("http://localhost:8080/v1/admin/roles/grant_inheritance"),
+        )
+        .await;
+        assert!(readable().await.is_empty());
+        drop_tables(namespaces).await;
+    }
+
+    #[tokio::test]
+    async fn grant_denial() {
+        let namespaces: &[(&str, &[&str])] = &[
+            ("grant_denial", &["events"]),
+            ("grant_denial_hidden", &["events"]),
+        ];
+        create_tables(namespaces).await;
+        put_role("grant_denial", "grant_denial_reader").await;
+        update_grant(
+            "grant_denial",
+            "grants",
+            "TABLE_READ",
+            Some("grant_denial"),
+            None,
+        )
+        .await;
+
+        let reader = configuration_as("grant_denial_reader", "catalog").client;
+        let table = "http://localhost:8080/v1/my_catalog/namespaces/grant_denial/tables/events";
+        assert_eq!(send(reader.get(table)).await.0, 200);
+
+        // Visible objects without the privilege are forbidden
+        let (status, body) = send(reader.delete(table)).await;
+        assert_eq!(status, 403);
+        assert_eq!(body["type"], "ForbiddenException");
+
+        // Objects without any privilege don't exist for the principal
+        let (status, body) = send(reader.get(
+            "http://localhost:8080/v1/my_catalog/namespaces/grant_denial_hidden/tables/events",
+        ))
+        .await;
+        assert_eq!(status, 404);
+        assert_eq!(body["type"], "NoSuchTableException");
+
+        // Only admins manage roles
+        let (status, _) = send(
+            reader
+                .put("http://localhost:8080/v1/admin/roles/grant_denial")
+                .body(serde_json::json!({ "principals": ["grant_denial_reader"] }).to_string()),
+        )
+        .await;
+        assert_eq!(status, 403);
+
+        // Tokens without the catalog scope hold no privileges, and admins act as
+        // admins only with the admin scope
+        let unscoped = configuration_as("grant_denial_reader", "").client;
+        assert_eq!(send(unscoped.get(table)).await.0, 403);
+        let admin = configuration_as(ADMIN, "catalog").client;
+        assert_eq!(
+            send(admin.get("http://localhost:8080/v1/admin/roles"))
+                .await
+                .0,
+            403
+        );
+
+        send(
+            configuration()
+                .client
+                .delete("http://localhost:8080/v1/admin/roles/grant_denial"),
+        )
+        .await;
+        drop_tables(namespaces).await;
     }
 }
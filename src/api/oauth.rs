@@ -0,0 +1,417 @@
+//! OAuth2 token endpoint.
+//!
+//! Clients registered in the `oauth_client` table exchange their ID and secret for
+//! an access token with the client credentials grant. With the token exchange of
+//! RFC 8693 a service trades its own token and a user's token for a delegated token
+//! of the user that names the service as actor, and clients refresh their tokens.
+//! Errors use the codes of RFC 6749, section 5.2. Secrets are never logged.
+
+use std::fmt;
+
+use log::error;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter,
+};
+
+use iceberg_catalog_rest_rdbms_server::{models, GetTokenResponse};
+
+use crate::auth::{self, Actor, TokenIssuer, Verifier};
+use crate::database::entities::{prelude::*, *};
+
+pub const CLIENT_CREDENTIALS: &str = "client_credentials";
+pub const TOKEN_EXCHANGE: &str = "urn:ietf:params:oauth:grant-type:token-exchange";
+
+/// Scope that allows an actor to exchange subject tokens not issued by the catalog,
+/// such as ID tokens of its users, for delegated tokens. The scope itself is never
+/// delegated.
+pub const DELEGATE_SCOPE: &str = "delegate";
+
+/// Scopes granted to clients registered without scopes.
+pub const DEFAULT_SCOPE: &str = super::access::CATALOG_SCOPE;
+
+/// Error of a token request.
+#[derive(Debug)]
+pub enum OAuthError {
+    /// A required parameter is missing or a parameter is invalid
+    InvalidRequest(String),
+    /// The client is unknown or its secret is wrong
+    InvalidClient,
+    /// The grant type is not supported by the catalog
+    UnsupportedGrantType(String),
+    /// A requested scope is not granted to the client
+    InvalidScope(String),
+    /// Any server-side failure
+    ServerError(String),
+}
+
+impl OAuthError {
+    /// The OAuth2 error code.
+    pub fn code(&self) -> &'static str {
+        match self {
+            OAuthError::InvalidRequest(_) => "invalid_request",
+            OAuthError::InvalidClient => "invalid_client",
+            OAuthError::UnsupportedGrantType(_) => "unsupported_grant_type",
+            OAuthError::InvalidScope(_) => "invalid_scope",
+            OAuthError::ServerError(_) => "server_error",
+        }
+    }
+
+    /// The description sent to the client.
+    pub fn description(&self) -> String {
+        match self {
+            OAuthError::InvalidClient => "Client authentication failed".to_owned(),
+            OAuthError::ServerError(_) => "The token could not be issued".to_owned(),
+            _ => self.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for OAuthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OAuthError::InvalidClient => write!(f, "Client authentication failed"),
+            OAuthError::InvalidRequest(message)
+            | OAuthError::UnsupportedGrantType(message)
+            | OAuthError::InvalidScope(message)
+            | OAuthError::ServerError(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl From<sea_orm::DbErr> for OAuthError {
+    fn from(err: sea_orm::DbErr) -> Self {
+        OAuthError::ServerError(err.to_string())
+    }
+}
+
+impl From<OAuthError> for GetTokenResponse {
+    fn from(err: OAuthError) -> Self {
+        if let OAuthError::ServerError(message) = &err {
+            error!("Failed to issue a token: {}", message);
+        }
+        let response = models::GetToken400Response {
+            error: err.code().to_owned(),
+            error_description: Some(err.description()),
+            error_uri: None,
+        };
+        match err {
+            OAuthError::InvalidClient => GetTokenResponse::OAuth_3(response),
+            OAuthError::ServerError(_) => GetTokenResponse::OAuth_4(response),
+            _ => GetTokenResponse::OAuth_2(response),
+        }
+    }
+}
+
+/// Issues a token for a client that authenticates with its ID and secret.
+pub async fn client_credentials(
+    db: &DatabaseConnection,
+    issuer: &TokenIssuer,
+    client_id: Option<String>,
+    client_secret: Option<String>,
+    scope: Option<String>,
+) -> Result<models::GetToken200Response, OAuthError> {
+    let (client_id, client_secret) = match (client_id, client_secret) {
+        (Some(client_id), Some(client_secret)) => (client_id, client_secret),
+        _ => return Err(OAuthError::InvalidClient),
+    };
+
+    let client = OauthClient::find()
+        .filter(oauth_client::Column::ClientId.eq(client_id.as_str()))
+        .one(db)
+        .await?
+        .ok_or(OAuthError::InvalidClient)?;
+
+    // Hashing is deliberately slow, so it must not block the runtime
+    let hash = client.secret_hash.clone();
+    let valid = tokio::task::spawn_blocking(move || auth::verify_secret(&client_secret, &hash))
+        .await
+        .map_err(|err| OAuthError::ServerError(err.to_string()))?;
+    if !valid {
+        return Err(OAuthError::InvalidClient);
+    }
+
+    let scope = granted_scope(&client.scopes, scope.as_deref())?;
+    issue(issuer, &issuer.claims(&client.client_id, &scope))
+}
+
+/// Exchanges a subject token, and the token of the actor on its behalf, for a new
+/// access token of the subject.
+///
+/// Without an actor the subject token must have been issued by the catalog, which
+/// refreshes it. With an actor the new token names the actor in its `act` claim.
+/// Subject tokens not issued by the catalog are only accepted from actors with the
+/// [`DELEGATE_SCOPE`] and must pass the `verifier`, which checks them against the
+/// keys, issuers and audiences of the configured identity providers.
+///
+/// The new token is limited to the scopes of both tokens if the catalog issued the
+/// subject token, and to the scopes of the actor otherwise. The delegate scope is
+/// never passed on to a token with an actor. Fewer scopes can be requested to
+/// down-scope the token further.
+#[allow(clippy::too_many_arguments)]
+pub fn token_exchange(
+    issuer: &TokenIssuer,
+    verifier: &Verifier,
+    scope: Option<String>,
+    requested_token_type: Option<models::TokenType>,
+    subject_token: Option<String>,
+    subject_token_type: Option<models::TokenType>,
+    actor_token: Option<String>,
+    actor_token_type: Option<models::TokenType>,
+) -> Result<models::GetToken200Response, OAuthError> {
+    match requested_token_type {
+        None | Some(models::TokenType::AccessToken) | Some(models::TokenType::Jwt) => (),
+        Some(token_type) => {
+            return Err(OAuthError::InvalidRequest(format!(
+                "Token type {} can't be issued",
+                token_type
+            )))
+        }
+    }
+    let subject_token = match (subject_token, subject_token_type) {
+        (Some(subject_token), Some(_)) => subject_token,
+        _ => {
+            return Err(OAuthError::InvalidRequest(
+                "Missing parameter subject_token or subject_token_type".to_owned(),
+            ))
+        }
+    };
+    let actor =
+        match (actor_token, actor_token_type) {
+            (Some(actor_token), Some(_)) => Some(issuer.verify(&actor_token).map_err(|_| {
+                OAuthError::InvalidRequest("The actor token is invalid".to_owned())
+            })?),
+            (Some(_), None) => {
+                return Err(OAuthError::InvalidRequest(
+                    "Missing parameter actor_token_type".to_owned(),
+                ))
+            }
+            (None, _) => None,
+        };
+
+    let (subject, available, previous_actor) = match (issuer.verify(&subject_token), &actor) {
+        (Ok(subject), Some(actor)) => (
+            subject.sub,
+            intersection(&subject.scope, &actor.scope),
+            subject.act,
+        ),
+        (Ok(subject), None) => (subject.sub, subject.scope, subject.act),
+        (Err(_), Some(actor)) if actor.scope.split_whitespace().any(|s| s == DELEGATE_SCOPE) => {
+            let subject = verifier
+                .verify(&subject_token)
+                .map_err(|_| OAuthError::InvalidRequest("The subject token is invalid".to_owned()))?
+                .subject;
+            if subject.is_empty() {
+                return Err(OAuthError::InvalidRequest(
+                    "The subject token has no subject".to_owned(),
+                ));
+            }
+            (subject, actor.scope.clone(), None)
+        }
+        (Err(_), _) => {
+            return Err(OAuthError::InvalidRequest(
+                "The subject token is invalid".to_owned(),
+            ))
+        }
+    };
+    let available = available
+        .split_whitespace()
+        .filter(|scope| *scope != DELEGATE_SCOPE || actor.is_none())
+        .collect::<Vec<_>>()
+        .join(" ");
+    let scope = granted_scope(&available, scope.as_deref())?;
+
+    let mut claims = issuer.claims(&subject, &scope);
+    claims.act = match actor {
+        Some(actor) => Some(Actor {
+            sub: actor.sub,
+            act: previous_actor.map(Box::new),
+        }),
+        None => previous_actor,
+    };
+    issue(issuer, &claims)
+}
+
+/// Signs the claims and returns them as token response.
+fn issue(
+    issuer: &TokenIssuer,
+    claims: &auth::Claims,
+) -> Result<models::GetToken200Response, OAuthError> {
+    let access_token = issuer
+        .sign(claims)
+        .map_err(|err| OAuthError::ServerError(err.to_string()))?;
+
+    Ok(models::GetToken200Response {
+        access_token,
+        token_type: "bearer".to_owned(),
+        expires_in: Some(issuer.lifetime() as isize),
+        issued_token_type: Some(models::TokenType::AccessToken),
+        refresh_token: None,
+        scope: Some(claims.scope.clone()),
+    })
+}
+
+/// Returns the scopes contained in both space separated lists.
+fn intersection(left: &str, right: &str) -> String {
+    let right: Vec<&str> = right.split_whitespace().collect();
+    left.split_whitespace()
+        .filter(|scope| right.contains(scope))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Returns the requested scopes if the client may request all of them, or all
+/// scopes of the client if none are requested.
+fn granted_scope(allowed: &str, requested: Option<&str>) -> Result<String, OAuthError> {
+    let requested = match requested.map(str::trim) {
+        Some(requested) if !requested.is_empty() => requested,
+        _ => return Ok(allowed.to_owned()),
+    };
+    let allowed: Vec<&str> = allowed.split_whitespace().collect();
+    let mut granted: Vec<&str> = Vec::new();
+    for scope in requested.split_whitespace() {
+        if !allowed.contains(&scope) {
+            return Err(OAuthError::InvalidScope(format!(
+                "Scope {} is not granted to the client",
+                scope
+            )));
+        }
+        if !granted.contains(&scope) {
+            granted.push(scope);
+        }
+    }
+    Ok(granted.join(" "))
+}
+
+/// Registers a client or replaces the secret and scopes of an existing one.
+pub async fn register_client(
+    db: &DatabaseConnection,
+    client_id: &str,
+    client_secret: &str,
+    scopes: &str,
+) -> Result<(), OAuthError> {
+    let secret_hash =
+        auth::hash_secret(client_secret).map_err(|err| OAuthError::ServerError(err.to_string()))?;
+
+    match OauthClient::find()
+        .filter(oauth_client::Column::ClientId.eq(client_id))
+        .one(db)
+        .await?
+    {
+        Some(client) => {
+            let mut client: oauth_client::ActiveModel = client.into();
+            client.secret_hash = ActiveValue::Set(secret_hash);
+            client.scopes = ActiveValue::Set(scopes.to_owned());
+            client.update(db).await?;
+        }
+        None => {
+            let client = oauth_client::ActiveModel {
+                id: ActiveValue::NotSet,
+                client_id: ActiveValue::Set(client_id.to_owned()),
+                secret_hash: ActiveValue::Set(secret_hash),
+                scopes: ActiveValue::Set(scopes.to_owned()),
+                created_at: ActiveValue::Set(chrono::Utc::now().into()),
+            };
+            OauthClient::insert(client).exec(db).await?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use iceberg_catalog_rest_rdbms_server::models::TokenType;
+
+    use crate::auth::{TokenIssuer, Verifier};
+
+    use super::{granted_scope, token_exchange};
+
+    #[test]
+    fn scopes() {
+        assert_eq!(
+            granted_scope("catalog admin", None).unwrap(),
+            "catalog admin"
+        );
+        assert_eq!(
+            granted_scope("catalog admin", Some(" ")).unwrap(),
+            "catalog admin"
+        );
+        assert_eq!(
+            granted_scope("catalog admin", Some("catalog catalog")).unwrap(),
+            "catalog"
+        );
+        assert_eq!(
+            granted_scope("catalog", Some("catalog admin"))
+                .unwrap_err()
+                .code(),
+            "invalid_scope"
+        );
+    }
+
+    #[test]
+    fn exchange() {
+        let issuer = TokenIssuer::new(b"key", "catalog".into(), "iceberg".into(), 3600);
+        let idp = TokenIssuer::new(b"idp-key", "idp".into(), "iceberg".into(), 3600);
+        let verifier =
+            Verifier::new(vec!["idp".into()], vec!["iceberg".into()]).with_secret(b"idp-key");
+        let token =
+            |subject: &str, scope: &str| issuer.sign(&issuer.claims(subject, scope)).unwrap();
+        let exchange = |subject: String, actor: Option<String>, scope: Option<&str>| {
+            token_exchange(
+                &issuer,
+                &verifier,
+                scope.map(str::to_owned),
+                None,
+                Some(subject),
+                Some(TokenType::AccessToken),
+                actor.clone(),
+                actor.map(|_| TokenType::AccessToken),
+            )
+            .map(|response| issuer.verify(&response.access_token).unwrap())
+        };
+        let gateway = token("gateway", "catalog delegate");
+        // ID token of alice signed by the identity provider
+        let id_token = idp.sign(&idp.claims("alice", "")).unwrap();
+
+        let delegated = exchange(id_token.clone(), Some(gateway.clone()), None).unwrap();
+        assert_eq!(delegated.sub, "alice");
+        assert_eq!(delegated.act.as_ref().unwrap().sub, "gateway");
+        assert_eq!(delegated.scope, "catalog");
+
+        // Tokens of identity providers must be signed, current and meant for the catalog
+        let unsecured = "eyJhbGciOiJub25lIn0.eyJzdWIiOiJhbGljZSJ9.".to_owned();
+        let forged = TokenIssuer::new(b"other", "idp".into(), "iceberg".into(), 3600);
+        let expired = TokenIssuer::new(b"idp-key", "idp".into(), "iceberg".into(), -3600);
+        let elsewhere = TokenIssuer::new(b"idp-key", "idp".into(), "other".into(), 3600);
+        for subject in [
+            unsecured,
+            forged.sign(&forged.claims("alice", "")).unwrap(),
+            expired.sign(&expired.claims("alice", "")).unwrap(),
+            elsewhere.sign(&elsewhere.claims("alice", "")).unwrap(),
+        ] {
+            let err = exchange(subject, Some(gateway.clone()), None).unwrap_err();
+            assert_eq!(err.code(), "invalid_request");
+        }
+
+        // Only actors with the delegate scope vouch for tokens of other issuers
+        let service = token("service", "catalog");
+        let err = exchange(id_token.clone(), Some(service.clone()), None).unwrap_err();
+        assert_eq!(err.code(), "invalid_request");
+        assert_eq!(
+            exchange(id_token, None, None).unwrap_err().code(),
+            "invalid_request"
+        );
+
+        // Tokens are limited to the scopes of subject and actor
+        let alice = token("alice", "catalog admin");
+        let delegated = exchange(alice.clone(), Some(service.clone()), None).unwrap();
+        assert_eq!(delegated.scope, "catalog");
+        let err = exchange(alice, Some(service), Some("admin")).unwrap_err();
+        assert_eq!(err.code(), "invalid_scope");
+
+        // Refreshing keeps the actor
+        let refreshed = exchange(issuer.sign(&delegated).unwrap(), None, None).unwrap();
+        assert_eq!(refreshed.sub, "alice");
+        assert_eq!(refreshed.act.unwrap().sub, "service");
+        assert_ne!(refreshed.jti, delegated.jti);
+    }
+}
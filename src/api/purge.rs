@@ -0,0 +1,282 @@
+//! Deletion of the files of dropped tables.
+//!
+//! Dropping a table with `purgeRequested` records a purge in the same transaction
+//! that deletes the table, so the request can't get lost. The files are deleted in
+//! the background afterwards. Failed attempts are retried with exponential backoff
+//! and the outcome is recorded in `table_purge`. Purges interrupted by a restart
+//! are resumed when the server starts.
+//!
+//! Only the locations of the table that lie below the storage root and don't
+//! overlap with the locations of other tables are purged, as clients control the
+//! table location and the properties that place files elsewhere.
+
+use std::time::Duration;
+
+use log::{error, info, warn};
+use sea_orm::sea_query::Expr;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue, ColumnTrait, ConnectionTrait, DatabaseConnection, DbErr,
+    EntityTrait, QueryFilter,
+};
+
+use iceberg_catalog_rest_rdbms_server::models;
+
+use crate::database::entities::{prelude::*, *};
+use crate::io::{self, IoError};
+
+use super::metadata;
+
+/// Status of a purge whose files are not deleted yet.
+pub const PENDING: &str = "pending";
+/// Status of a purge whose files are deleted.
+pub const SUCCEEDED: &str = "succeeded";
+/// Status of a purge that was given up after the last attempt.
+pub const FAILED: &str = "failed";
+
+const MAX_ATTEMPTS: i32 = 5;
+
+/// Delay before the first retry. Every further retry waits twice as long.
+const INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+
+/// Table properties that place files outside the table location.
+const LOCATION_PROPERTIES: [&str; 4] = [
+    "write.data.path",
+    "write.metadata.path",
+    "write.object-storage.path",
+    "write.folder-storage.path",
+];
+
+/// Returns the locations below which the files of a table are stored and that
+/// can be deleted: those below the storage root that no other table uses. `live` are
+/// the locations of the other tables.
+pub fn locations(
+    table_name: &str,
+    metadata: &models::TableMetadata,
+    root: Option<&str>,
+    live: &[String],
+) -> Vec<String> {
+    let mut locations: Vec<String> = stored_locations(metadata)
+        .into_iter()
+        .filter(|location| {
+            if metadata::confine_location(location, root).is_err() {
+                warn!(
+                    "Not purging {} of table {}, which is outside of the storage root.",
+                    location, table_name
+                );
+                false
+            } else if live.iter().any(|other| overlap(location, other)) {
+                warn!(
+                    "Not purging {} of table {}, which another table uses.",
+                    location, table_name
+                );
+                false
+            } else {
+                true
+            }
+        })
+        .collect();
+    locations.sort();
+    locations.dedup();
+    locations
+}
+
+/// Returns the locations of all tables except one.
+pub async fn live_locations<C: ConnectionTrait>(
+    db: &C,
+    except_table_id: i32,
+) -> Result<Vec<String>, DbErr> {
+    let documents = TableMetadata::find()
+        .inner_join(IcebergTable)
+        .filter(
+            Expr::tbl(IcebergTable, iceberg_table::Column::MetadataLocation)
+                .equals(TableMetadata, table_metadata::Column::MetadataLocation),
+        )
+        .filter(iceberg_table::Column::Id.ne(except_table_id))
+        .all(db)
+        .await?;
+
+    let mut locations = Vec::new();
+    for document in documents {
+        let metadata: models::TableMetadata = serde_json::from_value(document.metadata)
+            .map_err(|err| DbErr::Json(err.to_string()))?;
+        locations.extend(stored_locations(&metadata));
+    }
+    Ok(locations)
+}
+
+/// Returns the table location and the locations the properties place files at.
+fn stored_locations(metadata: &models::TableMetadata) -> Vec<String> {
+    let properties = metadata.properties.as_ref();
+    metadata
+        .location
+        .iter()
+        .chain(
+            LOCATION_PROPERTIES
+                .iter()
+                .filter_map(|property| properties.and_then(|properties| properties.get(*property))),
+        )
+        .map(|location| location.trim_end_matches('/').to_owned())
+        .filter(|location| !location.is_empty())
+        .collect()
+}
+
+/// Whether one location is the other or lies below it.
+fn overlap(location: &str, other: &str) -> bool {
+    let other = other.trim_end_matches('/');
+    let below = |location: &str, ancestor: &str| {
+        location
+            .strip_prefix(ancestor)
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+    };
+    below(location, other) || below(other, location)
+}
+
+/// Records a purge of the locations of the table.
+pub async fn schedule<C: ConnectionTrait>(
+    db: &C,
+    table_name: &str,
+    locations: &[String],
+) -> Result<i32, DbErr> {
+    let now = chrono::Utc::now();
+    let purge = table_purge::ActiveModel {
+        id: ActiveValue::NotSet,
+        table_name: ActiveValue::Set(table_name.to_owned()),
+        locations: ActiveValue::Set(serde_json::json!(locations)),
+        status: ActiveValue::Set(PENDING.to_owned()),
+        attempts: ActiveValue::Set(0),
+        last_error: ActiveValue::Set(None),
+        created_at: ActiveValue::Set(now.into()),
+        updated_at: ActiveValue::Set(now.into()),
+    };
+
+    Ok(TablePurge::insert(purge).exec(db).await?.last_insert_id)
+}
+
+/// Runs a recorded purge in the background.
+pub fn spawn(db: DatabaseConnection, id: i32) {
+    tokio::spawn(async move {
+        if let Err(err) = run(&db, id).await {
+            error!("Purge {} could not be recorded: {}", id, err);
+        }
+    });
+}
+
+/// Runs all purges that are still pending, e.g. after a restart.
+pub async fn resume(db: DatabaseConnection) -> Result<(), DbErr> {
+    let purges = TablePurge::find()
+        .filter(table_purge::Column::Status.eq(PENDING))
+        .all(&db)
+        .await?;
+    for purge in purges {
+        spawn(db.clone(), purge.id);
+    }
+    Ok(())
+}
+
+async fn run(db: &DatabaseConnection, id: i32) -> Result<(), DbErr> {
+    let purge = TablePurge::find_by_id(id)
+        .one(db)
+        .await?
+        .ok_or_else(|| DbErr::RecordNotFound(format!("No purge with id {}.", id)))?;
+    let locations: Vec<String> = serde_json::from_value(purge.locations.clone())
+        .map_err(|err| DbErr::Json(err.to_string()))?;
+    let table_name = purge.table_name.clone();
+    let mut attempts = purge.attempts;
+    let mut purge: table_purge::ActiveModel = purge.into();
+
+    while attempts < MAX_ATTEMPTS {
+        if attempts > 0 {
+            tokio::time::sleep(INITIAL_BACKOFF * 2u32.pow(attempts as u32 - 1)).await;
+        }
+        attempts += 1;
+
+        let result = delete(&locations).await;
+        purge.attempts = ActiveValue::Set(attempts);
+        purge.updated_at = ActiveValue::Set(chrono::Utc::now().into());
+        match &result {
+            Ok(()) => {
+                info!("Purged the files of table {}.", table_name);
+                purge.status = ActiveValue::Set(SUCCEEDED.to_owned());
+                purge.last_error = ActiveValue::Set(None);
+            }
+            Err(err) => {
+                warn!(
+                    "Attempt {} to purge the files of table {} failed: {}",
+                    attempts, table_name, err
+                );
+                if attempts == MAX_ATTEMPTS {
+                    purge.status = ActiveValue::Set(FAILED.to_owned());
+                }
+                purge.last_error = ActiveValue::Set(Some(err.to_string()));
+            }
+        }
+        purge = purge.update(db).await?.into();
+
+        if result.is_ok() {
+            break;
+        }
+    }
+    Ok(())
+}
+
+async fn delete(locations: &[String]) -> Result<(), IoError> {
+    for location in locations {
+        io::file_io(location)?.delete_prefix(location).await?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use iceberg_catalog_rest_rdbms_server::models;
+
+    #[test]
+    fn locations() {
+        let mut metadata = models::TableMetadata::new(2, "uuid".to_owned());
+        metadata.location = Some("s3://warehouse/db/table/".to_owned());
+        metadata.properties = Some(HashMap::from([
+            (
+                "write.data.path".to_owned(),
+                "s3://data/db/table".to_owned(),
+            ),
+            (
+                "write.metadata.path".to_owned(),
+                "s3://warehouse/db/table".to_owned(),
+            ),
+            ("owner".to_owned(), "s3://elsewhere".to_owned()),
+        ]));
+
+        assert_eq!(
+            super::locations("db.table", &metadata, Some("s3://warehouse"), &[]),
+            vec!["s3://warehouse/db/table"]
+        );
+        assert_eq!(
+            super::locations("db.table", &metadata, Some("s3://"), &[]),
+            vec!["s3://data/db/table", "s3://warehouse/db/table"]
+        );
+    }
+
+    #[test]
+    fn shared_locations() {
+        let mut metadata = models::TableMetadata::new(2, "uuid".to_owned());
+        metadata.location = Some("s3://warehouse/db/table".to_owned());
+        metadata.properties = Some(HashMap::from([(
+            "write.data.path".to_owned(),
+            "s3://warehouse/db".to_owned(),
+        )]));
+
+        let live = ["s3://warehouse/db/other".to_owned()];
+        assert_eq!(
+            super::locations("db.table", &metadata, Some("s3://warehouse"), &live),
+            vec!["s3://warehouse/db/table"]
+        );
+        let live = ["s3://warehouse/db/table/nested".to_owned()];
+        assert!(super::locations("db.table", &metadata, Some("s3://warehouse"), &live).is_empty());
+        assert!(!super::overlap(
+            "s3://warehouse/db/table",
+            "s3://warehouse/db/table2"
+        ));
+    }
+}
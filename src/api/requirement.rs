@@ -0,0 +1,268 @@
+//! Evaluation of the requirements of a table commit.
+//!
+//! Clients send requirements with every commit to assert the state of the table
+//! their changes are based on. A commit is only applied if all of them hold for
+//! the current table metadata.
+
+use std::fmt;
+
+use iceberg_catalog_rest_rdbms_server::models;
+
+/// Error returned when a requirement can't be satisfied.
+#[derive(Debug, PartialEq)]
+pub enum RequirementError {
+    /// The requirement doesn't hold for the current metadata
+    Failed(String),
+    /// The requirement is unknown or lacks its value
+    Invalid(String),
+}
+
+impl fmt::Display for RequirementError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RequirementError::Failed(message) | RequirementError::Invalid(message) => {
+                f.write_str(message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for RequirementError {}
+
+/// Checks a requirement against the current metadata of the table.
+pub fn check(
+    requirement: &models::TableRequirement,
+    metadata: &models::TableMetadata,
+) -> Result<(), RequirementError> {
+    let name = requirement.requirement.as_str();
+    match name {
+        "assert-create" => Err(RequirementError::Failed(format!(
+            "Requirement failed: {}: the table already exists.",
+            name
+        ))),
+        "assert-table-uuid" => {
+            let uuid = value(name, "uuid", requirement.uuid.as_ref())?;
+            if uuid == &metadata.table_uuid {
+                Ok(())
+            } else {
+                Err(failed(name, "table uuid", &metadata.table_uuid, uuid))
+            }
+        }
+        "assert-ref-snapshot-id" => {
+            let r#ref = value(name, "ref", requirement.r#ref.as_ref())?;
+            let snapshot_id = ref_snapshot_id(metadata, r#ref);
+            match (snapshot_id, requirement.snapshot_id) {
+                (None, None) => Ok(()),
+                (Some(_), None) => Err(RequirementError::Failed(format!(
+                    "Requirement failed: {}: the ref {} already exists.",
+                    name, r#ref
+                ))),
+                (None, Some(_)) => Err(RequirementError::Failed(format!(
+                    "Requirement failed: {}: the ref {} does not exist.",
+                    name, r#ref
+                ))),
+                (Some(current), Some(expected)) if current == expected => Ok(()),
+                (Some(current), Some(expected)) => Err(failed(
+                    name,
+                    &format!("snapshot id of ref {}", r#ref),
+                    current,
+                    expected,
+                )),
+            }
+        }
+        "assert-last-assigned-field-id" => check_id(
+            name,
+            "last assigned field id",
+            metadata.last_column_id,
+            value(
+                name,
+                "last-assigned-field-id",
+                requirement.last_assigned_field_id.as_ref(),
+            )?,
+        ),
+        "assert-current-schema-id" => check_id(
+            name,
+            "current schema id",
+            metadata.current_schema_id,
+            value(
+                name,
+                "current-schema-id",
+                requirement.current_schema_id.as_ref(),
+            )?,
+        ),
+        "assert-last-assigned-partition-id" => check_id(
+            name,
+            "last assigned partition id",
+            metadata.last_partition_id,
+            value(
+                name,
+                "last-assigned-partition-id",
+                requirement.last_assigned_partition_id.as_ref(),
+            )?,
+        ),
+        "assert-default-spec-id" => check_id(
+            name,
+            "default spec id",
+            metadata.default_spec_id,
+            value(
+                name,
+                "default-spec-id",
+                requirement.default_spec_id.as_ref(),
+            )?,
+        ),
+        "assert-default-sort-order-id" => check_id(
+            name,
+            "default sort order id",
+            metadata.default_sort_order_id,
+            value(
+                name,
+                "default-sort-order-id",
+                requirement.default_sort_order_id.as_ref(),
+            )?,
+        ),
+        _ => Err(RequirementError::Invalid(format!(
+            "Unknown requirement {}.",
+            name
+        ))),
+    }
+}
+
+/// Returns the snapshot the ref points to. Without refs, the current snapshot is the main branch.
+fn ref_snapshot_id(metadata: &models::TableMetadata, r#ref: &str) -> Option<i64> {
+    match metadata.refs.as_ref() {
+        Some(refs) if !refs.is_empty() => refs.get(r#ref).map(|r#ref| r#ref.snapshot_id),
+        _ if r#ref == "main" => metadata
+            .current_snapshot_id
+            .filter(|id| *id != -1)
+            .map(|id| id as i64),
+        _ => None,
+    }
+}
+
+fn value<'a, T>(name: &str, field: &str, value: Option<&'a T>) -> Result<&'a T, RequirementError> {
+    value.ok_or_else(|| {
+        RequirementError::Invalid(format!("The requirement {} is missing {}.", name, field))
+    })
+}
+
+fn check_id(
+    name: &str,
+    description: &str,
+    current: Option<isize>,
+    expected: &isize,
+) -> Result<(), RequirementError> {
+    match current {
+        Some(current) if current == *expected => Ok(()),
+        Some(current) => Err(failed(name, description, current, expected)),
+        None => Err(failed(name, description, "missing", expected)),
+    }
+}
+
+fn failed(
+    name: &str,
+    description: &str,
+    current: impl fmt::Display,
+    expected: impl fmt::Display,
+) -> RequirementError {
+    RequirementError::Failed(format!(
+        "Requirement failed: {}: {} is {}, expected {}.",
+        name, description, current, expected
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use iceberg_catalog_rest_rdbms_server::models;
+
+    use super::{check, RequirementError};
+
+    fn metadata() -> models::TableMetadata {
+        serde_json::from_value(serde_json::json!({
+            "format-version": 2,
+            "table-uuid": "9c12d441-03fe-4693-9a96-a0705ddf69c1",
+            "last-column-id": 3,
+            "current-schema-id": 1,
+            "last-partition-id": 1000,
+            "default-spec-id": 0,
+            "default-sort-order-id": 3,
+            "current-snapshot-id": 3055729675574597004i64,
+            "refs": {
+                "main": { "type": "branch", "snapshot-id": 3055729675574597004i64 }
+            }
+        }))
+        .unwrap()
+    }
+
+    fn requirement(requirement: serde_json::Value) -> models::TableRequirement {
+        serde_json::from_value(requirement).unwrap()
+    }
+
+    fn is_failed(result: Result<(), RequirementError>) -> bool {
+        matches!(result, Err(RequirementError::Failed(_)))
+    }
+
+    #[test]
+    fn requirements() {
+        let metadata = metadata();
+        for (passing, failing) in [
+            (
+                serde_json::json!({ "requirement": "assert-table-uuid", "uuid": "9c12d441-03fe-4693-9a96-a0705ddf69c1" }),
+                serde_json::json!({ "requirement": "assert-table-uuid", "uuid": "0c12d441-03fe-4693-9a96-a0705ddf69c1" }),
+            ),
+            (
+                serde_json::json!({ "requirement": "assert-ref-snapshot-id", "ref": "main", "snapshot-id": 3055729675574597004i64 }),
+                serde_json::json!({ "requirement": "assert-ref-snapshot-id", "ref": "main", "snapshot-id": 1 }),
+            ),
+            (
+                serde_json::json!({ "requirement": "assert-ref-snapshot-id", "ref": "audit" }),
+                serde_json::json!({ "requirement": "assert-ref-snapshot-id", "ref": "main" }),
+            ),
+            (
+                serde_json::json!({ "requirement": "assert-last-assigned-field-id", "last-assigned-field-id": 3 }),
+                serde_json::json!({ "requirement": "assert-last-assigned-field-id", "last-assigned-field-id": 2 }),
+            ),
+            (
+                serde_json::json!({ "requirement": "assert-current-schema-id", "current-schema-id": 1 }),
+                serde_json::json!({ "requirement": "assert-current-schema-id", "current-schema-id": 0 }),
+            ),
+            (
+                serde_json::json!({ "requirement": "assert-last-assigned-partition-id", "last-assigned-partition-id": 1000 }),
+                serde_json::json!({ "requirement": "assert-last-assigned-partition-id", "last-assigned-partition-id": 999 }),
+            ),
+            (
+                serde_json::json!({ "requirement": "assert-default-spec-id", "default-spec-id": 0 }),
+                serde_json::json!({ "requirement": "assert-default-spec-id", "default-spec-id": 1 }),
+            ),
+            (
+                serde_json::json!({ "requirement": "assert-default-sort-order-id", "default-sort-order-id": 3 }),
+                serde_json::json!({ "requirement": "assert-default-sort-order-id", "default-sort-order-id": 0 }),
+            ),
+        ] {
+            assert_eq!(check(&requirement(passing), &metadata), Ok(()));
+            let name = failing["requirement"].as_str().unwrap().to_owned();
+            let result = check(&requirement(failing), &metadata);
+            assert!(result.as_ref().unwrap_err().to_string().contains(&name));
+            assert!(is_failed(result));
+        }
+
+        assert!(is_failed(check(
+            &requirement(serde_json::json!({ "requirement": "assert-create" })),
+            &metadata
+        )));
+    }
+
+    #[test]
+    fn invalid_requirements() {
+        let metadata = metadata();
+        for invalid in [
+            serde_json::json!({ "requirement": "assert-table-uuid" }),
+            serde_json::json!({ "requirement": "assert-current-schema-id" }),
+            serde_json::json!({ "requirement": "assert-something" }),
+        ] {
+            assert!(matches!(
+                check(&requirement(invalid), &metadata),
+                Err(RequirementError::Invalid(_))
+            ));
+        }
+    }
+}
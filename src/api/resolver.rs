@@ -0,0 +1,158 @@
+//! Resolution of the identifiers used in the REST paths to database entities.
+//!
+//! Every lookup matches names exactly. A missing catalog, namespace or table is
+//! reported as the corresponding [`CatalogError`], which the handlers turn into a
+//! `NotFound` response.
+
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DbErr, EntityTrait, QueryFilter, QueryOrder,
+};
+use serde_json::json;
+
+use crate::database::entities::{prelude::*, *};
+
+use super::error::CatalogError;
+
+/// Converts a namespace path parameter to the name stored in the database.
+///
+/// Levels may be separated by the unit separator (0x1F) as required by the
+/// REST specification or by ".".
+pub fn namespace_name(namespace: &str) -> String {
+    namespace.replace('\u{1f}', ".")
+}
+
+/// Splits the stored name of a namespace into its levels.
+pub fn namespace_levels(name: &str) -> Vec<String> {
+    name.split('.').map(|level| level.to_owned()).collect()
+}
+
+/// Resolves the catalog of a prefix. The empty prefix refers to the default catalog.
+pub async fn catalog<C: ConnectionTrait>(
+    db: &C,
+    prefix: &str,
+) -> Result<Option<catalog::Model>, CatalogError> {
+    if prefix.is_empty() {
+        return Ok(None);
+    }
+    Catalog::find()
+        .filter(catalog::Column::Name.eq(prefix))
+        .one(db)
+        .await?
+        .map(Some)
+        .ok_or_else(|| CatalogError::NoSuchCatalog(prefix.to_owned()))
+}
+
+/// Resolves the catalog of a warehouse, which clients name instead of a prefix. A
+/// warehouse is the name of a catalog or else its storage root.
+pub async fn warehouse<C: ConnectionTrait>(
+    db: &C,
+    warehouse: &str,
+) -> Result<catalog::Model, CatalogError> {
+    if let Some(catalog) = Catalog::find()
+        .filter(catalog::Column::Name.eq(warehouse))
+        .one(db)
+        .await?
+    {
+        return Ok(catalog);
+    }
+    Catalog::find()
+        .filter(catalog::Column::StorageRoot.eq(warehouse.trim_end_matches('/')))
+        .order_by_asc(catalog::Column::Id)
+        .one(db)
+        .await?
+        .ok_or_else(|| CatalogError::NoSuchWarehouse(warehouse.to_owned()))
+}
+
+/// Resolves the catalog of a prefix and registers it if it doesn't exist yet.
+pub async fn catalog_or_insert<C: ConnectionTrait>(
+    db: &C,
+    prefix: &str,
+) -> Result<Option<catalog::Model>, CatalogError> {
+    match catalog(db, prefix).await {
+        Err(CatalogError::NoSuchCatalog(_)) => {
+            let new_catalog = catalog::ActiveModel::from_json(json!({
+                "name": prefix,
+            }))?;
+
+            let result = Catalog::insert(new_catalog).exec(db).await?;
+
+            Ok(Catalog::find_by_id(result.last_insert_id).one(db).await?)
+        }
+        x => x,
+    }
+}
+
+/// Looks up a namespace of a catalog by its full name.
+pub async fn find_namespace<C: ConnectionTrait>(
+    db: &C,
+    catalog: Option<&catalog::Model>,
+    name: &str,
+) -> Result<Option<namespace::Model>, DbErr> {
+    let in_catalog = match catalog {
+        None => namespace::Column::CatalogId.is_null(),
+        Some(catalog) => namespace::Column::CatalogId.eq(catalog.id),
+    };
+    Namespace::find()
+        .filter(in_catalog)
+        .filter(namespace::Column::Name.eq(name))
+        .one(db)
+        .await
+}
+
+/// Resolves a namespace of a catalog by its full name.
+pub async fn namespace<C: ConnectionTrait>(
+    db: &C,
+    catalog: Option<&catalog::Model>,
+    name: &str,
+) -> Result<namespace::Model, CatalogError> {
+    find_namespace(db, catalog, name)
+        .await?
+        .ok_or_else(|| CatalogError::NoSuchNamespace(name.to_owned()))
+}
+
+/// Looks up a table of a namespace by its name.
+pub async fn find_table<C: ConnectionTrait>(
+    db: &C,
+    namespace: &namespace::Model,
+    name: &str,
+) -> Result<Option<iceberg_table::Model>, DbErr> {
+    IcebergTable::find()
+        .filter(iceberg_table::Column::NamespaceId.eq(namespace.id))
+        .filter(iceberg_table::Column::Name.eq(name))
+        .one(db)
+        .await
+}
+
+/// Resolves a table of a namespace by its name.
+pub async fn table<C: ConnectionTrait>(
+    db: &C,
+    namespace: &namespace::Model,
+    name: &str,
+) -> Result<iceberg_table::Model, CatalogError> {
+    find_table(db, namespace, name)
+        .await?
+        .ok_or_else(|| CatalogError::NoSuchTable(format!("{}.{}", namespace.name, name)))
+}
+
+/// Resolves the namespace identified by a prefix and a namespace path parameter.
+pub async fn resolve_namespace<C: ConnectionTrait>(
+    db: &C,
+    prefix: &str,
+    namespace: &str,
+) -> Result<namespace::Model, CatalogError> {
+    let catalog = catalog(db, prefix).await?;
+    self::namespace(db, catalog.as_ref(), &namespace_name(namespace)).await
+}
+
+/// Resolves the table identified by a prefix, a namespace and a table path parameter
+/// together with its namespace.
+pub async fn resolve_table<C: ConnectionTrait>(
+    db: &C,
+    prefix: &str,
+    namespace: &str,
+    table: &str,
+) -> Result<(namespace::Model, iceberg_table::Model), CatalogError> {
+    let namespace = resolve_namespace(db, prefix, namespace).await?;
+    let table = self::table(db, &namespace, table).await?;
+    Ok((namespace, table))
+}
@@ -0,0 +1,299 @@
+//! Roles and the privileges granted to them, as managed by the admin endpoints.
+//!
+//! Only admins create and drop roles and set their principals. Grants are managed
+//! by every principal holding `MANAGE_GRANTS` on the object of the grant, which
+//! also limits the grants of a role that are listed to them.
+
+use std::collections::hash_map::Entry;
+use std::collections::{BTreeSet, HashMap};
+
+use sea_orm::{
+    ActiveValue, ColumnTrait, Condition, ConnectionTrait, DbErr, EntityTrait, ModelTrait,
+    QueryFilter, QueryOrder,
+};
+
+use iceberg_catalog_rest_rdbms_server::models::{self, Privilege};
+
+use crate::database::entities::{prelude::*, *};
+
+use super::access::{self, Caller, Grants, Target};
+use super::error::CatalogError;
+use super::resolver;
+
+/// Lists all roles with their principals.
+pub async fn list<C: ConnectionTrait>(db: &C) -> Result<Vec<models::Role>, DbErr> {
+    let roles = Role::find()
+        .order_by_asc(role::Column::Name)
+        .find_with_related(RoleMember)
+        .all(db)
+        .await?;
+
+    Ok(roles
+        .into_iter()
+        .map(|(role, members)| models::Role {
+            name: role.name,
+            principals: members.into_iter().map(|member| member.principal).collect(),
+        })
+        .collect())
+}
+
+/// Resolves a role by its name.
+pub async fn role<C: ConnectionTrait>(db: &C, name: &str) -> Result<role::Model, CatalogError> {
+    Role::find()
+        .filter(role::Column::Name.eq(name))
+        .one(db)
+        .await?
+        .ok_or_else(|| CatalogError::NoSuchRole(name.to_owned()))
+}
+
+/// Creates a role or replaces its principals.
+pub async fn put<C: ConnectionTrait>(
+    db: &C,
+    name: &str,
+    principals: Vec<String>,
+) -> Result<models::Role, CatalogError> {
+    let principals = principals.into_iter().collect::<BTreeSet<_>>();
+    if principals.iter().any(String::is_empty) {
+        return Err(CatalogError::BadRequest(
+            "Principals have to be non-empty.".into(),
+        ));
+    }
+
+    let role_id = match role(db, name).await {
+        Ok(role) => {
+            RoleMember::delete_many()
+                .filter(role_member::Column::RoleId.eq(role.id))
+                .exec(db)
+                .await?;
+            role.id
+        }
+        Err(CatalogError::NoSuchRole(_)) => {
+            let new_role = role::ActiveModel {
+                id: ActiveValue::NotSet,
+                name: ActiveValue::Set(name.to_owned()),
+            };
+            Role::insert(new_role).exec(db).await?.last_insert_id
+        }
+        Err(err) => return Err(err),
+    };
+
+    if !principals.is_empty() {
+        RoleMember::insert_many(principals.iter().map(|principal| role_member::ActiveModel {
+            id: ActiveValue::NotSet,
+            principal: ActiveValue::Set(principal.clone()),
+            role_id: ActiveValue::Set(role_id),
+        }))
+        .exec(db)
+        .await?;
+    }
+
+    Ok(models::Role {
+        name: name.to_owned(),
+        principals: principals.into_iter().collect(),
+    })
+}
+
+/// Drops a role together with its principals and grants.
+pub async fn remove<C: ConnectionTrait>(db: &C, role: role::Model) -> Result<(), DbErr> {
+    PrivilegeGrant::delete_many()
+        .filter(privilege_grant::Column::RoleId.eq(role.id))
+        .exec(db)
+        .await?;
+    RoleMember::delete_many()
+        .filter(role_member::Column::RoleId.eq(role.id))
+        .exec(db)
+        .await?;
+
+    role.delete(db).await?;
+
+    Ok(())
+}
+
+/// Lists the grants of a role on the objects the caller manages the grants of.
+pub async fn grants<C: ConnectionTrait>(
+    db: &C,
+    role: &role::Model,
+    caller: &Caller,
+) -> Result<Vec<models::Grant>, CatalogError> {
+    let rows = role
+        .find_related(PrivilegeGrant)
+        .order_by_asc(privilege_grant::Column::Id)
+        .all(db)
+        .await?;
+
+    let mut catalogs: HashMap<Option<i32>, (String, Grants)> = HashMap::new();
+    let mut grants = Vec::new();
+    for row in rows {
+        let privilege = match row.privilege.parse::<Privilege>() {
+            Ok(privilege) => privilege,
+            Err(_) => continue,
+        };
+
+        let (catalog, held) = match catalogs.entry(row.catalog_id) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                let catalog = match row.catalog_id {
+                    Some(id) => Catalog::find_by_id(id).one(db).await?,
+                    None => None,
+                };
+                let held = caller.grants(db, row.catalog_id).await?;
+                let name = catalog.map(|catalog| catalog.name).unwrap_or_default();
+                entry.insert((name, held))
+            }
+        };
+
+        let namespace = match row.namespace_id {
+            Some(id) => Namespace::find_by_id(id).one(db).await?,
+            None => None,
+        };
+        let table = match row.table_id {
+            Some(id) => IcebergTable::find_by_id(id).one(db).await?,
+            None => None,
+        };
+        let target = match (&namespace, &table) {
+            (Some(namespace), Some(table)) => Target::Table(namespace, table),
+            (Some(namespace), None) => Target::Namespace(namespace),
+            _ => Target::Catalog,
+        };
+        if !held.allows(Privilege::ManageGrants, target) {
+            continue;
+        }
+
+        grants.push(models::Grant {
+            privilege,
+            catalog: catalog.clone(),
+            namespace: namespace.map(|namespace| resolver::namespace_levels(&namespace.name)),
+            table: table.map(|table| table.name),
+        });
+    }
+
+    Ok(grants)
+}
+
+/// Revokes and then grants privileges to a role. The caller has to manage the grants
+/// of every object of the request.
+pub async fn update<C: ConnectionTrait>(
+    db: &C,
+    role: &role::Model,
+    caller: &Caller,
+    request: models::UpdateGrantsRequest,
+) -> Result<(), CatalogError> {
+    for grant in request.revokes.unwrap_or_default() {
+        let object = Object::resolve(db, &grant).await?;
+        object.require_manage(db, caller).await?;
+
+        PrivilegeGrant::delete_many()
+            .filter(object.condition(role, grant.privilege))
+            .exec(db)
+            .await?;
+    }
+
+    for grant in request.grants.unwrap_or_default() {
+        let object = Object::resolve(db, &grant).await?;
+        object.require_manage(db, caller).await?;
+        if object.table.is_some() && !access::is_table_privilege(grant.privilege) {
+            return Err(CatalogError::BadRequest(format!(
+                "The privilege {} can't be granted on a table.",
+                grant.privilege
+            )));
+        }
+
+        let granted = PrivilegeGrant::find()
+            .filter(object.condition(role, grant.privilege))
+            .one(db)
+            .await?;
+        if granted.is_none() {
+            let new_grant = privilege_grant::ActiveModel {
+                id: ActiveValue::NotSet,
+                privilege: ActiveValue::Set(grant.privilege.to_string()),
+                role_id: ActiveValue::Set(role.id),
+                catalog_id: ActiveValue::Set(object.catalog.as_ref().map(|catalog| catalog.id)),
+                namespace_id: ActiveValue::Set(
+                    object.namespace.as_ref().map(|namespace| namespace.id),
+                ),
+                table_id: ActiveValue::Set(object.table.as_ref().map(|table| table.id)),
+            };
+            PrivilegeGrant::insert(new_grant).exec(db).await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// The catalog, namespace or table of a grant.
+struct Object {
+    catalog: Option<catalog::Model>,
+    namespace: Option<namespace::Model>,
+    table: Option<iceberg_table::Model>,
+}
+
+impl Object {
+    async fn resolve<C: ConnectionTrait>(
+        db: &C,
+        grant: &models::Grant,
+    ) -> Result<Self, CatalogError> {
+        let catalog = resolver::catalog(db, &grant.catalog).await?;
+        let namespace = match &grant.namespace {
+            Some(levels) => {
+                Some(resolver::namespace(db, catalog.as_ref(), &levels.join(".")).await?)
+            }
+            None => None,
+        };
+        let table = match (&grant.table, &namespace) {
+            (Some(table), Some(namespace)) => Some(resolver::table(db, namespace, table).await?),
+            (Some(_), None) => {
+                return Err(CatalogError::BadRequest(
+                    "The grant of a table has to name its namespace.".into(),
+                ))
+            }
+            (None, _) => None,
+        };
+
+        Ok(Object {
+            catalog,
+            namespace,
+            table,
+        })
+    }
+
+    fn target(&self) -> Target<'_> {
+        match (&self.namespace, &self.table) {
+            (Some(namespace), Some(table)) => Target::Table(namespace, table),
+            (Some(namespace), None) => Target::Namespace(namespace),
+            _ => Target::Catalog,
+        }
+    }
+
+    async fn require_manage<C: ConnectionTrait>(
+        &self,
+        db: &C,
+        caller: &Caller,
+    ) -> Result<(), CatalogError> {
+        caller
+            .grants(db, self.catalog.as_ref().map(|catalog| catalog.id))
+            .await?
+            .require(Privilege::ManageGrants, self.target())
+    }
+
+    /// Matches the grant of the privilege on the object to the role.
+    fn condition(&self, role: &role::Model, privilege: Privilege) -> Condition {
+        let catalog = match &self.catalog {
+            None => privilege_grant::Column::CatalogId.is_null(),
+            Some(catalog) => privilege_grant::Column::CatalogId.eq(catalog.id),
+        };
+        let namespace = match &self.namespace {
+            None => privilege_grant::Column::NamespaceId.is_null(),
+            Some(namespace) => privilege_grant::Column::NamespaceId.eq(namespace.id),
+        };
+        let table = match &self.table {
+            None => privilege_grant::Column::TableId.is_null(),
+            Some(table) => privilege_grant::Column::TableId.eq(table.id),
+        };
+        Condition::all()
+            .add(privilege_grant::Column::RoleId.eq(role.id))
+            .add(privilege_grant::Column::Privilege.eq(privilege.to_string()))
+            .add(catalog)
+            .add(namespace)
+            .add(table)
+    }
+}
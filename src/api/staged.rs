@@ -0,0 +1,68 @@
+//! Tables staged by `create_table` with `stage-create`.
+//!
+//! A staged table is not visible in the catalog. Its metadata is kept until a
+//! commit with an `assert-create` requirement creates the table from it, or until
+//! a table of the same name is staged or created.
+
+use sea_orm::{ActiveModelTrait, ColumnTrait, ConnectionTrait, DbErr, EntityTrait, QueryFilter};
+use serde_json::json;
+
+use iceberg_catalog_rest_rdbms_server::models;
+
+use crate::database::entities::{prelude::*, *};
+
+use super::error::CatalogError;
+
+/// Stages a table, replacing a staged table of the same name.
+pub async fn stage<C: ConnectionTrait>(
+    db: &C,
+    namespace: &namespace::Model,
+    name: &str,
+    metadata: &models::TableMetadata,
+) -> Result<(), CatalogError> {
+    remove(db, namespace, name).await?;
+
+    let staged_table = staged_table::ActiveModel::from_json(json!({
+        "name": name,
+        "metadata": metadata,
+        "staged_at": chrono::Utc::now().to_rfc3339(),
+        "namespace_id": namespace.id,
+    }))?;
+
+    StagedTable::insert(staged_table).exec(db).await?;
+
+    Ok(())
+}
+
+/// Returns the metadata of the table staged with the name.
+pub async fn find<C: ConnectionTrait>(
+    db: &C,
+    namespace: &namespace::Model,
+    name: &str,
+) -> Result<Option<models::TableMetadata>, CatalogError> {
+    let staged_table = StagedTable::find()
+        .filter(staged_table::Column::NamespaceId.eq(namespace.id))
+        .filter(staged_table::Column::Name.eq(name))
+        .one(db)
+        .await?;
+
+    Ok(staged_table
+        .map(|staged_table| serde_json::from_value(staged_table.metadata))
+        .transpose()
+        .map_err(|err| DbErr::Json(err.to_string()))?)
+}
+
+/// Removes the table staged with the name, if there is one.
+pub async fn remove<C: ConnectionTrait>(
+    db: &C,
+    namespace: &namespace::Model,
+    name: &str,
+) -> Result<(), DbErr> {
+    StagedTable::delete_many()
+        .filter(staged_table::Column::NamespaceId.eq(namespace.id))
+        .filter(staged_table::Column::Name.eq(name))
+        .exec(db)
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,663 @@
+//! Application of the updates of a table commit to the table metadata.
+//!
+//! The updates are applied in order and follow the rules of Iceberg's metadata
+//! builder. Schemas, partition specs and sort orders that are added get fresh ids
+//! unless an identical one already exists, and `-1` refers to the last one added
+//! by the same commit.
+
+use std::collections::HashMap;
+
+use iceberg_catalog_rest_rdbms_server::models::{self, TableUpdate};
+
+use super::metadata::PARTITION_DATA_ID_START;
+
+/// Highest format version the server can write.
+const SUPPORTED_FORMAT_VERSION: u8 = 2;
+
+/// Refers to the last schema, spec or sort order added by the commit.
+const LAST_ADDED: isize = -1;
+
+/// Name of the branch that points to the current snapshot.
+const MAIN_BRANCH: &str = "main";
+
+/// Applies the updates of a commit to the metadata of a table.
+pub struct MetadataBuilder {
+    metadata: models::TableMetadata,
+    last_added_schema_id: Option<isize>,
+    last_added_spec_id: Option<isize>,
+    last_added_order_id: Option<isize>,
+    added_snapshot_ids: Vec<i64>,
+    last_updated_ms: Option<isize>,
+}
+
+impl MetadataBuilder {
+    pub fn new(metadata: models::TableMetadata) -> Self {
+        MetadataBuilder {
+            metadata,
+            last_added_schema_id: None,
+            last_added_spec_id: None,
+            last_added_order_id: None,
+            added_snapshot_ids: vec![],
+            last_updated_ms: None,
+        }
+    }
+
+    /// Applies an update. Fails with a message if the update is invalid for the metadata.
+    pub fn apply(&mut self, update: &TableUpdate) -> Result<(), String> {
+        match update {
+            TableUpdate::AssignUuid(update) => self.assign_uuid(&update.uuid),
+            TableUpdate::UpgradeFormatVersion(update) => {
+                self.upgrade_format_version(update.format_version)
+            }
+            TableUpdate::AddSchema(update) => {
+                self.add_schema(update.schema.clone(), update.last_column_id)
+            }
+            TableUpdate::SetCurrentSchema(update) => self.set_current_schema(update.schema_id),
+            TableUpdate::AddPartitionSpec(update) => self.add_spec(update.spec.clone()),
+            TableUpdate::SetDefaultSpec(update) => self.set_default_spec(update.spec_id),
+            TableUpdate::AddSortOrder(update) => self.add_sort_order(update.sort_order.clone()),
+            TableUpdate::SetDefaultSortOrder(update) => {
+                self.set_default_sort_order(update.sort_order_id)
+            }
+            TableUpdate::AddSnapshot(update) => self.add_snapshot(update.snapshot.clone()),
+            TableUpdate::SetSnapshotRef(update) => self.set_snapshot_ref(
+                &update.ref_name,
+                models::SnapshotReference {
+                    r#type: update.r#type.clone(),
+                    snapshot_id: update.snapshot_id,
+                    max_ref_age_ms: update.max_ref_age_ms,
+                    max_snapshot_age_ms: update.max_snapshot_age_ms,
+                    min_snapshots_to_keep: update.min_snapshots_to_keep,
+                },
+            ),
+            TableUpdate::RemoveSnapshots(update) => {
+                self.remove_snapshots(&update.snapshot_ids);
+                Ok(())
+            }
+            TableUpdate::RemoveSnapshotRef(update) => {
+                self.remove_snapshot_ref(&update.ref_name);
+                Ok(())
+            }
+            TableUpdate::SetLocation(update) => {
+                self.metadata.location = Some(update.location.trim_end_matches('/').to_owned());
+                Ok(())
+            }
+            TableUpdate::SetProperties(update) => {
+                self.metadata
+                    .properties
+                    .get_or_insert_with(HashMap::new)
+                    .extend(update.updates.clone());
+                Ok(())
+            }
+            TableUpdate::RemoveProperties(update) => {
+                if let Some(properties) = self.metadata.properties.as_mut() {
+                    for key in &update.removals {
+                        properties.remove(key);
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Returns the updated metadata. The previous metadata file, if the table has one, is
+    /// added to the metadata log.
+    pub fn build(
+        mut self,
+        previous_metadata_location: Option<&str>,
+        previous_last_updated_ms: Option<isize>,
+    ) -> models::TableMetadata {
+        if let Some(previous_metadata_location) = previous_metadata_location {
+            let max_previous_versions = self
+                .metadata
+                .properties
+                .as_ref()
+                .and_then(|properties| properties.get("write.metadata.previous-versions-max"))
+                .and_then(|max| max.parse::<usize>().ok())
+                .unwrap_or(100)
+                .max(1);
+            let metadata_log = self.metadata.metadata_log.get_or_insert_with(Vec::new);
+            metadata_log.push(models::MetadataLogInner::new(
+                previous_metadata_location.to_owned(),
+                previous_last_updated_ms.unwrap_or_default(),
+            ));
+            if metadata_log.len() > max_previous_versions {
+                metadata_log.drain(..metadata_log.len() - max_previous_versions);
+            }
+        }
+
+        self.metadata.last_updated_ms = Some(
+            self.last_updated_ms
+                .unwrap_or_else(|| chrono::Utc::now().timestamp_millis() as isize),
+        );
+        self.metadata
+    }
+
+    fn assign_uuid(&mut self, uuid: &str) -> Result<(), String> {
+        if !uuid.eq_ignore_ascii_case(&self.metadata.table_uuid) {
+            return Err(format!(
+                "Cannot reassign the UUID {} of the table to {}.",
+                self.metadata.table_uuid, uuid
+            ));
+        }
+        Ok(())
+    }
+
+    fn upgrade_format_version(&mut self, format_version: isize) -> Result<(), String> {
+        let current = self.metadata.format_version as isize;
+        if format_version < current {
+            return Err(format!(
+                "Cannot downgrade the format version from {} to {}.",
+                current, format_version
+            ));
+        }
+        if format_version > SUPPORTED_FORMAT_VERSION as isize {
+            return Err(format!(
+                "The format version {} is not supported.",
+                format_version
+            ));
+        }
+        self.metadata.format_version = format_version as u8;
+        Ok(())
+    }
+
+    fn add_schema(
+        &mut self,
+        mut schema: models::Schema,
+        last_column_id: Option<isize>,
+    ) -> Result<(), String> {
+        let schemas = self.metadata.schemas.get_or_insert_with(Vec::new);
+        let existing = schemas.iter().find(|existing| {
+            existing.fields == schema.fields
+                && existing.identifier_field_ids.clone().unwrap_or_default()
+                    == schema.identifier_field_ids.clone().unwrap_or_default()
+        });
+        let schema_id = match existing {
+            Some(existing) => existing.schema_id.unwrap_or_default(),
+            None => {
+                let schema_id = schemas
+                    .iter()
+                    .filter_map(|schema| schema.schema_id)
+                    .max()
+                    .map_or(0, |id| id + 1);
+                let last_column_id = max_field_id(&schema.fields);
+                self.metadata.last_column_id = Some(
+                    self.metadata
+                        .last_column_id
+                        .unwrap_or(0)
+                        .max(last_column_id),
+                );
+                schema.schema_id = Some(schema_id);
+                schemas.push(schema);
+                schema_id
+            }
+        };
+        if let Some(last_column_id) = last_column_id {
+            self.metadata.last_column_id = Some(
+                self.metadata
+                    .last_column_id
+                    .unwrap_or(0)
+                    .max(last_column_id),
+            );
+        }
+        self.last_added_schema_id = Some(schema_id);
+        Ok(())
+    }
+
+    fn set_current_schema(&mut self, schema_id: isize) -> Result<(), String> {
+        let schema_id = resolve_last_added(schema_id, self.last_added_schema_id, "schema")?;
+        let exists = self
+            .metadata
+            .schemas
+            .iter()
+            .flatten()
+            .any(|schema| schema.schema_id == Some(schema_id));
+        if !exists {
+            return Err(format!("The schema {} does not exist.", schema_id));
+        }
+        self.metadata.current_schema_id = Some(schema_id);
+        Ok(())
+    }
+
+    fn add_spec(&mut self, mut spec: models::PartitionSpec) -> Result<(), String> {
+        let mut last_partition_id = self
+            .metadata
+            .last_partition_id
+            .unwrap_or(PARTITION_DATA_ID_START - 1);
+        for field in spec.fields.iter_mut() {
+            match field.field_id {
+                Some(field_id) => last_partition_id = last_partition_id.max(field_id),
+                None => {
+                    last_partition_id += 1;
+                    field.field_id = Some(last_partition_id);
+                }
+            }
+        }
+
+        let specs = self.metadata.partition_specs.get_or_insert_with(Vec::new);
+        let existing = specs.iter().find(|existing| existing.fields == spec.fields);
+        let spec_id = match existing {
+            Some(existing) => existing.spec_id.unwrap_or_default(),
+            None => {
+                let spec_id = specs
+                    .iter()
+                    .filter_map(|spec| spec.spec_id)
+                    .max()
+                    .map_or(0, |id| id + 1);
+                spec.spec_id = Some(spec_id);
+                specs.push(spec);
+                self.metadata.last_partition_id = Some(last_partition_id);
+                spec_id
+            }
+        };
+        self.last_added_spec_id = Some(spec_id);
+        Ok(())
+    }
+
+    fn set_default_spec(&mut self, spec_id: isize) -> Result<(), String> {
+        let spec_id = resolve_last_added(spec_id, self.last_added_spec_id, "partition spec")?;
+        let exists = self
+            .metadata
+            .partition_specs
+            .iter()
+            .flatten()
+            .any(|spec| spec.spec_id == Some(spec_id));
+        if !exists {
+            return Err(format!("The partition spec {} does not exist.", spec_id));
+        }
+        self.metadata.default_spec_id = Some(spec_id);
+        Ok(())
+    }
+
+    fn add_sort_order(&mut self, mut sort_order: models::SortOrder) -> Result<(), String> {
+        let sort_orders = self.metadata.sort_orders.get_or_insert_with(Vec::new);
+        let existing = sort_orders
+            .iter()
+            .find(|existing| existing.fields == sort_order.fields);
+        let order_id = match existing {
+            Some(existing) => existing.order_id,
+            None => {
+                // The order id 0 is reserved for the unsorted order
+                let order_id = if sort_order.fields.is_empty() {
+                    0
+                } else {
+                    sort_orders
+                        .iter()
+                        .map(|sort_order| sort_order.order_id)
+                        .max()
+                        .map_or(1, |id| id.max(0) + 1)
+                };
+                sort_order.order_id = order_id;
+                sort_orders.push(sort_order);
+                order_id
+            }
+        };
+        self.last_added_order_id = Some(order_id);
+        Ok(())
+    }
+
+    fn set_default_sort_order(&mut self, order_id: isize) -> Result<(), String> {
+        let order_id = resolve_last_added(order_id, self.last_added_order_id, "sort order")?;
+        let exists = self
+            .metadata
+            .sort_orders
+            .iter()
+            .flatten()
+            .any(|sort_order| sort_order.order_id == order_id);
+        if !exists {
+            return Err(format!("The sort order {} does not exist.", order_id));
+        }
+        self.metadata.default_sort_order_id = Some(order_id);
+        Ok(())
+    }
+
+    fn add_snapshot(&mut self, snapshot: models::Snapshot) -> Result<(), String> {
+        if self.snapshot(snapshot.snapshot_id as i64).is_some() {
+            return Err(format!(
+                "The snapshot {} already exists.",
+                snapshot.snapshot_id
+            ));
+        }
+
+        let last_sequence_number = self.metadata.last_sequence_number.unwrap_or(0);
+        if self.metadata.format_version > 1 {
+            let sequence_number = snapshot.sequence_number.unwrap_or(0) as i64;
+            if sequence_number <= last_sequence_number && snapshot.parent_snapshot_id.is_some() {
+                return Err(format!(
+                    "The sequence number {} of the snapshot is not greater than the last sequence number {}.",
+                    sequence_number, last_sequence_number
+                ));
+            }
+            self.metadata.last_sequence_number = Some(sequence_number.max(last_sequence_number));
+        }
+
+        self.last_updated_ms = Some(snapshot.timestamp_ms);
+        self.added_snapshot_ids.push(snapshot.snapshot_id as i64);
+        self.metadata
+            .snapshots
+            .get_or_insert_with(Vec::new)
+            .push(snapshot);
+        Ok(())
+    }
+
+    fn set_snapshot_ref(
+        &mut self,
+        ref_name: &str,
+        reference: models::SnapshotReference,
+    ) -> Result<(), String> {
+        if reference.r#type != "branch" && reference.r#type != "tag" {
+            return Err(format!("Unknown ref type {}.", reference.r#type));
+        }
+        let timestamp_ms = match self.snapshot(reference.snapshot_id) {
+            None => {
+                return Err(format!(
+                    "The snapshot {} does not exist.",
+                    reference.snapshot_id
+                ))
+            }
+            Some(snapshot) => snapshot.timestamp_ms,
+        };
+
+        if ref_name == MAIN_BRANCH {
+            let timestamp_ms = if self.added_snapshot_ids.contains(&reference.snapshot_id) {
+                timestamp_ms
+            } else {
+                let now = chrono::Utc::now().timestamp_millis() as isize;
+                self.last_updated_ms = Some(now);
+                now
+            };
+            self.metadata.current_snapshot_id = Some(reference.snapshot_id as isize);
+            self.metadata
+                .snapshot_log
+                .get_or_insert_with(Vec::new)
+                .push(models::SnapshotLogInner::new(
+                    reference.snapshot_id as isize,
+                    timestamp_ms,
+                ));
+        }
+
+        self.metadata
+            .refs
+            .get_or_insert_with(HashMap::new)
+            .insert(ref_name.to_owned(), reference);
+        Ok(())
+    }
+
+    fn remove_snapshots(&mut self, snapshot_ids: &[i64]) {
+        if let Some(snapshots) = self.metadata.snapshots.as_mut() {
+            snapshots.retain(|snapshot| !snapshot_ids.contains(&(snapshot.snapshot_id as i64)));
+        }
+
+        let removed_refs = self
+            .metadata
+            .refs
+            .iter()
+            .flatten()
+            .filter(|(_, reference)| snapshot_ids.contains(&reference.snapshot_id))
+            .map(|(name, _)| name.clone())
+            .collect::<Vec<_>>();
+        for ref_name in removed_refs {
+            self.remove_snapshot_ref(&ref_name);
+        }
+
+        // Entries of removed snapshots invalidate the history before them
+        if let Some(snapshot_log) = self.metadata.snapshot_log.take() {
+            let mut new_snapshot_log = vec![];
+            for entry in snapshot_log {
+                if self.snapshot(entry.snapshot_id as i64).is_some() {
+                    new_snapshot_log.push(entry);
+                } else {
+                    new_snapshot_log.clear();
+                }
+            }
+            self.metadata.snapshot_log = Some(new_snapshot_log);
+        }
+    }
+
+    fn remove_snapshot_ref(&mut self, ref_name: &str) {
+        if ref_name == MAIN_BRANCH {
+            self.metadata.current_snapshot_id = None;
+        }
+        if let Some(refs) = self.metadata.refs.as_mut() {
+            refs.remove(ref_name);
+        }
+    }
+
+    fn snapshot(&self, snapshot_id: i64) -> Option<&models::Snapshot> {
+        self.metadata
+            .snapshots
+            .iter()
+            .flatten()
+            .find(|snapshot| snapshot.snapshot_id as i64 == snapshot_id)
+    }
+}
+
+fn resolve_last_added(id: isize, last_added: Option<isize>, kind: &str) -> Result<isize, String> {
+    if id != LAST_ADDED {
+        return Ok(id);
+    }
+    last_added.ok_or_else(|| {
+        format!(
+            "Cannot set the last added {} because no {} has been added.",
+            kind, kind
+        )
+    })
+}
+
+/// Returns the highest field id of the fields and the types nested in them.
+fn max_field_id(fields: &[models::StructField]) -> isize {
+    fields
+        .iter()
+        .map(|field| field.id.max(max_type_id(&field.r#type)))
+        .max()
+        .unwrap_or(0)
+}
+
+fn max_type_id(r#type: &models::Type) -> isize {
+    match r#type {
+        models::Type::Primitive(_) => 0,
+        models::Type::Struct(r#struct) => max_field_id(&r#struct.fields),
+        models::Type::List(list) => list.element_id.max(max_type_id(&list.element)),
+        models::Type::Map(map) => map
+            .key_id
+            .max(map.value_id)
+            .max(max_type_id(&map.key))
+            .max(max_type_id(&map.value)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iceberg_catalog_rest_rdbms_server::models;
+
+    use super::MetadataBuilder;
+
+    fn metadata() -> models::TableMetadata {
+        serde_json::from_value(serde_json::json!({
+            "format-version": 1,
+            "table-uuid": "9c12d441-03fe-4693-9a96-a0705ddf69c1",
+            "location": "file:///tmp/events",
+            "last-updated-ms": 1602638573590i64,
+            "last-column-id": 1,
+            "current-schema-id": 0,
+            "schemas": [{
+                "type": "struct",
+                "schema-id": 0,
+                "fields": [{ "id": 1, "name": "id", "required": true, "type": "long" }]
+            }],
+            "default-spec-id": 0,
+            "partition-specs": [{ "spec-id": 0, "fields": [] }],
+            "last-partition-id": 999,
+            "default-sort-order-id": 0,
+            "sort-orders": [{ "order-id": 0, "fields": [] }],
+            "properties": { "owner": "me" },
+            "snapshots": [{
+                "snapshot-id": 1,
+                "timestamp-ms": 1602638573000i64,
+                "manifest-list": "file:///tmp/events/metadata/snap-1.avro",
+                "summary": { "operation": "append" }
+            }],
+            "current-snapshot-id": 1,
+            "refs": { "main": { "type": "branch", "snapshot-id": 1 } },
+            "snapshot-log": [{ "snapshot-id": 1, "timestamp-ms": 1602638573000i64 }],
+            "metadata-log": []
+        }))
+        .unwrap()
+    }
+
+    fn apply(builder: &mut MetadataBuilder, update: serde_json::Value) -> Result<(), String> {
+        builder.apply(&serde_json::from_value(update).unwrap())
+    }
+
+    #[test]
+    fn schemas_specs_and_orders() {
+        let mut builder = MetadataBuilder::new(metadata());
+        for update in [
+            serde_json::json!({ "action": "upgrade-format-version", "format-version": 2 }),
+            serde_json::json!({ "action": "add-schema", "schema": {
+                "type": "struct",
+                "fields": [
+                    { "id": 1, "name": "id", "required": true, "type": "long" },
+                    { "id": 2, "name": "tags", "required": false, "type": {
+                        "type": "list", "element-id": 3, "element": "string", "element-required": false
+                    } }
+                ]
+            } }),
+            serde_json::json!({ "action": "set-current-schema", "schema-id": -1 }),
+            serde_json::json!({ "action": "add-spec", "spec": { "fields": [
+                { "source-id": 1, "transform": "identity", "name": "id" }
+            ] } }),
+            serde_json::json!({ "action": "set-default-spec", "spec-id": -1 }),
+            serde_json::json!({ "action": "add-sort-order", "sort-order": { "order-id": 5, "fields": [
+                { "source-id": 1, "transform": "identity", "direction": "asc", "null-order": "nulls-first" }
+            ] } }),
+            serde_json::json!({ "action": "set-default-sort-order", "sort-order-id": -1 }),
+            serde_json::json!({ "action": "set-properties", "updates": { "comment": "events" } }),
+            serde_json::json!({ "action": "remove-properties", "removals": ["owner"] }),
+            serde_json::json!({ "action": "set-location", "location": "file:///tmp/events2/" }),
+        ] {
+            apply(&mut builder, update).unwrap();
+        }
+        let metadata = builder.build(
+            Some("file:///tmp/events/metadata/00000-a.metadata.json"),
+            Some(1),
+        );
+
+        assert_eq!(metadata.format_version, 2);
+        assert_eq!(metadata.current_schema_id, Some(1));
+        assert_eq!(metadata.last_column_id, Some(3));
+        assert_eq!(metadata.default_spec_id, Some(1));
+        assert_eq!(
+            metadata.partition_specs.as_ref().unwrap()[1].fields[0].field_id,
+            Some(1000)
+        );
+        assert_eq!(metadata.last_partition_id, Some(1000));
+        assert_eq!(metadata.default_sort_order_id, Some(1));
+        assert_eq!(
+            metadata
+                .properties
+                .as_ref()
+                .unwrap()
+                .keys()
+                .collect::<Vec<_>>(),
+            vec!["comment"]
+        );
+        assert_eq!(metadata.location.as_deref(), Some("file:///tmp/events2"));
+        let metadata_log = metadata.metadata_log.unwrap();
+        assert_eq!(metadata_log.len(), 1);
+        assert_eq!(metadata_log[0].timestamp_ms, 1);
+    }
+
+    #[test]
+    fn existing_schemas_are_reused() {
+        let mut builder = MetadataBuilder::new(metadata());
+        apply(
+            &mut builder,
+            serde_json::json!({ "action": "add-schema", "last-column-id": 4, "schema": {
+                "type": "struct",
+                "fields": [{ "id": 1, "name": "id", "required": true, "type": "long" }]
+            } }),
+        )
+        .unwrap();
+        apply(
+            &mut builder,
+            serde_json::json!({ "action": "set-current-schema", "schema-id": -1 }),
+        )
+        .unwrap();
+        apply(
+            &mut builder,
+            serde_json::json!({ "action": "assign-uuid", "uuid": "9C12D441-03FE-4693-9A96-A0705DDF69C1" }),
+        )
+        .unwrap();
+        let metadata = builder.build(None, None);
+        assert_eq!(metadata.schemas.unwrap().len(), 1);
+        assert_eq!(metadata.current_schema_id, Some(0));
+        assert_eq!(metadata.last_column_id, Some(4));
+    }
+
+    #[test]
+    fn snapshots() {
+        let mut builder = MetadataBuilder::new(metadata());
+        apply(
+            &mut builder,
+            serde_json::json!({ "action": "add-snapshot", "snapshot": {
+                "snapshot-id": 2,
+                "parent-snapshot-id": 1,
+                "timestamp-ms": 1602638574000i64,
+                "manifest-list": "file:///tmp/events/metadata/snap-2.avro",
+                "summary": { "operation": "append" }
+            } }),
+        )
+        .unwrap();
+        for update in [
+            serde_json::json!({ "action": "set-snapshot-ref", "ref-name": "main", "type": "branch", "snapshot-id": 2 }),
+            serde_json::json!({ "action": "set-snapshot-ref", "ref-name": "audit", "type": "tag", "snapshot-id": 1 }),
+            serde_json::json!({ "action": "remove-snapshots", "snapshot-ids": [1] }),
+        ] {
+            apply(&mut builder, update).unwrap();
+        }
+        let metadata = builder.build(None, None);
+
+        assert_eq!(metadata.current_snapshot_id, Some(2));
+        assert_eq!(metadata.last_updated_ms, Some(1602638574000));
+        assert_eq!(metadata.snapshots.unwrap().len(), 1);
+        let refs = metadata.refs.unwrap();
+        assert_eq!(refs.len(), 1);
+        assert_eq!(refs["main"].snapshot_id, 2);
+        let snapshot_log = metadata.snapshot_log.unwrap();
+        assert_eq!(snapshot_log.len(), 1);
+        assert_eq!(snapshot_log[0].snapshot_id, 2);
+
+        let mut builder = MetadataBuilder::new(self::metadata());
+        apply(
+            &mut builder,
+            serde_json::json!({ "action": "remove-snapshot-ref", "ref-name": "main" }),
+        )
+        .unwrap();
+        let metadata = builder.build(None, None);
+        assert_eq!(metadata.current_snapshot_id, None);
+        assert!(metadata.refs.unwrap().is_empty());
+    }
+
+    #[test]
+    fn invalid_updates() {
+        for update in [
+            serde_json::json!({ "action": "assign-uuid", "uuid": "2cc52516-5e73-41f2-b139-545d41a4e151" }),
+            serde_json::json!({ "action": "upgrade-format-version", "format-version": 3 }),
+            serde_json::json!({ "action": "set-current-schema", "schema-id": 1 }),
+            serde_json::json!({ "action": "set-current-schema", "schema-id": -1 }),
+            serde_json::json!({ "action": "set-default-spec", "spec-id": -1 }),
+            serde_json::json!({ "action": "set-default-sort-order", "sort-order-id": 3 }),
+            serde_json::json!({ "action": "set-snapshot-ref", "ref-name": "main", "type": "branch", "snapshot-id": 2 }),
+            serde_json::json!({ "action": "add-snapshot", "snapshot": {
+                "snapshot-id": 1,
+                "timestamp-ms": 1602638574000i64,
+                "manifest-list": "file:///tmp/events/metadata/snap-1.avro",
+                "summary": { "operation": "append" }
+            } }),
+        ] {
+            let mut builder = MetadataBuilder::new(metadata());
+            assert!(apply(&mut builder, update).is_err());
+        }
+    }
+}
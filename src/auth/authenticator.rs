@@ -0,0 +1,278 @@
+use std::marker::PhantomData;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use futures::future::{BoxFuture, FutureExt};
+use hyper::header::{HeaderValue, CONTENT_TYPE, WWW_AUTHENTICATE};
+use hyper::service::Service;
+use hyper::{Body, Method, Request, Response, StatusCode};
+use jsonwebtoken::errors::ErrorKind;
+use log::debug;
+use swagger::auth::{AuthData, Authorization, RcBound, Scopes};
+use swagger::{Has, XSpanIdString};
+
+use iceberg_catalog_rest_rdbms_server::models;
+
+use super::Verifier;
+
+/// Path of the token endpoint, which clients call before they have a token.
+const TOKEN_PATH: &str = "/v1/oauth/tokens";
+
+/// Subject of the requests to the token endpoint without a bearer token.
+const ANONYMOUS: &str = "anonymous";
+
+/// Middleware that authorizes requests with a valid bearer token and rejects all
+/// others with 401, except those to the token endpoint.
+pub struct MakeBearerAuthenticator<T, RC> {
+    inner: T,
+    verifier: Arc<Verifier>,
+    marker: PhantomData<RC>,
+}
+
+impl<T, RC> MakeBearerAuthenticator<T, RC> {
+    pub fn new(inner: T, verifier: Arc<Verifier>) -> Self {
+        MakeBearerAuthenticator {
+            inner,
+            verifier,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<Inner, RC, Target> Service<Target> for MakeBearerAuthenticator<Inner, RC>
+where
+    Inner: Service<Target>,
+    Inner::Future: Send + 'static,
+    RC: 'static,
+{
+    type Error = Inner::Error;
+    type Response = BearerAuthenticator<Inner::Response, RC>;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, target: Target) -> Self::Future {
+        let verifier = self.verifier.clone();
+        Box::pin(self.inner.call(target).map(|service| {
+            Ok(BearerAuthenticator {
+                inner: service?,
+                verifier,
+                marker: PhantomData,
+            })
+        }))
+    }
+}
+
+/// Authenticator of the requests of a connection.
+pub struct BearerAuthenticator<T, RC> {
+    inner: T,
+    verifier: Arc<Verifier>,
+    marker: PhantomData<RC>,
+}
+
+impl<T, B, RC> Service<(Request<B>, RC)> for BearerAuthenticator<T, RC>
+where
+    RC: RcBound + Has<Option<AuthData>> + Has<XSpanIdString>,
+    RC::Result: Send + 'static,
+    T: Service<(Request<B>, RC::Result), Response = Response<Body>>,
+    T::Error: Send + 'static,
+    T::Future: Send + 'static,
+{
+    type Response = Response<Body>;
+    type Error = T::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: (Request<B>, RC)) -> Self::Future {
+        let (request, context) = req;
+        let authorization = match Has::<Option<AuthData>>::get(&context) {
+            Some(AuthData::Bearer(bearer)) => self.verifier.verify(&bearer.token).map_err(|err| {
+                debug!(
+                    "Rejected bearer token - X-Span-ID: {}: {}",
+                    Has::<XSpanIdString>::get(&context).0,
+                    err
+                );
+                match err.kind() {
+                    ErrorKind::ExpiredSignature => "The bearer token has expired.",
+                    _ => "The bearer token is invalid.",
+                }
+            }),
+            _ => Err("The request has no bearer token."),
+        };
+
+        let authorization = match authorization {
+            Ok(authorization) => authorization,
+            // Clients obtain their tokens from the token endpoint, which checks the
+            // credentials of the request itself
+            Err(_) if request.method() == Method::POST && request.uri().path() == TOKEN_PATH => {
+                Authorization {
+                    subject: ANONYMOUS.to_owned(),
+                    scopes: Scopes::All,
+                    issuer: None,
+                }
+            }
+            Err(message) => return Box::pin(futures::future::ok(unauthorized(message))),
+        };
+
+        Box::pin(
+            self.inner
+                .call((request, context.push(Some(authorization)))),
+        )
+    }
+}
+
+/// Returns a 401 response with an error model as body.
+fn unauthorized(message: &str) -> Response<Body> {
+    let body = models::ErrorModel::new(message.to_owned(), "NotAuthorizedException".into(), 401);
+    let mut response = Response::new(Body::from(
+        serde_json::to_string(&body).expect("impossible to fail to serialize"),
+    ));
+    *response.status_mut() = StatusCode::UNAUTHORIZED;
+    response
+        .headers_mut()
+        .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+    response
+        .headers_mut()
+        .insert(WWW_AUTHENTICATE, HeaderValue::from_static("Bearer"));
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+    use std::sync::Arc;
+    use std::task::{Context, Poll};
+
+    use hyper::service::Service;
+    use hyper::{Body, Method, Request, Response, StatusCode};
+    use swagger::auth::{AuthData, Authorization};
+    use swagger::{
+        make_context, make_context_ty, ContextBuilder, EmptyContext, Has, Push, XSpanIdString,
+    };
+
+    use super::super::{TokenIssuer, Verifier};
+    use super::MakeBearerAuthenticator;
+
+    type RequestContext = make_context_ty!(
+        ContextBuilder,
+        EmptyContext,
+        Option<AuthData>,
+        XSpanIdString
+    );
+
+    struct MakeTestService;
+
+    impl<Target> Service<Target> for MakeTestService {
+        type Response = TestService;
+        type Error = Infallible;
+        type Future = futures::future::Ready<Result<Self::Response, Self::Error>>;
+
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, _target: Target) -> Self::Future {
+            futures::future::ok(TestService)
+        }
+    }
+
+    /// Answers with the subject of the authorization of the request.
+    struct TestService;
+
+    impl<C: Has<Option<Authorization>>> Service<(Request<Body>, C)> for TestService {
+        type Response = Response<Body>;
+        type Error = Infallible;
+        type Future = futures::future::Ready<Result<Self::Response, Self::Error>>;
+
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, (_, context): (Request<Body>, C)) -> Self::Future {
+            let subject = Has::<Option<Authorization>>::get(&context)
+                .as_ref()
+                .map(|authorization| authorization.subject.clone())
+                .unwrap_or_default();
+            futures::future::ok(Response::new(Body::from(subject)))
+        }
+    }
+
+    /// Sends a request with the bearer token to the authenticator and returns the
+    /// status and body of the response.
+    async fn send(method: Method, path: &str, token: Option<&str>) -> (StatusCode, String) {
+        let verifier =
+            Verifier::new(vec!["catalog".into()], vec!["iceberg".into()]).with_secret(b"key");
+        let mut make_service =
+            MakeBearerAuthenticator::<_, RequestContext>::new(MakeTestService, Arc::new(verifier));
+        let mut service = make_service.call(()).await.unwrap();
+
+        let auth_data = token.map(AuthData::bearer);
+        let request = Request::builder()
+            .method(method)
+            .uri(format!("http://localhost{}", path))
+            .body(Body::empty())
+            .unwrap();
+        let response = service
+            .call((
+                request,
+                make_context!(
+                    ContextBuilder,
+                    EmptyContext,
+                    auth_data,
+                    XSpanIdString::default()
+                ),
+            ))
+            .await
+            .unwrap();
+        let status = response.status();
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        (status, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    fn token(lifetime: i64) -> String {
+        let issuer = TokenIssuer::new(b"key", "catalog".into(), "iceberg".into(), lifetime);
+        issuer.sign(&issuer.claims("alice", "catalog")).unwrap()
+    }
+
+    #[tokio::test]
+    async fn bearer_tokens() {
+        let (status, body) = send(Method::GET, "/v1/config", Some(&token(3600))).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, "alice");
+
+        for (token, message) in [
+            (None, "The request has no bearer token."),
+            (
+                Some("not a token".to_owned()),
+                "The bearer token is invalid.",
+            ),
+            (Some(token(-3600)), "The bearer token has expired."),
+        ] {
+            let (status, body) = send(Method::GET, "/v1/config", token.as_deref()).await;
+            assert_eq!(status, StatusCode::UNAUTHORIZED);
+            let body: serde_json::Value = serde_json::from_str(&body).unwrap();
+            assert_eq!(body["type"], "NotAuthorizedException");
+            assert_eq!(body["code"], 401);
+            assert_eq!(body["message"], message);
+        }
+    }
+
+    #[tokio::test]
+    async fn token_endpoint() {
+        let (status, body) = send(Method::POST, "/v1/oauth/tokens", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, "anonymous");
+        let (status, body) = send(Method::POST, "/v1/oauth/tokens", Some("not a token")).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, "anonymous");
+
+        // Only token requests are exempt
+        let (status, _) = send(Method::GET, "/v1/oauth/tokens", None).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+    }
+}
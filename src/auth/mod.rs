@@ -0,0 +1,180 @@
+//! Access tokens and client secrets.
+//!
+//! The catalog issues its own access tokens at `/v1/oauth/tokens`. They are JWTs
+//! signed with HS256 that carry the client as subject and the granted scopes.
+//! Tokens obtained by token exchange also carry the service acting on behalf of
+//! the subject in the `act` claim of RFC 8693. Client secrets are only stored as
+//! Argon2 hashes.
+//!
+//! Every other request has to carry a bearer token, which is verified against the
+//! catalog's own key and the configured keys of external identity providers.
+
+use argon2::password_hash::{rand_core::OsRng, PasswordHash, PasswordHasher, SaltString};
+use argon2::{Argon2, PasswordVerifier};
+use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey, Header, Validation};
+use serde::{Deserialize, Serialize};
+
+mod authenticator;
+mod verifier;
+
+pub use self::authenticator::MakeBearerAuthenticator;
+pub use self::verifier::Verifier;
+
+/// Claims of the access tokens issued by the catalog.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Claims {
+    pub iss: String,
+    pub aud: String,
+    /// The client or user the token was issued to
+    pub sub: String,
+    pub iat: i64,
+    pub exp: i64,
+    pub jti: String,
+    /// Space separated scopes granted to the token
+    pub scope: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub act: Option<Actor>,
+}
+
+/// The party acting on behalf of the subject of a token. Earlier actors of a
+/// delegation chain are nested.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Actor {
+    pub sub: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub act: Option<Box<Actor>>,
+}
+
+/// Signs and verifies access tokens.
+pub struct TokenIssuer {
+    key: EncodingKey,
+    decoding_key: DecodingKey,
+    issuer: String,
+    audience: String,
+    lifetime: i64,
+}
+
+impl TokenIssuer {
+    /// Creates an issuer of tokens that are valid for `lifetime` seconds.
+    pub fn new(secret: &[u8], issuer: String, audience: String, lifetime: i64) -> Self {
+        TokenIssuer {
+            key: EncodingKey::from_secret(secret),
+            decoding_key: DecodingKey::from_secret(secret),
+            issuer,
+            audience,
+            lifetime,
+        }
+    }
+
+    /// Lifetime of the issued tokens in seconds.
+    pub fn lifetime(&self) -> i64 {
+        self.lifetime
+    }
+
+    /// Returns the claims of a new token for the subject.
+    pub fn claims(&self, subject: &str, scope: &str) -> Claims {
+        let now = chrono::Utc::now().timestamp();
+        Claims {
+            iss: self.issuer.clone(),
+            aud: self.audience.clone(),
+            sub: subject.to_owned(),
+            iat: now,
+            exp: now + self.lifetime,
+            jti: uuid::Uuid::new_v4().to_string(),
+            scope: scope.to_owned(),
+            act: None,
+        }
+    }
+
+    /// Signs the claims.
+    pub fn sign(&self, claims: &Claims) -> Result<String, jsonwebtoken::errors::Error> {
+        jsonwebtoken::encode(&Header::new(Algorithm::HS256), claims, &self.key)
+    }
+
+    /// Returns the claims of a token issued by the catalog that has not expired.
+    pub fn verify(&self, token: &str) -> Result<Claims, jsonwebtoken::errors::Error> {
+        let mut validation = Validation::new(Algorithm::HS256);
+        validation.set_issuer(&[&self.issuer]);
+        validation.set_audience(&[&self.audience]);
+        jsonwebtoken::decode::<Claims>(token, &self.decoding_key, &validation)
+            .map(|token| token.claims)
+    }
+}
+
+/// Returns the PHC string of a new Argon2 hash of the secret.
+pub fn hash_secret(secret: &str) -> Result<String, argon2::password_hash::Error> {
+    let salt = SaltString::generate(&mut OsRng);
+    Ok(Argon2::default()
+        .hash_password(secret.as_bytes(), &salt)?
+        .to_string())
+}
+
+/// Checks a secret against the PHC string of its hash.
+pub fn verify_secret(secret: &str, hash: &str) -> bool {
+    PasswordHash::new(hash)
+        .and_then(|hash| Argon2::default().verify_password(secret.as_bytes(), &hash))
+        .is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use jsonwebtoken::{DecodingKey, Validation};
+
+    use super::{hash_secret, verify_secret, Actor, Claims, TokenIssuer};
+
+    #[test]
+    fn secrets() {
+        let hash = hash_secret("secret").unwrap();
+        assert!(!hash.contains("secret"));
+        assert!(verify_secret("secret", &hash));
+        assert!(!verify_secret("other", &hash));
+        assert!(!verify_secret("secret", "not a hash"));
+    }
+
+    #[test]
+    fn tokens() {
+        let issuer = TokenIssuer::new(b"key", "catalog".into(), "iceberg".into(), 3600);
+        let claims = issuer.claims("spark", "catalog");
+        let token = issuer.sign(&claims).unwrap();
+
+        let mut validation = Validation::new(jsonwebtoken::Algorithm::HS256);
+        validation.set_audience(&["iceberg"]);
+        validation.set_issuer(&["catalog"]);
+        let decoded =
+            jsonwebtoken::decode::<Claims>(&token, &DecodingKey::from_secret(b"key"), &validation)
+                .unwrap();
+        assert_eq!(decoded.claims, claims);
+        assert_eq!(claims.exp - claims.iat, 3600);
+        assert!(jsonwebtoken::decode::<Claims>(
+            &token,
+            &DecodingKey::from_secret(b"other"),
+            &validation
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn verification() {
+        let issuer = TokenIssuer::new(b"key", "catalog".into(), "iceberg".into(), 3600);
+        let mut claims = issuer.claims("alice", "catalog");
+        claims.act = Some(Actor {
+            sub: "gateway".into(),
+            act: None,
+        });
+        let token = issuer.sign(&claims).unwrap();
+        assert_eq!(issuer.verify(&token).unwrap(), claims);
+
+        let other = TokenIssuer::new(b"key", "other".into(), "iceberg".into(), 3600);
+        assert!(issuer
+            .verify(&other.sign(&other.claims("alice", "")).unwrap())
+            .is_err());
+        let expired = TokenIssuer::new(b"key", "catalog".into(), "iceberg".into(), -3600);
+        assert!(issuer
+            .verify(&expired.sign(&expired.claims("alice", "")).unwrap())
+            .is_err());
+        let forged = TokenIssuer::new(b"other", "catalog".into(), "iceberg".into(), 3600);
+        assert!(issuer
+            .verify(&forged.sign(&forged.claims("alice", "")).unwrap())
+            .is_err());
+    }
+}
@@ -0,0 +1,215 @@
+use std::collections::BTreeSet;
+use std::path::Path;
+
+use jsonwebtoken::errors::{Error, ErrorKind};
+use jsonwebtoken::jwk::{AlgorithmParameters, JwkSet};
+use jsonwebtoken::{DecodingKey, Validation};
+use serde::Deserialize;
+use swagger::auth::{Authorization, Scopes};
+
+use super::Actor;
+
+/// A key bearer tokens may be signed with.
+struct VerificationKey {
+    /// Matched against the `kid` header of tokens if both are set
+    kid: Option<String>,
+    key: DecodingKey,
+}
+
+/// Claims of a bearer token that determine the authorization of a request.
+#[derive(Deserialize)]
+struct BearerClaims {
+    sub: String,
+    #[serde(default)]
+    scope: Option<String>,
+    #[serde(default)]
+    act: Option<Actor>,
+}
+
+/// Verifies bearer tokens against the configured keys, issuers and audiences.
+///
+/// The subject of a valid token becomes the subject of the request's
+/// [`Authorization`], its scopes the scopes and the actor of a delegated token, the
+/// client acting on behalf of the subject, the issuer.
+pub struct Verifier {
+    keys: Vec<VerificationKey>,
+    issuers: Vec<String>,
+    audiences: Vec<String>,
+}
+
+impl Verifier {
+    pub fn new(issuers: Vec<String>, audiences: Vec<String>) -> Self {
+        Verifier {
+            keys: Vec::new(),
+            issuers,
+            audiences,
+        }
+    }
+
+    /// Adds a shared secret for HMAC signed tokens.
+    pub fn with_secret(mut self, secret: &[u8]) -> Self {
+        self.keys.push(VerificationKey {
+            kid: None,
+            key: DecodingKey::from_secret(secret),
+        });
+        self
+    }
+
+    /// Adds an RSA, EC or Ed25519 public key in PEM format.
+    pub fn with_pem(mut self, pem: &[u8]) -> Result<Self, Error> {
+        let key = DecodingKey::from_rsa_pem(pem)
+            .or_else(|_| DecodingKey::from_ec_pem(pem))
+            .or_else(|_| DecodingKey::from_ed_pem(pem))?;
+        self.keys.push(VerificationKey { kid: None, key });
+        Ok(self)
+    }
+
+    /// Adds the keys of a JWK set.
+    pub fn with_jwks(mut self, jwks: &JwkSet) -> Result<Self, Error> {
+        for jwk in &jwks.keys {
+            let key = match &jwk.algorithm {
+                // The value of symmetric keys is unpadded base64url, which
+                // `DecodingKey::from_jwk` doesn't accept
+                AlgorithmParameters::OctetKey(octet) => {
+                    let secret = base64::decode_config(&octet.value, base64::URL_SAFE_NO_PAD)
+                        .map_err(|_| Error::from(ErrorKind::InvalidKeyFormat))?;
+                    DecodingKey::from_secret(&secret)
+                }
+                _ => DecodingKey::from_jwk(jwk)?,
+            };
+            self.keys.push(VerificationKey {
+                kid: jwk.common.key_id.clone(),
+                key,
+            });
+        }
+        Ok(self)
+    }
+
+    /// Adds the keys of a JWK set stored in a file.
+    pub fn with_jwks_file(self, path: &Path) -> Result<Self, String> {
+        let jwks = std::fs::read(path).map_err(|err| err.to_string())?;
+        let jwks: JwkSet = serde_json::from_slice(&jwks).map_err(|err| err.to_string())?;
+        self.with_jwks(&jwks).map_err(|err| err.to_string())
+    }
+
+    /// Returns the authorization granted by a valid token.
+    pub fn verify(&self, token: &str) -> Result<Authorization, Error> {
+        let header = jsonwebtoken::decode_header(token)?;
+        let mut validation = Validation::new(header.alg);
+        validation.set_issuer(&self.issuers);
+        validation.set_audience(&self.audiences);
+
+        let mut result = Err(Error::from(ErrorKind::InvalidSignature));
+        let keys = self
+            .keys
+            .iter()
+            .filter(|key| key.kid.is_none() || header.kid.is_none() || key.kid == header.kid);
+        for key in keys {
+            result = jsonwebtoken::decode::<BearerClaims>(token, &key.key, &validation);
+            match &result {
+                // Keys of another algorithm or another signer
+                Err(err)
+                    if matches!(
+                        err.kind(),
+                        ErrorKind::InvalidAlgorithm | ErrorKind::InvalidSignature
+                    ) => {}
+                _ => break,
+            }
+        }
+
+        let claims = result?.claims;
+        Ok(Authorization {
+            subject: claims.sub,
+            scopes: Scopes::Some(
+                claims
+                    .scope
+                    .unwrap_or_default()
+                    .split_whitespace()
+                    .map(str::to_owned)
+                    .collect::<BTreeSet<_>>(),
+            ),
+            issuer: claims.act.map(|actor| actor.sub),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use jsonwebtoken::errors::ErrorKind;
+    use jsonwebtoken::jwk::JwkSet;
+    use jsonwebtoken::{Algorithm, EncodingKey, Header};
+    use swagger::auth::Scopes;
+
+    use super::super::{Actor, TokenIssuer};
+    use super::Verifier;
+
+    fn verifier() -> Verifier {
+        Verifier::new(vec!["catalog".into()], vec!["iceberg".into()]).with_secret(b"key")
+    }
+
+    #[test]
+    fn catalog_tokens() {
+        let issuer = TokenIssuer::new(b"key", "catalog".into(), "iceberg".into(), 3600);
+        let mut claims = issuer.claims("alice", "catalog admin");
+        claims.act = Some(Actor {
+            sub: "gateway".into(),
+            act: None,
+        });
+
+        let authorization = verifier().verify(&issuer.sign(&claims).unwrap()).unwrap();
+        assert_eq!(authorization.subject, "alice");
+        assert_eq!(authorization.issuer.as_deref(), Some("gateway"));
+        assert_eq!(
+            authorization.scopes,
+            Scopes::Some(["admin".to_owned(), "catalog".to_owned()].into())
+        );
+
+        let expired = TokenIssuer::new(b"key", "catalog".into(), "iceberg".into(), -3600);
+        let token = expired.sign(&expired.claims("alice", "")).unwrap();
+        assert_eq!(
+            verifier().verify(&token).unwrap_err().kind(),
+            &ErrorKind::ExpiredSignature
+        );
+        let audience = TokenIssuer::new(b"key", "catalog".into(), "other".into(), 3600);
+        let token = audience.sign(&audience.claims("alice", "")).unwrap();
+        assert_eq!(
+            verifier().verify(&token).unwrap_err().kind(),
+            &ErrorKind::InvalidAudience
+        );
+        let forged = TokenIssuer::new(b"other", "catalog".into(), "iceberg".into(), 3600);
+        let token = forged.sign(&forged.claims("alice", "")).unwrap();
+        assert_eq!(
+            verifier().verify(&token).unwrap_err().kind(),
+            &ErrorKind::InvalidSignature
+        );
+        assert!(verifier()
+            .verify("eyJhbGciOiJub25lIn0.eyJzdWIiOiJhbGljZSJ9.")
+            .is_err());
+    }
+
+    #[test]
+    fn jwks() {
+        let jwks: JwkSet = serde_json::from_value(serde_json::json!({
+            "keys": [{"kty": "oct", "kid": "idp", "k": "aWRwLWtleQ"}]
+        }))
+        .unwrap();
+        let verifier = verifier().with_jwks(&jwks).unwrap();
+
+        let claims = serde_json::json!({
+            "iss": "catalog", "aud": "iceberg", "sub": "bob",
+            "exp": chrono::Utc::now().timestamp() + 60,
+        });
+        let mut header = Header::new(Algorithm::HS256);
+        header.kid = Some("idp".into());
+        let token =
+            jsonwebtoken::encode(&header, &claims, &EncodingKey::from_secret(b"idp-key")).unwrap();
+        let authorization = verifier.verify(&token).unwrap();
+        assert_eq!(authorization.subject, "bob");
+        assert_eq!(authorization.issuer, None);
+
+        header.kid = Some("unknown".into());
+        let token =
+            jsonwebtoken::encode(&header, &claims, &EncodingKey::from_secret(b"idp-key")).unwrap();
+        assert!(verifier.verify(&token).is_err());
+    }
+}
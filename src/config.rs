@@ -0,0 +1,816 @@
+//! Settings of the server.
+//!
+//! Settings are read from an optional TOML file, then overridden by environment
+//! variables and finally by command line flags. The database is given either as a
+//! full URL or as components, whose user and password are escaped when the URL is
+//! built. Invalid settings are reported at startup as a [`ConfigError`] that names
+//! the setting.
+//!
+//! ```toml
+//! [catalog]
+//! warehouse = "s3://bucket/warehouse"
+//! config-defaults = { "write.format.default" = "parquet" }
+//!
+//! [metrics]
+//! reporters = ["log", "jsonl"]
+//! jsonl-path = "/var/log/iceberg/metrics.jsonl"
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::net::{IpAddr, SocketAddr};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::Duration;
+
+use log::LevelFilter;
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use sea_orm::ConnectOptions;
+use serde::Deserialize;
+
+use crate::io;
+
+/// Characters of the user and password of a database URL that are not escaped
+const USERINFO: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+/// Backends whose databases are created by the server
+const DATABASE_TYPES: [&str; 2] = ["postgres", "mysql"];
+
+/// Settings of the server.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub server: ServerConfig,
+    pub database: DatabaseConfig,
+    pub auth: AuthConfig,
+    pub catalog: CatalogConfig,
+    pub metrics: MetricsConfig,
+    pub log: LogConfig,
+}
+
+/// Address the REST API is served at and its TLS files.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct ServerConfig {
+    /// IP address to listen at
+    pub host: String,
+    pub port: u16,
+    /// Serves HTTPS with the key and certificate chain files
+    pub https: bool,
+    /// PEM file of the private key of the server
+    pub tls_key_file: Option<PathBuf>,
+    /// PEM file of the certificate chain of the server
+    pub tls_chain_file: Option<PathBuf>,
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        ServerConfig {
+            host: "0.0.0.0".to_owned(),
+            port: 8080,
+            https: false,
+            tls_key_file: None,
+            tls_chain_file: None,
+        }
+    }
+}
+
+impl ServerConfig {
+    /// Returns the address to listen at.
+    pub fn addr(&self) -> Result<SocketAddr, ConfigError> {
+        let ip = self
+            .host
+            .parse::<IpAddr>()
+            .map_err(|_| invalid("server.host", format!("{} is no IP address", self.host)))?;
+        Ok(SocketAddr::new(ip, self.port))
+    }
+}
+
+/// Connection and pool settings of the database.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct DatabaseConfig {
+    /// Full URL of the database, which is used instead of the components
+    pub url: Option<String>,
+    /// Backend of the database server, `postgres` or `mysql`
+    #[serde(rename = "type")]
+    pub r#type: String,
+    pub host: Option<String>,
+    pub port: u16,
+    pub user: String,
+    pub password: Option<String>,
+    /// Database that is created on the server if it doesn't exist yet
+    pub name: String,
+    /// Maximum number of pooled connections
+    pub max_connections: u32,
+    /// Number of connections the pool keeps open
+    pub min_connections: u32,
+    /// Seconds to wait for a connection
+    pub connect_timeout: u64,
+    /// Seconds after which an unused connection is closed
+    pub idle_timeout: u64,
+    /// Logs every statement
+    pub sql_logging: bool,
+}
+
+impl Default for DatabaseConfig {
+    fn default() -> Self {
+        DatabaseConfig {
+            url: None,
+            r#type: "postgres".to_owned(),
+            host: None,
+            port: 5432,
+            user: "postgres".to_owned(),
+            password: None,
+            name: "iceberg_catalog".to_owned(),
+            max_connections: 10,
+            min_connections: 0,
+            connect_timeout: 30,
+            idle_timeout: 600,
+            sql_logging: false,
+        }
+    }
+}
+
+/// Where the database of the catalog is found.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DatabaseLocation {
+    /// Full URL of an existing database
+    Url(String),
+    /// URL of a database server without a database, and the database to create on it
+    Server { url: String, name: String },
+}
+
+impl DatabaseConfig {
+    /// Returns the location of the database, building the URL from the components
+    /// unless a full URL is configured.
+    pub fn location(&self) -> DatabaseLocation {
+        if let Some(url) = &self.url {
+            return DatabaseLocation::Url(url.clone());
+        }
+        let mut userinfo = utf8_percent_encode(&self.user, USERINFO).to_string();
+        if let Some(password) = &self.password {
+            userinfo.push(':');
+            userinfo.extend(utf8_percent_encode(password, USERINFO));
+        }
+        // IPv6 addresses are bracketed to separate them from the port
+        let host = match self.host.as_deref().unwrap_or_default() {
+            host if host.contains(':') && !host.starts_with('[') => format!("[{}]", host),
+            host => host.to_owned(),
+        };
+        DatabaseLocation::Server {
+            url: format!("{}://{}@{}:{}", self.r#type, userinfo, host, self.port),
+            name: self.name.clone(),
+        }
+    }
+
+    /// Returns the setting that locates the database, to report failures to use it.
+    pub fn location_setting(&self) -> &'static str {
+        match self.url {
+            Some(_) => "database.url",
+            None => "database.host",
+        }
+    }
+
+    /// Returns the options of a pool of connections to `url`.
+    pub fn connect_options(&self, url: String) -> ConnectOptions {
+        let mut options = ConnectOptions::new(url);
+        options
+            .max_connections(self.max_connections)
+            .min_connections(self.min_connections)
+            .connect_timeout(Duration::from_secs(self.connect_timeout))
+            .idle_timeout(Duration::from_secs(self.idle_timeout))
+            .sqlx_logging(self.sql_logging);
+        options
+    }
+}
+
+/// Settings of the access tokens the catalog issues and accepts.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct AuthConfig {
+    /// Issuer of the access tokens
+    pub token_issuer: String,
+    /// Audience of the access tokens
+    pub token_audience: String,
+    /// Lifetime of the access tokens in seconds
+    pub token_lifetime: i64,
+    /// Key the access tokens are signed with. A random key is used if not set.
+    pub token_signing_key: Option<String>,
+    /// Issuers of bearer tokens from an identity provider that are accepted besides
+    /// the catalog's own tokens
+    pub jwt_issuers: Vec<String>,
+    /// Audiences of bearer tokens from an identity provider that are accepted
+    /// besides the audience of the catalog's own tokens
+    pub jwt_audiences: Vec<String>,
+    /// PEM file of the public key bearer tokens from an identity provider are
+    /// signed with
+    pub jwt_public_key: Option<PathBuf>,
+    /// JWKS file of the keys bearer tokens from an identity provider are signed with
+    pub jwks: Option<PathBuf>,
+    /// Subjects of principals that hold every privilege and manage the roles with
+    /// tokens of the `admin` scope
+    pub admins: Vec<String>,
+}
+
+impl Default for AuthConfig {
+    fn default() -> Self {
+        AuthConfig {
+            token_issuer: "iceberg-catalog".to_owned(),
+            token_audience: "iceberg-catalog".to_owned(),
+            token_lifetime: 3600,
+            token_signing_key: None,
+            jwt_issuers: Vec::new(),
+            jwt_audiences: Vec::new(),
+            jwt_public_key: None,
+            jwks: None,
+            admins: Vec::new(),
+        }
+    }
+}
+
+/// Where tables are stored and how catalogs are created and configured.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct CatalogConfig {
+    /// Location below which tables are created if no location is requested
+    pub warehouse: Option<String>,
+    /// Rejects requests for prefixes without a catalog instead of creating the
+    /// catalog with its first namespace or table
+    pub strict_catalogs: bool,
+    /// Properties every catalog pushes to clients as defaults
+    pub config_defaults: BTreeMap<String, String>,
+    /// Properties every catalog pushes to clients as overrides
+    pub config_overrides: BTreeMap<String, String>,
+}
+
+/// Sinks the metrics reports are handed to.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct MetricsConfig {
+    pub reporters: Vec<MetricsReporterKind>,
+    /// File the jsonl sink appends the reports to
+    pub jsonl_path: PathBuf,
+    /// Size in bytes at which the file of the jsonl sink is rotated
+    pub jsonl_max_bytes: u64,
+    /// Number of rotated files the jsonl sink keeps
+    pub jsonl_max_files: usize,
+    /// Address the prometheus sink serves the metrics at
+    pub prometheus_addr: SocketAddr,
+}
+
+impl Default for MetricsConfig {
+    fn default() -> Self {
+        MetricsConfig {
+            reporters: Vec::new(),
+            jsonl_path: "metrics-reports.jsonl".into(),
+            jsonl_max_bytes: 100 * 1024 * 1024,
+            jsonl_max_files: 5,
+            prometheus_addr: ([0, 0, 0, 0], 9464).into(),
+        }
+    }
+}
+
+/// A sink of metrics reports.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MetricsReporterKind {
+    /// Log every report as a JSON line
+    Log,
+    /// Append every report to a rotated JSON lines file
+    Jsonl,
+    /// Expose the reports as Prometheus metrics
+    Prometheus,
+}
+
+impl FromStr for MetricsReporterKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "log" => Ok(MetricsReporterKind::Log),
+            "jsonl" => Ok(MetricsReporterKind::Jsonl),
+            "prometheus" => Ok(MetricsReporterKind::Prometheus),
+            _ => Err("expected log, jsonl or prometheus".to_owned()),
+        }
+    }
+}
+
+/// Settings of the log.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LogConfig {
+    /// Levels of the log in the syntax of `RUST_LOG`, e.g. `info,sqlx=warn`
+    pub filter: String,
+}
+
+impl Default for LogConfig {
+    fn default() -> Self {
+        LogConfig {
+            filter: "info".to_owned(),
+        }
+    }
+}
+
+/// Error of a setting, which prevents the server from starting.
+#[derive(Debug)]
+pub enum ConfigError {
+    /// The configuration file can't be read
+    Read(PathBuf, std::io::Error),
+    /// The configuration file is no valid TOML or has unknown or mistyped settings
+    Parse(PathBuf, toml::de::Error),
+    /// An environment variable can't be parsed
+    Env(&'static str, String),
+    /// A setting has an invalid value
+    Invalid(&'static str, String),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Read(path, err) => write!(
+                f,
+                "Failed to read the configuration file {}: {}",
+                path.display(),
+                err
+            ),
+            ConfigError::Parse(path, err) => {
+                write!(f, "Invalid configuration file {}: {}", path.display(), err)
+            }
+            ConfigError::Env(name, message) => {
+                write!(f, "Invalid environment variable {}: {}", name, message)
+            }
+            ConfigError::Invalid(setting, message) => {
+                write!(f, "Invalid setting {}: {}", setting, message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+impl Config {
+    /// Reads the settings from a TOML file. Settings missing from the file keep their
+    /// defaults.
+    pub fn from_file(path: &Path) -> Result<Self, ConfigError> {
+        let text =
+            std::fs::read_to_string(path).map_err(|err| ConfigError::Read(path.to_owned(), err))?;
+        toml::from_str(&text).map_err(|err| ConfigError::Parse(path.to_owned(), err))
+    }
+
+    /// Overrides the settings with the environment variables returned by `var`.
+    ///
+    /// The database is configured with `DB_URL` or with `DB_TYPE`, `DB_HOST`,
+    /// `DB_PORT`, `DB_USER`, `DB_PASSWORD` and `DB_NAME`, its pool with
+    /// `DB_MAX_CONNECTIONS`, `DB_MIN_CONNECTIONS`, `DB_CONNECT_TIMEOUT` and
+    /// `DB_IDLE_TIMEOUT`. The address is set with `HOST` and `PORT`, HTTPS with
+    /// `HTTPS`, `TLS_KEY_FILE` and `TLS_CHAIN_FILE`, the signing key of the access
+    /// tokens with `TOKEN_SIGNING_KEY` and the log with `RUST_LOG`.
+    ///
+    /// The catalogs are configured with `WAREHOUSE`, `STRICT_CATALOGS` and the comma
+    /// separated `KEY=VALUE` properties of `CONFIG_DEFAULTS` and `CONFIG_OVERRIDES`,
+    /// the metrics sinks with the comma separated `METRICS_REPORTERS`,
+    /// `METRICS_JSONL_PATH`, `METRICS_JSONL_MAX_BYTES`, `METRICS_JSONL_MAX_FILES` and
+    /// `PROMETHEUS_ADDR`.
+    pub fn apply_env(&mut self, var: impl Fn(&str) -> Option<String>) -> Result<(), ConfigError> {
+        let database = &mut self.database;
+        if let Some(url) = var("DB_URL") {
+            database.url = Some(url);
+        }
+        if let Some(r#type) = var("DB_TYPE") {
+            database.r#type = r#type;
+        }
+        if let Some(host) = var("DB_HOST") {
+            database.host = Some(host);
+        }
+        if let Some(port) = var("DB_PORT") {
+            database.port = parse("DB_PORT", &port)?;
+        }
+        if let Some(user) = var("DB_USER") {
+            database.user = user;
+        }
+        if let Some(password) = var("DB_PASSWORD") {
+            database.password = Some(password);
+        }
+        if let Some(name) = var("DB_NAME") {
+            database.name = name;
+        }
+        if let Some(max_connections) = var("DB_MAX_CONNECTIONS") {
+            database.max_connections = parse("DB_MAX_CONNECTIONS", &max_connections)?;
+        }
+        if let Some(min_connections) = var("DB_MIN_CONNECTIONS") {
+            database.min_connections = parse("DB_MIN_CONNECTIONS", &min_connections)?;
+        }
+        if let Some(connect_timeout) = var("DB_CONNECT_TIMEOUT") {
+            database.connect_timeout = parse("DB_CONNECT_TIMEOUT", &connect_timeout)?;
+        }
+        if let Some(idle_timeout) = var("DB_IDLE_TIMEOUT") {
+            database.idle_timeout = parse("DB_IDLE_TIMEOUT", &idle_timeout)?;
+        }
+
+        let server = &mut self.server;
+        if let Some(host) = var("HOST") {
+            server.host = host;
+        }
+        if let Some(port) = var("PORT") {
+            server.port = parse("PORT", &port)?;
+        }
+        if let Some(https) = var("HTTPS") {
+            server.https = parse("HTTPS", &https)?;
+        }
+        if let Some(tls_key_file) = var("TLS_KEY_FILE") {
+            server.tls_key_file = Some(tls_key_file.into());
+        }
+        if let Some(tls_chain_file) = var("TLS_CHAIN_FILE") {
+            server.tls_chain_file = Some(tls_chain_file.into());
+        }
+        if let Some(token_signing_key) = var("TOKEN_SIGNING_KEY") {
+            self.auth.token_signing_key = Some(token_signing_key);
+        }
+
+        let catalog = &mut self.catalog;
+        if let Some(warehouse) = var("WAREHOUSE") {
+            catalog.warehouse = Some(warehouse);
+        }
+        if let Some(strict_catalogs) = var("STRICT_CATALOGS") {
+            catalog.strict_catalogs = parse("STRICT_CATALOGS", &strict_catalogs)?;
+        }
+        if let Some(config_defaults) = var("CONFIG_DEFAULTS") {
+            catalog.config_defaults = properties("CONFIG_DEFAULTS", &config_defaults)?;
+        }
+        if let Some(config_overrides) = var("CONFIG_OVERRIDES") {
+            catalog.config_overrides = properties("CONFIG_OVERRIDES", &config_overrides)?;
+        }
+
+        let metrics = &mut self.metrics;
+        if let Some(reporters) = var("METRICS_REPORTERS") {
+            metrics.reporters = reporters
+                .split(',')
+                .map(str::trim)
+                .filter(|reporter| !reporter.is_empty())
+                .map(|reporter| parse("METRICS_REPORTERS", reporter))
+                .collect::<Result<_, _>>()?;
+        }
+        if let Some(jsonl_path) = var("METRICS_JSONL_PATH") {
+            metrics.jsonl_path = jsonl_path.into();
+        }
+        if let Some(jsonl_max_bytes) = var("METRICS_JSONL_MAX_BYTES") {
+            metrics.jsonl_max_bytes = parse("METRICS_JSONL_MAX_BYTES", &jsonl_max_bytes)?;
+        }
+        if let Some(jsonl_max_files) = var("METRICS_JSONL_MAX_FILES") {
+            metrics.jsonl_max_files = parse("METRICS_JSONL_MAX_FILES", &jsonl_max_files)?;
+        }
+        if let Some(prometheus_addr) = var("PROMETHEUS_ADDR") {
+            metrics.prometheus_addr = parse("PROMETHEUS_ADDR", &prometheus_addr)?;
+        }
+
+        if let Some(filter) = var("RUST_LOG") {
+            self.log.filter = filter;
+        }
+        Ok(())
+    }
+
+    /// Checks the settings, reporting the first invalid one.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        let server = &self.server;
+        server.addr()?;
+        if server.https {
+            required_file("server.tls-key-file", &server.tls_key_file)?;
+            required_file("server.tls-chain-file", &server.tls_chain_file)?;
+        }
+
+        let database = &self.database;
+        match &database.url {
+            Some(url) if url.is_empty() => {
+                return Err(invalid("database.url", "has to be non-empty"));
+            }
+            Some(_) => (),
+            None => {
+                if !DATABASE_TYPES.contains(&database.r#type.as_str()) {
+                    return Err(invalid(
+                        "database.type",
+                        format!(
+                            "{} is not supported, use one of {}",
+                            database.r#type,
+                            DATABASE_TYPES.join(", ")
+                        ),
+                    ));
+                }
+                if database.host.as_deref().unwrap_or_default().is_empty() {
+                    return Err(invalid(
+                        "database.host",
+                        "has to be set unless database.url is set",
+                    ));
+                }
+                if database.user.is_empty() {
+                    return Err(invalid("database.user", "has to be non-empty"));
+                }
+                if database.name.is_empty()
+                    || !database
+                        .name
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || c == '_')
+                {
+                    return Err(invalid(
+                        "database.name",
+                        "has to be non-empty and may only contain ASCII letters, digits and \"_\"",
+                    ));
+                }
+            }
+        }
+        if database.max_connections == 0 {
+            return Err(invalid("database.max-connections", "has to be positive"));
+        }
+        if database.min_connections > database.max_connections {
+            return Err(invalid(
+                "database.min-connections",
+                "must not exceed database.max-connections",
+            ));
+        }
+        if database.connect_timeout == 0 {
+            return Err(invalid("database.connect-timeout", "has to be positive"));
+        }
+        if database.idle_timeout == 0 {
+            return Err(invalid("database.idle-timeout", "has to be positive"));
+        }
+
+        let auth = &self.auth;
+        if auth.token_issuer.is_empty() {
+            return Err(invalid("auth.token-issuer", "has to be non-empty"));
+        }
+        if auth.token_audience.is_empty() {
+            return Err(invalid("auth.token-audience", "has to be non-empty"));
+        }
+        if auth.token_lifetime <= 0 {
+            return Err(invalid("auth.token-lifetime", "has to be positive"));
+        }
+        if auth.token_signing_key.as_deref() == Some("") {
+            return Err(invalid("auth.token-signing-key", "has to be non-empty"));
+        }
+        if let Some(path) = &auth.jwt_public_key {
+            existing_file("auth.jwt-public-key", path)?;
+        }
+        if let Some(path) = &auth.jwks {
+            existing_file("auth.jwks", path)?;
+        }
+
+        if let Some(warehouse) = &self.catalog.warehouse {
+            if warehouse.trim_end_matches('/').is_empty() {
+                return Err(invalid("catalog.warehouse", "has to be non-empty"));
+            }
+            if !io::is_supported(warehouse) {
+                return Err(invalid(
+                    "catalog.warehouse",
+                    format!("the scheme of {} is not supported", warehouse),
+                ));
+            }
+        }
+        for (setting, properties) in [
+            ("catalog.config-defaults", &self.catalog.config_defaults),
+            ("catalog.config-overrides", &self.catalog.config_overrides),
+        ] {
+            if properties.keys().any(String::is_empty) {
+                return Err(invalid(setting, "property names have to be non-empty"));
+            }
+        }
+
+        let metrics = &self.metrics;
+        if metrics.reporters.contains(&MetricsReporterKind::Jsonl) {
+            if metrics.jsonl_path.as_os_str().is_empty() {
+                return Err(invalid("metrics.jsonl-path", "has to be non-empty"));
+            }
+            if metrics.jsonl_max_bytes == 0 {
+                return Err(invalid("metrics.jsonl-max-bytes", "has to be positive"));
+            }
+        }
+
+        validate_filter(&self.log.filter)
+    }
+}
+
+fn invalid(setting: &'static str, message: impl Into<String>) -> ConfigError {
+    ConfigError::Invalid(setting, message.into())
+}
+
+fn parse<T>(name: &'static str, value: &str) -> Result<T, ConfigError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    value
+        .parse()
+        .map_err(|err| ConfigError::Env(name, format!("{}: {}", value, err)))
+}
+
+/// Parses comma separated `KEY=VALUE` properties.
+fn properties(name: &'static str, value: &str) -> Result<BTreeMap<String, String>, ConfigError> {
+    value
+        .split(',')
+        .filter(|property| !property.trim().is_empty())
+        .map(|property| {
+            property
+                .split_once('=')
+                .map(|(key, value)| (key.trim().to_owned(), value.trim().to_owned()))
+                .ok_or_else(|| {
+                    ConfigError::Env(name, format!("expected KEY=VALUE, got {}", property))
+                })
+        })
+        .collect()
+}
+
+fn required_file(setting: &'static str, path: &Option<PathBuf>) -> Result<(), ConfigError> {
+    match path {
+        Some(path) => existing_file(setting, path),
+        None => Err(invalid(setting, "has to be set for HTTPS")),
+    }
+}
+
+fn existing_file(setting: &'static str, path: &Path) -> Result<(), ConfigError> {
+    if path.is_file() {
+        Ok(())
+    } else {
+        Err(invalid(
+            setting,
+            format!("{} is no readable file", path.display()),
+        ))
+    }
+}
+
+/// Checks the levels of a filter in the syntax of `RUST_LOG`. Directives without a
+/// level name a module.
+fn validate_filter(filter: &str) -> Result<(), ConfigError> {
+    let directives = filter.split('/').next().unwrap_or_default();
+    for directive in directives.split(',').map(str::trim) {
+        if let Some((_, level)) = directive.split_once('=') {
+            if LevelFilter::from_str(level).is_err() {
+                return Err(invalid("log.filter", format!("{} is no log level", level)));
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::{Config, ConfigError, DatabaseLocation, MetricsReporterKind};
+
+    fn env(pairs: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars = pairs
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect::<HashMap<_, _>>();
+        move |name| vars.get(name).cloned()
+    }
+
+    #[test]
+    fn environment_overrides_file() {
+        let mut config: Config = toml::from_str(
+            r#"
+            [database]
+            host = "db"
+            port = 5433
+            password = "p@ss/word"
+            max-connections = 20
+
+            [auth]
+            admins = ["alice"]
+
+            [catalog]
+            warehouse = "s3://bucket/warehouse"
+            config-defaults = { "write.format.default" = "parquet" }
+
+            [metrics]
+            reporters = ["log"]
+            jsonl-max-files = 3
+            "#,
+        )
+        .unwrap();
+        config
+            .apply_env(env(&[
+                ("DB_PORT", "6543"),
+                ("DB_USER", "iceberg"),
+                ("PORT", "8181"),
+                ("STRICT_CATALOGS", "true"),
+                (
+                    "CONFIG_OVERRIDES",
+                    "s3.endpoint=http://minio:9000, s3.path-style-access=true",
+                ),
+                ("METRICS_REPORTERS", "log,prometheus"),
+                ("PROMETHEUS_ADDR", "127.0.0.1:9000"),
+            ]))
+            .unwrap();
+        config.validate().unwrap();
+
+        assert_eq!(
+            config.database.location(),
+            DatabaseLocation::Server {
+                url: "postgres://iceberg:p%40ss%2Fword@db:6543".to_owned(),
+                name: "iceberg_catalog".to_owned(),
+            }
+        );
+        assert_eq!(config.database.max_connections, 20);
+        assert_eq!(config.auth.admins, vec!["alice".to_owned()]);
+        assert_eq!(config.server.addr().unwrap().to_string(), "0.0.0.0:8181");
+
+        config.database.host = Some("fd00::5".to_owned());
+        assert_eq!(
+            config.database.location(),
+            DatabaseLocation::Server {
+                url: "postgres://iceberg:p%40ss%2Fword@[fd00::5]:6543".to_owned(),
+                name: "iceberg_catalog".to_owned(),
+            }
+        );
+
+        let catalog = &config.catalog;
+        assert_eq!(catalog.warehouse.as_deref(), Some("s3://bucket/warehouse"));
+        assert!(catalog.strict_catalogs);
+        assert_eq!(catalog.config_defaults["write.format.default"], "parquet");
+        assert_eq!(catalog.config_overrides.len(), 2);
+        assert_eq!(catalog.config_overrides["s3.endpoint"], "http://minio:9000");
+
+        let metrics = &config.metrics;
+        assert_eq!(
+            metrics.reporters,
+            vec![MetricsReporterKind::Log, MetricsReporterKind::Prometheus]
+        );
+        assert_eq!(metrics.jsonl_max_files, 3);
+        assert_eq!(metrics.prometheus_addr.to_string(), "127.0.0.1:9000");
+    }
+
+    #[test]
+    fn invalid_settings() {
+        assert!(toml::from_str::<Config>("[database]\nhots = \"db\"").is_err());
+
+        let mut config = Config::default();
+        assert!(matches!(
+            config.apply_env(env(&[("DB_PORT", "postgres")])),
+            Err(ConfigError::Env("DB_PORT", _))
+        ));
+        assert!(matches!(
+            config.apply_env(env(&[("HTTPS", "yes")])),
+            Err(ConfigError::Env("HTTPS", _))
+        ));
+        assert!(matches!(
+            config.validate(),
+            Err(ConfigError::Invalid("database.host", _))
+        ));
+
+        config.database.url = Some("sqlite::memory:".to_owned());
+        config.validate().unwrap();
+
+        config.database.min_connections = 20;
+        assert!(matches!(
+            config.validate(),
+            Err(ConfigError::Invalid("database.min-connections", _))
+        ));
+        config.database.min_connections = 0;
+
+        config.apply_env(env(&[("HTTPS", "true")])).unwrap();
+        assert!(matches!(
+            config.validate(),
+            Err(ConfigError::Invalid("server.tls-key-file", _))
+        ));
+        config.server.https = false;
+
+        config.catalog.warehouse = Some("gs://bucket/warehouse".to_owned());
+        assert!(matches!(
+            config.validate(),
+            Err(ConfigError::Invalid("catalog.warehouse", _))
+        ));
+        config.catalog.warehouse = Some("/".to_owned());
+        assert!(matches!(
+            config.validate(),
+            Err(ConfigError::Invalid("catalog.warehouse", _))
+        ));
+        config.catalog.warehouse = None;
+
+        assert!(matches!(
+            config.apply_env(env(&[("CONFIG_DEFAULTS", "s3.endpoint")])),
+            Err(ConfigError::Env("CONFIG_DEFAULTS", _))
+        ));
+        assert!(matches!(
+            config.apply_env(env(&[("METRICS_REPORTERS", "log,statsd")])),
+            Err(ConfigError::Env("METRICS_REPORTERS", _))
+        ));
+        assert!(toml::from_str::<Config>("[metrics]\nreporters = [\"statsd\"]").is_err());
+        config.metrics.reporters = vec![MetricsReporterKind::Jsonl];
+        config.metrics.jsonl_max_bytes = 0;
+        assert!(matches!(
+            config.validate(),
+            Err(ConfigError::Invalid("metrics.jsonl-max-bytes", _))
+        ));
+        config.metrics.jsonl_max_bytes = 1024;
+
+        config.log.filter = "info,sqlx=loud".to_owned();
+        assert!(matches!(
+            config.validate(),
+            Err(ConfigError::Invalid("log.filter", _))
+        ));
+    }
+}
@@ -11,12 +11,31 @@ pub struct Model {
     pub id: i32,
     #[sea_orm(unique)]
     pub name: String,
+    pub storage_root: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(has_many = "super::catalog_config::Entity")]
+    CatalogConfig,
+    #[sea_orm(has_many = "super::catalog_property::Entity")]
+    CatalogProperty,
     #[sea_orm(has_many = "super::namespace::Entity")]
     Namespace,
+    #[sea_orm(has_many = "super::privilege_grant::Entity")]
+    PrivilegeGrant,
+}
+
+impl Related<super::catalog_config::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::CatalogConfig.def()
+    }
+}
+
+impl Related<super::catalog_property::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::CatalogProperty.def()
+    }
 }
 
 impl Related<super::namespace::Entity> for Entity {
@@ -25,4 +44,10 @@ impl Related<super::namespace::Entity> for Entity {
     }
 }
 
+impl Related<super::privilege_grant::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::PrivilegeGrant.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
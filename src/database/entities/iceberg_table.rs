@@ -9,7 +9,6 @@ pub struct Model {
     #[sea_orm(primary_key)]
     #[serde(skip_deserializing)]
     pub id: i32,
-    #[sea_orm(unique)]
     pub name: String,
     pub metadata_location: String,
     pub previous_metadata_location: Option<String>,
@@ -26,6 +25,18 @@ pub enum Relation {
         on_delete = "NoAction"
     )]
     Namespace,
+    #[sea_orm(has_many = "super::commit_report::Entity")]
+    CommitReport,
+    #[sea_orm(has_many = "super::privilege_grant::Entity")]
+    PrivilegeGrant,
+    #[sea_orm(has_many = "super::report_metric::Entity")]
+    ReportMetric,
+    #[sea_orm(has_many = "super::scan_report::Entity")]
+    ScanReport,
+    #[sea_orm(has_many = "super::table_commit::Entity")]
+    TableCommit,
+    #[sea_orm(has_many = "super::table_metadata::Entity")]
+    TableMetadata,
 }
 
 impl Related<super::namespace::Entity> for Entity {
@@ -34,4 +45,40 @@ impl Related<super::namespace::Entity> for Entity {
     }
 }
 
+impl Related<super::commit_report::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::CommitReport.def()
+    }
+}
+
+impl Related<super::privilege_grant::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::PrivilegeGrant.def()
+    }
+}
+
+impl Related<super::report_metric::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ReportMetric.def()
+    }
+}
+
+impl Related<super::scan_report::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ScanReport.def()
+    }
+}
+
+impl Related<super::table_commit::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TableCommit.def()
+    }
+}
+
+impl Related<super::table_metadata::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TableMetadata.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
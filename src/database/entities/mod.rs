@@ -3,5 +3,19 @@
 pub mod prelude;
 
 pub mod catalog;
+pub mod catalog_config;
+pub mod catalog_property;
+pub mod commit_report;
 pub mod iceberg_table;
 pub mod namespace;
+pub mod namespace_property;
+pub mod oauth_client;
+pub mod privilege_grant;
+pub mod report_metric;
+pub mod role;
+pub mod role_member;
+pub mod scan_report;
+pub mod staged_table;
+pub mod table_commit;
+pub mod table_metadata;
+pub mod table_purge;
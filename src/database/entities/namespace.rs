@@ -9,9 +9,9 @@ pub struct Model {
     #[sea_orm(primary_key)]
     #[serde(skip_deserializing)]
     pub id: i32,
-    #[sea_orm(unique)]
     pub name: String,
     pub catalog_id: Option<i32>,
+    pub parent_id: Option<i32>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -24,8 +24,22 @@ pub enum Relation {
         on_delete = "NoAction"
     )]
     Catalog,
+    #[sea_orm(
+        belongs_to = "Entity",
+        from = "Column::ParentId",
+        to = "Column::Id",
+        on_update = "NoAction",
+        on_delete = "NoAction"
+    )]
+    Parent,
     #[sea_orm(has_many = "super::iceberg_table::Entity")]
     IcebergTable,
+    #[sea_orm(has_many = "super::namespace_property::Entity")]
+    NamespaceProperty,
+    #[sea_orm(has_many = "super::privilege_grant::Entity")]
+    PrivilegeGrant,
+    #[sea_orm(has_many = "super::staged_table::Entity")]
+    StagedTable,
 }
 
 impl Related<super::catalog::Entity> for Entity {
@@ -40,4 +54,22 @@ impl Related<super::iceberg_table::Entity> for Entity {
     }
 }
 
+impl Related<super::namespace_property::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::NamespaceProperty.def()
+    }
+}
+
+impl Related<super::privilege_grant::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::PrivilegeGrant.def()
+    }
+}
+
+impl Related<super::staged_table::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::StagedTable.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
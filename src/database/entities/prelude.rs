@@ -1,5 +1,19 @@
 //! SeaORM Entity. Generated by sea-orm-codegen 0.10.0
 
 pub use super::catalog::Entity as Catalog;
+pub use super::catalog_config::Entity as CatalogConfig;
+pub use super::catalog_property::Entity as CatalogProperty;
+pub use super::commit_report::Entity as CommitReport;
 pub use super::iceberg_table::Entity as IcebergTable;
 pub use super::namespace::Entity as Namespace;
+pub use super::namespace_property::Entity as NamespaceProperty;
+pub use super::oauth_client::Entity as OauthClient;
+pub use super::privilege_grant::Entity as PrivilegeGrant;
+pub use super::report_metric::Entity as ReportMetric;
+pub use super::role::Entity as Role;
+pub use super::role_member::Entity as RoleMember;
+pub use super::scan_report::Entity as ScanReport;
+pub use super::staged_table::Entity as StagedTable;
+pub use super::table_commit::Entity as TableCommit;
+pub use super::table_metadata::Entity as TableMetadata;
+pub use super::table_purge::Entity as TablePurge;
@@ -0,0 +1,68 @@
+use sea_orm_migration::prelude::*;
+
+use super::m20221026_000002_create_namespace_table::Namespace;
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20261017_000001_create_namespace_property_table"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    // Define how to apply this migration: Create the NamespaceProperty table.
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(NamespaceProperty::Table)
+                    .col(
+                        ColumnDef::new(NamespaceProperty::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(NamespaceProperty::Key).string().not_null())
+                    .col(ColumnDef::new(NamespaceProperty::Value).string().not_null())
+                    .col(
+                        ColumnDef::new(NamespaceProperty::NamespaceId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-namespace_property-namespace_id")
+                            .from(NamespaceProperty::Table, NamespaceProperty::NamespaceId)
+                            .to(Namespace::Table, Namespace::Id),
+                    )
+                    .index(
+                        Index::create()
+                            .name("idx-namespace_property-namespace_id-key")
+                            .col(NamespaceProperty::NamespaceId)
+                            .col(NamespaceProperty::Key)
+                            .unique(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    // Define how to rollback this migration: Drop the NamespaceProperty table.
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(NamespaceProperty::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+pub enum NamespaceProperty {
+    Table,
+    Id,
+    Key,
+    Value,
+    NamespaceId,
+}
@@ -0,0 +1,114 @@
+use std::collections::HashMap;
+
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::{ConnectionTrait, DbBackend};
+
+use super::m20221026_000002_create_namespace_table::Namespace;
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20261017_000002_add_namespace_parent"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    // Define how to apply this migration: Add the parent link to the Namespace table.
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Namespace::Table)
+                    .add_column(ColumnDef::new(NamespaceParent::ParentId).integer())
+                    .to_owned(),
+            )
+            .await?;
+
+        // SQLite can't add foreign keys to existing tables
+        if manager.get_database_backend() != DbBackend::Sqlite {
+            manager
+                .create_foreign_key(
+                    ForeignKey::create()
+                        .name("fk-namespace-parent_id")
+                        .from(Namespace::Table, NamespaceParent::ParentId)
+                        .to(Namespace::Table, Namespace::Id)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        // Link existing namespaces to the namespace one level above them
+        let db = manager.get_connection();
+        let builder = db.get_database_backend();
+        let rows = db
+            .query_all(
+                builder.build(
+                    Query::select()
+                        .columns([Namespace::Id, Namespace::Name, Namespace::CatalogId])
+                        .from(Namespace::Table),
+                ),
+            )
+            .await?;
+        let namespaces = rows
+            .iter()
+            .map(|row| {
+                Ok((
+                    row.try_get::<i32>("", "id")?,
+                    row.try_get::<String>("", "name")?,
+                    row.try_get::<Option<i32>>("", "catalog_id")?,
+                ))
+            })
+            .collect::<Result<Vec<_>, DbErr>>()?;
+        let ids = namespaces
+            .iter()
+            .map(|(id, name, catalog_id)| ((name.as_str(), *catalog_id), *id))
+            .collect::<HashMap<_, _>>();
+        for (id, name, catalog_id) in &namespaces {
+            let parent_id = name
+                .rsplit_once('.')
+                .and_then(|(parent, _)| ids.get(&(parent, *catalog_id)));
+            if let Some(parent_id) = parent_id {
+                db.execute(
+                    builder.build(
+                        Query::update()
+                            .table(Namespace::Table)
+                            .value(NamespaceParent::ParentId, (*parent_id).into())
+                            .and_where(Expr::col(Namespace::Id).eq(*id)),
+                    ),
+                )
+                .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    // Define how to rollback this migration: Drop the parent link from the Namespace table.
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if manager.get_database_backend() != DbBackend::Sqlite {
+            manager
+                .drop_foreign_key(
+                    ForeignKey::drop()
+                        .name("fk-namespace-parent_id")
+                        .table(Namespace::Table)
+                        .to_owned(),
+                )
+                .await?;
+        }
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Namespace::Table)
+                    .drop_column(NamespaceParent::ParentId)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(Iden)]
+pub enum NamespaceParent {
+    ParentId,
+}
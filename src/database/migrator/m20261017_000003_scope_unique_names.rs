@@ -0,0 +1,253 @@
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::{ConnectionTrait, DbBackend, Statement};
+
+use super::m20221026_000001_create_catalog_table::Catalog;
+use super::m20221026_000002_create_namespace_table::Namespace;
+use super::m20221026_000003_create_table_table::IcebergTable;
+use super::m20261017_000002_add_namespace_parent::NamespaceParent;
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20261017_000003_scope_unique_names"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    // Define how to apply this migration: Replace the global unique names of namespaces and
+    // tables with names that are unique within their catalog and namespace. The namespaces
+    // of the default catalog have no catalog_id, which the index on catalog_id and name
+    // doesn't compare, so their names get an index of their own.
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        match manager.get_database_backend() {
+            DbBackend::Postgres => {
+                execute(
+                    manager,
+                    "ALTER TABLE namespace DROP CONSTRAINT IF EXISTS namespace_name_key",
+                )
+                .await?;
+                execute(
+                    manager,
+                    "ALTER TABLE iceberg_table DROP CONSTRAINT IF EXISTS iceberg_table_name_key",
+                )
+                .await?;
+            }
+            DbBackend::MySql => {
+                execute(manager, "ALTER TABLE `namespace` DROP INDEX `name`").await?;
+                execute(manager, "ALTER TABLE `iceberg_table` DROP INDEX `name`").await?;
+            }
+            // SQLite can't drop constraints, so the tables are rebuilt without them
+            DbBackend::Sqlite => {
+                execute(manager, "PRAGMA foreign_keys = OFF").await?;
+                rebuild_sqlite_table(
+                    manager,
+                    namespace_table(Alias::new("namespace_new")),
+                    "INSERT INTO namespace_new (id, name, catalog_id, parent_id) SELECT id, name, catalog_id, parent_id FROM namespace",
+                    "namespace",
+                )
+                .await?;
+                rebuild_sqlite_table(
+                    manager,
+                    iceberg_table_table(Alias::new("iceberg_table_new")),
+                    "INSERT INTO iceberg_table_new (id, name, metadata_location, previous_metadata_location, namespace_id) SELECT id, name, metadata_location, previous_metadata_location, namespace_id FROM iceberg_table",
+                    "iceberg_table",
+                )
+                .await?;
+                execute(manager, "PRAGMA foreign_keys = ON").await?;
+            }
+        }
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-namespace-catalog_id-name")
+                    .table(Namespace::Table)
+                    .col(Namespace::CatalogId)
+                    .col(Namespace::Name)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        match manager.get_database_backend() {
+            DbBackend::Postgres | DbBackend::Sqlite => {
+                execute(
+                    manager,
+                    r#"CREATE UNIQUE INDEX "idx-namespace-name-default_catalog" ON namespace (name) WHERE catalog_id IS NULL"#,
+                )
+                .await?;
+            }
+            // MySQL has no partial indexes, so the default catalog is indexed as catalog 0
+            // through a generated column
+            DbBackend::MySql => {
+                execute(
+                    manager,
+                    "ALTER TABLE `namespace` ADD COLUMN `catalog_key` INT AS (COALESCE(`catalog_id`, 0)) STORED",
+                )
+                .await?;
+                execute(
+                    manager,
+                    "CREATE UNIQUE INDEX `idx-namespace-name-default_catalog` ON `namespace` (`catalog_key`, `name`)",
+                )
+                .await?;
+            }
+        }
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-iceberg_table-namespace_id-name")
+                    .table(IcebergTable::Table)
+                    .col(IcebergTable::NamespaceId)
+                    .col(IcebergTable::Name)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    // Define how to rollback this migration: Restore the global unique names. Fails if
+    // names have been reused in different catalogs or namespaces.
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx-iceberg_table-namespace_id-name")
+                    .table(IcebergTable::Table)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx-namespace-catalog_id-name")
+                    .table(Namespace::Table)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx-namespace-name-default_catalog")
+                    .table(Namespace::Table)
+                    .to_owned(),
+            )
+            .await?;
+        if manager.get_database_backend() == DbBackend::MySql {
+            execute(manager, "ALTER TABLE `namespace` DROP COLUMN `catalog_key`").await?;
+        }
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("namespace_name_key")
+                    .table(Namespace::Table)
+                    .col(Namespace::Name)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("iceberg_table_name_key")
+                    .table(IcebergTable::Table)
+                    .col(IcebergTable::Name)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+async fn execute(manager: &SchemaManager<'_>, sql: &str) -> Result<(), DbErr> {
+    let db = manager.get_connection();
+    db.execute(Statement::from_string(
+        db.get_database_backend(),
+        sql.to_owned(),
+    ))
+    .await
+    .map(|_| ())
+}
+
+/// Copies the rows of an SQLite table into a table with the new definition and replaces it.
+async fn rebuild_sqlite_table(
+    manager: &SchemaManager<'_>,
+    definition: TableCreateStatement,
+    copy: &str,
+    table: &str,
+) -> Result<(), DbErr> {
+    manager.create_table(definition).await?;
+    execute(manager, copy).await?;
+    execute(manager, &format!("DROP TABLE {}", table)).await?;
+    execute(
+        manager,
+        &format!("ALTER TABLE {}_new RENAME TO {}", table, table),
+    )
+    .await
+}
+
+/// Definition of the namespace table without the unique name.
+fn namespace_table(name: Alias) -> TableCreateStatement {
+    Table::create()
+        .table(name)
+        .col(
+            ColumnDef::new(Namespace::Id)
+                .integer()
+                .not_null()
+                .auto_increment()
+                .primary_key(),
+        )
+        .col(ColumnDef::new(Namespace::Name).string().not_null())
+        .col(ColumnDef::new(Namespace::CatalogId).integer())
+        .col(ColumnDef::new(NamespaceParent::ParentId).integer())
+        .foreign_key(
+            ForeignKey::create()
+                .name("fk-namespace-catalog_id")
+                .from_col(Namespace::CatalogId)
+                .to(Catalog::Table, Catalog::Id),
+        )
+        .foreign_key(
+            ForeignKey::create()
+                .name("fk-namespace-parent_id")
+                .from_col(NamespaceParent::ParentId)
+                .to(Namespace::Table, Namespace::Id),
+        )
+        .to_owned()
+}
+
+/// Definition of the iceberg_table table without the unique name.
+fn iceberg_table_table(name: Alias) -> TableCreateStatement {
+    Table::create()
+        .table(name)
+        .col(
+            ColumnDef::new(IcebergTable::Id)
+                .integer()
+                .not_null()
+                .auto_increment()
+                .primary_key(),
+        )
+        .col(ColumnDef::new(IcebergTable::Name).string().not_null())
+        .col(
+            ColumnDef::new(IcebergTable::MetadataLocation)
+                .string()
+                .not_null(),
+        )
+        .col(ColumnDef::new(IcebergTable::PreviousMetadataLocation).string())
+        .col(
+            ColumnDef::new(IcebergTable::NamespaceId)
+                .integer()
+                .not_null(),
+        )
+        .foreign_key(
+            ForeignKey::create()
+                .name("fk-table-namespace_id")
+                .from_col(IcebergTable::NamespaceId)
+                .to(Namespace::Table, Namespace::Id),
+        )
+        .to_owned()
+}
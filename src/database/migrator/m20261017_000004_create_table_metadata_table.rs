@@ -0,0 +1,68 @@
+use sea_orm_migration::prelude::*;
+
+use super::m20221026_000003_create_table_table::IcebergTable;
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20261017_000004_create_table_metadata_table"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    // Define how to apply this migration: Create the TableMetadata table.
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(TableMetadata::Table)
+                    .col(
+                        ColumnDef::new(TableMetadata::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(TableMetadata::MetadataLocation)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(TableMetadata::Metadata).json().not_null())
+                    .col(ColumnDef::new(TableMetadata::TableId).integer().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-table_metadata-table_id")
+                            .from(TableMetadata::Table, TableMetadata::TableId)
+                            .to(IcebergTable::Table, IcebergTable::Id),
+                    )
+                    .index(
+                        Index::create()
+                            .name("idx-table_metadata-table_id-metadata_location")
+                            .col(TableMetadata::TableId)
+                            .col(TableMetadata::MetadataLocation)
+                            .unique(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    // Define how to rollback this migration: Drop the TableMetadata table.
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(TableMetadata::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+pub enum TableMetadata {
+    Table,
+    Id,
+    MetadataLocation,
+    Metadata,
+    TableId,
+}
@@ -0,0 +1,92 @@
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::{ConnectionTrait, Statement};
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20261017_000005_create_table_commit_table"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    // Define how to apply this migration: Create the TableCommit table and record the current
+    // metadata location of existing tables as their first commit. The commits have no
+    // foreign key of their table, as they outlive it with the name and the drop time of the
+    // dropped table.
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(TableCommit::Table)
+                    .col(
+                        ColumnDef::new(TableCommit::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(TableCommit::MetadataLocation)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(TableCommit::PreviousMetadataLocation).string())
+                    .col(
+                        ColumnDef::new(TableCommit::CommittedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(TableCommit::SpanId).string())
+                    .col(ColumnDef::new(TableCommit::Principal).string())
+                    .col(ColumnDef::new(TableCommit::TableId).integer().not_null())
+                    .col(ColumnDef::new(TableCommit::TableName).string())
+                    .col(ColumnDef::new(TableCommit::DroppedAt).timestamp_with_time_zone())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-table_commit-table_id-id")
+                    .table(TableCommit::Table)
+                    .col(TableCommit::TableId)
+                    .col(TableCommit::Id)
+                    .to_owned(),
+            )
+            .await?;
+
+        let db = manager.get_connection();
+        db.execute(Statement::from_string(
+            db.get_database_backend(),
+            "INSERT INTO table_commit (metadata_location, previous_metadata_location, committed_at, table_id) \
+             SELECT metadata_location, previous_metadata_location, CURRENT_TIMESTAMP, id FROM iceberg_table"
+                .to_owned(),
+        ))
+        .await
+        .map(|_| ())
+    }
+
+    // Define how to rollback this migration: Drop the TableCommit table.
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(TableCommit::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+pub enum TableCommit {
+    Table,
+    Id,
+    MetadataLocation,
+    PreviousMetadataLocation,
+    CommittedAt,
+    SpanId,
+    Principal,
+    TableId,
+    TableName,
+    DroppedAt,
+}
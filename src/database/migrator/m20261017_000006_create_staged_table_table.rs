@@ -0,0 +1,74 @@
+use sea_orm_migration::prelude::*;
+
+use super::m20221026_000002_create_namespace_table::Namespace;
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20261017_000006_create_staged_table_table"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    // Define how to apply this migration: Create the StagedTable table.
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(StagedTable::Table)
+                    .col(
+                        ColumnDef::new(StagedTable::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(StagedTable::Name).string().not_null())
+                    .col(ColumnDef::new(StagedTable::Metadata).json().not_null())
+                    .col(
+                        ColumnDef::new(StagedTable::StagedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(StagedTable::NamespaceId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-staged_table-namespace_id")
+                            .from(StagedTable::Table, StagedTable::NamespaceId)
+                            .to(Namespace::Table, Namespace::Id),
+                    )
+                    .index(
+                        Index::create()
+                            .name("idx-staged_table-namespace_id-name")
+                            .col(StagedTable::NamespaceId)
+                            .col(StagedTable::Name)
+                            .unique(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    // Define how to rollback this migration: Drop the StagedTable table.
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(StagedTable::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+pub enum StagedTable {
+    Table,
+    Id,
+    Name,
+    Metadata,
+    StagedAt,
+    NamespaceId,
+}
@@ -0,0 +1,65 @@
+use sea_orm_migration::prelude::*;
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20261017_000007_create_table_purge_table"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    // Define how to apply this migration: Create the TablePurge table.
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(TablePurge::Table)
+                    .col(
+                        ColumnDef::new(TablePurge::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(TablePurge::TableName).string().not_null())
+                    .col(ColumnDef::new(TablePurge::Locations).json().not_null())
+                    .col(ColumnDef::new(TablePurge::Status).string().not_null())
+                    .col(ColumnDef::new(TablePurge::Attempts).integer().not_null())
+                    .col(ColumnDef::new(TablePurge::LastError).text())
+                    .col(
+                        ColumnDef::new(TablePurge::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(TablePurge::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    // Define how to rollback this migration: Drop the TablePurge table.
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(TablePurge::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+pub enum TablePurge {
+    Table,
+    Id,
+    TableName,
+    Locations,
+    Status,
+    Attempts,
+    LastError,
+    CreatedAt,
+    UpdatedAt,
+}
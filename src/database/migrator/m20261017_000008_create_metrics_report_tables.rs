@@ -0,0 +1,219 @@
+use sea_orm_migration::prelude::*;
+
+use super::m20221026_000003_create_table_table::IcebergTable;
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20261017_000008_create_metrics_report_tables"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    // Define how to apply this migration: Create the ScanReport and CommitReport tables, and
+    // the ReportMetric table, which holds the counters and timers of the reports as rows so
+    // that they can be summed up in SQL.
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ScanReport::Table)
+                    .col(
+                        ColumnDef::new(ScanReport::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(ScanReport::SnapshotId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(ScanReport::Filter).json())
+                    .col(ColumnDef::new(ScanReport::SchemaId).integer())
+                    .col(ColumnDef::new(ScanReport::ProjectedFieldIds).json())
+                    .col(ColumnDef::new(ScanReport::ProjectedFieldNames).json())
+                    .col(ColumnDef::new(ScanReport::Projection).json())
+                    .col(ColumnDef::new(ScanReport::Metrics).json().not_null())
+                    .col(ColumnDef::new(ScanReport::Metadata).json())
+                    .col(
+                        ColumnDef::new(ScanReport::ReportedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(ScanReport::TableId).integer().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-scan_report-table_id")
+                            .from(ScanReport::Table, ScanReport::TableId)
+                            .to(IcebergTable::Table, IcebergTable::Id),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-scan_report-table_id-reported_at")
+                    .table(ScanReport::Table)
+                    .col(ScanReport::TableId)
+                    .col(ScanReport::ReportedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(CommitReport::Table)
+                    .col(
+                        ColumnDef::new(CommitReport::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(CommitReport::SnapshotId)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CommitReport::SequenceNumber)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(CommitReport::Operation).string().not_null())
+                    .col(ColumnDef::new(CommitReport::Metrics).json().not_null())
+                    .col(ColumnDef::new(CommitReport::Metadata).json())
+                    .col(
+                        ColumnDef::new(CommitReport::ReportedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(CommitReport::TableId).integer().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-commit_report-table_id")
+                            .from(CommitReport::Table, CommitReport::TableId)
+                            .to(IcebergTable::Table, IcebergTable::Id),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-commit_report-table_id-reported_at")
+                    .table(CommitReport::Table)
+                    .col(CommitReport::TableId)
+                    .col(CommitReport::ReportedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(ReportMetric::Table)
+                    .col(
+                        ColumnDef::new(ReportMetric::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(ReportMetric::ReportType).string().not_null())
+                    .col(ColumnDef::new(ReportMetric::Name).string().not_null())
+                    .col(ColumnDef::new(ReportMetric::Value).big_integer())
+                    .col(ColumnDef::new(ReportMetric::Count).big_integer())
+                    .col(ColumnDef::new(ReportMetric::TotalDuration).big_integer())
+                    .col(
+                        ColumnDef::new(ReportMetric::ReportedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(ReportMetric::TableId).integer().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-report_metric-table_id")
+                            .from(ReportMetric::Table, ReportMetric::TableId)
+                            .to(IcebergTable::Table, IcebergTable::Id),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-report_metric-table_id-reported_at")
+                    .table(ReportMetric::Table)
+                    .col(ReportMetric::TableId)
+                    .col(ReportMetric::ReportedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    // Define how to rollback this migration: Drop the ReportMetric, ScanReport and
+    // CommitReport tables.
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ReportMetric::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(CommitReport::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(ScanReport::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+pub enum ScanReport {
+    Table,
+    Id,
+    SnapshotId,
+    Filter,
+    SchemaId,
+    ProjectedFieldIds,
+    ProjectedFieldNames,
+    Projection,
+    Metrics,
+    Metadata,
+    ReportedAt,
+    TableId,
+}
+
+#[derive(Iden)]
+pub enum CommitReport {
+    Table,
+    Id,
+    SnapshotId,
+    SequenceNumber,
+    Operation,
+    Metrics,
+    Metadata,
+    ReportedAt,
+    TableId,
+}
+
+#[derive(Iden)]
+pub enum ReportMetric {
+    Table,
+    Id,
+    ReportType,
+    Name,
+    Value,
+    Count,
+    TotalDuration,
+    ReportedAt,
+    TableId,
+}
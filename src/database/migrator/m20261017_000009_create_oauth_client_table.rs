@@ -0,0 +1,60 @@
+use sea_orm_migration::prelude::*;
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20261017_000009_create_oauth_client_table"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    // Define how to apply this migration: Create the OauthClient table.
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(OauthClient::Table)
+                    .col(
+                        ColumnDef::new(OauthClient::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(OauthClient::ClientId)
+                            .string()
+                            .not_null()
+                            .unique_key(),
+                    )
+                    .col(ColumnDef::new(OauthClient::SecretHash).string().not_null())
+                    .col(ColumnDef::new(OauthClient::Scopes).string().not_null())
+                    .col(
+                        ColumnDef::new(OauthClient::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    // Define how to rollback this migration: Drop the OauthClient table.
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(OauthClient::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+pub enum OauthClient {
+    Table,
+    Id,
+    ClientId,
+    SecretHash,
+    Scopes,
+    CreatedAt,
+}
@@ -0,0 +1,43 @@
+use sea_orm_migration::prelude::*;
+
+use super::m20261017_000005_create_table_commit_table::TableCommit;
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20261017_000010_add_table_commit_actor"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    // Define how to apply this migration: Add the actor to the TableCommit table.
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TableCommit::Table)
+                    .add_column(ColumnDef::new(TableCommitActor::Actor).string())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    // Define how to rollback this migration: Drop the actor from the TableCommit table.
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TableCommit::Table)
+                    .drop_column(TableCommitActor::Actor)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(Iden)]
+pub enum TableCommitActor {
+    Actor,
+}
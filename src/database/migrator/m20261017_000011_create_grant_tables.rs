@@ -0,0 +1,168 @@
+use sea_orm_migration::prelude::*;
+
+use super::m20221026_000001_create_catalog_table::Catalog;
+use super::m20221026_000002_create_namespace_table::Namespace;
+use super::m20221026_000003_create_table_table::IcebergTable;
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20261017_000011_create_grant_tables"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    // Define how to apply this migration: Create the Role, RoleMember and PrivilegeGrant tables.
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Role::Table)
+                    .col(
+                        ColumnDef::new(Role::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Role::Name).string().not_null().unique_key())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(RoleMember::Table)
+                    .col(
+                        ColumnDef::new(RoleMember::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(RoleMember::Principal).string().not_null())
+                    .col(ColumnDef::new(RoleMember::RoleId).integer().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-role_member-role_id")
+                            .from(RoleMember::Table, RoleMember::RoleId)
+                            .to(Role::Table, Role::Id),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-role_member-principal-role_id")
+                    .table(RoleMember::Table)
+                    .col(RoleMember::Principal)
+                    .col(RoleMember::RoleId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(PrivilegeGrant::Table)
+                    .col(
+                        ColumnDef::new(PrivilegeGrant::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(PrivilegeGrant::Privilege)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(PrivilegeGrant::RoleId).integer().not_null())
+                    .col(ColumnDef::new(PrivilegeGrant::CatalogId).integer())
+                    .col(ColumnDef::new(PrivilegeGrant::NamespaceId).integer())
+                    .col(ColumnDef::new(PrivilegeGrant::TableId).integer())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-privilege_grant-role_id")
+                            .from(PrivilegeGrant::Table, PrivilegeGrant::RoleId)
+                            .to(Role::Table, Role::Id),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-privilege_grant-catalog_id")
+                            .from(PrivilegeGrant::Table, PrivilegeGrant::CatalogId)
+                            .to(Catalog::Table, Catalog::Id),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-privilege_grant-namespace_id")
+                            .from(PrivilegeGrant::Table, PrivilegeGrant::NamespaceId)
+                            .to(Namespace::Table, Namespace::Id),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-privilege_grant-table_id")
+                            .from(PrivilegeGrant::Table, PrivilegeGrant::TableId)
+                            .to(IcebergTable::Table, IcebergTable::Id),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-privilege_grant-role_id-catalog_id")
+                    .table(PrivilegeGrant::Table)
+                    .col(PrivilegeGrant::RoleId)
+                    .col(PrivilegeGrant::CatalogId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    // Define how to rollback this migration: Drop the PrivilegeGrant, RoleMember and Role tables.
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(PrivilegeGrant::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(RoleMember::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(Role::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+pub enum Role {
+    Table,
+    Id,
+    Name,
+}
+
+#[derive(Iden)]
+pub enum RoleMember {
+    Table,
+    Id,
+    Principal,
+    RoleId,
+}
+
+#[derive(Iden)]
+pub enum PrivilegeGrant {
+    Table,
+    Id,
+    Privilege,
+    RoleId,
+    CatalogId,
+    NamespaceId,
+    TableId,
+}
@@ -0,0 +1,69 @@
+use sea_orm_migration::prelude::*;
+
+use super::m20221026_000001_create_catalog_table::Catalog;
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20261017_000012_create_catalog_config_table"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    // Define how to apply this migration: Create the CatalogConfig table.
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(CatalogConfig::Table)
+                    .col(
+                        ColumnDef::new(CatalogConfig::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(CatalogConfig::Kind).string().not_null())
+                    .col(ColumnDef::new(CatalogConfig::Key).string().not_null())
+                    .col(ColumnDef::new(CatalogConfig::Value).string().not_null())
+                    .col(ColumnDef::new(CatalogConfig::CatalogId).integer())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-catalog_config-catalog_id")
+                            .from(CatalogConfig::Table, CatalogConfig::CatalogId)
+                            .to(Catalog::Table, Catalog::Id),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-catalog_config-catalog_id")
+                    .table(CatalogConfig::Table)
+                    .col(CatalogConfig::CatalogId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    // Define how to rollback this migration: Drop the CatalogConfig table.
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(CatalogConfig::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+pub enum CatalogConfig {
+    Table,
+    Id,
+    Kind,
+    Key,
+    Value,
+    CatalogId,
+}
@@ -0,0 +1,98 @@
+use sea_orm_migration::prelude::*;
+
+use super::m20221026_000001_create_catalog_table::Catalog;
+
+pub struct Migration;
+
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20261017_000013_add_catalog_storage_root_and_properties"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    // Define how to apply this migration: Add the storage root to the Catalog table and
+    // create the CatalogProperty table.
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Catalog::Table)
+                    .add_column(ColumnDef::new(CatalogStorageRoot::StorageRoot).string())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(CatalogProperty::Table)
+                    .col(
+                        ColumnDef::new(CatalogProperty::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(CatalogProperty::Key).string().not_null())
+                    .col(ColumnDef::new(CatalogProperty::Value).string().not_null())
+                    .col(
+                        ColumnDef::new(CatalogProperty::CatalogId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-catalog_property-catalog_id")
+                            .from(CatalogProperty::Table, CatalogProperty::CatalogId)
+                            .to(Catalog::Table, Catalog::Id),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-catalog_property-catalog_id-key")
+                    .table(CatalogProperty::Table)
+                    .col(CatalogProperty::CatalogId)
+                    .col(CatalogProperty::Key)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    // Define how to rollback this migration: Drop the CatalogProperty table and the
+    // storage root from the Catalog table.
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(CatalogProperty::Table).to_owned())
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Catalog::Table)
+                    .drop_column(CatalogStorageRoot::StorageRoot)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(Iden)]
+pub enum CatalogStorageRoot {
+    StorageRoot,
+}
+
+#[derive(Iden)]
+pub enum CatalogProperty {
+    Table,
+    Id,
+    Key,
+    Value,
+    CatalogId,
+}
@@ -3,6 +3,19 @@ use sea_orm_migration::prelude::*;
 mod m20221026_000001_create_catalog_table;
 mod m20221026_000002_create_namespace_table;
 mod m20221026_000003_create_table_table;
+mod m20261017_000001_create_namespace_property_table;
+mod m20261017_000002_add_namespace_parent;
+mod m20261017_000003_scope_unique_names;
+mod m20261017_000004_create_table_metadata_table;
+mod m20261017_000005_create_table_commit_table;
+mod m20261017_000006_create_staged_table_table;
+mod m20261017_000007_create_table_purge_table;
+mod m20261017_000008_create_metrics_report_tables;
+mod m20261017_000009_create_oauth_client_table;
+mod m20261017_000010_add_table_commit_actor;
+mod m20261017_000011_create_grant_tables;
+mod m20261017_000012_create_catalog_config_table;
+mod m20261017_000013_add_catalog_storage_root_and_properties;
 pub struct Migrator;
 
 #[async_trait::async_trait]
@@ -12,6 +25,19 @@ impl MigratorTrait for Migrator {
             Box::new(m20221026_000001_create_catalog_table::Migration),
             Box::new(m20221026_000002_create_namespace_table::Migration),
             Box::new(m20221026_000003_create_table_table::Migration),
+            Box::new(m20261017_000001_create_namespace_property_table::Migration),
+            Box::new(m20261017_000002_add_namespace_parent::Migration),
+            Box::new(m20261017_000003_scope_unique_names::Migration),
+            Box::new(m20261017_000004_create_table_metadata_table::Migration),
+            Box::new(m20261017_000005_create_table_commit_table::Migration),
+            Box::new(m20261017_000006_create_staged_table_table::Migration),
+            Box::new(m20261017_000007_create_table_purge_table::Migration),
+            Box::new(m20261017_000008_create_metrics_report_tables::Migration),
+            Box::new(m20261017_000009_create_oauth_client_table::Migration),
+            Box::new(m20261017_000010_add_table_commit_actor::Migration),
+            Box::new(m20261017_000011_create_grant_tables::Migration),
+            Box::new(m20261017_000012_create_catalog_config_table::Migration),
+            Box::new(m20261017_000013_add_catalog_storage_root_and_properties::Migration),
         ]
     }
 }
@@ -2,13 +2,38 @@ use sea_orm::{ConnectionTrait, DatabaseConnection};
 use sea_orm::{Database, DbBackend, DbErr, Statement};
 use sea_orm_migration::prelude::*;
 
+use crate::config::{DatabaseConfig, DatabaseLocation};
+
 pub mod entities;
 mod migrator;
 
-pub(crate) async fn run(connection: &str, db_name: &str) -> Result<DatabaseConnection, DbErr> {
-    let db = Database::connect(connection.to_owned() + "/postgres").await?;
+pub(crate) async fn run(config: &DatabaseConfig) -> Result<DatabaseConnection, DbErr> {
+    let db = match config.location() {
+        DatabaseLocation::Url(url) => Database::connect(config.connect_options(url)).await?,
+        DatabaseLocation::Server { url, name } => create(config, &url, &name).await?,
+    };
+
+    let schema_manager = SchemaManager::new(&db); // To investigate the schema
+
+    if !schema_manager.has_table("catalog").await? {
+        migrator::Migrator::refresh(&db).await?;
+    } else {
+        // Apply migrations that were added after the database was created
+        migrator::Migrator::up(&db, None).await?;
+    }
+
+    Ok(db)
+}
+
+/// Connects to a database of a server, which is created if it doesn't exist yet.
+async fn create(
+    config: &DatabaseConfig,
+    connection: &str,
+    db_name: &str,
+) -> Result<DatabaseConnection, DbErr> {
+    let db = Database::connect(config.connect_options(connection.to_owned() + "/postgres")).await?;
 
-    let db = match db.get_database_backend() {
+    Ok(match db.get_database_backend() {
         DbBackend::MySql => {
             db.execute(Statement::from_string(
                 db.get_database_backend(),
@@ -16,14 +41,8 @@ pub(crate) async fn run(connection: &str, db_name: &str) -> Result<DatabaseConne
             ))
             .await?;
 
-            db.execute(Statement::from_string(
-                db.get_database_backend(),
-                format!("SET GLOBAL TRANSACTION ISOLATION LEVEL SERIALIZABLE"),
-            ))
-            .await?;
-
             let url = format!("{}/{}", connection, db_name);
-            Database::connect(&url).await?
+            Database::connect(config.connect_options(url)).await?
         }
         DbBackend::Postgres => {
             let _ = db
@@ -33,26 +52,9 @@ pub(crate) async fn run(connection: &str, db_name: &str) -> Result<DatabaseConne
                 ))
                 .await;
 
-            db.execute(Statement::from_string(
-                db.get_database_backend(),
-                format!(
-                    "ALTER DATABASE {} SET DEFAULT_TRANSACTION_ISOLATION TO SERIALIZABLE",
-                    db_name
-                ),
-            ))
-            .await?;
-
             let url = format!("{}/{}", connection, db_name);
-            Database::connect(&url).await?
+            Database::connect(config.connect_options(url)).await?
         }
         DbBackend::Sqlite => db,
-    };
-
-    let schema_manager = SchemaManager::new(&db); // To investigate the schema
-
-    if !schema_manager.has_table("catalog").await? {
-        migrator::Migrator::refresh(&db).await?;
-    }
-
-    Ok(db)
+    })
 }
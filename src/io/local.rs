@@ -0,0 +1,38 @@
+//! File IO of the local filesystem.
+
+use std::path::Path;
+
+use async_trait::async_trait;
+
+use super::{FileIO, IoError};
+
+/// Accesses files of the local filesystem. Locations are either paths or `file://` URLs.
+pub struct LocalFileIO;
+
+fn path(location: &str) -> &Path {
+    Path::new(location.strip_prefix("file://").unwrap_or(location))
+}
+
+#[async_trait]
+impl FileIO for LocalFileIO {
+    async fn write(&self, location: &str, bytes: &[u8]) -> Result<(), IoError> {
+        let path = path(location);
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        Ok(tokio::fs::write(path, bytes).await?)
+    }
+
+    async fn delete_prefix(&self, location: &str) -> Result<(), IoError> {
+        let path = path(location);
+        let result = match tokio::fs::metadata(path).await {
+            Ok(metadata) if metadata.is_dir() => tokio::fs::remove_dir_all(path).await,
+            Ok(_) => tokio::fs::remove_file(path).await,
+            Err(err) => Err(err),
+        };
+        match result {
+            Err(err) if err.kind() != std::io::ErrorKind::NotFound => Err(err.into()),
+            _ => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,76 @@
+//! Access to the files of tables.
+//!
+//! Files are written through the [`FileIO`] trait. The implementation is
+//! chosen by the scheme of the location, so that further storage backends can be
+//! added without touching the handlers.
+
+use std::fmt;
+
+use async_trait::async_trait;
+
+mod local;
+mod s3;
+
+pub use self::local::LocalFileIO;
+pub use self::s3::{S3Config, S3FileIO};
+
+/// Reads and writes files of a storage backend.
+#[async_trait]
+pub trait FileIO: Send + Sync {
+    /// Writes the file at the location, replacing an existing file.
+    async fn write(&self, location: &str, bytes: &[u8]) -> Result<(), IoError>;
+
+    /// Deletes all files below the location. Succeeds if there are none.
+    async fn delete_prefix(&self, location: &str) -> Result<(), IoError>;
+}
+
+/// Error returned when a file can't be accessed.
+#[derive(Debug)]
+pub enum IoError {
+    /// No backend is available for the scheme of the location
+    UnsupportedScheme(String),
+    /// The backend failed to access the file
+    Io(std::io::Error),
+    /// The storage service rejected the request or could not be reached
+    Storage(String),
+}
+
+impl fmt::Display for IoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IoError::UnsupportedScheme(scheme) => {
+                write!(f, "Locations with scheme \"{}\" are not supported.", scheme)
+            }
+            IoError::Io(err) => err.fmt(f),
+            IoError::Storage(message) => f.write_str(message),
+        }
+    }
+}
+
+impl std::error::Error for IoError {}
+
+impl From<std::io::Error> for IoError {
+    fn from(err: std::io::Error) -> Self {
+        IoError::Io(err)
+    }
+}
+
+/// Returns the file IO responsible for a location. Locations without a scheme are
+/// paths of the local filesystem.
+pub fn file_io(location: &str) -> Result<Box<dyn FileIO>, IoError> {
+    match location.split_once("://") {
+        None | Some(("file", _)) => Ok(Box::new(LocalFileIO)),
+        Some((scheme, _)) if !is_supported(location) => {
+            Err(IoError::UnsupportedScheme(scheme.to_owned()))
+        }
+        Some(_) => Ok(Box::new(S3FileIO::new(S3Config::from_env()?))),
+    }
+}
+
+/// Whether a backend is available for the scheme of the location.
+pub fn is_supported(location: &str) -> bool {
+    matches!(
+        location.split_once("://"),
+        None | Some(("file" | "s3" | "s3a" | "s3n", _))
+    )
+}
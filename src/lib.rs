@@ -14,6 +14,428 @@ type ServiceError = Box<dyn Error + Send + Sync + 'static>;
 pub const BASE_PATH: &str = "";
 pub const API_VERSION: &str = "0.0.1";
 
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+pub enum CreateCatalogResponse {
+    /// The catalog
+    TheCatalog
+    (models::Catalog)
+    ,
+    /// Indicates a bad request error. It could be caused by an unexpected request body format or other forms of request validation failure, such as invalid json. Usually serves application/json content, although in some cases simple text/plain content might be returned by the server's middleware.
+    IndicatesABadRequestError
+    (models::ErrorModel)
+    ,
+    /// Unauthorized. Authentication is required and has failed or has not yet been provided.
+    Unauthorized
+    (models::ErrorModel)
+    ,
+    /// Forbidden. Authenticated user does not have the necessary permissions.
+    Forbidden
+    (models::ErrorModel)
+    ,
+    /// Conflict - AlreadyExistsException, the catalog already exists
+    Conflict
+    (models::ErrorModel)
+    ,
+    /// Credentials have timed out. If possible, the client should refresh credentials and retry.
+    CredentialsHaveTimedOut
+    (models::ErrorModel)
+    ,
+    /// The service is not ready to handle the request. The client should wait and retry.  The service may additionally send a Retry-After header to indicate when to retry.
+    TheServiceIsNotReadyToHandleTheRequest
+    (models::ErrorModel)
+    ,
+    /// A server-side problem that might not be addressable from the client side. Used for server 5xx errors without more specific documentation in individual routes.
+    AServer
+    (models::ErrorModel)
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+pub enum DropCatalogResponse {
+    /// Success, no content
+    Success
+    ,
+    /// Indicates a bad request error. It could be caused by an unexpected request body format or other forms of request validation failure, such as invalid json. Usually serves application/json content, although in some cases simple text/plain content might be returned by the server's middleware.
+    IndicatesABadRequestError
+    (models::ErrorModel)
+    ,
+    /// Unauthorized. Authentication is required and has failed or has not yet been provided.
+    Unauthorized
+    (models::ErrorModel)
+    ,
+    /// Forbidden. Authenticated user does not have the necessary permissions.
+    Forbidden
+    (models::ErrorModel)
+    ,
+    /// Not Found - NoSuchCatalogException, the catalog does not exist
+    NotFound
+    (models::ErrorModel)
+    ,
+    /// Conflict - NamespaceNotEmptyException, the catalog still contains namespaces
+    Conflict
+    (models::ErrorModel)
+    ,
+    /// Credentials have timed out. If possible, the client should refresh credentials and retry.
+    CredentialsHaveTimedOut
+    (models::ErrorModel)
+    ,
+    /// The service is not ready to handle the request. The client should wait and retry.  The service may additionally send a Retry-After header to indicate when to retry.
+    TheServiceIsNotReadyToHandleTheRequest
+    (models::ErrorModel)
+    ,
+    /// A server-side problem that might not be addressable from the client side. Used for server 5xx errors without more specific documentation in individual routes.
+    AServer
+    (models::ErrorModel)
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+pub enum DropRoleResponse {
+    /// Success, no content
+    Success
+    ,
+    /// Indicates a bad request error. It could be caused by an unexpected request body format or other forms of request validation failure, such as invalid json. Usually serves application/json content, although in some cases simple text/plain content might be returned by the server's middleware.
+    IndicatesABadRequestError
+    (models::ErrorModel)
+    ,
+    /// Unauthorized. Authentication is required and has failed or has not yet been provided.
+    Unauthorized
+    (models::ErrorModel)
+    ,
+    /// Forbidden. Authenticated user does not have the necessary permissions.
+    Forbidden
+    (models::ErrorModel)
+    ,
+    /// Not Found - NoSuchRoleException, the role does not exist
+    NotFound
+    (models::ErrorModel)
+    ,
+    /// Credentials have timed out. If possible, the client should refresh credentials and retry.
+    CredentialsHaveTimedOut
+    (models::ErrorModel)
+    ,
+    /// The service is not ready to handle the request. The client should wait and retry.  The service may additionally send a Retry-After header to indicate when to retry.
+    TheServiceIsNotReadyToHandleTheRequest
+    (models::ErrorModel)
+    ,
+    /// A server-side problem that might not be addressable from the client side. Used for server 5xx errors without more specific documentation in individual routes.
+    AServer
+    (models::ErrorModel)
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+pub enum GetCatalogConfigResponse {
+    /// The configuration of the catalog
+    TheConfigurationOfTheCatalog
+    (models::CatalogConfig)
+    ,
+    /// Indicates a bad request error. It could be caused by an unexpected request body format or other forms of request validation failure, such as invalid json. Usually serves application/json content, although in some cases simple text/plain content might be returned by the server's middleware.
+    IndicatesABadRequestError
+    (models::ErrorModel)
+    ,
+    /// Unauthorized. Authentication is required and has failed or has not yet been provided.
+    Unauthorized
+    (models::ErrorModel)
+    ,
+    /// Forbidden. Authenticated user does not have the necessary permissions.
+    Forbidden
+    (models::ErrorModel)
+    ,
+    /// Not Found - NoSuchCatalogException, the catalog does not exist
+    NotFound
+    (models::ErrorModel)
+    ,
+    /// Credentials have timed out. If possible, the client should refresh credentials and retry.
+    CredentialsHaveTimedOut
+    (models::ErrorModel)
+    ,
+    /// The service is not ready to handle the request. The client should wait and retry.  The service may additionally send a Retry-After header to indicate when to retry.
+    TheServiceIsNotReadyToHandleTheRequest
+    (models::ErrorModel)
+    ,
+    /// A server-side problem that might not be addressable from the client side. Used for server 5xx errors without more specific documentation in individual routes.
+    AServer
+    (models::ErrorModel)
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+pub enum ListCatalogsResponse {
+    /// The catalogs
+    TheCatalogs
+    (models::ListCatalogs200Response)
+    ,
+    /// Indicates a bad request error. It could be caused by an unexpected request body format or other forms of request validation failure, such as invalid json. Usually serves application/json content, although in some cases simple text/plain content might be returned by the server's middleware.
+    IndicatesABadRequestError
+    (models::ErrorModel)
+    ,
+    /// Unauthorized. Authentication is required and has failed or has not yet been provided.
+    Unauthorized
+    (models::ErrorModel)
+    ,
+    /// Forbidden. Authenticated user does not have the necessary permissions.
+    Forbidden
+    (models::ErrorModel)
+    ,
+    /// Credentials have timed out. If possible, the client should refresh credentials and retry.
+    CredentialsHaveTimedOut
+    (models::ErrorModel)
+    ,
+    /// The service is not ready to handle the request. The client should wait and retry.  The service may additionally send a Retry-After header to indicate when to retry.
+    TheServiceIsNotReadyToHandleTheRequest
+    (models::ErrorModel)
+    ,
+    /// A server-side problem that might not be addressable from the client side. Used for server 5xx errors without more specific documentation in individual routes.
+    AServer
+    (models::ErrorModel)
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+pub enum ListGrantsResponse {
+    /// The grants of the role
+    TheGrantsOfTheRole
+    (models::ListGrants200Response)
+    ,
+    /// Indicates a bad request error. It could be caused by an unexpected request body format or other forms of request validation failure, such as invalid json. Usually serves application/json content, although in some cases simple text/plain content might be returned by the server's middleware.
+    IndicatesABadRequestError
+    (models::ErrorModel)
+    ,
+    /// Unauthorized. Authentication is required and has failed or has not yet been provided.
+    Unauthorized
+    (models::ErrorModel)
+    ,
+    /// Forbidden. Authenticated user does not have the necessary permissions.
+    Forbidden
+    (models::ErrorModel)
+    ,
+    /// Not Found - NoSuchRoleException, the role does not exist
+    NotFound
+    (models::ErrorModel)
+    ,
+    /// Credentials have timed out. If possible, the client should refresh credentials and retry.
+    CredentialsHaveTimedOut
+    (models::ErrorModel)
+    ,
+    /// The service is not ready to handle the request. The client should wait and retry.  The service may additionally send a Retry-After header to indicate when to retry.
+    TheServiceIsNotReadyToHandleTheRequest
+    (models::ErrorModel)
+    ,
+    /// A server-side problem that might not be addressable from the client side. Used for server 5xx errors without more specific documentation in individual routes.
+    AServer
+    (models::ErrorModel)
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+pub enum ListRolesResponse {
+    /// The roles and their principals
+    TheRolesAndTheirPrincipals
+    (models::ListRoles200Response)
+    ,
+    /// Indicates a bad request error. It could be caused by an unexpected request body format or other forms of request validation failure, such as invalid json. Usually serves application/json content, although in some cases simple text/plain content might be returned by the server's middleware.
+    IndicatesABadRequestError
+    (models::ErrorModel)
+    ,
+    /// Unauthorized. Authentication is required and has failed or has not yet been provided.
+    Unauthorized
+    (models::ErrorModel)
+    ,
+    /// Forbidden. Authenticated user does not have the necessary permissions.
+    Forbidden
+    (models::ErrorModel)
+    ,
+    /// Credentials have timed out. If possible, the client should refresh credentials and retry.
+    CredentialsHaveTimedOut
+    (models::ErrorModel)
+    ,
+    /// The service is not ready to handle the request. The client should wait and retry.  The service may additionally send a Retry-After header to indicate when to retry.
+    TheServiceIsNotReadyToHandleTheRequest
+    (models::ErrorModel)
+    ,
+    /// A server-side problem that might not be addressable from the client side. Used for server 5xx errors without more specific documentation in individual routes.
+    AServer
+    (models::ErrorModel)
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+pub enum LoadCatalogResponse {
+    /// The catalog
+    TheCatalog
+    (models::Catalog)
+    ,
+    /// Indicates a bad request error. It could be caused by an unexpected request body format or other forms of request validation failure, such as invalid json. Usually serves application/json content, although in some cases simple text/plain content might be returned by the server's middleware.
+    IndicatesABadRequestError
+    (models::ErrorModel)
+    ,
+    /// Unauthorized. Authentication is required and has failed or has not yet been provided.
+    Unauthorized
+    (models::ErrorModel)
+    ,
+    /// Forbidden. Authenticated user does not have the necessary permissions.
+    Forbidden
+    (models::ErrorModel)
+    ,
+    /// Not Found - NoSuchCatalogException, the catalog does not exist
+    NotFound
+    (models::ErrorModel)
+    ,
+    /// Credentials have timed out. If possible, the client should refresh credentials and retry.
+    CredentialsHaveTimedOut
+    (models::ErrorModel)
+    ,
+    /// The service is not ready to handle the request. The client should wait and retry.  The service may additionally send a Retry-After header to indicate when to retry.
+    TheServiceIsNotReadyToHandleTheRequest
+    (models::ErrorModel)
+    ,
+    /// A server-side problem that might not be addressable from the client side. Used for server 5xx errors without more specific documentation in individual routes.
+    AServer
+    (models::ErrorModel)
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+pub enum UpdateCatalogResponse {
+    /// The catalog
+    TheCatalog
+    (models::Catalog)
+    ,
+    /// Indicates a bad request error. It could be caused by an unexpected request body format or other forms of request validation failure, such as invalid json. Usually serves application/json content, although in some cases simple text/plain content might be returned by the server's middleware.
+    IndicatesABadRequestError
+    (models::ErrorModel)
+    ,
+    /// Unauthorized. Authentication is required and has failed or has not yet been provided.
+    Unauthorized
+    (models::ErrorModel)
+    ,
+    /// Forbidden. Authenticated user does not have the necessary permissions.
+    Forbidden
+    (models::ErrorModel)
+    ,
+    /// Not Found - NoSuchCatalogException, the catalog does not exist
+    NotFound
+    (models::ErrorModel)
+    ,
+    /// Credentials have timed out. If possible, the client should refresh credentials and retry.
+    CredentialsHaveTimedOut
+    (models::ErrorModel)
+    ,
+    /// The service is not ready to handle the request. The client should wait and retry.  The service may additionally send a Retry-After header to indicate when to retry.
+    TheServiceIsNotReadyToHandleTheRequest
+    (models::ErrorModel)
+    ,
+    /// A server-side problem that might not be addressable from the client side. Used for server 5xx errors without more specific documentation in individual routes.
+    AServer
+    (models::ErrorModel)
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+pub enum UpdateCatalogConfigResponse {
+    /// The configuration of the catalog
+    TheConfigurationOfTheCatalog
+    (models::CatalogConfig)
+    ,
+    /// Indicates a bad request error. It could be caused by an unexpected request body format or other forms of request validation failure, such as invalid json. Usually serves application/json content, although in some cases simple text/plain content might be returned by the server's middleware.
+    IndicatesABadRequestError
+    (models::ErrorModel)
+    ,
+    /// Unauthorized. Authentication is required and has failed or has not yet been provided.
+    Unauthorized
+    (models::ErrorModel)
+    ,
+    /// Forbidden. Authenticated user does not have the necessary permissions.
+    Forbidden
+    (models::ErrorModel)
+    ,
+    /// Not Found - NoSuchCatalogException, the catalog does not exist
+    NotFound
+    (models::ErrorModel)
+    ,
+    /// Credentials have timed out. If possible, the client should refresh credentials and retry.
+    CredentialsHaveTimedOut
+    (models::ErrorModel)
+    ,
+    /// The service is not ready to handle the request. The client should wait and retry.  The service may additionally send a Retry-After header to indicate when to retry.
+    TheServiceIsNotReadyToHandleTheRequest
+    (models::ErrorModel)
+    ,
+    /// A server-side problem that might not be addressable from the client side. Used for server 5xx errors without more specific documentation in individual routes.
+    AServer
+    (models::ErrorModel)
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+pub enum UpdateGrantsResponse {
+    /// The grants of the role
+    TheGrantsOfTheRole
+    (models::ListGrants200Response)
+    ,
+    /// Indicates a bad request error. It could be caused by an unexpected request body format or other forms of request validation failure, such as invalid json. Usually serves application/json content, although in some cases simple text/plain content might be returned by the server's middleware.
+    IndicatesABadRequestError
+    (models::ErrorModel)
+    ,
+    /// Unauthorized. Authentication is required and has failed or has not yet been provided.
+    Unauthorized
+    (models::ErrorModel)
+    ,
+    /// Forbidden. Authenticated user does not have the necessary permissions.
+    Forbidden
+    (models::ErrorModel)
+    ,
+    /// Not Found - NoSuchRoleException, the role does not exist - NoSuchNamespaceException or NoSuchTableException, the object of a grant does not exist
+    NotFound
+    (models::ErrorModel)
+    ,
+    /// Credentials have timed out. If possible, the client should refresh credentials and retry.
+    CredentialsHaveTimedOut
+    (models::ErrorModel)
+    ,
+    /// The service is not ready to handle the request. The client should wait and retry.  The service may additionally send a Retry-After header to indicate when to retry.
+    TheServiceIsNotReadyToHandleTheRequest
+    (models::ErrorModel)
+    ,
+    /// A server-side problem that might not be addressable from the client side. Used for server 5xx errors without more specific documentation in individual routes.
+    AServer
+    (models::ErrorModel)
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+pub enum UpdateRoleResponse {
+    /// The role and its principals
+    TheRoleAndItsPrincipals
+    (models::Role)
+    ,
+    /// Indicates a bad request error. It could be caused by an unexpected request body format or other forms of request validation failure, such as invalid json. Usually serves application/json content, although in some cases simple text/plain content might be returned by the server's middleware.
+    IndicatesABadRequestError
+    (models::ErrorModel)
+    ,
+    /// Unauthorized. Authentication is required and has failed or has not yet been provided.
+    Unauthorized
+    (models::ErrorModel)
+    ,
+    /// Forbidden. Authenticated user does not have the necessary permissions.
+    Forbidden
+    (models::ErrorModel)
+    ,
+    /// Credentials have timed out. If possible, the client should refresh credentials and retry.
+    CredentialsHaveTimedOut
+    (models::ErrorModel)
+    ,
+    /// The service is not ready to handle the request. The client should wait and retry.  The service may additionally send a Retry-After header to indicate when to retry.
+    TheServiceIsNotReadyToHandleTheRequest
+    (models::ErrorModel)
+    ,
+    /// A server-side problem that might not be addressable from the client side. Used for server 5xx errors without more specific documentation in individual routes.
+    AServer
+    (models::ErrorModel)
+}
+
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[must_use]
 pub enum CreateNamespaceResponse {
@@ -37,6 +459,10 @@ pub enum CreateNamespaceResponse {
     NotAcceptable
     (models::ErrorModel)
     ,
+    /// Not Found - The parent of the namespace does not exist.
+    NotFound
+    (models::ErrorModel)
+    ,
     /// Conflict - The namespace already exists
     Conflict
     (models::ErrorModel)
@@ -116,6 +542,10 @@ pub enum DropNamespaceResponse {
     NotFound
     (models::ErrorModel)
     ,
+    /// Conflict - The namespace contains tables or namespaces and cascade was not requested
+    Conflict
+    (models::ErrorModel)
+    ,
     /// Credentials have timed out. If possible, the client should refresh credentials and retry.
     CredentialsHaveTimedOut
     (models::ErrorModel)
@@ -151,6 +581,10 @@ pub enum DropTableResponse {
     NotFound
     (models::ErrorModel)
     ,
+    /// Conflict - CommitFailedException, the table has been changed concurrently
+    Conflict
+    (models::ErrorModel)
+    ,
     /// Credentials have timed out. If possible, the client should refresh credentials and retry.
     CredentialsHaveTimedOut
     (models::ErrorModel)
@@ -200,6 +634,78 @@ pub enum ListNamespacesResponse {
     (models::ErrorModel)
 }
 
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+pub enum ListTableCommitsResponse {
+    /// A page of the commit history of the table
+    APageOfTheCommitHistoryOfTheTable
+    (models::ListTableCommits200Response)
+    ,
+    /// Indicates a bad request error. It could be caused by an unexpected request body format or other forms of request validation failure, such as invalid json. Usually serves application/json content, although in some cases simple text/plain content might be returned by the server's middleware.
+    IndicatesABadRequestError
+    (models::ErrorModel)
+    ,
+    /// Unauthorized. Authentication is required and has failed or has not yet been provided.
+    Unauthorized
+    (models::ErrorModel)
+    ,
+    /// Forbidden. Authenticated user does not have the necessary permissions.
+    Forbidden
+    (models::ErrorModel)
+    ,
+    /// Not Found - NoSuchTableException, the table does not exist
+    NotFound
+    (models::ErrorModel)
+    ,
+    /// Credentials have timed out. If possible, the client should refresh credentials and retry.
+    CredentialsHaveTimedOut
+    (models::ErrorModel)
+    ,
+    /// A server-side problem that might not be addressable from the client side. Used for server 5xx errors without more specific documentation in individual routes.
+    AServer
+    (models::ErrorModel)
+    ,
+    /// The service is not ready to handle the request. The client should wait and retry.  The service may additionally send a Retry-After header to indicate when to retry.
+    TheServiceIsNotReadyToHandleTheRequest
+    (models::ErrorModel)
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+pub enum ListTableMetricsResponse {
+    /// The aggregated metrics of the tables
+    TheAggregatedMetricsOfTheTables
+    (models::ListTableMetrics200Response)
+    ,
+    /// Indicates a bad request error. It could be caused by an unexpected request body format or other forms of request validation failure, such as invalid json. Usually serves application/json content, although in some cases simple text/plain content might be returned by the server's middleware.
+    IndicatesABadRequestError
+    (models::ErrorModel)
+    ,
+    /// Unauthorized. Authentication is required and has failed or has not yet been provided.
+    Unauthorized
+    (models::ErrorModel)
+    ,
+    /// Forbidden. Authenticated user does not have the necessary permissions.
+    Forbidden
+    (models::ErrorModel)
+    ,
+    /// Not Found - NoSuchNamespaceException, the namespace does not exist
+    NotFound
+    (models::ErrorModel)
+    ,
+    /// Credentials have timed out. If possible, the client should refresh credentials and retry.
+    CredentialsHaveTimedOut
+    (models::ErrorModel)
+    ,
+    /// A server-side problem that might not be addressable from the client side. Used for server 5xx errors without more specific documentation in individual routes.
+    AServer
+    (models::ErrorModel)
+    ,
+    /// The service is not ready to handle the request. The client should wait and retry.  The service may additionally send a Retry-After header to indicate when to retry.
+    TheServiceIsNotReadyToHandleTheRequest
+    (models::ErrorModel)
+}
+
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[must_use]
 pub enum ListTablesResponse {
@@ -398,6 +904,9 @@ pub enum TableExistsResponse {
     /// Unauthorized
     Unauthorized
     ,
+    /// Forbidden
+    Forbidden
+    ,
     /// Not Found
     NotFound
     ,
@@ -525,6 +1034,10 @@ pub enum GetConfigResponse {
     Forbidden
     (models::ErrorModel)
     ,
+    /// Not Found - NoSuchWarehouseException, the warehouse does not exist
+    NotFound
+    (models::ErrorModel)
+    ,
     /// Credentials have timed out. If possible, the client should refresh credentials and retry.
     CredentialsHaveTimedOut
     (models::ErrorModel)
@@ -566,6 +1079,79 @@ pub trait Api<C: Send + Sync> {
         Poll::Ready(Ok(()))
     }
 
+    /// Create a catalog
+    async fn create_catalog(
+        &self,
+        create_catalog_request: models::CreateCatalogRequest,
+        context: &C) -> Result<CreateCatalogResponse, ApiError>;
+
+    /// Drop an empty catalog
+    async fn drop_catalog(
+        &self,
+        catalog: String,
+        context: &C) -> Result<DropCatalogResponse, ApiError>;
+
+    /// Drop a role and its grants
+    async fn drop_role(
+        &self,
+        role: String,
+        context: &C) -> Result<DropRoleResponse, ApiError>;
+
+    /// Get the configuration a catalog pushes to clients
+    async fn get_catalog_config(
+        &self,
+        catalog: Option<String>,
+        context: &C) -> Result<GetCatalogConfigResponse, ApiError>;
+
+    /// List the catalogs
+    async fn list_catalogs(
+        &self,
+        context: &C) -> Result<ListCatalogsResponse, ApiError>;
+
+    /// List the grants of a role
+    async fn list_grants(
+        &self,
+        role: String,
+        context: &C) -> Result<ListGrantsResponse, ApiError>;
+
+    /// List the roles and their principals
+    async fn list_roles(
+        &self,
+        context: &C) -> Result<ListRolesResponse, ApiError>;
+
+    /// Describe a catalog
+    async fn load_catalog(
+        &self,
+        catalog: String,
+        context: &C) -> Result<LoadCatalogResponse, ApiError>;
+
+    /// Update the storage root or properties of a catalog
+    async fn update_catalog(
+        &self,
+        catalog: String,
+        update_catalog_request: models::UpdateCatalogRequest,
+        context: &C) -> Result<UpdateCatalogResponse, ApiError>;
+
+    /// Replace the configuration a catalog pushes to clients
+    async fn update_catalog_config(
+        &self,
+        update_catalog_config_request: models::UpdateCatalogConfigRequest,
+        context: &C) -> Result<UpdateCatalogConfigResponse, ApiError>;
+
+    /// Grant privileges to or revoke privileges from a role
+    async fn update_grants(
+        &self,
+        role: String,
+        update_grants_request: models::UpdateGrantsRequest,
+        context: &C) -> Result<UpdateGrantsResponse, ApiError>;
+
+    /// Create a role or replace its principals
+    async fn update_role(
+        &self,
+        role: String,
+        update_role_request: models::UpdateRoleRequest,
+        context: &C) -> Result<UpdateRoleResponse, ApiError>;
+
     /// Create a namespace
     async fn create_namespace(
         &self,
@@ -586,6 +1172,7 @@ pub trait Api<C: Send + Sync> {
         &self,
         prefix: String,
         namespace: String,
+        cascade: Option<bool>,
         context: &C) -> Result<DropNamespaceResponse, ApiError>;
 
     /// Drop a table from the catalog
@@ -604,6 +1191,26 @@ pub trait Api<C: Send + Sync> {
         parent: Option<String>,
         context: &C) -> Result<ListNamespacesResponse, ApiError>;
 
+    /// List the commits of a table
+    async fn list_table_commits(
+        &self,
+        prefix: String,
+        namespace: String,
+        table: String,
+        page_token: Option<String>,
+        page_size: Option<i32>,
+        context: &C) -> Result<ListTableCommitsResponse, ApiError>;
+
+    /// Aggregate the metrics reports of the tables of a namespace
+    async fn list_table_metrics(
+        &self,
+        prefix: String,
+        namespace: String,
+        table: Option<String>,
+        from: Option<i64>,
+        to: Option<i64>,
+        context: &C) -> Result<ListTableMetricsResponse, ApiError>;
+
     /// List all table identifiers underneath a given namespace
     async fn list_tables(
         &self,
@@ -670,6 +1277,7 @@ pub trait Api<C: Send + Sync> {
     /// List all catalog configuration settings
     async fn get_config(
         &self,
+        warehouse: Option<String>,
         context: &C) -> Result<GetConfigResponse, ApiError>;
 
     /// Get a token using an OAuth2 flow
@@ -697,6 +1305,79 @@ pub trait ApiNoContext<C: Send + Sync> {
 
     fn context(&self) -> &C;
 
+    /// Create a catalog
+    async fn create_catalog(
+        &self,
+        create_catalog_request: models::CreateCatalogRequest,
+        ) -> Result<CreateCatalogResponse, ApiError>;
+
+    /// Drop an empty catalog
+    async fn drop_catalog(
+        &self,
+        catalog: String,
+        ) -> Result<DropCatalogResponse, ApiError>;
+
+    /// Drop a role and its grants
+    async fn drop_role(
+        &self,
+        role: String,
+        ) -> Result<DropRoleResponse, ApiError>;
+
+    /// Get the configuration a catalog pushes to clients
+    async fn get_catalog_config(
+        &self,
+        catalog: Option<String>,
+        ) -> Result<GetCatalogConfigResponse, ApiError>;
+
+    /// List the catalogs
+    async fn list_catalogs(
+        &self,
+        ) -> Result<ListCatalogsResponse, ApiError>;
+
+    /// List the grants of a role
+    async fn list_grants(
+        &self,
+        role: String,
+        ) -> Result<ListGrantsResponse, ApiError>;
+
+    /// List the roles and their principals
+    async fn list_roles(
+        &self,
+        ) -> Result<ListRolesResponse, ApiError>;
+
+    /// Describe a catalog
+    async fn load_catalog(
+        &self,
+        catalog: String,
+        ) -> Result<LoadCatalogResponse, ApiError>;
+
+    /// Update the storage root or properties of a catalog
+    async fn update_catalog(
+        &self,
+        catalog: String,
+        update_catalog_request: models::UpdateCatalogRequest,
+        ) -> Result<UpdateCatalogResponse, ApiError>;
+
+    /// Replace the configuration a catalog pushes to clients
+    async fn update_catalog_config(
+        &self,
+        update_catalog_config_request: models::UpdateCatalogConfigRequest,
+        ) -> Result<UpdateCatalogConfigResponse, ApiError>;
+
+    /// Grant privileges to or revoke privileges from a role
+    async fn update_grants(
+        &self,
+        role: String,
+        update_grants_request: models::UpdateGrantsRequest,
+        ) -> Result<UpdateGrantsResponse, ApiError>;
+
+    /// Create a role or replace its principals
+    async fn update_role(
+        &self,
+        role: String,
+        update_role_request: models::UpdateRoleRequest,
+        ) -> Result<UpdateRoleResponse, ApiError>;
+
     /// Create a namespace
     async fn create_namespace(
         &self,
@@ -717,6 +1398,7 @@ pub trait ApiNoContext<C: Send + Sync> {
         &self,
         prefix: String,
         namespace: String,
+        cascade: Option<bool>,
         ) -> Result<DropNamespaceResponse, ApiError>;
 
     /// Drop a table from the catalog
@@ -735,6 +1417,26 @@ pub trait ApiNoContext<C: Send + Sync> {
         parent: Option<String>,
         ) -> Result<ListNamespacesResponse, ApiError>;
 
+    /// List the commits of a table
+    async fn list_table_commits(
+        &self,
+        prefix: String,
+        namespace: String,
+        table: String,
+        page_token: Option<String>,
+        page_size: Option<i32>,
+        ) -> Result<ListTableCommitsResponse, ApiError>;
+
+    /// Aggregate the metrics reports of the tables of a namespace
+    async fn list_table_metrics(
+        &self,
+        prefix: String,
+        namespace: String,
+        table: Option<String>,
+        from: Option<i64>,
+        to: Option<i64>,
+        ) -> Result<ListTableMetricsResponse, ApiError>;
+
     /// List all table identifiers underneath a given namespace
     async fn list_tables(
         &self,
@@ -801,6 +1503,7 @@ pub trait ApiNoContext<C: Send + Sync> {
     /// List all catalog configuration settings
     async fn get_config(
         &self,
+        warehouse: Option<String>,
         ) -> Result<GetConfigResponse, ApiError>;
 
     /// Get a token using an OAuth2 flow
@@ -842,6 +1545,127 @@ impl<T: Api<C> + Send + Sync, C: Clone + Send + Sync> ApiNoContext<C> for Contex
         ContextWrapper::context(self)
     }
 
+    /// Create a catalog
+    async fn create_catalog(
+        &self,
+        create_catalog_request: models::CreateCatalogRequest,
+        ) -> Result<CreateCatalogResponse, ApiError>
+    {
+        let context = self.context().clone();
+        self.api().create_catalog(create_catalog_request, &context).await
+    }
+
+    /// Drop an empty catalog
+    async fn drop_catalog(
+        &self,
+        catalog: String,
+        ) -> Result<DropCatalogResponse, ApiError>
+    {
+        let context = self.context().clone();
+        self.api().drop_catalog(catalog, &context).await
+    }
+
+    /// Drop a role and its grants
+    async fn drop_role(
+        &self,
+        role: String,
+        ) -> Result<DropRoleResponse, ApiError>
+    {
+        let context = self.context().clone();
+        self.api().drop_role(role, &context).await
+    }
+
+    /// Get the configuration a catalog pushes to clients
+    async fn get_catalog_config(
+        &self,
+        catalog: Option<String>,
+        ) -> Result<GetCatalogConfigResponse, ApiError>
+    {
+        let context = self.context().clone();
+        self.api().get_catalog_config(catalog, &context).await
+    }
+
+    /// List the catalogs
+    async fn list_catalogs(
+        &self,
+        ) -> Result<ListCatalogsResponse, ApiError>
+    {
+        let context = self.context().clone();
+        self.api().list_catalogs(&context).await
+    }
+
+    /// List the grants of a role
+    async fn list_grants(
+        &self,
+        role: String,
+        ) -> Result<ListGrantsResponse, ApiError>
+    {
+        let context = self.context().clone();
+        self.api().list_grants(role, &context).await
+    }
+
+    /// List the roles and their principals
+    async fn list_roles(
+        &self,
+        ) -> Result<ListRolesResponse, ApiError>
+    {
+        let context = self.context().clone();
+        self.api().list_roles(&context).await
+    }
+
+    /// Describe a catalog
+    async fn load_catalog(
+        &self,
+        catalog: String,
+        ) -> Result<LoadCatalogResponse, ApiError>
+    {
+        let context = self.context().clone();
+        self.api().load_catalog(catalog, &context).await
+    }
+
+    /// Update the storage root or properties of a catalog
+    async fn update_catalog(
+        &self,
+        catalog: String,
+        update_catalog_request: models::UpdateCatalogRequest,
+        ) -> Result<UpdateCatalogResponse, ApiError>
+    {
+        let context = self.context().clone();
+        self.api().update_catalog(catalog, update_catalog_request, &context).await
+    }
+
+    /// Replace the configuration a catalog pushes to clients
+    async fn update_catalog_config(
+        &self,
+        update_catalog_config_request: models::UpdateCatalogConfigRequest,
+        ) -> Result<UpdateCatalogConfigResponse, ApiError>
+    {
+        let context = self.context().clone();
+        self.api().update_catalog_config(update_catalog_config_request, &context).await
+    }
+
+    /// Grant privileges to or revoke privileges from a role
+    async fn update_grants(
+        &self,
+        role: String,
+        update_grants_request: models::UpdateGrantsRequest,
+        ) -> Result<UpdateGrantsResponse, ApiError>
+    {
+        let context = self.context().clone();
+        self.api().update_grants(role, update_grants_request, &context).await
+    }
+
+    /// Create a role or replace its principals
+    async fn update_role(
+        &self,
+        role: String,
+        update_role_request: models::UpdateRoleRequest,
+        ) -> Result<UpdateRoleResponse, ApiError>
+    {
+        let context = self.context().clone();
+        self.api().update_role(role, update_role_request, &context).await
+    }
+
     /// Create a namespace
     async fn create_namespace(
         &self,
@@ -870,10 +1694,11 @@ impl<T: Api<C> + Send + Sync, C: Clone + Send + Sync> ApiNoContext<C> for Contex
         &self,
         prefix: String,
         namespace: String,
+        cascade: Option<bool>,
         ) -> Result<DropNamespaceResponse, ApiError>
     {
         let context = self.context().clone();
-        self.api().drop_namespace(prefix, namespace, &context).await
+        self.api().drop_namespace(prefix, namespace, cascade, &context).await
     }
 
     /// Drop a table from the catalog
@@ -900,6 +1725,34 @@ impl<T: Api<C> + Send + Sync, C: Clone + Send + Sync> ApiNoContext<C> for Contex
         self.api().list_namespaces(prefix, parent, &context).await
     }
 
+    /// List the commits of a table
+    async fn list_table_commits(
+        &self,
+        prefix: String,
+        namespace: String,
+        table: String,
+        page_token: Option<String>,
+        page_size: Option<i32>,
+        ) -> Result<ListTableCommitsResponse, ApiError>
+    {
+        let context = self.context().clone();
+        self.api().list_table_commits(prefix, namespace, table, page_token, page_size, &context).await
+    }
+
+    /// Aggregate the metrics reports of the tables of a namespace
+    async fn list_table_metrics(
+        &self,
+        prefix: String,
+        namespace: String,
+        table: Option<String>,
+        from: Option<i64>,
+        to: Option<i64>,
+        ) -> Result<ListTableMetricsResponse, ApiError>
+    {
+        let context = self.context().clone();
+        self.api().list_table_metrics(prefix, namespace, table, from, to, &context).await
+    }
+
     /// List all table identifiers underneath a given namespace
     async fn list_tables(
         &self,
@@ -998,10 +1851,11 @@ impl<T: Api<C> + Send + Sync, C: Clone + Send + Sync> ApiNoContext<C> for Contex
     /// List all catalog configuration settings
     async fn get_config(
         &self,
+        warehouse: Option<String>,
         ) -> Result<GetConfigResponse, ApiError>
     {
         let context = self.context().clone();
-        self.api().get_config(&context).await
+        self.api().get_config(warehouse, &context).await
     }
 
     /// Get a token using an OAuth2 flow
@@ -1,10 +1,12 @@
 //! Main library entry point for openapi_client implementation.
 use std::env;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::process;
+use std::sync::Arc;
 
 use hyper::server::conn::Http;
 use hyper::service::Service;
-use sea_orm::DatabaseConnection;
-use swagger::auth::MakeAllowAllAuthenticator;
 use swagger::EmptyContext;
 use tokio::net::TcpListener;
 
@@ -13,64 +15,377 @@ use openssl::ssl::{Ssl, SslAcceptor, SslFiletype, SslMethod};
 
 use iceberg_catalog_rest_rdbms_server::server::MakeService;
 
-use clap::Parser;
+use clap::{Parser, Subcommand};
+use log::warn;
+use rand::Rng;
+
+use api::ClientConfig;
+use auth::{MakeBearerAuthenticator, TokenIssuer, Verifier};
+use config::{AuthConfig, Config, ConfigError, MetricsConfig, MetricsReporterKind, ServerConfig};
+use reporter::{JsonlReporter, LogReporter, MetricsReporter, PrometheusReporter, Reporters};
 
 mod api;
+mod auth;
+mod config;
 mod database;
+mod io;
+mod reporter;
 
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
 struct Args {
-    /// Host
+    /// TOML file with the settings of the server, which environment variables and
+    /// flags override
+    #[arg(long)]
+    config: Option<PathBuf>,
+    /// IP address to listen at
     #[arg(long)]
     host: Option<String>,
-    /// Port
+    /// Port to listen at
     #[arg(short, long)]
-    port: Option<String>,
-    /// Https flag
+    port: Option<u16>,
+    /// Serve HTTPS with the TLS key and certificate chain files
     #[arg(long)]
     https: bool,
+    /// PEM file of the private key of the server
+    #[arg(long)]
+    tls_key_file: Option<PathBuf>,
+    /// PEM file of the certificate chain of the server
+    #[arg(long)]
+    tls_chain_file: Option<PathBuf>,
+    /// Location below which tables are created if no location is requested
+    #[arg(long)]
+    warehouse: Option<String>,
+    /// Sink the metrics reports are handed to, `log`, `jsonl` or `prometheus`, may
+    /// be repeated
+    #[arg(long)]
+    metrics_reporter: Vec<MetricsReporterKind>,
+    /// File the jsonl sink appends the reports to
+    #[arg(long)]
+    metrics_jsonl_path: Option<PathBuf>,
+    /// Size in bytes at which the file of the jsonl sink is rotated
+    #[arg(long)]
+    metrics_jsonl_max_bytes: Option<u64>,
+    /// Number of rotated files the jsonl sink keeps
+    #[arg(long)]
+    metrics_jsonl_max_files: Option<usize>,
+    /// Address the prometheus sink serves the metrics at
+    #[arg(long)]
+    prometheus_addr: Option<SocketAddr>,
+    /// Issuer of the access tokens
+    #[arg(long)]
+    token_issuer: Option<String>,
+    /// Audience of the access tokens
+    #[arg(long)]
+    token_audience: Option<String>,
+    /// Lifetime of the access tokens in seconds
+    #[arg(long)]
+    token_lifetime: Option<i64>,
+    /// Issuer of bearer tokens from an identity provider that are accepted besides
+    /// the catalog's own tokens, may be repeated
+    #[arg(long)]
+    jwt_issuer: Vec<String>,
+    /// Audience of bearer tokens from an identity provider that is accepted
+    /// besides the audience of the catalog's own tokens, may be repeated
+    #[arg(long)]
+    jwt_audience: Vec<String>,
+    /// PEM file of the public key bearer tokens from an identity provider are
+    /// signed with
+    #[arg(long)]
+    jwt_public_key: Option<PathBuf>,
+    /// JWKS file of the keys bearer tokens from an identity provider are signed with
+    #[arg(long)]
+    jwks: Option<PathBuf>,
+    /// Subject of the access tokens of a principal that holds every privilege and
+    /// manages the roles with tokens of the `admin` scope, may be repeated
+    #[arg(long)]
+    admin: Vec<String>,
+    /// Property every catalog pushes to clients as a default, as KEY=VALUE, may be
+    /// repeated
+    #[arg(long, value_parser = property)]
+    config_default: Vec<(String, String)>,
+    /// Property every catalog pushes to clients as an override, as KEY=VALUE, may be
+    /// repeated
+    #[arg(long, value_parser = property)]
+    config_override: Vec<(String, String)>,
+    /// Reject requests for prefixes without a catalog instead of creating the catalog
+    /// with its first namespace or table
+    #[arg(long)]
+    strict_catalogs: bool,
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Register an OAuth2 client or replace its secret and scopes. The secret is
+    /// read from CLIENT_SECRET or generated and printed.
+    RegisterClient {
+        #[arg(long)]
+        client_id: String,
+        /// Space separated scopes the client may request
+        #[arg(long, default_value = api::DEFAULT_SCOPE)]
+        scopes: String,
+    },
 }
 
 /// Create custom server, wire it to the autogenerated router,
 /// and pass it to the web server.
 #[tokio::main]
 async fn main() {
-    env_logger::init();
+    let args = Args::parse();
 
-    let db_type = env::var("DB_TYPE").unwrap_or("postgres".to_owned());
-    let db_user = env::var("DB_USER").unwrap_or("postgres".to_owned());
-    let db_password = env::var("DB_PASSWORD").expect("Database password has to be provided");
-    let db_host = env::var("DB_HOST").expect("Database host has to be provided");
-    let db_port = env::var("DB_PORT").unwrap_or("5432".to_owned());
+    let config = config(&args).unwrap_or_else(|err| exit(err));
 
-    let db_name = env::var("DB_NAME").unwrap_or("iceberg_catalog".to_owned());
+    env_logger::Builder::new()
+        .parse_filters(&config.log.filter)
+        .init();
 
-    let db_connection =
-        db_type + "://" + &db_user + ":" + &db_password + "@" + &db_host + ":" + &db_port;
+    let db = database::run(&config.database).await.unwrap_or_else(|err| {
+        exit(ConfigError::Invalid(
+            config.database.location_setting(),
+            err.to_string(),
+        ))
+    });
 
-    let db = database::run(&db_connection, &db_name).await.unwrap();
+    if let Some(Command::RegisterClient { client_id, scopes }) = &args.command {
+        let (client_secret, generated) = match env::var("CLIENT_SECRET") {
+            Ok(client_secret) => (client_secret, false),
+            Err(_) => (hex::encode(rand::thread_rng().gen::<[u8; 32]>()), true),
+        };
+        api::register_client(&db, client_id, &client_secret, scopes)
+            .await
+            .expect("Failed to register the client");
+        println!("Registered client {}", client_id);
+        if generated {
+            println!("Client secret: {}", client_secret);
+        }
+        return;
+    }
 
-    let args = Args::parse();
+    let reporters = reporters(&config.metrics);
+
+    let auth = &config.auth;
+    let signing_key = signing_key(auth);
+    let tokens = Arc::new(TokenIssuer::new(
+        &signing_key,
+        auth.token_issuer.clone(),
+        auth.token_audience.clone(),
+        auth.token_lifetime,
+    ));
+    let verifier = Arc::new(verifier(auth, &signing_key).unwrap_or_else(|err| exit(err)));
+
+    if auth.admins.is_empty() {
+        warn!("No admins are configured, so no roles can be managed");
+    }
+
+    let catalog = config.catalog;
+    let client_config = ClientConfig {
+        defaults: catalog.config_defaults.into_iter().collect(),
+        overrides: catalog.config_overrides.into_iter().collect(),
+    };
+
+    let server = api::Server::new(
+        db,
+        catalog.warehouse,
+        reporters,
+        tokens,
+        verifier.clone(),
+        auth.admins.iter().cloned().collect(),
+        client_config,
+        catalog.strict_catalogs,
+    );
+    if let Err(err) = server.resume_purges().await {
+        exit(ConfigError::Invalid(
+            config.database.location_setting(),
+            format!("Failed to resume purges: {}", err),
+        ));
+    }
+
+    if let Err(err) = create(&config.server, server, verifier).await {
+        exit(err);
+    }
+}
+
+/// Reports an invalid setting and exits.
+fn exit(err: ConfigError) -> ! {
+    eprintln!("{}", err);
+    process::exit(1);
+}
 
-    let host = args.host.unwrap_or_else(|| "0.0.0.0".to_owned());
+/// Reads the settings from the configuration file, the environment and the flags,
+/// in increasing precedence, and validates them.
+fn config(args: &Args) -> Result<Config, ConfigError> {
+    let mut config = match &args.config {
+        Some(path) => Config::from_file(path)?,
+        None => Config::default(),
+    };
+    config.apply_env(|name| env::var(name).ok())?;
 
-    let port = args.port.unwrap_or_else(|| "8080".to_owned());
+    let server = &mut config.server;
+    if let Some(host) = &args.host {
+        server.host = host.clone();
+    }
+    if let Some(port) = args.port {
+        server.port = port;
+    }
+    server.https |= args.https;
+    if let Some(tls_key_file) = &args.tls_key_file {
+        server.tls_key_file = Some(tls_key_file.clone());
+    }
+    if let Some(tls_chain_file) = &args.tls_chain_file {
+        server.tls_chain_file = Some(tls_chain_file.clone());
+    }
 
-    let addr = host + ":" + &port;
+    let auth = &mut config.auth;
+    if let Some(token_issuer) = &args.token_issuer {
+        auth.token_issuer = token_issuer.clone();
+    }
+    if let Some(token_audience) = &args.token_audience {
+        auth.token_audience = token_audience.clone();
+    }
+    if let Some(token_lifetime) = args.token_lifetime {
+        auth.token_lifetime = token_lifetime;
+    }
+    if !args.jwt_issuer.is_empty() {
+        auth.jwt_issuers = args.jwt_issuer.clone();
+    }
+    if !args.jwt_audience.is_empty() {
+        auth.jwt_audiences = args.jwt_audience.clone();
+    }
+    if let Some(jwt_public_key) = &args.jwt_public_key {
+        auth.jwt_public_key = Some(jwt_public_key.clone());
+    }
+    if let Some(jwks) = &args.jwks {
+        auth.jwks = Some(jwks.clone());
+    }
+    if !args.admin.is_empty() {
+        auth.admins = args.admin.clone();
+    }
 
-    create(&addr, args.https, db).await;
+    let catalog = &mut config.catalog;
+    if let Some(warehouse) = &args.warehouse {
+        catalog.warehouse = Some(warehouse.clone());
+    }
+    catalog.strict_catalogs |= args.strict_catalogs;
+    catalog
+        .config_defaults
+        .extend(args.config_default.iter().cloned());
+    catalog
+        .config_overrides
+        .extend(args.config_override.iter().cloned());
+
+    let metrics = &mut config.metrics;
+    if !args.metrics_reporter.is_empty() {
+        metrics.reporters = args.metrics_reporter.clone();
+    }
+    if let Some(jsonl_path) = &args.metrics_jsonl_path {
+        metrics.jsonl_path = jsonl_path.clone();
+    }
+    if let Some(jsonl_max_bytes) = args.metrics_jsonl_max_bytes {
+        metrics.jsonl_max_bytes = jsonl_max_bytes;
+    }
+    if let Some(jsonl_max_files) = args.metrics_jsonl_max_files {
+        metrics.jsonl_max_files = jsonl_max_files;
+    }
+    if let Some(prometheus_addr) = args.prometheus_addr {
+        metrics.prometheus_addr = prometheus_addr;
+    }
+
+    config.validate()?;
+    Ok(config)
+}
+
+/// Parses a KEY=VALUE property given on the command line.
+fn property(arg: &str) -> Result<(String, String), String> {
+    match arg.split_once('=') {
+        Some((key, value)) if !key.is_empty() => Ok((key.to_owned(), value.to_owned())),
+        _ => Err(format!("expected KEY=VALUE, got {}", arg)),
+    }
+}
+
+/// Returns the key the access tokens are signed with.
+///
+/// Without a configured key a random key is used, so tokens are invalidated by a
+/// restart and can't be verified by other instances.
+fn signing_key(auth: &AuthConfig) -> Vec<u8> {
+    match &auth.token_signing_key {
+        Some(key) => key.clone().into_bytes(),
+        None => {
+            warn!("No token signing key is configured, signing access tokens with a random key");
+            rand::thread_rng().gen::<[u8; 32]>().to_vec()
+        }
+    }
+}
+
+/// Returns the verifier of bearer tokens, which accepts the catalog's own tokens
+/// and those of the configured identity provider.
+fn verifier(auth: &AuthConfig, signing_key: &[u8]) -> Result<Verifier, ConfigError> {
+    let mut issuers = vec![auth.token_issuer.clone()];
+    issuers.extend(auth.jwt_issuers.iter().cloned());
+    let mut audiences = vec![auth.token_audience.clone()];
+    audiences.extend(auth.jwt_audiences.iter().cloned());
+
+    let mut verifier = Verifier::new(issuers, audiences).with_secret(signing_key);
+    if let Some(path) = &auth.jwt_public_key {
+        let invalid = |err: String| ConfigError::Invalid("auth.jwt-public-key", err);
+        let pem = std::fs::read(path).map_err(|err| invalid(err.to_string()))?;
+        verifier = verifier
+            .with_pem(&pem)
+            .map_err(|err| invalid(err.to_string()))?;
+    }
+    if let Some(path) = &auth.jwks {
+        verifier = verifier
+            .with_jwks_file(path)
+            .map_err(|err| ConfigError::Invalid("auth.jwks", err))?;
+    }
+    Ok(verifier)
 }
 
-/// Builds an SSL implementation for Simple HTTPS from some hard-coded file names
-pub async fn create(addr: &str, https: bool, db: DatabaseConnection) {
-    let addr = addr.parse().expect("Failed to parse bind address");
+/// Starts the configured metrics report sinks.
+fn reporters(config: &MetricsConfig) -> Reporters {
+    let mut kinds = Vec::new();
+    for kind in &config.reporters {
+        if !kinds.contains(kind) {
+            kinds.push(*kind);
+        }
+    }
+    let reporters = kinds
+        .into_iter()
+        .map(|kind| -> Arc<dyn MetricsReporter> {
+            match kind {
+                MetricsReporterKind::Log => Arc::new(LogReporter),
+                MetricsReporterKind::Jsonl => Arc::new(JsonlReporter::new(
+                    &config.jsonl_path,
+                    config.jsonl_max_bytes,
+                    config.jsonl_max_files,
+                )),
+                MetricsReporterKind::Prometheus => {
+                    let reporter = Arc::new(
+                        PrometheusReporter::new()
+                            .expect("Failed to register the Prometheus metrics"),
+                    );
+                    reporter.clone().serve(config.prometheus_addr);
+                    reporter
+                }
+            }
+        })
+        .collect();
+    Reporters::new(reporters)
+}
 
-    let server = api::Server::new(db);
+/// Serves the API over HTTP, or over HTTPS with the configured TLS files. Fails if
+/// the TLS files can't be used or the address can't be bound.
+pub async fn create(
+    config: &ServerConfig,
+    server: api::Server,
+    verifier: Arc<Verifier>,
+) -> Result<(), ConfigError> {
+    let addr = config.addr()?;
 
     let service = MakeService::new(server);
 
-    let service = MakeAllowAllAuthenticator::new(service, "cosmo");
+    let service = MakeBearerAuthenticator::new(service, verifier);
 
     #[allow(unused_mut)]
     let mut service = iceberg_catalog_rest_rdbms_server::server::context::MakeAddContext::<
@@ -78,7 +393,7 @@ pub async fn create(addr: &str, https: bool, db: DatabaseConnection) {
         EmptyContext,
     >::new(service);
 
-    if https {
+    if config.https {
         #[cfg(any(target_os = "macos", target_os = "windows", target_os = "ios"))]
         {
             unimplemented!("SSL is not implemented for the examples on MacOS, Windows or iOS");
@@ -89,16 +404,25 @@ pub async fn create(addr: &str, https: bool, db: DatabaseConnection) {
             let mut ssl = SslAcceptor::mozilla_intermediate_v5(SslMethod::tls())
                 .expect("Failed to create SSL Acceptor");
 
-            // Server authentication
-            ssl.set_private_key_file("examples/server-key.pem", SslFiletype::PEM)
-                .expect("Failed to set private key");
-            ssl.set_certificate_chain_file("examples/server-chain.pem")
-                .expect("Failed to set certificate chain");
-            ssl.check_private_key()
-                .expect("Failed to check private key");
+            // Server authentication, the files are known to exist after validation
+            let invalid_key = |err: openssl::error::ErrorStack| {
+                ConfigError::Invalid("server.tls-key-file", err.to_string())
+            };
+            let key_file = config.tls_key_file.as_ref().expect("Missing TLS key file");
+            let chain_file = config
+                .tls_chain_file
+                .as_ref()
+                .expect("Missing TLS certificate chain file");
+            ssl.set_private_key_file(key_file, SslFiletype::PEM)
+                .map_err(invalid_key)?;
+            ssl.set_certificate_chain_file(chain_file)
+                .map_err(|err| ConfigError::Invalid("server.tls-chain-file", err.to_string()))?;
+            ssl.check_private_key().map_err(invalid_key)?;
 
             let tls_acceptor = ssl.build();
-            let tcp_listener = TcpListener::bind(&addr).await.unwrap();
+            let tcp_listener = TcpListener::bind(&addr)
+                .await
+                .map_err(|err| ConfigError::Invalid("server.port", err.to_string()))?;
 
             loop {
                 if let Ok((tcp, _)) = tcp_listener.accept().await {
@@ -120,9 +444,11 @@ pub async fn create(addr: &str, https: bool, db: DatabaseConnection) {
         }
     } else {
         // Using HTTP
-        hyper::server::Server::bind(&addr)
+        hyper::server::Server::try_bind(&addr)
+            .map_err(|err| ConfigError::Invalid("server.port", err.to_string()))?
             .serve(service)
             .await
-            .unwrap()
+            .unwrap();
+        Ok(())
     }
 }
@@ -306,12 +306,21 @@ pub struct AddSchemaUpdate {
 
     #[serde(rename = "schema")]
     pub schema: models::Schema,
+
+    /// The highest assigned column ID for the table. This is used to ensure columns are always assigned an unused ID when evolving schemas. When omitted, it will be computed on the server side.
+    #[serde(rename = "last-column-id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_column_id: Option<isize>,
 }
 
 impl AddSchemaUpdate {
     #[allow(clippy::new_without_default)]
     pub fn new(action: String, schema: models::Schema) -> AddSchemaUpdate {
-        AddSchemaUpdate { action, schema }
+        AddSchemaUpdate {
+            action,
+            schema,
+            last_column_id: None,
+        }
     }
 }
 
@@ -324,6 +333,9 @@ impl std::string::ToString for AddSchemaUpdate {
             Some("action".to_string()),
             Some(self.action.to_string()),
             // Skipping schema in query parameter serialization
+            self.last_column_id.as_ref().map(|last_column_id| {
+                vec!["last-column-id".to_string(), last_column_id.to_string()].join(",")
+            }),
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
@@ -343,6 +355,7 @@ impl std::str::FromStr for AddSchemaUpdate {
         struct IntermediateRep {
             pub action: Vec<String>,
             pub schema: Vec<models::Schema>,
+            pub last_column_id: Vec<isize>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -373,6 +386,10 @@ impl std::str::FromStr for AddSchemaUpdate {
                         <models::Schema as std::str::FromStr>::from_str(val)
                             .map_err(|x| x.to_string())?,
                     ),
+                    #[allow(clippy::redundant_clone)]
+                    "last-column-id" => intermediate_rep.last_column_id.push(
+                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
                     _ => {
                         return std::result::Result::Err(
                             "Unexpected key while parsing AddSchemaUpdate".to_string(),
@@ -397,6 +414,7 @@ impl std::str::FromStr for AddSchemaUpdate {
                 .into_iter()
                 .next()
                 .ok_or_else(|| "schema missing in AddSchemaUpdate".to_string())?,
+            last_column_id: intermediate_rep.last_column_id.into_iter().next(),
         })
     }
 }
@@ -1348,35 +1366,42 @@ impl std::convert::TryFrom<hyper::header::HeaderValue>
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct BaseUpdate {
+pub struct AssignUuidUpdate {
     // Note: inline enums are not fully supported by openapi-generator
     #[serde(rename = "action")]
     pub action: String,
+
+    #[serde(rename = "uuid")]
+    pub uuid: String,
 }
 
-impl BaseUpdate {
+impl AssignUuidUpdate {
     #[allow(clippy::new_without_default)]
-    pub fn new(action: String) -> BaseUpdate {
-        BaseUpdate { action }
+    pub fn new(action: String, uuid: String) -> AssignUuidUpdate {
+        AssignUuidUpdate { action, uuid }
     }
 }
 
-/// Converts the BaseUpdate value to the Query Parameters representation (style=form, explode=false)
+/// Converts the AssignUuidUpdate value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for BaseUpdate {
+impl std::string::ToString for AssignUuidUpdate {
     fn to_string(&self) -> String {
-        let params: Vec<Option<String>> =
-            vec![Some("action".to_string()), Some(self.action.to_string())];
+        let params: Vec<Option<String>> = vec![
+            Some("action".to_string()),
+            Some(self.action.to_string()),
+            Some("uuid".to_string()),
+            Some(self.uuid.to_string()),
+        ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a BaseUpdate value
+/// Converts Query Parameters representation (style=form, explode=false) to a AssignUuidUpdate value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for BaseUpdate {
+impl std::str::FromStr for AssignUuidUpdate {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -1385,6 +1410,7 @@ impl std::str::FromStr for BaseUpdate {
         #[allow(dead_code)]
         struct IntermediateRep {
             pub action: Vec<String>,
+            pub uuid: Vec<String>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -1398,7 +1424,7 @@ impl std::str::FromStr for BaseUpdate {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing BaseUpdate".to_string(),
+                        "Missing value while parsing AssignUuidUpdate".to_string(),
                     )
                 }
             };
@@ -1410,9 +1436,13 @@ impl std::str::FromStr for BaseUpdate {
                     "action" => intermediate_rep.action.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
+                    #[allow(clippy::redundant_clone)]
+                    "uuid" => intermediate_rep.uuid.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing BaseUpdate".to_string(),
+                            "Unexpected key while parsing AssignUuidUpdate".to_string(),
                         )
                     }
                 }
@@ -1423,30 +1453,37 @@ impl std::str::FromStr for BaseUpdate {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(BaseUpdate {
+        std::result::Result::Ok(AssignUuidUpdate {
             action: intermediate_rep
                 .action
                 .into_iter()
                 .next()
-                .ok_or_else(|| "action missing in BaseUpdate".to_string())?,
+                .ok_or_else(|| "action missing in AssignUuidUpdate".to_string())?,
+            uuid: intermediate_rep
+                .uuid
+                .into_iter()
+                .next()
+                .ok_or_else(|| "uuid missing in AssignUuidUpdate".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<BaseUpdate> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<AssignUuidUpdate> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<BaseUpdate>> for hyper::header::HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<AssignUuidUpdate>>
+    for hyper::header::HeaderValue
+{
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<BaseUpdate>,
+        hdr_value: header::IntoHeaderValue<AssignUuidUpdate>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for BaseUpdate - value: {} is invalid {}",
+                "Invalid header value for AssignUuidUpdate - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -1454,18 +1491,20 @@ impl std::convert::TryFrom<header::IntoHeaderValue<BaseUpdate>> for hyper::heade
 }
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<BaseUpdate> {
+impl std::convert::TryFrom<hyper::header::HeaderValue>
+    for header::IntoHeaderValue<AssignUuidUpdate>
+{
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <BaseUpdate as std::str::FromStr>::from_str(value) {
+                match <AssignUuidUpdate as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into BaseUpdate - {}",
+                        "Unable to convert header value '{}' into AssignUuidUpdate - {}",
                         value, err
                     )),
                 }
@@ -1478,49 +1517,38 @@ impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderVal
     }
 }
 
-/// Server-provided configuration for the catalog.
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct CatalogConfig {
-    /// Properties that should be used to override client configuration; applied after defaults and client configuration.
-    #[serde(rename = "overrides")]
-    pub overrides: serde_json::Value,
-
-    /// Properties that should be used as default configuration; applied before client configuration.
-    #[serde(rename = "defaults")]
-    pub defaults: serde_json::Value,
+pub struct AssignUuidUpdateAllOf {
+    #[serde(rename = "uuid")]
+    pub uuid: String,
 }
 
-impl CatalogConfig {
+impl AssignUuidUpdateAllOf {
     #[allow(clippy::new_without_default)]
-    pub fn new(overrides: serde_json::Value, defaults: serde_json::Value) -> CatalogConfig {
-        CatalogConfig {
-            overrides,
-            defaults,
-        }
+    pub fn new(uuid: String) -> AssignUuidUpdateAllOf {
+        AssignUuidUpdateAllOf { uuid }
     }
 }
 
-/// Converts the CatalogConfig value to the Query Parameters representation (style=form, explode=false)
+/// Converts the AssignUuidUpdateAllOf value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for CatalogConfig {
+impl std::string::ToString for AssignUuidUpdateAllOf {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
-            // Skipping overrides in query parameter serialization
-
-            // Skipping defaults in query parameter serialization
-
+            Some("uuid".to_string()),
+            Some(self.uuid.to_string()),
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a CatalogConfig value
+/// Converts Query Parameters representation (style=form, explode=false) to a AssignUuidUpdateAllOf value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for CatalogConfig {
+impl std::str::FromStr for AssignUuidUpdateAllOf {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -1528,8 +1556,7 @@ impl std::str::FromStr for CatalogConfig {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub overrides: Vec<serde_json::Value>,
-            pub defaults: Vec<serde_json::Value>,
+            pub uuid: Vec<String>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -1543,7 +1570,7 @@ impl std::str::FromStr for CatalogConfig {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing CatalogConfig".to_string(),
+                        "Missing value while parsing AssignUuidUpdateAllOf".to_string(),
                     )
                 }
             };
@@ -1552,18 +1579,12 @@ impl std::str::FromStr for CatalogConfig {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "overrides" => intermediate_rep.overrides.push(
-                        <serde_json::Value as std::str::FromStr>::from_str(val)
-                            .map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "defaults" => intermediate_rep.defaults.push(
-                        <serde_json::Value as std::str::FromStr>::from_str(val)
-                            .map_err(|x| x.to_string())?,
+                    "uuid" => intermediate_rep.uuid.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing CatalogConfig".to_string(),
+                            "Unexpected key while parsing AssignUuidUpdateAllOf".to_string(),
                         )
                     }
                 }
@@ -1574,35 +1595,32 @@ impl std::str::FromStr for CatalogConfig {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(CatalogConfig {
-            overrides: intermediate_rep
-                .overrides
-                .into_iter()
-                .next()
-                .ok_or_else(|| "overrides missing in CatalogConfig".to_string())?,
-            defaults: intermediate_rep
-                .defaults
+        std::result::Result::Ok(AssignUuidUpdateAllOf {
+            uuid: intermediate_rep
+                .uuid
                 .into_iter()
                 .next()
-                .ok_or_else(|| "defaults missing in CatalogConfig".to_string())?,
+                .ok_or_else(|| "uuid missing in AssignUuidUpdateAllOf".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<CatalogConfig> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<AssignUuidUpdateAllOf> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<CatalogConfig>> for hyper::header::HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<AssignUuidUpdateAllOf>>
+    for hyper::header::HeaderValue
+{
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<CatalogConfig>,
+        hdr_value: header::IntoHeaderValue<AssignUuidUpdateAllOf>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for CatalogConfig - value: {} is invalid {}",
+                "Invalid header value for AssignUuidUpdateAllOf - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -1610,18 +1628,20 @@ impl std::convert::TryFrom<header::IntoHeaderValue<CatalogConfig>> for hyper::he
 }
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<CatalogConfig> {
+impl std::convert::TryFrom<hyper::header::HeaderValue>
+    for header::IntoHeaderValue<AssignUuidUpdateAllOf>
+{
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <CatalogConfig as std::str::FromStr>::from_str(value) {
+                match <AssignUuidUpdateAllOf as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into CatalogConfig - {}",
+                        "Unable to convert header value '{}' into AssignUuidUpdateAllOf - {}",
                         value, err
                     )),
                 }
@@ -1636,47 +1656,35 @@ impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderVal
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct CommitTableRequest {
-    #[serde(rename = "requirements")]
-    pub requirements: Vec<models::TableRequirement>,
-
-    #[serde(rename = "updates")]
-    pub updates: Vec<models::TableUpdate>,
+pub struct BaseUpdate {
+    // Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "action")]
+    pub action: String,
 }
 
-impl CommitTableRequest {
+impl BaseUpdate {
     #[allow(clippy::new_without_default)]
-    pub fn new(
-        requirements: Vec<models::TableRequirement>,
-        updates: Vec<models::TableUpdate>,
-    ) -> CommitTableRequest {
-        CommitTableRequest {
-            requirements,
-            updates,
-        }
+    pub fn new(action: String) -> BaseUpdate {
+        BaseUpdate { action }
     }
 }
 
-/// Converts the CommitTableRequest value to the Query Parameters representation (style=form, explode=false)
+/// Converts the BaseUpdate value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for CommitTableRequest {
+impl std::string::ToString for BaseUpdate {
     fn to_string(&self) -> String {
-        let params: Vec<Option<String>> = vec![
-            // Skipping requirements in query parameter serialization
-
-            // Skipping updates in query parameter serialization
-
-        ];
+        let params: Vec<Option<String>> =
+            vec![Some("action".to_string()), Some(self.action.to_string())];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a CommitTableRequest value
+/// Converts Query Parameters representation (style=form, explode=false) to a BaseUpdate value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for CommitTableRequest {
+impl std::str::FromStr for BaseUpdate {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -1684,8 +1692,7 @@ impl std::str::FromStr for CommitTableRequest {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub requirements: Vec<Vec<models::TableRequirement>>,
-            pub updates: Vec<Vec<models::TableUpdate>>,
+            pub action: Vec<String>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -1699,7 +1706,7 @@ impl std::str::FromStr for CommitTableRequest {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing CommitTableRequest".to_string(),
+                        "Missing value while parsing BaseUpdate".to_string(),
                     )
                 }
             };
@@ -1707,17 +1714,13 @@ impl std::str::FromStr for CommitTableRequest {
             if let Some(key) = key_result {
                 #[allow(clippy::match_single_binding)]
                 match key {
-                    "requirements" => return std::result::Result::Err(
-                        "Parsing a container in this style is not supported in CommitTableRequest"
-                            .to_string(),
-                    ),
-                    "updates" => return std::result::Result::Err(
-                        "Parsing a container in this style is not supported in CommitTableRequest"
-                            .to_string(),
+                    #[allow(clippy::redundant_clone)]
+                    "action" => intermediate_rep.action.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing CommitTableRequest".to_string(),
+                            "Unexpected key while parsing BaseUpdate".to_string(),
                         )
                     }
                 }
@@ -1728,37 +1731,30 @@ impl std::str::FromStr for CommitTableRequest {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(CommitTableRequest {
-            requirements: intermediate_rep
-                .requirements
-                .into_iter()
-                .next()
-                .ok_or_else(|| "requirements missing in CommitTableRequest".to_string())?,
-            updates: intermediate_rep
-                .updates
+        std::result::Result::Ok(BaseUpdate {
+            action: intermediate_rep
+                .action
                 .into_iter()
                 .next()
-                .ok_or_else(|| "updates missing in CommitTableRequest".to_string())?,
+                .ok_or_else(|| "action missing in BaseUpdate".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<CommitTableRequest> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<BaseUpdate> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<CommitTableRequest>>
-    for hyper::header::HeaderValue
-{
+impl std::convert::TryFrom<header::IntoHeaderValue<BaseUpdate>> for hyper::header::HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<CommitTableRequest>,
+        hdr_value: header::IntoHeaderValue<BaseUpdate>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for CommitTableRequest - value: {} is invalid {}",
+                "Invalid header value for BaseUpdate - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -1766,20 +1762,18 @@ impl std::convert::TryFrom<header::IntoHeaderValue<CommitTableRequest>>
 }
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<hyper::header::HeaderValue>
-    for header::IntoHeaderValue<CommitTableRequest>
-{
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<BaseUpdate> {
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <CommitTableRequest as std::str::FromStr>::from_str(value) {
+                match <BaseUpdate as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into CommitTableRequest - {}",
+                        "Unable to convert header value '{}' into BaseUpdate - {}",
                         value, err
                     )),
                 }
@@ -1792,43 +1786,55 @@ impl std::convert::TryFrom<hyper::header::HeaderValue>
     }
 }
 
+/// A catalog, whose name is the prefix of the paths of its namespaces and tables
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct CounterResult {
-    #[serde(rename = "unit")]
-    pub unit: String,
+pub struct Catalog {
+    #[serde(rename = "name")]
+    pub name: String,
 
-    #[serde(rename = "value")]
-    pub value: i64,
+    /// Location below which the tables of the catalog are created if no location is requested
+    #[serde(rename = "storage-root")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub storage_root: Option<String>,
+
+    #[serde(rename = "properties")]
+    pub properties: std::collections::HashMap<String, String>,
 }
 
-impl CounterResult {
+impl Catalog {
     #[allow(clippy::new_without_default)]
-    pub fn new(unit: String, value: i64) -> CounterResult {
-        CounterResult { unit, value }
+    pub fn new(name: String, properties: std::collections::HashMap<String, String>) -> Catalog {
+        Catalog {
+            name,
+            storage_root: None,
+            properties,
+        }
     }
 }
 
-/// Converts the CounterResult value to the Query Parameters representation (style=form, explode=false)
+/// Converts the Catalog value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for CounterResult {
+impl std::string::ToString for Catalog {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
-            Some("unit".to_string()),
-            Some(self.unit.to_string()),
-            Some("value".to_string()),
-            Some(self.value.to_string()),
+            Some("name".to_string()),
+            Some(self.name.to_string()),
+            self.storage_root.as_ref().map(|storage_root| {
+                vec!["storage-root".to_string(), storage_root.to_string()].join(",")
+            }),
+            // Skipping properties in query parameter serialization
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a CounterResult value
+/// Converts Query Parameters representation (style=form, explode=false) to a Catalog value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for CounterResult {
+impl std::str::FromStr for Catalog {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -1836,8 +1842,9 @@ impl std::str::FromStr for CounterResult {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub unit: Vec<String>,
-            pub value: Vec<i64>,
+            pub name: Vec<String>,
+            pub storage_root: Vec<String>,
+            pub properties: Vec<std::collections::HashMap<String, String>>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -1851,7 +1858,7 @@ impl std::str::FromStr for CounterResult {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing CounterResult".to_string(),
+                        "Missing value while parsing Catalog".to_string(),
                     )
                 }
             };
@@ -1860,16 +1867,22 @@ impl std::str::FromStr for CounterResult {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "unit" => intermediate_rep.unit.push(
+                    "name" => intermediate_rep.name.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "value" => intermediate_rep.value.push(
-                        <i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "storage-root" => intermediate_rep.storage_root.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
+                    "properties" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in Catalog"
+                                .to_string(),
+                        )
+                    }
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing CounterResult".to_string(),
+                            "Unexpected key while parsing Catalog".to_string(),
                         )
                     }
                 }
@@ -1880,35 +1893,36 @@ impl std::str::FromStr for CounterResult {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(CounterResult {
-            unit: intermediate_rep
-                .unit
+        std::result::Result::Ok(Catalog {
+            name: intermediate_rep
+                .name
                 .into_iter()
                 .next()
-                .ok_or_else(|| "unit missing in CounterResult".to_string())?,
-            value: intermediate_rep
-                .value
+                .ok_or_else(|| "name missing in Catalog".to_string())?,
+            storage_root: intermediate_rep.storage_root.into_iter().next(),
+            properties: intermediate_rep
+                .properties
                 .into_iter()
                 .next()
-                .ok_or_else(|| "value missing in CounterResult".to_string())?,
+                .ok_or_else(|| "properties missing in Catalog".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<CounterResult> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<Catalog> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<CounterResult>> for hyper::header::HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<Catalog>> for hyper::header::HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<CounterResult>,
+        hdr_value: header::IntoHeaderValue<Catalog>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for CounterResult - value: {} is invalid {}",
+                "Invalid header value for Catalog - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -1916,18 +1930,18 @@ impl std::convert::TryFrom<header::IntoHeaderValue<CounterResult>> for hyper::he
 }
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<CounterResult> {
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<Catalog> {
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <CounterResult as std::str::FromStr>::from_str(value) {
+                match <Catalog as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into CounterResult - {}",
+                        "Unable to convert header value '{}' into Catalog - {}",
                         value, err
                     )),
                 }
@@ -1940,54 +1954,49 @@ impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderVal
     }
 }
 
+/// Server-provided configuration for the catalog.
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct CreateNamespace200Response {
-    /// Reference to one or more levels of a namespace
-    #[serde(rename = "namespace")]
-    pub namespace: Vec<String>,
+pub struct CatalogConfig {
+    /// Properties that should be used to override client configuration; applied after defaults and client configuration.
+    #[serde(rename = "overrides")]
+    pub overrides: serde_json::Value,
 
-    /// Properties stored on the namespace, if supported by the server.
-    #[serde(rename = "properties")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub properties: Option<std::collections::HashMap<String, String>>,
+    /// Properties that should be used as default configuration; applied before client configuration.
+    #[serde(rename = "defaults")]
+    pub defaults: serde_json::Value,
 }
 
-impl CreateNamespace200Response {
+impl CatalogConfig {
     #[allow(clippy::new_without_default)]
-    pub fn new(namespace: Vec<String>) -> CreateNamespace200Response {
-        CreateNamespace200Response {
-            namespace,
-            properties: None,
+    pub fn new(overrides: serde_json::Value, defaults: serde_json::Value) -> CatalogConfig {
+        CatalogConfig {
+            overrides,
+            defaults,
         }
     }
 }
 
-/// Converts the CreateNamespace200Response value to the Query Parameters representation (style=form, explode=false)
+/// Converts the CatalogConfig value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for CreateNamespace200Response {
+impl std::string::ToString for CatalogConfig {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
-            Some("namespace".to_string()),
-            Some(
-                self.namespace
-                    .iter()
-                    .map(|x| x.to_string())
-                    .collect::<Vec<_>>()
-                    .join(","),
-            ),
-            // Skipping properties in query parameter serialization
+            // Skipping overrides in query parameter serialization
+
+            // Skipping defaults in query parameter serialization
+
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a CreateNamespace200Response value
+/// Converts Query Parameters representation (style=form, explode=false) to a CatalogConfig value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for CreateNamespace200Response {
+impl std::str::FromStr for CatalogConfig {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -1995,8 +2004,8 @@ impl std::str::FromStr for CreateNamespace200Response {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub namespace: Vec<Vec<String>>,
-            pub properties: Vec<std::collections::HashMap<String, String>>,
+            pub overrides: Vec<serde_json::Value>,
+            pub defaults: Vec<serde_json::Value>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -2010,7 +2019,7 @@ impl std::str::FromStr for CreateNamespace200Response {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing CreateNamespace200Response".to_string(),
+                        "Missing value while parsing CatalogConfig".to_string(),
                     )
                 }
             };
@@ -2018,9 +2027,21 @@ impl std::str::FromStr for CreateNamespace200Response {
             if let Some(key) = key_result {
                 #[allow(clippy::match_single_binding)]
                 match key {
-                    "namespace" => return std::result::Result::Err("Parsing a container in this style is not supported in CreateNamespace200Response".to_string()),
-                    "properties" => return std::result::Result::Err("Parsing a container in this style is not supported in CreateNamespace200Response".to_string()),
-                    _ => return std::result::Result::Err("Unexpected key while parsing CreateNamespace200Response".to_string())
+                    #[allow(clippy::redundant_clone)]
+                    "overrides" => intermediate_rep.overrides.push(
+                        <serde_json::Value as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "defaults" => intermediate_rep.defaults.push(
+                        <serde_json::Value as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing CatalogConfig".to_string(),
+                        )
+                    }
                 }
             }
 
@@ -2029,33 +2050,35 @@ impl std::str::FromStr for CreateNamespace200Response {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(CreateNamespace200Response {
-            namespace: intermediate_rep
-                .namespace
+        std::result::Result::Ok(CatalogConfig {
+            overrides: intermediate_rep
+                .overrides
                 .into_iter()
                 .next()
-                .ok_or_else(|| "namespace missing in CreateNamespace200Response".to_string())?,
-            properties: intermediate_rep.properties.into_iter().next(),
+                .ok_or_else(|| "overrides missing in CatalogConfig".to_string())?,
+            defaults: intermediate_rep
+                .defaults
+                .into_iter()
+                .next()
+                .ok_or_else(|| "defaults missing in CatalogConfig".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<CreateNamespace200Response> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<CatalogConfig> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<CreateNamespace200Response>>
-    for hyper::header::HeaderValue
-{
+impl std::convert::TryFrom<header::IntoHeaderValue<CatalogConfig>> for hyper::header::HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<CreateNamespace200Response>,
+        hdr_value: header::IntoHeaderValue<CatalogConfig>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for CreateNamespace200Response - value: {} is invalid {}",
+                "Invalid header value for CatalogConfig - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -2063,20 +2086,18 @@ impl std::convert::TryFrom<header::IntoHeaderValue<CreateNamespace200Response>>
 }
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<hyper::header::HeaderValue>
-    for header::IntoHeaderValue<CreateNamespace200Response>
-{
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<CatalogConfig> {
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <CreateNamespace200Response as std::str::FromStr>::from_str(value) {
+                match <CatalogConfig as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into CreateNamespace200Response - {}",
+                        "Unable to convert header value '{}' into CatalogConfig - {}",
                         value, err
                     )),
                 }
@@ -2091,52 +2112,47 @@ impl std::convert::TryFrom<hyper::header::HeaderValue>
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct CreateNamespaceRequest {
-    /// Reference to one or more levels of a namespace
-    #[serde(rename = "namespace")]
-    pub namespace: Vec<String>,
+pub struct CommitTableRequest {
+    #[serde(rename = "requirements")]
+    pub requirements: Vec<models::TableRequirement>,
 
-    /// Configured string to string map of properties for the namespace
-    #[serde(rename = "properties")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub properties: Option<serde_json::Value>,
+    #[serde(rename = "updates")]
+    pub updates: Vec<models::TableUpdate>,
 }
 
-impl CreateNamespaceRequest {
+impl CommitTableRequest {
     #[allow(clippy::new_without_default)]
-    pub fn new(namespace: Vec<String>) -> CreateNamespaceRequest {
-        CreateNamespaceRequest {
-            namespace,
-            properties: None,
+    pub fn new(
+        requirements: Vec<models::TableRequirement>,
+        updates: Vec<models::TableUpdate>,
+    ) -> CommitTableRequest {
+        CommitTableRequest {
+            requirements,
+            updates,
         }
     }
 }
 
-/// Converts the CreateNamespaceRequest value to the Query Parameters representation (style=form, explode=false)
+/// Converts the CommitTableRequest value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for CreateNamespaceRequest {
+impl std::string::ToString for CommitTableRequest {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
-            Some("namespace".to_string()),
-            Some(
-                self.namespace
-                    .iter()
-                    .map(|x| x.to_string())
-                    .collect::<Vec<_>>()
-                    .join(","),
-            ),
-            // Skipping properties in query parameter serialization
+            // Skipping requirements in query parameter serialization
+
+            // Skipping updates in query parameter serialization
+
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a CreateNamespaceRequest value
+/// Converts Query Parameters representation (style=form, explode=false) to a CommitTableRequest value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for CreateNamespaceRequest {
+impl std::str::FromStr for CommitTableRequest {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -2144,8 +2160,8 @@ impl std::str::FromStr for CreateNamespaceRequest {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub namespace: Vec<Vec<String>>,
-            pub properties: Vec<serde_json::Value>,
+            pub requirements: Vec<Vec<models::TableRequirement>>,
+            pub updates: Vec<Vec<models::TableUpdate>>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -2159,7 +2175,7 @@ impl std::str::FromStr for CreateNamespaceRequest {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing CreateNamespaceRequest".to_string(),
+                        "Missing value while parsing CommitTableRequest".to_string(),
                     )
                 }
             };
@@ -2167,10 +2183,19 @@ impl std::str::FromStr for CreateNamespaceRequest {
             if let Some(key) = key_result {
                 #[allow(clippy::match_single_binding)]
                 match key {
-                    "namespace" => return std::result::Result::Err("Parsing a container in this style is not supported in CreateNamespaceRequest".to_string()),
-                    #[allow(clippy::redundant_clone)]
-                    "properties" => intermediate_rep.properties.push(<serde_json::Value as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
-                    _ => return std::result::Result::Err("Unexpected key while parsing CreateNamespaceRequest".to_string())
+                    "requirements" => return std::result::Result::Err(
+                        "Parsing a container in this style is not supported in CommitTableRequest"
+                            .to_string(),
+                    ),
+                    "updates" => return std::result::Result::Err(
+                        "Parsing a container in this style is not supported in CommitTableRequest"
+                            .to_string(),
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing CommitTableRequest".to_string(),
+                        )
+                    }
                 }
             }
 
@@ -2179,33 +2204,37 @@ impl std::str::FromStr for CreateNamespaceRequest {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(CreateNamespaceRequest {
-            namespace: intermediate_rep
-                .namespace
+        std::result::Result::Ok(CommitTableRequest {
+            requirements: intermediate_rep
+                .requirements
                 .into_iter()
                 .next()
-                .ok_or_else(|| "namespace missing in CreateNamespaceRequest".to_string())?,
-            properties: intermediate_rep.properties.into_iter().next(),
+                .ok_or_else(|| "requirements missing in CommitTableRequest".to_string())?,
+            updates: intermediate_rep
+                .updates
+                .into_iter()
+                .next()
+                .ok_or_else(|| "updates missing in CommitTableRequest".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<CreateNamespaceRequest> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<CommitTableRequest> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<CreateNamespaceRequest>>
+impl std::convert::TryFrom<header::IntoHeaderValue<CommitTableRequest>>
     for hyper::header::HeaderValue
 {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<CreateNamespaceRequest>,
+        hdr_value: header::IntoHeaderValue<CommitTableRequest>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for CreateNamespaceRequest - value: {} is invalid {}",
+                "Invalid header value for CommitTableRequest - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -2214,19 +2243,19 @@ impl std::convert::TryFrom<header::IntoHeaderValue<CreateNamespaceRequest>>
 
 #[cfg(any(feature = "client", feature = "server"))]
 impl std::convert::TryFrom<hyper::header::HeaderValue>
-    for header::IntoHeaderValue<CreateNamespaceRequest>
+    for header::IntoHeaderValue<CommitTableRequest>
 {
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <CreateNamespaceRequest as std::str::FromStr>::from_str(value) {
+                match <CommitTableRequest as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into CreateNamespaceRequest - {}",
+                        "Unable to convert header value '{}' into CommitTableRequest - {}",
                         value, err
                     )),
                 }
@@ -2241,79 +2270,41 @@ impl std::convert::TryFrom<hyper::header::HeaderValue>
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct CreateTableRequest {
-    #[serde(rename = "name")]
-    pub name: String,
-
-    #[serde(rename = "location")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub location: Option<String>,
-
-    #[serde(rename = "schema")]
-    pub schema: models::Schema,
-
-    #[serde(rename = "partition-spec")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub partition_spec: Option<models::PartitionSpec>,
-
-    #[serde(rename = "write-order")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub write_order: Option<models::SortOrder>,
-
-    #[serde(rename = "stage-create")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub stage_create: Option<bool>,
+pub struct CounterResult {
+    #[serde(rename = "unit")]
+    pub unit: String,
 
-    #[serde(rename = "properties")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub properties: Option<std::collections::HashMap<String, String>>,
+    #[serde(rename = "value")]
+    pub value: i64,
 }
 
-impl CreateTableRequest {
+impl CounterResult {
     #[allow(clippy::new_without_default)]
-    pub fn new(name: String, schema: models::Schema) -> CreateTableRequest {
-        CreateTableRequest {
-            name,
-            location: None,
-            schema,
-            partition_spec: None,
-            write_order: None,
-            stage_create: None,
-            properties: None,
-        }
+    pub fn new(unit: String, value: i64) -> CounterResult {
+        CounterResult { unit, value }
     }
 }
 
-/// Converts the CreateTableRequest value to the Query Parameters representation (style=form, explode=false)
+/// Converts the CounterResult value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for CreateTableRequest {
+impl std::string::ToString for CounterResult {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
-            Some("name".to_string()),
-            Some(self.name.to_string()),
-            self.location
-                .as_ref()
-                .map(|location| vec!["location".to_string(), location.to_string()].join(",")),
-            // Skipping schema in query parameter serialization
-
-            // Skipping partition-spec in query parameter serialization
-
-            // Skipping write-order in query parameter serialization
-            self.stage_create.as_ref().map(|stage_create| {
-                vec!["stage-create".to_string(), stage_create.to_string()].join(",")
-            }),
-            // Skipping properties in query parameter serialization
+            Some("unit".to_string()),
+            Some(self.unit.to_string()),
+            Some("value".to_string()),
+            Some(self.value.to_string()),
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a CreateTableRequest value
+/// Converts Query Parameters representation (style=form, explode=false) to a CounterResult value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for CreateTableRequest {
+impl std::str::FromStr for CounterResult {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -2321,13 +2312,8 @@ impl std::str::FromStr for CreateTableRequest {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub name: Vec<String>,
-            pub location: Vec<String>,
-            pub schema: Vec<models::Schema>,
-            pub partition_spec: Vec<models::PartitionSpec>,
-            pub write_order: Vec<models::SortOrder>,
-            pub stage_create: Vec<bool>,
-            pub properties: Vec<std::collections::HashMap<String, String>>,
+            pub unit: Vec<String>,
+            pub value: Vec<i64>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -2341,7 +2327,7 @@ impl std::str::FromStr for CreateTableRequest {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing CreateTableRequest".to_string(),
+                        "Missing value while parsing CounterResult".to_string(),
                     )
                 }
             };
@@ -2350,39 +2336,16 @@ impl std::str::FromStr for CreateTableRequest {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "name" => intermediate_rep.name.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "location" => intermediate_rep.location.push(
+                    "unit" => intermediate_rep.unit.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "schema" => intermediate_rep.schema.push(
-                        <models::Schema as std::str::FromStr>::from_str(val)
-                            .map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "partition-spec" => intermediate_rep.partition_spec.push(
-                        <models::PartitionSpec as std::str::FromStr>::from_str(val)
-                            .map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "write-order" => intermediate_rep.write_order.push(
-                        <models::SortOrder as std::str::FromStr>::from_str(val)
-                            .map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "stage-create" => intermediate_rep.stage_create.push(
-                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    "properties" => return std::result::Result::Err(
-                        "Parsing a container in this style is not supported in CreateTableRequest"
-                            .to_string(),
+                    "value" => intermediate_rep.value.push(
+                        <i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing CreateTableRequest".to_string(),
+                            "Unexpected key while parsing CounterResult".to_string(),
                         )
                     }
                 }
@@ -2393,42 +2356,35 @@ impl std::str::FromStr for CreateTableRequest {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(CreateTableRequest {
-            name: intermediate_rep
-                .name
+        std::result::Result::Ok(CounterResult {
+            unit: intermediate_rep
+                .unit
                 .into_iter()
                 .next()
-                .ok_or_else(|| "name missing in CreateTableRequest".to_string())?,
-            location: intermediate_rep.location.into_iter().next(),
-            schema: intermediate_rep
-                .schema
+                .ok_or_else(|| "unit missing in CounterResult".to_string())?,
+            value: intermediate_rep
+                .value
                 .into_iter()
                 .next()
-                .ok_or_else(|| "schema missing in CreateTableRequest".to_string())?,
-            partition_spec: intermediate_rep.partition_spec.into_iter().next(),
-            write_order: intermediate_rep.write_order.into_iter().next(),
-            stage_create: intermediate_rep.stage_create.into_iter().next(),
-            properties: intermediate_rep.properties.into_iter().next(),
+                .ok_or_else(|| "value missing in CounterResult".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<CreateTableRequest> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<CounterResult> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<CreateTableRequest>>
-    for hyper::header::HeaderValue
-{
+impl std::convert::TryFrom<header::IntoHeaderValue<CounterResult>> for hyper::header::HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<CreateTableRequest>,
+        hdr_value: header::IntoHeaderValue<CounterResult>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for CreateTableRequest - value: {} is invalid {}",
+                "Invalid header value for CounterResult - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -2436,20 +2392,18 @@ impl std::convert::TryFrom<header::IntoHeaderValue<CreateTableRequest>>
 }
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<hyper::header::HeaderValue>
-    for header::IntoHeaderValue<CreateTableRequest>
-{
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<CounterResult> {
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <CreateTableRequest as std::str::FromStr>::from_str(value) {
+                match <CounterResult as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into CreateTableRequest - {}",
+                        "Unable to convert header value '{}' into CounterResult - {}",
                         value, err
                     )),
                 }
@@ -2462,72 +2416,55 @@ impl std::convert::TryFrom<hyper::header::HeaderValue>
     }
 }
 
-/// JSON error payload returned in a response with further details on the error
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct ErrorModel {
-    /// Human-readable error message
-    #[serde(rename = "message")]
-    pub message: String,
-
-    /// Internal type definition of the error
-    #[serde(rename = "type")]
-    pub r#type: String,
+pub struct CreateCatalogRequest {
+    #[serde(rename = "name")]
+    pub name: String,
 
-    /// HTTP response code
-    #[serde(rename = "code")]
-    pub code: u16,
+    /// Location below which the tables of the catalog are created if no location is requested
+    #[serde(rename = "storage-root")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub storage_root: Option<String>,
 
-    #[serde(rename = "stack")]
+    #[serde(rename = "properties")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stack: Option<Vec<String>>,
+    pub properties: Option<std::collections::HashMap<String, String>>,
 }
 
-impl ErrorModel {
+impl CreateCatalogRequest {
     #[allow(clippy::new_without_default)]
-    pub fn new(message: String, r#type: String, code: u16) -> ErrorModel {
-        ErrorModel {
-            message,
-            r#type,
-            code,
-            stack: None,
+    pub fn new(name: String) -> CreateCatalogRequest {
+        CreateCatalogRequest {
+            name,
+            storage_root: None,
+            properties: None,
         }
     }
 }
 
-/// Converts the ErrorModel value to the Query Parameters representation (style=form, explode=false)
+/// Converts the CreateCatalogRequest value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for ErrorModel {
+impl std::string::ToString for CreateCatalogRequest {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
-            Some("message".to_string()),
-            Some(self.message.to_string()),
-            Some("type".to_string()),
-            Some(self.r#type.to_string()),
-            Some("code".to_string()),
-            Some(self.code.to_string()),
-            self.stack.as_ref().map(|stack| {
-                vec![
-                    "stack".to_string(),
-                    stack
-                        .iter()
-                        .map(|x| x.to_string())
-                        .collect::<Vec<_>>()
-                        .join(","),
-                ]
-                .join(",")
+            Some("name".to_string()),
+            Some(self.name.to_string()),
+            self.storage_root.as_ref().map(|storage_root| {
+                vec!["storage-root".to_string(), storage_root.to_string()].join(",")
             }),
+            // Skipping properties in query parameter serialization
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a ErrorModel value
+/// Converts Query Parameters representation (style=form, explode=false) to a CreateCatalogRequest value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for ErrorModel {
+impl std::str::FromStr for CreateCatalogRequest {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -2535,10 +2472,9 @@ impl std::str::FromStr for ErrorModel {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub message: Vec<String>,
-            pub r#type: Vec<String>,
-            pub code: Vec<u16>,
-            pub stack: Vec<Vec<String>>,
+            pub name: Vec<String>,
+            pub storage_root: Vec<String>,
+            pub properties: Vec<std::collections::HashMap<String, String>>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -2552,7 +2488,7 @@ impl std::str::FromStr for ErrorModel {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing ErrorModel".to_string(),
+                        "Missing value while parsing CreateCatalogRequest".to_string(),
                     )
                 }
             };
@@ -2561,28 +2497,11 @@ impl std::str::FromStr for ErrorModel {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "message" => intermediate_rep.message.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "type" => intermediate_rep.r#type.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
+                    "name" => intermediate_rep.name.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
                     #[allow(clippy::redundant_clone)]
-                    "code" => intermediate_rep.code.push(
-                        <u16 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    "stack" => {
-                        return std::result::Result::Err(
-                            "Parsing a container in this style is not supported in ErrorModel"
-                                .to_string(),
-                        )
-                    }
-                    _ => {
-                        return std::result::Result::Err(
-                            "Unexpected key while parsing ErrorModel".to_string(),
-                        )
-                    }
+                    "storage-root" => intermediate_rep.storage_root.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "properties" => return std::result::Result::Err("Parsing a container in this style is not supported in CreateCatalogRequest".to_string()),
+                    _ => return std::result::Result::Err("Unexpected key while parsing CreateCatalogRequest".to_string())
                 }
             }
 
@@ -2591,41 +2510,34 @@ impl std::str::FromStr for ErrorModel {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(ErrorModel {
-            message: intermediate_rep
-                .message
-                .into_iter()
-                .next()
-                .ok_or_else(|| "message missing in ErrorModel".to_string())?,
-            r#type: intermediate_rep
-                .r#type
-                .into_iter()
-                .next()
-                .ok_or_else(|| "type missing in ErrorModel".to_string())?,
-            code: intermediate_rep
-                .code
+        std::result::Result::Ok(CreateCatalogRequest {
+            name: intermediate_rep
+                .name
                 .into_iter()
                 .next()
-                .ok_or_else(|| "code missing in ErrorModel".to_string())?,
-            stack: intermediate_rep.stack.into_iter().next(),
+                .ok_or_else(|| "name missing in CreateCatalogRequest".to_string())?,
+            storage_root: intermediate_rep.storage_root.into_iter().next(),
+            properties: intermediate_rep.properties.into_iter().next(),
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<ErrorModel> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<CreateCatalogRequest> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<ErrorModel>> for hyper::header::HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<CreateCatalogRequest>>
+    for hyper::header::HeaderValue
+{
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<ErrorModel>,
+        hdr_value: header::IntoHeaderValue<CreateCatalogRequest>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for ErrorModel - value: {} is invalid {}",
+                "Invalid header value for CreateCatalogRequest - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -2633,18 +2545,20 @@ impl std::convert::TryFrom<header::IntoHeaderValue<ErrorModel>> for hyper::heade
 }
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<ErrorModel> {
+impl std::convert::TryFrom<hyper::header::HeaderValue>
+    for header::IntoHeaderValue<CreateCatalogRequest>
+{
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <ErrorModel as std::str::FromStr>::from_str(value) {
+                match <CreateCatalogRequest as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into ErrorModel - {}",
+                        "Unable to convert header value '{}' into CreateCatalogRequest - {}",
                         value, err
                     )),
                 }
@@ -2659,81 +2573,52 @@ impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderVal
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct Expression {
-    #[serde(rename = "type")]
-    pub r#type: String,
-
-    #[serde(rename = "left")]
-    pub left: Box<Expression>,
-
-    #[serde(rename = "right")]
-    pub right: Box<Expression>,
-
-    #[serde(rename = "child")]
-    pub child: Box<Expression>,
-
-    #[serde(rename = "term")]
-    pub term: models::Term,
-
-    #[serde(rename = "values")]
-    pub values: Vec<serde_json::Value>,
+pub struct CreateNamespace200Response {
+    /// Reference to one or more levels of a namespace
+    #[serde(rename = "namespace")]
+    pub namespace: Vec<String>,
 
-    #[serde(rename = "value")]
-    pub value: serde_json::Value,
+    /// Properties stored on the namespace, if supported by the server.
+    #[serde(rename = "properties")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub properties: Option<std::collections::HashMap<String, String>>,
 }
 
-impl Expression {
+impl CreateNamespace200Response {
     #[allow(clippy::new_without_default)]
-    pub fn new(
-        r#type: String,
-        left: models::Expression,
-        right: models::Expression,
-        child: models::Expression,
-        term: models::Term,
-        values: Vec<serde_json::Value>,
-        value: serde_json::Value,
-    ) -> Expression {
-        Expression {
-            r#type,
-            left: Box::new(left),
-            right: Box::new(right),
-            child: Box::new(child),
-            term,
-            values,
-            value,
+    pub fn new(namespace: Vec<String>) -> CreateNamespace200Response {
+        CreateNamespace200Response {
+            namespace,
+            properties: None,
         }
     }
 }
 
-/// Converts the Expression value to the Query Parameters representation (style=form, explode=false)
+/// Converts the CreateNamespace200Response value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for Expression {
+impl std::string::ToString for CreateNamespace200Response {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
-            Some("type".to_string()),
-            Some(self.r#type.to_string()),
-            // Skipping left in query parameter serialization
-
-            // Skipping right in query parameter serialization
-
-            // Skipping child in query parameter serialization
-
-            // Skipping term in query parameter serialization
-
-            // Skipping values in query parameter serialization
-
-            // Skipping value in query parameter serialization
+            Some("namespace".to_string()),
+            Some(
+                self.namespace
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect::<Vec<_>>()
+                    .join(","),
+            ),
+            // Skipping properties in query parameter serialization
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a Expression value
+/// Converts Query Parameters representation (style=form, explode=false) to a CreateNamespace200Response value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for Expression {
+impl std::str::FromStr for CreateNamespace200Response {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -2741,13 +2626,8 @@ impl std::str::FromStr for Expression {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub r#type: Vec<String>,
-            pub left: Vec<models::Expression>,
-            pub right: Vec<models::Expression>,
-            pub child: Vec<models::Expression>,
-            pub term: Vec<models::Term>,
-            pub values: Vec<Vec<serde_json::Value>>,
-            pub value: Vec<serde_json::Value>,
+            pub namespace: Vec<Vec<String>>,
+            pub properties: Vec<std::collections::HashMap<String, String>>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -2761,7 +2641,7 @@ impl std::str::FromStr for Expression {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing Expression".to_string(),
+                        "Missing value while parsing CreateNamespace200Response".to_string(),
                     )
                 }
             };
@@ -2769,46 +2649,9 @@ impl std::str::FromStr for Expression {
             if let Some(key) = key_result {
                 #[allow(clippy::match_single_binding)]
                 match key {
-                    #[allow(clippy::redundant_clone)]
-                    "type" => intermediate_rep.r#type.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "left" => intermediate_rep.left.push(
-                        <models::Expression as std::str::FromStr>::from_str(val)
-                            .map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "right" => intermediate_rep.right.push(
-                        <models::Expression as std::str::FromStr>::from_str(val)
-                            .map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "child" => intermediate_rep.child.push(
-                        <models::Expression as std::str::FromStr>::from_str(val)
-                            .map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "term" => intermediate_rep.term.push(
-                        <models::Term as std::str::FromStr>::from_str(val)
-                            .map_err(|x| x.to_string())?,
-                    ),
-                    "values" => {
-                        return std::result::Result::Err(
-                            "Parsing a container in this style is not supported in Expression"
-                                .to_string(),
-                        )
-                    }
-                    #[allow(clippy::redundant_clone)]
-                    "value" => intermediate_rep.value.push(
-                        <serde_json::Value as std::str::FromStr>::from_str(val)
-                            .map_err(|x| x.to_string())?,
-                    ),
-                    _ => {
-                        return std::result::Result::Err(
-                            "Unexpected key while parsing Expression".to_string(),
-                        )
-                    }
+                    "namespace" => return std::result::Result::Err("Parsing a container in this style is not supported in CreateNamespace200Response".to_string()),
+                    "properties" => return std::result::Result::Err("Parsing a container in this style is not supported in CreateNamespace200Response".to_string()),
+                    _ => return std::result::Result::Err("Unexpected key while parsing CreateNamespace200Response".to_string())
                 }
             }
 
@@ -2817,66 +2660,33 @@ impl std::str::FromStr for Expression {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(Expression {
-            r#type: intermediate_rep
-                .r#type
-                .into_iter()
-                .next()
-                .ok_or_else(|| "type missing in Expression".to_string())?,
-            left: Box::new(
-                intermediate_rep
-                    .left
-                    .into_iter()
-                    .next()
-                    .ok_or_else(|| "left missing in Expression".to_string())?,
-            ),
-            right: Box::new(
-                intermediate_rep
-                    .right
-                    .into_iter()
-                    .next()
-                    .ok_or_else(|| "right missing in Expression".to_string())?,
-            ),
-            child: Box::new(
-                intermediate_rep
-                    .child
-                    .into_iter()
-                    .next()
-                    .ok_or_else(|| "child missing in Expression".to_string())?,
-            ),
-            term: intermediate_rep
-                .term
-                .into_iter()
-                .next()
-                .ok_or_else(|| "term missing in Expression".to_string())?,
-            values: intermediate_rep
-                .values
-                .into_iter()
-                .next()
-                .ok_or_else(|| "values missing in Expression".to_string())?,
-            value: intermediate_rep
-                .value
+        std::result::Result::Ok(CreateNamespace200Response {
+            namespace: intermediate_rep
+                .namespace
                 .into_iter()
                 .next()
-                .ok_or_else(|| "value missing in Expression".to_string())?,
+                .ok_or_else(|| "namespace missing in CreateNamespace200Response".to_string())?,
+            properties: intermediate_rep.properties.into_iter().next(),
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<Expression> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<CreateNamespace200Response> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<Expression>> for hyper::header::HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<CreateNamespace200Response>>
+    for hyper::header::HeaderValue
+{
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<Expression>,
+        hdr_value: header::IntoHeaderValue<CreateNamespace200Response>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for Expression - value: {} is invalid {}",
+                "Invalid header value for CreateNamespace200Response - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -2884,18 +2694,20 @@ impl std::convert::TryFrom<header::IntoHeaderValue<Expression>> for hyper::heade
 }
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<Expression> {
+impl std::convert::TryFrom<hyper::header::HeaderValue>
+    for header::IntoHeaderValue<CreateNamespace200Response>
+{
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <Expression as std::str::FromStr>::from_str(value) {
+                match <CreateNamespace200Response as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into Expression - {}",
+                        "Unable to convert header value '{}' into CreateNamespace200Response - {}",
                         value, err
                     )),
                 }
@@ -2908,124 +2720,231 @@ impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderVal
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct ExpressionType(String);
-
-impl std::convert::From<String> for ExpressionType {
-    fn from(x: String) -> Self {
-        ExpressionType(x)
-    }
-}
+pub struct CreateNamespaceRequest {
+    /// Reference to one or more levels of a namespace
+    #[serde(rename = "namespace")]
+    pub namespace: Vec<String>,
 
-impl std::string::ToString for ExpressionType {
-    fn to_string(&self) -> String {
-        self.0.to_string()
-    }
+    /// Configured string to string map of properties for the namespace
+    #[serde(rename = "properties")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub properties: Option<serde_json::Value>,
 }
 
-impl std::str::FromStr for ExpressionType {
-    type Err = std::string::ParseError;
-    fn from_str(x: &str) -> std::result::Result<Self, Self::Err> {
-        std::result::Result::Ok(ExpressionType(x.to_string()))
+impl CreateNamespaceRequest {
+    #[allow(clippy::new_without_default)]
+    pub fn new(namespace: Vec<String>) -> CreateNamespaceRequest {
+        CreateNamespaceRequest {
+            namespace,
+            properties: None,
+        }
     }
 }
 
-impl std::convert::From<ExpressionType> for String {
-    fn from(x: ExpressionType) -> Self {
-        x.0
-    }
-}
+/// Converts the CreateNamespaceRequest value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for CreateNamespaceRequest {
+    fn to_string(&self) -> String {
+        let params: Vec<Option<String>> = vec![
+            Some("namespace".to_string()),
+            Some(
+                self.namespace
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect::<Vec<_>>()
+                    .join(","),
+            ),
+            // Skipping properties in query parameter serialization
+        ];
 
-impl std::ops::Deref for ExpressionType {
-    type Target = String;
-    fn deref(&self) -> &String {
-        &self.0
+        params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-impl std::ops::DerefMut for ExpressionType {
-    fn deref_mut(&mut self) -> &mut String {
-        &mut self.0
-    }
-}
+/// Converts Query Parameters representation (style=form, explode=false) to a CreateNamespaceRequest value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for CreateNamespaceRequest {
+    type Err = String;
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct GetToken200Response {
-    /// The access token, for client credentials or token exchange
-    #[serde(rename = "access_token")]
-    pub access_token: String,
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub namespace: Vec<Vec<String>>,
+            pub properties: Vec<serde_json::Value>,
+        }
 
-    /// Access token type for client credentials or token exchange  See https://datatracker.ietf.org/doc/html/rfc6749#section-7.1
-    // Note: inline enums are not fully supported by openapi-generator
-    #[serde(rename = "token_type")]
-    pub token_type: String,
+        let mut intermediate_rep = IntermediateRep::default();
 
-    /// Lifetime of the access token in seconds for client credentials or token exchange
-    #[serde(rename = "expires_in")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub expires_in: Option<isize>,
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
 
-    #[serde(rename = "issued_token_type")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub issued_token_type: Option<models::TokenType>,
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing CreateNamespaceRequest".to_string(),
+                    )
+                }
+            };
 
-    /// Refresh token for client credentials or token exchange
-    #[serde(rename = "refresh_token")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub refresh_token: Option<String>,
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    "namespace" => return std::result::Result::Err("Parsing a container in this style is not supported in CreateNamespaceRequest".to_string()),
+                    #[allow(clippy::redundant_clone)]
+                    "properties" => intermediate_rep.properties.push(<serde_json::Value as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing CreateNamespaceRequest".to_string())
+                }
+            }
 
-    /// Authorization scope for client credentials or token exchange
-    #[serde(rename = "scope")]
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(CreateNamespaceRequest {
+            namespace: intermediate_rep
+                .namespace
+                .into_iter()
+                .next()
+                .ok_or_else(|| "namespace missing in CreateNamespaceRequest".to_string())?,
+            properties: intermediate_rep.properties.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<CreateNamespaceRequest> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<CreateNamespaceRequest>>
+    for hyper::header::HeaderValue
+{
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<CreateNamespaceRequest>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for CreateNamespaceRequest - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue>
+    for header::IntoHeaderValue<CreateNamespaceRequest>
+{
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <CreateNamespaceRequest as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into CreateNamespaceRequest - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct CreateTableRequest {
+    #[serde(rename = "name")]
+    pub name: String,
+
+    #[serde(rename = "location")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scope: Option<String>,
+    pub location: Option<String>,
+
+    #[serde(rename = "schema")]
+    pub schema: models::Schema,
+
+    #[serde(rename = "partition-spec")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub partition_spec: Option<models::PartitionSpec>,
+
+    #[serde(rename = "write-order")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub write_order: Option<models::SortOrder>,
+
+    #[serde(rename = "stage-create")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stage_create: Option<bool>,
+
+    #[serde(rename = "properties")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub properties: Option<std::collections::HashMap<String, String>>,
 }
 
-impl GetToken200Response {
+impl CreateTableRequest {
     #[allow(clippy::new_without_default)]
-    pub fn new(access_token: String, token_type: String) -> GetToken200Response {
-        GetToken200Response {
-            access_token,
-            token_type,
-            expires_in: None,
-            issued_token_type: None,
-            refresh_token: None,
-            scope: None,
+    pub fn new(name: String, schema: models::Schema) -> CreateTableRequest {
+        CreateTableRequest {
+            name,
+            location: None,
+            schema,
+            partition_spec: None,
+            write_order: None,
+            stage_create: None,
+            properties: None,
         }
     }
 }
 
-/// Converts the GetToken200Response value to the Query Parameters representation (style=form, explode=false)
+/// Converts the CreateTableRequest value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for GetToken200Response {
+impl std::string::ToString for CreateTableRequest {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
-            Some("access_token".to_string()),
-            Some(self.access_token.to_string()),
-            Some("token_type".to_string()),
-            Some(self.token_type.to_string()),
-            self.expires_in
+            Some("name".to_string()),
+            Some(self.name.to_string()),
+            self.location
                 .as_ref()
-                .map(|expires_in| vec!["expires_in".to_string(), expires_in.to_string()].join(",")),
-            // Skipping issued_token_type in query parameter serialization
-            self.refresh_token.as_ref().map(|refresh_token| {
-                vec!["refresh_token".to_string(), refresh_token.to_string()].join(",")
+                .map(|location| vec!["location".to_string(), location.to_string()].join(",")),
+            // Skipping schema in query parameter serialization
+
+            // Skipping partition-spec in query parameter serialization
+
+            // Skipping write-order in query parameter serialization
+            self.stage_create.as_ref().map(|stage_create| {
+                vec!["stage-create".to_string(), stage_create.to_string()].join(",")
             }),
-            self.scope
-                .as_ref()
-                .map(|scope| vec!["scope".to_string(), scope.to_string()].join(",")),
+            // Skipping properties in query parameter serialization
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a GetToken200Response value
+/// Converts Query Parameters representation (style=form, explode=false) to a CreateTableRequest value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for GetToken200Response {
+impl std::str::FromStr for CreateTableRequest {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -3033,12 +2952,13 @@ impl std::str::FromStr for GetToken200Response {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub access_token: Vec<String>,
-            pub token_type: Vec<String>,
-            pub expires_in: Vec<isize>,
-            pub issued_token_type: Vec<models::TokenType>,
-            pub refresh_token: Vec<String>,
-            pub scope: Vec<String>,
+            pub name: Vec<String>,
+            pub location: Vec<String>,
+            pub schema: Vec<models::Schema>,
+            pub partition_spec: Vec<models::PartitionSpec>,
+            pub write_order: Vec<models::SortOrder>,
+            pub stage_create: Vec<bool>,
+            pub properties: Vec<std::collections::HashMap<String, String>>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -3052,7 +2972,7 @@ impl std::str::FromStr for GetToken200Response {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing GetToken200Response".to_string(),
+                        "Missing value while parsing CreateTableRequest".to_string(),
                     )
                 }
             };
@@ -3061,33 +2981,39 @@ impl std::str::FromStr for GetToken200Response {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "access_token" => intermediate_rep.access_token.push(
+                    "name" => intermediate_rep.name.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "token_type" => intermediate_rep.token_type.push(
+                    "location" => intermediate_rep.location.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "expires_in" => intermediate_rep.expires_in.push(
-                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "schema" => intermediate_rep.schema.push(
+                        <models::Schema as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "issued_token_type" => intermediate_rep.issued_token_type.push(
-                        <models::TokenType as std::str::FromStr>::from_str(val)
+                    "partition-spec" => intermediate_rep.partition_spec.push(
+                        <models::PartitionSpec as std::str::FromStr>::from_str(val)
                             .map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "refresh_token" => intermediate_rep.refresh_token.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "write-order" => intermediate_rep.write_order.push(
+                        <models::SortOrder as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "scope" => intermediate_rep.scope.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "stage-create" => intermediate_rep.stage_create.push(
+                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    "properties" => return std::result::Result::Err(
+                        "Parsing a container in this style is not supported in CreateTableRequest"
+                            .to_string(),
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing GetToken200Response".to_string(),
+                            "Unexpected key while parsing CreateTableRequest".to_string(),
                         )
                     }
                 }
@@ -3098,41 +3024,42 @@ impl std::str::FromStr for GetToken200Response {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(GetToken200Response {
-            access_token: intermediate_rep
-                .access_token
+        std::result::Result::Ok(CreateTableRequest {
+            name: intermediate_rep
+                .name
                 .into_iter()
                 .next()
-                .ok_or_else(|| "access_token missing in GetToken200Response".to_string())?,
-            token_type: intermediate_rep
-                .token_type
+                .ok_or_else(|| "name missing in CreateTableRequest".to_string())?,
+            location: intermediate_rep.location.into_iter().next(),
+            schema: intermediate_rep
+                .schema
                 .into_iter()
                 .next()
-                .ok_or_else(|| "token_type missing in GetToken200Response".to_string())?,
-            expires_in: intermediate_rep.expires_in.into_iter().next(),
-            issued_token_type: intermediate_rep.issued_token_type.into_iter().next(),
-            refresh_token: intermediate_rep.refresh_token.into_iter().next(),
-            scope: intermediate_rep.scope.into_iter().next(),
+                .ok_or_else(|| "schema missing in CreateTableRequest".to_string())?,
+            partition_spec: intermediate_rep.partition_spec.into_iter().next(),
+            write_order: intermediate_rep.write_order.into_iter().next(),
+            stage_create: intermediate_rep.stage_create.into_iter().next(),
+            properties: intermediate_rep.properties.into_iter().next(),
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<GetToken200Response> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<CreateTableRequest> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<GetToken200Response>>
+impl std::convert::TryFrom<header::IntoHeaderValue<CreateTableRequest>>
     for hyper::header::HeaderValue
 {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<GetToken200Response>,
+        hdr_value: header::IntoHeaderValue<CreateTableRequest>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for GetToken200Response - value: {} is invalid {}",
+                "Invalid header value for CreateTableRequest - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -3141,19 +3068,19 @@ impl std::convert::TryFrom<header::IntoHeaderValue<GetToken200Response>>
 
 #[cfg(any(feature = "client", feature = "server"))]
 impl std::convert::TryFrom<hyper::header::HeaderValue>
-    for header::IntoHeaderValue<GetToken200Response>
+    for header::IntoHeaderValue<CreateTableRequest>
 {
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <GetToken200Response as std::str::FromStr>::from_str(value) {
+                match <CreateTableRequest as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into GetToken200Response - {}",
+                        "Unable to convert header value '{}' into CreateTableRequest - {}",
                         value, err
                     )),
                 }
@@ -3166,61 +3093,72 @@ impl std::convert::TryFrom<hyper::header::HeaderValue>
     }
 }
 
+/// JSON error payload returned in a response with further details on the error
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct GetToken400Response {
-    // Note: inline enums are not fully supported by openapi-generator
-    #[serde(rename = "error")]
-    pub error: String,
-
-    #[serde(rename = "error_description")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub error_description: Option<String>,
+pub struct ErrorModel {
+    /// Human-readable error message
+    #[serde(rename = "message")]
+    pub message: String,
 
-    #[serde(rename = "error_uri")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub error_uri: Option<String>,
-}
+    /// Internal type definition of the error
+    #[serde(rename = "type")]
+    pub r#type: String,
 
-impl GetToken400Response {
+    /// HTTP response code
+    #[serde(rename = "code")]
+    pub code: u16,
+
+    #[serde(rename = "stack")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stack: Option<Vec<String>>,
+}
+
+impl ErrorModel {
     #[allow(clippy::new_without_default)]
-    pub fn new(error: String) -> GetToken400Response {
-        GetToken400Response {
-            error,
-            error_description: None,
-            error_uri: None,
+    pub fn new(message: String, r#type: String, code: u16) -> ErrorModel {
+        ErrorModel {
+            message,
+            r#type,
+            code,
+            stack: None,
         }
     }
 }
 
-/// Converts the GetToken400Response value to the Query Parameters representation (style=form, explode=false)
+/// Converts the ErrorModel value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for GetToken400Response {
+impl std::string::ToString for ErrorModel {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
-            Some("error".to_string()),
-            Some(self.error.to_string()),
-            self.error_description.as_ref().map(|error_description| {
+            Some("message".to_string()),
+            Some(self.message.to_string()),
+            Some("type".to_string()),
+            Some(self.r#type.to_string()),
+            Some("code".to_string()),
+            Some(self.code.to_string()),
+            self.stack.as_ref().map(|stack| {
                 vec![
-                    "error_description".to_string(),
-                    error_description.to_string(),
+                    "stack".to_string(),
+                    stack
+                        .iter()
+                        .map(|x| x.to_string())
+                        .collect::<Vec<_>>()
+                        .join(","),
                 ]
                 .join(",")
             }),
-            self.error_uri
-                .as_ref()
-                .map(|error_uri| vec!["error_uri".to_string(), error_uri.to_string()].join(",")),
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a GetToken400Response value
+/// Converts Query Parameters representation (style=form, explode=false) to a ErrorModel value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for GetToken400Response {
+impl std::str::FromStr for ErrorModel {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -3228,9 +3166,10 @@ impl std::str::FromStr for GetToken400Response {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub error: Vec<String>,
-            pub error_description: Vec<String>,
-            pub error_uri: Vec<String>,
+            pub message: Vec<String>,
+            pub r#type: Vec<String>,
+            pub code: Vec<u16>,
+            pub stack: Vec<Vec<String>>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -3244,7 +3183,7 @@ impl std::str::FromStr for GetToken400Response {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing GetToken400Response".to_string(),
+                        "Missing value while parsing ErrorModel".to_string(),
                     )
                 }
             };
@@ -3253,20 +3192,26 @@ impl std::str::FromStr for GetToken400Response {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "error" => intermediate_rep.error.push(
+                    "message" => intermediate_rep.message.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "error_description" => intermediate_rep.error_description.push(
+                    "type" => intermediate_rep.r#type.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "error_uri" => intermediate_rep.error_uri.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "code" => intermediate_rep.code.push(
+                        <u16 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
+                    "stack" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in ErrorModel"
+                                .to_string(),
+                        )
+                    }
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing GetToken400Response".to_string(),
+                            "Unexpected key while parsing ErrorModel".to_string(),
                         )
                     }
                 }
@@ -3277,34 +3222,41 @@ impl std::str::FromStr for GetToken400Response {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(GetToken400Response {
-            error: intermediate_rep
-                .error
+        std::result::Result::Ok(ErrorModel {
+            message: intermediate_rep
+                .message
                 .into_iter()
                 .next()
-                .ok_or_else(|| "error missing in GetToken400Response".to_string())?,
-            error_description: intermediate_rep.error_description.into_iter().next(),
-            error_uri: intermediate_rep.error_uri.into_iter().next(),
+                .ok_or_else(|| "message missing in ErrorModel".to_string())?,
+            r#type: intermediate_rep
+                .r#type
+                .into_iter()
+                .next()
+                .ok_or_else(|| "type missing in ErrorModel".to_string())?,
+            code: intermediate_rep
+                .code
+                .into_iter()
+                .next()
+                .ok_or_else(|| "code missing in ErrorModel".to_string())?,
+            stack: intermediate_rep.stack.into_iter().next(),
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<GetToken400Response> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<ErrorModel> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<GetToken400Response>>
-    for hyper::header::HeaderValue
-{
+impl std::convert::TryFrom<header::IntoHeaderValue<ErrorModel>> for hyper::header::HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<GetToken400Response>,
+        hdr_value: header::IntoHeaderValue<ErrorModel>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for GetToken400Response - value: {} is invalid {}",
+                "Invalid header value for ErrorModel - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -3312,20 +3264,18 @@ impl std::convert::TryFrom<header::IntoHeaderValue<GetToken400Response>>
 }
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<hyper::header::HeaderValue>
-    for header::IntoHeaderValue<GetToken400Response>
-{
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<ErrorModel> {
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <GetToken400Response as std::str::FromStr>::from_str(value) {
+                match <ErrorModel as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into GetToken400Response - {}",
+                        "Unable to convert header value '{}' into ErrorModel - {}",
                         value, err
                     )),
                 }
@@ -3340,37 +3290,81 @@ impl std::convert::TryFrom<hyper::header::HeaderValue>
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct ListNamespaces200Response {
-    #[serde(rename = "namespaces")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub namespaces: Option<Vec<models::Namespace>>,
+pub struct Expression {
+    #[serde(rename = "type")]
+    pub r#type: String,
+
+    #[serde(rename = "left")]
+    pub left: Box<Expression>,
+
+    #[serde(rename = "right")]
+    pub right: Box<Expression>,
+
+    #[serde(rename = "child")]
+    pub child: Box<Expression>,
+
+    #[serde(rename = "term")]
+    pub term: models::Term,
+
+    #[serde(rename = "values")]
+    pub values: Vec<serde_json::Value>,
+
+    #[serde(rename = "value")]
+    pub value: serde_json::Value,
 }
 
-impl ListNamespaces200Response {
+impl Expression {
     #[allow(clippy::new_without_default)]
-    pub fn new() -> ListNamespaces200Response {
-        ListNamespaces200Response { namespaces: None }
+    pub fn new(
+        r#type: String,
+        left: models::Expression,
+        right: models::Expression,
+        child: models::Expression,
+        term: models::Term,
+        values: Vec<serde_json::Value>,
+        value: serde_json::Value,
+    ) -> Expression {
+        Expression {
+            r#type,
+            left: Box::new(left),
+            right: Box::new(right),
+            child: Box::new(child),
+            term,
+            values,
+            value,
+        }
     }
 }
 
-/// Converts the ListNamespaces200Response value to the Query Parameters representation (style=form, explode=false)
+/// Converts the Expression value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for ListNamespaces200Response {
+impl std::string::ToString for Expression {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
-            // Skipping namespaces in query parameter serialization
+            Some("type".to_string()),
+            Some(self.r#type.to_string()),
+            // Skipping left in query parameter serialization
+
+            // Skipping right in query parameter serialization
+
+            // Skipping child in query parameter serialization
+
+            // Skipping term in query parameter serialization
 
+            // Skipping values in query parameter serialization
+
+            // Skipping value in query parameter serialization
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a ListNamespaces200Response value
+/// Converts Query Parameters representation (style=form, explode=false) to a Expression value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for ListNamespaces200Response {
+impl std::str::FromStr for Expression {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -3378,7 +3372,13 @@ impl std::str::FromStr for ListNamespaces200Response {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub namespaces: Vec<Vec<models::Namespace>>,
+            pub r#type: Vec<String>,
+            pub left: Vec<models::Expression>,
+            pub right: Vec<models::Expression>,
+            pub child: Vec<models::Expression>,
+            pub term: Vec<models::Term>,
+            pub values: Vec<Vec<serde_json::Value>>,
+            pub value: Vec<serde_json::Value>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -3392,7 +3392,7 @@ impl std::str::FromStr for ListNamespaces200Response {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing ListNamespaces200Response".to_string(),
+                        "Missing value while parsing Expression".to_string(),
                     )
                 }
             };
@@ -3400,8 +3400,46 @@ impl std::str::FromStr for ListNamespaces200Response {
             if let Some(key) = key_result {
                 #[allow(clippy::match_single_binding)]
                 match key {
-                    "namespaces" => return std::result::Result::Err("Parsing a container in this style is not supported in ListNamespaces200Response".to_string()),
-                    _ => return std::result::Result::Err("Unexpected key while parsing ListNamespaces200Response".to_string())
+                    #[allow(clippy::redundant_clone)]
+                    "type" => intermediate_rep.r#type.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "left" => intermediate_rep.left.push(
+                        <models::Expression as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "right" => intermediate_rep.right.push(
+                        <models::Expression as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "child" => intermediate_rep.child.push(
+                        <models::Expression as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "term" => intermediate_rep.term.push(
+                        <models::Term as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    "values" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in Expression"
+                                .to_string(),
+                        )
+                    }
+                    #[allow(clippy::redundant_clone)]
+                    "value" => intermediate_rep.value.push(
+                        <serde_json::Value as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing Expression".to_string(),
+                        )
+                    }
                 }
             }
 
@@ -3410,28 +3448,66 @@ impl std::str::FromStr for ListNamespaces200Response {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(ListNamespaces200Response {
-            namespaces: intermediate_rep.namespaces.into_iter().next(),
-        })
-    }
-}
-
-// Methods for converting between header::IntoHeaderValue<ListNamespaces200Response> and hyper::header::HeaderValue
-
-#[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<ListNamespaces200Response>>
-    for hyper::header::HeaderValue
-{
-    type Error = String;
-
-    fn try_from(
-        hdr_value: header::IntoHeaderValue<ListNamespaces200Response>,
-    ) -> std::result::Result<Self, Self::Error> {
-        let hdr_value = hdr_value.to_string();
-        match hyper::header::HeaderValue::from_str(&hdr_value) {
-            std::result::Result::Ok(value) => std::result::Result::Ok(value),
-            std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for ListNamespaces200Response - value: {} is invalid {}",
+        std::result::Result::Ok(Expression {
+            r#type: intermediate_rep
+                .r#type
+                .into_iter()
+                .next()
+                .ok_or_else(|| "type missing in Expression".to_string())?,
+            left: Box::new(
+                intermediate_rep
+                    .left
+                    .into_iter()
+                    .next()
+                    .ok_or_else(|| "left missing in Expression".to_string())?,
+            ),
+            right: Box::new(
+                intermediate_rep
+                    .right
+                    .into_iter()
+                    .next()
+                    .ok_or_else(|| "right missing in Expression".to_string())?,
+            ),
+            child: Box::new(
+                intermediate_rep
+                    .child
+                    .into_iter()
+                    .next()
+                    .ok_or_else(|| "child missing in Expression".to_string())?,
+            ),
+            term: intermediate_rep
+                .term
+                .into_iter()
+                .next()
+                .ok_or_else(|| "term missing in Expression".to_string())?,
+            values: intermediate_rep
+                .values
+                .into_iter()
+                .next()
+                .ok_or_else(|| "values missing in Expression".to_string())?,
+            value: intermediate_rep
+                .value
+                .into_iter()
+                .next()
+                .ok_or_else(|| "value missing in Expression".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<Expression> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<Expression>> for hyper::header::HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<Expression>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for Expression - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -3439,20 +3515,18 @@ impl std::convert::TryFrom<header::IntoHeaderValue<ListNamespaces200Response>>
 }
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<hyper::header::HeaderValue>
-    for header::IntoHeaderValue<ListNamespaces200Response>
-{
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<Expression> {
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <ListNamespaces200Response as std::str::FromStr>::from_str(value) {
+                match <Expression as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into ListNamespaces200Response - {}",
+                        "Unable to convert header value '{}' into Expression - {}",
                         value, err
                     )),
                 }
@@ -3465,39 +3539,86 @@ impl std::convert::TryFrom<hyper::header::HeaderValue>
     }
 }
 
+#[derive(Debug, Clone, PartialEq, PartialOrd, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct ExpressionType(String);
+
+impl std::convert::From<String> for ExpressionType {
+    fn from(x: String) -> Self {
+        ExpressionType(x)
+    }
+}
+
+impl std::string::ToString for ExpressionType {
+    fn to_string(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+impl std::str::FromStr for ExpressionType {
+    type Err = std::string::ParseError;
+    fn from_str(x: &str) -> std::result::Result<Self, Self::Err> {
+        std::result::Result::Ok(ExpressionType(x.to_string()))
+    }
+}
+
+impl std::convert::From<ExpressionType> for String {
+    fn from(x: ExpressionType) -> Self {
+        x.0
+    }
+}
+
+impl std::ops::Deref for ExpressionType {
+    type Target = String;
+    fn deref(&self) -> &String {
+        &self.0
+    }
+}
+
+impl std::ops::DerefMut for ExpressionType {
+    fn deref_mut(&mut self) -> &mut String {
+        &mut self.0
+    }
+}
+
+/// A filter expression and the number of scans that used it
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct ListTables200Response {
-    #[serde(rename = "identifiers")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub identifiers: Option<Vec<models::TableIdentifier>>,
+pub struct FilterUsage {
+    #[serde(rename = "filter")]
+    pub filter: serde_json::Value,
+
+    #[serde(rename = "count")]
+    pub count: i64,
 }
 
-impl ListTables200Response {
+impl FilterUsage {
     #[allow(clippy::new_without_default)]
-    pub fn new() -> ListTables200Response {
-        ListTables200Response { identifiers: None }
+    pub fn new(filter: serde_json::Value, count: i64) -> FilterUsage {
+        FilterUsage { filter, count }
     }
 }
 
-/// Converts the ListTables200Response value to the Query Parameters representation (style=form, explode=false)
+/// Converts the FilterUsage value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for ListTables200Response {
+impl std::string::ToString for FilterUsage {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
-            // Skipping identifiers in query parameter serialization
-
+            Some("filter".to_string()),
+            Some(self.filter.to_string()),
+            Some("count".to_string()),
+            Some(self.count.to_string()),
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a ListTables200Response value
+/// Converts Query Parameters representation (style=form, explode=false) to a FilterUsage value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for ListTables200Response {
+impl std::str::FromStr for FilterUsage {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -3505,7 +3626,8 @@ impl std::str::FromStr for ListTables200Response {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub identifiers: Vec<Vec<models::TableIdentifier>>,
+            pub filter: Vec<serde_json::Value>,
+            pub count: Vec<i64>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -3519,7 +3641,7 @@ impl std::str::FromStr for ListTables200Response {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing ListTables200Response".to_string(),
+                        "Missing value while parsing FilterUsage".to_string(),
                     )
                 }
             };
@@ -3527,8 +3649,20 @@ impl std::str::FromStr for ListTables200Response {
             if let Some(key) = key_result {
                 #[allow(clippy::match_single_binding)]
                 match key {
-                    "identifiers" => return std::result::Result::Err("Parsing a container in this style is not supported in ListTables200Response".to_string()),
-                    _ => return std::result::Result::Err("Unexpected key while parsing ListTables200Response".to_string())
+                    #[allow(clippy::redundant_clone)]
+                    "filter" => intermediate_rep.filter.push(
+                        <serde_json::Value as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "count" => intermediate_rep.count.push(
+                        <i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing FilterUsage".to_string(),
+                        )
+                    }
                 }
             }
 
@@ -3537,28 +3671,35 @@ impl std::str::FromStr for ListTables200Response {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(ListTables200Response {
-            identifiers: intermediate_rep.identifiers.into_iter().next(),
+        std::result::Result::Ok(FilterUsage {
+            filter: intermediate_rep
+                .filter
+                .into_iter()
+                .next()
+                .ok_or_else(|| "filter missing in FilterUsage".to_string())?,
+            count: intermediate_rep
+                .count
+                .into_iter()
+                .next()
+                .ok_or_else(|| "count missing in FilterUsage".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<ListTables200Response> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<FilterUsage> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<ListTables200Response>>
-    for hyper::header::HeaderValue
-{
+impl std::convert::TryFrom<header::IntoHeaderValue<FilterUsage>> for hyper::header::HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<ListTables200Response>,
+        hdr_value: header::IntoHeaderValue<FilterUsage>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for ListTables200Response - value: {} is invalid {}",
+                "Invalid header value for FilterUsage - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -3566,20 +3707,18 @@ impl std::convert::TryFrom<header::IntoHeaderValue<ListTables200Response>>
 }
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<hyper::header::HeaderValue>
-    for header::IntoHeaderValue<ListTables200Response>
-{
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<FilterUsage> {
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <ListTables200Response as std::str::FromStr>::from_str(value) {
+                match <FilterUsage as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into ListTables200Response - {}",
+                        "Unable to convert header value '{}' into FilterUsage - {}",
                         value, err
                     )),
                 }
@@ -3594,61 +3733,80 @@ impl std::convert::TryFrom<hyper::header::HeaderValue>
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct ListType {
+pub struct GetToken200Response {
+    /// The access token, for client credentials or token exchange
+    #[serde(rename = "access_token")]
+    pub access_token: String,
+
+    /// Access token type for client credentials or token exchange  See https://datatracker.ietf.org/doc/html/rfc6749#section-7.1
     // Note: inline enums are not fully supported by openapi-generator
-    #[serde(rename = "type")]
-    pub r#type: String,
+    #[serde(rename = "token_type")]
+    pub token_type: String,
 
-    #[serde(rename = "element-id")]
-    pub element_id: isize,
+    /// Lifetime of the access token in seconds for client credentials or token exchange
+    #[serde(rename = "expires_in")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_in: Option<isize>,
 
-    #[serde(rename = "element")]
-    pub element: models::Type,
+    #[serde(rename = "issued_token_type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issued_token_type: Option<models::TokenType>,
 
-    #[serde(rename = "element-required")]
-    pub element_required: bool,
+    /// Refresh token for client credentials or token exchange
+    #[serde(rename = "refresh_token")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refresh_token: Option<String>,
+
+    /// Authorization scope for client credentials or token exchange
+    #[serde(rename = "scope")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
 }
 
-impl ListType {
+impl GetToken200Response {
     #[allow(clippy::new_without_default)]
-    pub fn new(
-        r#type: String,
-        element_id: isize,
-        element: models::Type,
-        element_required: bool,
-    ) -> ListType {
-        ListType {
-            r#type,
-            element_id,
-            element,
-            element_required,
+    pub fn new(access_token: String, token_type: String) -> GetToken200Response {
+        GetToken200Response {
+            access_token,
+            token_type,
+            expires_in: None,
+            issued_token_type: None,
+            refresh_token: None,
+            scope: None,
         }
     }
 }
 
-/// Converts the ListType value to the Query Parameters representation (style=form, explode=false)
+/// Converts the GetToken200Response value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for ListType {
+impl std::string::ToString for GetToken200Response {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
-            Some("type".to_string()),
-            Some(self.r#type.to_string()),
-            Some("element-id".to_string()),
-            Some(self.element_id.to_string()),
-            // Skipping element in query parameter serialization
-            Some("element-required".to_string()),
-            Some(self.element_required.to_string()),
+            Some("access_token".to_string()),
+            Some(self.access_token.to_string()),
+            Some("token_type".to_string()),
+            Some(self.token_type.to_string()),
+            self.expires_in
+                .as_ref()
+                .map(|expires_in| vec!["expires_in".to_string(), expires_in.to_string()].join(",")),
+            // Skipping issued_token_type in query parameter serialization
+            self.refresh_token.as_ref().map(|refresh_token| {
+                vec!["refresh_token".to_string(), refresh_token.to_string()].join(",")
+            }),
+            self.scope
+                .as_ref()
+                .map(|scope| vec!["scope".to_string(), scope.to_string()].join(",")),
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a ListType value
+/// Converts Query Parameters representation (style=form, explode=false) to a GetToken200Response value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for ListType {
+impl std::str::FromStr for GetToken200Response {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -3656,10 +3814,12 @@ impl std::str::FromStr for ListType {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub r#type: Vec<String>,
-            pub element_id: Vec<isize>,
-            pub element: Vec<models::Type>,
-            pub element_required: Vec<bool>,
+            pub access_token: Vec<String>,
+            pub token_type: Vec<String>,
+            pub expires_in: Vec<isize>,
+            pub issued_token_type: Vec<models::TokenType>,
+            pub refresh_token: Vec<String>,
+            pub scope: Vec<String>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -3673,7 +3833,7 @@ impl std::str::FromStr for ListType {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing ListType".to_string(),
+                        "Missing value while parsing GetToken200Response".to_string(),
                     )
                 }
             };
@@ -3682,25 +3842,33 @@ impl std::str::FromStr for ListType {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "type" => intermediate_rep.r#type.push(
+                    "access_token" => intermediate_rep.access_token.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "element-id" => intermediate_rep.element_id.push(
+                    "token_type" => intermediate_rep.token_type.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "expires_in" => intermediate_rep.expires_in.push(
                         <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "element" => intermediate_rep.element.push(
-                        <models::Type as std::str::FromStr>::from_str(val)
+                    "issued_token_type" => intermediate_rep.issued_token_type.push(
+                        <models::TokenType as std::str::FromStr>::from_str(val)
                             .map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "element-required" => intermediate_rep.element_required.push(
-                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "refresh_token" => intermediate_rep.refresh_token.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "scope" => intermediate_rep.scope.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing ListType".to_string(),
+                            "Unexpected key while parsing GetToken200Response".to_string(),
                         )
                     }
                 }
@@ -3711,45 +3879,41 @@ impl std::str::FromStr for ListType {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(ListType {
-            r#type: intermediate_rep
-                .r#type
-                .into_iter()
-                .next()
-                .ok_or_else(|| "type missing in ListType".to_string())?,
-            element_id: intermediate_rep
-                .element_id
-                .into_iter()
-                .next()
-                .ok_or_else(|| "element-id missing in ListType".to_string())?,
-            element: intermediate_rep
-                .element
+        std::result::Result::Ok(GetToken200Response {
+            access_token: intermediate_rep
+                .access_token
                 .into_iter()
                 .next()
-                .ok_or_else(|| "element missing in ListType".to_string())?,
-            element_required: intermediate_rep
-                .element_required
+                .ok_or_else(|| "access_token missing in GetToken200Response".to_string())?,
+            token_type: intermediate_rep
+                .token_type
                 .into_iter()
                 .next()
-                .ok_or_else(|| "element-required missing in ListType".to_string())?,
+                .ok_or_else(|| "token_type missing in GetToken200Response".to_string())?,
+            expires_in: intermediate_rep.expires_in.into_iter().next(),
+            issued_token_type: intermediate_rep.issued_token_type.into_iter().next(),
+            refresh_token: intermediate_rep.refresh_token.into_iter().next(),
+            scope: intermediate_rep.scope.into_iter().next(),
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<ListType> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<GetToken200Response> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<ListType>> for hyper::header::HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<GetToken200Response>>
+    for hyper::header::HeaderValue
+{
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<ListType>,
+        hdr_value: header::IntoHeaderValue<GetToken200Response>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for ListType - value: {} is invalid {}",
+                "Invalid header value for GetToken200Response - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -3757,18 +3921,20 @@ impl std::convert::TryFrom<header::IntoHeaderValue<ListType>> for hyper::header:
 }
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<ListType> {
+impl std::convert::TryFrom<hyper::header::HeaderValue>
+    for header::IntoHeaderValue<GetToken200Response>
+{
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <ListType as std::str::FromStr>::from_str(value) {
+                match <GetToken200Response as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into ListType - {}",
+                        "Unable to convert header value '{}' into GetToken200Response - {}",
                         value, err
                     )),
                 }
@@ -3783,49 +3949,59 @@ impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderVal
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct LiteralExpression {
-    #[serde(rename = "type")]
-    pub r#type: String,
+pub struct GetToken400Response {
+    // Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "error")]
+    pub error: String,
 
-    #[serde(rename = "term")]
-    pub term: models::Term,
+    #[serde(rename = "error_description")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_description: Option<String>,
 
-    #[serde(rename = "value")]
-    pub value: serde_json::Value,
+    #[serde(rename = "error_uri")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_uri: Option<String>,
 }
 
-impl LiteralExpression {
+impl GetToken400Response {
     #[allow(clippy::new_without_default)]
-    pub fn new(r#type: String, term: models::Term, value: serde_json::Value) -> LiteralExpression {
-        LiteralExpression {
-            r#type,
-            term,
-            value,
+    pub fn new(error: String) -> GetToken400Response {
+        GetToken400Response {
+            error,
+            error_description: None,
+            error_uri: None,
         }
     }
 }
 
-/// Converts the LiteralExpression value to the Query Parameters representation (style=form, explode=false)
+/// Converts the GetToken400Response value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for LiteralExpression {
+impl std::string::ToString for GetToken400Response {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
-            Some("type".to_string()),
-            Some(self.r#type.to_string()),
-            // Skipping term in query parameter serialization
-
-            // Skipping value in query parameter serialization
+            Some("error".to_string()),
+            Some(self.error.to_string()),
+            self.error_description.as_ref().map(|error_description| {
+                vec![
+                    "error_description".to_string(),
+                    error_description.to_string(),
+                ]
+                .join(",")
+            }),
+            self.error_uri
+                .as_ref()
+                .map(|error_uri| vec!["error_uri".to_string(), error_uri.to_string()].join(",")),
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a LiteralExpression value
+/// Converts Query Parameters representation (style=form, explode=false) to a GetToken400Response value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for LiteralExpression {
+impl std::str::FromStr for GetToken400Response {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -3833,9 +4009,9 @@ impl std::str::FromStr for LiteralExpression {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub r#type: Vec<String>,
-            pub term: Vec<models::Term>,
-            pub value: Vec<serde_json::Value>,
+            pub error: Vec<String>,
+            pub error_description: Vec<String>,
+            pub error_uri: Vec<String>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -3849,7 +4025,7 @@ impl std::str::FromStr for LiteralExpression {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing LiteralExpression".to_string(),
+                        "Missing value while parsing GetToken400Response".to_string(),
                     )
                 }
             };
@@ -3858,22 +4034,20 @@ impl std::str::FromStr for LiteralExpression {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "type" => intermediate_rep.r#type.push(
+                    "error" => intermediate_rep.error.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "term" => intermediate_rep.term.push(
-                        <models::Term as std::str::FromStr>::from_str(val)
-                            .map_err(|x| x.to_string())?,
+                    "error_description" => intermediate_rep.error_description.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "value" => intermediate_rep.value.push(
-                        <serde_json::Value as std::str::FromStr>::from_str(val)
-                            .map_err(|x| x.to_string())?,
+                    "error_uri" => intermediate_rep.error_uri.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing LiteralExpression".to_string(),
+                            "Unexpected key while parsing GetToken400Response".to_string(),
                         )
                     }
                 }
@@ -3884,42 +4058,34 @@ impl std::str::FromStr for LiteralExpression {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(LiteralExpression {
-            r#type: intermediate_rep
-                .r#type
-                .into_iter()
-                .next()
-                .ok_or_else(|| "type missing in LiteralExpression".to_string())?,
-            term: intermediate_rep
-                .term
-                .into_iter()
-                .next()
-                .ok_or_else(|| "term missing in LiteralExpression".to_string())?,
-            value: intermediate_rep
-                .value
+        std::result::Result::Ok(GetToken400Response {
+            error: intermediate_rep
+                .error
                 .into_iter()
                 .next()
-                .ok_or_else(|| "value missing in LiteralExpression".to_string())?,
+                .ok_or_else(|| "error missing in GetToken400Response".to_string())?,
+            error_description: intermediate_rep.error_description.into_iter().next(),
+            error_uri: intermediate_rep.error_uri.into_iter().next(),
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<LiteralExpression> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<GetToken400Response> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<LiteralExpression>>
+impl std::convert::TryFrom<header::IntoHeaderValue<GetToken400Response>>
     for hyper::header::HeaderValue
 {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<LiteralExpression>,
+        hdr_value: header::IntoHeaderValue<GetToken400Response>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for LiteralExpression - value: {} is invalid {}",
+                "Invalid header value for GetToken400Response - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -3928,19 +4094,19 @@ impl std::convert::TryFrom<header::IntoHeaderValue<LiteralExpression>>
 
 #[cfg(any(feature = "client", feature = "server"))]
 impl std::convert::TryFrom<hyper::header::HeaderValue>
-    for header::IntoHeaderValue<LiteralExpression>
+    for header::IntoHeaderValue<GetToken400Response>
 {
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <LiteralExpression as std::str::FromStr>::from_str(value) {
+                match <GetToken400Response as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into LiteralExpression - {}",
+                        "Unable to convert header value '{}' into GetToken400Response - {}",
                         value, err
                     )),
                 }
@@ -3953,54 +4119,73 @@ impl std::convert::TryFrom<hyper::header::HeaderValue>
     }
 }
 
+/// A privilege granted to a role on a catalog, namespace or table
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct LoadNamespaceMetadata200Response {
-    /// Reference to one or more levels of a namespace
+pub struct Grant {
+    #[serde(rename = "privilege")]
+    pub privilege: models::Privilege,
+
+    /// The prefix of the catalog, empty for the default catalog
+    #[serde(rename = "catalog")]
+    pub catalog: String,
+
+    /// The namespace of the grant, the whole catalog if missing
     #[serde(rename = "namespace")]
-    pub namespace: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<Vec<String>>,
 
-    /// Properties stored on the namespace, if supported by the server. If the server does not support namespace properties, it should return null for this field. If namespace properties are supported, but none are set, it should return an empty object.
-    #[serde(rename = "properties")]
+    /// The table of the grant, the whole namespace if missing
+    #[serde(rename = "table")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub properties: Option<serde_json::Value>,
+    pub table: Option<String>,
 }
 
-impl LoadNamespaceMetadata200Response {
+impl Grant {
     #[allow(clippy::new_without_default)]
-    pub fn new(namespace: Vec<String>) -> LoadNamespaceMetadata200Response {
-        LoadNamespaceMetadata200Response {
-            namespace,
-            properties: None,
+    pub fn new(privilege: models::Privilege, catalog: String) -> Grant {
+        Grant {
+            privilege,
+            catalog,
+            namespace: None,
+            table: None,
         }
     }
 }
 
-/// Converts the LoadNamespaceMetadata200Response value to the Query Parameters representation (style=form, explode=false)
+/// Converts the Grant value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for LoadNamespaceMetadata200Response {
+impl std::string::ToString for Grant {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
-            Some("namespace".to_string()),
-            Some(
-                self.namespace
-                    .iter()
-                    .map(|x| x.to_string())
-                    .collect::<Vec<_>>()
-                    .join(","),
-            ),
-            // Skipping properties in query parameter serialization
+            // Skipping privilege in query parameter serialization
+            Some("catalog".to_string()),
+            Some(self.catalog.to_string()),
+            self.namespace.as_ref().map(|namespace| {
+                vec![
+                    "namespace".to_string(),
+                    namespace
+                        .iter()
+                        .map(|x| x.to_string())
+                        .collect::<Vec<_>>()
+                        .join(","),
+                ]
+                .join(",")
+            }),
+            self.table
+                .as_ref()
+                .map(|table| vec!["table".to_string(), table.to_string()].join(",")),
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a LoadNamespaceMetadata200Response value
+/// Converts Query Parameters representation (style=form, explode=false) to a Grant value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for LoadNamespaceMetadata200Response {
+impl std::str::FromStr for Grant {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -4008,8 +4193,10 @@ impl std::str::FromStr for LoadNamespaceMetadata200Response {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
+            pub privilege: Vec<models::Privilege>,
+            pub catalog: Vec<String>,
             pub namespace: Vec<Vec<String>>,
-            pub properties: Vec<serde_json::Value>,
+            pub table: Vec<String>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -4023,7 +4210,7 @@ impl std::str::FromStr for LoadNamespaceMetadata200Response {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing LoadNamespaceMetadata200Response".to_string(),
+                        "Missing value while parsing Grant".to_string(),
                     )
                 }
             };
@@ -4031,10 +4218,30 @@ impl std::str::FromStr for LoadNamespaceMetadata200Response {
             if let Some(key) = key_result {
                 #[allow(clippy::match_single_binding)]
                 match key {
-                    "namespace" => return std::result::Result::Err("Parsing a container in this style is not supported in LoadNamespaceMetadata200Response".to_string()),
                     #[allow(clippy::redundant_clone)]
-                    "properties" => intermediate_rep.properties.push(<serde_json::Value as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
-                    _ => return std::result::Result::Err("Unexpected key while parsing LoadNamespaceMetadata200Response".to_string())
+                    "privilege" => intermediate_rep.privilege.push(
+                        <models::Privilege as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "catalog" => intermediate_rep.catalog.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    "namespace" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in Grant"
+                                .to_string(),
+                        )
+                    }
+                    #[allow(clippy::redundant_clone)]
+                    "table" => intermediate_rep.table.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing Grant".to_string(),
+                        )
+                    }
                 }
             }
 
@@ -4043,117 +4250,97 @@ impl std::str::FromStr for LoadNamespaceMetadata200Response {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(LoadNamespaceMetadata200Response {
-            namespace: intermediate_rep
-                .namespace
+        std::result::Result::Ok(Grant {
+            privilege: intermediate_rep
+                .privilege
                 .into_iter()
                 .next()
-                .ok_or_else(|| {
-                    "namespace missing in LoadNamespaceMetadata200Response".to_string()
-                })?,
-            properties: intermediate_rep.properties.into_iter().next(),
+                .ok_or_else(|| "privilege missing in Grant".to_string())?,
+            catalog: intermediate_rep
+                .catalog
+                .into_iter()
+                .next()
+                .ok_or_else(|| "catalog missing in Grant".to_string())?,
+            namespace: intermediate_rep.namespace.into_iter().next(),
+            table: intermediate_rep.table.into_iter().next(),
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<LoadNamespaceMetadata200Response> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<Grant> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<LoadNamespaceMetadata200Response>>
-    for hyper::header::HeaderValue
-{
+impl std::convert::TryFrom<header::IntoHeaderValue<Grant>> for hyper::header::HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<LoadNamespaceMetadata200Response>,
+        hdr_value: header::IntoHeaderValue<Grant>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
-             std::result::Result::Ok(value) => std::result::Result::Ok(value),
-             std::result::Result::Err(e) => std::result::Result::Err(
-                 format!("Invalid header value for LoadNamespaceMetadata200Response - value: {} is invalid {}",
-                     hdr_value, e))
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for Grant - value: {} is invalid {}",
+                hdr_value, e
+            )),
         }
     }
 }
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<hyper::header::HeaderValue>
-    for header::IntoHeaderValue<LoadNamespaceMetadata200Response>
-{
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<Grant> {
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
-             std::result::Result::Ok(value) => {
-                    match <LoadNamespaceMetadata200Response as std::str::FromStr>::from_str(value) {
-                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
-                        std::result::Result::Err(err) => std::result::Result::Err(
-                            format!("Unable to convert header value '{}' into LoadNamespaceMetadata200Response - {}",
-                                value, err))
-                    }
-             },
-             std::result::Result::Err(e) => std::result::Result::Err(
-                 format!("Unable to convert header: {:?} to string: {}",
-                     hdr_value, e))
+            std::result::Result::Ok(value) => match <Grant as std::str::FromStr>::from_str(value) {
+                std::result::Result::Ok(value) => {
+                    std::result::Result::Ok(header::IntoHeaderValue(value))
+                }
+                std::result::Result::Err(err) => std::result::Result::Err(format!(
+                    "Unable to convert header value '{}' into Grant - {}",
+                    value, err
+                )),
+            },
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
         }
     }
 }
 
-/// Result used when a table is successfully loaded.  The table metadata JSON is returned in the `metadata` field. The corresponding file location of table metadata should be returned in the `metadata-location` field, unless the metadata is not yet committed. For example, a create transaction may return metadata that is staged but not committed. Clients can check whether metadata has changed by comparing metadata locations after the table has been created.  The `config` map returns table-specific configuration for the table's resources, including its HTTP client and FileIO. For example, config may contain a specific FileIO implementation class for the table depending on its underlying storage.
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct LoadTableResult {
-    /// May be null if the table is staged as part of a transaction
-    #[serde(rename = "metadata-location")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub metadata_location: Option<String>,
-
-    #[serde(rename = "metadata")]
-    pub metadata: models::TableMetadata,
-
-    #[serde(rename = "config")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub config: Option<std::collections::HashMap<String, String>>,
+pub struct ListCatalogs200Response {
+    #[serde(rename = "catalogs")]
+    pub catalogs: Vec<models::Catalog>,
 }
 
-impl LoadTableResult {
+impl ListCatalogs200Response {
     #[allow(clippy::new_without_default)]
-    pub fn new(metadata: models::TableMetadata) -> LoadTableResult {
-        LoadTableResult {
-            metadata_location: None,
-            metadata,
-            config: None,
-        }
+    pub fn new(catalogs: Vec<models::Catalog>) -> ListCatalogs200Response {
+        ListCatalogs200Response { catalogs }
     }
 }
 
-/// Converts the LoadTableResult value to the Query Parameters representation (style=form, explode=false)
+/// Converts the ListCatalogs200Response value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for LoadTableResult {
+impl std::string::ToString for ListCatalogs200Response {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
-            self.metadata_location.as_ref().map(|metadata_location| {
-                vec![
-                    "metadata-location".to_string(),
-                    metadata_location.to_string(),
-                ]
-                .join(",")
-            }),
-            // Skipping metadata in query parameter serialization
-
-            // Skipping config in query parameter serialization
+            // Skipping catalogs in query parameter serialization
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a LoadTableResult value
+/// Converts Query Parameters representation (style=form, explode=false) to a ListCatalogs200Response value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for LoadTableResult {
+impl std::str::FromStr for ListCatalogs200Response {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -4161,9 +4348,7 @@ impl std::str::FromStr for LoadTableResult {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub metadata_location: Vec<String>,
-            pub metadata: Vec<models::TableMetadata>,
-            pub config: Vec<std::collections::HashMap<String, String>>,
+            pub catalogs: Vec<Vec<models::Catalog>>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -4177,7 +4362,7 @@ impl std::str::FromStr for LoadTableResult {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing LoadTableResult".to_string(),
+                        "Missing value while parsing ListCatalogs200Response".to_string(),
                     )
                 }
             };
@@ -4185,26 +4370,8 @@ impl std::str::FromStr for LoadTableResult {
             if let Some(key) = key_result {
                 #[allow(clippy::match_single_binding)]
                 match key {
-                    #[allow(clippy::redundant_clone)]
-                    "metadata-location" => intermediate_rep.metadata_location.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "metadata" => intermediate_rep.metadata.push(
-                        <models::TableMetadata as std::str::FromStr>::from_str(val)
-                            .map_err(|x| x.to_string())?,
-                    ),
-                    "config" => {
-                        return std::result::Result::Err(
-                            "Parsing a container in this style is not supported in LoadTableResult"
-                                .to_string(),
-                        )
-                    }
-                    _ => {
-                        return std::result::Result::Err(
-                            "Unexpected key while parsing LoadTableResult".to_string(),
-                        )
-                    }
+                    "catalogs" => return std::result::Result::Err("Parsing a container in this style is not supported in ListCatalogs200Response".to_string()),
+                    _ => return std::result::Result::Err("Unexpected key while parsing ListCatalogs200Response".to_string())
                 }
             }
 
@@ -4213,34 +4380,32 @@ impl std::str::FromStr for LoadTableResult {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(LoadTableResult {
-            metadata_location: intermediate_rep.metadata_location.into_iter().next(),
-            metadata: intermediate_rep
-                .metadata
+        std::result::Result::Ok(ListCatalogs200Response {
+            catalogs: intermediate_rep
+                .catalogs
                 .into_iter()
                 .next()
-                .ok_or_else(|| "metadata missing in LoadTableResult".to_string())?,
-            config: intermediate_rep.config.into_iter().next(),
+                .ok_or_else(|| "catalogs missing in ListCatalogs200Response".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<LoadTableResult> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<ListCatalogs200Response> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<LoadTableResult>>
+impl std::convert::TryFrom<header::IntoHeaderValue<ListCatalogs200Response>>
     for hyper::header::HeaderValue
 {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<LoadTableResult>,
+        hdr_value: header::IntoHeaderValue<ListCatalogs200Response>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for LoadTableResult - value: {} is invalid {}",
+                "Invalid header value for ListCatalogs200Response - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -4249,19 +4414,19 @@ impl std::convert::TryFrom<header::IntoHeaderValue<LoadTableResult>>
 
 #[cfg(any(feature = "client", feature = "server"))]
 impl std::convert::TryFrom<hyper::header::HeaderValue>
-    for header::IntoHeaderValue<LoadTableResult>
+    for header::IntoHeaderValue<ListCatalogs200Response>
 {
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <LoadTableResult as std::str::FromStr>::from_str(value) {
+                match <ListCatalogs200Response as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into LoadTableResult - {}",
+                        "Unable to convert header value '{}' into ListCatalogs200Response - {}",
                         value, err
                     )),
                 }
@@ -4276,74 +4441,35 @@ impl std::convert::TryFrom<hyper::header::HeaderValue>
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct MapType {
-    // Note: inline enums are not fully supported by openapi-generator
-    #[serde(rename = "type")]
-    pub r#type: String,
-
-    #[serde(rename = "key-id")]
-    pub key_id: isize,
-
-    #[serde(rename = "key")]
-    pub key: models::Type,
-
-    #[serde(rename = "value-id")]
-    pub value_id: isize,
-
-    #[serde(rename = "value")]
-    pub value: models::Type,
-
-    #[serde(rename = "value-required")]
-    pub value_required: bool,
+pub struct ListGrants200Response {
+    #[serde(rename = "grants")]
+    pub grants: Vec<models::Grant>,
 }
 
-impl MapType {
+impl ListGrants200Response {
     #[allow(clippy::new_without_default)]
-    pub fn new(
-        r#type: String,
-        key_id: isize,
-        key: models::Type,
-        value_id: isize,
-        value: models::Type,
-        value_required: bool,
-    ) -> MapType {
-        MapType {
-            r#type,
-            key_id,
-            key,
-            value_id,
-            value,
-            value_required,
-        }
+    pub fn new(grants: Vec<models::Grant>) -> ListGrants200Response {
+        ListGrants200Response { grants }
     }
 }
 
-/// Converts the MapType value to the Query Parameters representation (style=form, explode=false)
+/// Converts the ListGrants200Response value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for MapType {
+impl std::string::ToString for ListGrants200Response {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
-            Some("type".to_string()),
-            Some(self.r#type.to_string()),
-            Some("key-id".to_string()),
-            Some(self.key_id.to_string()),
-            // Skipping key in query parameter serialization
-            Some("value-id".to_string()),
-            Some(self.value_id.to_string()),
-            // Skipping value in query parameter serialization
-            Some("value-required".to_string()),
-            Some(self.value_required.to_string()),
+            // Skipping grants in query parameter serialization
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a MapType value
+/// Converts Query Parameters representation (style=form, explode=false) to a ListGrants200Response value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for MapType {
+impl std::str::FromStr for ListGrants200Response {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -4351,12 +4477,7 @@ impl std::str::FromStr for MapType {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub r#type: Vec<String>,
-            pub key_id: Vec<isize>,
-            pub key: Vec<models::Type>,
-            pub value_id: Vec<isize>,
-            pub value: Vec<models::Type>,
-            pub value_required: Vec<bool>,
+            pub grants: Vec<Vec<models::Grant>>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -4370,7 +4491,7 @@ impl std::str::FromStr for MapType {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing MapType".to_string(),
+                        "Missing value while parsing ListGrants200Response".to_string(),
                     )
                 }
             };
@@ -4378,37 +4499,8 @@ impl std::str::FromStr for MapType {
             if let Some(key) = key_result {
                 #[allow(clippy::match_single_binding)]
                 match key {
-                    #[allow(clippy::redundant_clone)]
-                    "type" => intermediate_rep.r#type.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "key-id" => intermediate_rep.key_id.push(
-                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "key" => intermediate_rep.key.push(
-                        <models::Type as std::str::FromStr>::from_str(val)
-                            .map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "value-id" => intermediate_rep.value_id.push(
-                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "value" => intermediate_rep.value.push(
-                        <models::Type as std::str::FromStr>::from_str(val)
-                            .map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "value-required" => intermediate_rep.value_required.push(
-                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    _ => {
-                        return std::result::Result::Err(
-                            "Unexpected key while parsing MapType".to_string(),
-                        )
-                    }
+                    "grants" => return std::result::Result::Err("Parsing a container in this style is not supported in ListGrants200Response".to_string()),
+                    _ => return std::result::Result::Err("Unexpected key while parsing ListGrants200Response".to_string())
                 }
             }
 
@@ -4417,55 +4509,32 @@ impl std::str::FromStr for MapType {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(MapType {
-            r#type: intermediate_rep
-                .r#type
-                .into_iter()
-                .next()
-                .ok_or_else(|| "type missing in MapType".to_string())?,
-            key_id: intermediate_rep
-                .key_id
-                .into_iter()
-                .next()
-                .ok_or_else(|| "key-id missing in MapType".to_string())?,
-            key: intermediate_rep
-                .key
-                .into_iter()
-                .next()
-                .ok_or_else(|| "key missing in MapType".to_string())?,
-            value_id: intermediate_rep
-                .value_id
-                .into_iter()
-                .next()
-                .ok_or_else(|| "value-id missing in MapType".to_string())?,
-            value: intermediate_rep
-                .value
-                .into_iter()
-                .next()
-                .ok_or_else(|| "value missing in MapType".to_string())?,
-            value_required: intermediate_rep
-                .value_required
+        std::result::Result::Ok(ListGrants200Response {
+            grants: intermediate_rep
+                .grants
                 .into_iter()
                 .next()
-                .ok_or_else(|| "value-required missing in MapType".to_string())?,
+                .ok_or_else(|| "grants missing in ListGrants200Response".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<MapType> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<ListGrants200Response> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<MapType>> for hyper::header::HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<ListGrants200Response>>
+    for hyper::header::HeaderValue
+{
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<MapType>,
+        hdr_value: header::IntoHeaderValue<ListGrants200Response>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for MapType - value: {} is invalid {}",
+                "Invalid header value for ListGrants200Response - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -4473,18 +4542,20 @@ impl std::convert::TryFrom<header::IntoHeaderValue<MapType>> for hyper::header::
 }
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<MapType> {
+impl std::convert::TryFrom<hyper::header::HeaderValue>
+    for header::IntoHeaderValue<ListGrants200Response>
+{
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <MapType as std::str::FromStr>::from_str(value) {
+                match <ListGrants200Response as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into MapType - {}",
+                        "Unable to convert header value '{}' into ListGrants200Response - {}",
                         value, err
                     )),
                 }
@@ -4499,44 +4570,37 @@ impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderVal
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct MetadataLogInner {
-    #[serde(rename = "metadata-file")]
-    pub metadata_file: String,
-
-    #[serde(rename = "timestamp-ms")]
-    pub timestamp_ms: isize,
+pub struct ListNamespaces200Response {
+    #[serde(rename = "namespaces")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespaces: Option<Vec<models::Namespace>>,
 }
 
-impl MetadataLogInner {
+impl ListNamespaces200Response {
     #[allow(clippy::new_without_default)]
-    pub fn new(metadata_file: String, timestamp_ms: isize) -> MetadataLogInner {
-        MetadataLogInner {
-            metadata_file,
-            timestamp_ms,
-        }
+    pub fn new() -> ListNamespaces200Response {
+        ListNamespaces200Response { namespaces: None }
     }
 }
 
-/// Converts the MetadataLogInner value to the Query Parameters representation (style=form, explode=false)
+/// Converts the ListNamespaces200Response value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for MetadataLogInner {
+impl std::string::ToString for ListNamespaces200Response {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
-            Some("metadata-file".to_string()),
-            Some(self.metadata_file.to_string()),
-            Some("timestamp-ms".to_string()),
-            Some(self.timestamp_ms.to_string()),
+            // Skipping namespaces in query parameter serialization
+
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a MetadataLogInner value
+/// Converts Query Parameters representation (style=form, explode=false) to a ListNamespaces200Response value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for MetadataLogInner {
+impl std::str::FromStr for ListNamespaces200Response {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -4544,8 +4608,7 @@ impl std::str::FromStr for MetadataLogInner {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub metadata_file: Vec<String>,
-            pub timestamp_ms: Vec<isize>,
+            pub namespaces: Vec<Vec<models::Namespace>>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -4559,7 +4622,7 @@ impl std::str::FromStr for MetadataLogInner {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing MetadataLogInner".to_string(),
+                        "Missing value while parsing ListNamespaces200Response".to_string(),
                     )
                 }
             };
@@ -4567,19 +4630,8 @@ impl std::str::FromStr for MetadataLogInner {
             if let Some(key) = key_result {
                 #[allow(clippy::match_single_binding)]
                 match key {
-                    #[allow(clippy::redundant_clone)]
-                    "metadata-file" => intermediate_rep.metadata_file.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "timestamp-ms" => intermediate_rep.timestamp_ms.push(
-                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    _ => {
-                        return std::result::Result::Err(
-                            "Unexpected key while parsing MetadataLogInner".to_string(),
-                        )
-                    }
+                    "namespaces" => return std::result::Result::Err("Parsing a container in this style is not supported in ListNamespaces200Response".to_string()),
+                    _ => return std::result::Result::Err("Unexpected key while parsing ListNamespaces200Response".to_string())
                 }
             }
 
@@ -4588,37 +4640,28 @@ impl std::str::FromStr for MetadataLogInner {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(MetadataLogInner {
-            metadata_file: intermediate_rep
-                .metadata_file
-                .into_iter()
-                .next()
-                .ok_or_else(|| "metadata-file missing in MetadataLogInner".to_string())?,
-            timestamp_ms: intermediate_rep
-                .timestamp_ms
-                .into_iter()
-                .next()
-                .ok_or_else(|| "timestamp-ms missing in MetadataLogInner".to_string())?,
+        std::result::Result::Ok(ListNamespaces200Response {
+            namespaces: intermediate_rep.namespaces.into_iter().next(),
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<MetadataLogInner> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<ListNamespaces200Response> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<MetadataLogInner>>
+impl std::convert::TryFrom<header::IntoHeaderValue<ListNamespaces200Response>>
     for hyper::header::HeaderValue
 {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<MetadataLogInner>,
+        hdr_value: header::IntoHeaderValue<ListNamespaces200Response>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for MetadataLogInner - value: {} is invalid {}",
+                "Invalid header value for ListNamespaces200Response - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -4627,19 +4670,19 @@ impl std::convert::TryFrom<header::IntoHeaderValue<MetadataLogInner>>
 
 #[cfg(any(feature = "client", feature = "server"))]
 impl std::convert::TryFrom<hyper::header::HeaderValue>
-    for header::IntoHeaderValue<MetadataLogInner>
+    for header::IntoHeaderValue<ListNamespaces200Response>
 {
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <MetadataLogInner as std::str::FromStr>::from_str(value) {
+                match <ListNamespaces200Response as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into MetadataLogInner - {}",
+                        "Unable to convert header value '{}' into ListNamespaces200Response - {}",
                         value, err
                     )),
                 }
@@ -4654,68 +4697,35 @@ impl std::convert::TryFrom<hyper::header::HeaderValue>
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct MetricResult {
-    #[serde(rename = "unit")]
-    pub unit: String,
-
-    #[serde(rename = "value")]
-    pub value: i64,
-
-    #[serde(rename = "time-unit")]
-    pub time_unit: String,
-
-    #[serde(rename = "count")]
-    pub count: i64,
-
-    #[serde(rename = "total-duration")]
-    pub total_duration: i64,
+pub struct ListRoles200Response {
+    #[serde(rename = "roles")]
+    pub roles: Vec<models::Role>,
 }
 
-impl MetricResult {
+impl ListRoles200Response {
     #[allow(clippy::new_without_default)]
-    pub fn new(
-        unit: String,
-        value: i64,
-        time_unit: String,
-        count: i64,
-        total_duration: i64,
-    ) -> MetricResult {
-        MetricResult {
-            unit,
-            value,
-            time_unit,
-            count,
-            total_duration,
-        }
+    pub fn new(roles: Vec<models::Role>) -> ListRoles200Response {
+        ListRoles200Response { roles }
     }
 }
 
-/// Converts the MetricResult value to the Query Parameters representation (style=form, explode=false)
+/// Converts the ListRoles200Response value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for MetricResult {
+impl std::string::ToString for ListRoles200Response {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
-            Some("unit".to_string()),
-            Some(self.unit.to_string()),
-            Some("value".to_string()),
-            Some(self.value.to_string()),
-            Some("time-unit".to_string()),
-            Some(self.time_unit.to_string()),
-            Some("count".to_string()),
-            Some(self.count.to_string()),
-            Some("total-duration".to_string()),
-            Some(self.total_duration.to_string()),
+            // Skipping roles in query parameter serialization
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a MetricResult value
+/// Converts Query Parameters representation (style=form, explode=false) to a ListRoles200Response value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for MetricResult {
+impl std::str::FromStr for ListRoles200Response {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -4723,11 +4733,7 @@ impl std::str::FromStr for MetricResult {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub unit: Vec<String>,
-            pub value: Vec<i64>,
-            pub time_unit: Vec<String>,
-            pub count: Vec<i64>,
-            pub total_duration: Vec<i64>,
+            pub roles: Vec<Vec<models::Role>>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -4741,7 +4747,7 @@ impl std::str::FromStr for MetricResult {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing MetricResult".to_string(),
+                        "Missing value while parsing ListRoles200Response".to_string(),
                     )
                 }
             };
@@ -4749,31 +4755,8 @@ impl std::str::FromStr for MetricResult {
             if let Some(key) = key_result {
                 #[allow(clippy::match_single_binding)]
                 match key {
-                    #[allow(clippy::redundant_clone)]
-                    "unit" => intermediate_rep.unit.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "value" => intermediate_rep.value.push(
-                        <i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "time-unit" => intermediate_rep.time_unit.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "count" => intermediate_rep.count.push(
-                        <i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "total-duration" => intermediate_rep.total_duration.push(
-                        <i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    _ => {
-                        return std::result::Result::Err(
-                            "Unexpected key while parsing MetricResult".to_string(),
-                        )
-                    }
+                    "roles" => return std::result::Result::Err("Parsing a container in this style is not supported in ListRoles200Response".to_string()),
+                    _ => return std::result::Result::Err("Unexpected key while parsing ListRoles200Response".to_string())
                 }
             }
 
@@ -4782,50 +4765,32 @@ impl std::str::FromStr for MetricResult {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(MetricResult {
-            unit: intermediate_rep
-                .unit
-                .into_iter()
-                .next()
-                .ok_or_else(|| "unit missing in MetricResult".to_string())?,
-            value: intermediate_rep
-                .value
-                .into_iter()
-                .next()
-                .ok_or_else(|| "value missing in MetricResult".to_string())?,
-            time_unit: intermediate_rep
-                .time_unit
-                .into_iter()
-                .next()
-                .ok_or_else(|| "time-unit missing in MetricResult".to_string())?,
-            count: intermediate_rep
-                .count
-                .into_iter()
-                .next()
-                .ok_or_else(|| "count missing in MetricResult".to_string())?,
-            total_duration: intermediate_rep
-                .total_duration
+        std::result::Result::Ok(ListRoles200Response {
+            roles: intermediate_rep
+                .roles
                 .into_iter()
                 .next()
-                .ok_or_else(|| "total-duration missing in MetricResult".to_string())?,
+                .ok_or_else(|| "roles missing in ListRoles200Response".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<MetricResult> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<ListRoles200Response> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<MetricResult>> for hyper::header::HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<ListRoles200Response>>
+    for hyper::header::HeaderValue
+{
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<MetricResult>,
+        hdr_value: header::IntoHeaderValue<ListRoles200Response>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for MetricResult - value: {} is invalid {}",
+                "Invalid header value for ListRoles200Response - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -4833,18 +4798,20 @@ impl std::convert::TryFrom<header::IntoHeaderValue<MetricResult>> for hyper::hea
 }
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<MetricResult> {
+impl std::convert::TryFrom<hyper::header::HeaderValue>
+    for header::IntoHeaderValue<ListRoles200Response>
+{
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <MetricResult as std::str::FromStr>::from_str(value) {
+                match <ListRoles200Response as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into MetricResult - {}",
+                        "Unable to convert header value '{}' into ListRoles200Response - {}",
                         value, err
                     )),
                 }
@@ -4859,40 +4826,46 @@ impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderVal
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct NotExpression {
-    #[serde(rename = "type")]
-    pub r#type: String,
+pub struct ListTableCommits200Response {
+    #[serde(rename = "commits")]
+    pub commits: Vec<models::TableCommit>,
 
-    #[serde(rename = "child")]
-    pub child: models::Expression,
+    /// Token to get the next page, missing on the last page
+    #[serde(rename = "next-page-token")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_page_token: Option<String>,
 }
 
-impl NotExpression {
+impl ListTableCommits200Response {
     #[allow(clippy::new_without_default)]
-    pub fn new(r#type: String, child: models::Expression) -> NotExpression {
-        NotExpression { r#type, child }
+    pub fn new(commits: Vec<models::TableCommit>) -> ListTableCommits200Response {
+        ListTableCommits200Response {
+            commits,
+            next_page_token: None,
+        }
     }
 }
 
-/// Converts the NotExpression value to the Query Parameters representation (style=form, explode=false)
+/// Converts the ListTableCommits200Response value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for NotExpression {
+impl std::string::ToString for ListTableCommits200Response {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
-            Some("type".to_string()),
-            Some(self.r#type.to_string()),
-            // Skipping child in query parameter serialization
+            // Skipping commits in query parameter serialization
+            self.next_page_token.as_ref().map(|next_page_token| {
+                vec!["next-page-token".to_string(), next_page_token.to_string()].join(",")
+            }),
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a NotExpression value
+/// Converts Query Parameters representation (style=form, explode=false) to a ListTableCommits200Response value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for NotExpression {
+impl std::str::FromStr for ListTableCommits200Response {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -4900,8 +4873,8 @@ impl std::str::FromStr for NotExpression {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub r#type: Vec<String>,
-            pub child: Vec<models::Expression>,
+            pub commits: Vec<Vec<models::TableCommit>>,
+            pub next_page_token: Vec<String>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -4915,7 +4888,7 @@ impl std::str::FromStr for NotExpression {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing NotExpression".to_string(),
+                        "Missing value while parsing ListTableCommits200Response".to_string(),
                     )
                 }
             };
@@ -4923,20 +4896,10 @@ impl std::str::FromStr for NotExpression {
             if let Some(key) = key_result {
                 #[allow(clippy::match_single_binding)]
                 match key {
+                    "commits" => return std::result::Result::Err("Parsing a container in this style is not supported in ListTableCommits200Response".to_string()),
                     #[allow(clippy::redundant_clone)]
-                    "type" => intermediate_rep.r#type.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "child" => intermediate_rep.child.push(
-                        <models::Expression as std::str::FromStr>::from_str(val)
-                            .map_err(|x| x.to_string())?,
-                    ),
-                    _ => {
-                        return std::result::Result::Err(
-                            "Unexpected key while parsing NotExpression".to_string(),
-                        )
-                    }
+                    "next-page-token" => intermediate_rep.next_page_token.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing ListTableCommits200Response".to_string())
                 }
             }
 
@@ -4945,35 +4908,33 @@ impl std::str::FromStr for NotExpression {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(NotExpression {
-            r#type: intermediate_rep
-                .r#type
-                .into_iter()
-                .next()
-                .ok_or_else(|| "type missing in NotExpression".to_string())?,
-            child: intermediate_rep
-                .child
+        std::result::Result::Ok(ListTableCommits200Response {
+            commits: intermediate_rep
+                .commits
                 .into_iter()
                 .next()
-                .ok_or_else(|| "child missing in NotExpression".to_string())?,
+                .ok_or_else(|| "commits missing in ListTableCommits200Response".to_string())?,
+            next_page_token: intermediate_rep.next_page_token.into_iter().next(),
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<NotExpression> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<ListTableCommits200Response> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<NotExpression>> for hyper::header::HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<ListTableCommits200Response>>
+    for hyper::header::HeaderValue
+{
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<NotExpression>,
+        hdr_value: header::IntoHeaderValue<ListTableCommits200Response>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for NotExpression - value: {} is invalid {}",
+                "Invalid header value for ListTableCommits200Response - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -4981,18 +4942,20 @@ impl std::convert::TryFrom<header::IntoHeaderValue<NotExpression>> for hyper::he
 }
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<NotExpression> {
+impl std::convert::TryFrom<hyper::header::HeaderValue>
+    for header::IntoHeaderValue<ListTableCommits200Response>
+{
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <NotExpression as std::str::FromStr>::from_str(value) {
+                match <ListTableCommits200Response as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into NotExpression - {}",
+                        "Unable to convert header value '{}' into ListTableCommits200Response - {}",
                         value, err
                     )),
                 }
@@ -5005,97 +4968,38 @@ impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderVal
     }
 }
 
-/// Enumeration of values.
-/// Since this enum's variants do not hold data, we can easily define them them as `#[repr(C)]`
-/// which helps with FFI.
-#[allow(non_camel_case_types)]
-#[repr(C)]
-#[derive(
-    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
-)]
-#[cfg_attr(feature = "conversion", derive(frunk_enum_derive::LabelledGenericEnum))]
-pub enum NullOrder {
-    #[serde(rename = "nulls-first")]
-    First,
-    #[serde(rename = "nulls-last")]
-    Last,
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct ListTableMetrics200Response {
+    #[serde(rename = "tables")]
+    pub tables: Vec<models::TableMetrics>,
 }
 
-impl std::fmt::Display for NullOrder {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match *self {
-            NullOrder::First => write!(f, "nulls-first"),
-            NullOrder::Last => write!(f, "nulls-last"),
-        }
+impl ListTableMetrics200Response {
+    #[allow(clippy::new_without_default)]
+    pub fn new(tables: Vec<models::TableMetrics>) -> ListTableMetrics200Response {
+        ListTableMetrics200Response { tables }
     }
 }
 
-impl std::str::FromStr for NullOrder {
-    type Err = String;
+/// Converts the ListTableMetrics200Response value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for ListTableMetrics200Response {
+    fn to_string(&self) -> String {
+        let params: Vec<Option<String>> = vec![
+            // Skipping tables in query parameter serialization
 
-    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
-        match s {
-            "nulls-first" => std::result::Result::Ok(NullOrder::First),
-            "nulls-last" => std::result::Result::Ok(NullOrder::Last),
-            _ => std::result::Result::Err(format!("Value not valid: {}", s)),
-        }
+        ];
+
+        params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct PartitionField {
-    #[serde(rename = "field-id")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub field_id: Option<isize>,
-
-    #[serde(rename = "source-id")]
-    pub source_id: isize,
-
-    #[serde(rename = "name")]
-    pub name: String,
-
-    #[serde(rename = "transform")]
-    pub transform: String,
-}
-
-impl PartitionField {
-    #[allow(clippy::new_without_default)]
-    pub fn new(source_id: isize, name: String, transform: String) -> PartitionField {
-        PartitionField {
-            field_id: None,
-            source_id,
-            name,
-            transform,
-        }
-    }
-}
-
-/// Converts the PartitionField value to the Query Parameters representation (style=form, explode=false)
-/// specified in https://swagger.io/docs/specification/serialization/
-/// Should be implemented in a serde serializer
-impl std::string::ToString for PartitionField {
-    fn to_string(&self) -> String {
-        let params: Vec<Option<String>> = vec![
-            self.field_id
-                .as_ref()
-                .map(|field_id| vec!["field-id".to_string(), field_id.to_string()].join(",")),
-            Some("source-id".to_string()),
-            Some(self.source_id.to_string()),
-            Some("name".to_string()),
-            Some(self.name.to_string()),
-            Some("transform".to_string()),
-            Some(self.transform.to_string()),
-        ];
-
-        params.into_iter().flatten().collect::<Vec<_>>().join(",")
-    }
-}
-
-/// Converts Query Parameters representation (style=form, explode=false) to a PartitionField value
+/// Converts Query Parameters representation (style=form, explode=false) to a ListTableMetrics200Response value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for PartitionField {
+impl std::str::FromStr for ListTableMetrics200Response {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -5103,10 +5007,7 @@ impl std::str::FromStr for PartitionField {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub field_id: Vec<isize>,
-            pub source_id: Vec<isize>,
-            pub name: Vec<String>,
-            pub transform: Vec<String>,
+            pub tables: Vec<Vec<models::TableMetrics>>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -5120,7 +5021,7 @@ impl std::str::FromStr for PartitionField {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing PartitionField".to_string(),
+                        "Missing value while parsing ListTableMetrics200Response".to_string(),
                     )
                 }
             };
@@ -5128,27 +5029,8 @@ impl std::str::FromStr for PartitionField {
             if let Some(key) = key_result {
                 #[allow(clippy::match_single_binding)]
                 match key {
-                    #[allow(clippy::redundant_clone)]
-                    "field-id" => intermediate_rep.field_id.push(
-                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "source-id" => intermediate_rep.source_id.push(
-                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "name" => intermediate_rep.name.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "transform" => intermediate_rep.transform.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    _ => {
-                        return std::result::Result::Err(
-                            "Unexpected key while parsing PartitionField".to_string(),
-                        )
-                    }
+                    "tables" => return std::result::Result::Err("Parsing a container in this style is not supported in ListTableMetrics200Response".to_string()),
+                    _ => return std::result::Result::Err("Unexpected key while parsing ListTableMetrics200Response".to_string())
                 }
             }
 
@@ -5157,41 +5039,32 @@ impl std::str::FromStr for PartitionField {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(PartitionField {
-            field_id: intermediate_rep.field_id.into_iter().next(),
-            source_id: intermediate_rep
-                .source_id
-                .into_iter()
-                .next()
-                .ok_or_else(|| "source-id missing in PartitionField".to_string())?,
-            name: intermediate_rep
-                .name
-                .into_iter()
-                .next()
-                .ok_or_else(|| "name missing in PartitionField".to_string())?,
-            transform: intermediate_rep
-                .transform
+        std::result::Result::Ok(ListTableMetrics200Response {
+            tables: intermediate_rep
+                .tables
                 .into_iter()
                 .next()
-                .ok_or_else(|| "transform missing in PartitionField".to_string())?,
+                .ok_or_else(|| "tables missing in ListTableMetrics200Response".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<PartitionField> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<ListTableMetrics200Response> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<PartitionField>> for hyper::header::HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<ListTableMetrics200Response>>
+    for hyper::header::HeaderValue
+{
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<PartitionField>,
+        hdr_value: header::IntoHeaderValue<ListTableMetrics200Response>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for PartitionField - value: {} is invalid {}",
+                "Invalid header value for ListTableMetrics200Response - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -5199,18 +5072,20 @@ impl std::convert::TryFrom<header::IntoHeaderValue<PartitionField>> for hyper::h
 }
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<PartitionField> {
+impl std::convert::TryFrom<hyper::header::HeaderValue>
+    for header::IntoHeaderValue<ListTableMetrics200Response>
+{
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <PartitionField as std::str::FromStr>::from_str(value) {
+                match <ListTableMetrics200Response as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into PartitionField - {}",
+                        "Unable to convert header value '{}' into ListTableMetrics200Response - {}",
                         value, err
                     )),
                 }
@@ -5225,45 +5100,37 @@ impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderVal
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct PartitionSpec {
-    #[serde(rename = "spec-id")]
+pub struct ListTables200Response {
+    #[serde(rename = "identifiers")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub spec_id: Option<isize>,
-
-    #[serde(rename = "fields")]
-    pub fields: Vec<models::PartitionField>,
+    pub identifiers: Option<Vec<models::TableIdentifier>>,
 }
 
-impl PartitionSpec {
+impl ListTables200Response {
     #[allow(clippy::new_without_default)]
-    pub fn new(fields: Vec<models::PartitionField>) -> PartitionSpec {
-        PartitionSpec {
-            spec_id: None,
-            fields,
-        }
+    pub fn new() -> ListTables200Response {
+        ListTables200Response { identifiers: None }
     }
 }
 
-/// Converts the PartitionSpec value to the Query Parameters representation (style=form, explode=false)
+/// Converts the ListTables200Response value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for PartitionSpec {
+impl std::string::ToString for ListTables200Response {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
-            self.spec_id
-                .as_ref()
-                .map(|spec_id| vec!["spec-id".to_string(), spec_id.to_string()].join(",")),
-            // Skipping fields in query parameter serialization
+            // Skipping identifiers in query parameter serialization
+
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a PartitionSpec value
+/// Converts Query Parameters representation (style=form, explode=false) to a ListTables200Response value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for PartitionSpec {
+impl std::str::FromStr for ListTables200Response {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -5271,8 +5138,7 @@ impl std::str::FromStr for PartitionSpec {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub spec_id: Vec<isize>,
-            pub fields: Vec<Vec<models::PartitionField>>,
+            pub identifiers: Vec<Vec<models::TableIdentifier>>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -5286,7 +5152,7 @@ impl std::str::FromStr for PartitionSpec {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing PartitionSpec".to_string(),
+                        "Missing value while parsing ListTables200Response".to_string(),
                     )
                 }
             };
@@ -5294,21 +5160,8 @@ impl std::str::FromStr for PartitionSpec {
             if let Some(key) = key_result {
                 #[allow(clippy::match_single_binding)]
                 match key {
-                    #[allow(clippy::redundant_clone)]
-                    "spec-id" => intermediate_rep.spec_id.push(
-                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    "fields" => {
-                        return std::result::Result::Err(
-                            "Parsing a container in this style is not supported in PartitionSpec"
-                                .to_string(),
-                        )
-                    }
-                    _ => {
-                        return std::result::Result::Err(
-                            "Unexpected key while parsing PartitionSpec".to_string(),
-                        )
-                    }
+                    "identifiers" => return std::result::Result::Err("Parsing a container in this style is not supported in ListTables200Response".to_string()),
+                    _ => return std::result::Result::Err("Unexpected key while parsing ListTables200Response".to_string())
                 }
             }
 
@@ -5317,31 +5170,28 @@ impl std::str::FromStr for PartitionSpec {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(PartitionSpec {
-            spec_id: intermediate_rep.spec_id.into_iter().next(),
-            fields: intermediate_rep
-                .fields
-                .into_iter()
-                .next()
-                .ok_or_else(|| "fields missing in PartitionSpec".to_string())?,
+        std::result::Result::Ok(ListTables200Response {
+            identifiers: intermediate_rep.identifiers.into_iter().next(),
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<PartitionSpec> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<ListTables200Response> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<PartitionSpec>> for hyper::header::HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<ListTables200Response>>
+    for hyper::header::HeaderValue
+{
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<PartitionSpec>,
+        hdr_value: header::IntoHeaderValue<ListTables200Response>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for PartitionSpec - value: {} is invalid {}",
+                "Invalid header value for ListTables200Response - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -5349,18 +5199,20 @@ impl std::convert::TryFrom<header::IntoHeaderValue<PartitionSpec>> for hyper::he
 }
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<PartitionSpec> {
+impl std::convert::TryFrom<hyper::header::HeaderValue>
+    for header::IntoHeaderValue<ListTables200Response>
+{
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <PartitionSpec as std::str::FromStr>::from_str(value) {
+                match <ListTables200Response as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into PartitionSpec - {}",
+                        "Unable to convert header value '{}' into ListTables200Response - {}",
                         value, err
                     )),
                 }
@@ -5373,143 +5225,74 @@ impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderVal
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct PrimitiveType(String);
+pub struct ListType {
+    // Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "type")]
+    pub r#type: String,
 
-impl std::convert::From<String> for PrimitiveType {
-    fn from(x: String) -> Self {
-        PrimitiveType(x)
-    }
-}
+    #[serde(rename = "element-id")]
+    pub element_id: isize,
 
-impl std::string::ToString for PrimitiveType {
-    fn to_string(&self) -> String {
-        self.0.to_string()
-    }
-}
+    #[serde(rename = "element")]
+    pub element: models::Type,
 
-impl std::str::FromStr for PrimitiveType {
-    type Err = std::string::ParseError;
-    fn from_str(x: &str) -> std::result::Result<Self, Self::Err> {
-        std::result::Result::Ok(PrimitiveType(x.to_string()))
-    }
+    #[serde(rename = "element-required")]
+    pub element_required: bool,
 }
 
-impl std::convert::From<PrimitiveType> for String {
-    fn from(x: PrimitiveType) -> Self {
-        x.0
+impl ListType {
+    #[allow(clippy::new_without_default)]
+    pub fn new(
+        r#type: String,
+        element_id: isize,
+        element: models::Type,
+        element_required: bool,
+    ) -> ListType {
+        ListType {
+            r#type,
+            element_id,
+            element,
+            element_required,
+        }
     }
 }
 
-impl std::ops::Deref for PrimitiveType {
-    type Target = String;
-    fn deref(&self) -> &String {
-        &self.0
-    }
-}
+/// Converts the ListType value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for ListType {
+    fn to_string(&self) -> String {
+        let params: Vec<Option<String>> = vec![
+            Some("type".to_string()),
+            Some(self.r#type.to_string()),
+            Some("element-id".to_string()),
+            Some(self.element_id.to_string()),
+            // Skipping element in query parameter serialization
+            Some("element-required".to_string()),
+            Some(self.element_required.to_string()),
+        ];
 
-impl std::ops::DerefMut for PrimitiveType {
-    fn deref_mut(&mut self) -> &mut String {
-        &mut self.0
+        params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, serde::Serialize, serde::Deserialize)]
-#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct Reference(String);
-
-impl std::convert::From<String> for Reference {
-    fn from(x: String) -> Self {
-        Reference(x)
-    }
-}
-
-impl std::string::ToString for Reference {
-    fn to_string(&self) -> String {
-        self.0.to_string()
-    }
-}
-
-impl std::str::FromStr for Reference {
-    type Err = std::string::ParseError;
-    fn from_str(x: &str) -> std::result::Result<Self, Self::Err> {
-        std::result::Result::Ok(Reference(x.to_string()))
-    }
-}
-
-impl std::convert::From<Reference> for String {
-    fn from(x: Reference) -> Self {
-        x.0
-    }
-}
-
-impl std::ops::Deref for Reference {
-    type Target = String;
-    fn deref(&self) -> &String {
-        &self.0
-    }
-}
-
-impl std::ops::DerefMut for Reference {
-    fn deref_mut(&mut self) -> &mut String {
-        &mut self.0
-    }
-}
-
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct RemovePropertiesUpdate {
-    // Note: inline enums are not fully supported by openapi-generator
-    #[serde(rename = "action")]
-    pub action: String,
-
-    #[serde(rename = "removals")]
-    pub removals: Vec<String>,
-}
-
-impl RemovePropertiesUpdate {
-    #[allow(clippy::new_without_default)]
-    pub fn new(action: String, removals: Vec<String>) -> RemovePropertiesUpdate {
-        RemovePropertiesUpdate { action, removals }
-    }
-}
-
-/// Converts the RemovePropertiesUpdate value to the Query Parameters representation (style=form, explode=false)
-/// specified in https://swagger.io/docs/specification/serialization/
-/// Should be implemented in a serde serializer
-impl std::string::ToString for RemovePropertiesUpdate {
-    fn to_string(&self) -> String {
-        let params: Vec<Option<String>> = vec![
-            Some("action".to_string()),
-            Some(self.action.to_string()),
-            Some("removals".to_string()),
-            Some(
-                self.removals
-                    .iter()
-                    .map(|x| x.to_string())
-                    .collect::<Vec<_>>()
-                    .join(","),
-            ),
-        ];
-
-        params.into_iter().flatten().collect::<Vec<_>>().join(",")
-    }
-}
-
-/// Converts Query Parameters representation (style=form, explode=false) to a RemovePropertiesUpdate value
-/// as specified in https://swagger.io/docs/specification/serialization/
-/// Should be implemented in a serde deserializer
-impl std::str::FromStr for RemovePropertiesUpdate {
-    type Err = String;
+/// Converts Query Parameters representation (style=form, explode=false) to a ListType value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for ListType {
+    type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
         /// An intermediate representation of the struct to use for parsing.
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub action: Vec<String>,
-            pub removals: Vec<Vec<String>>,
+            pub r#type: Vec<String>,
+            pub element_id: Vec<isize>,
+            pub element: Vec<models::Type>,
+            pub element_required: Vec<bool>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -5523,7 +5306,7 @@ impl std::str::FromStr for RemovePropertiesUpdate {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing RemovePropertiesUpdate".to_string(),
+                        "Missing value while parsing ListType".to_string(),
                     )
                 }
             };
@@ -5532,9 +5315,27 @@ impl std::str::FromStr for RemovePropertiesUpdate {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "action" => intermediate_rep.action.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
-                    "removals" => return std::result::Result::Err("Parsing a container in this style is not supported in RemovePropertiesUpdate".to_string()),
-                    _ => return std::result::Result::Err("Unexpected key while parsing RemovePropertiesUpdate".to_string())
+                    "type" => intermediate_rep.r#type.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "element-id" => intermediate_rep.element_id.push(
+                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "element" => intermediate_rep.element.push(
+                        <models::Type as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "element-required" => intermediate_rep.element_required.push(
+                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing ListType".to_string(),
+                        )
+                    }
                 }
             }
 
@@ -5543,37 +5344,45 @@ impl std::str::FromStr for RemovePropertiesUpdate {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(RemovePropertiesUpdate {
-            action: intermediate_rep
-                .action
+        std::result::Result::Ok(ListType {
+            r#type: intermediate_rep
+                .r#type
                 .into_iter()
                 .next()
-                .ok_or_else(|| "action missing in RemovePropertiesUpdate".to_string())?,
-            removals: intermediate_rep
-                .removals
+                .ok_or_else(|| "type missing in ListType".to_string())?,
+            element_id: intermediate_rep
+                .element_id
                 .into_iter()
                 .next()
-                .ok_or_else(|| "removals missing in RemovePropertiesUpdate".to_string())?,
+                .ok_or_else(|| "element-id missing in ListType".to_string())?,
+            element: intermediate_rep
+                .element
+                .into_iter()
+                .next()
+                .ok_or_else(|| "element missing in ListType".to_string())?,
+            element_required: intermediate_rep
+                .element_required
+                .into_iter()
+                .next()
+                .ok_or_else(|| "element-required missing in ListType".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<RemovePropertiesUpdate> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<ListType> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<RemovePropertiesUpdate>>
-    for hyper::header::HeaderValue
-{
+impl std::convert::TryFrom<header::IntoHeaderValue<ListType>> for hyper::header::HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<RemovePropertiesUpdate>,
+        hdr_value: header::IntoHeaderValue<ListType>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for RemovePropertiesUpdate - value: {} is invalid {}",
+                "Invalid header value for ListType - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -5581,20 +5390,18 @@ impl std::convert::TryFrom<header::IntoHeaderValue<RemovePropertiesUpdate>>
 }
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<hyper::header::HeaderValue>
-    for header::IntoHeaderValue<RemovePropertiesUpdate>
-{
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<ListType> {
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <RemovePropertiesUpdate as std::str::FromStr>::from_str(value) {
+                match <ListType as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into RemovePropertiesUpdate - {}",
+                        "Unable to convert header value '{}' into ListType - {}",
                         value, err
                     )),
                 }
@@ -5609,42 +5416,49 @@ impl std::convert::TryFrom<hyper::header::HeaderValue>
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct RemovePropertiesUpdateAllOf {
-    #[serde(rename = "removals")]
-    pub removals: Vec<String>,
+pub struct LiteralExpression {
+    #[serde(rename = "type")]
+    pub r#type: String,
+
+    #[serde(rename = "term")]
+    pub term: models::Term,
+
+    #[serde(rename = "value")]
+    pub value: serde_json::Value,
 }
 
-impl RemovePropertiesUpdateAllOf {
+impl LiteralExpression {
     #[allow(clippy::new_without_default)]
-    pub fn new(removals: Vec<String>) -> RemovePropertiesUpdateAllOf {
-        RemovePropertiesUpdateAllOf { removals }
+    pub fn new(r#type: String, term: models::Term, value: serde_json::Value) -> LiteralExpression {
+        LiteralExpression {
+            r#type,
+            term,
+            value,
+        }
     }
 }
 
-/// Converts the RemovePropertiesUpdateAllOf value to the Query Parameters representation (style=form, explode=false)
+/// Converts the LiteralExpression value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for RemovePropertiesUpdateAllOf {
+impl std::string::ToString for LiteralExpression {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
-            Some("removals".to_string()),
-            Some(
-                self.removals
-                    .iter()
-                    .map(|x| x.to_string())
-                    .collect::<Vec<_>>()
-                    .join(","),
-            ),
+            Some("type".to_string()),
+            Some(self.r#type.to_string()),
+            // Skipping term in query parameter serialization
+
+            // Skipping value in query parameter serialization
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a RemovePropertiesUpdateAllOf value
+/// Converts Query Parameters representation (style=form, explode=false) to a LiteralExpression value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for RemovePropertiesUpdateAllOf {
+impl std::str::FromStr for LiteralExpression {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -5652,7 +5466,9 @@ impl std::str::FromStr for RemovePropertiesUpdateAllOf {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub removals: Vec<Vec<String>>,
+            pub r#type: Vec<String>,
+            pub term: Vec<models::Term>,
+            pub value: Vec<serde_json::Value>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -5666,7 +5482,7 @@ impl std::str::FromStr for RemovePropertiesUpdateAllOf {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing RemovePropertiesUpdateAllOf".to_string(),
+                        "Missing value while parsing LiteralExpression".to_string(),
                     )
                 }
             };
@@ -5674,8 +5490,25 @@ impl std::str::FromStr for RemovePropertiesUpdateAllOf {
             if let Some(key) = key_result {
                 #[allow(clippy::match_single_binding)]
                 match key {
-                    "removals" => return std::result::Result::Err("Parsing a container in this style is not supported in RemovePropertiesUpdateAllOf".to_string()),
-                    _ => return std::result::Result::Err("Unexpected key while parsing RemovePropertiesUpdateAllOf".to_string())
+                    #[allow(clippy::redundant_clone)]
+                    "type" => intermediate_rep.r#type.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "term" => intermediate_rep.term.push(
+                        <models::Term as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "value" => intermediate_rep.value.push(
+                        <serde_json::Value as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing LiteralExpression".to_string(),
+                        )
+                    }
                 }
             }
 
@@ -5684,32 +5517,42 @@ impl std::str::FromStr for RemovePropertiesUpdateAllOf {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(RemovePropertiesUpdateAllOf {
-            removals: intermediate_rep
-                .removals
+        std::result::Result::Ok(LiteralExpression {
+            r#type: intermediate_rep
+                .r#type
                 .into_iter()
                 .next()
-                .ok_or_else(|| "removals missing in RemovePropertiesUpdateAllOf".to_string())?,
+                .ok_or_else(|| "type missing in LiteralExpression".to_string())?,
+            term: intermediate_rep
+                .term
+                .into_iter()
+                .next()
+                .ok_or_else(|| "term missing in LiteralExpression".to_string())?,
+            value: intermediate_rep
+                .value
+                .into_iter()
+                .next()
+                .ok_or_else(|| "value missing in LiteralExpression".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<RemovePropertiesUpdateAllOf> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<LiteralExpression> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<RemovePropertiesUpdateAllOf>>
+impl std::convert::TryFrom<header::IntoHeaderValue<LiteralExpression>>
     for hyper::header::HeaderValue
 {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<RemovePropertiesUpdateAllOf>,
+        hdr_value: header::IntoHeaderValue<LiteralExpression>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for RemovePropertiesUpdateAllOf - value: {} is invalid {}",
+                "Invalid header value for LiteralExpression - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -5718,19 +5561,19 @@ impl std::convert::TryFrom<header::IntoHeaderValue<RemovePropertiesUpdateAllOf>>
 
 #[cfg(any(feature = "client", feature = "server"))]
 impl std::convert::TryFrom<hyper::header::HeaderValue>
-    for header::IntoHeaderValue<RemovePropertiesUpdateAllOf>
+    for header::IntoHeaderValue<LiteralExpression>
 {
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <RemovePropertiesUpdateAllOf as std::str::FromStr>::from_str(value) {
+                match <LiteralExpression as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into RemovePropertiesUpdateAllOf - {}",
+                        "Unable to convert header value '{}' into LiteralExpression - {}",
                         value, err
                     )),
                 }
@@ -5745,42 +5588,52 @@ impl std::convert::TryFrom<hyper::header::HeaderValue>
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct RemoveSnapshotRefUpdate {
-    // Note: inline enums are not fully supported by openapi-generator
-    #[serde(rename = "action")]
-    pub action: String,
+pub struct LoadNamespaceMetadata200Response {
+    /// Reference to one or more levels of a namespace
+    #[serde(rename = "namespace")]
+    pub namespace: Vec<String>,
 
-    #[serde(rename = "ref-name")]
-    pub ref_name: String,
+    /// Properties stored on the namespace, if supported by the server. If the server does not support namespace properties, it should return null for this field. If namespace properties are supported, but none are set, it should return an empty object.
+    #[serde(rename = "properties")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub properties: Option<serde_json::Value>,
 }
 
-impl RemoveSnapshotRefUpdate {
+impl LoadNamespaceMetadata200Response {
     #[allow(clippy::new_without_default)]
-    pub fn new(action: String, ref_name: String) -> RemoveSnapshotRefUpdate {
-        RemoveSnapshotRefUpdate { action, ref_name }
+    pub fn new(namespace: Vec<String>) -> LoadNamespaceMetadata200Response {
+        LoadNamespaceMetadata200Response {
+            namespace,
+            properties: None,
+        }
     }
 }
 
-/// Converts the RemoveSnapshotRefUpdate value to the Query Parameters representation (style=form, explode=false)
+/// Converts the LoadNamespaceMetadata200Response value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for RemoveSnapshotRefUpdate {
+impl std::string::ToString for LoadNamespaceMetadata200Response {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
-            Some("action".to_string()),
-            Some(self.action.to_string()),
-            Some("ref-name".to_string()),
-            Some(self.ref_name.to_string()),
+            Some("namespace".to_string()),
+            Some(
+                self.namespace
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect::<Vec<_>>()
+                    .join(","),
+            ),
+            // Skipping properties in query parameter serialization
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a RemoveSnapshotRefUpdate value
+/// Converts Query Parameters representation (style=form, explode=false) to a LoadNamespaceMetadata200Response value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for RemoveSnapshotRefUpdate {
+impl std::str::FromStr for LoadNamespaceMetadata200Response {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -5788,8 +5641,8 @@ impl std::str::FromStr for RemoveSnapshotRefUpdate {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub action: Vec<String>,
-            pub ref_name: Vec<String>,
+            pub namespace: Vec<Vec<String>>,
+            pub properties: Vec<serde_json::Value>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -5803,7 +5656,7 @@ impl std::str::FromStr for RemoveSnapshotRefUpdate {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing RemoveSnapshotRefUpdate".to_string(),
+                        "Missing value while parsing LoadNamespaceMetadata200Response".to_string(),
                     )
                 }
             };
@@ -5811,19 +5664,10 @@ impl std::str::FromStr for RemoveSnapshotRefUpdate {
             if let Some(key) = key_result {
                 #[allow(clippy::match_single_binding)]
                 match key {
+                    "namespace" => return std::result::Result::Err("Parsing a container in this style is not supported in LoadNamespaceMetadata200Response".to_string()),
                     #[allow(clippy::redundant_clone)]
-                    "action" => intermediate_rep.action.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "ref-name" => intermediate_rep.ref_name.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    _ => {
-                        return std::result::Result::Err(
-                            "Unexpected key while parsing RemoveSnapshotRefUpdate".to_string(),
-                        )
-                    }
+                    "properties" => intermediate_rep.properties.push(<serde_json::Value as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing LoadNamespaceMetadata200Response".to_string())
                 }
             }
 
@@ -5832,117 +5676,117 @@ impl std::str::FromStr for RemoveSnapshotRefUpdate {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(RemoveSnapshotRefUpdate {
-            action: intermediate_rep
-                .action
-                .into_iter()
-                .next()
-                .ok_or_else(|| "action missing in RemoveSnapshotRefUpdate".to_string())?,
-            ref_name: intermediate_rep
-                .ref_name
+        std::result::Result::Ok(LoadNamespaceMetadata200Response {
+            namespace: intermediate_rep
+                .namespace
                 .into_iter()
                 .next()
-                .ok_or_else(|| "ref-name missing in RemoveSnapshotRefUpdate".to_string())?,
+                .ok_or_else(|| {
+                    "namespace missing in LoadNamespaceMetadata200Response".to_string()
+                })?,
+            properties: intermediate_rep.properties.into_iter().next(),
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<RemoveSnapshotRefUpdate> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<LoadNamespaceMetadata200Response> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<RemoveSnapshotRefUpdate>>
+impl std::convert::TryFrom<header::IntoHeaderValue<LoadNamespaceMetadata200Response>>
     for hyper::header::HeaderValue
 {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<RemoveSnapshotRefUpdate>,
+        hdr_value: header::IntoHeaderValue<LoadNamespaceMetadata200Response>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
-            std::result::Result::Ok(value) => std::result::Result::Ok(value),
-            std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for RemoveSnapshotRefUpdate - value: {} is invalid {}",
-                hdr_value, e
-            )),
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for LoadNamespaceMetadata200Response - value: {} is invalid {}",
+                     hdr_value, e))
         }
     }
 }
 
 #[cfg(any(feature = "client", feature = "server"))]
 impl std::convert::TryFrom<hyper::header::HeaderValue>
-    for header::IntoHeaderValue<RemoveSnapshotRefUpdate>
+    for header::IntoHeaderValue<LoadNamespaceMetadata200Response>
 {
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
-            std::result::Result::Ok(value) => {
-                match <RemoveSnapshotRefUpdate as std::str::FromStr>::from_str(value) {
-                    std::result::Result::Ok(value) => {
-                        std::result::Result::Ok(header::IntoHeaderValue(value))
+             std::result::Result::Ok(value) => {
+                    match <LoadNamespaceMetadata200Response as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into LoadNamespaceMetadata200Response - {}",
+                                value, err))
                     }
-                    std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into RemoveSnapshotRefUpdate - {}",
-                        value, err
-                    )),
-                }
-            }
-            std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Unable to convert header: {:?} to string: {}",
-                hdr_value, e
-            )),
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
         }
     }
 }
 
+/// Result used when a table is successfully loaded.  The table metadata JSON is returned in the `metadata` field. The corresponding file location of table metadata should be returned in the `metadata-location` field, unless the metadata is not yet committed. For example, a create transaction may return metadata that is staged but not committed. Clients can check whether metadata has changed by comparing metadata locations after the table has been created.  The `config` map returns table-specific configuration for the table's resources, including its HTTP client and FileIO. For example, config may contain a specific FileIO implementation class for the table depending on its underlying storage.
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct RemoveSnapshotsUpdate {
-    // Note: inline enums are not fully supported by openapi-generator
-    #[serde(rename = "action")]
-    pub action: String,
+pub struct LoadTableResult {
+    /// May be null if the table is staged as part of a transaction
+    #[serde(rename = "metadata-location")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata_location: Option<String>,
 
-    #[serde(rename = "snapshot-ids")]
-    pub snapshot_ids: Vec<i64>,
+    #[serde(rename = "metadata")]
+    pub metadata: models::TableMetadata,
+
+    #[serde(rename = "config")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub config: Option<std::collections::HashMap<String, String>>,
 }
 
-impl RemoveSnapshotsUpdate {
+impl LoadTableResult {
     #[allow(clippy::new_without_default)]
-    pub fn new(action: String, snapshot_ids: Vec<i64>) -> RemoveSnapshotsUpdate {
-        RemoveSnapshotsUpdate {
-            action,
-            snapshot_ids,
+    pub fn new(metadata: models::TableMetadata) -> LoadTableResult {
+        LoadTableResult {
+            metadata_location: None,
+            metadata,
+            config: None,
         }
     }
 }
 
-/// Converts the RemoveSnapshotsUpdate value to the Query Parameters representation (style=form, explode=false)
+/// Converts the LoadTableResult value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for RemoveSnapshotsUpdate {
+impl std::string::ToString for LoadTableResult {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
-            Some("action".to_string()),
-            Some(self.action.to_string()),
-            Some("snapshot-ids".to_string()),
-            Some(
-                self.snapshot_ids
-                    .iter()
-                    .map(|x| x.to_string())
-                    .collect::<Vec<_>>()
-                    .join(","),
-            ),
+            self.metadata_location.as_ref().map(|metadata_location| {
+                vec![
+                    "metadata-location".to_string(),
+                    metadata_location.to_string(),
+                ]
+                .join(",")
+            }),
+            // Skipping metadata in query parameter serialization
+
+            // Skipping config in query parameter serialization
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a RemoveSnapshotsUpdate value
+/// Converts Query Parameters representation (style=form, explode=false) to a LoadTableResult value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for RemoveSnapshotsUpdate {
+impl std::str::FromStr for LoadTableResult {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -5950,8 +5794,9 @@ impl std::str::FromStr for RemoveSnapshotsUpdate {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub action: Vec<String>,
-            pub snapshot_ids: Vec<Vec<i64>>,
+            pub metadata_location: Vec<String>,
+            pub metadata: Vec<models::TableMetadata>,
+            pub config: Vec<std::collections::HashMap<String, String>>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -5965,7 +5810,7 @@ impl std::str::FromStr for RemoveSnapshotsUpdate {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing RemoveSnapshotsUpdate".to_string(),
+                        "Missing value while parsing LoadTableResult".to_string(),
                     )
                 }
             };
@@ -5974,9 +5819,25 @@ impl std::str::FromStr for RemoveSnapshotsUpdate {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "action" => intermediate_rep.action.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
-                    "snapshot-ids" => return std::result::Result::Err("Parsing a container in this style is not supported in RemoveSnapshotsUpdate".to_string()),
-                    _ => return std::result::Result::Err("Unexpected key while parsing RemoveSnapshotsUpdate".to_string())
+                    "metadata-location" => intermediate_rep.metadata_location.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "metadata" => intermediate_rep.metadata.push(
+                        <models::TableMetadata as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    "config" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in LoadTableResult"
+                                .to_string(),
+                        )
+                    }
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing LoadTableResult".to_string(),
+                        )
+                    }
                 }
             }
 
@@ -5985,37 +5846,34 @@ impl std::str::FromStr for RemoveSnapshotsUpdate {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(RemoveSnapshotsUpdate {
-            action: intermediate_rep
-                .action
-                .into_iter()
-                .next()
-                .ok_or_else(|| "action missing in RemoveSnapshotsUpdate".to_string())?,
-            snapshot_ids: intermediate_rep
-                .snapshot_ids
+        std::result::Result::Ok(LoadTableResult {
+            metadata_location: intermediate_rep.metadata_location.into_iter().next(),
+            metadata: intermediate_rep
+                .metadata
                 .into_iter()
                 .next()
-                .ok_or_else(|| "snapshot-ids missing in RemoveSnapshotsUpdate".to_string())?,
+                .ok_or_else(|| "metadata missing in LoadTableResult".to_string())?,
+            config: intermediate_rep.config.into_iter().next(),
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<RemoveSnapshotsUpdate> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<LoadTableResult> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<RemoveSnapshotsUpdate>>
+impl std::convert::TryFrom<header::IntoHeaderValue<LoadTableResult>>
     for hyper::header::HeaderValue
 {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<RemoveSnapshotsUpdate>,
+        hdr_value: header::IntoHeaderValue<LoadTableResult>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for RemoveSnapshotsUpdate - value: {} is invalid {}",
+                "Invalid header value for LoadTableResult - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -6024,19 +5882,19 @@ impl std::convert::TryFrom<header::IntoHeaderValue<RemoveSnapshotsUpdate>>
 
 #[cfg(any(feature = "client", feature = "server"))]
 impl std::convert::TryFrom<hyper::header::HeaderValue>
-    for header::IntoHeaderValue<RemoveSnapshotsUpdate>
+    for header::IntoHeaderValue<LoadTableResult>
 {
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <RemoveSnapshotsUpdate as std::str::FromStr>::from_str(value) {
+                match <LoadTableResult as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into RemoveSnapshotsUpdate - {}",
+                        "Unable to convert header value '{}' into LoadTableResult - {}",
                         value, err
                     )),
                 }
@@ -6051,42 +5909,74 @@ impl std::convert::TryFrom<hyper::header::HeaderValue>
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct RemoveSnapshotsUpdateAllOf {
-    #[serde(rename = "snapshot-ids")]
-    pub snapshot_ids: Vec<i64>,
-}
+pub struct MapType {
+    // Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "type")]
+    pub r#type: String,
 
-impl RemoveSnapshotsUpdateAllOf {
-    #[allow(clippy::new_without_default)]
-    pub fn new(snapshot_ids: Vec<i64>) -> RemoveSnapshotsUpdateAllOf {
-        RemoveSnapshotsUpdateAllOf { snapshot_ids }
-    }
-}
+    #[serde(rename = "key-id")]
+    pub key_id: isize,
 
-/// Converts the RemoveSnapshotsUpdateAllOf value to the Query Parameters representation (style=form, explode=false)
+    #[serde(rename = "key")]
+    pub key: models::Type,
+
+    #[serde(rename = "value-id")]
+    pub value_id: isize,
+
+    #[serde(rename = "value")]
+    pub value: models::Type,
+
+    #[serde(rename = "value-required")]
+    pub value_required: bool,
+}
+
+impl MapType {
+    #[allow(clippy::new_without_default)]
+    pub fn new(
+        r#type: String,
+        key_id: isize,
+        key: models::Type,
+        value_id: isize,
+        value: models::Type,
+        value_required: bool,
+    ) -> MapType {
+        MapType {
+            r#type,
+            key_id,
+            key,
+            value_id,
+            value,
+            value_required,
+        }
+    }
+}
+
+/// Converts the MapType value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for RemoveSnapshotsUpdateAllOf {
+impl std::string::ToString for MapType {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
-            Some("snapshot-ids".to_string()),
-            Some(
-                self.snapshot_ids
-                    .iter()
-                    .map(|x| x.to_string())
-                    .collect::<Vec<_>>()
-                    .join(","),
-            ),
+            Some("type".to_string()),
+            Some(self.r#type.to_string()),
+            Some("key-id".to_string()),
+            Some(self.key_id.to_string()),
+            // Skipping key in query parameter serialization
+            Some("value-id".to_string()),
+            Some(self.value_id.to_string()),
+            // Skipping value in query parameter serialization
+            Some("value-required".to_string()),
+            Some(self.value_required.to_string()),
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a RemoveSnapshotsUpdateAllOf value
+/// Converts Query Parameters representation (style=form, explode=false) to a MapType value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for RemoveSnapshotsUpdateAllOf {
+impl std::str::FromStr for MapType {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -6094,7 +5984,12 @@ impl std::str::FromStr for RemoveSnapshotsUpdateAllOf {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub snapshot_ids: Vec<Vec<i64>>,
+            pub r#type: Vec<String>,
+            pub key_id: Vec<isize>,
+            pub key: Vec<models::Type>,
+            pub value_id: Vec<isize>,
+            pub value: Vec<models::Type>,
+            pub value_required: Vec<bool>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -6108,7 +6003,7 @@ impl std::str::FromStr for RemoveSnapshotsUpdateAllOf {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing RemoveSnapshotsUpdateAllOf".to_string(),
+                        "Missing value while parsing MapType".to_string(),
                     )
                 }
             };
@@ -6116,8 +6011,37 @@ impl std::str::FromStr for RemoveSnapshotsUpdateAllOf {
             if let Some(key) = key_result {
                 #[allow(clippy::match_single_binding)]
                 match key {
-                    "snapshot-ids" => return std::result::Result::Err("Parsing a container in this style is not supported in RemoveSnapshotsUpdateAllOf".to_string()),
-                    _ => return std::result::Result::Err("Unexpected key while parsing RemoveSnapshotsUpdateAllOf".to_string())
+                    #[allow(clippy::redundant_clone)]
+                    "type" => intermediate_rep.r#type.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "key-id" => intermediate_rep.key_id.push(
+                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "key" => intermediate_rep.key.push(
+                        <models::Type as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "value-id" => intermediate_rep.value_id.push(
+                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "value" => intermediate_rep.value.push(
+                        <models::Type as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "value-required" => intermediate_rep.value_required.push(
+                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing MapType".to_string(),
+                        )
+                    }
                 }
             }
 
@@ -6126,32 +6050,55 @@ impl std::str::FromStr for RemoveSnapshotsUpdateAllOf {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(RemoveSnapshotsUpdateAllOf {
-            snapshot_ids: intermediate_rep
-                .snapshot_ids
+        std::result::Result::Ok(MapType {
+            r#type: intermediate_rep
+                .r#type
                 .into_iter()
                 .next()
-                .ok_or_else(|| "snapshot-ids missing in RemoveSnapshotsUpdateAllOf".to_string())?,
+                .ok_or_else(|| "type missing in MapType".to_string())?,
+            key_id: intermediate_rep
+                .key_id
+                .into_iter()
+                .next()
+                .ok_or_else(|| "key-id missing in MapType".to_string())?,
+            key: intermediate_rep
+                .key
+                .into_iter()
+                .next()
+                .ok_or_else(|| "key missing in MapType".to_string())?,
+            value_id: intermediate_rep
+                .value_id
+                .into_iter()
+                .next()
+                .ok_or_else(|| "value-id missing in MapType".to_string())?,
+            value: intermediate_rep
+                .value
+                .into_iter()
+                .next()
+                .ok_or_else(|| "value missing in MapType".to_string())?,
+            value_required: intermediate_rep
+                .value_required
+                .into_iter()
+                .next()
+                .ok_or_else(|| "value-required missing in MapType".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<RemoveSnapshotsUpdateAllOf> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<MapType> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<RemoveSnapshotsUpdateAllOf>>
-    for hyper::header::HeaderValue
-{
+impl std::convert::TryFrom<header::IntoHeaderValue<MapType>> for hyper::header::HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<RemoveSnapshotsUpdateAllOf>,
+        hdr_value: header::IntoHeaderValue<MapType>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for RemoveSnapshotsUpdateAllOf - value: {} is invalid {}",
+                "Invalid header value for MapType - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -6159,20 +6106,18 @@ impl std::convert::TryFrom<header::IntoHeaderValue<RemoveSnapshotsUpdateAllOf>>
 }
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<hyper::header::HeaderValue>
-    for header::IntoHeaderValue<RemoveSnapshotsUpdateAllOf>
-{
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<MapType> {
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <RemoveSnapshotsUpdateAllOf as std::str::FromStr>::from_str(value) {
+                match <MapType as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into RemoveSnapshotsUpdateAllOf - {}",
+                        "Unable to convert header value '{}' into MapType - {}",
                         value, err
                     )),
                 }
@@ -6187,47 +6132,44 @@ impl std::convert::TryFrom<hyper::header::HeaderValue>
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct RenameTableRequest {
-    #[serde(rename = "source")]
-    pub source: models::TableIdentifier,
+pub struct MetadataLogInner {
+    #[serde(rename = "metadata-file")]
+    pub metadata_file: String,
 
-    #[serde(rename = "destination")]
-    pub destination: models::TableIdentifier,
+    #[serde(rename = "timestamp-ms")]
+    pub timestamp_ms: isize,
 }
 
-impl RenameTableRequest {
+impl MetadataLogInner {
     #[allow(clippy::new_without_default)]
-    pub fn new(
-        source: models::TableIdentifier,
-        destination: models::TableIdentifier,
-    ) -> RenameTableRequest {
-        RenameTableRequest {
-            source,
-            destination,
+    pub fn new(metadata_file: String, timestamp_ms: isize) -> MetadataLogInner {
+        MetadataLogInner {
+            metadata_file,
+            timestamp_ms,
         }
     }
 }
 
-/// Converts the RenameTableRequest value to the Query Parameters representation (style=form, explode=false)
+/// Converts the MetadataLogInner value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for RenameTableRequest {
+impl std::string::ToString for MetadataLogInner {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
-            // Skipping source in query parameter serialization
-
-            // Skipping destination in query parameter serialization
-
+            Some("metadata-file".to_string()),
+            Some(self.metadata_file.to_string()),
+            Some("timestamp-ms".to_string()),
+            Some(self.timestamp_ms.to_string()),
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a RenameTableRequest value
+/// Converts Query Parameters representation (style=form, explode=false) to a MetadataLogInner value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for RenameTableRequest {
+impl std::str::FromStr for MetadataLogInner {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -6235,8 +6177,8 @@ impl std::str::FromStr for RenameTableRequest {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub source: Vec<models::TableIdentifier>,
-            pub destination: Vec<models::TableIdentifier>,
+            pub metadata_file: Vec<String>,
+            pub timestamp_ms: Vec<isize>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -6250,7 +6192,7 @@ impl std::str::FromStr for RenameTableRequest {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing RenameTableRequest".to_string(),
+                        "Missing value while parsing MetadataLogInner".to_string(),
                     )
                 }
             };
@@ -6259,18 +6201,16 @@ impl std::str::FromStr for RenameTableRequest {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "source" => intermediate_rep.source.push(
-                        <models::TableIdentifier as std::str::FromStr>::from_str(val)
-                            .map_err(|x| x.to_string())?,
+                    "metadata-file" => intermediate_rep.metadata_file.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "destination" => intermediate_rep.destination.push(
-                        <models::TableIdentifier as std::str::FromStr>::from_str(val)
-                            .map_err(|x| x.to_string())?,
+                    "timestamp-ms" => intermediate_rep.timestamp_ms.push(
+                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing RenameTableRequest".to_string(),
+                            "Unexpected key while parsing MetadataLogInner".to_string(),
                         )
                     }
                 }
@@ -6281,37 +6221,37 @@ impl std::str::FromStr for RenameTableRequest {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(RenameTableRequest {
-            source: intermediate_rep
-                .source
+        std::result::Result::Ok(MetadataLogInner {
+            metadata_file: intermediate_rep
+                .metadata_file
                 .into_iter()
                 .next()
-                .ok_or_else(|| "source missing in RenameTableRequest".to_string())?,
-            destination: intermediate_rep
-                .destination
+                .ok_or_else(|| "metadata-file missing in MetadataLogInner".to_string())?,
+            timestamp_ms: intermediate_rep
+                .timestamp_ms
                 .into_iter()
                 .next()
-                .ok_or_else(|| "destination missing in RenameTableRequest".to_string())?,
+                .ok_or_else(|| "timestamp-ms missing in MetadataLogInner".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<RenameTableRequest> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<MetadataLogInner> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<RenameTableRequest>>
+impl std::convert::TryFrom<header::IntoHeaderValue<MetadataLogInner>>
     for hyper::header::HeaderValue
 {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<RenameTableRequest>,
+        hdr_value: header::IntoHeaderValue<MetadataLogInner>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for RenameTableRequest - value: {} is invalid {}",
+                "Invalid header value for MetadataLogInner - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -6320,19 +6260,19 @@ impl std::convert::TryFrom<header::IntoHeaderValue<RenameTableRequest>>
 
 #[cfg(any(feature = "client", feature = "server"))]
 impl std::convert::TryFrom<hyper::header::HeaderValue>
-    for header::IntoHeaderValue<RenameTableRequest>
+    for header::IntoHeaderValue<MetadataLogInner>
 {
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <RenameTableRequest as std::str::FromStr>::from_str(value) {
+                match <MetadataLogInner as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into RenameTableRequest - {}",
+                        "Unable to convert header value '{}' into MetadataLogInner - {}",
                         value, err
                     )),
                 }
@@ -6345,77 +6285,75 @@ impl std::convert::TryFrom<hyper::header::HeaderValue>
     }
 }
 
+/// A counter with `unit` and `value`, or a timer with `time-unit`, `count` and `total-duration`
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct ReportMetricsRequest {
-    #[serde(rename = "report-type")]
-    pub report_type: String,
+pub struct MetricResult {
+    #[serde(rename = "unit")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit: Option<String>,
 
-    #[serde(rename = "table-name")]
-    pub table_name: String,
+    #[serde(rename = "value")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<i64>,
 
-    #[serde(rename = "snapshot-id")]
-    pub snapshot_id: i64,
+    #[serde(rename = "time-unit")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_unit: Option<String>,
 
-    #[serde(rename = "filter")]
-    pub filter: models::Expression,
+    #[serde(rename = "count")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<i64>,
 
-    #[serde(rename = "projection")]
-    pub projection: models::Schema,
-
-    #[serde(rename = "metrics")]
-    pub metrics: std::collections::HashMap<String, models::MetricResult>,
+    #[serde(rename = "total-duration")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_duration: Option<i64>,
 }
 
-impl ReportMetricsRequest {
+impl MetricResult {
     #[allow(clippy::new_without_default)]
-    pub fn new(
-        report_type: String,
-        table_name: String,
-        snapshot_id: i64,
-        filter: models::Expression,
-        projection: models::Schema,
-        metrics: std::collections::HashMap<String, models::MetricResult>,
-    ) -> ReportMetricsRequest {
-        ReportMetricsRequest {
-            report_type,
-            table_name,
-            snapshot_id,
-            filter,
-            projection,
-            metrics,
+    pub fn new() -> MetricResult {
+        MetricResult {
+            unit: None,
+            value: None,
+            time_unit: None,
+            count: None,
+            total_duration: None,
         }
     }
 }
 
-/// Converts the ReportMetricsRequest value to the Query Parameters representation (style=form, explode=false)
+/// Converts the MetricResult value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for ReportMetricsRequest {
+impl std::string::ToString for MetricResult {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
-            Some("report-type".to_string()),
-            Some(self.report_type.to_string()),
-            Some("table-name".to_string()),
-            Some(self.table_name.to_string()),
-            Some("snapshot-id".to_string()),
-            Some(self.snapshot_id.to_string()),
-            // Skipping filter in query parameter serialization
-
-            // Skipping projection in query parameter serialization
-
-            // Skipping metrics in query parameter serialization
-            // Skipping metrics in query parameter serialization
+            self.unit
+                .as_ref()
+                .map(|unit| vec!["unit".to_string(), unit.to_string()].join(",")),
+            self.value
+                .as_ref()
+                .map(|value| vec!["value".to_string(), value.to_string()].join(",")),
+            self.time_unit
+                .as_ref()
+                .map(|time_unit| vec!["time-unit".to_string(), time_unit.to_string()].join(",")),
+            self.count
+                .as_ref()
+                .map(|count| vec!["count".to_string(), count.to_string()].join(",")),
+            self.total_duration.as_ref().map(|total_duration| {
+                vec!["total-duration".to_string(), total_duration.to_string()].join(",")
+            }),
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a ReportMetricsRequest value
+/// Converts Query Parameters representation (style=form, explode=false) to a MetricResult value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for ReportMetricsRequest {
+impl std::str::FromStr for MetricResult {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -6423,12 +6361,11 @@ impl std::str::FromStr for ReportMetricsRequest {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub report_type: Vec<String>,
-            pub table_name: Vec<String>,
-            pub snapshot_id: Vec<i64>,
-            pub filter: Vec<models::Expression>,
-            pub projection: Vec<models::Schema>,
-            pub metrics: Vec<std::collections::HashMap<String, models::MetricResult>>,
+            pub unit: Vec<String>,
+            pub value: Vec<i64>,
+            pub time_unit: Vec<String>,
+            pub count: Vec<i64>,
+            pub total_duration: Vec<i64>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -6442,7 +6379,7 @@ impl std::str::FromStr for ReportMetricsRequest {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing ReportMetricsRequest".to_string(),
+                        "Missing value while parsing MetricResult".to_string(),
                     )
                 }
             };
@@ -6451,17 +6388,30 @@ impl std::str::FromStr for ReportMetricsRequest {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "report-type" => intermediate_rep.report_type.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "unit" => intermediate_rep.unit.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
                     #[allow(clippy::redundant_clone)]
-                    "table-name" => intermediate_rep.table_name.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "value" => intermediate_rep.value.push(
+                        <i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
                     #[allow(clippy::redundant_clone)]
-                    "snapshot-id" => intermediate_rep.snapshot_id.push(<i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "time-unit" => intermediate_rep.time_unit.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
                     #[allow(clippy::redundant_clone)]
-                    "filter" => intermediate_rep.filter.push(<models::Expression as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "count" => intermediate_rep.count.push(
+                        <i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
                     #[allow(clippy::redundant_clone)]
-                    "projection" => intermediate_rep.projection.push(<models::Schema as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
-                    "metrics" => return std::result::Result::Err("Parsing a container in this style is not supported in ReportMetricsRequest".to_string()),
-                    _ => return std::result::Result::Err("Unexpected key while parsing ReportMetricsRequest".to_string())
+                    "total-duration" => intermediate_rep.total_duration.push(
+                        <i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing MetricResult".to_string(),
+                        )
+                    }
                 }
             }
 
@@ -6470,57 +6420,30 @@ impl std::str::FromStr for ReportMetricsRequest {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(ReportMetricsRequest {
-            report_type: intermediate_rep
-                .report_type
-                .into_iter()
-                .next()
-                .ok_or_else(|| "report-type missing in ReportMetricsRequest".to_string())?,
-            table_name: intermediate_rep
-                .table_name
-                .into_iter()
-                .next()
-                .ok_or_else(|| "table-name missing in ReportMetricsRequest".to_string())?,
-            snapshot_id: intermediate_rep
-                .snapshot_id
-                .into_iter()
-                .next()
-                .ok_or_else(|| "snapshot-id missing in ReportMetricsRequest".to_string())?,
-            filter: intermediate_rep
-                .filter
-                .into_iter()
-                .next()
-                .ok_or_else(|| "filter missing in ReportMetricsRequest".to_string())?,
-            projection: intermediate_rep
-                .projection
-                .into_iter()
-                .next()
-                .ok_or_else(|| "projection missing in ReportMetricsRequest".to_string())?,
-            metrics: intermediate_rep
-                .metrics
-                .into_iter()
-                .next()
-                .ok_or_else(|| "metrics missing in ReportMetricsRequest".to_string())?,
+        std::result::Result::Ok(MetricResult {
+            unit: intermediate_rep.unit.into_iter().next(),
+            value: intermediate_rep.value.into_iter().next(),
+            time_unit: intermediate_rep.time_unit.into_iter().next(),
+            count: intermediate_rep.count.into_iter().next(),
+            total_duration: intermediate_rep.total_duration.into_iter().next(),
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<ReportMetricsRequest> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<MetricResult> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<ReportMetricsRequest>>
-    for hyper::header::HeaderValue
-{
+impl std::convert::TryFrom<header::IntoHeaderValue<MetricResult>> for hyper::header::HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<ReportMetricsRequest>,
+        hdr_value: header::IntoHeaderValue<MetricResult>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for ReportMetricsRequest - value: {} is invalid {}",
+                "Invalid header value for MetricResult - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -6528,20 +6451,18 @@ impl std::convert::TryFrom<header::IntoHeaderValue<ReportMetricsRequest>>
 }
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<hyper::header::HeaderValue>
-    for header::IntoHeaderValue<ReportMetricsRequest>
-{
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<MetricResult> {
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <ReportMetricsRequest as std::str::FromStr>::from_str(value) {
+                match <MetricResult as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into ReportMetricsRequest - {}",
+                        "Unable to convert header value '{}' into MetricResult - {}",
                         value, err
                     )),
                 }
@@ -6556,68 +6477,40 @@ impl std::convert::TryFrom<hyper::header::HeaderValue>
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct ScanReport {
-    #[serde(rename = "table-name")]
-    pub table_name: String,
-
-    #[serde(rename = "snapshot-id")]
-    pub snapshot_id: i64,
-
-    #[serde(rename = "filter")]
-    pub filter: models::Expression,
-
-    #[serde(rename = "projection")]
-    pub projection: models::Schema,
+pub struct NotExpression {
+    #[serde(rename = "type")]
+    pub r#type: String,
 
-    #[serde(rename = "metrics")]
-    pub metrics: std::collections::HashMap<String, models::MetricResult>,
+    #[serde(rename = "child")]
+    pub child: models::Expression,
 }
 
-impl ScanReport {
+impl NotExpression {
     #[allow(clippy::new_without_default)]
-    pub fn new(
-        table_name: String,
-        snapshot_id: i64,
-        filter: models::Expression,
-        projection: models::Schema,
-        metrics: std::collections::HashMap<String, models::MetricResult>,
-    ) -> ScanReport {
-        ScanReport {
-            table_name,
-            snapshot_id,
-            filter,
-            projection,
-            metrics,
-        }
+    pub fn new(r#type: String, child: models::Expression) -> NotExpression {
+        NotExpression { r#type, child }
     }
 }
 
-/// Converts the ScanReport value to the Query Parameters representation (style=form, explode=false)
+/// Converts the NotExpression value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for ScanReport {
+impl std::string::ToString for NotExpression {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
-            Some("table-name".to_string()),
-            Some(self.table_name.to_string()),
-            Some("snapshot-id".to_string()),
-            Some(self.snapshot_id.to_string()),
-            // Skipping filter in query parameter serialization
-
-            // Skipping projection in query parameter serialization
-
-            // Skipping metrics in query parameter serialization
-            // Skipping metrics in query parameter serialization
+            Some("type".to_string()),
+            Some(self.r#type.to_string()),
+            // Skipping child in query parameter serialization
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a ScanReport value
+/// Converts Query Parameters representation (style=form, explode=false) to a NotExpression value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for ScanReport {
+impl std::str::FromStr for NotExpression {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -6625,11 +6518,8 @@ impl std::str::FromStr for ScanReport {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub table_name: Vec<String>,
-            pub snapshot_id: Vec<i64>,
-            pub filter: Vec<models::Expression>,
-            pub projection: Vec<models::Schema>,
-            pub metrics: Vec<std::collections::HashMap<String, models::MetricResult>>,
+            pub r#type: Vec<String>,
+            pub child: Vec<models::Expression>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -6643,7 +6533,7 @@ impl std::str::FromStr for ScanReport {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing ScanReport".to_string(),
+                        "Missing value while parsing NotExpression".to_string(),
                     )
                 }
             };
@@ -6652,32 +6542,17 @@ impl std::str::FromStr for ScanReport {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "table-name" => intermediate_rep.table_name.push(
+                    "type" => intermediate_rep.r#type.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "snapshot-id" => intermediate_rep.snapshot_id.push(
-                        <i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "filter" => intermediate_rep.filter.push(
+                    "child" => intermediate_rep.child.push(
                         <models::Expression as std::str::FromStr>::from_str(val)
                             .map_err(|x| x.to_string())?,
                     ),
-                    #[allow(clippy::redundant_clone)]
-                    "projection" => intermediate_rep.projection.push(
-                        <models::Schema as std::str::FromStr>::from_str(val)
-                            .map_err(|x| x.to_string())?,
-                    ),
-                    "metrics" => {
-                        return std::result::Result::Err(
-                            "Parsing a container in this style is not supported in ScanReport"
-                                .to_string(),
-                        )
-                    }
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing ScanReport".to_string(),
+                            "Unexpected key while parsing NotExpression".to_string(),
                         )
                     }
                 }
@@ -6688,50 +6563,35 @@ impl std::str::FromStr for ScanReport {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(ScanReport {
-            table_name: intermediate_rep
-                .table_name
-                .into_iter()
-                .next()
-                .ok_or_else(|| "table-name missing in ScanReport".to_string())?,
-            snapshot_id: intermediate_rep
-                .snapshot_id
-                .into_iter()
-                .next()
-                .ok_or_else(|| "snapshot-id missing in ScanReport".to_string())?,
-            filter: intermediate_rep
-                .filter
-                .into_iter()
-                .next()
-                .ok_or_else(|| "filter missing in ScanReport".to_string())?,
-            projection: intermediate_rep
-                .projection
+        std::result::Result::Ok(NotExpression {
+            r#type: intermediate_rep
+                .r#type
                 .into_iter()
                 .next()
-                .ok_or_else(|| "projection missing in ScanReport".to_string())?,
-            metrics: intermediate_rep
-                .metrics
+                .ok_or_else(|| "type missing in NotExpression".to_string())?,
+            child: intermediate_rep
+                .child
                 .into_iter()
                 .next()
-                .ok_or_else(|| "metrics missing in ScanReport".to_string())?,
+                .ok_or_else(|| "child missing in NotExpression".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<ScanReport> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<NotExpression> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<ScanReport>> for hyper::header::HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<NotExpression>> for hyper::header::HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<ScanReport>,
+        hdr_value: header::IntoHeaderValue<NotExpression>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for ScanReport - value: {} is invalid {}",
+                "Invalid header value for NotExpression - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -6739,18 +6599,18 @@ impl std::convert::TryFrom<header::IntoHeaderValue<ScanReport>> for hyper::heade
 }
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<ScanReport> {
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<NotExpression> {
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <ScanReport as std::str::FromStr>::from_str(value) {
+                match <NotExpression as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into ScanReport - {}",
+                        "Unable to convert header value '{}' into NotExpression - {}",
                         value, err
                     )),
                 }
@@ -6763,72 +6623,97 @@ impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderVal
     }
 }
 
+/// Enumeration of values.
+/// Since this enum's variants do not hold data, we can easily define them them as `#[repr(C)]`
+/// which helps with FFI.
+#[allow(non_camel_case_types)]
+#[repr(C)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+#[cfg_attr(feature = "conversion", derive(frunk_enum_derive::LabelledGenericEnum))]
+pub enum NullOrder {
+    #[serde(rename = "nulls-first")]
+    First,
+    #[serde(rename = "nulls-last")]
+    Last,
+}
+
+impl std::fmt::Display for NullOrder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            NullOrder::First => write!(f, "nulls-first"),
+            NullOrder::Last => write!(f, "nulls-last"),
+        }
+    }
+}
+
+impl std::str::FromStr for NullOrder {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "nulls-first" => std::result::Result::Ok(NullOrder::First),
+            "nulls-last" => std::result::Result::Ok(NullOrder::Last),
+            _ => std::result::Result::Err(format!("Value not valid: {}", s)),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct Schema {
-    // Note: inline enums are not fully supported by openapi-generator
-    #[serde(rename = "type")]
-    pub r#type: String,
+pub struct PartitionField {
+    #[serde(rename = "field-id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub field_id: Option<isize>,
 
-    #[serde(rename = "fields")]
-    pub fields: Vec<models::StructField>,
+    #[serde(rename = "source-id")]
+    pub source_id: isize,
 
-    #[serde(rename = "schema-id")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub schema_id: Option<isize>,
+    #[serde(rename = "name")]
+    pub name: String,
 
-    #[serde(rename = "identifier-field-ids")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub identifier_field_ids: Option<Vec<i32>>,
+    #[serde(rename = "transform")]
+    pub transform: String,
 }
 
-impl Schema {
+impl PartitionField {
     #[allow(clippy::new_without_default)]
-    pub fn new(r#type: String, fields: Vec<models::StructField>) -> Schema {
-        Schema {
-            r#type,
-            fields,
-            schema_id: None,
-            identifier_field_ids: None,
+    pub fn new(source_id: isize, name: String, transform: String) -> PartitionField {
+        PartitionField {
+            field_id: None,
+            source_id,
+            name,
+            transform,
         }
     }
 }
 
-/// Converts the Schema value to the Query Parameters representation (style=form, explode=false)
+/// Converts the PartitionField value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for Schema {
+impl std::string::ToString for PartitionField {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
-            Some("type".to_string()),
-            Some(self.r#type.to_string()),
-            // Skipping fields in query parameter serialization
-            self.schema_id
-                .as_ref()
-                .map(|schema_id| vec!["schema-id".to_string(), schema_id.to_string()].join(",")),
-            self.identifier_field_ids
+            self.field_id
                 .as_ref()
-                .map(|identifier_field_ids| {
-                    vec![
-                        "identifier-field-ids".to_string(),
-                        identifier_field_ids
-                            .iter()
-                            .map(|x| x.to_string())
-                            .collect::<Vec<_>>()
-                            .join(","),
-                    ]
-                    .join(",")
-                }),
+                .map(|field_id| vec!["field-id".to_string(), field_id.to_string()].join(",")),
+            Some("source-id".to_string()),
+            Some(self.source_id.to_string()),
+            Some("name".to_string()),
+            Some(self.name.to_string()),
+            Some("transform".to_string()),
+            Some(self.transform.to_string()),
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a Schema value
+/// Converts Query Parameters representation (style=form, explode=false) to a PartitionField value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for Schema {
+impl std::str::FromStr for PartitionField {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -6836,10 +6721,10 @@ impl std::str::FromStr for Schema {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub r#type: Vec<String>,
-            pub fields: Vec<Vec<models::StructField>>,
-            pub schema_id: Vec<isize>,
-            pub identifier_field_ids: Vec<Vec<i32>>,
+            pub field_id: Vec<isize>,
+            pub source_id: Vec<isize>,
+            pub name: Vec<String>,
+            pub transform: Vec<String>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -6853,7 +6738,7 @@ impl std::str::FromStr for Schema {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing Schema".to_string(),
+                        "Missing value while parsing PartitionField".to_string(),
                     )
                 }
             };
@@ -6862,28 +6747,24 @@ impl std::str::FromStr for Schema {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "type" => intermediate_rep.r#type.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "field-id" => intermediate_rep.field_id.push(
+                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
-                    "fields" => {
-                        return std::result::Result::Err(
-                            "Parsing a container in this style is not supported in Schema"
-                                .to_string(),
-                        )
-                    }
                     #[allow(clippy::redundant_clone)]
-                    "schema-id" => intermediate_rep.schema_id.push(
+                    "source-id" => intermediate_rep.source_id.push(
                         <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
-                    "identifier-field-ids" => {
-                        return std::result::Result::Err(
-                            "Parsing a container in this style is not supported in Schema"
-                                .to_string(),
-                        )
-                    }
+                    #[allow(clippy::redundant_clone)]
+                    "name" => intermediate_rep.name.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "transform" => intermediate_rep.transform.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing Schema".to_string(),
+                            "Unexpected key while parsing PartitionField".to_string(),
                         )
                     }
                 }
@@ -6894,37 +6775,41 @@ impl std::str::FromStr for Schema {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(Schema {
-            r#type: intermediate_rep
-                .r#type
+        std::result::Result::Ok(PartitionField {
+            field_id: intermediate_rep.field_id.into_iter().next(),
+            source_id: intermediate_rep
+                .source_id
                 .into_iter()
                 .next()
-                .ok_or_else(|| "type missing in Schema".to_string())?,
-            fields: intermediate_rep
-                .fields
+                .ok_or_else(|| "source-id missing in PartitionField".to_string())?,
+            name: intermediate_rep
+                .name
                 .into_iter()
                 .next()
-                .ok_or_else(|| "fields missing in Schema".to_string())?,
-            schema_id: intermediate_rep.schema_id.into_iter().next(),
-            identifier_field_ids: intermediate_rep.identifier_field_ids.into_iter().next(),
+                .ok_or_else(|| "name missing in PartitionField".to_string())?,
+            transform: intermediate_rep
+                .transform
+                .into_iter()
+                .next()
+                .ok_or_else(|| "transform missing in PartitionField".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<Schema> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<PartitionField> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<Schema>> for hyper::header::HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<PartitionField>> for hyper::header::HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<Schema>,
+        hdr_value: header::IntoHeaderValue<PartitionField>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for Schema - value: {} is invalid {}",
+                "Invalid header value for PartitionField - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -6932,18 +6817,18 @@ impl std::convert::TryFrom<header::IntoHeaderValue<Schema>> for hyper::header::H
 }
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<Schema> {
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<PartitionField> {
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <Schema as std::str::FromStr>::from_str(value) {
+                match <PartitionField as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into Schema - {}",
+                        "Unable to convert header value '{}' into PartitionField - {}",
                         value, err
                     )),
                 }
@@ -6958,58 +6843,45 @@ impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderVal
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct SchemaAllOf {
-    #[serde(rename = "schema-id")]
+pub struct PartitionSpec {
+    #[serde(rename = "spec-id")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub schema_id: Option<isize>,
+    pub spec_id: Option<isize>,
 
-    #[serde(rename = "identifier-field-ids")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub identifier_field_ids: Option<Vec<i32>>,
+    #[serde(rename = "fields")]
+    pub fields: Vec<models::PartitionField>,
 }
 
-impl SchemaAllOf {
+impl PartitionSpec {
     #[allow(clippy::new_without_default)]
-    pub fn new() -> SchemaAllOf {
-        SchemaAllOf {
-            schema_id: None,
-            identifier_field_ids: None,
+    pub fn new(fields: Vec<models::PartitionField>) -> PartitionSpec {
+        PartitionSpec {
+            spec_id: None,
+            fields,
         }
     }
 }
 
-/// Converts the SchemaAllOf value to the Query Parameters representation (style=form, explode=false)
+/// Converts the PartitionSpec value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for SchemaAllOf {
+impl std::string::ToString for PartitionSpec {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
-            self.schema_id
-                .as_ref()
-                .map(|schema_id| vec!["schema-id".to_string(), schema_id.to_string()].join(",")),
-            self.identifier_field_ids
+            self.spec_id
                 .as_ref()
-                .map(|identifier_field_ids| {
-                    vec![
-                        "identifier-field-ids".to_string(),
-                        identifier_field_ids
-                            .iter()
-                            .map(|x| x.to_string())
-                            .collect::<Vec<_>>()
-                            .join(","),
-                    ]
-                    .join(",")
-                }),
+                .map(|spec_id| vec!["spec-id".to_string(), spec_id.to_string()].join(",")),
+            // Skipping fields in query parameter serialization
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a SchemaAllOf value
+/// Converts Query Parameters representation (style=form, explode=false) to a PartitionSpec value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for SchemaAllOf {
+impl std::str::FromStr for PartitionSpec {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -7017,8 +6889,8 @@ impl std::str::FromStr for SchemaAllOf {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub schema_id: Vec<isize>,
-            pub identifier_field_ids: Vec<Vec<i32>>,
+            pub spec_id: Vec<isize>,
+            pub fields: Vec<Vec<models::PartitionField>>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -7032,7 +6904,7 @@ impl std::str::FromStr for SchemaAllOf {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing SchemaAllOf".to_string(),
+                        "Missing value while parsing PartitionSpec".to_string(),
                     )
                 }
             };
@@ -7041,18 +6913,18 @@ impl std::str::FromStr for SchemaAllOf {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "schema-id" => intermediate_rep.schema_id.push(
+                    "spec-id" => intermediate_rep.spec_id.push(
                         <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
-                    "identifier-field-ids" => {
+                    "fields" => {
                         return std::result::Result::Err(
-                            "Parsing a container in this style is not supported in SchemaAllOf"
+                            "Parsing a container in this style is not supported in PartitionSpec"
                                 .to_string(),
                         )
                     }
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing SchemaAllOf".to_string(),
+                            "Unexpected key while parsing PartitionSpec".to_string(),
                         )
                     }
                 }
@@ -7063,27 +6935,31 @@ impl std::str::FromStr for SchemaAllOf {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(SchemaAllOf {
-            schema_id: intermediate_rep.schema_id.into_iter().next(),
-            identifier_field_ids: intermediate_rep.identifier_field_ids.into_iter().next(),
+        std::result::Result::Ok(PartitionSpec {
+            spec_id: intermediate_rep.spec_id.into_iter().next(),
+            fields: intermediate_rep
+                .fields
+                .into_iter()
+                .next()
+                .ok_or_else(|| "fields missing in PartitionSpec".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<SchemaAllOf> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<PartitionSpec> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<SchemaAllOf>> for hyper::header::HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<PartitionSpec>> for hyper::header::HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<SchemaAllOf>,
+        hdr_value: header::IntoHeaderValue<PartitionSpec>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for SchemaAllOf - value: {} is invalid {}",
+                "Invalid header value for PartitionSpec - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -7091,18 +6967,18 @@ impl std::convert::TryFrom<header::IntoHeaderValue<SchemaAllOf>> for hyper::head
 }
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<SchemaAllOf> {
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<PartitionSpec> {
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <SchemaAllOf as std::str::FromStr>::from_str(value) {
+                match <PartitionSpec as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into SchemaAllOf - {}",
+                        "Unable to convert header value '{}' into PartitionSpec - {}",
                         value, err
                     )),
                 }
@@ -7115,45 +6991,200 @@ impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderVal
     }
 }
 
+#[derive(Debug, Clone, PartialEq, PartialOrd, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct PrimitiveType(String);
+
+impl std::convert::From<String> for PrimitiveType {
+    fn from(x: String) -> Self {
+        PrimitiveType(x)
+    }
+}
+
+impl std::string::ToString for PrimitiveType {
+    fn to_string(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+impl std::str::FromStr for PrimitiveType {
+    type Err = std::string::ParseError;
+    fn from_str(x: &str) -> std::result::Result<Self, Self::Err> {
+        std::result::Result::Ok(PrimitiveType(x.to_string()))
+    }
+}
+
+impl std::convert::From<PrimitiveType> for String {
+    fn from(x: PrimitiveType) -> Self {
+        x.0
+    }
+}
+
+impl std::ops::Deref for PrimitiveType {
+    type Target = String;
+    fn deref(&self) -> &String {
+        &self.0
+    }
+}
+
+impl std::ops::DerefMut for PrimitiveType {
+    fn deref_mut(&mut self) -> &mut String {
+        &mut self.0
+    }
+}
+
+/// A privilege on a catalog, namespace or table. Privileges granted on a catalog or namespace apply to all namespaces and tables inside it.
+/// Enumeration of values.
+/// Since this enum's variants do not hold data, we can easily define them them as `#[repr(C)]`
+/// which helps with FFI.
+#[allow(non_camel_case_types)]
+#[repr(C)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+#[cfg_attr(feature = "conversion", derive(frunk_enum_derive::LabelledGenericEnum))]
+pub enum Privilege {
+    #[serde(rename = "CATALOG_READ")]
+    CatalogRead,
+    #[serde(rename = "NAMESPACE_CREATE")]
+    NamespaceCreate,
+    #[serde(rename = "NAMESPACE_WRITE")]
+    NamespaceWrite,
+    #[serde(rename = "NAMESPACE_DROP")]
+    NamespaceDrop,
+    #[serde(rename = "TABLE_CREATE")]
+    TableCreate,
+    #[serde(rename = "TABLE_READ")]
+    TableRead,
+    #[serde(rename = "TABLE_WRITE")]
+    TableWrite,
+    #[serde(rename = "TABLE_DROP")]
+    TableDrop,
+    #[serde(rename = "MANAGE_GRANTS")]
+    ManageGrants,
+}
+
+impl std::fmt::Display for Privilege {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Privilege::CatalogRead => write!(f, "CATALOG_READ"),
+            Privilege::NamespaceCreate => write!(f, "NAMESPACE_CREATE"),
+            Privilege::NamespaceWrite => write!(f, "NAMESPACE_WRITE"),
+            Privilege::NamespaceDrop => write!(f, "NAMESPACE_DROP"),
+            Privilege::TableCreate => write!(f, "TABLE_CREATE"),
+            Privilege::TableRead => write!(f, "TABLE_READ"),
+            Privilege::TableWrite => write!(f, "TABLE_WRITE"),
+            Privilege::TableDrop => write!(f, "TABLE_DROP"),
+            Privilege::ManageGrants => write!(f, "MANAGE_GRANTS"),
+        }
+    }
+}
+
+impl std::str::FromStr for Privilege {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "CATALOG_READ" => std::result::Result::Ok(Privilege::CatalogRead),
+            "NAMESPACE_CREATE" => std::result::Result::Ok(Privilege::NamespaceCreate),
+            "NAMESPACE_WRITE" => std::result::Result::Ok(Privilege::NamespaceWrite),
+            "NAMESPACE_DROP" => std::result::Result::Ok(Privilege::NamespaceDrop),
+            "TABLE_CREATE" => std::result::Result::Ok(Privilege::TableCreate),
+            "TABLE_READ" => std::result::Result::Ok(Privilege::TableRead),
+            "TABLE_WRITE" => std::result::Result::Ok(Privilege::TableWrite),
+            "TABLE_DROP" => std::result::Result::Ok(Privilege::TableDrop),
+            "MANAGE_GRANTS" => std::result::Result::Ok(Privilege::ManageGrants),
+            _ => std::result::Result::Err(format!("Value not valid: {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, PartialOrd, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct Reference(String);
+
+impl std::convert::From<String> for Reference {
+    fn from(x: String) -> Self {
+        Reference(x)
+    }
+}
+
+impl std::string::ToString for Reference {
+    fn to_string(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+impl std::str::FromStr for Reference {
+    type Err = std::string::ParseError;
+    fn from_str(x: &str) -> std::result::Result<Self, Self::Err> {
+        std::result::Result::Ok(Reference(x.to_string()))
+    }
+}
+
+impl std::convert::From<Reference> for String {
+    fn from(x: Reference) -> Self {
+        x.0
+    }
+}
+
+impl std::ops::Deref for Reference {
+    type Target = String;
+    fn deref(&self) -> &String {
+        &self.0
+    }
+}
+
+impl std::ops::DerefMut for Reference {
+    fn deref_mut(&mut self) -> &mut String {
+        &mut self.0
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct SetCurrentSchemaUpdate {
+pub struct RemovePropertiesUpdate {
     // Note: inline enums are not fully supported by openapi-generator
     #[serde(rename = "action")]
     pub action: String,
 
-    /// Schema ID to set as current, or -1 to set last added schema
-    #[serde(rename = "schema-id")]
-    pub schema_id: isize,
+    #[serde(rename = "removals")]
+    pub removals: Vec<String>,
 }
 
-impl SetCurrentSchemaUpdate {
+impl RemovePropertiesUpdate {
     #[allow(clippy::new_without_default)]
-    pub fn new(action: String, schema_id: isize) -> SetCurrentSchemaUpdate {
-        SetCurrentSchemaUpdate { action, schema_id }
+    pub fn new(action: String, removals: Vec<String>) -> RemovePropertiesUpdate {
+        RemovePropertiesUpdate { action, removals }
     }
 }
 
-/// Converts the SetCurrentSchemaUpdate value to the Query Parameters representation (style=form, explode=false)
+/// Converts the RemovePropertiesUpdate value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for SetCurrentSchemaUpdate {
+impl std::string::ToString for RemovePropertiesUpdate {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
             Some("action".to_string()),
             Some(self.action.to_string()),
-            Some("schema-id".to_string()),
-            Some(self.schema_id.to_string()),
+            Some("removals".to_string()),
+            Some(
+                self.removals
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect::<Vec<_>>()
+                    .join(","),
+            ),
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a SetCurrentSchemaUpdate value
+/// Converts Query Parameters representation (style=form, explode=false) to a RemovePropertiesUpdate value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for SetCurrentSchemaUpdate {
+impl std::str::FromStr for RemovePropertiesUpdate {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -7162,7 +7193,7 @@ impl std::str::FromStr for SetCurrentSchemaUpdate {
         #[allow(dead_code)]
         struct IntermediateRep {
             pub action: Vec<String>,
-            pub schema_id: Vec<isize>,
+            pub removals: Vec<Vec<String>>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -7176,7 +7207,7 @@ impl std::str::FromStr for SetCurrentSchemaUpdate {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing SetCurrentSchemaUpdate".to_string(),
+                        "Missing value while parsing RemovePropertiesUpdate".to_string(),
                     )
                 }
             };
@@ -7185,18 +7216,9 @@ impl std::str::FromStr for SetCurrentSchemaUpdate {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "action" => intermediate_rep.action.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "schema-id" => intermediate_rep.schema_id.push(
-                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    _ => {
-                        return std::result::Result::Err(
-                            "Unexpected key while parsing SetCurrentSchemaUpdate".to_string(),
-                        )
-                    }
+                    "action" => intermediate_rep.action.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "removals" => return std::result::Result::Err("Parsing a container in this style is not supported in RemovePropertiesUpdate".to_string()),
+                    _ => return std::result::Result::Err("Unexpected key while parsing RemovePropertiesUpdate".to_string())
                 }
             }
 
@@ -7205,37 +7227,37 @@ impl std::str::FromStr for SetCurrentSchemaUpdate {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(SetCurrentSchemaUpdate {
+        std::result::Result::Ok(RemovePropertiesUpdate {
             action: intermediate_rep
                 .action
                 .into_iter()
                 .next()
-                .ok_or_else(|| "action missing in SetCurrentSchemaUpdate".to_string())?,
-            schema_id: intermediate_rep
-                .schema_id
+                .ok_or_else(|| "action missing in RemovePropertiesUpdate".to_string())?,
+            removals: intermediate_rep
+                .removals
                 .into_iter()
                 .next()
-                .ok_or_else(|| "schema-id missing in SetCurrentSchemaUpdate".to_string())?,
+                .ok_or_else(|| "removals missing in RemovePropertiesUpdate".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<SetCurrentSchemaUpdate> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<RemovePropertiesUpdate> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<SetCurrentSchemaUpdate>>
+impl std::convert::TryFrom<header::IntoHeaderValue<RemovePropertiesUpdate>>
     for hyper::header::HeaderValue
 {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<SetCurrentSchemaUpdate>,
+        hdr_value: header::IntoHeaderValue<RemovePropertiesUpdate>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for SetCurrentSchemaUpdate - value: {} is invalid {}",
+                "Invalid header value for RemovePropertiesUpdate - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -7244,19 +7266,19 @@ impl std::convert::TryFrom<header::IntoHeaderValue<SetCurrentSchemaUpdate>>
 
 #[cfg(any(feature = "client", feature = "server"))]
 impl std::convert::TryFrom<hyper::header::HeaderValue>
-    for header::IntoHeaderValue<SetCurrentSchemaUpdate>
+    for header::IntoHeaderValue<RemovePropertiesUpdate>
 {
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <SetCurrentSchemaUpdate as std::str::FromStr>::from_str(value) {
+                match <RemovePropertiesUpdate as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into SetCurrentSchemaUpdate - {}",
+                        "Unable to convert header value '{}' into RemovePropertiesUpdate - {}",
                         value, err
                     )),
                 }
@@ -7271,37 +7293,42 @@ impl std::convert::TryFrom<hyper::header::HeaderValue>
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct SetCurrentSchemaUpdateAllOf {
-    /// Schema ID to set as current, or -1 to set last added schema
-    #[serde(rename = "schema-id")]
-    pub schema_id: isize,
+pub struct RemovePropertiesUpdateAllOf {
+    #[serde(rename = "removals")]
+    pub removals: Vec<String>,
 }
 
-impl SetCurrentSchemaUpdateAllOf {
+impl RemovePropertiesUpdateAllOf {
     #[allow(clippy::new_without_default)]
-    pub fn new(schema_id: isize) -> SetCurrentSchemaUpdateAllOf {
-        SetCurrentSchemaUpdateAllOf { schema_id }
+    pub fn new(removals: Vec<String>) -> RemovePropertiesUpdateAllOf {
+        RemovePropertiesUpdateAllOf { removals }
     }
 }
 
-/// Converts the SetCurrentSchemaUpdateAllOf value to the Query Parameters representation (style=form, explode=false)
+/// Converts the RemovePropertiesUpdateAllOf value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for SetCurrentSchemaUpdateAllOf {
+impl std::string::ToString for RemovePropertiesUpdateAllOf {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
-            Some("schema-id".to_string()),
-            Some(self.schema_id.to_string()),
+            Some("removals".to_string()),
+            Some(
+                self.removals
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect::<Vec<_>>()
+                    .join(","),
+            ),
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a SetCurrentSchemaUpdateAllOf value
+/// Converts Query Parameters representation (style=form, explode=false) to a RemovePropertiesUpdateAllOf value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for SetCurrentSchemaUpdateAllOf {
+impl std::str::FromStr for RemovePropertiesUpdateAllOf {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -7309,7 +7336,7 @@ impl std::str::FromStr for SetCurrentSchemaUpdateAllOf {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub schema_id: Vec<isize>,
+            pub removals: Vec<Vec<String>>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -7323,7 +7350,7 @@ impl std::str::FromStr for SetCurrentSchemaUpdateAllOf {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing SetCurrentSchemaUpdateAllOf".to_string(),
+                        "Missing value while parsing RemovePropertiesUpdateAllOf".to_string(),
                     )
                 }
             };
@@ -7331,15 +7358,8 @@ impl std::str::FromStr for SetCurrentSchemaUpdateAllOf {
             if let Some(key) = key_result {
                 #[allow(clippy::match_single_binding)]
                 match key {
-                    #[allow(clippy::redundant_clone)]
-                    "schema-id" => intermediate_rep.schema_id.push(
-                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    _ => {
-                        return std::result::Result::Err(
-                            "Unexpected key while parsing SetCurrentSchemaUpdateAllOf".to_string(),
-                        )
-                    }
+                    "removals" => return std::result::Result::Err("Parsing a container in this style is not supported in RemovePropertiesUpdateAllOf".to_string()),
+                    _ => return std::result::Result::Err("Unexpected key while parsing RemovePropertiesUpdateAllOf".to_string())
                 }
             }
 
@@ -7348,32 +7368,32 @@ impl std::str::FromStr for SetCurrentSchemaUpdateAllOf {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(SetCurrentSchemaUpdateAllOf {
-            schema_id: intermediate_rep
-                .schema_id
+        std::result::Result::Ok(RemovePropertiesUpdateAllOf {
+            removals: intermediate_rep
+                .removals
                 .into_iter()
                 .next()
-                .ok_or_else(|| "schema-id missing in SetCurrentSchemaUpdateAllOf".to_string())?,
+                .ok_or_else(|| "removals missing in RemovePropertiesUpdateAllOf".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<SetCurrentSchemaUpdateAllOf> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<RemovePropertiesUpdateAllOf> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<SetCurrentSchemaUpdateAllOf>>
+impl std::convert::TryFrom<header::IntoHeaderValue<RemovePropertiesUpdateAllOf>>
     for hyper::header::HeaderValue
 {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<SetCurrentSchemaUpdateAllOf>,
+        hdr_value: header::IntoHeaderValue<RemovePropertiesUpdateAllOf>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for SetCurrentSchemaUpdateAllOf - value: {} is invalid {}",
+                "Invalid header value for RemovePropertiesUpdateAllOf - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -7382,19 +7402,19 @@ impl std::convert::TryFrom<header::IntoHeaderValue<SetCurrentSchemaUpdateAllOf>>
 
 #[cfg(any(feature = "client", feature = "server"))]
 impl std::convert::TryFrom<hyper::header::HeaderValue>
-    for header::IntoHeaderValue<SetCurrentSchemaUpdateAllOf>
+    for header::IntoHeaderValue<RemovePropertiesUpdateAllOf>
 {
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <SetCurrentSchemaUpdateAllOf as std::str::FromStr>::from_str(value) {
+                match <RemovePropertiesUpdateAllOf as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into SetCurrentSchemaUpdateAllOf - {}",
+                        "Unable to convert header value '{}' into RemovePropertiesUpdateAllOf - {}",
                         value, err
                     )),
                 }
@@ -7409,46 +7429,42 @@ impl std::convert::TryFrom<hyper::header::HeaderValue>
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct SetDefaultSortOrderUpdate {
+pub struct RemoveSnapshotRefUpdate {
     // Note: inline enums are not fully supported by openapi-generator
     #[serde(rename = "action")]
     pub action: String,
 
-    /// Sort order ID to set as the default, or -1 to set last added sort order
-    #[serde(rename = "sort-order-id")]
-    pub sort_order_id: isize,
+    #[serde(rename = "ref-name")]
+    pub ref_name: String,
 }
 
-impl SetDefaultSortOrderUpdate {
+impl RemoveSnapshotRefUpdate {
     #[allow(clippy::new_without_default)]
-    pub fn new(action: String, sort_order_id: isize) -> SetDefaultSortOrderUpdate {
-        SetDefaultSortOrderUpdate {
-            action,
-            sort_order_id,
-        }
+    pub fn new(action: String, ref_name: String) -> RemoveSnapshotRefUpdate {
+        RemoveSnapshotRefUpdate { action, ref_name }
     }
 }
 
-/// Converts the SetDefaultSortOrderUpdate value to the Query Parameters representation (style=form, explode=false)
+/// Converts the RemoveSnapshotRefUpdate value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for SetDefaultSortOrderUpdate {
+impl std::string::ToString for RemoveSnapshotRefUpdate {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
             Some("action".to_string()),
             Some(self.action.to_string()),
-            Some("sort-order-id".to_string()),
-            Some(self.sort_order_id.to_string()),
+            Some("ref-name".to_string()),
+            Some(self.ref_name.to_string()),
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a SetDefaultSortOrderUpdate value
+/// Converts Query Parameters representation (style=form, explode=false) to a RemoveSnapshotRefUpdate value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for SetDefaultSortOrderUpdate {
+impl std::str::FromStr for RemoveSnapshotRefUpdate {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -7457,7 +7473,7 @@ impl std::str::FromStr for SetDefaultSortOrderUpdate {
         #[allow(dead_code)]
         struct IntermediateRep {
             pub action: Vec<String>,
-            pub sort_order_id: Vec<isize>,
+            pub ref_name: Vec<String>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -7471,7 +7487,7 @@ impl std::str::FromStr for SetDefaultSortOrderUpdate {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing SetDefaultSortOrderUpdate".to_string(),
+                        "Missing value while parsing RemoveSnapshotRefUpdate".to_string(),
                     )
                 }
             };
@@ -7484,12 +7500,12 @@ impl std::str::FromStr for SetDefaultSortOrderUpdate {
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "sort-order-id" => intermediate_rep.sort_order_id.push(
-                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "ref-name" => intermediate_rep.ref_name.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing SetDefaultSortOrderUpdate".to_string(),
+                            "Unexpected key while parsing RemoveSnapshotRefUpdate".to_string(),
                         )
                     }
                 }
@@ -7500,37 +7516,37 @@ impl std::str::FromStr for SetDefaultSortOrderUpdate {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(SetDefaultSortOrderUpdate {
+        std::result::Result::Ok(RemoveSnapshotRefUpdate {
             action: intermediate_rep
                 .action
                 .into_iter()
                 .next()
-                .ok_or_else(|| "action missing in SetDefaultSortOrderUpdate".to_string())?,
-            sort_order_id: intermediate_rep
-                .sort_order_id
+                .ok_or_else(|| "action missing in RemoveSnapshotRefUpdate".to_string())?,
+            ref_name: intermediate_rep
+                .ref_name
                 .into_iter()
                 .next()
-                .ok_or_else(|| "sort-order-id missing in SetDefaultSortOrderUpdate".to_string())?,
+                .ok_or_else(|| "ref-name missing in RemoveSnapshotRefUpdate".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<SetDefaultSortOrderUpdate> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<RemoveSnapshotRefUpdate> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<SetDefaultSortOrderUpdate>>
+impl std::convert::TryFrom<header::IntoHeaderValue<RemoveSnapshotRefUpdate>>
     for hyper::header::HeaderValue
 {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<SetDefaultSortOrderUpdate>,
+        hdr_value: header::IntoHeaderValue<RemoveSnapshotRefUpdate>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for SetDefaultSortOrderUpdate - value: {} is invalid {}",
+                "Invalid header value for RemoveSnapshotRefUpdate - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -7539,19 +7555,19 @@ impl std::convert::TryFrom<header::IntoHeaderValue<SetDefaultSortOrderUpdate>>
 
 #[cfg(any(feature = "client", feature = "server"))]
 impl std::convert::TryFrom<hyper::header::HeaderValue>
-    for header::IntoHeaderValue<SetDefaultSortOrderUpdate>
+    for header::IntoHeaderValue<RemoveSnapshotRefUpdate>
 {
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <SetDefaultSortOrderUpdate as std::str::FromStr>::from_str(value) {
+                match <RemoveSnapshotRefUpdate as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into SetDefaultSortOrderUpdate - {}",
+                        "Unable to convert header value '{}' into RemoveSnapshotRefUpdate - {}",
                         value, err
                     )),
                 }
@@ -7566,37 +7582,51 @@ impl std::convert::TryFrom<hyper::header::HeaderValue>
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct SetDefaultSortOrderUpdateAllOf {
-    /// Sort order ID to set as the default, or -1 to set last added sort order
-    #[serde(rename = "sort-order-id")]
-    pub sort_order_id: isize,
+pub struct RemoveSnapshotsUpdate {
+    // Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "action")]
+    pub action: String,
+
+    #[serde(rename = "snapshot-ids")]
+    pub snapshot_ids: Vec<i64>,
 }
 
-impl SetDefaultSortOrderUpdateAllOf {
+impl RemoveSnapshotsUpdate {
     #[allow(clippy::new_without_default)]
-    pub fn new(sort_order_id: isize) -> SetDefaultSortOrderUpdateAllOf {
-        SetDefaultSortOrderUpdateAllOf { sort_order_id }
+    pub fn new(action: String, snapshot_ids: Vec<i64>) -> RemoveSnapshotsUpdate {
+        RemoveSnapshotsUpdate {
+            action,
+            snapshot_ids,
+        }
     }
 }
 
-/// Converts the SetDefaultSortOrderUpdateAllOf value to the Query Parameters representation (style=form, explode=false)
+/// Converts the RemoveSnapshotsUpdate value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for SetDefaultSortOrderUpdateAllOf {
+impl std::string::ToString for RemoveSnapshotsUpdate {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
-            Some("sort-order-id".to_string()),
-            Some(self.sort_order_id.to_string()),
+            Some("action".to_string()),
+            Some(self.action.to_string()),
+            Some("snapshot-ids".to_string()),
+            Some(
+                self.snapshot_ids
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect::<Vec<_>>()
+                    .join(","),
+            ),
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a SetDefaultSortOrderUpdateAllOf value
+/// Converts Query Parameters representation (style=form, explode=false) to a RemoveSnapshotsUpdate value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for SetDefaultSortOrderUpdateAllOf {
+impl std::str::FromStr for RemoveSnapshotsUpdate {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -7604,7 +7634,8 @@ impl std::str::FromStr for SetDefaultSortOrderUpdateAllOf {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub sort_order_id: Vec<isize>,
+            pub action: Vec<String>,
+            pub snapshot_ids: Vec<Vec<i64>>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -7618,7 +7649,7 @@ impl std::str::FromStr for SetDefaultSortOrderUpdateAllOf {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing SetDefaultSortOrderUpdateAllOf".to_string(),
+                        "Missing value while parsing RemoveSnapshotsUpdate".to_string(),
                     )
                 }
             };
@@ -7627,15 +7658,9 @@ impl std::str::FromStr for SetDefaultSortOrderUpdateAllOf {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "sort-order-id" => intermediate_rep.sort_order_id.push(
-                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    _ => {
-                        return std::result::Result::Err(
-                            "Unexpected key while parsing SetDefaultSortOrderUpdateAllOf"
-                                .to_string(),
-                        )
-                    }
+                    "action" => intermediate_rep.action.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "snapshot-ids" => return std::result::Result::Err("Parsing a container in this style is not supported in RemoveSnapshotsUpdate".to_string()),
+                    _ => return std::result::Result::Err("Unexpected key while parsing RemoveSnapshotsUpdate".to_string())
                 }
             }
 
@@ -7644,34 +7669,37 @@ impl std::str::FromStr for SetDefaultSortOrderUpdateAllOf {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(SetDefaultSortOrderUpdateAllOf {
-            sort_order_id: intermediate_rep
-                .sort_order_id
+        std::result::Result::Ok(RemoveSnapshotsUpdate {
+            action: intermediate_rep
+                .action
                 .into_iter()
                 .next()
-                .ok_or_else(|| {
-                    "sort-order-id missing in SetDefaultSortOrderUpdateAllOf".to_string()
-                })?,
+                .ok_or_else(|| "action missing in RemoveSnapshotsUpdate".to_string())?,
+            snapshot_ids: intermediate_rep
+                .snapshot_ids
+                .into_iter()
+                .next()
+                .ok_or_else(|| "snapshot-ids missing in RemoveSnapshotsUpdate".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<SetDefaultSortOrderUpdateAllOf> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<RemoveSnapshotsUpdate> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<SetDefaultSortOrderUpdateAllOf>>
+impl std::convert::TryFrom<header::IntoHeaderValue<RemoveSnapshotsUpdate>>
     for hyper::header::HeaderValue
 {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<SetDefaultSortOrderUpdateAllOf>,
+        hdr_value: header::IntoHeaderValue<RemoveSnapshotsUpdate>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for SetDefaultSortOrderUpdateAllOf - value: {} is invalid {}",
+                "Invalid header value for RemoveSnapshotsUpdate - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -7680,66 +7708,69 @@ impl std::convert::TryFrom<header::IntoHeaderValue<SetDefaultSortOrderUpdateAllO
 
 #[cfg(any(feature = "client", feature = "server"))]
 impl std::convert::TryFrom<hyper::header::HeaderValue>
-    for header::IntoHeaderValue<SetDefaultSortOrderUpdateAllOf>
+    for header::IntoHeaderValue<RemoveSnapshotsUpdate>
 {
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
-             std::result::Result::Ok(value) => {
-                    match <SetDefaultSortOrderUpdateAllOf as std::str::FromStr>::from_str(value) {
-                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
-                        std::result::Result::Err(err) => std::result::Result::Err(
-                            format!("Unable to convert header value '{}' into SetDefaultSortOrderUpdateAllOf - {}",
-                                value, err))
+            std::result::Result::Ok(value) => {
+                match <RemoveSnapshotsUpdate as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
-             },
-             std::result::Result::Err(e) => std::result::Result::Err(
-                 format!("Unable to convert header: {:?} to string: {}",
-                     hdr_value, e))
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into RemoveSnapshotsUpdate - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
         }
     }
 }
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct SetDefaultSpecUpdate {
-    // Note: inline enums are not fully supported by openapi-generator
-    #[serde(rename = "action")]
-    pub action: String,
-
-    /// Partition spec ID to set as the default, or -1 to set last added spec
-    #[serde(rename = "spec-id")]
-    pub spec_id: isize,
+pub struct RemoveSnapshotsUpdateAllOf {
+    #[serde(rename = "snapshot-ids")]
+    pub snapshot_ids: Vec<i64>,
 }
 
-impl SetDefaultSpecUpdate {
+impl RemoveSnapshotsUpdateAllOf {
     #[allow(clippy::new_without_default)]
-    pub fn new(action: String, spec_id: isize) -> SetDefaultSpecUpdate {
-        SetDefaultSpecUpdate { action, spec_id }
+    pub fn new(snapshot_ids: Vec<i64>) -> RemoveSnapshotsUpdateAllOf {
+        RemoveSnapshotsUpdateAllOf { snapshot_ids }
     }
 }
 
-/// Converts the SetDefaultSpecUpdate value to the Query Parameters representation (style=form, explode=false)
+/// Converts the RemoveSnapshotsUpdateAllOf value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for SetDefaultSpecUpdate {
+impl std::string::ToString for RemoveSnapshotsUpdateAllOf {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
-            Some("action".to_string()),
-            Some(self.action.to_string()),
-            Some("spec-id".to_string()),
-            Some(self.spec_id.to_string()),
+            Some("snapshot-ids".to_string()),
+            Some(
+                self.snapshot_ids
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect::<Vec<_>>()
+                    .join(","),
+            ),
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a SetDefaultSpecUpdate value
+/// Converts Query Parameters representation (style=form, explode=false) to a RemoveSnapshotsUpdateAllOf value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for SetDefaultSpecUpdate {
+impl std::str::FromStr for RemoveSnapshotsUpdateAllOf {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -7747,8 +7778,7 @@ impl std::str::FromStr for SetDefaultSpecUpdate {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub action: Vec<String>,
-            pub spec_id: Vec<isize>,
+            pub snapshot_ids: Vec<Vec<i64>>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -7762,7 +7792,7 @@ impl std::str::FromStr for SetDefaultSpecUpdate {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing SetDefaultSpecUpdate".to_string(),
+                        "Missing value while parsing RemoveSnapshotsUpdateAllOf".to_string(),
                     )
                 }
             };
@@ -7770,19 +7800,8 @@ impl std::str::FromStr for SetDefaultSpecUpdate {
             if let Some(key) = key_result {
                 #[allow(clippy::match_single_binding)]
                 match key {
-                    #[allow(clippy::redundant_clone)]
-                    "action" => intermediate_rep.action.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "spec-id" => intermediate_rep.spec_id.push(
-                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    _ => {
-                        return std::result::Result::Err(
-                            "Unexpected key while parsing SetDefaultSpecUpdate".to_string(),
-                        )
-                    }
+                    "snapshot-ids" => return std::result::Result::Err("Parsing a container in this style is not supported in RemoveSnapshotsUpdateAllOf".to_string()),
+                    _ => return std::result::Result::Err("Unexpected key while parsing RemoveSnapshotsUpdateAllOf".to_string())
                 }
             }
 
@@ -7791,37 +7810,32 @@ impl std::str::FromStr for SetDefaultSpecUpdate {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(SetDefaultSpecUpdate {
-            action: intermediate_rep
-                .action
-                .into_iter()
-                .next()
-                .ok_or_else(|| "action missing in SetDefaultSpecUpdate".to_string())?,
-            spec_id: intermediate_rep
-                .spec_id
+        std::result::Result::Ok(RemoveSnapshotsUpdateAllOf {
+            snapshot_ids: intermediate_rep
+                .snapshot_ids
                 .into_iter()
                 .next()
-                .ok_or_else(|| "spec-id missing in SetDefaultSpecUpdate".to_string())?,
+                .ok_or_else(|| "snapshot-ids missing in RemoveSnapshotsUpdateAllOf".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<SetDefaultSpecUpdate> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<RemoveSnapshotsUpdateAllOf> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<SetDefaultSpecUpdate>>
+impl std::convert::TryFrom<header::IntoHeaderValue<RemoveSnapshotsUpdateAllOf>>
     for hyper::header::HeaderValue
 {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<SetDefaultSpecUpdate>,
+        hdr_value: header::IntoHeaderValue<RemoveSnapshotsUpdateAllOf>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for SetDefaultSpecUpdate - value: {} is invalid {}",
+                "Invalid header value for RemoveSnapshotsUpdateAllOf - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -7830,19 +7844,19 @@ impl std::convert::TryFrom<header::IntoHeaderValue<SetDefaultSpecUpdate>>
 
 #[cfg(any(feature = "client", feature = "server"))]
 impl std::convert::TryFrom<hyper::header::HeaderValue>
-    for header::IntoHeaderValue<SetDefaultSpecUpdate>
+    for header::IntoHeaderValue<RemoveSnapshotsUpdateAllOf>
 {
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <SetDefaultSpecUpdate as std::str::FromStr>::from_str(value) {
+                match <RemoveSnapshotsUpdateAllOf as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into SetDefaultSpecUpdate - {}",
+                        "Unable to convert header value '{}' into RemoveSnapshotsUpdateAllOf - {}",
                         value, err
                     )),
                 }
@@ -7857,35 +7871,47 @@ impl std::convert::TryFrom<hyper::header::HeaderValue>
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct SetDefaultSpecUpdateAllOf {
-    /// Partition spec ID to set as the default, or -1 to set last added spec
-    #[serde(rename = "spec-id")]
-    pub spec_id: isize,
+pub struct RenameTableRequest {
+    #[serde(rename = "source")]
+    pub source: models::TableIdentifier,
+
+    #[serde(rename = "destination")]
+    pub destination: models::TableIdentifier,
 }
 
-impl SetDefaultSpecUpdateAllOf {
+impl RenameTableRequest {
     #[allow(clippy::new_without_default)]
-    pub fn new(spec_id: isize) -> SetDefaultSpecUpdateAllOf {
-        SetDefaultSpecUpdateAllOf { spec_id }
+    pub fn new(
+        source: models::TableIdentifier,
+        destination: models::TableIdentifier,
+    ) -> RenameTableRequest {
+        RenameTableRequest {
+            source,
+            destination,
+        }
     }
 }
 
-/// Converts the SetDefaultSpecUpdateAllOf value to the Query Parameters representation (style=form, explode=false)
+/// Converts the RenameTableRequest value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for SetDefaultSpecUpdateAllOf {
+impl std::string::ToString for RenameTableRequest {
     fn to_string(&self) -> String {
-        let params: Vec<Option<String>> =
-            vec![Some("spec-id".to_string()), Some(self.spec_id.to_string())];
+        let params: Vec<Option<String>> = vec![
+            // Skipping source in query parameter serialization
+
+            // Skipping destination in query parameter serialization
+
+        ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a SetDefaultSpecUpdateAllOf value
+/// Converts Query Parameters representation (style=form, explode=false) to a RenameTableRequest value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for SetDefaultSpecUpdateAllOf {
+impl std::str::FromStr for RenameTableRequest {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -7893,7 +7919,8 @@ impl std::str::FromStr for SetDefaultSpecUpdateAllOf {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub spec_id: Vec<isize>,
+            pub source: Vec<models::TableIdentifier>,
+            pub destination: Vec<models::TableIdentifier>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -7907,7 +7934,7 @@ impl std::str::FromStr for SetDefaultSpecUpdateAllOf {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing SetDefaultSpecUpdateAllOf".to_string(),
+                        "Missing value while parsing RenameTableRequest".to_string(),
                     )
                 }
             };
@@ -7916,12 +7943,18 @@ impl std::str::FromStr for SetDefaultSpecUpdateAllOf {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "spec-id" => intermediate_rep.spec_id.push(
-                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "source" => intermediate_rep.source.push(
+                        <models::TableIdentifier as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "destination" => intermediate_rep.destination.push(
+                        <models::TableIdentifier as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing SetDefaultSpecUpdateAllOf".to_string(),
+                            "Unexpected key while parsing RenameTableRequest".to_string(),
                         )
                     }
                 }
@@ -7932,32 +7965,37 @@ impl std::str::FromStr for SetDefaultSpecUpdateAllOf {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(SetDefaultSpecUpdateAllOf {
-            spec_id: intermediate_rep
-                .spec_id
+        std::result::Result::Ok(RenameTableRequest {
+            source: intermediate_rep
+                .source
                 .into_iter()
                 .next()
-                .ok_or_else(|| "spec-id missing in SetDefaultSpecUpdateAllOf".to_string())?,
+                .ok_or_else(|| "source missing in RenameTableRequest".to_string())?,
+            destination: intermediate_rep
+                .destination
+                .into_iter()
+                .next()
+                .ok_or_else(|| "destination missing in RenameTableRequest".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<SetDefaultSpecUpdateAllOf> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<RenameTableRequest> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<SetDefaultSpecUpdateAllOf>>
+impl std::convert::TryFrom<header::IntoHeaderValue<RenameTableRequest>>
     for hyper::header::HeaderValue
 {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<SetDefaultSpecUpdateAllOf>,
+        hdr_value: header::IntoHeaderValue<RenameTableRequest>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for SetDefaultSpecUpdateAllOf - value: {} is invalid {}",
+                "Invalid header value for RenameTableRequest - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -7966,19 +8004,19 @@ impl std::convert::TryFrom<header::IntoHeaderValue<SetDefaultSpecUpdateAllOf>>
 
 #[cfg(any(feature = "client", feature = "server"))]
 impl std::convert::TryFrom<hyper::header::HeaderValue>
-    for header::IntoHeaderValue<SetDefaultSpecUpdateAllOf>
+    for header::IntoHeaderValue<RenameTableRequest>
 {
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <SetDefaultSpecUpdateAllOf as std::str::FromStr>::from_str(value) {
+                match <RenameTableRequest as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into SetDefaultSpecUpdateAllOf - {}",
+                        "Unable to convert header value '{}' into RenameTableRequest - {}",
                         value, err
                     )),
                 }
@@ -7991,55 +8029,126 @@ impl std::convert::TryFrom<hyper::header::HeaderValue>
     }
 }
 
+/// A scan report or a commit report. Scan reports set `report-type` to `scan-report`, commit reports to `commit-report`.
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct SetExpression {
-    #[serde(rename = "type")]
-    pub r#type: String,
+pub struct ReportMetricsRequest {
+    #[serde(rename = "report-type")]
+    pub report_type: String,
 
-    #[serde(rename = "term")]
-    pub term: models::Term,
+    #[serde(rename = "table-name")]
+    pub table_name: String,
 
-    #[serde(rename = "values")]
-    pub values: Vec<serde_json::Value>,
+    #[serde(rename = "snapshot-id")]
+    pub snapshot_id: i64,
+
+    /// The filter expression of a scan
+    #[serde(rename = "filter")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<serde_json::Value>,
+
+    /// The id of the schema a scan projected
+    #[serde(rename = "schema-id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema_id: Option<i32>,
+
+    #[serde(rename = "projected-field-ids")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub projected_field_ids: Option<Vec<i32>>,
+
+    #[serde(rename = "projected-field-names")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub projected_field_names: Option<Vec<String>>,
+
+    #[serde(rename = "projection")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub projection: Option<models::Schema>,
+
+    /// The sequence number of the snapshot a commit created
+    #[serde(rename = "sequence-number")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sequence_number: Option<i64>,
+
+    /// The operation of the snapshot a commit created
+    #[serde(rename = "operation")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub operation: Option<String>,
+
+    #[serde(rename = "metrics")]
+    pub metrics: std::collections::HashMap<String, models::MetricResult>,
+
+    #[serde(rename = "metadata")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<std::collections::HashMap<String, String>>,
 }
 
-impl SetExpression {
+impl ReportMetricsRequest {
     #[allow(clippy::new_without_default)]
     pub fn new(
-        r#type: String,
-        term: models::Term,
-        values: Vec<serde_json::Value>,
-    ) -> SetExpression {
-        SetExpression {
-            r#type,
-            term,
-            values,
+        report_type: String,
+        table_name: String,
+        snapshot_id: i64,
+        metrics: std::collections::HashMap<String, models::MetricResult>,
+    ) -> ReportMetricsRequest {
+        ReportMetricsRequest {
+            report_type,
+            table_name,
+            snapshot_id,
+            filter: None,
+            schema_id: None,
+            projected_field_ids: None,
+            projected_field_names: None,
+            projection: None,
+            sequence_number: None,
+            operation: None,
+            metrics,
+            metadata: None,
         }
     }
 }
 
-/// Converts the SetExpression value to the Query Parameters representation (style=form, explode=false)
+/// Converts the ReportMetricsRequest value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for SetExpression {
+impl std::string::ToString for ReportMetricsRequest {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
-            Some("type".to_string()),
-            Some(self.r#type.to_string()),
-            // Skipping term in query parameter serialization
+            Some("report-type".to_string()),
+            Some(self.report_type.to_string()),
+            Some("table-name".to_string()),
+            Some(self.table_name.to_string()),
+            Some("snapshot-id".to_string()),
+            Some(self.snapshot_id.to_string()),
+            self.filter
+                .as_ref()
+                .map(|filter| vec!["filter".to_string(), filter.to_string()].join(",")),
+            self.schema_id
+                .as_ref()
+                .map(|schema_id| vec!["schema-id".to_string(), schema_id.to_string()].join(",")),
+            // Skipping projected-field-ids in query parameter serialization
 
-            // Skipping values in query parameter serialization
+            // Skipping projected-field-names in query parameter serialization
+
+            // Skipping projection in query parameter serialization
+            self.sequence_number.as_ref().map(|sequence_number| {
+                vec!["sequence-number".to_string(), sequence_number.to_string()].join(",")
+            }),
+            self.operation
+                .as_ref()
+                .map(|operation| vec!["operation".to_string(), operation.to_string()].join(",")),
+            // Skipping metrics in query parameter serialization
+
+            // Skipping metadata in query parameter serialization
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a SetExpression value
+/// Converts Query Parameters representation (style=form, explode=false) to a ReportMetricsRequest value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for SetExpression {
+impl std::str::FromStr for ReportMetricsRequest {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -8047,9 +8156,18 @@ impl std::str::FromStr for SetExpression {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub r#type: Vec<String>,
-            pub term: Vec<models::Term>,
-            pub values: Vec<Vec<serde_json::Value>>,
+            pub report_type: Vec<String>,
+            pub table_name: Vec<String>,
+            pub snapshot_id: Vec<i64>,
+            pub filter: Vec<serde_json::Value>,
+            pub schema_id: Vec<i32>,
+            pub projected_field_ids: Vec<Vec<i32>>,
+            pub projected_field_names: Vec<Vec<String>>,
+            pub projection: Vec<models::Schema>,
+            pub sequence_number: Vec<i64>,
+            pub operation: Vec<String>,
+            pub metrics: Vec<std::collections::HashMap<String, models::MetricResult>>,
+            pub metadata: Vec<std::collections::HashMap<String, String>>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -8063,7 +8181,7 @@ impl std::str::FromStr for SetExpression {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing SetExpression".to_string(),
+                        "Missing value while parsing ReportMetricsRequest".to_string(),
                     )
                 }
             };
@@ -8072,25 +8190,26 @@ impl std::str::FromStr for SetExpression {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "type" => intermediate_rep.r#type.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
+                    "report-type" => intermediate_rep.report_type.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
                     #[allow(clippy::redundant_clone)]
-                    "term" => intermediate_rep.term.push(
-                        <models::Term as std::str::FromStr>::from_str(val)
-                            .map_err(|x| x.to_string())?,
-                    ),
-                    "values" => {
-                        return std::result::Result::Err(
-                            "Parsing a container in this style is not supported in SetExpression"
-                                .to_string(),
-                        )
-                    }
-                    _ => {
-                        return std::result::Result::Err(
-                            "Unexpected key while parsing SetExpression".to_string(),
-                        )
-                    }
+                    "table-name" => intermediate_rep.table_name.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "snapshot-id" => intermediate_rep.snapshot_id.push(<i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "filter" => intermediate_rep.filter.push(<serde_json::Value as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "schema-id" => intermediate_rep.schema_id.push(<i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "projected-field-ids" => return std::result::Result::Err("Parsing a container in this style is not supported in ReportMetricsRequest".to_string()),
+                    "projected-field-names" => return std::result::Result::Err("Parsing a container in this style is not supported in ReportMetricsRequest".to_string()),
+                    #[allow(clippy::redundant_clone)]
+                    "projection" => intermediate_rep.projection.push(<models::Schema as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "sequence-number" => intermediate_rep.sequence_number.push(<i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "operation" => intermediate_rep.operation.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "metrics" => return std::result::Result::Err("Parsing a container in this style is not supported in ReportMetricsRequest".to_string()),
+                    "metadata" => return std::result::Result::Err("Parsing a container in this style is not supported in ReportMetricsRequest".to_string()),
+                    _ => return std::result::Result::Err("Unexpected key while parsing ReportMetricsRequest".to_string())
                 }
             }
 
@@ -8099,40 +8218,55 @@ impl std::str::FromStr for SetExpression {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(SetExpression {
-            r#type: intermediate_rep
-                .r#type
+        std::result::Result::Ok(ReportMetricsRequest {
+            report_type: intermediate_rep
+                .report_type
                 .into_iter()
                 .next()
-                .ok_or_else(|| "type missing in SetExpression".to_string())?,
-            term: intermediate_rep
-                .term
+                .ok_or_else(|| "report-type missing in ReportMetricsRequest".to_string())?,
+            table_name: intermediate_rep
+                .table_name
                 .into_iter()
                 .next()
-                .ok_or_else(|| "term missing in SetExpression".to_string())?,
-            values: intermediate_rep
-                .values
+                .ok_or_else(|| "table-name missing in ReportMetricsRequest".to_string())?,
+            snapshot_id: intermediate_rep
+                .snapshot_id
                 .into_iter()
                 .next()
-                .ok_or_else(|| "values missing in SetExpression".to_string())?,
+                .ok_or_else(|| "snapshot-id missing in ReportMetricsRequest".to_string())?,
+            filter: intermediate_rep.filter.into_iter().next(),
+            schema_id: intermediate_rep.schema_id.into_iter().next(),
+            projected_field_ids: intermediate_rep.projected_field_ids.into_iter().next(),
+            projected_field_names: intermediate_rep.projected_field_names.into_iter().next(),
+            projection: intermediate_rep.projection.into_iter().next(),
+            sequence_number: intermediate_rep.sequence_number.into_iter().next(),
+            operation: intermediate_rep.operation.into_iter().next(),
+            metrics: intermediate_rep
+                .metrics
+                .into_iter()
+                .next()
+                .ok_or_else(|| "metrics missing in ReportMetricsRequest".to_string())?,
+            metadata: intermediate_rep.metadata.into_iter().next(),
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<SetExpression> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<ReportMetricsRequest> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<SetExpression>> for hyper::header::HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<ReportMetricsRequest>>
+    for hyper::header::HeaderValue
+{
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<SetExpression>,
+        hdr_value: header::IntoHeaderValue<ReportMetricsRequest>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for SetExpression - value: {} is invalid {}",
+                "Invalid header value for ReportMetricsRequest - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -8140,18 +8274,20 @@ impl std::convert::TryFrom<header::IntoHeaderValue<SetExpression>> for hyper::he
 }
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<SetExpression> {
+impl std::convert::TryFrom<hyper::header::HeaderValue>
+    for header::IntoHeaderValue<ReportMetricsRequest>
+{
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <SetExpression as std::str::FromStr>::from_str(value) {
+                match <ReportMetricsRequest as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into SetExpression - {}",
+                        "Unable to convert header value '{}' into ReportMetricsRequest - {}",
                         value, err
                     )),
                 }
@@ -8164,44 +8300,51 @@ impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderVal
     }
 }
 
+/// A role whose grants apply to all of its principals
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct SetLocationUpdate {
-    // Note: inline enums are not fully supported by openapi-generator
-    #[serde(rename = "action")]
-    pub action: String,
+pub struct Role {
+    #[serde(rename = "name")]
+    pub name: String,
 
-    #[serde(rename = "location")]
-    pub location: String,
+    /// The subjects of the access tokens of the members of the role
+    #[serde(rename = "principals")]
+    pub principals: Vec<String>,
 }
 
-impl SetLocationUpdate {
+impl Role {
     #[allow(clippy::new_without_default)]
-    pub fn new(action: String, location: String) -> SetLocationUpdate {
-        SetLocationUpdate { action, location }
+    pub fn new(name: String, principals: Vec<String>) -> Role {
+        Role { name, principals }
     }
 }
 
-/// Converts the SetLocationUpdate value to the Query Parameters representation (style=form, explode=false)
+/// Converts the Role value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for SetLocationUpdate {
+impl std::string::ToString for Role {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
-            Some("action".to_string()),
-            Some(self.action.to_string()),
-            Some("location".to_string()),
-            Some(self.location.to_string()),
+            Some("name".to_string()),
+            Some(self.name.to_string()),
+            Some("principals".to_string()),
+            Some(
+                self.principals
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect::<Vec<_>>()
+                    .join(","),
+            ),
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a SetLocationUpdate value
+/// Converts Query Parameters representation (style=form, explode=false) to a Role value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for SetLocationUpdate {
+impl std::str::FromStr for Role {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -8209,8 +8352,8 @@ impl std::str::FromStr for SetLocationUpdate {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub action: Vec<String>,
-            pub location: Vec<String>,
+            pub name: Vec<String>,
+            pub principals: Vec<Vec<String>>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -8223,9 +8366,7 @@ impl std::str::FromStr for SetLocationUpdate {
             let val = match string_iter.next() {
                 Some(x) => x,
                 None => {
-                    return std::result::Result::Err(
-                        "Missing value while parsing SetLocationUpdate".to_string(),
-                    )
+                    return std::result::Result::Err("Missing value while parsing Role".to_string())
                 }
             };
 
@@ -8233,16 +8374,18 @@ impl std::str::FromStr for SetLocationUpdate {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "action" => intermediate_rep.action.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "location" => intermediate_rep.location.push(
+                    "name" => intermediate_rep.name.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
+                    "principals" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in Role"
+                                .to_string(),
+                        )
+                    }
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing SetLocationUpdate".to_string(),
+                            "Unexpected key while parsing Role".to_string(),
                         )
                     }
                 }
@@ -8253,37 +8396,35 @@ impl std::str::FromStr for SetLocationUpdate {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(SetLocationUpdate {
-            action: intermediate_rep
-                .action
+        std::result::Result::Ok(Role {
+            name: intermediate_rep
+                .name
                 .into_iter()
                 .next()
-                .ok_or_else(|| "action missing in SetLocationUpdate".to_string())?,
-            location: intermediate_rep
-                .location
+                .ok_or_else(|| "name missing in Role".to_string())?,
+            principals: intermediate_rep
+                .principals
                 .into_iter()
                 .next()
-                .ok_or_else(|| "location missing in SetLocationUpdate".to_string())?,
+                .ok_or_else(|| "principals missing in Role".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<SetLocationUpdate> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<Role> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<SetLocationUpdate>>
-    for hyper::header::HeaderValue
-{
+impl std::convert::TryFrom<header::IntoHeaderValue<Role>> for hyper::header::HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<SetLocationUpdate>,
+        hdr_value: header::IntoHeaderValue<Role>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for SetLocationUpdate - value: {} is invalid {}",
+                "Invalid header value for Role - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -8291,24 +8432,20 @@ impl std::convert::TryFrom<header::IntoHeaderValue<SetLocationUpdate>>
 }
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<hyper::header::HeaderValue>
-    for header::IntoHeaderValue<SetLocationUpdate>
-{
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<Role> {
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
-            std::result::Result::Ok(value) => {
-                match <SetLocationUpdate as std::str::FromStr>::from_str(value) {
-                    std::result::Result::Ok(value) => {
-                        std::result::Result::Ok(header::IntoHeaderValue(value))
-                    }
-                    std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into SetLocationUpdate - {}",
-                        value, err
-                    )),
+            std::result::Result::Ok(value) => match <Role as std::str::FromStr>::from_str(value) {
+                std::result::Result::Ok(value) => {
+                    std::result::Result::Ok(header::IntoHeaderValue(value))
                 }
-            }
+                std::result::Result::Err(err) => std::result::Result::Err(format!(
+                    "Unable to convert header value '{}' into Role - {}",
+                    value, err
+                )),
+            },
             std::result::Result::Err(e) => std::result::Result::Err(format!(
                 "Unable to convert header: {:?} to string: {}",
                 hdr_value, e
@@ -8319,36 +8456,68 @@ impl std::convert::TryFrom<hyper::header::HeaderValue>
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct SetLocationUpdateAllOf {
-    #[serde(rename = "location")]
-    pub location: String,
+pub struct ScanReport {
+    #[serde(rename = "table-name")]
+    pub table_name: String,
+
+    #[serde(rename = "snapshot-id")]
+    pub snapshot_id: i64,
+
+    #[serde(rename = "filter")]
+    pub filter: models::Expression,
+
+    #[serde(rename = "projection")]
+    pub projection: models::Schema,
+
+    #[serde(rename = "metrics")]
+    pub metrics: std::collections::HashMap<String, models::MetricResult>,
 }
 
-impl SetLocationUpdateAllOf {
+impl ScanReport {
     #[allow(clippy::new_without_default)]
-    pub fn new(location: String) -> SetLocationUpdateAllOf {
-        SetLocationUpdateAllOf { location }
+    pub fn new(
+        table_name: String,
+        snapshot_id: i64,
+        filter: models::Expression,
+        projection: models::Schema,
+        metrics: std::collections::HashMap<String, models::MetricResult>,
+    ) -> ScanReport {
+        ScanReport {
+            table_name,
+            snapshot_id,
+            filter,
+            projection,
+            metrics,
+        }
     }
 }
 
-/// Converts the SetLocationUpdateAllOf value to the Query Parameters representation (style=form, explode=false)
+/// Converts the ScanReport value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for SetLocationUpdateAllOf {
+impl std::string::ToString for ScanReport {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
-            Some("location".to_string()),
-            Some(self.location.to_string()),
+            Some("table-name".to_string()),
+            Some(self.table_name.to_string()),
+            Some("snapshot-id".to_string()),
+            Some(self.snapshot_id.to_string()),
+            // Skipping filter in query parameter serialization
+
+            // Skipping projection in query parameter serialization
+
+            // Skipping metrics in query parameter serialization
+            // Skipping metrics in query parameter serialization
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a SetLocationUpdateAllOf value
+/// Converts Query Parameters representation (style=form, explode=false) to a ScanReport value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for SetLocationUpdateAllOf {
+impl std::str::FromStr for ScanReport {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -8356,7 +8525,11 @@ impl std::str::FromStr for SetLocationUpdateAllOf {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub location: Vec<String>,
+            pub table_name: Vec<String>,
+            pub snapshot_id: Vec<i64>,
+            pub filter: Vec<models::Expression>,
+            pub projection: Vec<models::Schema>,
+            pub metrics: Vec<std::collections::HashMap<String, models::MetricResult>>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -8370,7 +8543,7 @@ impl std::str::FromStr for SetLocationUpdateAllOf {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing SetLocationUpdateAllOf".to_string(),
+                        "Missing value while parsing ScanReport".to_string(),
                     )
                 }
             };
@@ -8379,12 +8552,32 @@ impl std::str::FromStr for SetLocationUpdateAllOf {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "location" => intermediate_rep.location.push(
+                    "table-name" => intermediate_rep.table_name.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
+                    #[allow(clippy::redundant_clone)]
+                    "snapshot-id" => intermediate_rep.snapshot_id.push(
+                        <i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "filter" => intermediate_rep.filter.push(
+                        <models::Expression as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "projection" => intermediate_rep.projection.push(
+                        <models::Schema as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    "metrics" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in ScanReport"
+                                .to_string(),
+                        )
+                    }
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing SetLocationUpdateAllOf".to_string(),
+                            "Unexpected key while parsing ScanReport".to_string(),
                         )
                     }
                 }
@@ -8395,32 +8588,50 @@ impl std::str::FromStr for SetLocationUpdateAllOf {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(SetLocationUpdateAllOf {
-            location: intermediate_rep
-                .location
+        std::result::Result::Ok(ScanReport {
+            table_name: intermediate_rep
+                .table_name
                 .into_iter()
                 .next()
-                .ok_or_else(|| "location missing in SetLocationUpdateAllOf".to_string())?,
+                .ok_or_else(|| "table-name missing in ScanReport".to_string())?,
+            snapshot_id: intermediate_rep
+                .snapshot_id
+                .into_iter()
+                .next()
+                .ok_or_else(|| "snapshot-id missing in ScanReport".to_string())?,
+            filter: intermediate_rep
+                .filter
+                .into_iter()
+                .next()
+                .ok_or_else(|| "filter missing in ScanReport".to_string())?,
+            projection: intermediate_rep
+                .projection
+                .into_iter()
+                .next()
+                .ok_or_else(|| "projection missing in ScanReport".to_string())?,
+            metrics: intermediate_rep
+                .metrics
+                .into_iter()
+                .next()
+                .ok_or_else(|| "metrics missing in ScanReport".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<SetLocationUpdateAllOf> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<ScanReport> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<SetLocationUpdateAllOf>>
-    for hyper::header::HeaderValue
-{
+impl std::convert::TryFrom<header::IntoHeaderValue<ScanReport>> for hyper::header::HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<SetLocationUpdateAllOf>,
+        hdr_value: header::IntoHeaderValue<ScanReport>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for SetLocationUpdateAllOf - value: {} is invalid {}",
+                "Invalid header value for ScanReport - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -8428,20 +8639,18 @@ impl std::convert::TryFrom<header::IntoHeaderValue<SetLocationUpdateAllOf>>
 }
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<hyper::header::HeaderValue>
-    for header::IntoHeaderValue<SetLocationUpdateAllOf>
-{
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<ScanReport> {
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <SetLocationUpdateAllOf as std::str::FromStr>::from_str(value) {
+                match <ScanReport as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into SetLocationUpdateAllOf - {}",
+                        "Unable to convert header value '{}' into ScanReport - {}",
                         value, err
                     )),
                 }
@@ -8456,44 +8665,70 @@ impl std::convert::TryFrom<hyper::header::HeaderValue>
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct SetPropertiesUpdate {
+pub struct Schema {
     // Note: inline enums are not fully supported by openapi-generator
-    #[serde(rename = "action")]
-    pub action: String,
+    #[serde(rename = "type")]
+    pub r#type: String,
 
-    #[serde(rename = "updates")]
-    pub updates: std::collections::HashMap<String, String>,
-}
+    #[serde(rename = "fields")]
+    pub fields: Vec<models::StructField>,
 
-impl SetPropertiesUpdate {
-    #[allow(clippy::new_without_default)]
-    pub fn new(
-        action: String,
-        updates: std::collections::HashMap<String, String>,
-    ) -> SetPropertiesUpdate {
-        SetPropertiesUpdate { action, updates }
-    }
+    #[serde(rename = "schema-id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema_id: Option<isize>,
+
+    #[serde(rename = "identifier-field-ids")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub identifier_field_ids: Option<Vec<i32>>,
 }
 
-/// Converts the SetPropertiesUpdate value to the Query Parameters representation (style=form, explode=false)
-/// specified in https://swagger.io/docs/specification/serialization/
-/// Should be implemented in a serde serializer
-impl std::string::ToString for SetPropertiesUpdate {
+impl Schema {
+    #[allow(clippy::new_without_default)]
+    pub fn new(r#type: String, fields: Vec<models::StructField>) -> Schema {
+        Schema {
+            r#type,
+            fields,
+            schema_id: None,
+            identifier_field_ids: None,
+        }
+    }
+}
+
+/// Converts the Schema value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for Schema {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
-            Some("action".to_string()),
-            Some(self.action.to_string()),
-            // Skipping updates in query parameter serialization
+            Some("type".to_string()),
+            Some(self.r#type.to_string()),
+            // Skipping fields in query parameter serialization
+            self.schema_id
+                .as_ref()
+                .map(|schema_id| vec!["schema-id".to_string(), schema_id.to_string()].join(",")),
+            self.identifier_field_ids
+                .as_ref()
+                .map(|identifier_field_ids| {
+                    vec![
+                        "identifier-field-ids".to_string(),
+                        identifier_field_ids
+                            .iter()
+                            .map(|x| x.to_string())
+                            .collect::<Vec<_>>()
+                            .join(","),
+                    ]
+                    .join(",")
+                }),
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a SetPropertiesUpdate value
+/// Converts Query Parameters representation (style=form, explode=false) to a Schema value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for SetPropertiesUpdate {
+impl std::str::FromStr for Schema {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -8501,8 +8736,10 @@ impl std::str::FromStr for SetPropertiesUpdate {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub action: Vec<String>,
-            pub updates: Vec<std::collections::HashMap<String, String>>,
+            pub r#type: Vec<String>,
+            pub fields: Vec<Vec<models::StructField>>,
+            pub schema_id: Vec<isize>,
+            pub identifier_field_ids: Vec<Vec<i32>>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -8516,7 +8753,7 @@ impl std::str::FromStr for SetPropertiesUpdate {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing SetPropertiesUpdate".to_string(),
+                        "Missing value while parsing Schema".to_string(),
                     )
                 }
             };
@@ -8525,16 +8762,28 @@ impl std::str::FromStr for SetPropertiesUpdate {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "action" => intermediate_rep.action.push(
+                    "type" => intermediate_rep.r#type.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
-                    "updates" => return std::result::Result::Err(
-                        "Parsing a container in this style is not supported in SetPropertiesUpdate"
-                            .to_string(),
+                    "fields" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in Schema"
+                                .to_string(),
+                        )
+                    }
+                    #[allow(clippy::redundant_clone)]
+                    "schema-id" => intermediate_rep.schema_id.push(
+                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
+                    "identifier-field-ids" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in Schema"
+                                .to_string(),
+                        )
+                    }
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing SetPropertiesUpdate".to_string(),
+                            "Unexpected key while parsing Schema".to_string(),
                         )
                     }
                 }
@@ -8545,37 +8794,37 @@ impl std::str::FromStr for SetPropertiesUpdate {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(SetPropertiesUpdate {
-            action: intermediate_rep
-                .action
+        std::result::Result::Ok(Schema {
+            r#type: intermediate_rep
+                .r#type
                 .into_iter()
                 .next()
-                .ok_or_else(|| "action missing in SetPropertiesUpdate".to_string())?,
-            updates: intermediate_rep
-                .updates
+                .ok_or_else(|| "type missing in Schema".to_string())?,
+            fields: intermediate_rep
+                .fields
                 .into_iter()
                 .next()
-                .ok_or_else(|| "updates missing in SetPropertiesUpdate".to_string())?,
+                .ok_or_else(|| "fields missing in Schema".to_string())?,
+            schema_id: intermediate_rep.schema_id.into_iter().next(),
+            identifier_field_ids: intermediate_rep.identifier_field_ids.into_iter().next(),
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<SetPropertiesUpdate> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<Schema> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<SetPropertiesUpdate>>
-    for hyper::header::HeaderValue
-{
+impl std::convert::TryFrom<header::IntoHeaderValue<Schema>> for hyper::header::HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<SetPropertiesUpdate>,
+        hdr_value: header::IntoHeaderValue<Schema>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for SetPropertiesUpdate - value: {} is invalid {}",
+                "Invalid header value for Schema - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -8583,20 +8832,18 @@ impl std::convert::TryFrom<header::IntoHeaderValue<SetPropertiesUpdate>>
 }
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<hyper::header::HeaderValue>
-    for header::IntoHeaderValue<SetPropertiesUpdate>
-{
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<Schema> {
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <SetPropertiesUpdate as std::str::FromStr>::from_str(value) {
+                match <Schema as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into SetPropertiesUpdate - {}",
+                        "Unable to convert header value '{}' into Schema - {}",
                         value, err
                     )),
                 }
@@ -8611,36 +8858,58 @@ impl std::convert::TryFrom<hyper::header::HeaderValue>
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct SetPropertiesUpdateAllOf {
-    #[serde(rename = "updates")]
-    pub updates: std::collections::HashMap<String, String>,
+pub struct SchemaAllOf {
+    #[serde(rename = "schema-id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema_id: Option<isize>,
+
+    #[serde(rename = "identifier-field-ids")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub identifier_field_ids: Option<Vec<i32>>,
 }
 
-impl SetPropertiesUpdateAllOf {
+impl SchemaAllOf {
     #[allow(clippy::new_without_default)]
-    pub fn new(updates: std::collections::HashMap<String, String>) -> SetPropertiesUpdateAllOf {
-        SetPropertiesUpdateAllOf { updates }
+    pub fn new() -> SchemaAllOf {
+        SchemaAllOf {
+            schema_id: None,
+            identifier_field_ids: None,
+        }
     }
 }
 
-/// Converts the SetPropertiesUpdateAllOf value to the Query Parameters representation (style=form, explode=false)
+/// Converts the SchemaAllOf value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for SetPropertiesUpdateAllOf {
+impl std::string::ToString for SchemaAllOf {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
-            // Skipping updates in query parameter serialization
-
+            self.schema_id
+                .as_ref()
+                .map(|schema_id| vec!["schema-id".to_string(), schema_id.to_string()].join(",")),
+            self.identifier_field_ids
+                .as_ref()
+                .map(|identifier_field_ids| {
+                    vec![
+                        "identifier-field-ids".to_string(),
+                        identifier_field_ids
+                            .iter()
+                            .map(|x| x.to_string())
+                            .collect::<Vec<_>>()
+                            .join(","),
+                    ]
+                    .join(",")
+                }),
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a SetPropertiesUpdateAllOf value
+/// Converts Query Parameters representation (style=form, explode=false) to a SchemaAllOf value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for SetPropertiesUpdateAllOf {
+impl std::str::FromStr for SchemaAllOf {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -8648,7 +8917,8 @@ impl std::str::FromStr for SetPropertiesUpdateAllOf {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub updates: Vec<std::collections::HashMap<String, String>>,
+            pub schema_id: Vec<isize>,
+            pub identifier_field_ids: Vec<Vec<i32>>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -8662,7 +8932,7 @@ impl std::str::FromStr for SetPropertiesUpdateAllOf {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing SetPropertiesUpdateAllOf".to_string(),
+                        "Missing value while parsing SchemaAllOf".to_string(),
                     )
                 }
             };
@@ -8670,8 +8940,21 @@ impl std::str::FromStr for SetPropertiesUpdateAllOf {
             if let Some(key) = key_result {
                 #[allow(clippy::match_single_binding)]
                 match key {
-                    "updates" => return std::result::Result::Err("Parsing a container in this style is not supported in SetPropertiesUpdateAllOf".to_string()),
-                    _ => return std::result::Result::Err("Unexpected key while parsing SetPropertiesUpdateAllOf".to_string())
+                    #[allow(clippy::redundant_clone)]
+                    "schema-id" => intermediate_rep.schema_id.push(
+                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    "identifier-field-ids" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in SchemaAllOf"
+                                .to_string(),
+                        )
+                    }
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing SchemaAllOf".to_string(),
+                        )
+                    }
                 }
             }
 
@@ -8680,32 +8963,27 @@ impl std::str::FromStr for SetPropertiesUpdateAllOf {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(SetPropertiesUpdateAllOf {
-            updates: intermediate_rep
-                .updates
-                .into_iter()
-                .next()
-                .ok_or_else(|| "updates missing in SetPropertiesUpdateAllOf".to_string())?,
+        std::result::Result::Ok(SchemaAllOf {
+            schema_id: intermediate_rep.schema_id.into_iter().next(),
+            identifier_field_ids: intermediate_rep.identifier_field_ids.into_iter().next(),
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<SetPropertiesUpdateAllOf> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<SchemaAllOf> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<SetPropertiesUpdateAllOf>>
-    for hyper::header::HeaderValue
-{
+impl std::convert::TryFrom<header::IntoHeaderValue<SchemaAllOf>> for hyper::header::HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<SetPropertiesUpdateAllOf>,
+        hdr_value: header::IntoHeaderValue<SchemaAllOf>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for SetPropertiesUpdateAllOf - value: {} is invalid {}",
+                "Invalid header value for SchemaAllOf - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -8713,20 +8991,18 @@ impl std::convert::TryFrom<header::IntoHeaderValue<SetPropertiesUpdateAllOf>>
 }
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<hyper::header::HeaderValue>
-    for header::IntoHeaderValue<SetPropertiesUpdateAllOf>
-{
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<SchemaAllOf> {
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <SetPropertiesUpdateAllOf as std::str::FromStr>::from_str(value) {
+                match <SchemaAllOf as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into SetPropertiesUpdateAllOf - {}",
+                        "Unable to convert header value '{}' into SchemaAllOf - {}",
                         value, err
                     )),
                 }
@@ -8741,99 +9017,43 @@ impl std::convert::TryFrom<hyper::header::HeaderValue>
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct SetSnapshotRefUpdate {
+pub struct SetCurrentSchemaUpdate {
     // Note: inline enums are not fully supported by openapi-generator
     #[serde(rename = "action")]
     pub action: String,
 
-    // Note: inline enums are not fully supported by openapi-generator
-    #[serde(rename = "type")]
-    pub r#type: String,
-
-    #[serde(rename = "snapshot-id")]
-    pub snapshot_id: i64,
-
-    #[serde(rename = "max-ref-age-ms")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub max_ref_age_ms: Option<i64>,
-
-    #[serde(rename = "max-snapshot-age-ms")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub max_snapshot_age_ms: Option<i64>,
-
-    #[serde(rename = "min-snapshots-to-keep")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub min_snapshots_to_keep: Option<isize>,
-
-    #[serde(rename = "ref-name")]
-    pub ref_name: String,
+    /// Schema ID to set as current, or -1 to set last added schema
+    #[serde(rename = "schema-id")]
+    pub schema_id: isize,
 }
 
-impl SetSnapshotRefUpdate {
+impl SetCurrentSchemaUpdate {
     #[allow(clippy::new_without_default)]
-    pub fn new(
-        action: String,
-        r#type: String,
-        snapshot_id: i64,
-        ref_name: String,
-    ) -> SetSnapshotRefUpdate {
-        SetSnapshotRefUpdate {
-            action,
-            r#type,
-            snapshot_id,
-            max_ref_age_ms: None,
-            max_snapshot_age_ms: None,
-            min_snapshots_to_keep: None,
-            ref_name,
-        }
+    pub fn new(action: String, schema_id: isize) -> SetCurrentSchemaUpdate {
+        SetCurrentSchemaUpdate { action, schema_id }
     }
 }
 
-/// Converts the SetSnapshotRefUpdate value to the Query Parameters representation (style=form, explode=false)
+/// Converts the SetCurrentSchemaUpdate value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for SetSnapshotRefUpdate {
+impl std::string::ToString for SetCurrentSchemaUpdate {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
             Some("action".to_string()),
             Some(self.action.to_string()),
-            Some("type".to_string()),
-            Some(self.r#type.to_string()),
-            Some("snapshot-id".to_string()),
-            Some(self.snapshot_id.to_string()),
-            self.max_ref_age_ms.as_ref().map(|max_ref_age_ms| {
-                vec!["max-ref-age-ms".to_string(), max_ref_age_ms.to_string()].join(",")
-            }),
-            self.max_snapshot_age_ms
-                .as_ref()
-                .map(|max_snapshot_age_ms| {
-                    vec![
-                        "max-snapshot-age-ms".to_string(),
-                        max_snapshot_age_ms.to_string(),
-                    ]
-                    .join(",")
-                }),
-            self.min_snapshots_to_keep
-                .as_ref()
-                .map(|min_snapshots_to_keep| {
-                    vec![
-                        "min-snapshots-to-keep".to_string(),
-                        min_snapshots_to_keep.to_string(),
-                    ]
-                    .join(",")
-                }),
-            Some("ref-name".to_string()),
-            Some(self.ref_name.to_string()),
+            Some("schema-id".to_string()),
+            Some(self.schema_id.to_string()),
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a SetSnapshotRefUpdate value
+/// Converts Query Parameters representation (style=form, explode=false) to a SetCurrentSchemaUpdate value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for SetSnapshotRefUpdate {
+impl std::str::FromStr for SetCurrentSchemaUpdate {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -8842,12 +9062,7 @@ impl std::str::FromStr for SetSnapshotRefUpdate {
         #[allow(dead_code)]
         struct IntermediateRep {
             pub action: Vec<String>,
-            pub r#type: Vec<String>,
-            pub snapshot_id: Vec<i64>,
-            pub max_ref_age_ms: Vec<i64>,
-            pub max_snapshot_age_ms: Vec<i64>,
-            pub min_snapshots_to_keep: Vec<isize>,
-            pub ref_name: Vec<String>,
+            pub schema_id: Vec<isize>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -8861,7 +9076,7 @@ impl std::str::FromStr for SetSnapshotRefUpdate {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing SetSnapshotRefUpdate".to_string(),
+                        "Missing value while parsing SetCurrentSchemaUpdate".to_string(),
                     )
                 }
             };
@@ -8874,32 +9089,12 @@ impl std::str::FromStr for SetSnapshotRefUpdate {
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "type" => intermediate_rep.r#type.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "snapshot-id" => intermediate_rep.snapshot_id.push(
-                        <i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "max-ref-age-ms" => intermediate_rep.max_ref_age_ms.push(
-                        <i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "max-snapshot-age-ms" => intermediate_rep.max_snapshot_age_ms.push(
-                        <i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "min-snapshots-to-keep" => intermediate_rep.min_snapshots_to_keep.push(
+                    "schema-id" => intermediate_rep.schema_id.push(
                         <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
-                    #[allow(clippy::redundant_clone)]
-                    "ref-name" => intermediate_rep.ref_name.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing SetSnapshotRefUpdate".to_string(),
+                            "Unexpected key while parsing SetCurrentSchemaUpdate".to_string(),
                         )
                     }
                 }
@@ -8910,50 +9105,37 @@ impl std::str::FromStr for SetSnapshotRefUpdate {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(SetSnapshotRefUpdate {
+        std::result::Result::Ok(SetCurrentSchemaUpdate {
             action: intermediate_rep
                 .action
                 .into_iter()
                 .next()
-                .ok_or_else(|| "action missing in SetSnapshotRefUpdate".to_string())?,
-            r#type: intermediate_rep
-                .r#type
-                .into_iter()
-                .next()
-                .ok_or_else(|| "type missing in SetSnapshotRefUpdate".to_string())?,
-            snapshot_id: intermediate_rep
-                .snapshot_id
-                .into_iter()
-                .next()
-                .ok_or_else(|| "snapshot-id missing in SetSnapshotRefUpdate".to_string())?,
-            max_ref_age_ms: intermediate_rep.max_ref_age_ms.into_iter().next(),
-            max_snapshot_age_ms: intermediate_rep.max_snapshot_age_ms.into_iter().next(),
-            min_snapshots_to_keep: intermediate_rep.min_snapshots_to_keep.into_iter().next(),
-            ref_name: intermediate_rep
-                .ref_name
+                .ok_or_else(|| "action missing in SetCurrentSchemaUpdate".to_string())?,
+            schema_id: intermediate_rep
+                .schema_id
                 .into_iter()
                 .next()
-                .ok_or_else(|| "ref-name missing in SetSnapshotRefUpdate".to_string())?,
+                .ok_or_else(|| "schema-id missing in SetCurrentSchemaUpdate".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<SetSnapshotRefUpdate> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<SetCurrentSchemaUpdate> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<SetSnapshotRefUpdate>>
+impl std::convert::TryFrom<header::IntoHeaderValue<SetCurrentSchemaUpdate>>
     for hyper::header::HeaderValue
 {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<SetSnapshotRefUpdate>,
+        hdr_value: header::IntoHeaderValue<SetCurrentSchemaUpdate>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for SetSnapshotRefUpdate - value: {} is invalid {}",
+                "Invalid header value for SetCurrentSchemaUpdate - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -8962,19 +9144,19 @@ impl std::convert::TryFrom<header::IntoHeaderValue<SetSnapshotRefUpdate>>
 
 #[cfg(any(feature = "client", feature = "server"))]
 impl std::convert::TryFrom<hyper::header::HeaderValue>
-    for header::IntoHeaderValue<SetSnapshotRefUpdate>
+    for header::IntoHeaderValue<SetCurrentSchemaUpdate>
 {
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <SetSnapshotRefUpdate as std::str::FromStr>::from_str(value) {
+                match <SetCurrentSchemaUpdate as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into SetSnapshotRefUpdate - {}",
+                        "Unable to convert header value '{}' into SetCurrentSchemaUpdate - {}",
                         value, err
                     )),
                 }
@@ -8989,36 +9171,37 @@ impl std::convert::TryFrom<hyper::header::HeaderValue>
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct SetSnapshotRefUpdateAllOf {
-    #[serde(rename = "ref-name")]
-    pub ref_name: String,
+pub struct SetCurrentSchemaUpdateAllOf {
+    /// Schema ID to set as current, or -1 to set last added schema
+    #[serde(rename = "schema-id")]
+    pub schema_id: isize,
 }
 
-impl SetSnapshotRefUpdateAllOf {
+impl SetCurrentSchemaUpdateAllOf {
     #[allow(clippy::new_without_default)]
-    pub fn new(ref_name: String) -> SetSnapshotRefUpdateAllOf {
-        SetSnapshotRefUpdateAllOf { ref_name }
+    pub fn new(schema_id: isize) -> SetCurrentSchemaUpdateAllOf {
+        SetCurrentSchemaUpdateAllOf { schema_id }
     }
 }
 
-/// Converts the SetSnapshotRefUpdateAllOf value to the Query Parameters representation (style=form, explode=false)
+/// Converts the SetCurrentSchemaUpdateAllOf value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for SetSnapshotRefUpdateAllOf {
+impl std::string::ToString for SetCurrentSchemaUpdateAllOf {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
-            Some("ref-name".to_string()),
-            Some(self.ref_name.to_string()),
+            Some("schema-id".to_string()),
+            Some(self.schema_id.to_string()),
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a SetSnapshotRefUpdateAllOf value
+/// Converts Query Parameters representation (style=form, explode=false) to a SetCurrentSchemaUpdateAllOf value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for SetSnapshotRefUpdateAllOf {
+impl std::str::FromStr for SetCurrentSchemaUpdateAllOf {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -9026,7 +9209,7 @@ impl std::str::FromStr for SetSnapshotRefUpdateAllOf {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub ref_name: Vec<String>,
+            pub schema_id: Vec<isize>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -9040,7 +9223,7 @@ impl std::str::FromStr for SetSnapshotRefUpdateAllOf {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing SetSnapshotRefUpdateAllOf".to_string(),
+                        "Missing value while parsing SetCurrentSchemaUpdateAllOf".to_string(),
                     )
                 }
             };
@@ -9049,12 +9232,12 @@ impl std::str::FromStr for SetSnapshotRefUpdateAllOf {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "ref-name" => intermediate_rep.ref_name.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "schema-id" => intermediate_rep.schema_id.push(
+                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing SetSnapshotRefUpdateAllOf".to_string(),
+                            "Unexpected key while parsing SetCurrentSchemaUpdateAllOf".to_string(),
                         )
                     }
                 }
@@ -9065,32 +9248,32 @@ impl std::str::FromStr for SetSnapshotRefUpdateAllOf {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(SetSnapshotRefUpdateAllOf {
-            ref_name: intermediate_rep
-                .ref_name
+        std::result::Result::Ok(SetCurrentSchemaUpdateAllOf {
+            schema_id: intermediate_rep
+                .schema_id
                 .into_iter()
                 .next()
-                .ok_or_else(|| "ref-name missing in SetSnapshotRefUpdateAllOf".to_string())?,
+                .ok_or_else(|| "schema-id missing in SetCurrentSchemaUpdateAllOf".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<SetSnapshotRefUpdateAllOf> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<SetCurrentSchemaUpdateAllOf> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<SetSnapshotRefUpdateAllOf>>
+impl std::convert::TryFrom<header::IntoHeaderValue<SetCurrentSchemaUpdateAllOf>>
     for hyper::header::HeaderValue
 {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<SetSnapshotRefUpdateAllOf>,
+        hdr_value: header::IntoHeaderValue<SetCurrentSchemaUpdateAllOf>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for SetSnapshotRefUpdateAllOf - value: {} is invalid {}",
+                "Invalid header value for SetCurrentSchemaUpdateAllOf - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -9099,19 +9282,19 @@ impl std::convert::TryFrom<header::IntoHeaderValue<SetSnapshotRefUpdateAllOf>>
 
 #[cfg(any(feature = "client", feature = "server"))]
 impl std::convert::TryFrom<hyper::header::HeaderValue>
-    for header::IntoHeaderValue<SetSnapshotRefUpdateAllOf>
+    for header::IntoHeaderValue<SetCurrentSchemaUpdateAllOf>
 {
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <SetSnapshotRefUpdateAllOf as std::str::FromStr>::from_str(value) {
+                match <SetCurrentSchemaUpdateAllOf as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into SetSnapshotRefUpdateAllOf - {}",
+                        "Unable to convert header value '{}' into SetCurrentSchemaUpdateAllOf - {}",
                         value, err
                     )),
                 }
@@ -9126,89 +9309,46 @@ impl std::convert::TryFrom<hyper::header::HeaderValue>
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct Snapshot {
-    #[serde(rename = "snapshot-id")]
-    pub snapshot_id: isize,
-
-    #[serde(rename = "parent-snapshot-id")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub parent_snapshot_id: Option<isize>,
-
-    #[serde(rename = "sequence-number")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub sequence_number: Option<isize>,
-
-    #[serde(rename = "timestamp-ms")]
-    pub timestamp_ms: isize,
-
-    /// Location of the snapshot's manifest list file
-    #[serde(rename = "manifest-list")]
-    pub manifest_list: String,
-
-    #[serde(rename = "summary")]
-    pub summary: models::SnapshotSummary,
+pub struct SetDefaultSortOrderUpdate {
+    // Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "action")]
+    pub action: String,
 
-    #[serde(rename = "schema-id")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub schema_id: Option<isize>,
+    /// Sort order ID to set as the default, or -1 to set last added sort order
+    #[serde(rename = "sort-order-id")]
+    pub sort_order_id: isize,
 }
 
-impl Snapshot {
+impl SetDefaultSortOrderUpdate {
     #[allow(clippy::new_without_default)]
-    pub fn new(
-        snapshot_id: isize,
-        timestamp_ms: isize,
-        manifest_list: String,
-        summary: models::SnapshotSummary,
-    ) -> Snapshot {
-        Snapshot {
-            snapshot_id,
-            parent_snapshot_id: None,
-            sequence_number: None,
-            timestamp_ms,
-            manifest_list,
-            summary,
-            schema_id: None,
+    pub fn new(action: String, sort_order_id: isize) -> SetDefaultSortOrderUpdate {
+        SetDefaultSortOrderUpdate {
+            action,
+            sort_order_id,
         }
     }
 }
 
-/// Converts the Snapshot value to the Query Parameters representation (style=form, explode=false)
+/// Converts the SetDefaultSortOrderUpdate value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for Snapshot {
+impl std::string::ToString for SetDefaultSortOrderUpdate {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
-            Some("snapshot-id".to_string()),
-            Some(self.snapshot_id.to_string()),
-            self.parent_snapshot_id.as_ref().map(|parent_snapshot_id| {
-                vec![
-                    "parent-snapshot-id".to_string(),
-                    parent_snapshot_id.to_string(),
-                ]
-                .join(",")
-            }),
-            self.sequence_number.as_ref().map(|sequence_number| {
-                vec!["sequence-number".to_string(), sequence_number.to_string()].join(",")
-            }),
-            Some("timestamp-ms".to_string()),
-            Some(self.timestamp_ms.to_string()),
-            Some("manifest-list".to_string()),
-            Some(self.manifest_list.to_string()),
-            // Skipping summary in query parameter serialization
-            self.schema_id
-                .as_ref()
-                .map(|schema_id| vec!["schema-id".to_string(), schema_id.to_string()].join(",")),
+            Some("action".to_string()),
+            Some(self.action.to_string()),
+            Some("sort-order-id".to_string()),
+            Some(self.sort_order_id.to_string()),
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a Snapshot value
+/// Converts Query Parameters representation (style=form, explode=false) to a SetDefaultSortOrderUpdate value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for Snapshot {
+impl std::str::FromStr for SetDefaultSortOrderUpdate {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -9216,13 +9356,8 @@ impl std::str::FromStr for Snapshot {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub snapshot_id: Vec<isize>,
-            pub parent_snapshot_id: Vec<isize>,
-            pub sequence_number: Vec<isize>,
-            pub timestamp_ms: Vec<isize>,
-            pub manifest_list: Vec<String>,
-            pub summary: Vec<models::SnapshotSummary>,
-            pub schema_id: Vec<isize>,
+            pub action: Vec<String>,
+            pub sort_order_id: Vec<isize>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -9236,7 +9371,7 @@ impl std::str::FromStr for Snapshot {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing Snapshot".to_string(),
+                        "Missing value while parsing SetDefaultSortOrderUpdate".to_string(),
                     )
                 }
             };
@@ -9245,37 +9380,16 @@ impl std::str::FromStr for Snapshot {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "snapshot-id" => intermediate_rep.snapshot_id.push(
-                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "action" => intermediate_rep.action.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "parent-snapshot-id" => intermediate_rep.parent_snapshot_id.push(
-                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "sequence-number" => intermediate_rep.sequence_number.push(
-                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "timestamp-ms" => intermediate_rep.timestamp_ms.push(
-                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "manifest-list" => intermediate_rep.manifest_list.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "summary" => intermediate_rep.summary.push(
-                        <models::SnapshotSummary as std::str::FromStr>::from_str(val)
-                            .map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "schema-id" => intermediate_rep.schema_id.push(
+                    "sort-order-id" => intermediate_rep.sort_order_id.push(
                         <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing Snapshot".to_string(),
+                            "Unexpected key while parsing SetDefaultSortOrderUpdate".to_string(),
                         )
                     }
                 }
@@ -9286,48 +9400,37 @@ impl std::str::FromStr for Snapshot {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(Snapshot {
-            snapshot_id: intermediate_rep
-                .snapshot_id
-                .into_iter()
-                .next()
-                .ok_or_else(|| "snapshot-id missing in Snapshot".to_string())?,
-            parent_snapshot_id: intermediate_rep.parent_snapshot_id.into_iter().next(),
-            sequence_number: intermediate_rep.sequence_number.into_iter().next(),
-            timestamp_ms: intermediate_rep
-                .timestamp_ms
-                .into_iter()
-                .next()
-                .ok_or_else(|| "timestamp-ms missing in Snapshot".to_string())?,
-            manifest_list: intermediate_rep
-                .manifest_list
+        std::result::Result::Ok(SetDefaultSortOrderUpdate {
+            action: intermediate_rep
+                .action
                 .into_iter()
                 .next()
-                .ok_or_else(|| "manifest-list missing in Snapshot".to_string())?,
-            summary: intermediate_rep
-                .summary
+                .ok_or_else(|| "action missing in SetDefaultSortOrderUpdate".to_string())?,
+            sort_order_id: intermediate_rep
+                .sort_order_id
                 .into_iter()
                 .next()
-                .ok_or_else(|| "summary missing in Snapshot".to_string())?,
-            schema_id: intermediate_rep.schema_id.into_iter().next(),
+                .ok_or_else(|| "sort-order-id missing in SetDefaultSortOrderUpdate".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<Snapshot> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<SetDefaultSortOrderUpdate> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<Snapshot>> for hyper::header::HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<SetDefaultSortOrderUpdate>>
+    for hyper::header::HeaderValue
+{
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<Snapshot>,
+        hdr_value: header::IntoHeaderValue<SetDefaultSortOrderUpdate>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for Snapshot - value: {} is invalid {}",
+                "Invalid header value for SetDefaultSortOrderUpdate - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -9335,18 +9438,20 @@ impl std::convert::TryFrom<header::IntoHeaderValue<Snapshot>> for hyper::header:
 }
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<Snapshot> {
+impl std::convert::TryFrom<hyper::header::HeaderValue>
+    for header::IntoHeaderValue<SetDefaultSortOrderUpdate>
+{
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <Snapshot as std::str::FromStr>::from_str(value) {
+                match <SetDefaultSortOrderUpdate as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into Snapshot - {}",
+                        "Unable to convert header value '{}' into SetDefaultSortOrderUpdate - {}",
                         value, err
                     )),
                 }
@@ -9361,44 +9466,37 @@ impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderVal
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct SnapshotLogInner {
-    #[serde(rename = "snapshot-id")]
-    pub snapshot_id: isize,
-
-    #[serde(rename = "timestamp-ms")]
-    pub timestamp_ms: isize,
+pub struct SetDefaultSortOrderUpdateAllOf {
+    /// Sort order ID to set as the default, or -1 to set last added sort order
+    #[serde(rename = "sort-order-id")]
+    pub sort_order_id: isize,
 }
 
-impl SnapshotLogInner {
+impl SetDefaultSortOrderUpdateAllOf {
     #[allow(clippy::new_without_default)]
-    pub fn new(snapshot_id: isize, timestamp_ms: isize) -> SnapshotLogInner {
-        SnapshotLogInner {
-            snapshot_id,
-            timestamp_ms,
-        }
+    pub fn new(sort_order_id: isize) -> SetDefaultSortOrderUpdateAllOf {
+        SetDefaultSortOrderUpdateAllOf { sort_order_id }
     }
 }
 
-/// Converts the SnapshotLogInner value to the Query Parameters representation (style=form, explode=false)
+/// Converts the SetDefaultSortOrderUpdateAllOf value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for SnapshotLogInner {
+impl std::string::ToString for SetDefaultSortOrderUpdateAllOf {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
-            Some("snapshot-id".to_string()),
-            Some(self.snapshot_id.to_string()),
-            Some("timestamp-ms".to_string()),
-            Some(self.timestamp_ms.to_string()),
+            Some("sort-order-id".to_string()),
+            Some(self.sort_order_id.to_string()),
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a SnapshotLogInner value
+/// Converts Query Parameters representation (style=form, explode=false) to a SetDefaultSortOrderUpdateAllOf value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for SnapshotLogInner {
+impl std::str::FromStr for SetDefaultSortOrderUpdateAllOf {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -9406,8 +9504,7 @@ impl std::str::FromStr for SnapshotLogInner {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub snapshot_id: Vec<isize>,
-            pub timestamp_ms: Vec<isize>,
+            pub sort_order_id: Vec<isize>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -9421,7 +9518,7 @@ impl std::str::FromStr for SnapshotLogInner {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing SnapshotLogInner".to_string(),
+                        "Missing value while parsing SetDefaultSortOrderUpdateAllOf".to_string(),
                     )
                 }
             };
@@ -9430,16 +9527,13 @@ impl std::str::FromStr for SnapshotLogInner {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "snapshot-id" => intermediate_rep.snapshot_id.push(
-                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "timestamp-ms" => intermediate_rep.timestamp_ms.push(
+                    "sort-order-id" => intermediate_rep.sort_order_id.push(
                         <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing SnapshotLogInner".to_string(),
+                            "Unexpected key while parsing SetDefaultSortOrderUpdateAllOf"
+                                .to_string(),
                         )
                     }
                 }
@@ -9450,37 +9544,34 @@ impl std::str::FromStr for SnapshotLogInner {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(SnapshotLogInner {
-            snapshot_id: intermediate_rep
-                .snapshot_id
-                .into_iter()
-                .next()
-                .ok_or_else(|| "snapshot-id missing in SnapshotLogInner".to_string())?,
-            timestamp_ms: intermediate_rep
-                .timestamp_ms
+        std::result::Result::Ok(SetDefaultSortOrderUpdateAllOf {
+            sort_order_id: intermediate_rep
+                .sort_order_id
                 .into_iter()
                 .next()
-                .ok_or_else(|| "timestamp-ms missing in SnapshotLogInner".to_string())?,
+                .ok_or_else(|| {
+                    "sort-order-id missing in SetDefaultSortOrderUpdateAllOf".to_string()
+                })?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<SnapshotLogInner> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<SetDefaultSortOrderUpdateAllOf> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<SnapshotLogInner>>
+impl std::convert::TryFrom<header::IntoHeaderValue<SetDefaultSortOrderUpdateAllOf>>
     for hyper::header::HeaderValue
 {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<SnapshotLogInner>,
+        hdr_value: header::IntoHeaderValue<SetDefaultSortOrderUpdateAllOf>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for SnapshotLogInner - value: {} is invalid {}",
+                "Invalid header value for SetDefaultSortOrderUpdateAllOf - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -9489,108 +9580,66 @@ impl std::convert::TryFrom<header::IntoHeaderValue<SnapshotLogInner>>
 
 #[cfg(any(feature = "client", feature = "server"))]
 impl std::convert::TryFrom<hyper::header::HeaderValue>
-    for header::IntoHeaderValue<SnapshotLogInner>
+    for header::IntoHeaderValue<SetDefaultSortOrderUpdateAllOf>
 {
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
-            std::result::Result::Ok(value) => {
-                match <SnapshotLogInner as std::str::FromStr>::from_str(value) {
-                    std::result::Result::Ok(value) => {
-                        std::result::Result::Ok(header::IntoHeaderValue(value))
+             std::result::Result::Ok(value) => {
+                    match <SetDefaultSortOrderUpdateAllOf as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into SetDefaultSortOrderUpdateAllOf - {}",
+                                value, err))
                     }
-                    std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into SnapshotLogInner - {}",
-                        value, err
-                    )),
-                }
-            }
-            std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Unable to convert header: {:?} to string: {}",
-                hdr_value, e
-            )),
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
         }
     }
 }
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct SnapshotReference {
+pub struct SetDefaultSpecUpdate {
     // Note: inline enums are not fully supported by openapi-generator
-    #[serde(rename = "type")]
-    pub r#type: String,
-
-    #[serde(rename = "snapshot-id")]
-    pub snapshot_id: i64,
-
-    #[serde(rename = "max-ref-age-ms")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub max_ref_age_ms: Option<i64>,
-
-    #[serde(rename = "max-snapshot-age-ms")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub max_snapshot_age_ms: Option<i64>,
+    #[serde(rename = "action")]
+    pub action: String,
 
-    #[serde(rename = "min-snapshots-to-keep")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub min_snapshots_to_keep: Option<isize>,
+    /// Partition spec ID to set as the default, or -1 to set last added spec
+    #[serde(rename = "spec-id")]
+    pub spec_id: isize,
 }
 
-impl SnapshotReference {
+impl SetDefaultSpecUpdate {
     #[allow(clippy::new_without_default)]
-    pub fn new(r#type: String, snapshot_id: i64) -> SnapshotReference {
-        SnapshotReference {
-            r#type,
-            snapshot_id,
-            max_ref_age_ms: None,
-            max_snapshot_age_ms: None,
-            min_snapshots_to_keep: None,
-        }
+    pub fn new(action: String, spec_id: isize) -> SetDefaultSpecUpdate {
+        SetDefaultSpecUpdate { action, spec_id }
     }
 }
 
-/// Converts the SnapshotReference value to the Query Parameters representation (style=form, explode=false)
+/// Converts the SetDefaultSpecUpdate value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for SnapshotReference {
+impl std::string::ToString for SetDefaultSpecUpdate {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
-            Some("type".to_string()),
-            Some(self.r#type.to_string()),
-            Some("snapshot-id".to_string()),
-            Some(self.snapshot_id.to_string()),
-            self.max_ref_age_ms.as_ref().map(|max_ref_age_ms| {
-                vec!["max-ref-age-ms".to_string(), max_ref_age_ms.to_string()].join(",")
-            }),
-            self.max_snapshot_age_ms
-                .as_ref()
-                .map(|max_snapshot_age_ms| {
-                    vec![
-                        "max-snapshot-age-ms".to_string(),
-                        max_snapshot_age_ms.to_string(),
-                    ]
-                    .join(",")
-                }),
-            self.min_snapshots_to_keep
-                .as_ref()
-                .map(|min_snapshots_to_keep| {
-                    vec![
-                        "min-snapshots-to-keep".to_string(),
-                        min_snapshots_to_keep.to_string(),
-                    ]
-                    .join(",")
-                }),
+            Some("action".to_string()),
+            Some(self.action.to_string()),
+            Some("spec-id".to_string()),
+            Some(self.spec_id.to_string()),
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a SnapshotReference value
+/// Converts Query Parameters representation (style=form, explode=false) to a SetDefaultSpecUpdate value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for SnapshotReference {
+impl std::str::FromStr for SetDefaultSpecUpdate {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -9598,11 +9647,8 @@ impl std::str::FromStr for SnapshotReference {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub r#type: Vec<String>,
-            pub snapshot_id: Vec<i64>,
-            pub max_ref_age_ms: Vec<i64>,
-            pub max_snapshot_age_ms: Vec<i64>,
-            pub min_snapshots_to_keep: Vec<isize>,
+            pub action: Vec<String>,
+            pub spec_id: Vec<isize>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -9616,7 +9662,7 @@ impl std::str::FromStr for SnapshotReference {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing SnapshotReference".to_string(),
+                        "Missing value while parsing SetDefaultSpecUpdate".to_string(),
                     )
                 }
             };
@@ -9625,28 +9671,16 @@ impl std::str::FromStr for SnapshotReference {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "type" => intermediate_rep.r#type.push(
+                    "action" => intermediate_rep.action.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "snapshot-id" => intermediate_rep.snapshot_id.push(
-                        <i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "max-ref-age-ms" => intermediate_rep.max_ref_age_ms.push(
-                        <i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "max-snapshot-age-ms" => intermediate_rep.max_snapshot_age_ms.push(
-                        <i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "min-snapshots-to-keep" => intermediate_rep.min_snapshots_to_keep.push(
-                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "spec-id" => intermediate_rep.spec_id.push(
+                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing SnapshotReference".to_string(),
+                            "Unexpected key while parsing SetDefaultSpecUpdate".to_string(),
                         )
                     }
                 }
@@ -9657,40 +9691,37 @@ impl std::str::FromStr for SnapshotReference {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(SnapshotReference {
-            r#type: intermediate_rep
-                .r#type
+        std::result::Result::Ok(SetDefaultSpecUpdate {
+            action: intermediate_rep
+                .action
                 .into_iter()
                 .next()
-                .ok_or_else(|| "type missing in SnapshotReference".to_string())?,
-            snapshot_id: intermediate_rep
-                .snapshot_id
+                .ok_or_else(|| "action missing in SetDefaultSpecUpdate".to_string())?,
+            spec_id: intermediate_rep
+                .spec_id
                 .into_iter()
                 .next()
-                .ok_or_else(|| "snapshot-id missing in SnapshotReference".to_string())?,
-            max_ref_age_ms: intermediate_rep.max_ref_age_ms.into_iter().next(),
-            max_snapshot_age_ms: intermediate_rep.max_snapshot_age_ms.into_iter().next(),
-            min_snapshots_to_keep: intermediate_rep.min_snapshots_to_keep.into_iter().next(),
+                .ok_or_else(|| "spec-id missing in SetDefaultSpecUpdate".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<SnapshotReference> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<SetDefaultSpecUpdate> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<SnapshotReference>>
+impl std::convert::TryFrom<header::IntoHeaderValue<SetDefaultSpecUpdate>>
     for hyper::header::HeaderValue
 {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<SnapshotReference>,
+        hdr_value: header::IntoHeaderValue<SetDefaultSpecUpdate>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for SnapshotReference - value: {} is invalid {}",
+                "Invalid header value for SetDefaultSpecUpdate - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -9699,19 +9730,19 @@ impl std::convert::TryFrom<header::IntoHeaderValue<SnapshotReference>>
 
 #[cfg(any(feature = "client", feature = "server"))]
 impl std::convert::TryFrom<hyper::header::HeaderValue>
-    for header::IntoHeaderValue<SnapshotReference>
+    for header::IntoHeaderValue<SetDefaultSpecUpdate>
 {
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <SnapshotReference as std::str::FromStr>::from_str(value) {
+                match <SetDefaultSpecUpdate as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into SnapshotReference - {}",
+                        "Unable to convert header value '{}' into SetDefaultSpecUpdate - {}",
                         value, err
                     )),
                 }
@@ -9726,53 +9757,35 @@ impl std::convert::TryFrom<hyper::header::HeaderValue>
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct SnapshotSummary {
-    // Note: inline enums are not fully supported by openapi-generator
-    #[serde(rename = "operation")]
-    pub operation: String,
-
-    #[serde(rename = "additionalProperties")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub additional_properties: Option<String>,
+pub struct SetDefaultSpecUpdateAllOf {
+    /// Partition spec ID to set as the default, or -1 to set last added spec
+    #[serde(rename = "spec-id")]
+    pub spec_id: isize,
 }
 
-impl SnapshotSummary {
+impl SetDefaultSpecUpdateAllOf {
     #[allow(clippy::new_without_default)]
-    pub fn new(operation: String) -> SnapshotSummary {
-        SnapshotSummary {
-            operation,
-            additional_properties: None,
-        }
+    pub fn new(spec_id: isize) -> SetDefaultSpecUpdateAllOf {
+        SetDefaultSpecUpdateAllOf { spec_id }
     }
 }
 
-/// Converts the SnapshotSummary value to the Query Parameters representation (style=form, explode=false)
+/// Converts the SetDefaultSpecUpdateAllOf value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for SnapshotSummary {
+impl std::string::ToString for SetDefaultSpecUpdateAllOf {
     fn to_string(&self) -> String {
-        let params: Vec<Option<String>> = vec![
-            Some("operation".to_string()),
-            Some(self.operation.to_string()),
-            self.additional_properties
-                .as_ref()
-                .map(|additional_properties| {
-                    vec![
-                        "additionalProperties".to_string(),
-                        additional_properties.to_string(),
-                    ]
-                    .join(",")
-                }),
-        ];
+        let params: Vec<Option<String>> =
+            vec![Some("spec-id".to_string()), Some(self.spec_id.to_string())];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a SnapshotSummary value
+/// Converts Query Parameters representation (style=form, explode=false) to a SetDefaultSpecUpdateAllOf value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for SnapshotSummary {
+impl std::str::FromStr for SetDefaultSpecUpdateAllOf {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -9780,8 +9793,7 @@ impl std::str::FromStr for SnapshotSummary {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub operation: Vec<String>,
-            pub additional_properties: Vec<String>,
+            pub spec_id: Vec<isize>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -9795,7 +9807,7 @@ impl std::str::FromStr for SnapshotSummary {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing SnapshotSummary".to_string(),
+                        "Missing value while parsing SetDefaultSpecUpdateAllOf".to_string(),
                     )
                 }
             };
@@ -9804,16 +9816,12 @@ impl std::str::FromStr for SnapshotSummary {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "operation" => intermediate_rep.operation.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "additionalProperties" => intermediate_rep.additional_properties.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "spec-id" => intermediate_rep.spec_id.push(
+                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing SnapshotSummary".to_string(),
+                            "Unexpected key while parsing SetDefaultSpecUpdateAllOf".to_string(),
                         )
                     }
                 }
@@ -9824,33 +9832,32 @@ impl std::str::FromStr for SnapshotSummary {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(SnapshotSummary {
-            operation: intermediate_rep
-                .operation
+        std::result::Result::Ok(SetDefaultSpecUpdateAllOf {
+            spec_id: intermediate_rep
+                .spec_id
                 .into_iter()
                 .next()
-                .ok_or_else(|| "operation missing in SnapshotSummary".to_string())?,
-            additional_properties: intermediate_rep.additional_properties.into_iter().next(),
+                .ok_or_else(|| "spec-id missing in SetDefaultSpecUpdateAllOf".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<SnapshotSummary> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<SetDefaultSpecUpdateAllOf> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<SnapshotSummary>>
+impl std::convert::TryFrom<header::IntoHeaderValue<SetDefaultSpecUpdateAllOf>>
     for hyper::header::HeaderValue
 {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<SnapshotSummary>,
+        hdr_value: header::IntoHeaderValue<SetDefaultSpecUpdateAllOf>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for SnapshotSummary - value: {} is invalid {}",
+                "Invalid header value for SetDefaultSpecUpdateAllOf - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -9859,19 +9866,19 @@ impl std::convert::TryFrom<header::IntoHeaderValue<SnapshotSummary>>
 
 #[cfg(any(feature = "client", feature = "server"))]
 impl std::convert::TryFrom<hyper::header::HeaderValue>
-    for header::IntoHeaderValue<SnapshotSummary>
+    for header::IntoHeaderValue<SetDefaultSpecUpdateAllOf>
 {
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <SnapshotSummary as std::str::FromStr>::from_str(value) {
+                match <SetDefaultSpecUpdateAllOf as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into SnapshotSummary - {}",
+                        "Unable to convert header value '{}' into SetDefaultSpecUpdateAllOf - {}",
                         value, err
                     )),
                 }
@@ -9884,99 +9891,55 @@ impl std::convert::TryFrom<hyper::header::HeaderValue>
     }
 }
 
-/// Enumeration of values.
-/// Since this enum's variants do not hold data, we can easily define them them as `#[repr(C)]`
-/// which helps with FFI.
-#[allow(non_camel_case_types)]
-#[repr(C)]
-#[derive(
-    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
-)]
-#[cfg_attr(feature = "conversion", derive(frunk_enum_derive::LabelledGenericEnum))]
-pub enum SortDirection {
-    #[serde(rename = "asc")]
-    Asc,
-    #[serde(rename = "desc")]
-    Desc,
-}
-
-impl std::fmt::Display for SortDirection {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match *self {
-            SortDirection::Asc => write!(f, "asc"),
-            SortDirection::Desc => write!(f, "desc"),
-        }
-    }
-}
-
-impl std::str::FromStr for SortDirection {
-    type Err = String;
-
-    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
-        match s {
-            "asc" => std::result::Result::Ok(SortDirection::Asc),
-            "desc" => std::result::Result::Ok(SortDirection::Desc),
-            _ => std::result::Result::Err(format!("Value not valid: {}", s)),
-        }
-    }
-}
-
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct SortField {
-    #[serde(rename = "source-id")]
-    pub source_id: isize,
-
-    #[serde(rename = "transform")]
-    pub transform: String,
+pub struct SetExpression {
+    #[serde(rename = "type")]
+    pub r#type: String,
 
-    #[serde(rename = "direction")]
-    pub direction: models::SortDirection,
+    #[serde(rename = "term")]
+    pub term: models::Term,
 
-    #[serde(rename = "null-order")]
-    pub null_order: models::NullOrder,
+    #[serde(rename = "values")]
+    pub values: Vec<serde_json::Value>,
 }
 
-impl SortField {
+impl SetExpression {
     #[allow(clippy::new_without_default)]
     pub fn new(
-        source_id: isize,
-        transform: String,
-        direction: models::SortDirection,
-        null_order: models::NullOrder,
-    ) -> SortField {
-        SortField {
-            source_id,
-            transform,
-            direction,
-            null_order,
+        r#type: String,
+        term: models::Term,
+        values: Vec<serde_json::Value>,
+    ) -> SetExpression {
+        SetExpression {
+            r#type,
+            term,
+            values,
         }
     }
 }
 
-/// Converts the SortField value to the Query Parameters representation (style=form, explode=false)
+/// Converts the SetExpression value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for SortField {
+impl std::string::ToString for SetExpression {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
-            Some("source-id".to_string()),
-            Some(self.source_id.to_string()),
-            Some("transform".to_string()),
-            Some(self.transform.to_string()),
-            // Skipping direction in query parameter serialization
+            Some("type".to_string()),
+            Some(self.r#type.to_string()),
+            // Skipping term in query parameter serialization
 
-            // Skipping null-order in query parameter serialization
+            // Skipping values in query parameter serialization
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a SortField value
+/// Converts Query Parameters representation (style=form, explode=false) to a SetExpression value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for SortField {
+impl std::str::FromStr for SetExpression {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -9984,10 +9947,9 @@ impl std::str::FromStr for SortField {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub source_id: Vec<isize>,
-            pub transform: Vec<String>,
-            pub direction: Vec<models::SortDirection>,
-            pub null_order: Vec<models::NullOrder>,
+            pub r#type: Vec<String>,
+            pub term: Vec<models::Term>,
+            pub values: Vec<Vec<serde_json::Value>>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -10001,7 +9963,7 @@ impl std::str::FromStr for SortField {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing SortField".to_string(),
+                        "Missing value while parsing SetExpression".to_string(),
                     )
                 }
             };
@@ -10010,26 +9972,23 @@ impl std::str::FromStr for SortField {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "source-id" => intermediate_rep.source_id.push(
-                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "transform" => intermediate_rep.transform.push(
+                    "type" => intermediate_rep.r#type.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "direction" => intermediate_rep.direction.push(
-                        <models::SortDirection as std::str::FromStr>::from_str(val)
-                            .map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "null-order" => intermediate_rep.null_order.push(
-                        <models::NullOrder as std::str::FromStr>::from_str(val)
+                    "term" => intermediate_rep.term.push(
+                        <models::Term as std::str::FromStr>::from_str(val)
                             .map_err(|x| x.to_string())?,
                     ),
+                    "values" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in SetExpression"
+                                .to_string(),
+                        )
+                    }
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing SortField".to_string(),
+                            "Unexpected key while parsing SetExpression".to_string(),
                         )
                     }
                 }
@@ -10040,45 +9999,40 @@ impl std::str::FromStr for SortField {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(SortField {
-            source_id: intermediate_rep
-                .source_id
+        std::result::Result::Ok(SetExpression {
+            r#type: intermediate_rep
+                .r#type
                 .into_iter()
                 .next()
-                .ok_or_else(|| "source-id missing in SortField".to_string())?,
-            transform: intermediate_rep
-                .transform
+                .ok_or_else(|| "type missing in SetExpression".to_string())?,
+            term: intermediate_rep
+                .term
                 .into_iter()
                 .next()
-                .ok_or_else(|| "transform missing in SortField".to_string())?,
-            direction: intermediate_rep
-                .direction
+                .ok_or_else(|| "term missing in SetExpression".to_string())?,
+            values: intermediate_rep
+                .values
                 .into_iter()
                 .next()
-                .ok_or_else(|| "direction missing in SortField".to_string())?,
-            null_order: intermediate_rep
-                .null_order
-                .into_iter()
-                .next()
-                .ok_or_else(|| "null-order missing in SortField".to_string())?,
+                .ok_or_else(|| "values missing in SetExpression".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<SortField> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<SetExpression> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<SortField>> for hyper::header::HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<SetExpression>> for hyper::header::HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<SortField>,
+        hdr_value: header::IntoHeaderValue<SetExpression>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for SortField - value: {} is invalid {}",
+                "Invalid header value for SetExpression - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -10086,18 +10040,18 @@ impl std::convert::TryFrom<header::IntoHeaderValue<SortField>> for hyper::header
 }
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<SortField> {
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<SetExpression> {
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <SortField as std::str::FromStr>::from_str(value) {
+                match <SetExpression as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into SortField - {}",
+                        "Unable to convert header value '{}' into SetExpression - {}",
                         value, err
                     )),
                 }
@@ -10112,40 +10066,42 @@ impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderVal
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct SortOrder {
-    #[serde(rename = "order-id")]
-    pub order_id: isize,
+pub struct SetLocationUpdate {
+    // Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "action")]
+    pub action: String,
 
-    #[serde(rename = "fields")]
-    pub fields: Vec<models::SortField>,
+    #[serde(rename = "location")]
+    pub location: String,
 }
 
-impl SortOrder {
+impl SetLocationUpdate {
     #[allow(clippy::new_without_default)]
-    pub fn new(order_id: isize, fields: Vec<models::SortField>) -> SortOrder {
-        SortOrder { order_id, fields }
+    pub fn new(action: String, location: String) -> SetLocationUpdate {
+        SetLocationUpdate { action, location }
     }
 }
 
-/// Converts the SortOrder value to the Query Parameters representation (style=form, explode=false)
+/// Converts the SetLocationUpdate value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for SortOrder {
+impl std::string::ToString for SetLocationUpdate {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
-            Some("order-id".to_string()),
-            Some(self.order_id.to_string()),
-            // Skipping fields in query parameter serialization
+            Some("action".to_string()),
+            Some(self.action.to_string()),
+            Some("location".to_string()),
+            Some(self.location.to_string()),
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a SortOrder value
+/// Converts Query Parameters representation (style=form, explode=false) to a SetLocationUpdate value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for SortOrder {
+impl std::str::FromStr for SetLocationUpdate {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -10153,8 +10109,8 @@ impl std::str::FromStr for SortOrder {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub order_id: Vec<isize>,
-            pub fields: Vec<Vec<models::SortField>>,
+            pub action: Vec<String>,
+            pub location: Vec<String>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -10168,7 +10124,7 @@ impl std::str::FromStr for SortOrder {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing SortOrder".to_string(),
+                        "Missing value while parsing SetLocationUpdate".to_string(),
                     )
                 }
             };
@@ -10177,18 +10133,16 @@ impl std::str::FromStr for SortOrder {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "order-id" => intermediate_rep.order_id.push(
-                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "action" => intermediate_rep.action.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "location" => intermediate_rep.location.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
-                    "fields" => {
-                        return std::result::Result::Err(
-                            "Parsing a container in this style is not supported in SortOrder"
-                                .to_string(),
-                        )
-                    }
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing SortOrder".to_string(),
+                            "Unexpected key while parsing SetLocationUpdate".to_string(),
                         )
                     }
                 }
@@ -10199,35 +10153,37 @@ impl std::str::FromStr for SortOrder {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(SortOrder {
-            order_id: intermediate_rep
-                .order_id
+        std::result::Result::Ok(SetLocationUpdate {
+            action: intermediate_rep
+                .action
                 .into_iter()
                 .next()
-                .ok_or_else(|| "order-id missing in SortOrder".to_string())?,
-            fields: intermediate_rep
-                .fields
+                .ok_or_else(|| "action missing in SetLocationUpdate".to_string())?,
+            location: intermediate_rep
+                .location
                 .into_iter()
                 .next()
-                .ok_or_else(|| "fields missing in SortOrder".to_string())?,
+                .ok_or_else(|| "location missing in SetLocationUpdate".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<SortOrder> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<SetLocationUpdate> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<SortOrder>> for hyper::header::HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<SetLocationUpdate>>
+    for hyper::header::HeaderValue
+{
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<SortOrder>,
+        hdr_value: header::IntoHeaderValue<SetLocationUpdate>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for SortOrder - value: {} is invalid {}",
+                "Invalid header value for SetLocationUpdate - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -10235,18 +10191,20 @@ impl std::convert::TryFrom<header::IntoHeaderValue<SortOrder>> for hyper::header
 }
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<SortOrder> {
+impl std::convert::TryFrom<hyper::header::HeaderValue>
+    for header::IntoHeaderValue<SetLocationUpdate>
+{
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <SortOrder as std::str::FromStr>::from_str(value) {
+                match <SetLocationUpdate as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into SortOrder - {}",
+                        "Unable to convert header value '{}' into SetLocationUpdate - {}",
                         value, err
                     )),
                 }
@@ -10261,63 +10219,36 @@ impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderVal
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct StructField {
-    #[serde(rename = "id")]
-    pub id: isize,
-
-    #[serde(rename = "name")]
-    pub name: String,
-
-    #[serde(rename = "type")]
-    pub r#type: models::Type,
-
-    #[serde(rename = "required")]
-    pub required: bool,
-
-    #[serde(rename = "doc")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub doc: Option<String>,
+pub struct SetLocationUpdateAllOf {
+    #[serde(rename = "location")]
+    pub location: String,
 }
 
-impl StructField {
+impl SetLocationUpdateAllOf {
     #[allow(clippy::new_without_default)]
-    pub fn new(id: isize, name: String, r#type: models::Type, required: bool) -> StructField {
-        StructField {
-            id,
-            name,
-            r#type,
-            required,
-            doc: None,
-        }
+    pub fn new(location: String) -> SetLocationUpdateAllOf {
+        SetLocationUpdateAllOf { location }
     }
 }
 
-/// Converts the StructField value to the Query Parameters representation (style=form, explode=false)
+/// Converts the SetLocationUpdateAllOf value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for StructField {
+impl std::string::ToString for SetLocationUpdateAllOf {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
-            Some("id".to_string()),
-            Some(self.id.to_string()),
-            Some("name".to_string()),
-            Some(self.name.to_string()),
-            // Skipping type in query parameter serialization
-            Some("required".to_string()),
-            Some(self.required.to_string()),
-            self.doc
-                .as_ref()
-                .map(|doc| vec!["doc".to_string(), doc.to_string()].join(",")),
+            Some("location".to_string()),
+            Some(self.location.to_string()),
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a StructField value
+/// Converts Query Parameters representation (style=form, explode=false) to a SetLocationUpdateAllOf value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for StructField {
+impl std::str::FromStr for SetLocationUpdateAllOf {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -10325,11 +10256,7 @@ impl std::str::FromStr for StructField {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub id: Vec<isize>,
-            pub name: Vec<String>,
-            pub r#type: Vec<models::Type>,
-            pub required: Vec<bool>,
-            pub doc: Vec<String>,
+            pub location: Vec<String>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -10343,7 +10270,7 @@ impl std::str::FromStr for StructField {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing StructField".to_string(),
+                        "Missing value while parsing SetLocationUpdateAllOf".to_string(),
                     )
                 }
             };
@@ -10352,29 +10279,12 @@ impl std::str::FromStr for StructField {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "id" => intermediate_rep.id.push(
-                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "name" => intermediate_rep.name.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "type" => intermediate_rep.r#type.push(
-                        <models::Type as std::str::FromStr>::from_str(val)
-                            .map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "required" => intermediate_rep.required.push(
-                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "doc" => intermediate_rep.doc.push(
+                    "location" => intermediate_rep.location.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing StructField".to_string(),
+                            "Unexpected key while parsing SetLocationUpdateAllOf".to_string(),
                         )
                     }
                 }
@@ -10385,46 +10295,32 @@ impl std::str::FromStr for StructField {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(StructField {
-            id: intermediate_rep
-                .id
-                .into_iter()
-                .next()
-                .ok_or_else(|| "id missing in StructField".to_string())?,
-            name: intermediate_rep
-                .name
-                .into_iter()
-                .next()
-                .ok_or_else(|| "name missing in StructField".to_string())?,
-            r#type: intermediate_rep
-                .r#type
-                .into_iter()
-                .next()
-                .ok_or_else(|| "type missing in StructField".to_string())?,
-            required: intermediate_rep
-                .required
+        std::result::Result::Ok(SetLocationUpdateAllOf {
+            location: intermediate_rep
+                .location
                 .into_iter()
                 .next()
-                .ok_or_else(|| "required missing in StructField".to_string())?,
-            doc: intermediate_rep.doc.into_iter().next(),
+                .ok_or_else(|| "location missing in SetLocationUpdateAllOf".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<StructField> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<SetLocationUpdateAllOf> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<StructField>> for hyper::header::HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<SetLocationUpdateAllOf>>
+    for hyper::header::HeaderValue
+{
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<StructField>,
+        hdr_value: header::IntoHeaderValue<SetLocationUpdateAllOf>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for StructField - value: {} is invalid {}",
+                "Invalid header value for SetLocationUpdateAllOf - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -10432,18 +10328,20 @@ impl std::convert::TryFrom<header::IntoHeaderValue<StructField>> for hyper::head
 }
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<StructField> {
+impl std::convert::TryFrom<hyper::header::HeaderValue>
+    for header::IntoHeaderValue<SetLocationUpdateAllOf>
+{
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <StructField as std::str::FromStr>::from_str(value) {
+                match <SetLocationUpdateAllOf as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into StructField - {}",
+                        "Unable to convert header value '{}' into SetLocationUpdateAllOf - {}",
                         value, err
                     )),
                 }
@@ -10458,41 +10356,44 @@ impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderVal
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct StructType {
+pub struct SetPropertiesUpdate {
     // Note: inline enums are not fully supported by openapi-generator
-    #[serde(rename = "type")]
-    pub r#type: String,
+    #[serde(rename = "action")]
+    pub action: String,
 
-    #[serde(rename = "fields")]
-    pub fields: Vec<models::StructField>,
+    #[serde(rename = "updates")]
+    pub updates: std::collections::HashMap<String, String>,
 }
 
-impl StructType {
+impl SetPropertiesUpdate {
     #[allow(clippy::new_without_default)]
-    pub fn new(r#type: String, fields: Vec<models::StructField>) -> StructType {
-        StructType { r#type, fields }
+    pub fn new(
+        action: String,
+        updates: std::collections::HashMap<String, String>,
+    ) -> SetPropertiesUpdate {
+        SetPropertiesUpdate { action, updates }
     }
 }
 
-/// Converts the StructType value to the Query Parameters representation (style=form, explode=false)
+/// Converts the SetPropertiesUpdate value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for StructType {
+impl std::string::ToString for SetPropertiesUpdate {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
-            Some("type".to_string()),
-            Some(self.r#type.to_string()),
-            // Skipping fields in query parameter serialization
+            Some("action".to_string()),
+            Some(self.action.to_string()),
+            // Skipping updates in query parameter serialization
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a StructType value
+/// Converts Query Parameters representation (style=form, explode=false) to a SetPropertiesUpdate value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for StructType {
+impl std::str::FromStr for SetPropertiesUpdate {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -10500,8 +10401,8 @@ impl std::str::FromStr for StructType {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub r#type: Vec<String>,
-            pub fields: Vec<Vec<models::StructField>>,
+            pub action: Vec<String>,
+            pub updates: Vec<std::collections::HashMap<String, String>>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -10515,7 +10416,7 @@ impl std::str::FromStr for StructType {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing StructType".to_string(),
+                        "Missing value while parsing SetPropertiesUpdate".to_string(),
                     )
                 }
             };
@@ -10524,18 +10425,16 @@ impl std::str::FromStr for StructType {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "type" => intermediate_rep.r#type.push(
+                    "action" => intermediate_rep.action.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
-                    "fields" => {
-                        return std::result::Result::Err(
-                            "Parsing a container in this style is not supported in StructType"
-                                .to_string(),
-                        )
-                    }
+                    "updates" => return std::result::Result::Err(
+                        "Parsing a container in this style is not supported in SetPropertiesUpdate"
+                            .to_string(),
+                    ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing StructType".to_string(),
+                            "Unexpected key while parsing SetPropertiesUpdate".to_string(),
                         )
                     }
                 }
@@ -10546,35 +10445,37 @@ impl std::str::FromStr for StructType {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(StructType {
-            r#type: intermediate_rep
-                .r#type
+        std::result::Result::Ok(SetPropertiesUpdate {
+            action: intermediate_rep
+                .action
                 .into_iter()
                 .next()
-                .ok_or_else(|| "type missing in StructType".to_string())?,
-            fields: intermediate_rep
-                .fields
+                .ok_or_else(|| "action missing in SetPropertiesUpdate".to_string())?,
+            updates: intermediate_rep
+                .updates
                 .into_iter()
                 .next()
-                .ok_or_else(|| "fields missing in StructType".to_string())?,
+                .ok_or_else(|| "updates missing in SetPropertiesUpdate".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<StructType> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<SetPropertiesUpdate> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<StructType>> for hyper::header::HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<SetPropertiesUpdate>>
+    for hyper::header::HeaderValue
+{
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<StructType>,
+        hdr_value: header::IntoHeaderValue<SetPropertiesUpdate>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for StructType - value: {} is invalid {}",
+                "Invalid header value for SetPropertiesUpdate - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -10582,18 +10483,20 @@ impl std::convert::TryFrom<header::IntoHeaderValue<StructType>> for hyper::heade
 }
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<StructType> {
+impl std::convert::TryFrom<hyper::header::HeaderValue>
+    for header::IntoHeaderValue<SetPropertiesUpdate>
+{
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <StructType as std::str::FromStr>::from_str(value) {
+                match <SetPropertiesUpdate as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into StructType - {}",
+                        "Unable to convert header value '{}' into SetPropertiesUpdate - {}",
                         value, err
                     )),
                 }
@@ -10608,48 +10511,36 @@ impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderVal
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct TableIdentifier {
-    /// Reference to one or more levels of a namespace
-    #[serde(rename = "namespace")]
-    pub namespace: Vec<String>,
-
-    #[serde(rename = "name")]
-    pub name: String,
+pub struct SetPropertiesUpdateAllOf {
+    #[serde(rename = "updates")]
+    pub updates: std::collections::HashMap<String, String>,
 }
 
-impl TableIdentifier {
+impl SetPropertiesUpdateAllOf {
     #[allow(clippy::new_without_default)]
-    pub fn new(namespace: Vec<String>, name: String) -> TableIdentifier {
-        TableIdentifier { namespace, name }
+    pub fn new(updates: std::collections::HashMap<String, String>) -> SetPropertiesUpdateAllOf {
+        SetPropertiesUpdateAllOf { updates }
     }
 }
 
-/// Converts the TableIdentifier value to the Query Parameters representation (style=form, explode=false)
+/// Converts the SetPropertiesUpdateAllOf value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for TableIdentifier {
+impl std::string::ToString for SetPropertiesUpdateAllOf {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
-            Some("namespace".to_string()),
-            Some(
-                self.namespace
-                    .iter()
-                    .map(|x| x.to_string())
-                    .collect::<Vec<_>>()
-                    .join(","),
-            ),
-            Some("name".to_string()),
-            Some(self.name.to_string()),
+            // Skipping updates in query parameter serialization
+
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a TableIdentifier value
+/// Converts Query Parameters representation (style=form, explode=false) to a SetPropertiesUpdateAllOf value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for TableIdentifier {
+impl std::str::FromStr for SetPropertiesUpdateAllOf {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -10657,8 +10548,7 @@ impl std::str::FromStr for TableIdentifier {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub namespace: Vec<Vec<String>>,
-            pub name: Vec<String>,
+            pub updates: Vec<std::collections::HashMap<String, String>>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -10672,7 +10562,7 @@ impl std::str::FromStr for TableIdentifier {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing TableIdentifier".to_string(),
+                        "Missing value while parsing SetPropertiesUpdateAllOf".to_string(),
                     )
                 }
             };
@@ -10680,21 +10570,8 @@ impl std::str::FromStr for TableIdentifier {
             if let Some(key) = key_result {
                 #[allow(clippy::match_single_binding)]
                 match key {
-                    "namespace" => {
-                        return std::result::Result::Err(
-                            "Parsing a container in this style is not supported in TableIdentifier"
-                                .to_string(),
-                        )
-                    }
-                    #[allow(clippy::redundant_clone)]
-                    "name" => intermediate_rep.name.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    _ => {
-                        return std::result::Result::Err(
-                            "Unexpected key while parsing TableIdentifier".to_string(),
-                        )
-                    }
+                    "updates" => return std::result::Result::Err("Parsing a container in this style is not supported in SetPropertiesUpdateAllOf".to_string()),
+                    _ => return std::result::Result::Err("Unexpected key while parsing SetPropertiesUpdateAllOf".to_string())
                 }
             }
 
@@ -10703,37 +10580,32 @@ impl std::str::FromStr for TableIdentifier {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(TableIdentifier {
-            namespace: intermediate_rep
-                .namespace
-                .into_iter()
-                .next()
-                .ok_or_else(|| "namespace missing in TableIdentifier".to_string())?,
-            name: intermediate_rep
-                .name
+        std::result::Result::Ok(SetPropertiesUpdateAllOf {
+            updates: intermediate_rep
+                .updates
                 .into_iter()
                 .next()
-                .ok_or_else(|| "name missing in TableIdentifier".to_string())?,
+                .ok_or_else(|| "updates missing in SetPropertiesUpdateAllOf".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<TableIdentifier> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<SetPropertiesUpdateAllOf> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<TableIdentifier>>
+impl std::convert::TryFrom<header::IntoHeaderValue<SetPropertiesUpdateAllOf>>
     for hyper::header::HeaderValue
 {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<TableIdentifier>,
+        hdr_value: header::IntoHeaderValue<SetPropertiesUpdateAllOf>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for TableIdentifier - value: {} is invalid {}",
+                "Invalid header value for SetPropertiesUpdateAllOf - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -10742,19 +10614,19 @@ impl std::convert::TryFrom<header::IntoHeaderValue<TableIdentifier>>
 
 #[cfg(any(feature = "client", feature = "server"))]
 impl std::convert::TryFrom<hyper::header::HeaderValue>
-    for header::IntoHeaderValue<TableIdentifier>
+    for header::IntoHeaderValue<SetPropertiesUpdateAllOf>
 {
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <TableIdentifier as std::str::FromStr>::from_str(value) {
+                match <SetPropertiesUpdateAllOf as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into TableIdentifier - {}",
+                        "Unable to convert header value '{}' into SetPropertiesUpdateAllOf - {}",
                         value, err
                     )),
                 }
@@ -10769,180 +10641,99 @@ impl std::convert::TryFrom<hyper::header::HeaderValue>
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct TableMetadata {
-    #[serde(rename = "format-version")]
-    pub format_version: u8,
-
-    #[serde(rename = "table-uuid")]
-    pub table_uuid: String,
-
-    #[serde(rename = "location")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub location: Option<String>,
-
-    #[serde(rename = "last-updated-ms")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_ms: Option<isize>,
-
-    #[serde(rename = "properties")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub properties: Option<std::collections::HashMap<String, String>>,
-
-    #[serde(rename = "schemas")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub schemas: Option<Vec<models::Schema>>,
-
-    #[serde(rename = "current-schema-id")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub current_schema_id: Option<isize>,
-
-    #[serde(rename = "last-column-id")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_column_id: Option<isize>,
-
-    #[serde(rename = "partition-specs")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub partition_specs: Option<Vec<models::PartitionSpec>>,
-
-    #[serde(rename = "default-spec-id")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub default_spec_id: Option<isize>,
-
-    #[serde(rename = "last-partition-id")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_partition_id: Option<isize>,
-
-    #[serde(rename = "sort-orders")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort_orders: Option<Vec<models::SortOrder>>,
+pub struct SetSnapshotRefUpdate {
+    // Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "action")]
+    pub action: String,
 
-    #[serde(rename = "default-sort-order-id")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub default_sort_order_id: Option<isize>,
+    // Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "type")]
+    pub r#type: String,
 
-    #[serde(rename = "snapshots")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub snapshots: Option<Vec<models::Snapshot>>,
+    #[serde(rename = "snapshot-id")]
+    pub snapshot_id: i64,
 
-    #[serde(rename = "refs")]
+    #[serde(rename = "max-ref-age-ms")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub refs: Option<std::collections::HashMap<String, models::SnapshotReference>>,
+    pub max_ref_age_ms: Option<i64>,
 
-    #[serde(rename = "current-snapshot-id")]
+    #[serde(rename = "max-snapshot-age-ms")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub current_snapshot_id: Option<isize>,
+    pub max_snapshot_age_ms: Option<i64>,
 
-    #[serde(rename = "snapshot-log")]
+    #[serde(rename = "min-snapshots-to-keep")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub snapshot_log: Option<Vec<models::SnapshotLogInner>>,
+    pub min_snapshots_to_keep: Option<isize>,
 
-    #[serde(rename = "metadata-log")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub metadata_log: Option<Vec<models::MetadataLogInner>>,
+    #[serde(rename = "ref-name")]
+    pub ref_name: String,
 }
 
-impl TableMetadata {
+impl SetSnapshotRefUpdate {
     #[allow(clippy::new_without_default)]
-    pub fn new(format_version: u8, table_uuid: String) -> TableMetadata {
-        TableMetadata {
-            format_version,
-            table_uuid,
-            location: None,
-            last_updated_ms: None,
-            properties: None,
-            schemas: None,
-            current_schema_id: None,
-            last_column_id: None,
-            partition_specs: None,
-            default_spec_id: None,
-            last_partition_id: None,
-            sort_orders: None,
-            default_sort_order_id: None,
-            snapshots: None,
-            refs: None,
-            current_snapshot_id: None,
-            snapshot_log: None,
-            metadata_log: None,
+    pub fn new(
+        action: String,
+        r#type: String,
+        snapshot_id: i64,
+        ref_name: String,
+    ) -> SetSnapshotRefUpdate {
+        SetSnapshotRefUpdate {
+            action,
+            r#type,
+            snapshot_id,
+            max_ref_age_ms: None,
+            max_snapshot_age_ms: None,
+            min_snapshots_to_keep: None,
+            ref_name,
         }
     }
 }
 
-/// Converts the TableMetadata value to the Query Parameters representation (style=form, explode=false)
+/// Converts the SetSnapshotRefUpdate value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for TableMetadata {
+impl std::string::ToString for SetSnapshotRefUpdate {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
-            Some("format-version".to_string()),
-            Some(self.format_version.to_string()),
-            Some("table-uuid".to_string()),
-            Some(self.table_uuid.to_string()),
-            self.location
-                .as_ref()
-                .map(|location| vec!["location".to_string(), location.to_string()].join(",")),
-            self.last_updated_ms.as_ref().map(|last_updated_ms| {
-                vec!["last-updated-ms".to_string(), last_updated_ms.to_string()].join(",")
-            }),
-            // Skipping properties in query parameter serialization
-
-            // Skipping schemas in query parameter serialization
-            self.current_schema_id.as_ref().map(|current_schema_id| {
-                vec![
-                    "current-schema-id".to_string(),
-                    current_schema_id.to_string(),
-                ]
-                .join(",")
-            }),
-            self.last_column_id.as_ref().map(|last_column_id| {
-                vec!["last-column-id".to_string(), last_column_id.to_string()].join(",")
-            }),
-            // Skipping partition-specs in query parameter serialization
-            self.default_spec_id.as_ref().map(|default_spec_id| {
-                vec!["default-spec-id".to_string(), default_spec_id.to_string()].join(",")
-            }),
-            self.last_partition_id.as_ref().map(|last_partition_id| {
-                vec![
-                    "last-partition-id".to_string(),
-                    last_partition_id.to_string(),
-                ]
-                .join(",")
+            Some("action".to_string()),
+            Some(self.action.to_string()),
+            Some("type".to_string()),
+            Some(self.r#type.to_string()),
+            Some("snapshot-id".to_string()),
+            Some(self.snapshot_id.to_string()),
+            self.max_ref_age_ms.as_ref().map(|max_ref_age_ms| {
+                vec!["max-ref-age-ms".to_string(), max_ref_age_ms.to_string()].join(",")
             }),
-            // Skipping sort-orders in query parameter serialization
-            self.default_sort_order_id
+            self.max_snapshot_age_ms
                 .as_ref()
-                .map(|default_sort_order_id| {
+                .map(|max_snapshot_age_ms| {
                     vec![
-                        "default-sort-order-id".to_string(),
-                        default_sort_order_id.to_string(),
+                        "max-snapshot-age-ms".to_string(),
+                        max_snapshot_age_ms.to_string(),
                     ]
                     .join(",")
                 }),
-            // Skipping snapshots in query parameter serialization
-
-            // Skipping refs in query parameter serialization
-            // Skipping refs in query parameter serialization
-            self.current_snapshot_id
+            self.min_snapshots_to_keep
                 .as_ref()
-                .map(|current_snapshot_id| {
+                .map(|min_snapshots_to_keep| {
                     vec![
-                        "current-snapshot-id".to_string(),
-                        current_snapshot_id.to_string(),
+                        "min-snapshots-to-keep".to_string(),
+                        min_snapshots_to_keep.to_string(),
                     ]
                     .join(",")
                 }),
-            // Skipping snapshot-log in query parameter serialization
-
-            // Skipping metadata-log in query parameter serialization
+            Some("ref-name".to_string()),
+            Some(self.ref_name.to_string()),
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a TableMetadata value
+/// Converts Query Parameters representation (style=form, explode=false) to a SetSnapshotRefUpdate value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for TableMetadata {
+impl std::str::FromStr for SetSnapshotRefUpdate {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -10950,24 +10741,13 @@ impl std::str::FromStr for TableMetadata {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub format_version: Vec<u8>,
-            pub table_uuid: Vec<String>,
-            pub location: Vec<String>,
-            pub last_updated_ms: Vec<isize>,
-            pub properties: Vec<std::collections::HashMap<String, String>>,
-            pub schemas: Vec<Vec<models::Schema>>,
-            pub current_schema_id: Vec<isize>,
-            pub last_column_id: Vec<isize>,
-            pub partition_specs: Vec<Vec<models::PartitionSpec>>,
-            pub default_spec_id: Vec<isize>,
-            pub last_partition_id: Vec<isize>,
-            pub sort_orders: Vec<Vec<models::SortOrder>>,
-            pub default_sort_order_id: Vec<isize>,
-            pub snapshots: Vec<Vec<models::Snapshot>>,
-            pub refs: Vec<std::collections::HashMap<String, models::SnapshotReference>>,
-            pub current_snapshot_id: Vec<isize>,
-            pub snapshot_log: Vec<Vec<models::SnapshotLogInner>>,
-            pub metadata_log: Vec<Vec<models::MetadataLogInner>>,
+            pub action: Vec<String>,
+            pub r#type: Vec<String>,
+            pub snapshot_id: Vec<i64>,
+            pub max_ref_age_ms: Vec<i64>,
+            pub max_snapshot_age_ms: Vec<i64>,
+            pub min_snapshots_to_keep: Vec<isize>,
+            pub ref_name: Vec<String>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -10981,7 +10761,7 @@ impl std::str::FromStr for TableMetadata {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing TableMetadata".to_string(),
+                        "Missing value while parsing SetSnapshotRefUpdate".to_string(),
                     )
                 }
             };
@@ -10990,96 +10770,36 @@ impl std::str::FromStr for TableMetadata {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "format-version" => intermediate_rep
-                        .format_version
-                        .push(<u8 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
-                    #[allow(clippy::redundant_clone)]
-                    "table-uuid" => intermediate_rep.table_uuid.push(
+                    "action" => intermediate_rep.action.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "location" => intermediate_rep.location.push(
+                    "type" => intermediate_rep.r#type.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "last-updated-ms" => intermediate_rep.last_updated_ms.push(
-                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    "properties" => {
-                        return std::result::Result::Err(
-                            "Parsing a container in this style is not supported in TableMetadata"
-                                .to_string(),
-                        )
-                    }
-                    "schemas" => {
-                        return std::result::Result::Err(
-                            "Parsing a container in this style is not supported in TableMetadata"
-                                .to_string(),
-                        )
-                    }
-                    #[allow(clippy::redundant_clone)]
-                    "current-schema-id" => intermediate_rep.current_schema_id.push(
-                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "last-column-id" => intermediate_rep.last_column_id.push(
-                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "snapshot-id" => intermediate_rep.snapshot_id.push(
+                        <i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
-                    "partition-specs" => {
-                        return std::result::Result::Err(
-                            "Parsing a container in this style is not supported in TableMetadata"
-                                .to_string(),
-                        )
-                    }
                     #[allow(clippy::redundant_clone)]
-                    "default-spec-id" => intermediate_rep.default_spec_id.push(
-                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "max-ref-age-ms" => intermediate_rep.max_ref_age_ms.push(
+                        <i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "last-partition-id" => intermediate_rep.last_partition_id.push(
-                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "max-snapshot-age-ms" => intermediate_rep.max_snapshot_age_ms.push(
+                        <i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
-                    "sort-orders" => {
-                        return std::result::Result::Err(
-                            "Parsing a container in this style is not supported in TableMetadata"
-                                .to_string(),
-                        )
-                    }
                     #[allow(clippy::redundant_clone)]
-                    "default-sort-order-id" => intermediate_rep.default_sort_order_id.push(
+                    "min-snapshots-to-keep" => intermediate_rep.min_snapshots_to_keep.push(
                         <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
-                    "snapshots" => {
-                        return std::result::Result::Err(
-                            "Parsing a container in this style is not supported in TableMetadata"
-                                .to_string(),
-                        )
-                    }
-                    "refs" => {
-                        return std::result::Result::Err(
-                            "Parsing a container in this style is not supported in TableMetadata"
-                                .to_string(),
-                        )
-                    }
                     #[allow(clippy::redundant_clone)]
-                    "current-snapshot-id" => intermediate_rep.current_snapshot_id.push(
-                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "ref-name" => intermediate_rep.ref_name.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
-                    "snapshot-log" => {
-                        return std::result::Result::Err(
-                            "Parsing a container in this style is not supported in TableMetadata"
-                                .to_string(),
-                        )
-                    }
-                    "metadata-log" => {
-                        return std::result::Result::Err(
-                            "Parsing a container in this style is not supported in TableMetadata"
-                                .to_string(),
-                        )
-                    }
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing TableMetadata".to_string(),
+                            "Unexpected key while parsing SetSnapshotRefUpdate".to_string(),
                         )
                     }
                 }
@@ -11090,51 +10810,50 @@ impl std::str::FromStr for TableMetadata {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(TableMetadata {
-            format_version: intermediate_rep
-                .format_version
+        std::result::Result::Ok(SetSnapshotRefUpdate {
+            action: intermediate_rep
+                .action
                 .into_iter()
                 .next()
-                .ok_or_else(|| "format-version missing in TableMetadata".to_string())?,
-            table_uuid: intermediate_rep
-                .table_uuid
+                .ok_or_else(|| "action missing in SetSnapshotRefUpdate".to_string())?,
+            r#type: intermediate_rep
+                .r#type
                 .into_iter()
                 .next()
-                .ok_or_else(|| "table-uuid missing in TableMetadata".to_string())?,
-            location: intermediate_rep.location.into_iter().next(),
-            last_updated_ms: intermediate_rep.last_updated_ms.into_iter().next(),
-            properties: intermediate_rep.properties.into_iter().next(),
-            schemas: intermediate_rep.schemas.into_iter().next(),
-            current_schema_id: intermediate_rep.current_schema_id.into_iter().next(),
-            last_column_id: intermediate_rep.last_column_id.into_iter().next(),
-            partition_specs: intermediate_rep.partition_specs.into_iter().next(),
-            default_spec_id: intermediate_rep.default_spec_id.into_iter().next(),
-            last_partition_id: intermediate_rep.last_partition_id.into_iter().next(),
-            sort_orders: intermediate_rep.sort_orders.into_iter().next(),
-            default_sort_order_id: intermediate_rep.default_sort_order_id.into_iter().next(),
-            snapshots: intermediate_rep.snapshots.into_iter().next(),
-            refs: intermediate_rep.refs.into_iter().next(),
-            current_snapshot_id: intermediate_rep.current_snapshot_id.into_iter().next(),
-            snapshot_log: intermediate_rep.snapshot_log.into_iter().next(),
-            metadata_log: intermediate_rep.metadata_log.into_iter().next(),
+                .ok_or_else(|| "type missing in SetSnapshotRefUpdate".to_string())?,
+            snapshot_id: intermediate_rep
+                .snapshot_id
+                .into_iter()
+                .next()
+                .ok_or_else(|| "snapshot-id missing in SetSnapshotRefUpdate".to_string())?,
+            max_ref_age_ms: intermediate_rep.max_ref_age_ms.into_iter().next(),
+            max_snapshot_age_ms: intermediate_rep.max_snapshot_age_ms.into_iter().next(),
+            min_snapshots_to_keep: intermediate_rep.min_snapshots_to_keep.into_iter().next(),
+            ref_name: intermediate_rep
+                .ref_name
+                .into_iter()
+                .next()
+                .ok_or_else(|| "ref-name missing in SetSnapshotRefUpdate".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<TableMetadata> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<SetSnapshotRefUpdate> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<TableMetadata>> for hyper::header::HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<SetSnapshotRefUpdate>>
+    for hyper::header::HeaderValue
+{
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<TableMetadata>,
+        hdr_value: header::IntoHeaderValue<SetSnapshotRefUpdate>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for TableMetadata - value: {} is invalid {}",
+                "Invalid header value for SetSnapshotRefUpdate - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -11142,18 +10861,20 @@ impl std::convert::TryFrom<header::IntoHeaderValue<TableMetadata>> for hyper::he
 }
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<TableMetadata> {
+impl std::convert::TryFrom<hyper::header::HeaderValue>
+    for header::IntoHeaderValue<SetSnapshotRefUpdate>
+{
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <TableMetadata as std::str::FromStr>::from_str(value) {
+                match <SetSnapshotRefUpdate as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into TableMetadata - {}",
+                        "Unable to convert header value '{}' into SetSnapshotRefUpdate - {}",
                         value, err
                     )),
                 }
@@ -11166,128 +10887,38 @@ impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderVal
     }
 }
 
-/// Assertions from the client that must be valid for the commit to succeed. Assertions are identified by `type` - - `assert-create` - the table must not already exist; used for create transactions - `assert-table-uuid` - the table UUID must match the requirement's `uuid` - `assert-ref-snapshot-id` - the table branch or tag identified by the requirement's `ref` must reference the requirement's `snapshot-id`; if `snapshot-id` is `null` or missing, the ref must not already exist - `assert-last-assigned-field-id` - the table's last assigned column id must match the requirement's `last-assigned-field-id` - `assert-current-schema-id` - the table's current schema id must match the requirement's `current-schema-id` - `assert-last-assigned-partition-id` - the table's last assigned partition id must match the requirement's `last-assigned-partition-id` - `assert-default-spec-id` - the table's default spec id must match the requirement's `default-spec-id` - `assert-default-sort-order-id` - the table's default sort order id must match the requirement's `default-sort-order-id`
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct TableRequirement {
-    // Note: inline enums are not fully supported by openapi-generator
-    #[serde(rename = "requirement")]
-    pub requirement: String,
+pub struct SetSnapshotRefUpdateAllOf {
+    #[serde(rename = "ref-name")]
+    pub ref_name: String,
+}
 
-    #[serde(rename = "ref")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub r#ref: Option<String>,
-
-    #[serde(rename = "uuid")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub uuid: Option<String>,
-
-    #[serde(rename = "snapshot-id")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub snapshot_id: Option<i64>,
-
-    #[serde(rename = "last-assigned-field-id")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_assigned_field_id: Option<isize>,
-
-    #[serde(rename = "current-schema-id")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub current_schema_id: Option<isize>,
-
-    #[serde(rename = "last-assigned-partition-id")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_assigned_partition_id: Option<isize>,
-
-    #[serde(rename = "default-spec-id")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub default_spec_id: Option<isize>,
-
-    #[serde(rename = "default-sort-order-id")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub default_sort_order_id: Option<isize>,
-}
-
-impl TableRequirement {
+impl SetSnapshotRefUpdateAllOf {
     #[allow(clippy::new_without_default)]
-    pub fn new(requirement: String) -> TableRequirement {
-        TableRequirement {
-            requirement,
-            r#ref: None,
-            uuid: None,
-            snapshot_id: None,
-            last_assigned_field_id: None,
-            current_schema_id: None,
-            last_assigned_partition_id: None,
-            default_spec_id: None,
-            default_sort_order_id: None,
-        }
+    pub fn new(ref_name: String) -> SetSnapshotRefUpdateAllOf {
+        SetSnapshotRefUpdateAllOf { ref_name }
     }
 }
 
-/// Converts the TableRequirement value to the Query Parameters representation (style=form, explode=false)
+/// Converts the SetSnapshotRefUpdateAllOf value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for TableRequirement {
+impl std::string::ToString for SetSnapshotRefUpdateAllOf {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
-            Some("requirement".to_string()),
-            Some(self.requirement.to_string()),
-            self.r#ref
-                .as_ref()
-                .map(|r#ref| vec!["ref".to_string(), r#ref.to_string()].join(",")),
-            self.uuid
-                .as_ref()
-                .map(|uuid| vec!["uuid".to_string(), uuid.to_string()].join(",")),
-            self.snapshot_id.as_ref().map(|snapshot_id| {
-                vec!["snapshot-id".to_string(), snapshot_id.to_string()].join(",")
-            }),
-            self.last_assigned_field_id
-                .as_ref()
-                .map(|last_assigned_field_id| {
-                    vec![
-                        "last-assigned-field-id".to_string(),
-                        last_assigned_field_id.to_string(),
-                    ]
-                    .join(",")
-                }),
-            self.current_schema_id.as_ref().map(|current_schema_id| {
-                vec![
-                    "current-schema-id".to_string(),
-                    current_schema_id.to_string(),
-                ]
-                .join(",")
-            }),
-            self.last_assigned_partition_id
-                .as_ref()
-                .map(|last_assigned_partition_id| {
-                    vec![
-                        "last-assigned-partition-id".to_string(),
-                        last_assigned_partition_id.to_string(),
-                    ]
-                    .join(",")
-                }),
-            self.default_spec_id.as_ref().map(|default_spec_id| {
-                vec!["default-spec-id".to_string(), default_spec_id.to_string()].join(",")
-            }),
-            self.default_sort_order_id
-                .as_ref()
-                .map(|default_sort_order_id| {
-                    vec![
-                        "default-sort-order-id".to_string(),
-                        default_sort_order_id.to_string(),
-                    ]
-                    .join(",")
-                }),
+            Some("ref-name".to_string()),
+            Some(self.ref_name.to_string()),
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a TableRequirement value
+/// Converts Query Parameters representation (style=form, explode=false) to a SetSnapshotRefUpdateAllOf value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for TableRequirement {
+impl std::str::FromStr for SetSnapshotRefUpdateAllOf {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -11295,15 +10926,7 @@ impl std::str::FromStr for TableRequirement {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub requirement: Vec<String>,
-            pub r#ref: Vec<String>,
-            pub uuid: Vec<String>,
-            pub snapshot_id: Vec<i64>,
-            pub last_assigned_field_id: Vec<isize>,
-            pub current_schema_id: Vec<isize>,
-            pub last_assigned_partition_id: Vec<isize>,
-            pub default_spec_id: Vec<isize>,
-            pub default_sort_order_id: Vec<isize>,
+            pub ref_name: Vec<String>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -11317,7 +10940,7 @@ impl std::str::FromStr for TableRequirement {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing TableRequirement".to_string(),
+                        "Missing value while parsing SetSnapshotRefUpdateAllOf".to_string(),
                     )
                 }
             };
@@ -11326,47 +10949,12 @@ impl std::str::FromStr for TableRequirement {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "requirement" => intermediate_rep.requirement.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "ref" => intermediate_rep.r#ref.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "uuid" => intermediate_rep.uuid.push(
+                    "ref-name" => intermediate_rep.ref_name.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
-                    #[allow(clippy::redundant_clone)]
-                    "snapshot-id" => intermediate_rep.snapshot_id.push(
-                        <i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "last-assigned-field-id" => intermediate_rep.last_assigned_field_id.push(
-                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "current-schema-id" => intermediate_rep.current_schema_id.push(
-                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "last-assigned-partition-id" => {
-                        intermediate_rep.last_assigned_partition_id.push(
-                            <isize as std::str::FromStr>::from_str(val)
-                                .map_err(|x| x.to_string())?,
-                        )
-                    }
-                    #[allow(clippy::redundant_clone)]
-                    "default-spec-id" => intermediate_rep.default_spec_id.push(
-                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "default-sort-order-id" => intermediate_rep.default_sort_order_id.push(
-                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing TableRequirement".to_string(),
+                            "Unexpected key while parsing SetSnapshotRefUpdateAllOf".to_string(),
                         )
                     }
                 }
@@ -11377,43 +10965,32 @@ impl std::str::FromStr for TableRequirement {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(TableRequirement {
-            requirement: intermediate_rep
-                .requirement
+        std::result::Result::Ok(SetSnapshotRefUpdateAllOf {
+            ref_name: intermediate_rep
+                .ref_name
                 .into_iter()
                 .next()
-                .ok_or_else(|| "requirement missing in TableRequirement".to_string())?,
-            r#ref: intermediate_rep.r#ref.into_iter().next(),
-            uuid: intermediate_rep.uuid.into_iter().next(),
-            snapshot_id: intermediate_rep.snapshot_id.into_iter().next(),
-            last_assigned_field_id: intermediate_rep.last_assigned_field_id.into_iter().next(),
-            current_schema_id: intermediate_rep.current_schema_id.into_iter().next(),
-            last_assigned_partition_id: intermediate_rep
-                .last_assigned_partition_id
-                .into_iter()
-                .next(),
-            default_spec_id: intermediate_rep.default_spec_id.into_iter().next(),
-            default_sort_order_id: intermediate_rep.default_sort_order_id.into_iter().next(),
+                .ok_or_else(|| "ref-name missing in SetSnapshotRefUpdateAllOf".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<TableRequirement> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<SetSnapshotRefUpdateAllOf> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<TableRequirement>>
+impl std::convert::TryFrom<header::IntoHeaderValue<SetSnapshotRefUpdateAllOf>>
     for hyper::header::HeaderValue
 {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<TableRequirement>,
+        hdr_value: header::IntoHeaderValue<SetSnapshotRefUpdateAllOf>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for TableRequirement - value: {} is invalid {}",
+                "Invalid header value for SetSnapshotRefUpdateAllOf - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -11422,19 +10999,19 @@ impl std::convert::TryFrom<header::IntoHeaderValue<TableRequirement>>
 
 #[cfg(any(feature = "client", feature = "server"))]
 impl std::convert::TryFrom<hyper::header::HeaderValue>
-    for header::IntoHeaderValue<TableRequirement>
+    for header::IntoHeaderValue<SetSnapshotRefUpdateAllOf>
 {
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <TableRequirement as std::str::FromStr>::from_str(value) {
+                match <SetSnapshotRefUpdateAllOf as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into TableRequirement - {}",
+                        "Unable to convert header value '{}' into SetSnapshotRefUpdateAllOf - {}",
                         value, err
                     )),
                 }
@@ -11449,193 +11026,89 @@ impl std::convert::TryFrom<hyper::header::HeaderValue>
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct TableUpdate {
-    // Note: inline enums are not fully supported by openapi-generator
-    #[serde(rename = "action")]
-    pub action: String,
-
-    #[serde(rename = "format-version")]
-    pub format_version: isize,
-
-    #[serde(rename = "schema")]
-    pub schema: models::Schema,
-
-    /// Schema ID to set as current, or -1 to set last added schema
-    #[serde(rename = "schema-id")]
-    pub schema_id: isize,
-
-    #[serde(rename = "spec")]
-    pub spec: models::PartitionSpec,
-
-    /// Partition spec ID to set as the default, or -1 to set last added spec
-    #[serde(rename = "spec-id")]
-    pub spec_id: isize,
-
-    #[serde(rename = "sort-order")]
-    pub sort_order: models::SortOrder,
-
-    /// Sort order ID to set as the default, or -1 to set last added sort order
-    #[serde(rename = "sort-order-id")]
-    pub sort_order_id: isize,
-
-    #[serde(rename = "snapshot")]
-    pub snapshot: models::Snapshot,
-
-    // Note: inline enums are not fully supported by openapi-generator
-    #[serde(rename = "type")]
-    pub r#type: String,
-
+pub struct Snapshot {
     #[serde(rename = "snapshot-id")]
-    pub snapshot_id: i64,
+    pub snapshot_id: isize,
 
-    #[serde(rename = "max-ref-age-ms")]
+    #[serde(rename = "parent-snapshot-id")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub max_ref_age_ms: Option<i64>,
+    pub parent_snapshot_id: Option<isize>,
 
-    #[serde(rename = "max-snapshot-age-ms")]
+    #[serde(rename = "sequence-number")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub max_snapshot_age_ms: Option<i64>,
+    pub sequence_number: Option<isize>,
 
-    #[serde(rename = "min-snapshots-to-keep")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub min_snapshots_to_keep: Option<isize>,
+    #[serde(rename = "timestamp-ms")]
+    pub timestamp_ms: isize,
 
-    #[serde(rename = "ref-name")]
-    pub ref_name: String,
+    /// Location of the snapshot's manifest list file
+    #[serde(rename = "manifest-list")]
+    pub manifest_list: String,
 
-    #[serde(rename = "snapshot-ids")]
-    pub snapshot_ids: Vec<i64>,
+    #[serde(rename = "summary")]
+    pub summary: models::SnapshotSummary,
 
-    #[serde(rename = "location")]
-    pub location: String,
+    #[serde(rename = "schema-id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema_id: Option<isize>,
+}
 
-    #[serde(rename = "updates")]
-    pub updates: std::collections::HashMap<String, String>,
-
-    #[serde(rename = "removals")]
-    pub removals: Vec<String>,
-}
-
-impl TableUpdate {
+impl Snapshot {
     #[allow(clippy::new_without_default)]
     pub fn new(
-        action: String,
-        format_version: isize,
-        schema: models::Schema,
-        schema_id: isize,
-        spec: models::PartitionSpec,
-        spec_id: isize,
-        sort_order: models::SortOrder,
-        sort_order_id: isize,
-        snapshot: models::Snapshot,
-        r#type: String,
-        snapshot_id: i64,
-        ref_name: String,
-        snapshot_ids: Vec<i64>,
-        location: String,
-        updates: std::collections::HashMap<String, String>,
-        removals: Vec<String>,
-    ) -> TableUpdate {
-        TableUpdate {
-            action,
-            format_version,
-            schema,
-            schema_id,
-            spec,
-            spec_id,
-            sort_order,
-            sort_order_id,
-            snapshot,
-            r#type,
+        snapshot_id: isize,
+        timestamp_ms: isize,
+        manifest_list: String,
+        summary: models::SnapshotSummary,
+    ) -> Snapshot {
+        Snapshot {
             snapshot_id,
-            max_ref_age_ms: None,
-            max_snapshot_age_ms: None,
-            min_snapshots_to_keep: None,
-            ref_name,
-            snapshot_ids,
-            location,
-            updates,
-            removals,
+            parent_snapshot_id: None,
+            sequence_number: None,
+            timestamp_ms,
+            manifest_list,
+            summary,
+            schema_id: None,
         }
     }
 }
 
-/// Converts the TableUpdate value to the Query Parameters representation (style=form, explode=false)
+/// Converts the Snapshot value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for TableUpdate {
+impl std::string::ToString for Snapshot {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
-            Some("action".to_string()),
-            Some(self.action.to_string()),
-            Some("format-version".to_string()),
-            Some(self.format_version.to_string()),
-            // Skipping schema in query parameter serialization
-            Some("schema-id".to_string()),
-            Some(self.schema_id.to_string()),
-            // Skipping spec in query parameter serialization
-            Some("spec-id".to_string()),
-            Some(self.spec_id.to_string()),
-            // Skipping sort-order in query parameter serialization
-            Some("sort-order-id".to_string()),
-            Some(self.sort_order_id.to_string()),
-            // Skipping snapshot in query parameter serialization
-            Some("type".to_string()),
-            Some(self.r#type.to_string()),
             Some("snapshot-id".to_string()),
             Some(self.snapshot_id.to_string()),
-            self.max_ref_age_ms.as_ref().map(|max_ref_age_ms| {
-                vec!["max-ref-age-ms".to_string(), max_ref_age_ms.to_string()].join(",")
+            self.parent_snapshot_id.as_ref().map(|parent_snapshot_id| {
+                vec![
+                    "parent-snapshot-id".to_string(),
+                    parent_snapshot_id.to_string(),
+                ]
+                .join(",")
             }),
-            self.max_snapshot_age_ms
-                .as_ref()
-                .map(|max_snapshot_age_ms| {
-                    vec![
-                        "max-snapshot-age-ms".to_string(),
-                        max_snapshot_age_ms.to_string(),
-                    ]
-                    .join(",")
-                }),
-            self.min_snapshots_to_keep
+            self.sequence_number.as_ref().map(|sequence_number| {
+                vec!["sequence-number".to_string(), sequence_number.to_string()].join(",")
+            }),
+            Some("timestamp-ms".to_string()),
+            Some(self.timestamp_ms.to_string()),
+            Some("manifest-list".to_string()),
+            Some(self.manifest_list.to_string()),
+            // Skipping summary in query parameter serialization
+            self.schema_id
                 .as_ref()
-                .map(|min_snapshots_to_keep| {
-                    vec![
-                        "min-snapshots-to-keep".to_string(),
-                        min_snapshots_to_keep.to_string(),
-                    ]
-                    .join(",")
-                }),
-            Some("ref-name".to_string()),
-            Some(self.ref_name.to_string()),
-            Some("snapshot-ids".to_string()),
-            Some(
-                self.snapshot_ids
-                    .iter()
-                    .map(|x| x.to_string())
-                    .collect::<Vec<_>>()
-                    .join(","),
-            ),
-            Some("location".to_string()),
-            Some(self.location.to_string()),
-            // Skipping updates in query parameter serialization
-            Some("removals".to_string()),
-            Some(
-                self.removals
-                    .iter()
-                    .map(|x| x.to_string())
-                    .collect::<Vec<_>>()
-                    .join(","),
-            ),
+                .map(|schema_id| vec!["schema-id".to_string(), schema_id.to_string()].join(",")),
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a TableUpdate value
+/// Converts Query Parameters representation (style=form, explode=false) to a Snapshot value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for TableUpdate {
+impl std::str::FromStr for Snapshot {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -11643,25 +11116,13 @@ impl std::str::FromStr for TableUpdate {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub action: Vec<String>,
-            pub format_version: Vec<isize>,
-            pub schema: Vec<models::Schema>,
+            pub snapshot_id: Vec<isize>,
+            pub parent_snapshot_id: Vec<isize>,
+            pub sequence_number: Vec<isize>,
+            pub timestamp_ms: Vec<isize>,
+            pub manifest_list: Vec<String>,
+            pub summary: Vec<models::SnapshotSummary>,
             pub schema_id: Vec<isize>,
-            pub spec: Vec<models::PartitionSpec>,
-            pub spec_id: Vec<isize>,
-            pub sort_order: Vec<models::SortOrder>,
-            pub sort_order_id: Vec<isize>,
-            pub snapshot: Vec<models::Snapshot>,
-            pub r#type: Vec<String>,
-            pub snapshot_id: Vec<i64>,
-            pub max_ref_age_ms: Vec<i64>,
-            pub max_snapshot_age_ms: Vec<i64>,
-            pub min_snapshots_to_keep: Vec<isize>,
-            pub ref_name: Vec<String>,
-            pub snapshot_ids: Vec<Vec<i64>>,
-            pub location: Vec<String>,
-            pub updates: Vec<std::collections::HashMap<String, String>>,
-            pub removals: Vec<Vec<String>>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -11675,7 +11136,7 @@ impl std::str::FromStr for TableUpdate {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing TableUpdate".to_string(),
+                        "Missing value while parsing Snapshot".to_string(),
                     )
                 }
             };
@@ -11684,94 +11145,37 @@ impl std::str::FromStr for TableUpdate {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "action" => intermediate_rep.action.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "format-version" => intermediate_rep.format_version.push(
+                    "snapshot-id" => intermediate_rep.snapshot_id.push(
                         <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "schema" => intermediate_rep.schema.push(
-                        <models::Schema as std::str::FromStr>::from_str(val)
-                            .map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "schema-id" => intermediate_rep.schema_id.push(
+                    "parent-snapshot-id" => intermediate_rep.parent_snapshot_id.push(
                         <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "spec" => intermediate_rep.spec.push(
-                        <models::PartitionSpec as std::str::FromStr>::from_str(val)
-                            .map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "spec-id" => intermediate_rep.spec_id.push(
+                    "sequence-number" => intermediate_rep.sequence_number.push(
                         <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "sort-order" => intermediate_rep.sort_order.push(
-                        <models::SortOrder as std::str::FromStr>::from_str(val)
-                            .map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "sort-order-id" => intermediate_rep.sort_order_id.push(
+                    "timestamp-ms" => intermediate_rep.timestamp_ms.push(
                         <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "snapshot" => intermediate_rep.snapshot.push(
-                        <models::Snapshot as std::str::FromStr>::from_str(val)
-                            .map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "type" => intermediate_rep.r#type.push(
+                    "manifest-list" => intermediate_rep.manifest_list.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "snapshot-id" => intermediate_rep.snapshot_id.push(
-                        <i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "max-ref-age-ms" => intermediate_rep.max_ref_age_ms.push(
-                        <i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "max-snapshot-age-ms" => intermediate_rep.max_snapshot_age_ms.push(
-                        <i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "summary" => intermediate_rep.summary.push(
+                        <models::SnapshotSummary as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "min-snapshots-to-keep" => intermediate_rep.min_snapshots_to_keep.push(
+                    "schema-id" => intermediate_rep.schema_id.push(
                         <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
-                    #[allow(clippy::redundant_clone)]
-                    "ref-name" => intermediate_rep.ref_name.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    "snapshot-ids" => {
-                        return std::result::Result::Err(
-                            "Parsing a container in this style is not supported in TableUpdate"
-                                .to_string(),
-                        )
-                    }
-                    #[allow(clippy::redundant_clone)]
-                    "location" => intermediate_rep.location.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    "updates" => {
-                        return std::result::Result::Err(
-                            "Parsing a container in this style is not supported in TableUpdate"
-                                .to_string(),
-                        )
-                    }
-                    "removals" => {
-                        return std::result::Result::Err(
-                            "Parsing a container in this style is not supported in TableUpdate"
-                                .to_string(),
-                        )
-                    }
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing TableUpdate".to_string(),
+                            "Unexpected key while parsing Snapshot".to_string(),
                         )
                     }
                 }
@@ -11782,108 +11186,3082 @@ impl std::str::FromStr for TableUpdate {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(TableUpdate {
-            action: intermediate_rep
-                .action
-                .into_iter()
-                .next()
-                .ok_or_else(|| "action missing in TableUpdate".to_string())?,
-            format_version: intermediate_rep
-                .format_version
-                .into_iter()
-                .next()
-                .ok_or_else(|| "format-version missing in TableUpdate".to_string())?,
-            schema: intermediate_rep
-                .schema
-                .into_iter()
-                .next()
-                .ok_or_else(|| "schema missing in TableUpdate".to_string())?,
-            schema_id: intermediate_rep
-                .schema_id
-                .into_iter()
-                .next()
-                .ok_or_else(|| "schema-id missing in TableUpdate".to_string())?,
-            spec: intermediate_rep
-                .spec
-                .into_iter()
-                .next()
-                .ok_or_else(|| "spec missing in TableUpdate".to_string())?,
-            spec_id: intermediate_rep
-                .spec_id
-                .into_iter()
-                .next()
-                .ok_or_else(|| "spec-id missing in TableUpdate".to_string())?,
-            sort_order: intermediate_rep
-                .sort_order
+        std::result::Result::Ok(Snapshot {
+            snapshot_id: intermediate_rep
+                .snapshot_id
                 .into_iter()
                 .next()
-                .ok_or_else(|| "sort-order missing in TableUpdate".to_string())?,
-            sort_order_id: intermediate_rep
-                .sort_order_id
+                .ok_or_else(|| "snapshot-id missing in Snapshot".to_string())?,
+            parent_snapshot_id: intermediate_rep.parent_snapshot_id.into_iter().next(),
+            sequence_number: intermediate_rep.sequence_number.into_iter().next(),
+            timestamp_ms: intermediate_rep
+                .timestamp_ms
                 .into_iter()
                 .next()
-                .ok_or_else(|| "sort-order-id missing in TableUpdate".to_string())?,
-            snapshot: intermediate_rep
-                .snapshot
+                .ok_or_else(|| "timestamp-ms missing in Snapshot".to_string())?,
+            manifest_list: intermediate_rep
+                .manifest_list
                 .into_iter()
                 .next()
-                .ok_or_else(|| "snapshot missing in TableUpdate".to_string())?,
-            r#type: intermediate_rep
-                .r#type
+                .ok_or_else(|| "manifest-list missing in Snapshot".to_string())?,
+            summary: intermediate_rep
+                .summary
                 .into_iter()
                 .next()
-                .ok_or_else(|| "type missing in TableUpdate".to_string())?,
-            snapshot_id: intermediate_rep
-                .snapshot_id
+                .ok_or_else(|| "summary missing in Snapshot".to_string())?,
+            schema_id: intermediate_rep.schema_id.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<Snapshot> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<Snapshot>> for hyper::header::HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<Snapshot>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for Snapshot - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<Snapshot> {
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <Snapshot as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into Snapshot - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct SnapshotLogInner {
+    #[serde(rename = "snapshot-id")]
+    pub snapshot_id: isize,
+
+    #[serde(rename = "timestamp-ms")]
+    pub timestamp_ms: isize,
+}
+
+impl SnapshotLogInner {
+    #[allow(clippy::new_without_default)]
+    pub fn new(snapshot_id: isize, timestamp_ms: isize) -> SnapshotLogInner {
+        SnapshotLogInner {
+            snapshot_id,
+            timestamp_ms,
+        }
+    }
+}
+
+/// Converts the SnapshotLogInner value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for SnapshotLogInner {
+    fn to_string(&self) -> String {
+        let params: Vec<Option<String>> = vec![
+            Some("snapshot-id".to_string()),
+            Some(self.snapshot_id.to_string()),
+            Some("timestamp-ms".to_string()),
+            Some(self.timestamp_ms.to_string()),
+        ];
+
+        params.into_iter().flatten().collect::<Vec<_>>().join(",")
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a SnapshotLogInner value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for SnapshotLogInner {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub snapshot_id: Vec<isize>,
+            pub timestamp_ms: Vec<isize>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing SnapshotLogInner".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "snapshot-id" => intermediate_rep.snapshot_id.push(
+                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "timestamp-ms" => intermediate_rep.timestamp_ms.push(
+                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing SnapshotLogInner".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(SnapshotLogInner {
+            snapshot_id: intermediate_rep
+                .snapshot_id
+                .into_iter()
+                .next()
+                .ok_or_else(|| "snapshot-id missing in SnapshotLogInner".to_string())?,
+            timestamp_ms: intermediate_rep
+                .timestamp_ms
+                .into_iter()
+                .next()
+                .ok_or_else(|| "timestamp-ms missing in SnapshotLogInner".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<SnapshotLogInner> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<SnapshotLogInner>>
+    for hyper::header::HeaderValue
+{
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<SnapshotLogInner>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for SnapshotLogInner - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue>
+    for header::IntoHeaderValue<SnapshotLogInner>
+{
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <SnapshotLogInner as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into SnapshotLogInner - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct SnapshotReference {
+    // Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "type")]
+    pub r#type: String,
+
+    #[serde(rename = "snapshot-id")]
+    pub snapshot_id: i64,
+
+    #[serde(rename = "max-ref-age-ms")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_ref_age_ms: Option<i64>,
+
+    #[serde(rename = "max-snapshot-age-ms")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_snapshot_age_ms: Option<i64>,
+
+    #[serde(rename = "min-snapshots-to-keep")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_snapshots_to_keep: Option<isize>,
+}
+
+impl SnapshotReference {
+    #[allow(clippy::new_without_default)]
+    pub fn new(r#type: String, snapshot_id: i64) -> SnapshotReference {
+        SnapshotReference {
+            r#type,
+            snapshot_id,
+            max_ref_age_ms: None,
+            max_snapshot_age_ms: None,
+            min_snapshots_to_keep: None,
+        }
+    }
+}
+
+/// Converts the SnapshotReference value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for SnapshotReference {
+    fn to_string(&self) -> String {
+        let params: Vec<Option<String>> = vec![
+            Some("type".to_string()),
+            Some(self.r#type.to_string()),
+            Some("snapshot-id".to_string()),
+            Some(self.snapshot_id.to_string()),
+            self.max_ref_age_ms.as_ref().map(|max_ref_age_ms| {
+                vec!["max-ref-age-ms".to_string(), max_ref_age_ms.to_string()].join(",")
+            }),
+            self.max_snapshot_age_ms
+                .as_ref()
+                .map(|max_snapshot_age_ms| {
+                    vec![
+                        "max-snapshot-age-ms".to_string(),
+                        max_snapshot_age_ms.to_string(),
+                    ]
+                    .join(",")
+                }),
+            self.min_snapshots_to_keep
+                .as_ref()
+                .map(|min_snapshots_to_keep| {
+                    vec![
+                        "min-snapshots-to-keep".to_string(),
+                        min_snapshots_to_keep.to_string(),
+                    ]
+                    .join(",")
+                }),
+        ];
+
+        params.into_iter().flatten().collect::<Vec<_>>().join(",")
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a SnapshotReference value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for SnapshotReference {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub r#type: Vec<String>,
+            pub snapshot_id: Vec<i64>,
+            pub max_ref_age_ms: Vec<i64>,
+            pub max_snapshot_age_ms: Vec<i64>,
+            pub min_snapshots_to_keep: Vec<isize>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing SnapshotReference".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "type" => intermediate_rep.r#type.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "snapshot-id" => intermediate_rep.snapshot_id.push(
+                        <i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "max-ref-age-ms" => intermediate_rep.max_ref_age_ms.push(
+                        <i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "max-snapshot-age-ms" => intermediate_rep.max_snapshot_age_ms.push(
+                        <i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "min-snapshots-to-keep" => intermediate_rep.min_snapshots_to_keep.push(
+                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing SnapshotReference".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(SnapshotReference {
+            r#type: intermediate_rep
+                .r#type
+                .into_iter()
+                .next()
+                .ok_or_else(|| "type missing in SnapshotReference".to_string())?,
+            snapshot_id: intermediate_rep
+                .snapshot_id
+                .into_iter()
+                .next()
+                .ok_or_else(|| "snapshot-id missing in SnapshotReference".to_string())?,
+            max_ref_age_ms: intermediate_rep.max_ref_age_ms.into_iter().next(),
+            max_snapshot_age_ms: intermediate_rep.max_snapshot_age_ms.into_iter().next(),
+            min_snapshots_to_keep: intermediate_rep.min_snapshots_to_keep.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<SnapshotReference> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<SnapshotReference>>
+    for hyper::header::HeaderValue
+{
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<SnapshotReference>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for SnapshotReference - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue>
+    for header::IntoHeaderValue<SnapshotReference>
+{
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <SnapshotReference as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into SnapshotReference - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct SnapshotSummary {
+    // Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "operation")]
+    pub operation: String,
+
+    #[serde(flatten)]
+    pub additional_properties: std::collections::HashMap<String, String>,
+}
+
+impl SnapshotSummary {
+    #[allow(clippy::new_without_default)]
+    pub fn new(operation: String) -> SnapshotSummary {
+        SnapshotSummary {
+            operation,
+            additional_properties: std::collections::HashMap::new(),
+        }
+    }
+}
+
+/// Converts the SnapshotSummary value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for SnapshotSummary {
+    fn to_string(&self) -> String {
+        let params: Vec<Option<String>> = vec![
+            Some("operation".to_string()),
+            Some(self.operation.to_string()),
+            // Skipping additionalProperties in query parameter serialization
+        ];
+
+        params.into_iter().flatten().collect::<Vec<_>>().join(",")
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a SnapshotSummary value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for SnapshotSummary {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub operation: Vec<String>,
+            pub additional_properties: std::collections::HashMap<String, String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing SnapshotSummary".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "operation" => intermediate_rep.operation.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        intermediate_rep
+                            .additional_properties
+                            .insert(key.to_string(), val.to_string());
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(SnapshotSummary {
+            operation: intermediate_rep
+                .operation
+                .into_iter()
+                .next()
+                .ok_or_else(|| "operation missing in SnapshotSummary".to_string())?,
+            additional_properties: intermediate_rep.additional_properties,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<SnapshotSummary> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<SnapshotSummary>>
+    for hyper::header::HeaderValue
+{
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<SnapshotSummary>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for SnapshotSummary - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue>
+    for header::IntoHeaderValue<SnapshotSummary>
+{
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <SnapshotSummary as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into SnapshotSummary - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+/// Enumeration of values.
+/// Since this enum's variants do not hold data, we can easily define them them as `#[repr(C)]`
+/// which helps with FFI.
+#[allow(non_camel_case_types)]
+#[repr(C)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+#[cfg_attr(feature = "conversion", derive(frunk_enum_derive::LabelledGenericEnum))]
+pub enum SortDirection {
+    #[serde(rename = "asc")]
+    Asc,
+    #[serde(rename = "desc")]
+    Desc,
+}
+
+impl std::fmt::Display for SortDirection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            SortDirection::Asc => write!(f, "asc"),
+            SortDirection::Desc => write!(f, "desc"),
+        }
+    }
+}
+
+impl std::str::FromStr for SortDirection {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "asc" => std::result::Result::Ok(SortDirection::Asc),
+            "desc" => std::result::Result::Ok(SortDirection::Desc),
+            _ => std::result::Result::Err(format!("Value not valid: {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct SortField {
+    #[serde(rename = "source-id")]
+    pub source_id: isize,
+
+    #[serde(rename = "transform")]
+    pub transform: String,
+
+    #[serde(rename = "direction")]
+    pub direction: models::SortDirection,
+
+    #[serde(rename = "null-order")]
+    pub null_order: models::NullOrder,
+}
+
+impl SortField {
+    #[allow(clippy::new_without_default)]
+    pub fn new(
+        source_id: isize,
+        transform: String,
+        direction: models::SortDirection,
+        null_order: models::NullOrder,
+    ) -> SortField {
+        SortField {
+            source_id,
+            transform,
+            direction,
+            null_order,
+        }
+    }
+}
+
+/// Converts the SortField value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for SortField {
+    fn to_string(&self) -> String {
+        let params: Vec<Option<String>> = vec![
+            Some("source-id".to_string()),
+            Some(self.source_id.to_string()),
+            Some("transform".to_string()),
+            Some(self.transform.to_string()),
+            // Skipping direction in query parameter serialization
+
+            // Skipping null-order in query parameter serialization
+        ];
+
+        params.into_iter().flatten().collect::<Vec<_>>().join(",")
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a SortField value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for SortField {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub source_id: Vec<isize>,
+            pub transform: Vec<String>,
+            pub direction: Vec<models::SortDirection>,
+            pub null_order: Vec<models::NullOrder>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing SortField".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "source-id" => intermediate_rep.source_id.push(
+                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "transform" => intermediate_rep.transform.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "direction" => intermediate_rep.direction.push(
+                        <models::SortDirection as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "null-order" => intermediate_rep.null_order.push(
+                        <models::NullOrder as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing SortField".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(SortField {
+            source_id: intermediate_rep
+                .source_id
+                .into_iter()
+                .next()
+                .ok_or_else(|| "source-id missing in SortField".to_string())?,
+            transform: intermediate_rep
+                .transform
+                .into_iter()
+                .next()
+                .ok_or_else(|| "transform missing in SortField".to_string())?,
+            direction: intermediate_rep
+                .direction
+                .into_iter()
+                .next()
+                .ok_or_else(|| "direction missing in SortField".to_string())?,
+            null_order: intermediate_rep
+                .null_order
+                .into_iter()
+                .next()
+                .ok_or_else(|| "null-order missing in SortField".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<SortField> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<SortField>> for hyper::header::HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<SortField>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for SortField - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<SortField> {
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <SortField as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into SortField - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct SortOrder {
+    #[serde(rename = "order-id")]
+    pub order_id: isize,
+
+    #[serde(rename = "fields")]
+    pub fields: Vec<models::SortField>,
+}
+
+impl SortOrder {
+    #[allow(clippy::new_without_default)]
+    pub fn new(order_id: isize, fields: Vec<models::SortField>) -> SortOrder {
+        SortOrder { order_id, fields }
+    }
+}
+
+/// Converts the SortOrder value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for SortOrder {
+    fn to_string(&self) -> String {
+        let params: Vec<Option<String>> = vec![
+            Some("order-id".to_string()),
+            Some(self.order_id.to_string()),
+            // Skipping fields in query parameter serialization
+        ];
+
+        params.into_iter().flatten().collect::<Vec<_>>().join(",")
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a SortOrder value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for SortOrder {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub order_id: Vec<isize>,
+            pub fields: Vec<Vec<models::SortField>>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing SortOrder".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "order-id" => intermediate_rep.order_id.push(
+                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    "fields" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in SortOrder"
+                                .to_string(),
+                        )
+                    }
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing SortOrder".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(SortOrder {
+            order_id: intermediate_rep
+                .order_id
+                .into_iter()
+                .next()
+                .ok_or_else(|| "order-id missing in SortOrder".to_string())?,
+            fields: intermediate_rep
+                .fields
+                .into_iter()
+                .next()
+                .ok_or_else(|| "fields missing in SortOrder".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<SortOrder> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<SortOrder>> for hyper::header::HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<SortOrder>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for SortOrder - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<SortOrder> {
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <SortOrder as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into SortOrder - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct StructField {
+    #[serde(rename = "id")]
+    pub id: isize,
+
+    #[serde(rename = "name")]
+    pub name: String,
+
+    #[serde(rename = "type")]
+    pub r#type: models::Type,
+
+    #[serde(rename = "required")]
+    pub required: bool,
+
+    #[serde(rename = "doc")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub doc: Option<String>,
+}
+
+impl StructField {
+    #[allow(clippy::new_without_default)]
+    pub fn new(id: isize, name: String, r#type: models::Type, required: bool) -> StructField {
+        StructField {
+            id,
+            name,
+            r#type,
+            required,
+            doc: None,
+        }
+    }
+}
+
+/// Converts the StructField value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for StructField {
+    fn to_string(&self) -> String {
+        let params: Vec<Option<String>> = vec![
+            Some("id".to_string()),
+            Some(self.id.to_string()),
+            Some("name".to_string()),
+            Some(self.name.to_string()),
+            // Skipping type in query parameter serialization
+            Some("required".to_string()),
+            Some(self.required.to_string()),
+            self.doc
+                .as_ref()
+                .map(|doc| vec!["doc".to_string(), doc.to_string()].join(",")),
+        ];
+
+        params.into_iter().flatten().collect::<Vec<_>>().join(",")
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a StructField value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for StructField {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub id: Vec<isize>,
+            pub name: Vec<String>,
+            pub r#type: Vec<models::Type>,
+            pub required: Vec<bool>,
+            pub doc: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing StructField".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "id" => intermediate_rep.id.push(
+                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "name" => intermediate_rep.name.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "type" => intermediate_rep.r#type.push(
+                        <models::Type as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "required" => intermediate_rep.required.push(
+                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "doc" => intermediate_rep.doc.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing StructField".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(StructField {
+            id: intermediate_rep
+                .id
+                .into_iter()
+                .next()
+                .ok_or_else(|| "id missing in StructField".to_string())?,
+            name: intermediate_rep
+                .name
+                .into_iter()
+                .next()
+                .ok_or_else(|| "name missing in StructField".to_string())?,
+            r#type: intermediate_rep
+                .r#type
+                .into_iter()
+                .next()
+                .ok_or_else(|| "type missing in StructField".to_string())?,
+            required: intermediate_rep
+                .required
+                .into_iter()
+                .next()
+                .ok_or_else(|| "required missing in StructField".to_string())?,
+            doc: intermediate_rep.doc.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<StructField> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<StructField>> for hyper::header::HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<StructField>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for StructField - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<StructField> {
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <StructField as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into StructField - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct StructType {
+    // Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "type")]
+    pub r#type: String,
+
+    #[serde(rename = "fields")]
+    pub fields: Vec<models::StructField>,
+}
+
+impl StructType {
+    #[allow(clippy::new_without_default)]
+    pub fn new(r#type: String, fields: Vec<models::StructField>) -> StructType {
+        StructType { r#type, fields }
+    }
+}
+
+/// Converts the StructType value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for StructType {
+    fn to_string(&self) -> String {
+        let params: Vec<Option<String>> = vec![
+            Some("type".to_string()),
+            Some(self.r#type.to_string()),
+            // Skipping fields in query parameter serialization
+        ];
+
+        params.into_iter().flatten().collect::<Vec<_>>().join(",")
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a StructType value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for StructType {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub r#type: Vec<String>,
+            pub fields: Vec<Vec<models::StructField>>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing StructType".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "type" => intermediate_rep.r#type.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    "fields" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in StructType"
+                                .to_string(),
+                        )
+                    }
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing StructType".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(StructType {
+            r#type: intermediate_rep
+                .r#type
+                .into_iter()
+                .next()
+                .ok_or_else(|| "type missing in StructType".to_string())?,
+            fields: intermediate_rep
+                .fields
+                .into_iter()
+                .next()
+                .ok_or_else(|| "fields missing in StructType".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<StructType> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<StructType>> for hyper::header::HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<StructType>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for StructType - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<StructType> {
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <StructType as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into StructType - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+/// A commit that swapped the metadata location of a table
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct TableCommit {
+    #[serde(rename = "metadata-location")]
+    pub metadata_location: String,
+
+    #[serde(rename = "previous-metadata-location")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_metadata_location: Option<String>,
+
+    /// When the commit happened
+    #[serde(rename = "timestamp-ms")]
+    pub timestamp_ms: i64,
+
+    /// The span id of the request that made the commit
+    #[serde(rename = "span-id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub span_id: Option<String>,
+
+    /// The principal that made the commit
+    #[serde(rename = "principal")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub principal: Option<String>,
+
+    /// The service that made the commit on behalf of the principal, if the principal's token was obtained by token exchange
+    #[serde(rename = "actor")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor: Option<String>,
+}
+
+impl TableCommit {
+    #[allow(clippy::new_without_default)]
+    pub fn new(metadata_location: String, timestamp_ms: i64) -> TableCommit {
+        TableCommit {
+            metadata_location,
+            previous_metadata_location: None,
+            timestamp_ms,
+            span_id: None,
+            principal: None,
+            actor: None,
+        }
+    }
+}
+
+/// Converts the TableCommit value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for TableCommit {
+    fn to_string(&self) -> String {
+        let params: Vec<Option<String>> = vec![
+            Some("metadata-location".to_string()),
+            Some(self.metadata_location.to_string()),
+            self.previous_metadata_location
+                .as_ref()
+                .map(|previous_metadata_location| {
+                    vec![
+                        "previous-metadata-location".to_string(),
+                        previous_metadata_location.to_string(),
+                    ]
+                    .join(",")
+                }),
+            Some("timestamp-ms".to_string()),
+            Some(self.timestamp_ms.to_string()),
+            self.span_id
+                .as_ref()
+                .map(|span_id| vec!["span-id".to_string(), span_id.to_string()].join(",")),
+            self.principal
+                .as_ref()
+                .map(|principal| vec!["principal".to_string(), principal.to_string()].join(",")),
+            self.actor
+                .as_ref()
+                .map(|actor| vec!["actor".to_string(), actor.to_string()].join(",")),
+        ];
+
+        params.into_iter().flatten().collect::<Vec<_>>().join(",")
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a TableCommit value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for TableCommit {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub metadata_location: Vec<String>,
+            pub previous_metadata_location: Vec<String>,
+            pub timestamp_ms: Vec<i64>,
+            pub span_id: Vec<String>,
+            pub principal: Vec<String>,
+            pub actor: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing TableCommit".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "metadata-location" => intermediate_rep.metadata_location.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "previous-metadata-location" => {
+                        intermediate_rep.previous_metadata_location.push(
+                            <String as std::str::FromStr>::from_str(val)
+                                .map_err(|x| x.to_string())?,
+                        )
+                    }
+                    #[allow(clippy::redundant_clone)]
+                    "timestamp-ms" => intermediate_rep.timestamp_ms.push(
+                        <i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "span-id" => intermediate_rep.span_id.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "principal" => intermediate_rep.principal.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "actor" => intermediate_rep.actor.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing TableCommit".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(TableCommit {
+            metadata_location: intermediate_rep
+                .metadata_location
+                .into_iter()
+                .next()
+                .ok_or_else(|| "metadata-location missing in TableCommit".to_string())?,
+            previous_metadata_location: intermediate_rep
+                .previous_metadata_location
+                .into_iter()
+                .next(),
+            timestamp_ms: intermediate_rep
+                .timestamp_ms
+                .into_iter()
+                .next()
+                .ok_or_else(|| "timestamp-ms missing in TableCommit".to_string())?,
+            span_id: intermediate_rep.span_id.into_iter().next(),
+            principal: intermediate_rep.principal.into_iter().next(),
+            actor: intermediate_rep.actor.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<TableCommit> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<TableCommit>> for hyper::header::HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<TableCommit>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for TableCommit - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<TableCommit> {
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <TableCommit as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into TableCommit - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct TableIdentifier {
+    /// Reference to one or more levels of a namespace
+    #[serde(rename = "namespace")]
+    pub namespace: Vec<String>,
+
+    #[serde(rename = "name")]
+    pub name: String,
+}
+
+impl TableIdentifier {
+    #[allow(clippy::new_without_default)]
+    pub fn new(namespace: Vec<String>, name: String) -> TableIdentifier {
+        TableIdentifier { namespace, name }
+    }
+}
+
+/// Converts the TableIdentifier value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for TableIdentifier {
+    fn to_string(&self) -> String {
+        let params: Vec<Option<String>> = vec![
+            Some("namespace".to_string()),
+            Some(
+                self.namespace
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect::<Vec<_>>()
+                    .join(","),
+            ),
+            Some("name".to_string()),
+            Some(self.name.to_string()),
+        ];
+
+        params.into_iter().flatten().collect::<Vec<_>>().join(",")
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a TableIdentifier value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for TableIdentifier {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub namespace: Vec<Vec<String>>,
+            pub name: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing TableIdentifier".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    "namespace" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in TableIdentifier"
+                                .to_string(),
+                        )
+                    }
+                    #[allow(clippy::redundant_clone)]
+                    "name" => intermediate_rep.name.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing TableIdentifier".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(TableIdentifier {
+            namespace: intermediate_rep
+                .namespace
+                .into_iter()
+                .next()
+                .ok_or_else(|| "namespace missing in TableIdentifier".to_string())?,
+            name: intermediate_rep
+                .name
+                .into_iter()
+                .next()
+                .ok_or_else(|| "name missing in TableIdentifier".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<TableIdentifier> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<TableIdentifier>>
+    for hyper::header::HeaderValue
+{
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<TableIdentifier>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for TableIdentifier - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue>
+    for header::IntoHeaderValue<TableIdentifier>
+{
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <TableIdentifier as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into TableIdentifier - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct TableMetadata {
+    #[serde(rename = "format-version")]
+    pub format_version: u8,
+
+    #[serde(rename = "table-uuid")]
+    pub table_uuid: String,
+
+    #[serde(rename = "location")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+
+    #[serde(rename = "last-updated-ms")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_updated_ms: Option<isize>,
+
+    #[serde(rename = "last-sequence-number")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_sequence_number: Option<i64>,
+
+    #[serde(rename = "properties")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub properties: Option<std::collections::HashMap<String, String>>,
+
+    #[serde(rename = "schemas")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schemas: Option<Vec<models::Schema>>,
+
+    #[serde(rename = "current-schema-id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current_schema_id: Option<isize>,
+
+    #[serde(rename = "last-column-id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_column_id: Option<isize>,
+
+    #[serde(rename = "partition-specs")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub partition_specs: Option<Vec<models::PartitionSpec>>,
+
+    #[serde(rename = "default-spec-id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_spec_id: Option<isize>,
+
+    #[serde(rename = "last-partition-id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_partition_id: Option<isize>,
+
+    #[serde(rename = "sort-orders")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort_orders: Option<Vec<models::SortOrder>>,
+
+    #[serde(rename = "default-sort-order-id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_sort_order_id: Option<isize>,
+
+    #[serde(rename = "snapshots")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snapshots: Option<Vec<models::Snapshot>>,
+
+    #[serde(rename = "refs")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refs: Option<std::collections::HashMap<String, models::SnapshotReference>>,
+
+    #[serde(rename = "current-snapshot-id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current_snapshot_id: Option<isize>,
+
+    #[serde(rename = "snapshot-log")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snapshot_log: Option<Vec<models::SnapshotLogInner>>,
+
+    #[serde(rename = "metadata-log")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata_log: Option<Vec<models::MetadataLogInner>>,
+}
+
+impl TableMetadata {
+    #[allow(clippy::new_without_default)]
+    pub fn new(format_version: u8, table_uuid: String) -> TableMetadata {
+        TableMetadata {
+            format_version,
+            table_uuid,
+            location: None,
+            last_updated_ms: None,
+            last_sequence_number: None,
+            properties: None,
+            schemas: None,
+            current_schema_id: None,
+            last_column_id: None,
+            partition_specs: None,
+            default_spec_id: None,
+            last_partition_id: None,
+            sort_orders: None,
+            default_sort_order_id: None,
+            snapshots: None,
+            refs: None,
+            current_snapshot_id: None,
+            snapshot_log: None,
+            metadata_log: None,
+        }
+    }
+}
+
+/// Converts the TableMetadata value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for TableMetadata {
+    fn to_string(&self) -> String {
+        let params: Vec<Option<String>> = vec![
+            Some("format-version".to_string()),
+            Some(self.format_version.to_string()),
+            Some("table-uuid".to_string()),
+            Some(self.table_uuid.to_string()),
+            self.location
+                .as_ref()
+                .map(|location| vec!["location".to_string(), location.to_string()].join(",")),
+            self.last_updated_ms.as_ref().map(|last_updated_ms| {
+                vec!["last-updated-ms".to_string(), last_updated_ms.to_string()].join(",")
+            }),
+            self.last_sequence_number
+                .as_ref()
+                .map(|last_sequence_number| {
+                    vec![
+                        "last-sequence-number".to_string(),
+                        last_sequence_number.to_string(),
+                    ]
+                    .join(",")
+                }),
+            // Skipping properties in query parameter serialization
+
+            // Skipping schemas in query parameter serialization
+            self.current_schema_id.as_ref().map(|current_schema_id| {
+                vec![
+                    "current-schema-id".to_string(),
+                    current_schema_id.to_string(),
+                ]
+                .join(",")
+            }),
+            self.last_column_id.as_ref().map(|last_column_id| {
+                vec!["last-column-id".to_string(), last_column_id.to_string()].join(",")
+            }),
+            // Skipping partition-specs in query parameter serialization
+            self.default_spec_id.as_ref().map(|default_spec_id| {
+                vec!["default-spec-id".to_string(), default_spec_id.to_string()].join(",")
+            }),
+            self.last_partition_id.as_ref().map(|last_partition_id| {
+                vec![
+                    "last-partition-id".to_string(),
+                    last_partition_id.to_string(),
+                ]
+                .join(",")
+            }),
+            // Skipping sort-orders in query parameter serialization
+            self.default_sort_order_id
+                .as_ref()
+                .map(|default_sort_order_id| {
+                    vec![
+                        "default-sort-order-id".to_string(),
+                        default_sort_order_id.to_string(),
+                    ]
+                    .join(",")
+                }),
+            // Skipping snapshots in query parameter serialization
+
+            // Skipping refs in query parameter serialization
+            // Skipping refs in query parameter serialization
+            self.current_snapshot_id
+                .as_ref()
+                .map(|current_snapshot_id| {
+                    vec![
+                        "current-snapshot-id".to_string(),
+                        current_snapshot_id.to_string(),
+                    ]
+                    .join(",")
+                }),
+            // Skipping snapshot-log in query parameter serialization
+
+            // Skipping metadata-log in query parameter serialization
+        ];
+
+        params.into_iter().flatten().collect::<Vec<_>>().join(",")
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a TableMetadata value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for TableMetadata {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub format_version: Vec<u8>,
+            pub table_uuid: Vec<String>,
+            pub location: Vec<String>,
+            pub last_updated_ms: Vec<isize>,
+            pub last_sequence_number: Vec<i64>,
+            pub properties: Vec<std::collections::HashMap<String, String>>,
+            pub schemas: Vec<Vec<models::Schema>>,
+            pub current_schema_id: Vec<isize>,
+            pub last_column_id: Vec<isize>,
+            pub partition_specs: Vec<Vec<models::PartitionSpec>>,
+            pub default_spec_id: Vec<isize>,
+            pub last_partition_id: Vec<isize>,
+            pub sort_orders: Vec<Vec<models::SortOrder>>,
+            pub default_sort_order_id: Vec<isize>,
+            pub snapshots: Vec<Vec<models::Snapshot>>,
+            pub refs: Vec<std::collections::HashMap<String, models::SnapshotReference>>,
+            pub current_snapshot_id: Vec<isize>,
+            pub snapshot_log: Vec<Vec<models::SnapshotLogInner>>,
+            pub metadata_log: Vec<Vec<models::MetadataLogInner>>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing TableMetadata".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "format-version" => intermediate_rep
+                        .format_version
+                        .push(<u8 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "table-uuid" => intermediate_rep.table_uuid.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "location" => intermediate_rep.location.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "last-updated-ms" => intermediate_rep.last_updated_ms.push(
+                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "last-sequence-number" => intermediate_rep.last_sequence_number.push(
+                        <i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    "properties" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in TableMetadata"
+                                .to_string(),
+                        )
+                    }
+                    "schemas" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in TableMetadata"
+                                .to_string(),
+                        )
+                    }
+                    #[allow(clippy::redundant_clone)]
+                    "current-schema-id" => intermediate_rep.current_schema_id.push(
+                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "last-column-id" => intermediate_rep.last_column_id.push(
+                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    "partition-specs" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in TableMetadata"
+                                .to_string(),
+                        )
+                    }
+                    #[allow(clippy::redundant_clone)]
+                    "default-spec-id" => intermediate_rep.default_spec_id.push(
+                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "last-partition-id" => intermediate_rep.last_partition_id.push(
+                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    "sort-orders" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in TableMetadata"
+                                .to_string(),
+                        )
+                    }
+                    #[allow(clippy::redundant_clone)]
+                    "default-sort-order-id" => intermediate_rep.default_sort_order_id.push(
+                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    "snapshots" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in TableMetadata"
+                                .to_string(),
+                        )
+                    }
+                    "refs" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in TableMetadata"
+                                .to_string(),
+                        )
+                    }
+                    #[allow(clippy::redundant_clone)]
+                    "current-snapshot-id" => intermediate_rep.current_snapshot_id.push(
+                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    "snapshot-log" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in TableMetadata"
+                                .to_string(),
+                        )
+                    }
+                    "metadata-log" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in TableMetadata"
+                                .to_string(),
+                        )
+                    }
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing TableMetadata".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(TableMetadata {
+            format_version: intermediate_rep
+                .format_version
+                .into_iter()
+                .next()
+                .ok_or_else(|| "format-version missing in TableMetadata".to_string())?,
+            table_uuid: intermediate_rep
+                .table_uuid
+                .into_iter()
+                .next()
+                .ok_or_else(|| "table-uuid missing in TableMetadata".to_string())?,
+            location: intermediate_rep.location.into_iter().next(),
+            last_updated_ms: intermediate_rep.last_updated_ms.into_iter().next(),
+            last_sequence_number: intermediate_rep.last_sequence_number.into_iter().next(),
+            properties: intermediate_rep.properties.into_iter().next(),
+            schemas: intermediate_rep.schemas.into_iter().next(),
+            current_schema_id: intermediate_rep.current_schema_id.into_iter().next(),
+            last_column_id: intermediate_rep.last_column_id.into_iter().next(),
+            partition_specs: intermediate_rep.partition_specs.into_iter().next(),
+            default_spec_id: intermediate_rep.default_spec_id.into_iter().next(),
+            last_partition_id: intermediate_rep.last_partition_id.into_iter().next(),
+            sort_orders: intermediate_rep.sort_orders.into_iter().next(),
+            default_sort_order_id: intermediate_rep.default_sort_order_id.into_iter().next(),
+            snapshots: intermediate_rep.snapshots.into_iter().next(),
+            refs: intermediate_rep.refs.into_iter().next(),
+            current_snapshot_id: intermediate_rep.current_snapshot_id.into_iter().next(),
+            snapshot_log: intermediate_rep.snapshot_log.into_iter().next(),
+            metadata_log: intermediate_rep.metadata_log.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<TableMetadata> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<TableMetadata>> for hyper::header::HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<TableMetadata>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for TableMetadata - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<TableMetadata> {
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <TableMetadata as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into TableMetadata - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+/// The metrics reports of a table aggregated over a time window
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct TableMetrics {
+    #[serde(rename = "identifier")]
+    pub identifier: models::TableIdentifier,
+
+    /// The number of scan reports
+    #[serde(rename = "scan-count")]
+    pub scan_count: i64,
+
+    /// The number of commit reports
+    #[serde(rename = "commit-count")]
+    pub commit_count: i64,
+
+    /// The sums of the counters by name
+    #[serde(rename = "counters")]
+    pub counters: std::collections::HashMap<String, i64>,
+
+    /// The sums of the timers by name, in nanoseconds
+    #[serde(rename = "timers")]
+    pub timers: std::collections::HashMap<String, models::TimerResult>,
+
+    /// The filters of the scans, most used first
+    #[serde(rename = "filters")]
+    pub filters: Vec<models::FilterUsage>,
+}
+
+impl TableMetrics {
+    #[allow(clippy::new_without_default)]
+    pub fn new(
+        identifier: models::TableIdentifier,
+        scan_count: i64,
+        commit_count: i64,
+        counters: std::collections::HashMap<String, i64>,
+        timers: std::collections::HashMap<String, models::TimerResult>,
+        filters: Vec<models::FilterUsage>,
+    ) -> TableMetrics {
+        TableMetrics {
+            identifier,
+            scan_count,
+            commit_count,
+            counters,
+            timers,
+            filters,
+        }
+    }
+}
+
+/// Converts the TableMetrics value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for TableMetrics {
+    fn to_string(&self) -> String {
+        let params: Vec<Option<String>> = vec![
+            // Skipping identifier in query parameter serialization
+            Some("scan-count".to_string()),
+            Some(self.scan_count.to_string()),
+            Some("commit-count".to_string()),
+            Some(self.commit_count.to_string()),
+            // Skipping counters in query parameter serialization
+
+            // Skipping timers in query parameter serialization
+
+            // Skipping filters in query parameter serialization
+        ];
+
+        params.into_iter().flatten().collect::<Vec<_>>().join(",")
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a TableMetrics value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for TableMetrics {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub identifier: Vec<models::TableIdentifier>,
+            pub scan_count: Vec<i64>,
+            pub commit_count: Vec<i64>,
+            pub counters: Vec<std::collections::HashMap<String, i64>>,
+            pub timers: Vec<std::collections::HashMap<String, models::TimerResult>>,
+            pub filters: Vec<Vec<models::FilterUsage>>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing TableMetrics".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "identifier" => intermediate_rep.identifier.push(
+                        <models::TableIdentifier as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "scan-count" => intermediate_rep.scan_count.push(
+                        <i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "commit-count" => intermediate_rep.commit_count.push(
+                        <i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    "counters" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in TableMetrics"
+                                .to_string(),
+                        )
+                    }
+                    "timers" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in TableMetrics"
+                                .to_string(),
+                        )
+                    }
+                    "filters" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in TableMetrics"
+                                .to_string(),
+                        )
+                    }
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing TableMetrics".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(TableMetrics {
+            identifier: intermediate_rep
+                .identifier
+                .into_iter()
+                .next()
+                .ok_or_else(|| "identifier missing in TableMetrics".to_string())?,
+            scan_count: intermediate_rep
+                .scan_count
+                .into_iter()
+                .next()
+                .ok_or_else(|| "scan-count missing in TableMetrics".to_string())?,
+            commit_count: intermediate_rep
+                .commit_count
+                .into_iter()
+                .next()
+                .ok_or_else(|| "commit-count missing in TableMetrics".to_string())?,
+            counters: intermediate_rep
+                .counters
+                .into_iter()
+                .next()
+                .ok_or_else(|| "counters missing in TableMetrics".to_string())?,
+            timers: intermediate_rep
+                .timers
+                .into_iter()
+                .next()
+                .ok_or_else(|| "timers missing in TableMetrics".to_string())?,
+            filters: intermediate_rep
+                .filters
+                .into_iter()
+                .next()
+                .ok_or_else(|| "filters missing in TableMetrics".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<TableMetrics> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<TableMetrics>> for hyper::header::HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<TableMetrics>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for TableMetrics - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<TableMetrics> {
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <TableMetrics as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into TableMetrics - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+/// Assertions from the client that must be valid for the commit to succeed. Assertions are identified by `type` - - `assert-create` - the table must not already exist; used for create transactions - `assert-table-uuid` - the table UUID must match the requirement's `uuid` - `assert-ref-snapshot-id` - the table branch or tag identified by the requirement's `ref` must reference the requirement's `snapshot-id`; if `snapshot-id` is `null` or missing, the ref must not already exist - `assert-last-assigned-field-id` - the table's last assigned column id must match the requirement's `last-assigned-field-id` - `assert-current-schema-id` - the table's current schema id must match the requirement's `current-schema-id` - `assert-last-assigned-partition-id` - the table's last assigned partition id must match the requirement's `last-assigned-partition-id` - `assert-default-spec-id` - the table's default spec id must match the requirement's `default-spec-id` - `assert-default-sort-order-id` - the table's default sort order id must match the requirement's `default-sort-order-id`
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct TableRequirement {
+    // Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "requirement")]
+    pub requirement: String,
+
+    #[serde(rename = "ref")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub r#ref: Option<String>,
+
+    #[serde(rename = "uuid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uuid: Option<String>,
+
+    #[serde(rename = "snapshot-id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snapshot_id: Option<i64>,
+
+    #[serde(rename = "last-assigned-field-id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_assigned_field_id: Option<isize>,
+
+    #[serde(rename = "current-schema-id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current_schema_id: Option<isize>,
+
+    #[serde(rename = "last-assigned-partition-id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_assigned_partition_id: Option<isize>,
+
+    #[serde(rename = "default-spec-id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_spec_id: Option<isize>,
+
+    #[serde(rename = "default-sort-order-id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_sort_order_id: Option<isize>,
+}
+
+impl TableRequirement {
+    #[allow(clippy::new_without_default)]
+    pub fn new(requirement: String) -> TableRequirement {
+        TableRequirement {
+            requirement,
+            r#ref: None,
+            uuid: None,
+            snapshot_id: None,
+            last_assigned_field_id: None,
+            current_schema_id: None,
+            last_assigned_partition_id: None,
+            default_spec_id: None,
+            default_sort_order_id: None,
+        }
+    }
+}
+
+/// Converts the TableRequirement value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for TableRequirement {
+    fn to_string(&self) -> String {
+        let params: Vec<Option<String>> = vec![
+            Some("requirement".to_string()),
+            Some(self.requirement.to_string()),
+            self.r#ref
+                .as_ref()
+                .map(|r#ref| vec!["ref".to_string(), r#ref.to_string()].join(",")),
+            self.uuid
+                .as_ref()
+                .map(|uuid| vec!["uuid".to_string(), uuid.to_string()].join(",")),
+            self.snapshot_id.as_ref().map(|snapshot_id| {
+                vec!["snapshot-id".to_string(), snapshot_id.to_string()].join(",")
+            }),
+            self.last_assigned_field_id
+                .as_ref()
+                .map(|last_assigned_field_id| {
+                    vec![
+                        "last-assigned-field-id".to_string(),
+                        last_assigned_field_id.to_string(),
+                    ]
+                    .join(",")
+                }),
+            self.current_schema_id.as_ref().map(|current_schema_id| {
+                vec![
+                    "current-schema-id".to_string(),
+                    current_schema_id.to_string(),
+                ]
+                .join(",")
+            }),
+            self.last_assigned_partition_id
+                .as_ref()
+                .map(|last_assigned_partition_id| {
+                    vec![
+                        "last-assigned-partition-id".to_string(),
+                        last_assigned_partition_id.to_string(),
+                    ]
+                    .join(",")
+                }),
+            self.default_spec_id.as_ref().map(|default_spec_id| {
+                vec!["default-spec-id".to_string(), default_spec_id.to_string()].join(",")
+            }),
+            self.default_sort_order_id
+                .as_ref()
+                .map(|default_sort_order_id| {
+                    vec![
+                        "default-sort-order-id".to_string(),
+                        default_sort_order_id.to_string(),
+                    ]
+                    .join(",")
+                }),
+        ];
+
+        params.into_iter().flatten().collect::<Vec<_>>().join(",")
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a TableRequirement value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for TableRequirement {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub requirement: Vec<String>,
+            pub r#ref: Vec<String>,
+            pub uuid: Vec<String>,
+            pub snapshot_id: Vec<i64>,
+            pub last_assigned_field_id: Vec<isize>,
+            pub current_schema_id: Vec<isize>,
+            pub last_assigned_partition_id: Vec<isize>,
+            pub default_spec_id: Vec<isize>,
+            pub default_sort_order_id: Vec<isize>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing TableRequirement".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "requirement" => intermediate_rep.requirement.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "ref" => intermediate_rep.r#ref.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "uuid" => intermediate_rep.uuid.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "snapshot-id" => intermediate_rep.snapshot_id.push(
+                        <i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "last-assigned-field-id" => intermediate_rep.last_assigned_field_id.push(
+                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "current-schema-id" => intermediate_rep.current_schema_id.push(
+                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "last-assigned-partition-id" => {
+                        intermediate_rep.last_assigned_partition_id.push(
+                            <isize as std::str::FromStr>::from_str(val)
+                                .map_err(|x| x.to_string())?,
+                        )
+                    }
+                    #[allow(clippy::redundant_clone)]
+                    "default-spec-id" => intermediate_rep.default_spec_id.push(
+                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "default-sort-order-id" => intermediate_rep.default_sort_order_id.push(
+                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing TableRequirement".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(TableRequirement {
+            requirement: intermediate_rep
+                .requirement
+                .into_iter()
+                .next()
+                .ok_or_else(|| "requirement missing in TableRequirement".to_string())?,
+            r#ref: intermediate_rep.r#ref.into_iter().next(),
+            uuid: intermediate_rep.uuid.into_iter().next(),
+            snapshot_id: intermediate_rep.snapshot_id.into_iter().next(),
+            last_assigned_field_id: intermediate_rep.last_assigned_field_id.into_iter().next(),
+            current_schema_id: intermediate_rep.current_schema_id.into_iter().next(),
+            last_assigned_partition_id: intermediate_rep
+                .last_assigned_partition_id
+                .into_iter()
+                .next(),
+            default_spec_id: intermediate_rep.default_spec_id.into_iter().next(),
+            default_sort_order_id: intermediate_rep.default_sort_order_id.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<TableRequirement> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<TableRequirement>>
+    for hyper::header::HeaderValue
+{
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<TableRequirement>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for TableRequirement - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue>
+    for header::IntoHeaderValue<TableRequirement>
+{
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <TableRequirement as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into TableRequirement - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+/// One of the updates of a table. The variant is chosen by the `action` of the update.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+#[serde(untagged)]
+pub enum TableUpdate {
+    AssignUuid(models::AssignUuidUpdate),
+    UpgradeFormatVersion(models::UpgradeFormatVersionUpdate),
+    AddSchema(models::AddSchemaUpdate),
+    SetCurrentSchema(models::SetCurrentSchemaUpdate),
+    AddPartitionSpec(models::AddPartitionSpecUpdate),
+    SetDefaultSpec(models::SetDefaultSpecUpdate),
+    AddSortOrder(models::AddSortOrderUpdate),
+    SetDefaultSortOrder(models::SetDefaultSortOrderUpdate),
+    AddSnapshot(models::AddSnapshotUpdate),
+    SetSnapshotRef(models::SetSnapshotRefUpdate),
+    RemoveSnapshots(models::RemoveSnapshotsUpdate),
+    RemoveSnapshotRef(models::RemoveSnapshotRefUpdate),
+    SetLocation(models::SetLocationUpdate),
+    SetProperties(models::SetPropertiesUpdate),
+    RemoveProperties(models::RemovePropertiesUpdate),
+}
+
+impl TableUpdate {
+    /// The actions of the variants in declaration order.
+    pub const ACTIONS: &'static [&'static str] = &[
+        "assign-uuid",
+        "upgrade-format-version",
+        "add-schema",
+        "set-current-schema",
+        "add-spec",
+        "set-default-spec",
+        "add-sort-order",
+        "set-default-sort-order",
+        "add-snapshot",
+        "set-snapshot-ref",
+        "remove-snapshots",
+        "remove-snapshot-ref",
+        "set-location",
+        "set-properties",
+        "remove-properties",
+    ];
+}
+
+impl<'de> serde::Deserialize<'de> for TableUpdate {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error;
+
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let action = value
+            .get("action")
+            .and_then(|action| action.as_str())
+            .ok_or_else(|| D::Error::missing_field("action"))?
+            .to_owned();
+        match action.as_str() {
+            "assign-uuid" => serde_json::from_value(value).map(TableUpdate::AssignUuid),
+            "upgrade-format-version" => {
+                serde_json::from_value(value).map(TableUpdate::UpgradeFormatVersion)
+            }
+            "add-schema" => serde_json::from_value(value).map(TableUpdate::AddSchema),
+            "set-current-schema" => {
+                serde_json::from_value(value).map(TableUpdate::SetCurrentSchema)
+            }
+            "add-spec" => serde_json::from_value(value).map(TableUpdate::AddPartitionSpec),
+            "set-default-spec" => serde_json::from_value(value).map(TableUpdate::SetDefaultSpec),
+            "add-sort-order" => serde_json::from_value(value).map(TableUpdate::AddSortOrder),
+            "set-default-sort-order" => {
+                serde_json::from_value(value).map(TableUpdate::SetDefaultSortOrder)
+            }
+            "add-snapshot" => serde_json::from_value(value).map(TableUpdate::AddSnapshot),
+            "set-snapshot-ref" => serde_json::from_value(value).map(TableUpdate::SetSnapshotRef),
+            "remove-snapshots" => serde_json::from_value(value).map(TableUpdate::RemoveSnapshots),
+            "remove-snapshot-ref" => {
+                serde_json::from_value(value).map(TableUpdate::RemoveSnapshotRef)
+            }
+            "set-location" => serde_json::from_value(value).map(TableUpdate::SetLocation),
+            "set-properties" => serde_json::from_value(value).map(TableUpdate::SetProperties),
+            "remove-properties" => serde_json::from_value(value).map(TableUpdate::RemoveProperties),
+            _ => {
+                return std::result::Result::Err(D::Error::unknown_variant(
+                    &action,
+                    TableUpdate::ACTIONS,
+                ))
+            }
+        }
+        .map_err(D::Error::custom)
+    }
+}
+
+/// Converts the TableUpdate value to its JSON representation.
+impl std::string::ToString for TableUpdate {
+    fn to_string(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+}
+
+/// Converts the JSON representation of a table update to a TableUpdate value.
+impl std::str::FromStr for TableUpdate {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        serde_json::from_str(s).map_err(|err| err.to_string())
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<TableUpdate> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<TableUpdate>> for hyper::header::HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<TableUpdate>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for TableUpdate - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<TableUpdate> {
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <TableUpdate as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into TableUpdate - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct Term {
+    // Note: inline enums are not fully supported by openapi-generator
+    #[serde(rename = "type")]
+    pub r#type: String,
+
+    #[serde(rename = "transform")]
+    pub transform: String,
+
+    #[serde(rename = "term")]
+    pub term: String,
+}
+
+impl Term {
+    #[allow(clippy::new_without_default)]
+    pub fn new(r#type: String, transform: String, term: String) -> Term {
+        Term {
+            r#type,
+            transform,
+            term,
+        }
+    }
+}
+
+/// Converts the Term value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for Term {
+    fn to_string(&self) -> String {
+        let params: Vec<Option<String>> = vec![
+            Some("type".to_string()),
+            Some(self.r#type.to_string()),
+            Some("transform".to_string()),
+            Some(self.transform.to_string()),
+            Some("term".to_string()),
+            Some(self.term.to_string()),
+        ];
+
+        params.into_iter().flatten().collect::<Vec<_>>().join(",")
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a Term value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for Term {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub r#type: Vec<String>,
+            pub transform: Vec<String>,
+            pub term: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err("Missing value while parsing Term".to_string())
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "type" => intermediate_rep.r#type.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "transform" => intermediate_rep.transform.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "term" => intermediate_rep.term.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing Term".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(Term {
+            r#type: intermediate_rep
+                .r#type
                 .into_iter()
                 .next()
-                .ok_or_else(|| "snapshot-id missing in TableUpdate".to_string())?,
-            max_ref_age_ms: intermediate_rep.max_ref_age_ms.into_iter().next(),
-            max_snapshot_age_ms: intermediate_rep.max_snapshot_age_ms.into_iter().next(),
-            min_snapshots_to_keep: intermediate_rep.min_snapshots_to_keep.into_iter().next(),
-            ref_name: intermediate_rep
-                .ref_name
+                .ok_or_else(|| "type missing in Term".to_string())?,
+            transform: intermediate_rep
+                .transform
                 .into_iter()
                 .next()
-                .ok_or_else(|| "ref-name missing in TableUpdate".to_string())?,
-            snapshot_ids: intermediate_rep
-                .snapshot_ids
+                .ok_or_else(|| "transform missing in Term".to_string())?,
+            term: intermediate_rep
+                .term
                 .into_iter()
                 .next()
-                .ok_or_else(|| "snapshot-ids missing in TableUpdate".to_string())?,
-            location: intermediate_rep
-                .location
+                .ok_or_else(|| "term missing in Term".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<Term> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<Term>> for hyper::header::HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<Term>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for Term - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<Term> {
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => match <Term as std::str::FromStr>::from_str(value) {
+                std::result::Result::Ok(value) => {
+                    std::result::Result::Ok(header::IntoHeaderValue(value))
+                }
+                std::result::Result::Err(err) => std::result::Result::Err(format!(
+                    "Unable to convert header value '{}' into Term - {}",
+                    value, err
+                )),
+            },
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct TimerResult {
+    #[serde(rename = "time-unit")]
+    pub time_unit: String,
+
+    #[serde(rename = "count")]
+    pub count: i64,
+
+    #[serde(rename = "total-duration")]
+    pub total_duration: i64,
+}
+
+impl TimerResult {
+    #[allow(clippy::new_without_default)]
+    pub fn new(time_unit: String, count: i64, total_duration: i64) -> TimerResult {
+        TimerResult {
+            time_unit,
+            count,
+            total_duration,
+        }
+    }
+}
+
+/// Converts the TimerResult value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for TimerResult {
+    fn to_string(&self) -> String {
+        let params: Vec<Option<String>> = vec![
+            Some("time-unit".to_string()),
+            Some(self.time_unit.to_string()),
+            Some("count".to_string()),
+            Some(self.count.to_string()),
+            Some("total-duration".to_string()),
+            Some(self.total_duration.to_string()),
+        ];
+
+        params.into_iter().flatten().collect::<Vec<_>>().join(",")
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a TimerResult value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for TimerResult {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub time_unit: Vec<String>,
+            pub count: Vec<i64>,
+            pub total_duration: Vec<i64>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing TimerResult".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "time-unit" => intermediate_rep.time_unit.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "count" => intermediate_rep.count.push(
+                        <i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "total-duration" => intermediate_rep.total_duration.push(
+                        <i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing TimerResult".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(TimerResult {
+            time_unit: intermediate_rep
+                .time_unit
                 .into_iter()
                 .next()
-                .ok_or_else(|| "location missing in TableUpdate".to_string())?,
-            updates: intermediate_rep
-                .updates
+                .ok_or_else(|| "time-unit missing in TimerResult".to_string())?,
+            count: intermediate_rep
+                .count
                 .into_iter()
                 .next()
-                .ok_or_else(|| "updates missing in TableUpdate".to_string())?,
-            removals: intermediate_rep
-                .removals
+                .ok_or_else(|| "count missing in TimerResult".to_string())?,
+            total_duration: intermediate_rep
+                .total_duration
                 .into_iter()
                 .next()
-                .ok_or_else(|| "removals missing in TableUpdate".to_string())?,
+                .ok_or_else(|| "total-duration missing in TimerResult".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<TableUpdate> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<TimerResult> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<TableUpdate>> for hyper::header::HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<TimerResult>> for hyper::header::HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<TableUpdate>,
+        hdr_value: header::IntoHeaderValue<TimerResult>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for TableUpdate - value: {} is invalid {}",
+                "Invalid header value for TimerResult - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -11891,18 +14269,18 @@ impl std::convert::TryFrom<header::IntoHeaderValue<TableUpdate>> for hyper::head
 }
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<TableUpdate> {
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<TimerResult> {
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <TableUpdate as std::str::FromStr>::from_str(value) {
+                match <TimerResult as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into TableUpdate - {}",
+                        "Unable to convert header value '{}' into TimerResult - {}",
                         value, err
                     )),
                 }
@@ -11915,9 +14293,111 @@ impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderVal
     }
 }
 
+/// Token type identifier, from RFC 8693 Section 3  See https://datatracker.ietf.org/doc/html/rfc8693#section-3
+/// Enumeration of values.
+/// Since this enum's variants do not hold data, we can easily define them them as `#[repr(C)]`
+/// which helps with FFI.
+#[allow(non_camel_case_types)]
+#[repr(C)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+#[cfg_attr(feature = "conversion", derive(frunk_enum_derive::LabelledGenericEnum))]
+pub enum TokenType {
+    #[serde(rename = "urn:ietf:params:oauth:token-type:access_token")]
+    AccessToken,
+    #[serde(rename = "urn:ietf:params:oauth:token-type:refresh_token")]
+    RefreshToken,
+    #[serde(rename = "urn:ietf:params:oauth:token-type:id_token")]
+    IdToken,
+    #[serde(rename = "urn:ietf:params:oauth:token-type:saml1")]
+    Saml1,
+    #[serde(rename = "urn:ietf:params:oauth:token-type:saml2")]
+    Saml2,
+    #[serde(rename = "urn:ietf:params:oauth:token-type:jwt")]
+    Jwt,
+}
+
+impl std::fmt::Display for TokenType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            TokenType::AccessToken => write!(f, "urn:ietf:params:oauth:token-type:access_token"),
+            TokenType::RefreshToken => write!(f, "urn:ietf:params:oauth:token-type:refresh_token"),
+            TokenType::IdToken => write!(f, "urn:ietf:params:oauth:token-type:id_token"),
+            TokenType::Saml1 => write!(f, "urn:ietf:params:oauth:token-type:saml1"),
+            TokenType::Saml2 => write!(f, "urn:ietf:params:oauth:token-type:saml2"),
+            TokenType::Jwt => write!(f, "urn:ietf:params:oauth:token-type:jwt"),
+        }
+    }
+}
+
+impl std::str::FromStr for TokenType {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "urn:ietf:params:oauth:token-type:access_token" => {
+                std::result::Result::Ok(TokenType::AccessToken)
+            }
+            "urn:ietf:params:oauth:token-type:refresh_token" => {
+                std::result::Result::Ok(TokenType::RefreshToken)
+            }
+            "urn:ietf:params:oauth:token-type:id_token" => {
+                std::result::Result::Ok(TokenType::IdToken)
+            }
+            "urn:ietf:params:oauth:token-type:saml1" => std::result::Result::Ok(TokenType::Saml1),
+            "urn:ietf:params:oauth:token-type:saml2" => std::result::Result::Ok(TokenType::Saml2),
+            "urn:ietf:params:oauth:token-type:jwt" => std::result::Result::Ok(TokenType::Jwt),
+            _ => std::result::Result::Err(format!("Value not valid: {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, PartialOrd, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct Transform(String);
+
+impl std::convert::From<String> for Transform {
+    fn from(x: String) -> Self {
+        Transform(x)
+    }
+}
+
+impl std::string::ToString for Transform {
+    fn to_string(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+impl std::str::FromStr for Transform {
+    type Err = std::string::ParseError;
+    fn from_str(x: &str) -> std::result::Result<Self, Self::Err> {
+        std::result::Result::Ok(Transform(x.to_string()))
+    }
+}
+
+impl std::convert::From<Transform> for String {
+    fn from(x: Transform) -> Self {
+        x.0
+    }
+}
+
+impl std::ops::Deref for Transform {
+    type Target = String;
+    fn deref(&self) -> &String {
+        &self.0
+    }
+}
+
+impl std::ops::DerefMut for Transform {
+    fn deref_mut(&mut self) -> &mut String {
+        &mut self.0
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct Term {
+pub struct TransformTerm {
     // Note: inline enums are not fully supported by openapi-generator
     #[serde(rename = "type")]
     pub r#type: String,
@@ -11929,10 +14409,10 @@ pub struct Term {
     pub term: String,
 }
 
-impl Term {
+impl TransformTerm {
     #[allow(clippy::new_without_default)]
-    pub fn new(r#type: String, transform: String, term: String) -> Term {
-        Term {
+    pub fn new(r#type: String, transform: String, term: String) -> TransformTerm {
+        TransformTerm {
             r#type,
             transform,
             term,
@@ -11940,10 +14420,10 @@ impl Term {
     }
 }
 
-/// Converts the Term value to the Query Parameters representation (style=form, explode=false)
+/// Converts the TransformTerm value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for Term {
+impl std::string::ToString for TransformTerm {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
             Some("type".to_string()),
@@ -11958,10 +14438,10 @@ impl std::string::ToString for Term {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a Term value
+/// Converts Query Parameters representation (style=form, explode=false) to a TransformTerm value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for Term {
+impl std::str::FromStr for TransformTerm {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -11984,7 +14464,9 @@ impl std::str::FromStr for Term {
             let val = match string_iter.next() {
                 Some(x) => x,
                 None => {
-                    return std::result::Result::Err("Missing value while parsing Term".to_string())
+                    return std::result::Result::Err(
+                        "Missing value while parsing TransformTerm".to_string(),
+                    )
                 }
             };
 
@@ -12005,7 +14487,7 @@ impl std::str::FromStr for Term {
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing Term".to_string(),
+                            "Unexpected key while parsing TransformTerm".to_string(),
                         )
                     }
                 }
@@ -12016,40 +14498,145 @@ impl std::str::FromStr for Term {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(Term {
+        std::result::Result::Ok(TransformTerm {
             r#type: intermediate_rep
                 .r#type
                 .into_iter()
                 .next()
-                .ok_or_else(|| "type missing in Term".to_string())?,
+                .ok_or_else(|| "type missing in TransformTerm".to_string())?,
             transform: intermediate_rep
                 .transform
                 .into_iter()
                 .next()
-                .ok_or_else(|| "transform missing in Term".to_string())?,
+                .ok_or_else(|| "transform missing in TransformTerm".to_string())?,
             term: intermediate_rep
                 .term
                 .into_iter()
                 .next()
-                .ok_or_else(|| "term missing in Term".to_string())?,
+                .ok_or_else(|| "term missing in TransformTerm".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<Term> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<TransformTerm> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<TransformTerm>> for hyper::header::HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<TransformTerm>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for TransformTerm - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<TransformTerm> {
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <TransformTerm as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into TransformTerm - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+/// One of the primitive, struct, list or map types.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(untagged)]
+pub enum Type {
+    Primitive(models::PrimitiveType),
+    Struct(models::StructType),
+    List(Box<models::ListType>),
+    Map(Box<models::MapType>),
+}
+
+impl From<models::PrimitiveType> for Type {
+    fn from(x: models::PrimitiveType) -> Self {
+        Type::Primitive(x)
+    }
+}
+
+impl From<models::StructType> for Type {
+    fn from(x: models::StructType) -> Self {
+        Type::Struct(x)
+    }
+}
+
+impl From<models::ListType> for Type {
+    fn from(x: models::ListType) -> Self {
+        Type::List(Box::new(x))
+    }
+}
+
+impl From<models::MapType> for Type {
+    fn from(x: models::MapType) -> Self {
+        Type::Map(Box::new(x))
+    }
+}
+
+/// Converts the Type value to its JSON representation. Primitive types are
+/// represented by their name.
+impl std::string::ToString for Type {
+    fn to_string(&self) -> String {
+        match self {
+            Type::Primitive(x) => x.to_string(),
+            x => serde_json::to_string(x).unwrap_or_default(),
+        }
+    }
+}
+
+/// Converts the JSON representation of a type or the name of a primitive type
+/// to a Type value.
+impl std::str::FromStr for Type {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match serde_json::from_str(s) {
+            std::result::Result::Ok(x) => std::result::Result::Ok(x),
+            std::result::Result::Err(_) => {
+                std::result::Result::Ok(Type::Primitive(models::PrimitiveType::from(s.to_string())))
+            }
+        }
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<Type> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<Term>> for hyper::header::HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<Type>> for hyper::header::HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<Term>,
+        hdr_value: header::IntoHeaderValue<Type>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for Term - value: {} is invalid {}",
+                "Invalid header value for Type - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -12057,17 +14644,17 @@ impl std::convert::TryFrom<header::IntoHeaderValue<Term>> for hyper::header::Hea
 }
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<Term> {
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<Type> {
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
-            std::result::Result::Ok(value) => match <Term as std::str::FromStr>::from_str(value) {
+            std::result::Result::Ok(value) => match <Type as std::str::FromStr>::from_str(value) {
                 std::result::Result::Ok(value) => {
                     std::result::Result::Ok(header::IntoHeaderValue(value))
                 }
                 std::result::Result::Err(err) => std::result::Result::Err(format!(
-                    "Unable to convert header value '{}' into Term - {}",
+                    "Unable to convert header value '{}' into Type - {}",
                     value, err
                 )),
             },
@@ -12081,50 +14668,49 @@ impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderVal
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct TimerResult {
-    #[serde(rename = "time-unit")]
-    pub time_unit: String,
+pub struct UnaryExpression {
+    #[serde(rename = "type")]
+    pub r#type: String,
 
-    #[serde(rename = "count")]
-    pub count: i64,
+    #[serde(rename = "term")]
+    pub term: models::Term,
 
-    #[serde(rename = "total-duration")]
-    pub total_duration: i64,
+    #[serde(rename = "value")]
+    pub value: serde_json::Value,
 }
 
-impl TimerResult {
+impl UnaryExpression {
     #[allow(clippy::new_without_default)]
-    pub fn new(time_unit: String, count: i64, total_duration: i64) -> TimerResult {
-        TimerResult {
-            time_unit,
-            count,
-            total_duration,
+    pub fn new(r#type: String, term: models::Term, value: serde_json::Value) -> UnaryExpression {
+        UnaryExpression {
+            r#type,
+            term,
+            value,
         }
     }
 }
 
-/// Converts the TimerResult value to the Query Parameters representation (style=form, explode=false)
+/// Converts the UnaryExpression value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for TimerResult {
+impl std::string::ToString for UnaryExpression {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
-            Some("time-unit".to_string()),
-            Some(self.time_unit.to_string()),
-            Some("count".to_string()),
-            Some(self.count.to_string()),
-            Some("total-duration".to_string()),
-            Some(self.total_duration.to_string()),
+            Some("type".to_string()),
+            Some(self.r#type.to_string()),
+            // Skipping term in query parameter serialization
+
+            // Skipping value in query parameter serialization
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a TimerResult value
+/// Converts Query Parameters representation (style=form, explode=false) to a UnaryExpression value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for TimerResult {
+impl std::str::FromStr for UnaryExpression {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -12132,9 +14718,9 @@ impl std::str::FromStr for TimerResult {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub time_unit: Vec<String>,
-            pub count: Vec<i64>,
-            pub total_duration: Vec<i64>,
+            pub r#type: Vec<String>,
+            pub term: Vec<models::Term>,
+            pub value: Vec<serde_json::Value>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -12148,7 +14734,7 @@ impl std::str::FromStr for TimerResult {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing TimerResult".to_string(),
+                        "Missing value while parsing UnaryExpression".to_string(),
                     )
                 }
             };
@@ -12157,20 +14743,22 @@ impl std::str::FromStr for TimerResult {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "time-unit" => intermediate_rep.time_unit.push(
+                    "type" => intermediate_rep.r#type.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "count" => intermediate_rep.count.push(
-                        <i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "term" => intermediate_rep.term.push(
+                        <models::Term as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "total-duration" => intermediate_rep.total_duration.push(
-                        <i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "value" => intermediate_rep.value.push(
+                        <serde_json::Value as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing TimerResult".to_string(),
+                            "Unexpected key while parsing UnaryExpression".to_string(),
                         )
                     }
                 }
@@ -12181,40 +14769,42 @@ impl std::str::FromStr for TimerResult {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(TimerResult {
-            time_unit: intermediate_rep
-                .time_unit
+        std::result::Result::Ok(UnaryExpression {
+            r#type: intermediate_rep
+                .r#type
                 .into_iter()
                 .next()
-                .ok_or_else(|| "time-unit missing in TimerResult".to_string())?,
-            count: intermediate_rep
-                .count
+                .ok_or_else(|| "type missing in UnaryExpression".to_string())?,
+            term: intermediate_rep
+                .term
                 .into_iter()
                 .next()
-                .ok_or_else(|| "count missing in TimerResult".to_string())?,
-            total_duration: intermediate_rep
-                .total_duration
+                .ok_or_else(|| "term missing in UnaryExpression".to_string())?,
+            value: intermediate_rep
+                .value
                 .into_iter()
                 .next()
-                .ok_or_else(|| "total-duration missing in TimerResult".to_string())?,
+                .ok_or_else(|| "value missing in UnaryExpression".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<TimerResult> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<UnaryExpression> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<TimerResult>> for hyper::header::HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<UnaryExpression>>
+    for hyper::header::HeaderValue
+{
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<TimerResult>,
+        hdr_value: header::IntoHeaderValue<UnaryExpression>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for TimerResult - value: {} is invalid {}",
+                "Invalid header value for UnaryExpression - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -12222,18 +14812,20 @@ impl std::convert::TryFrom<header::IntoHeaderValue<TimerResult>> for hyper::head
 }
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<TimerResult> {
+impl std::convert::TryFrom<hyper::header::HeaderValue>
+    for header::IntoHeaderValue<UnaryExpression>
+{
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <TimerResult as std::str::FromStr>::from_str(value) {
+                match <UnaryExpression as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into TimerResult - {}",
+                        "Unable to convert header value '{}' into UnaryExpression - {}",
                         value, err
                     )),
                 }
@@ -12246,155 +14838,56 @@ impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderVal
     }
 }
 
-/// Token type identifier, from RFC 8693 Section 3  See https://datatracker.ietf.org/doc/html/rfc8693#section-3
-/// Enumeration of values.
-/// Since this enum's variants do not hold data, we can easily define them them as `#[repr(C)]`
-/// which helps with FFI.
-#[allow(non_camel_case_types)]
-#[repr(C)]
-#[derive(
-    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
-)]
-#[cfg_attr(feature = "conversion", derive(frunk_enum_derive::LabelledGenericEnum))]
-pub enum TokenType {
-    #[serde(rename = "urn:ietf:params:oauth:token-type:access_token")]
-    AccessToken,
-    #[serde(rename = "urn:ietf:params:oauth:token-type:refresh_token")]
-    RefreshToken,
-    #[serde(rename = "urn:ietf:params:oauth:token-type:id_token")]
-    IdToken,
-    #[serde(rename = "urn:ietf:params:oauth:token-type:saml1")]
-    Saml1,
-    #[serde(rename = "urn:ietf:params:oauth:token-type:saml2")]
-    Saml2,
-    #[serde(rename = "urn:ietf:params:oauth:token-type:jwt")]
-    Jwt,
-}
-
-impl std::fmt::Display for TokenType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match *self {
-            TokenType::AccessToken => write!(f, "urn:ietf:params:oauth:token-type:access_token"),
-            TokenType::RefreshToken => write!(f, "urn:ietf:params:oauth:token-type:refresh_token"),
-            TokenType::IdToken => write!(f, "urn:ietf:params:oauth:token-type:id_token"),
-            TokenType::Saml1 => write!(f, "urn:ietf:params:oauth:token-type:saml1"),
-            TokenType::Saml2 => write!(f, "urn:ietf:params:oauth:token-type:saml2"),
-            TokenType::Jwt => write!(f, "urn:ietf:params:oauth:token-type:jwt"),
-        }
-    }
-}
-
-impl std::str::FromStr for TokenType {
-    type Err = String;
-
-    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
-        match s {
-            "urn:ietf:params:oauth:token-type:access_token" => {
-                std::result::Result::Ok(TokenType::AccessToken)
-            }
-            "urn:ietf:params:oauth:token-type:refresh_token" => {
-                std::result::Result::Ok(TokenType::RefreshToken)
-            }
-            "urn:ietf:params:oauth:token-type:id_token" => {
-                std::result::Result::Ok(TokenType::IdToken)
-            }
-            "urn:ietf:params:oauth:token-type:saml1" => std::result::Result::Ok(TokenType::Saml1),
-            "urn:ietf:params:oauth:token-type:saml2" => std::result::Result::Ok(TokenType::Saml2),
-            "urn:ietf:params:oauth:token-type:jwt" => std::result::Result::Ok(TokenType::Jwt),
-            _ => std::result::Result::Err(format!("Value not valid: {}", s)),
-        }
-    }
-}
-
-#[derive(Debug, Clone, PartialEq, PartialOrd, serde::Serialize, serde::Deserialize)]
-#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct Transform(String);
-
-impl std::convert::From<String> for Transform {
-    fn from(x: String) -> Self {
-        Transform(x)
-    }
-}
-
-impl std::string::ToString for Transform {
-    fn to_string(&self) -> String {
-        self.0.to_string()
-    }
-}
-
-impl std::str::FromStr for Transform {
-    type Err = std::string::ParseError;
-    fn from_str(x: &str) -> std::result::Result<Self, Self::Err> {
-        std::result::Result::Ok(Transform(x.to_string()))
-    }
-}
-
-impl std::convert::From<Transform> for String {
-    fn from(x: Transform) -> Self {
-        x.0
-    }
-}
-
-impl std::ops::Deref for Transform {
-    type Target = String;
-    fn deref(&self) -> &String {
-        &self.0
-    }
-}
-
-impl std::ops::DerefMut for Transform {
-    fn deref_mut(&mut self) -> &mut String {
-        &mut self.0
-    }
-}
-
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct TransformTerm {
-    // Note: inline enums are not fully supported by openapi-generator
-    #[serde(rename = "type")]
-    pub r#type: String,
+pub struct UpdateCatalogConfigRequest {
+    /// The prefix of the catalog, empty for the default catalog
+    #[serde(rename = "catalog")]
+    pub catalog: String,
 
-    #[serde(rename = "transform")]
-    pub transform: String,
+    /// Replaces the defaults of the catalog if present
+    #[serde(rename = "defaults")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub defaults: Option<std::collections::HashMap<String, String>>,
 
-    #[serde(rename = "term")]
-    pub term: String,
+    /// Replaces the overrides of the catalog if present
+    #[serde(rename = "overrides")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub overrides: Option<std::collections::HashMap<String, String>>,
 }
 
-impl TransformTerm {
+impl UpdateCatalogConfigRequest {
     #[allow(clippy::new_without_default)]
-    pub fn new(r#type: String, transform: String, term: String) -> TransformTerm {
-        TransformTerm {
-            r#type,
-            transform,
-            term,
+    pub fn new(catalog: String) -> UpdateCatalogConfigRequest {
+        UpdateCatalogConfigRequest {
+            catalog,
+            defaults: None,
+            overrides: None,
         }
     }
 }
-
-/// Converts the TransformTerm value to the Query Parameters representation (style=form, explode=false)
-/// specified in https://swagger.io/docs/specification/serialization/
-/// Should be implemented in a serde serializer
-impl std::string::ToString for TransformTerm {
-    fn to_string(&self) -> String {
-        let params: Vec<Option<String>> = vec![
-            Some("type".to_string()),
-            Some(self.r#type.to_string()),
-            Some("transform".to_string()),
-            Some(self.transform.to_string()),
-            Some("term".to_string()),
-            Some(self.term.to_string()),
+
+/// Converts the UpdateCatalogConfigRequest value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for UpdateCatalogConfigRequest {
+    fn to_string(&self) -> String {
+        let params: Vec<Option<String>> = vec![
+            Some("catalog".to_string()),
+            Some(self.catalog.to_string()),
+            // Skipping defaults in query parameter serialization
+
+            // Skipping overrides in query parameter serialization
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a TransformTerm value
+/// Converts Query Parameters representation (style=form, explode=false) to a UpdateCatalogConfigRequest value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for TransformTerm {
+impl std::str::FromStr for UpdateCatalogConfigRequest {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -12402,9 +14895,9 @@ impl std::str::FromStr for TransformTerm {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub r#type: Vec<String>,
-            pub transform: Vec<String>,
-            pub term: Vec<String>,
+            pub catalog: Vec<String>,
+            pub defaults: Vec<std::collections::HashMap<String, String>>,
+            pub overrides: Vec<std::collections::HashMap<String, String>>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -12418,7 +14911,7 @@ impl std::str::FromStr for TransformTerm {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing TransformTerm".to_string(),
+                        "Missing value while parsing UpdateCatalogConfigRequest".to_string(),
                     )
                 }
             };
@@ -12427,22 +14920,10 @@ impl std::str::FromStr for TransformTerm {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "type" => intermediate_rep.r#type.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "transform" => intermediate_rep.transform.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "term" => intermediate_rep.term.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    _ => {
-                        return std::result::Result::Err(
-                            "Unexpected key while parsing TransformTerm".to_string(),
-                        )
-                    }
+                    "catalog" => intermediate_rep.catalog.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "defaults" => return std::result::Result::Err("Parsing a container in this style is not supported in UpdateCatalogConfigRequest".to_string()),
+                    "overrides" => return std::result::Result::Err("Parsing a container in this style is not supported in UpdateCatalogConfigRequest".to_string()),
+                    _ => return std::result::Result::Err("Unexpected key while parsing UpdateCatalogConfigRequest".to_string())
                 }
             }
 
@@ -12451,40 +14932,34 @@ impl std::str::FromStr for TransformTerm {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(TransformTerm {
-            r#type: intermediate_rep
-                .r#type
-                .into_iter()
-                .next()
-                .ok_or_else(|| "type missing in TransformTerm".to_string())?,
-            transform: intermediate_rep
-                .transform
-                .into_iter()
-                .next()
-                .ok_or_else(|| "transform missing in TransformTerm".to_string())?,
-            term: intermediate_rep
-                .term
+        std::result::Result::Ok(UpdateCatalogConfigRequest {
+            catalog: intermediate_rep
+                .catalog
                 .into_iter()
                 .next()
-                .ok_or_else(|| "term missing in TransformTerm".to_string())?,
+                .ok_or_else(|| "catalog missing in UpdateCatalogConfigRequest".to_string())?,
+            defaults: intermediate_rep.defaults.into_iter().next(),
+            overrides: intermediate_rep.overrides.into_iter().next(),
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<TransformTerm> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<UpdateCatalogConfigRequest> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<TransformTerm>> for hyper::header::HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<UpdateCatalogConfigRequest>>
+    for hyper::header::HeaderValue
+{
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<TransformTerm>,
+        hdr_value: header::IntoHeaderValue<UpdateCatalogConfigRequest>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for TransformTerm - value: {} is invalid {}",
+                "Invalid header value for UpdateCatalogConfigRequest - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -12492,18 +14967,20 @@ impl std::convert::TryFrom<header::IntoHeaderValue<TransformTerm>> for hyper::he
 }
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<TransformTerm> {
+impl std::convert::TryFrom<hyper::header::HeaderValue>
+    for header::IntoHeaderValue<UpdateCatalogConfigRequest>
+{
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <TransformTerm as std::str::FromStr>::from_str(value) {
+                match <UpdateCatalogConfigRequest as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into TransformTerm - {}",
+                        "Unable to convert header value '{}' into UpdateCatalogConfigRequest - {}",
                         value, err
                     )),
                 }
@@ -12518,269 +14995,113 @@ impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderVal
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct Type {
-    // Note: inline enums are not fully supported by openapi-generator
-    #[serde(rename = "type")]
-    pub r#type: String,
-
-    #[serde(rename = "fields")]
-    pub fields: Vec<models::StructField>,
-
-    #[serde(rename = "element-id")]
-    pub element_id: isize,
-
-    #[serde(rename = "element")]
-    pub element: Box<Type>,
-
-    #[serde(rename = "element-required")]
-    pub element_required: bool,
-
-    #[serde(rename = "key-id")]
-    pub key_id: isize,
-
-    #[serde(rename = "key")]
-    pub key: Box<Type>,
-
-    #[serde(rename = "value-id")]
-    pub value_id: isize,
-
-    #[serde(rename = "value")]
-    pub value: Box<Type>,
+pub struct UpdateCatalogRequest {
+    /// Replaces the storage root if present
+    #[serde(rename = "storage-root")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub storage_root: Option<String>,
 
-    #[serde(rename = "value-required")]
-    pub value_required: bool,
+    /// Replaces the properties if present
+    #[serde(rename = "properties")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub properties: Option<std::collections::HashMap<String, String>>,
 }
 
-impl Type {
+impl UpdateCatalogRequest {
     #[allow(clippy::new_without_default)]
-    pub fn new(
-        r#type: String,
-        fields: Vec<models::StructField>,
-        element_id: isize,
-        element: models::Type,
-        element_required: bool,
-        key_id: isize,
-        key: models::Type,
-        value_id: isize,
-        value: models::Type,
-        value_required: bool,
-    ) -> Type {
-        Type {
-            r#type,
-            fields,
-            element_id,
-            element: Box::new(element),
-            element_required,
-            key_id,
-            key: Box::new(key),
-            value_id,
-            value: Box::new(value),
-            value_required,
+    pub fn new() -> UpdateCatalogRequest {
+        UpdateCatalogRequest {
+            storage_root: None,
+            properties: None,
         }
     }
 }
 
-/// Converts the Type value to the Query Parameters representation (style=form, explode=false)
+/// Converts the UpdateCatalogRequest value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for Type {
+impl std::string::ToString for UpdateCatalogRequest {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
-            Some("type".to_string()),
-            Some(self.r#type.to_string()),
-            // Skipping fields in query parameter serialization
-            Some("element-id".to_string()),
-            Some(self.element_id.to_string()),
-            // Skipping element in query parameter serialization
-            Some("element-required".to_string()),
-            Some(self.element_required.to_string()),
-            Some("key-id".to_string()),
-            Some(self.key_id.to_string()),
-            // Skipping key in query parameter serialization
-            Some("value-id".to_string()),
-            Some(self.value_id.to_string()),
-            // Skipping value in query parameter serialization
-            Some("value-required".to_string()),
-            Some(self.value_required.to_string()),
+            self.storage_root.as_ref().map(|storage_root| {
+                vec!["storage-root".to_string(), storage_root.to_string()].join(",")
+            }),
+            // Skipping properties in query parameter serialization
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a Type value
-/// as specified in https://swagger.io/docs/specification/serialization/
-/// Should be implemented in a serde deserializer
-impl std::str::FromStr for Type {
-    type Err = String;
-
-    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
-        /// An intermediate representation of the struct to use for parsing.
-        #[derive(Default)]
-        #[allow(dead_code)]
-        struct IntermediateRep {
-            pub r#type: Vec<String>,
-            pub fields: Vec<Vec<models::StructField>>,
-            pub element_id: Vec<isize>,
-            pub element: Vec<models::Type>,
-            pub element_required: Vec<bool>,
-            pub key_id: Vec<isize>,
-            pub key: Vec<models::Type>,
-            pub value_id: Vec<isize>,
-            pub value: Vec<models::Type>,
-            pub value_required: Vec<bool>,
-        }
-
-        let mut intermediate_rep = IntermediateRep::default();
-
-        // Parse into intermediate representation
-        let mut string_iter = s.split(',');
-        let mut key_result = string_iter.next();
-
-        while key_result.is_some() {
-            let val = match string_iter.next() {
-                Some(x) => x,
-                None => {
-                    return std::result::Result::Err("Missing value while parsing Type".to_string())
-                }
-            };
-
-            if let Some(key) = key_result {
-                #[allow(clippy::match_single_binding)]
-                match key {
-                    #[allow(clippy::redundant_clone)]
-                    "type" => intermediate_rep.r#type.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    "fields" => {
-                        return std::result::Result::Err(
-                            "Parsing a container in this style is not supported in Type"
-                                .to_string(),
-                        )
-                    }
-                    #[allow(clippy::redundant_clone)]
-                    "element-id" => intermediate_rep.element_id.push(
-                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "element" => intermediate_rep.element.push(
-                        <models::Type as std::str::FromStr>::from_str(val)
-                            .map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "element-required" => intermediate_rep.element_required.push(
-                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "key-id" => intermediate_rep.key_id.push(
-                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "key" => intermediate_rep.key.push(
-                        <models::Type as std::str::FromStr>::from_str(val)
-                            .map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "value-id" => intermediate_rep.value_id.push(
-                        <isize as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "value" => intermediate_rep.value.push(
-                        <models::Type as std::str::FromStr>::from_str(val)
-                            .map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "value-required" => intermediate_rep.value_required.push(
-                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    _ => {
-                        return std::result::Result::Err(
-                            "Unexpected key while parsing Type".to_string(),
-                        )
-                    }
-                }
-            }
-
-            // Get the next key
-            key_result = string_iter.next();
-        }
-
-        // Use the intermediate representation to return the struct
-        std::result::Result::Ok(Type {
-            r#type: intermediate_rep
-                .r#type
-                .into_iter()
-                .next()
-                .ok_or_else(|| "type missing in Type".to_string())?,
-            fields: intermediate_rep
-                .fields
-                .into_iter()
-                .next()
-                .ok_or_else(|| "fields missing in Type".to_string())?,
-            element_id: intermediate_rep
-                .element_id
-                .into_iter()
-                .next()
-                .ok_or_else(|| "element-id missing in Type".to_string())?,
-            element: Box::new(
-                intermediate_rep
-                    .element
-                    .into_iter()
-                    .next()
-                    .ok_or_else(|| "element missing in Type".to_string())?,
-            ),
-            element_required: intermediate_rep
-                .element_required
-                .into_iter()
-                .next()
-                .ok_or_else(|| "element-required missing in Type".to_string())?,
-            key_id: intermediate_rep
-                .key_id
-                .into_iter()
-                .next()
-                .ok_or_else(|| "key-id missing in Type".to_string())?,
-            key: Box::new(
-                intermediate_rep
-                    .key
-                    .into_iter()
-                    .next()
-                    .ok_or_else(|| "key missing in Type".to_string())?,
-            ),
-            value_id: intermediate_rep
-                .value_id
-                .into_iter()
-                .next()
-                .ok_or_else(|| "value-id missing in Type".to_string())?,
-            value: Box::new(
-                intermediate_rep
-                    .value
-                    .into_iter()
-                    .next()
-                    .ok_or_else(|| "value missing in Type".to_string())?,
-            ),
-            value_required: intermediate_rep
-                .value_required
-                .into_iter()
-                .next()
-                .ok_or_else(|| "value-required missing in Type".to_string())?,
+/// Converts Query Parameters representation (style=form, explode=false) to a UpdateCatalogRequest value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for UpdateCatalogRequest {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub storage_root: Vec<String>,
+            pub properties: Vec<std::collections::HashMap<String, String>>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing UpdateCatalogRequest".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "storage-root" => intermediate_rep.storage_root.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "properties" => return std::result::Result::Err("Parsing a container in this style is not supported in UpdateCatalogRequest".to_string()),
+                    _ => return std::result::Result::Err("Unexpected key while parsing UpdateCatalogRequest".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(UpdateCatalogRequest {
+            storage_root: intermediate_rep.storage_root.into_iter().next(),
+            properties: intermediate_rep.properties.into_iter().next(),
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<Type> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<UpdateCatalogRequest> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<Type>> for hyper::header::HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<UpdateCatalogRequest>>
+    for hyper::header::HeaderValue
+{
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<Type>,
+        hdr_value: header::IntoHeaderValue<UpdateCatalogRequest>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for Type - value: {} is invalid {}",
+                "Invalid header value for UpdateCatalogRequest - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -12788,20 +15109,24 @@ impl std::convert::TryFrom<header::IntoHeaderValue<Type>> for hyper::header::Hea
 }
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<Type> {
+impl std::convert::TryFrom<hyper::header::HeaderValue>
+    for header::IntoHeaderValue<UpdateCatalogRequest>
+{
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
-            std::result::Result::Ok(value) => match <Type as std::str::FromStr>::from_str(value) {
-                std::result::Result::Ok(value) => {
-                    std::result::Result::Ok(header::IntoHeaderValue(value))
+            std::result::Result::Ok(value) => {
+                match <UpdateCatalogRequest as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into UpdateCatalogRequest - {}",
+                        value, err
+                    )),
                 }
-                std::result::Result::Err(err) => std::result::Result::Err(format!(
-                    "Unable to convert header value '{}' into Type - {}",
-                    value, err
-                )),
-            },
+            }
             std::result::Result::Err(e) => std::result::Result::Err(format!(
                 "Unable to convert header: {:?} to string: {}",
                 hdr_value, e
@@ -12812,49 +15137,47 @@ impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderVal
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct UnaryExpression {
-    #[serde(rename = "type")]
-    pub r#type: String,
-
-    #[serde(rename = "term")]
-    pub term: models::Term,
+pub struct UpdateGrantsRequest {
+    /// Privileges to grant to the role
+    #[serde(rename = "grants")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub grants: Option<Vec<models::Grant>>,
 
-    #[serde(rename = "value")]
-    pub value: serde_json::Value,
+    /// Privileges to revoke from the role
+    #[serde(rename = "revokes")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revokes: Option<Vec<models::Grant>>,
 }
 
-impl UnaryExpression {
+impl UpdateGrantsRequest {
     #[allow(clippy::new_without_default)]
-    pub fn new(r#type: String, term: models::Term, value: serde_json::Value) -> UnaryExpression {
-        UnaryExpression {
-            r#type,
-            term,
-            value,
+    pub fn new() -> UpdateGrantsRequest {
+        UpdateGrantsRequest {
+            grants: None,
+            revokes: None,
         }
     }
 }
 
-/// Converts the UnaryExpression value to the Query Parameters representation (style=form, explode=false)
+/// Converts the UpdateGrantsRequest value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for UnaryExpression {
+impl std::string::ToString for UpdateGrantsRequest {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
-            Some("type".to_string()),
-            Some(self.r#type.to_string()),
-            // Skipping term in query parameter serialization
+            // Skipping grants in query parameter serialization
 
-            // Skipping value in query parameter serialization
+            // Skipping revokes in query parameter serialization
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a UnaryExpression value
+/// Converts Query Parameters representation (style=form, explode=false) to a UpdateGrantsRequest value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for UnaryExpression {
+impl std::str::FromStr for UpdateGrantsRequest {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -12862,9 +15185,8 @@ impl std::str::FromStr for UnaryExpression {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub r#type: Vec<String>,
-            pub term: Vec<models::Term>,
-            pub value: Vec<serde_json::Value>,
+            pub grants: Vec<Vec<models::Grant>>,
+            pub revokes: Vec<Vec<models::Grant>>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -12878,7 +15200,7 @@ impl std::str::FromStr for UnaryExpression {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing UnaryExpression".to_string(),
+                        "Missing value while parsing UpdateGrantsRequest".to_string(),
                     )
                 }
             };
@@ -12886,23 +15208,17 @@ impl std::str::FromStr for UnaryExpression {
             if let Some(key) = key_result {
                 #[allow(clippy::match_single_binding)]
                 match key {
-                    #[allow(clippy::redundant_clone)]
-                    "type" => intermediate_rep.r#type.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "term" => intermediate_rep.term.push(
-                        <models::Term as std::str::FromStr>::from_str(val)
-                            .map_err(|x| x.to_string())?,
+                    "grants" => return std::result::Result::Err(
+                        "Parsing a container in this style is not supported in UpdateGrantsRequest"
+                            .to_string(),
                     ),
-                    #[allow(clippy::redundant_clone)]
-                    "value" => intermediate_rep.value.push(
-                        <serde_json::Value as std::str::FromStr>::from_str(val)
-                            .map_err(|x| x.to_string())?,
+                    "revokes" => return std::result::Result::Err(
+                        "Parsing a container in this style is not supported in UpdateGrantsRequest"
+                            .to_string(),
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing UnaryExpression".to_string(),
+                            "Unexpected key while parsing UpdateGrantsRequest".to_string(),
                         )
                     }
                 }
@@ -12913,42 +15229,29 @@ impl std::str::FromStr for UnaryExpression {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(UnaryExpression {
-            r#type: intermediate_rep
-                .r#type
-                .into_iter()
-                .next()
-                .ok_or_else(|| "type missing in UnaryExpression".to_string())?,
-            term: intermediate_rep
-                .term
-                .into_iter()
-                .next()
-                .ok_or_else(|| "term missing in UnaryExpression".to_string())?,
-            value: intermediate_rep
-                .value
-                .into_iter()
-                .next()
-                .ok_or_else(|| "value missing in UnaryExpression".to_string())?,
+        std::result::Result::Ok(UpdateGrantsRequest {
+            grants: intermediate_rep.grants.into_iter().next(),
+            revokes: intermediate_rep.revokes.into_iter().next(),
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<UnaryExpression> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<UpdateGrantsRequest> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<UnaryExpression>>
+impl std::convert::TryFrom<header::IntoHeaderValue<UpdateGrantsRequest>>
     for hyper::header::HeaderValue
 {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<UnaryExpression>,
+        hdr_value: header::IntoHeaderValue<UpdateGrantsRequest>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for UnaryExpression - value: {} is invalid {}",
+                "Invalid header value for UpdateGrantsRequest - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -12957,19 +15260,19 @@ impl std::convert::TryFrom<header::IntoHeaderValue<UnaryExpression>>
 
 #[cfg(any(feature = "client", feature = "server"))]
 impl std::convert::TryFrom<hyper::header::HeaderValue>
-    for header::IntoHeaderValue<UnaryExpression>
+    for header::IntoHeaderValue<UpdateGrantsRequest>
 {
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <UnaryExpression as std::str::FromStr>::from_str(value) {
+                match <UpdateGrantsRequest as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into UnaryExpression - {}",
+                        "Unable to convert header value '{}' into UpdateGrantsRequest - {}",
                         value, err
                     )),
                 }
@@ -12991,7 +15294,7 @@ pub struct UpdateNamespacePropertiesRequest {
 
     #[serde(rename = "updates")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updates: Option<Vec<String>>,
+    pub updates: Option<std::collections::HashMap<String, String>>,
 }
 
 impl UpdateNamespacePropertiesRequest {
@@ -13021,17 +15324,7 @@ impl std::string::ToString for UpdateNamespacePropertiesRequest {
                 ]
                 .join(",")
             }),
-            self.updates.as_ref().map(|updates| {
-                vec![
-                    "updates".to_string(),
-                    updates
-                        .iter()
-                        .map(|x| x.to_string())
-                        .collect::<Vec<_>>()
-                        .join(","),
-                ]
-                .join(",")
-            }),
+            // Skipping updates in query parameter serialization
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
@@ -13050,7 +15343,7 @@ impl std::str::FromStr for UpdateNamespacePropertiesRequest {
         #[allow(dead_code)]
         struct IntermediateRep {
             pub removals: Vec<Vec<String>>,
-            pub updates: Vec<Vec<String>>,
+            pub updates: Vec<std::collections::HashMap<String, String>>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -13318,6 +15611,150 @@ impl std::convert::TryFrom<hyper::header::HeaderValue>
     }
 }
 
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct UpdateRoleRequest {
+    /// The subjects of the access tokens of the members of the role
+    #[serde(rename = "principals")]
+    pub principals: Vec<String>,
+}
+
+impl UpdateRoleRequest {
+    #[allow(clippy::new_without_default)]
+    pub fn new(principals: Vec<String>) -> UpdateRoleRequest {
+        UpdateRoleRequest { principals }
+    }
+}
+
+/// Converts the UpdateRoleRequest value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for UpdateRoleRequest {
+    fn to_string(&self) -> String {
+        let params: Vec<Option<String>> = vec![
+            Some("principals".to_string()),
+            Some(
+                self.principals
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect::<Vec<_>>()
+                    .join(","),
+            ),
+        ];
+
+        params.into_iter().flatten().collect::<Vec<_>>().join(",")
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a UpdateRoleRequest value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for UpdateRoleRequest {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub principals: Vec<Vec<String>>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing UpdateRoleRequest".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    "principals" => return std::result::Result::Err(
+                        "Parsing a container in this style is not supported in UpdateRoleRequest"
+                            .to_string(),
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing UpdateRoleRequest".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(UpdateRoleRequest {
+            principals: intermediate_rep
+                .principals
+                .into_iter()
+                .next()
+                .ok_or_else(|| "principals missing in UpdateRoleRequest".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<UpdateRoleRequest> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<UpdateRoleRequest>>
+    for hyper::header::HeaderValue
+{
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<UpdateRoleRequest>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for UpdateRoleRequest - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue>
+    for header::IntoHeaderValue<UpdateRoleRequest>
+{
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <UpdateRoleRequest as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into UpdateRoleRequest - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
 pub struct UpdateTable200Response {
@@ -0,0 +1,148 @@
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+use async_trait::async_trait;
+use log::warn;
+use tokio::fs::{self, File, OpenOptions};
+use tokio::io::AsyncWriteExt;
+use tokio::sync::Mutex;
+
+use super::{MetricsReporter, Report};
+
+/// Appends every report as a line of JSON to a file.
+///
+/// Before a line would make the file exceed `max_bytes`, the file is rotated: it
+/// is renamed to `<path>.1`, older files move up by one and the oldest of the
+/// `max_files` rotated files is deleted.
+pub struct JsonlReporter {
+    path: PathBuf,
+    max_bytes: u64,
+    max_files: usize,
+    /// The open file and its size
+    file: Mutex<Option<(File, u64)>>,
+}
+
+impl JsonlReporter {
+    pub fn new(path: impl Into<PathBuf>, max_bytes: u64, max_files: usize) -> Self {
+        JsonlReporter {
+            path: path.into(),
+            max_bytes,
+            max_files,
+            file: Mutex::new(None),
+        }
+    }
+
+    async fn append(&self, line: &[u8]) -> std::io::Result<()> {
+        let mut file = self.file.lock().await;
+        let size = match file.as_ref() {
+            Some((_, size)) => *size,
+            None => match fs::metadata(&self.path).await {
+                Ok(metadata) => metadata.len(),
+                Err(err) if err.kind() == ErrorKind::NotFound => 0,
+                Err(err) => return Err(err),
+            },
+        };
+        if size > 0 && size + line.len() as u64 > self.max_bytes {
+            *file = None;
+            self.rotate().await?;
+        }
+
+        if file.is_none() {
+            let opened = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&self.path)
+                .await?;
+            let size = opened.metadata().await?.len();
+            *file = Some((opened, size));
+        }
+        let (opened, size) = file.as_mut().expect("The file has been opened");
+        opened.write_all(line).await?;
+        opened.flush().await?;
+        *size += line.len() as u64;
+        Ok(())
+    }
+
+    async fn rotate(&self) -> std::io::Result<()> {
+        if self.max_files == 0 {
+            return fs::remove_file(&self.path).await;
+        }
+        for i in (1..self.max_files).rev() {
+            match fs::rename(rotated(&self.path, i), rotated(&self.path, i + 1)).await {
+                Err(err) if err.kind() != ErrorKind::NotFound => return Err(err),
+                _ => (),
+            }
+        }
+        fs::rename(&self.path, rotated(&self.path, 1)).await
+    }
+}
+
+/// Returns the path of the `i`th rotated file, the newest being the first.
+fn rotated(path: &Path, i: usize) -> PathBuf {
+    let mut rotated = path.as_os_str().to_owned();
+    rotated.push(format!(".{}", i));
+    rotated.into()
+}
+
+#[async_trait]
+impl MetricsReporter for JsonlReporter {
+    fn name(&self) -> &str {
+        "jsonl"
+    }
+
+    async fn report(&self, report: &Report) {
+        let mut line = match serde_json::to_vec(report) {
+            Ok(line) => line,
+            Err(err) => {
+                warn!("Failed to serialize a metrics report: {}", err);
+                return;
+            }
+        };
+        line.push(b'\n');
+        if let Err(err) = self.append(&line).await {
+            warn!(
+                "Failed to append a metrics report to {}: {}",
+                self.path.display(),
+                err
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::report;
+    use super::super::MetricsReporter;
+    use super::{rotated, JsonlReporter};
+
+    #[tokio::test]
+    async fn rotation() {
+        let dir = std::env::temp_dir().join(format!("jsonl-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("reports.jsonl");
+
+        let line = serde_json::to_vec(&report()).unwrap().len() as u64 + 1;
+        // Two reports fit into a file
+        let reporter = JsonlReporter::new(&path, 2 * line + 1, 2);
+        for _ in 0..7 {
+            reporter.report(&report()).await;
+        }
+
+        let lines = |path| {
+            std::fs::read_to_string(path)
+                .map(|content| content.lines().count())
+                .unwrap_or(0)
+        };
+        assert_eq!(lines(path.clone()), 1);
+        assert_eq!(lines(rotated(&path, 1)), 2);
+        assert_eq!(lines(rotated(&path, 2)), 2);
+        assert!(!rotated(&path, 3).exists());
+        let first = std::fs::read_to_string(rotated(&path, 1)).unwrap();
+        let report: serde_json::Value =
+            serde_json::from_str(first.lines().next().unwrap()).unwrap();
+        assert_eq!(report["table"], "events");
+        assert_eq!(report["report"]["report-type"], "scan-report");
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,24 @@
+use async_trait::async_trait;
+use log::{info, warn};
+
+use super::{MetricsReporter, Report};
+
+/// Target of the log lines, so that they can be filtered or routed separately.
+pub const TARGET: &str = "iceberg_catalog::metrics";
+
+/// Writes every report as a JSON log line.
+pub struct LogReporter;
+
+#[async_trait]
+impl MetricsReporter for LogReporter {
+    fn name(&self) -> &str {
+        "log"
+    }
+
+    async fn report(&self, report: &Report) {
+        match serde_json::to_string(report) {
+            Ok(line) => info!(target: TARGET, "{}", line),
+            Err(err) => warn!("Failed to serialize a metrics report: {}", err),
+        }
+    }
+}
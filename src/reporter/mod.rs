@@ -0,0 +1,249 @@
+//! Sinks for the metrics reports sent by engines.
+//!
+//! Besides being stored in the database, every report is handed to the sinks
+//! configured for the server through the [`MetricsReporter`] trait. Each sink has
+//! its own queue and task, so a slow sink neither delays the request that sent the
+//! report nor the other sinks. Reports are dropped if the queue of a sink is full.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde::Serialize;
+use tokio::sync::mpsc;
+
+use iceberg_catalog_rest_rdbms_server::models;
+
+mod jsonl;
+mod logging;
+mod prometheus;
+
+pub use self::jsonl::JsonlReporter;
+pub use self::logging::LogReporter;
+pub use self::prometheus::PrometheusReporter;
+
+/// Number of reports a sink can lag behind before reports are dropped.
+const QUEUE_CAPACITY: usize = 1024;
+
+/// A metrics report together with the table it was sent for.
+#[derive(Clone, Debug, Serialize)]
+pub struct Report {
+    pub catalog: String,
+    pub namespace: Vec<String>,
+    pub table: String,
+    #[serde(rename = "received-at-ms")]
+    pub received_at_ms: i64,
+    pub report: models::ReportMetricsRequest,
+}
+
+/// Receives the metrics reports sent to the catalog.
+#[async_trait]
+pub trait MetricsReporter: Send + Sync {
+    /// Name of the sink used in log messages.
+    fn name(&self) -> &str;
+
+    /// Handles a report. Failures are logged by the implementation.
+    async fn report(&self, report: &Report);
+}
+
+/// Hands reports to the configured sinks in the background.
+#[derive(Clone, Default)]
+pub struct Reporters {
+    queues: Vec<(Arc<str>, mpsc::Sender<Arc<Report>>)>,
+}
+
+impl Reporters {
+    /// Starts a task for every sink that handles the reports queued for it.
+    pub fn new(reporters: Vec<Arc<dyn MetricsReporter>>) -> Self {
+        let queues = reporters
+            .into_iter()
+            .map(|reporter| {
+                let (sender, mut receiver) = mpsc::channel::<Arc<Report>>(QUEUE_CAPACITY);
+                let name: Arc<str> = reporter.name().into();
+                tokio::spawn(async move {
+                    while let Some(report) = receiver.recv().await {
+                        reporter.report(&report).await;
+                    }
+                });
+                (name, sender)
+            })
+            .collect();
+        Reporters { queues }
+    }
+
+    /// Queues the report for every sink without waiting for them.
+    pub fn report(&self, report: Report) {
+        let report = Arc::new(report);
+        for (name, queue) in &self.queues {
+            if queue.try_send(report.clone()).is_err() {
+                log::warn!(
+                    "Dropped a metrics report for {}.{} because the {} sink is falling behind.",
+                    report.namespace.join("."),
+                    report.table,
+                    name
+                );
+            }
+        }
+    }
+}
+
+/// A counter or timer of a report. The total duration of timers is in nanoseconds.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Measurement {
+    pub name: String,
+    pub value: Option<i64>,
+    pub count: Option<i64>,
+    pub total_duration: Option<i64>,
+}
+
+/// Returns the counters and the timers of known time units among the metrics of a
+/// report.
+pub fn measurements(metrics: &HashMap<String, models::MetricResult>) -> Vec<Measurement> {
+    metrics
+        .iter()
+        .filter_map(|(name, result)| {
+            if let Some(value) = result.value {
+                return Some(Measurement {
+                    name: name.clone(),
+                    value: Some(value),
+                    count: None,
+                    total_duration: None,
+                });
+            }
+            let nanos = result.time_unit.as_deref().and_then(nanoseconds)?;
+            Some(Measurement {
+                name: name.clone(),
+                value: None,
+                count: Some(result.count?),
+                total_duration: Some(result.total_duration?.saturating_mul(nanos)),
+            })
+        })
+        .collect()
+}
+
+/// Returns the number of nanoseconds of a time unit.
+pub fn nanoseconds(time_unit: &str) -> Option<i64> {
+    match time_unit {
+        "nanoseconds" => Some(1),
+        "microseconds" => Some(1_000),
+        "milliseconds" => Some(1_000_000),
+        "seconds" => Some(1_000_000_000),
+        "minutes" => Some(60_000_000_000),
+        "hours" => Some(3_600_000_000_000),
+        "days" => Some(86_400_000_000_000),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use async_trait::async_trait;
+    use tokio::sync::mpsc;
+
+    use iceberg_catalog_rest_rdbms_server::models;
+
+    use super::{Measurement, MetricsReporter, Report, Reporters};
+
+    pub fn report() -> Report {
+        let mut report = models::ReportMetricsRequest::new(
+            "scan-report".into(),
+            "db.events".into(),
+            1,
+            HashMap::from([
+                (
+                    "result-data-files".into(),
+                    models::MetricResult {
+                        unit: Some("count".into()),
+                        value: Some(3),
+                        time_unit: None,
+                        count: None,
+                        total_duration: None,
+                    },
+                ),
+                (
+                    "total-planning-duration".into(),
+                    models::MetricResult {
+                        unit: None,
+                        value: None,
+                        time_unit: Some("milliseconds".into()),
+                        count: Some(1),
+                        total_duration: Some(250),
+                    },
+                ),
+            ]),
+        );
+        report.filter = Some(serde_json::json!(true));
+        Report {
+            catalog: "my_catalog".into(),
+            namespace: vec!["db".into()],
+            table: "events".into(),
+            received_at_ms: 0,
+            report,
+        }
+    }
+
+    #[test]
+    fn measurements() {
+        let mut metrics = report().report.metrics;
+        metrics.insert(
+            "unknown-duration".into(),
+            models::MetricResult {
+                unit: None,
+                value: None,
+                time_unit: Some("fortnights".into()),
+                count: Some(1),
+                total_duration: Some(1),
+            },
+        );
+
+        let mut measurements = super::measurements(&metrics);
+        measurements.sort_by(|a, b| a.name.cmp(&b.name));
+        assert_eq!(
+            measurements,
+            vec![
+                Measurement {
+                    name: "result-data-files".into(),
+                    value: Some(3),
+                    count: None,
+                    total_duration: None,
+                },
+                Measurement {
+                    name: "total-planning-duration".into(),
+                    value: None,
+                    count: Some(1),
+                    total_duration: Some(250_000_000),
+                },
+            ]
+        );
+    }
+
+    struct SlowReporter(mpsc::UnboundedSender<String>);
+
+    #[async_trait]
+    impl MetricsReporter for SlowReporter {
+        fn name(&self) -> &str {
+            "slow"
+        }
+
+        async fn report(&self, report: &Report) {
+            tokio::time::sleep(Duration::from_millis(200)).await;
+            self.0.send(report.table.clone()).unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn reports_in_background() {
+        let (sender, mut receiver) = mpsc::unbounded_channel();
+        let reporters = Reporters::new(vec![Arc::new(SlowReporter(sender))]);
+
+        let started = std::time::Instant::now();
+        reporters.report(report());
+        assert!(started.elapsed() < Duration::from_millis(100));
+
+        assert_eq!(receiver.recv().await.unwrap(), "events");
+    }
+}
@@ -0,0 +1,173 @@
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, Method, Request, Response, StatusCode};
+use log::{error, info};
+use prometheus::{
+    Encoder, HistogramOpts, HistogramVec, IntCounterVec, Opts, Registry, TextEncoder,
+};
+
+use super::{nanoseconds, MetricsReporter, Report};
+
+const LABELS: [&str; 4] = ["catalog", "namespace", "table", "report_type"];
+const METRIC_LABELS: [&str; 5] = ["catalog", "namespace", "table", "report_type", "metric"];
+
+/// Exposes the reports as Prometheus metrics labelled by catalog, namespace and table.
+///
+/// Counters of the reports are summed up, timers are observed by a histogram in
+/// seconds. The metrics are served in the text format at `/metrics`.
+pub struct PrometheusReporter {
+    registry: Registry,
+    reports: IntCounterVec,
+    counters: IntCounterVec,
+    durations: HistogramVec,
+}
+
+impl PrometheusReporter {
+    pub fn new() -> Result<Self, prometheus::Error> {
+        let registry = Registry::new();
+        let reports = IntCounterVec::new(
+            Opts::new(
+                "iceberg_metrics_reports_total",
+                "Number of metrics reports received",
+            ),
+            &LABELS,
+        )?;
+        let counters = IntCounterVec::new(
+            Opts::new(
+                "iceberg_report_counter_total",
+                "Sum of the counters of the metrics reports",
+            ),
+            &METRIC_LABELS,
+        )?;
+        let durations = HistogramVec::new(
+            HistogramOpts::new(
+                "iceberg_report_duration_seconds",
+                "Durations of the timers of the metrics reports",
+            ),
+            &METRIC_LABELS,
+        )?;
+        registry.register(Box::new(reports.clone()))?;
+        registry.register(Box::new(counters.clone()))?;
+        registry.register(Box::new(durations.clone()))?;
+
+        Ok(PrometheusReporter {
+            registry,
+            reports,
+            counters,
+            durations,
+        })
+    }
+
+    /// Returns the metrics in the Prometheus text format.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        if let Err(err) = TextEncoder::new().encode(&self.registry.gather(), &mut buffer) {
+            error!("Failed to encode the Prometheus metrics: {}", err);
+        }
+        buffer
+    }
+
+    /// Serves the metrics at `/metrics` on the address.
+    pub fn serve(self: Arc<Self>, addr: SocketAddr) {
+        let make_service = make_service_fn(move |_| {
+            let reporter = self.clone();
+            async move {
+                Ok::<_, Infallible>(service_fn(move |request: Request<Body>| {
+                    let reporter = reporter.clone();
+                    async move {
+                        let response = if request.method() == Method::GET
+                            && request.uri().path() == "/metrics"
+                        {
+                            Response::builder()
+                                .header("Content-Type", TextEncoder::new().format_type())
+                                .body(Body::from(reporter.encode()))
+                        } else {
+                            Response::builder()
+                                .status(StatusCode::NOT_FOUND)
+                                .body(Body::empty())
+                        };
+                        Ok::<_, hyper::http::Error>(
+                            response.expect("The response has a valid status and header"),
+                        )
+                    }
+                }))
+            }
+        });
+
+        info!("Serving Prometheus metrics at http://{}/metrics", addr);
+        tokio::spawn(async move {
+            if let Err(err) = hyper::Server::bind(&addr).serve(make_service).await {
+                error!("The Prometheus endpoint failed: {}", err);
+            }
+        });
+    }
+}
+
+#[async_trait]
+impl MetricsReporter for PrometheusReporter {
+    fn name(&self) -> &str {
+        "prometheus"
+    }
+
+    async fn report(&self, report: &Report) {
+        let namespace = report.namespace.join(".");
+        let report_type = report.report.report_type.as_str();
+        self.reports
+            .with_label_values(&[&report.catalog, &namespace, &report.table, report_type])
+            .inc();
+
+        for (name, result) in &report.report.metrics {
+            let labels = [
+                report.catalog.as_str(),
+                namespace.as_str(),
+                report.table.as_str(),
+                report_type,
+                name.as_str(),
+            ];
+            if let Some(value) = result.value {
+                if value > 0 {
+                    self.counters
+                        .with_label_values(&labels)
+                        .inc_by(value as u64);
+                }
+            } else if let (Some(total_duration), Some(nanos)) = (
+                result.total_duration,
+                result.time_unit.as_deref().and_then(nanoseconds),
+            ) {
+                self.durations
+                    .with_label_values(&labels)
+                    .observe(total_duration as f64 * nanos as f64 / 1e9);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::report;
+    use super::super::MetricsReporter;
+    use super::PrometheusReporter;
+
+    #[tokio::test]
+    async fn metrics() {
+        let reporter = PrometheusReporter::new().unwrap();
+        reporter.report(&report()).await;
+        reporter.report(&report()).await;
+
+        let text = String::from_utf8(reporter.encode()).unwrap();
+        // Labels are encoded in alphabetical order
+        assert!(text.contains(
+            r#"iceberg_metrics_reports_total{catalog="my_catalog",namespace="db",report_type="scan-report",table="events"} 2"#
+        ));
+        assert!(text.contains(
+            r#"iceberg_report_counter_total{catalog="my_catalog",metric="result-data-files",namespace="db",report_type="scan-report",table="events"} 6"#
+        ));
+        assert!(text.contains(
+            r#"iceberg_report_duration_seconds_sum{catalog="my_catalog",metric="total-planning-duration",namespace="db",report_type="scan-report",table="events"} 0.5"#
+        ));
+    }
+}
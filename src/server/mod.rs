@@ -22,10 +22,14 @@ pub use crate::context;
 type ServiceFuture = BoxFuture<'static, Result<Response<Body>, crate::ServiceError>>;
 
 use crate::{
-    Api, CreateNamespaceResponse, CreateTableResponse, DropNamespaceResponse, DropTableResponse,
-    GetConfigResponse, GetTokenResponse, ListNamespacesResponse, ListTablesResponse,
-    LoadNamespaceMetadataResponse, LoadTableResponse, RenameTableResponse, ReportMetricsResponse,
-    TableExistsResponse, UpdatePropertiesResponse, UpdateTableResponse,
+    Api, CreateCatalogResponse, CreateNamespaceResponse, CreateTableResponse, DropCatalogResponse,
+    DropNamespaceResponse, DropRoleResponse, DropTableResponse, GetCatalogConfigResponse,
+    GetConfigResponse, GetTokenResponse, ListCatalogsResponse, ListGrantsResponse,
+    ListNamespacesResponse, ListRolesResponse, ListTableCommitsResponse, ListTableMetricsResponse,
+    ListTablesResponse, LoadCatalogResponse, LoadNamespaceMetadataResponse, LoadTableResponse,
+    RenameTableResponse, ReportMetricsResponse, TableExistsResponse, UpdateCatalogConfigResponse,
+    UpdateCatalogResponse, UpdateGrantsResponse, UpdatePropertiesResponse, UpdateRoleResponse,
+    UpdateTableResponse,
 };
 
 mod paths {
@@ -33,35 +37,76 @@ mod paths {
 
     lazy_static! {
         pub static ref GLOBAL_REGEX_SET: regex::RegexSet = regex::RegexSet::new(vec![
+            r"^/v1/admin/catalogs$",
+            r"^/v1/admin/catalogs/(?P<catalog>[^/?#]*)$",
+            r"^/v1/admin/config$",
+            r"^/v1/admin/roles$",
+            r"^/v1/admin/roles/(?P<role>[^/?#]*)$",
+            r"^/v1/admin/roles/(?P<role>[^/?#]*)/grants$",
             r"^/v1/config$",
             r"^/v1/oauth/tokens$",
             r"^/v1/(?P<prefix>[^/?#]*)/namespaces$",
             r"^/v1/(?P<prefix>[^/?#]*)/namespaces/(?P<namespace>[^/?#]*)$",
+            r"^/v1/(?P<prefix>[^/?#]*)/namespaces/(?P<namespace>[^/?#]*)/metrics$",
             r"^/v1/(?P<prefix>[^/?#]*)/namespaces/(?P<namespace>[^/?#]*)/properties$",
             r"^/v1/(?P<prefix>[^/?#]*)/namespaces/(?P<namespace>[^/?#]*)/tables$",
             r"^/v1/(?P<prefix>[^/?#]*)/namespaces/(?P<namespace>[^/?#]*)/tables/(?P<table>[^/?#]*)$",
+            r"^/v1/(?P<prefix>[^/?#]*)/namespaces/(?P<namespace>[^/?#]*)/tables/(?P<table>[^/?#]*)/commits$",
             r"^/v1/(?P<prefix>[^/?#]*)/namespaces/(?P<namespace>[^/?#]*)/tables/(?P<table>[^/?#]*)/metrics$",
             r"^/v1/(?P<prefix>[^/?#]*)/tables/rename$"
         ])
         .expect("Unable to create global regex set");
     }
-    pub(crate) static ID_V1_CONFIG: usize = 0;
-    pub(crate) static ID_V1_OAUTH_TOKENS: usize = 1;
-    pub(crate) static ID_V1_PREFIX_NAMESPACES: usize = 2;
+    pub(crate) static ID_V1_ADMIN_CATALOGS: usize = 0;
+    pub(crate) static ID_V1_ADMIN_CATALOGS_CATALOG: usize = 1;
+    lazy_static! {
+        pub static ref REGEX_V1_ADMIN_CATALOGS_CATALOG: regex::Regex =
+            #[allow(clippy::invalid_regex)]
+            regex::Regex::new(r"^/v1/admin/catalogs/(?P<catalog>[^/?#]*)$")
+                .expect("Unable to create regex for V1_ADMIN_CATALOGS_CATALOG");
+    }
+    pub(crate) static ID_V1_ADMIN_CONFIG: usize = 2;
+    pub(crate) static ID_V1_ADMIN_ROLES: usize = 3;
+    pub(crate) static ID_V1_ADMIN_ROLES_ROLE: usize = 4;
+    lazy_static! {
+        pub static ref REGEX_V1_ADMIN_ROLES_ROLE: regex::Regex =
+            #[allow(clippy::invalid_regex)]
+            regex::Regex::new(r"^/v1/admin/roles/(?P<role>[^/?#]*)$")
+                .expect("Unable to create regex for V1_ADMIN_ROLES_ROLE");
+    }
+    pub(crate) static ID_V1_ADMIN_ROLES_ROLE_GRANTS: usize = 5;
+    lazy_static! {
+        pub static ref REGEX_V1_ADMIN_ROLES_ROLE_GRANTS: regex::Regex =
+            #[allow(clippy::invalid_regex)]
+            regex::Regex::new(r"^/v1/admin/roles/(?P<role>[^/?#]*)/grants$")
+                .expect("Unable to create regex for V1_ADMIN_ROLES_ROLE_GRANTS");
+    }
+    pub(crate) static ID_V1_CONFIG: usize = 6;
+    pub(crate) static ID_V1_OAUTH_TOKENS: usize = 7;
+    pub(crate) static ID_V1_PREFIX_NAMESPACES: usize = 8;
     lazy_static! {
         pub static ref REGEX_V1_PREFIX_NAMESPACES: regex::Regex =
             #[allow(clippy::invalid_regex)]
             regex::Regex::new(r"^/v1/(?P<prefix>[^/?#]*)/namespaces$")
                 .expect("Unable to create regex for V1_PREFIX_NAMESPACES");
     }
-    pub(crate) static ID_V1_PREFIX_NAMESPACES_NAMESPACE: usize = 3;
+    pub(crate) static ID_V1_PREFIX_NAMESPACES_NAMESPACE: usize = 9;
     lazy_static! {
         pub static ref REGEX_V1_PREFIX_NAMESPACES_NAMESPACE: regex::Regex =
             #[allow(clippy::invalid_regex)]
             regex::Regex::new(r"^/v1/(?P<prefix>[^/?#]*)/namespaces/(?P<namespace>[^/?#]*)$")
                 .expect("Unable to create regex for V1_PREFIX_NAMESPACES_NAMESPACE");
     }
-    pub(crate) static ID_V1_PREFIX_NAMESPACES_NAMESPACE_PROPERTIES: usize = 4;
+    pub(crate) static ID_V1_PREFIX_NAMESPACES_NAMESPACE_METRICS: usize = 10;
+    lazy_static! {
+        pub static ref REGEX_V1_PREFIX_NAMESPACES_NAMESPACE_METRICS: regex::Regex =
+            #[allow(clippy::invalid_regex)]
+            regex::Regex::new(
+                r"^/v1/(?P<prefix>[^/?#]*)/namespaces/(?P<namespace>[^/?#]*)/metrics$"
+            )
+            .expect("Unable to create regex for V1_PREFIX_NAMESPACES_NAMESPACE_METRICS");
+    }
+    pub(crate) static ID_V1_PREFIX_NAMESPACES_NAMESPACE_PROPERTIES: usize = 11;
     lazy_static! {
         pub static ref REGEX_V1_PREFIX_NAMESPACES_NAMESPACE_PROPERTIES: regex::Regex =
             #[allow(clippy::invalid_regex)]
@@ -70,7 +115,7 @@ mod paths {
             )
             .expect("Unable to create regex for V1_PREFIX_NAMESPACES_NAMESPACE_PROPERTIES");
     }
-    pub(crate) static ID_V1_PREFIX_NAMESPACES_NAMESPACE_TABLES: usize = 5;
+    pub(crate) static ID_V1_PREFIX_NAMESPACES_NAMESPACE_TABLES: usize = 12;
     lazy_static! {
         pub static ref REGEX_V1_PREFIX_NAMESPACES_NAMESPACE_TABLES: regex::Regex =
             #[allow(clippy::invalid_regex)]
@@ -79,21 +124,28 @@ mod paths {
             )
             .expect("Unable to create regex for V1_PREFIX_NAMESPACES_NAMESPACE_TABLES");
     }
-    pub(crate) static ID_V1_PREFIX_NAMESPACES_NAMESPACE_TABLES_TABLE: usize = 6;
+    pub(crate) static ID_V1_PREFIX_NAMESPACES_NAMESPACE_TABLES_TABLE: usize = 13;
     lazy_static! {
         pub static ref REGEX_V1_PREFIX_NAMESPACES_NAMESPACE_TABLES_TABLE: regex::Regex =
             #[allow(clippy::invalid_regex)]
             regex::Regex::new(r"^/v1/(?P<prefix>[^/?#]*)/namespaces/(?P<namespace>[^/?#]*)/tables/(?P<table>[^/?#]*)$")
                 .expect("Unable to create regex for V1_PREFIX_NAMESPACES_NAMESPACE_TABLES_TABLE");
     }
-    pub(crate) static ID_V1_PREFIX_NAMESPACES_NAMESPACE_TABLES_TABLE_METRICS: usize = 7;
+    pub(crate) static ID_V1_PREFIX_NAMESPACES_NAMESPACE_TABLES_TABLE_COMMITS: usize = 14;
+    lazy_static! {
+        pub static ref REGEX_V1_PREFIX_NAMESPACES_NAMESPACE_TABLES_TABLE_COMMITS: regex::Regex =
+            #[allow(clippy::invalid_regex)]
+            regex::Regex::new(r"^/v1/(?P<prefix>[^/?#]*)/namespaces/(?P<namespace>[^/?#]*)/tables/(?P<table>[^/?#]*)/commits$")
+                .expect("Unable to create regex for V1_PREFIX_NAMESPACES_NAMESPACE_TABLES_TABLE_COMMITS");
+    }
+    pub(crate) static ID_V1_PREFIX_NAMESPACES_NAMESPACE_TABLES_TABLE_METRICS: usize = 15;
     lazy_static! {
         pub static ref REGEX_V1_PREFIX_NAMESPACES_NAMESPACE_TABLES_TABLE_METRICS: regex::Regex =
             #[allow(clippy::invalid_regex)]
             regex::Regex::new(r"^/v1/(?P<prefix>[^/?#]*)/namespaces/(?P<namespace>[^/?#]*)/tables/(?P<table>[^/?#]*)/metrics$")
                 .expect("Unable to create regex for V1_PREFIX_NAMESPACES_NAMESPACE_TABLES_TABLE_METRICS");
     }
-    pub(crate) static ID_V1_PREFIX_TABLES_RENAME: usize = 8;
+    pub(crate) static ID_V1_PREFIX_TABLES_RENAME: usize = 16;
     lazy_static! {
         pub static ref REGEX_V1_PREFIX_TABLES_RENAME: regex::Regex =
             #[allow(clippy::invalid_regex)]
@@ -212,8 +264,8 @@ where
             let path = paths::GLOBAL_REGEX_SET.matches(uri.path());
 
             match method {
-                // CreateNamespace - POST /v1/{prefix}/namespaces
-                hyper::Method::POST if path.matched(paths::ID_V1_PREFIX_NAMESPACES) => {
+                // CreateCatalog - POST /v1/admin/catalogs
+                hyper::Method::POST if path.matched(paths::ID_V1_ADMIN_CATALOGS) => {
                     {
                         let authorization = match *(&context as &dyn Has<Option<Authorization>>)
                             .get()
@@ -250,29 +302,6 @@ where
                         }
                     }
 
-                    // Path parameters
-                    let path: &str = uri.path();
-                    let path_params =
-                    paths::REGEX_V1_PREFIX_NAMESPACES
-                    .captures(path)
-                    .unwrap_or_else(||
-                        panic!("Path {} matched RE V1_PREFIX_NAMESPACES in set but failed match against \"{}\"", path, paths::REGEX_V1_PREFIX_NAMESPACES.as_str())
-                    );
-
-                    let param_prefix = match percent_encoding::percent_decode(path_params["prefix"].as_bytes()).decode_utf8() {
-                    Ok(param_prefix) => match param_prefix.parse::<String>() {
-                        Ok(param_prefix) => param_prefix,
-                        Err(e) => return Ok(Response::builder()
-                                        .status(StatusCode::BAD_REQUEST)
-                                        .body(Body::from(format!("Couldn't parse path parameter prefix: {}", e)))
-                                        .expect("Unable to create Bad Request response for invalid path parameter")),
-                    },
-                    Err(_) => return Ok(Response::builder()
-                                        .status(StatusCode::BAD_REQUEST)
-                                        .body(Body::from(format!("Couldn't percent-decode path parameter as UTF-8: {}", &path_params["prefix"])))
-                                        .expect("Unable to create Bad Request response for invalid percent decode"))
-                };
-
                     // Body parameters (note that non-required body parameters will ignore garbage
                     // values, rather than causing a 400 response). Produce warning header and logs for
                     // any unused fields.
@@ -280,22 +309,31 @@ where
                     match result {
                             Ok(body) => {
                                 let mut unused_elements = Vec::new();
-                                let param_create_namespace_request: Option<models::CreateNamespaceRequest> = if !body.is_empty() {
+                                let param_create_catalog_request: Option<models::CreateCatalogRequest> = if !body.is_empty() {
                                     let deserializer = &mut serde_json::Deserializer::from_slice(&*body);
                                     match serde_ignored::deserialize(deserializer, |path| {
                                             warn!("Ignoring unknown field in body: {}", path);
                                             unused_elements.push(path.to_string());
                                     }) {
-                                        Ok(param_create_namespace_request) => param_create_namespace_request,
-                                        Err(_) => None,
+                                        Ok(param_create_catalog_request) => param_create_catalog_request,
+                                        Err(e) => return Ok(Response::builder()
+                                                        .status(StatusCode::BAD_REQUEST)
+                                                        .body(Body::from(format!("Couldn't parse body parameter CreateCatalogRequest - doesn't match schema: {}", e)))
+                                                        .expect("Unable to create Bad Request response for invalid body parameter CreateCatalogRequest due to schema")),
                                     }
                                 } else {
                                     None
                                 };
+                                let param_create_catalog_request = match param_create_catalog_request {
+                                    Some(param_create_catalog_request) => param_create_catalog_request,
+                                    None => return Ok(Response::builder()
+                                                        .status(StatusCode::BAD_REQUEST)
+                                                        .body(Body::from("Missing required body parameter CreateCatalogRequest"))
+                                                        .expect("Unable to create Bad Request response for missing body parameter CreateCatalogRequest")),
+                                };
 
-                                let result = api_impl.create_namespace(
-                                            param_prefix,
-                                            param_create_namespace_request,
+                                let result = api_impl.create_catalog(
+                                            param_create_catalog_request,
                                         &context
                                     ).await;
                                 let mut response = Response::new(Body::empty());
@@ -313,102 +351,91 @@ where
 
                                         match result {
                                             Ok(rsp) => match rsp {
-                                                CreateNamespaceResponse::RepresentsASuccessfulCallToCreateANamespace
+                                                CreateCatalogResponse::TheCatalog
                                                     (body)
                                                 => {
                                                     *response.status_mut() = StatusCode::from_u16(200).expect("Unable to turn 200 into a StatusCode");
                                                     response.headers_mut().insert(
                                                         CONTENT_TYPE,
                                                         HeaderValue::from_str("application/json")
-                                                            .expect("Unable to create Content-Type header for CREATE_NAMESPACE_REPRESENTS_A_SUCCESSFUL_CALL_TO_CREATE_A_NAMESPACE"));
+                                                            .expect("Unable to create Content-Type header for CREATE_CATALOG_THE_CATALOG"));
                                                     let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
                                                     *response.body_mut() = Body::from(body);
                                                 },
-                                                CreateNamespaceResponse::IndicatesABadRequestError
+                                                CreateCatalogResponse::IndicatesABadRequestError
                                                     (body)
                                                 => {
                                                     *response.status_mut() = StatusCode::from_u16(400).expect("Unable to turn 400 into a StatusCode");
                                                     response.headers_mut().insert(
                                                         CONTENT_TYPE,
                                                         HeaderValue::from_str("application/json")
-                                                            .expect("Unable to create Content-Type header for CREATE_NAMESPACE_INDICATES_A_BAD_REQUEST_ERROR"));
+                                                            .expect("Unable to create Content-Type header for CREATE_CATALOG_INDICATES_A_BAD_REQUEST_ERROR"));
                                                     let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
                                                     *response.body_mut() = Body::from(body);
                                                 },
-                                                CreateNamespaceResponse::Unauthorized
+                                                CreateCatalogResponse::Unauthorized
                                                     (body)
                                                 => {
                                                     *response.status_mut() = StatusCode::from_u16(401).expect("Unable to turn 401 into a StatusCode");
                                                     response.headers_mut().insert(
                                                         CONTENT_TYPE,
                                                         HeaderValue::from_str("application/json")
-                                                            .expect("Unable to create Content-Type header for CREATE_NAMESPACE_UNAUTHORIZED"));
+                                                            .expect("Unable to create Content-Type header for CREATE_CATALOG_UNAUTHORIZED"));
                                                     let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
                                                     *response.body_mut() = Body::from(body);
                                                 },
-                                                CreateNamespaceResponse::Forbidden
+                                                CreateCatalogResponse::Forbidden
                                                     (body)
                                                 => {
                                                     *response.status_mut() = StatusCode::from_u16(403).expect("Unable to turn 403 into a StatusCode");
                                                     response.headers_mut().insert(
                                                         CONTENT_TYPE,
                                                         HeaderValue::from_str("application/json")
-                                                            .expect("Unable to create Content-Type header for CREATE_NAMESPACE_FORBIDDEN"));
-                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
-                                                    *response.body_mut() = Body::from(body);
-                                                },
-                                                CreateNamespaceResponse::NotAcceptable
-                                                    (body)
-                                                => {
-                                                    *response.status_mut() = StatusCode::from_u16(406).expect("Unable to turn 406 into a StatusCode");
-                                                    response.headers_mut().insert(
-                                                        CONTENT_TYPE,
-                                                        HeaderValue::from_str("application/json")
-                                                            .expect("Unable to create Content-Type header for CREATE_NAMESPACE_NOT_ACCEPTABLE"));
+                                                            .expect("Unable to create Content-Type header for CREATE_CATALOG_FORBIDDEN"));
                                                     let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
                                                     *response.body_mut() = Body::from(body);
                                                 },
-                                                CreateNamespaceResponse::Conflict
+                                                CreateCatalogResponse::Conflict
                                                     (body)
                                                 => {
                                                     *response.status_mut() = StatusCode::from_u16(409).expect("Unable to turn 409 into a StatusCode");
                                                     response.headers_mut().insert(
                                                         CONTENT_TYPE,
                                                         HeaderValue::from_str("application/json")
-                                                            .expect("Unable to create Content-Type header for CREATE_NAMESPACE_CONFLICT"));
+                                                            .expect("Unable to create Content-Type header for CREATE_CATALOG_CONFLICT"));
                                                     let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
                                                     *response.body_mut() = Body::from(body);
                                                 },
-                                                CreateNamespaceResponse::CredentialsHaveTimedOut
+                                                CreateCatalogResponse::CredentialsHaveTimedOut
                                                     (body)
                                                 => {
                                                     *response.status_mut() = StatusCode::from_u16(419).expect("Unable to turn 419 into a StatusCode");
                                                     response.headers_mut().insert(
                                                         CONTENT_TYPE,
                                                         HeaderValue::from_str("application/json")
-                                                            .expect("Unable to create Content-Type header for CREATE_NAMESPACE_CREDENTIALS_HAVE_TIMED_OUT"));
+                                                            .expect("Unable to create Content-Type header for CREATE_CATALOG_CREDENTIALS_HAVE_TIMED_OUT"));
                                                     let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
                                                     *response.body_mut() = Body::from(body);
                                                 },
-                                                CreateNamespaceResponse::TheServiceIsNotReadyToHandleTheRequest
+                                                CreateCatalogResponse::TheServiceIsNotReadyToHandleTheRequest
                                                     (body)
                                                 => {
                                                     *response.status_mut() = StatusCode::from_u16(503).expect("Unable to turn 503 into a StatusCode");
                                                     response.headers_mut().insert(
                                                         CONTENT_TYPE,
                                                         HeaderValue::from_str("application/json")
-                                                            .expect("Unable to create Content-Type header for CREATE_NAMESPACE_THE_SERVICE_IS_NOT_READY_TO_HANDLE_THE_REQUEST"));
+                                                            .expect("Unable to create Content-Type header for CREATE_CATALOG_THE_SERVICE_IS_NOT_READY_TO_HANDLE_THE_REQUEST"));
                                                     let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
                                                     *response.body_mut() = Body::from(body);
                                                 },
-                                                CreateNamespaceResponse::AServer
+                                                CreateCatalogResponse::AServer
                                                     (body)
                                                 => {
                                                     *response.status_mut() = StatusCode::from_u16(500).expect("Unable to turn 5XX into a StatusCode");
                                                     response.headers_mut().insert(
                                                         CONTENT_TYPE,
                                                         HeaderValue::from_str("application/json")
-                                                            .expect("Unable to create Content-Type header for CREATE_NAMESPACE_A_SERVER"));
+                                                            .expect("Unable to create Content-Type header for CREATE_CATALOG_A_SERVER"));
                                                     let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
                                                     *response.body_mut() = Body::from(body);
                                                 },
@@ -425,15 +452,13 @@ where
                             },
                             Err(e) => Ok(Response::builder()
                                                 .status(StatusCode::BAD_REQUEST)
-                                                .body(Body::from(format!("Couldn't read body parameter CreateNamespaceRequest: {}", e)))
-                                                .expect("Unable to create Bad Request response due to unable to read body parameter CreateNamespaceRequest")),
+                                                .body(Body::from(format!("Couldn't read body parameter CreateCatalogRequest: {}", e)))
+                                                .expect("Unable to create Bad Request response due to unable to read body parameter CreateCatalogRequest")),
                         }
                 }
 
-                // CreateTable - POST /v1/{prefix}/namespaces/{namespace}/tables
-                hyper::Method::POST
-                    if path.matched(paths::ID_V1_PREFIX_NAMESPACES_NAMESPACE_TABLES) =>
-                {
+                // DropCatalog - DELETE /v1/admin/catalogs/{catalog}
+                hyper::Method::DELETE if path.matched(paths::ID_V1_ADMIN_CATALOGS_CATALOG) => {
                     {
                         let authorization = match *(&context as &dyn Has<Option<Authorization>>)
                             .get()
@@ -473,153 +498,2388 @@ where
                     // Path parameters
                     let path: &str = uri.path();
                     let path_params =
-                    paths::REGEX_V1_PREFIX_NAMESPACES_NAMESPACE_TABLES
+                    paths::REGEX_V1_ADMIN_CATALOGS_CATALOG
                     .captures(path)
                     .unwrap_or_else(||
-                        panic!("Path {} matched RE V1_PREFIX_NAMESPACES_NAMESPACE_TABLES in set but failed match against \"{}\"", path, paths::REGEX_V1_PREFIX_NAMESPACES_NAMESPACE_TABLES.as_str())
+                        panic!("Path {} matched RE V1_ADMIN_CATALOGS_CATALOG in set but failed match against \"{}\"", path, paths::REGEX_V1_ADMIN_CATALOGS_CATALOG.as_str())
                     );
 
-                    let param_prefix = match percent_encoding::percent_decode(path_params["prefix"].as_bytes()).decode_utf8() {
-                    Ok(param_prefix) => match param_prefix.parse::<String>() {
-                        Ok(param_prefix) => param_prefix,
+                    let param_catalog = match percent_encoding::percent_decode(path_params["catalog"].as_bytes()).decode_utf8() {
+                    Ok(param_catalog) => match param_catalog.parse::<String>() {
+                        Ok(param_catalog) => param_catalog,
                         Err(e) => return Ok(Response::builder()
                                         .status(StatusCode::BAD_REQUEST)
-                                        .body(Body::from(format!("Couldn't parse path parameter prefix: {}", e)))
+                                        .body(Body::from(format!("Couldn't parse path parameter catalog: {}", e)))
                                         .expect("Unable to create Bad Request response for invalid path parameter")),
                     },
                     Err(_) => return Ok(Response::builder()
                                         .status(StatusCode::BAD_REQUEST)
-                                        .body(Body::from(format!("Couldn't percent-decode path parameter as UTF-8: {}", &path_params["prefix"])))
+                                        .body(Body::from(format!("Couldn't percent-decode path parameter as UTF-8: {}", &path_params["catalog"])))
                                         .expect("Unable to create Bad Request response for invalid percent decode"))
                 };
 
-                    let param_namespace = match percent_encoding::percent_decode(path_params["namespace"].as_bytes()).decode_utf8() {
-                    Ok(param_namespace) => match param_namespace.parse::<String>() {
-                        Ok(param_namespace) => param_namespace,
-                        Err(e) => return Ok(Response::builder()
-                                        .status(StatusCode::BAD_REQUEST)
-                                        .body(Body::from(format!("Couldn't parse path parameter namespace: {}", e)))
-                                        .expect("Unable to create Bad Request response for invalid path parameter")),
-                    },
-                    Err(_) => return Ok(Response::builder()
-                                        .status(StatusCode::BAD_REQUEST)
-                                        .body(Body::from(format!("Couldn't percent-decode path parameter as UTF-8: {}", &path_params["namespace"])))
-                                        .expect("Unable to create Bad Request response for invalid percent decode"))
-                };
+                    let result = api_impl.drop_catalog(param_catalog, &context).await;
+                    let mut response = Response::new(Body::empty());
+                    response.headers_mut().insert(
+                        HeaderName::from_static("x-span-id"),
+                        HeaderValue::from_str(
+                            (&context as &dyn Has<XSpanIdString>)
+                                .get()
+                                .0
+                                .clone()
+                                .as_str(),
+                        )
+                        .expect("Unable to create X-Span-ID header value"),
+                    );
 
-                    // Body parameters (note that non-required body parameters will ignore garbage
-                    // values, rather than causing a 400 response). Produce warning header and logs for
-                    // any unused fields.
-                    let result = body.into_raw().await;
                     match result {
-                            Ok(body) => {
-                                let mut unused_elements = Vec::new();
-                                let param_create_table_request: Option<models::CreateTableRequest> = if !body.is_empty() {
-                                    let deserializer = &mut serde_json::Deserializer::from_slice(&*body);
-                                    match serde_ignored::deserialize(deserializer, |path| {
-                                            warn!("Ignoring unknown field in body: {}", path);
-                                            unused_elements.push(path.to_string());
-                                    }) {
-                                        Ok(param_create_table_request) => param_create_table_request,
-                                        Err(_) => None,
-                                    }
-                                } else {
-                                    None
-                                };
-
-                                let result = api_impl.create_table(
-                                            param_prefix,
-                                            param_namespace,
-                                            param_create_table_request,
-                                        &context
-                                    ).await;
-                                let mut response = Response::new(Body::empty());
+                        Ok(rsp) => match rsp {
+                            DropCatalogResponse::Success => {
+                                *response.status_mut() = StatusCode::from_u16(204)
+                                    .expect("Unable to turn 204 into a StatusCode");
+                            }
+                            DropCatalogResponse::IndicatesABadRequestError(body) => {
+                                *response.status_mut() = StatusCode::from_u16(400)
+                                    .expect("Unable to turn 400 into a StatusCode");
                                 response.headers_mut().insert(
-                                            HeaderName::from_static("x-span-id"),
-                                            HeaderValue::from_str((&context as &dyn Has<XSpanIdString>).get().0.clone().as_str())
-                                                .expect("Unable to create X-Span-ID header value"));
-
-                                        if !unused_elements.is_empty() {
-                                            response.headers_mut().insert(
-                                                HeaderName::from_static("warning"),
-                                                HeaderValue::from_str(format!("Ignoring unknown fields in body: {:?}", unused_elements).as_str())
-                                                    .expect("Unable to create Warning header value"));
-                                        }
-
-                                        match result {
-                                            Ok(rsp) => match rsp {
-                                                CreateTableResponse::TableMetadataResultAfterCreatingATable
-                                                    (body)
-                                                => {
-                                                    *response.status_mut() = StatusCode::from_u16(200).expect("Unable to turn 200 into a StatusCode");
-                                                    response.headers_mut().insert(
                                                         CONTENT_TYPE,
                                                         HeaderValue::from_str("application/json")
-                                                            .expect("Unable to create Content-Type header for CREATE_TABLE_TABLE_METADATA_RESULT_AFTER_CREATING_A_TABLE"));
-                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
-                                                    *response.body_mut() = Body::from(body);
-                                                },
-                                                CreateTableResponse::IndicatesABadRequestError
-                                                    (body)
-                                                => {
-                                                    *response.status_mut() = StatusCode::from_u16(400).expect("Unable to turn 400 into a StatusCode");
-                                                    response.headers_mut().insert(
+                                                            .expect("Unable to create Content-Type header for DROP_CATALOG_INDICATES_A_BAD_REQUEST_ERROR"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                            DropCatalogResponse::Unauthorized(body) => {
+                                *response.status_mut() = StatusCode::from_u16(401)
+                                    .expect("Unable to turn 401 into a StatusCode");
+                                response.headers_mut().insert(
                                                         CONTENT_TYPE,
                                                         HeaderValue::from_str("application/json")
-                                                            .expect("Unable to create Content-Type header for CREATE_TABLE_INDICATES_A_BAD_REQUEST_ERROR"));
-                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
-                                                    *response.body_mut() = Body::from(body);
-                                                },
-                                                CreateTableResponse::Unauthorized
-                                                    (body)
-                                                => {
-                                                    *response.status_mut() = StatusCode::from_u16(401).expect("Unable to turn 401 into a StatusCode");
-                                                    response.headers_mut().insert(
+                                                            .expect("Unable to create Content-Type header for DROP_CATALOG_UNAUTHORIZED"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                            DropCatalogResponse::Forbidden(body) => {
+                                *response.status_mut() = StatusCode::from_u16(403)
+                                    .expect("Unable to turn 403 into a StatusCode");
+                                response.headers_mut().insert(
                                                         CONTENT_TYPE,
                                                         HeaderValue::from_str("application/json")
-                                                            .expect("Unable to create Content-Type header for CREATE_TABLE_UNAUTHORIZED"));
-                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
-                                                    *response.body_mut() = Body::from(body);
-                                                },
-                                                CreateTableResponse::Forbidden
-                                                    (body)
-                                                => {
-                                                    *response.status_mut() = StatusCode::from_u16(403).expect("Unable to turn 403 into a StatusCode");
-                                                    response.headers_mut().insert(
+                                                            .expect("Unable to create Content-Type header for DROP_CATALOG_FORBIDDEN"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                            DropCatalogResponse::NotFound(body) => {
+                                *response.status_mut() = StatusCode::from_u16(404)
+                                    .expect("Unable to turn 404 into a StatusCode");
+                                response.headers_mut().insert(
                                                         CONTENT_TYPE,
                                                         HeaderValue::from_str("application/json")
-                                                            .expect("Unable to create Content-Type header for CREATE_TABLE_FORBIDDEN"));
-                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
-                                                    *response.body_mut() = Body::from(body);
-                                                },
-                                                CreateTableResponse::NotFound
-                                                    (body)
-                                                => {
-                                                    *response.status_mut() = StatusCode::from_u16(404).expect("Unable to turn 404 into a StatusCode");
-                                                    response.headers_mut().insert(
+                                                            .expect("Unable to create Content-Type header for DROP_CATALOG_NOT_FOUND"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                            DropCatalogResponse::Conflict(body) => {
+                                *response.status_mut() = StatusCode::from_u16(409)
+                                    .expect("Unable to turn 409 into a StatusCode");
+                                response.headers_mut().insert(
                                                         CONTENT_TYPE,
                                                         HeaderValue::from_str("application/json")
-                                                            .expect("Unable to create Content-Type header for CREATE_TABLE_NOT_FOUND"));
-                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
-                                                    *response.body_mut() = Body::from(body);
-                                                },
-                                                CreateTableResponse::Conflict
-                                                    (body)
-                                                => {
-                                                    *response.status_mut() = StatusCode::from_u16(409).expect("Unable to turn 409 into a StatusCode");
-                                                    response.headers_mut().insert(
+                                                            .expect("Unable to create Content-Type header for DROP_CATALOG_CONFLICT"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                            DropCatalogResponse::CredentialsHaveTimedOut(body) => {
+                                *response.status_mut() = StatusCode::from_u16(419)
+                                    .expect("Unable to turn 419 into a StatusCode");
+                                response.headers_mut().insert(
                                                         CONTENT_TYPE,
                                                         HeaderValue::from_str("application/json")
-                                                            .expect("Unable to create Content-Type header for CREATE_TABLE_CONFLICT"));
-                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
-                                                    *response.body_mut() = Body::from(body);
-                                                },
-                                                CreateTableResponse::CredentialsHaveTimedOut
-                                                    (body)
-                                                => {
-                                                    *response.status_mut() = StatusCode::from_u16(419).expect("Unable to turn 419 into a StatusCode");
-                                                    response.headers_mut().insert(
-                                                        CONTENT_TYPE,
+                                                            .expect("Unable to create Content-Type header for DROP_CATALOG_CREDENTIALS_HAVE_TIMED_OUT"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                            DropCatalogResponse::TheServiceIsNotReadyToHandleTheRequest(body) => {
+                                *response.status_mut() = StatusCode::from_u16(503)
+                                    .expect("Unable to turn 503 into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for DROP_CATALOG_THE_SERVICE_IS_NOT_READY_TO_HANDLE_THE_REQUEST"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                            DropCatalogResponse::AServer(body) => {
+                                *response.status_mut() = StatusCode::from_u16(500)
+                                    .expect("Unable to turn 5XX into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for DROP_CATALOG_A_SERVER"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                        },
+                        Err(_) => {
+                            // Application code returned an error. This should not happen, as the implementation should
+                            // return a valid response.
+                            *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+                            *response.body_mut() = Body::from("An internal error occurred");
+                        }
+                    }
+
+                    Ok(response)
+                }
+
+                // DropRole - DELETE /v1/admin/roles/{role}
+                hyper::Method::DELETE if path.matched(paths::ID_V1_ADMIN_ROLES_ROLE) => {
+                    {
+                        let authorization = match *(&context as &dyn Has<Option<Authorization>>)
+                            .get()
+                        {
+                            Some(ref authorization) => authorization,
+                            None => {
+                                return Ok(Response::builder()
+                                    .status(StatusCode::FORBIDDEN)
+                                    .body(Body::from("Unauthenticated"))
+                                    .expect("Unable to create Authentication Forbidden response"))
+                            }
+                        };
+
+                        // Authorization
+                        if let Scopes::Some(ref scopes) = authorization.scopes {
+                            let required_scopes: std::collections::BTreeSet<String> = vec![
+                                "catalog".to_string(), // Allows interacting with the Config and Catalog APIs
+                            ]
+                            .into_iter()
+                            .collect();
+
+                            if !required_scopes.is_subset(scopes) {
+                                let missing_scopes = required_scopes.difference(scopes);
+                                return Ok(Response::builder()
+                                    .status(StatusCode::FORBIDDEN)
+                                    .body(Body::from(missing_scopes.fold(
+                                        "Insufficient authorization, missing scopes".to_string(),
+                                        |s, scope| format!("{} {}", s, scope),
+                                    )))
+                                    .expect(
+                                        "Unable to create Authentication Insufficient response",
+                                    ));
+                            }
+                        }
+                    }
+
+                    // Path parameters
+                    let path: &str = uri.path();
+                    let path_params =
+                    paths::REGEX_V1_ADMIN_ROLES_ROLE
+                    .captures(path)
+                    .unwrap_or_else(||
+                        panic!("Path {} matched RE V1_ADMIN_ROLES_ROLE in set but failed match against \"{}\"", path, paths::REGEX_V1_ADMIN_ROLES_ROLE.as_str())
+                    );
+
+                    let param_role = match percent_encoding::percent_decode(path_params["role"].as_bytes()).decode_utf8() {
+                    Ok(param_role) => match param_role.parse::<String>() {
+                        Ok(param_role) => param_role,
+                        Err(e) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't parse path parameter role: {}", e)))
+                                        .expect("Unable to create Bad Request response for invalid path parameter")),
+                    },
+                    Err(_) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't percent-decode path parameter as UTF-8: {}", &path_params["role"])))
+                                        .expect("Unable to create Bad Request response for invalid percent decode"))
+                };
+
+                    let result = api_impl.drop_role(param_role, &context).await;
+                    let mut response = Response::new(Body::empty());
+                    response.headers_mut().insert(
+                        HeaderName::from_static("x-span-id"),
+                        HeaderValue::from_str(
+                            (&context as &dyn Has<XSpanIdString>)
+                                .get()
+                                .0
+                                .clone()
+                                .as_str(),
+                        )
+                        .expect("Unable to create X-Span-ID header value"),
+                    );
+
+                    match result {
+                        Ok(rsp) => match rsp {
+                            DropRoleResponse::Success => {
+                                *response.status_mut() = StatusCode::from_u16(204)
+                                    .expect("Unable to turn 204 into a StatusCode");
+                            }
+                            DropRoleResponse::IndicatesABadRequestError(body) => {
+                                *response.status_mut() = StatusCode::from_u16(400)
+                                    .expect("Unable to turn 400 into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for DROP_ROLE_INDICATES_A_BAD_REQUEST_ERROR"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                            DropRoleResponse::Unauthorized(body) => {
+                                *response.status_mut() = StatusCode::from_u16(401)
+                                    .expect("Unable to turn 401 into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for DROP_ROLE_UNAUTHORIZED"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                            DropRoleResponse::Forbidden(body) => {
+                                *response.status_mut() = StatusCode::from_u16(403)
+                                    .expect("Unable to turn 403 into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for DROP_ROLE_FORBIDDEN"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                            DropRoleResponse::NotFound(body) => {
+                                *response.status_mut() = StatusCode::from_u16(404)
+                                    .expect("Unable to turn 404 into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for DROP_ROLE_NOT_FOUND"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                            DropRoleResponse::CredentialsHaveTimedOut(body) => {
+                                *response.status_mut() = StatusCode::from_u16(419)
+                                    .expect("Unable to turn 419 into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for DROP_ROLE_CREDENTIALS_HAVE_TIMED_OUT"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                            DropRoleResponse::TheServiceIsNotReadyToHandleTheRequest(body) => {
+                                *response.status_mut() = StatusCode::from_u16(503)
+                                    .expect("Unable to turn 503 into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for DROP_ROLE_THE_SERVICE_IS_NOT_READY_TO_HANDLE_THE_REQUEST"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                            DropRoleResponse::AServer(body) => {
+                                *response.status_mut() = StatusCode::from_u16(500)
+                                    .expect("Unable to turn 5XX into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for DROP_ROLE_A_SERVER"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                        },
+                        Err(_) => {
+                            // Application code returned an error. This should not happen, as the implementation should
+                            // return a valid response.
+                            *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+                            *response.body_mut() = Body::from("An internal error occurred");
+                        }
+                    }
+
+                    Ok(response)
+                }
+
+                // GetCatalogConfig - GET /v1/admin/config
+                hyper::Method::GET if path.matched(paths::ID_V1_ADMIN_CONFIG) => {
+                    {
+                        let authorization = match *(&context as &dyn Has<Option<Authorization>>)
+                            .get()
+                        {
+                            Some(ref authorization) => authorization,
+                            None => {
+                                return Ok(Response::builder()
+                                    .status(StatusCode::FORBIDDEN)
+                                    .body(Body::from("Unauthenticated"))
+                                    .expect("Unable to create Authentication Forbidden response"))
+                            }
+                        };
+
+                        // Authorization
+                        if let Scopes::Some(ref scopes) = authorization.scopes {
+                            let required_scopes: std::collections::BTreeSet<String> = vec![
+                                "catalog".to_string(), // Allows interacting with the Config and Catalog APIs
+                            ]
+                            .into_iter()
+                            .collect();
+
+                            if !required_scopes.is_subset(scopes) {
+                                let missing_scopes = required_scopes.difference(scopes);
+                                return Ok(Response::builder()
+                                    .status(StatusCode::FORBIDDEN)
+                                    .body(Body::from(missing_scopes.fold(
+                                        "Insufficient authorization, missing scopes".to_string(),
+                                        |s, scope| format!("{} {}", s, scope),
+                                    )))
+                                    .expect(
+                                        "Unable to create Authentication Insufficient response",
+                                    ));
+                            }
+                        }
+                    }
+
+                    // Query parameters (note that non-required or collection query parameters will ignore garbage values, rather than causing a 400 response)
+                    let query_params =
+                        form_urlencoded::parse(uri.query().unwrap_or_default().as_bytes())
+                            .collect::<Vec<_>>();
+                    let param_catalog = query_params
+                        .iter()
+                        .filter(|e| e.0 == "catalog")
+                        .map(|e| e.1.to_owned())
+                        .next();
+                    let param_catalog = match param_catalog {
+                        Some(param_catalog) => {
+                            let param_catalog =
+                                <String as std::str::FromStr>::from_str(&param_catalog);
+                            match param_catalog {
+                            Ok(param_catalog) => Some(param_catalog),
+                            Err(e) => return Ok(Response::builder()
+                                .status(StatusCode::BAD_REQUEST)
+                                .body(Body::from(format!("Couldn't parse query parameter catalog - doesn't match schema: {}", e)))
+                                .expect("Unable to create Bad Request response for invalid query parameter catalog")),
+                        }
+                        }
+                        None => None,
+                    };
+
+                    let result = api_impl.get_catalog_config(param_catalog, &context).await;
+                    let mut response = Response::new(Body::empty());
+                    response.headers_mut().insert(
+                        HeaderName::from_static("x-span-id"),
+                        HeaderValue::from_str(
+                            (&context as &dyn Has<XSpanIdString>)
+                                .get()
+                                .0
+                                .clone()
+                                .as_str(),
+                        )
+                        .expect("Unable to create X-Span-ID header value"),
+                    );
+
+                    match result {
+                        Ok(rsp) => match rsp {
+                            GetCatalogConfigResponse::TheConfigurationOfTheCatalog(body) => {
+                                *response.status_mut() = StatusCode::from_u16(200)
+                                    .expect("Unable to turn 200 into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for GET_CATALOG_CONFIG_THE_CONFIGURATION_OF_THE_CATALOG"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                            GetCatalogConfigResponse::IndicatesABadRequestError(body) => {
+                                *response.status_mut() = StatusCode::from_u16(400)
+                                    .expect("Unable to turn 400 into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for GET_CATALOG_CONFIG_INDICATES_A_BAD_REQUEST_ERROR"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                            GetCatalogConfigResponse::Unauthorized(body) => {
+                                *response.status_mut() = StatusCode::from_u16(401)
+                                    .expect("Unable to turn 401 into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for GET_CATALOG_CONFIG_UNAUTHORIZED"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                            GetCatalogConfigResponse::Forbidden(body) => {
+                                *response.status_mut() = StatusCode::from_u16(403)
+                                    .expect("Unable to turn 403 into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for GET_CATALOG_CONFIG_FORBIDDEN"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                            GetCatalogConfigResponse::NotFound(body) => {
+                                *response.status_mut() = StatusCode::from_u16(404)
+                                    .expect("Unable to turn 404 into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for GET_CATALOG_CONFIG_NOT_FOUND"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                            GetCatalogConfigResponse::CredentialsHaveTimedOut(body) => {
+                                *response.status_mut() = StatusCode::from_u16(419)
+                                    .expect("Unable to turn 419 into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for GET_CATALOG_CONFIG_CREDENTIALS_HAVE_TIMED_OUT"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                            GetCatalogConfigResponse::TheServiceIsNotReadyToHandleTheRequest(
+                                body,
+                            ) => {
+                                *response.status_mut() = StatusCode::from_u16(503)
+                                    .expect("Unable to turn 503 into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for GET_CATALOG_CONFIG_THE_SERVICE_IS_NOT_READY_TO_HANDLE_THE_REQUEST"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                            GetCatalogConfigResponse::AServer(body) => {
+                                *response.status_mut() = StatusCode::from_u16(500)
+                                    .expect("Unable to turn 5XX into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for GET_CATALOG_CONFIG_A_SERVER"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                        },
+                        Err(_) => {
+                            // Application code returned an error. This should not happen, as the implementation should
+                            // return a valid response.
+                            *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+                            *response.body_mut() = Body::from("An internal error occurred");
+                        }
+                    }
+
+                    Ok(response)
+                }
+
+                // ListCatalogs - GET /v1/admin/catalogs
+                hyper::Method::GET if path.matched(paths::ID_V1_ADMIN_CATALOGS) => {
+                    {
+                        let authorization = match *(&context as &dyn Has<Option<Authorization>>)
+                            .get()
+                        {
+                            Some(ref authorization) => authorization,
+                            None => {
+                                return Ok(Response::builder()
+                                    .status(StatusCode::FORBIDDEN)
+                                    .body(Body::from("Unauthenticated"))
+                                    .expect("Unable to create Authentication Forbidden response"))
+                            }
+                        };
+
+                        // Authorization
+                        if let Scopes::Some(ref scopes) = authorization.scopes {
+                            let required_scopes: std::collections::BTreeSet<String> = vec![
+                                "catalog".to_string(), // Allows interacting with the Config and Catalog APIs
+                            ]
+                            .into_iter()
+                            .collect();
+
+                            if !required_scopes.is_subset(scopes) {
+                                let missing_scopes = required_scopes.difference(scopes);
+                                return Ok(Response::builder()
+                                    .status(StatusCode::FORBIDDEN)
+                                    .body(Body::from(missing_scopes.fold(
+                                        "Insufficient authorization, missing scopes".to_string(),
+                                        |s, scope| format!("{} {}", s, scope),
+                                    )))
+                                    .expect(
+                                        "Unable to create Authentication Insufficient response",
+                                    ));
+                            }
+                        }
+                    }
+
+                    let result = api_impl.list_catalogs(&context).await;
+                    let mut response = Response::new(Body::empty());
+                    response.headers_mut().insert(
+                        HeaderName::from_static("x-span-id"),
+                        HeaderValue::from_str(
+                            (&context as &dyn Has<XSpanIdString>)
+                                .get()
+                                .0
+                                .clone()
+                                .as_str(),
+                        )
+                        .expect("Unable to create X-Span-ID header value"),
+                    );
+
+                    match result {
+                        Ok(rsp) => match rsp {
+                            ListCatalogsResponse::TheCatalogs(body) => {
+                                *response.status_mut() = StatusCode::from_u16(200)
+                                    .expect("Unable to turn 200 into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for LIST_CATALOGS_THE_CATALOGS"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                            ListCatalogsResponse::IndicatesABadRequestError(body) => {
+                                *response.status_mut() = StatusCode::from_u16(400)
+                                    .expect("Unable to turn 400 into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for LIST_CATALOGS_INDICATES_A_BAD_REQUEST_ERROR"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                            ListCatalogsResponse::Unauthorized(body) => {
+                                *response.status_mut() = StatusCode::from_u16(401)
+                                    .expect("Unable to turn 401 into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for LIST_CATALOGS_UNAUTHORIZED"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                            ListCatalogsResponse::Forbidden(body) => {
+                                *response.status_mut() = StatusCode::from_u16(403)
+                                    .expect("Unable to turn 403 into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for LIST_CATALOGS_FORBIDDEN"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                            ListCatalogsResponse::CredentialsHaveTimedOut(body) => {
+                                *response.status_mut() = StatusCode::from_u16(419)
+                                    .expect("Unable to turn 419 into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for LIST_CATALOGS_CREDENTIALS_HAVE_TIMED_OUT"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                            ListCatalogsResponse::TheServiceIsNotReadyToHandleTheRequest(body) => {
+                                *response.status_mut() = StatusCode::from_u16(503)
+                                    .expect("Unable to turn 503 into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for LIST_CATALOGS_THE_SERVICE_IS_NOT_READY_TO_HANDLE_THE_REQUEST"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                            ListCatalogsResponse::AServer(body) => {
+                                *response.status_mut() = StatusCode::from_u16(500)
+                                    .expect("Unable to turn 5XX into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for LIST_CATALOGS_A_SERVER"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                        },
+                        Err(_) => {
+                            // Application code returned an error. This should not happen, as the implementation should
+                            // return a valid response.
+                            *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+                            *response.body_mut() = Body::from("An internal error occurred");
+                        }
+                    }
+
+                    Ok(response)
+                }
+
+                // ListGrants - GET /v1/admin/roles/{role}/grants
+                hyper::Method::GET if path.matched(paths::ID_V1_ADMIN_ROLES_ROLE_GRANTS) => {
+                    {
+                        let authorization = match *(&context as &dyn Has<Option<Authorization>>)
+                            .get()
+                        {
+                            Some(ref authorization) => authorization,
+                            None => {
+                                return Ok(Response::builder()
+                                    .status(StatusCode::FORBIDDEN)
+                                    .body(Body::from("Unauthenticated"))
+                                    .expect("Unable to create Authentication Forbidden response"))
+                            }
+                        };
+
+                        // Authorization
+                        if let Scopes::Some(ref scopes) = authorization.scopes {
+                            let required_scopes: std::collections::BTreeSet<String> = vec![
+                                "catalog".to_string(), // Allows interacting with the Config and Catalog APIs
+                            ]
+                            .into_iter()
+                            .collect();
+
+                            if !required_scopes.is_subset(scopes) {
+                                let missing_scopes = required_scopes.difference(scopes);
+                                return Ok(Response::builder()
+                                    .status(StatusCode::FORBIDDEN)
+                                    .body(Body::from(missing_scopes.fold(
+                                        "Insufficient authorization, missing scopes".to_string(),
+                                        |s, scope| format!("{} {}", s, scope),
+                                    )))
+                                    .expect(
+                                        "Unable to create Authentication Insufficient response",
+                                    ));
+                            }
+                        }
+                    }
+
+                    // Path parameters
+                    let path: &str = uri.path();
+                    let path_params =
+                    paths::REGEX_V1_ADMIN_ROLES_ROLE_GRANTS
+                    .captures(path)
+                    .unwrap_or_else(||
+                        panic!("Path {} matched RE V1_ADMIN_ROLES_ROLE_GRANTS in set but failed match against \"{}\"", path, paths::REGEX_V1_ADMIN_ROLES_ROLE_GRANTS.as_str())
+                    );
+
+                    let param_role = match percent_encoding::percent_decode(path_params["role"].as_bytes()).decode_utf8() {
+                    Ok(param_role) => match param_role.parse::<String>() {
+                        Ok(param_role) => param_role,
+                        Err(e) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't parse path parameter role: {}", e)))
+                                        .expect("Unable to create Bad Request response for invalid path parameter")),
+                    },
+                    Err(_) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't percent-decode path parameter as UTF-8: {}", &path_params["role"])))
+                                        .expect("Unable to create Bad Request response for invalid percent decode"))
+                };
+
+                    let result = api_impl.list_grants(param_role, &context).await;
+                    let mut response = Response::new(Body::empty());
+                    response.headers_mut().insert(
+                        HeaderName::from_static("x-span-id"),
+                        HeaderValue::from_str(
+                            (&context as &dyn Has<XSpanIdString>)
+                                .get()
+                                .0
+                                .clone()
+                                .as_str(),
+                        )
+                        .expect("Unable to create X-Span-ID header value"),
+                    );
+
+                    match result {
+                        Ok(rsp) => match rsp {
+                            ListGrantsResponse::TheGrantsOfTheRole(body) => {
+                                *response.status_mut() = StatusCode::from_u16(200)
+                                    .expect("Unable to turn 200 into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for LIST_GRANTS_THE_GRANTS_OF_THE_ROLE"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                            ListGrantsResponse::IndicatesABadRequestError(body) => {
+                                *response.status_mut() = StatusCode::from_u16(400)
+                                    .expect("Unable to turn 400 into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for LIST_GRANTS_INDICATES_A_BAD_REQUEST_ERROR"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                            ListGrantsResponse::Unauthorized(body) => {
+                                *response.status_mut() = StatusCode::from_u16(401)
+                                    .expect("Unable to turn 401 into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for LIST_GRANTS_UNAUTHORIZED"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                            ListGrantsResponse::Forbidden(body) => {
+                                *response.status_mut() = StatusCode::from_u16(403)
+                                    .expect("Unable to turn 403 into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for LIST_GRANTS_FORBIDDEN"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                            ListGrantsResponse::NotFound(body) => {
+                                *response.status_mut() = StatusCode::from_u16(404)
+                                    .expect("Unable to turn 404 into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for LIST_GRANTS_NOT_FOUND"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                            ListGrantsResponse::CredentialsHaveTimedOut(body) => {
+                                *response.status_mut() = StatusCode::from_u16(419)
+                                    .expect("Unable to turn 419 into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for LIST_GRANTS_CREDENTIALS_HAVE_TIMED_OUT"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                            ListGrantsResponse::TheServiceIsNotReadyToHandleTheRequest(body) => {
+                                *response.status_mut() = StatusCode::from_u16(503)
+                                    .expect("Unable to turn 503 into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for LIST_GRANTS_THE_SERVICE_IS_NOT_READY_TO_HANDLE_THE_REQUEST"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                            ListGrantsResponse::AServer(body) => {
+                                *response.status_mut() = StatusCode::from_u16(500)
+                                    .expect("Unable to turn 5XX into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for LIST_GRANTS_A_SERVER"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                        },
+                        Err(_) => {
+                            // Application code returned an error. This should not happen, as the implementation should
+                            // return a valid response.
+                            *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+                            *response.body_mut() = Body::from("An internal error occurred");
+                        }
+                    }
+
+                    Ok(response)
+                }
+
+                // ListRoles - GET /v1/admin/roles
+                hyper::Method::GET if path.matched(paths::ID_V1_ADMIN_ROLES) => {
+                    {
+                        let authorization = match *(&context as &dyn Has<Option<Authorization>>)
+                            .get()
+                        {
+                            Some(ref authorization) => authorization,
+                            None => {
+                                return Ok(Response::builder()
+                                    .status(StatusCode::FORBIDDEN)
+                                    .body(Body::from("Unauthenticated"))
+                                    .expect("Unable to create Authentication Forbidden response"))
+                            }
+                        };
+
+                        // Authorization
+                        if let Scopes::Some(ref scopes) = authorization.scopes {
+                            let required_scopes: std::collections::BTreeSet<String> = vec![
+                                "catalog".to_string(), // Allows interacting with the Config and Catalog APIs
+                            ]
+                            .into_iter()
+                            .collect();
+
+                            if !required_scopes.is_subset(scopes) {
+                                let missing_scopes = required_scopes.difference(scopes);
+                                return Ok(Response::builder()
+                                    .status(StatusCode::FORBIDDEN)
+                                    .body(Body::from(missing_scopes.fold(
+                                        "Insufficient authorization, missing scopes".to_string(),
+                                        |s, scope| format!("{} {}", s, scope),
+                                    )))
+                                    .expect(
+                                        "Unable to create Authentication Insufficient response",
+                                    ));
+                            }
+                        }
+                    }
+
+                    let result = api_impl.list_roles(&context).await;
+                    let mut response = Response::new(Body::empty());
+                    response.headers_mut().insert(
+                        HeaderName::from_static("x-span-id"),
+                        HeaderValue::from_str(
+                            (&context as &dyn Has<XSpanIdString>)
+                                .get()
+                                .0
+                                .clone()
+                                .as_str(),
+                        )
+                        .expect("Unable to create X-Span-ID header value"),
+                    );
+
+                    match result {
+                        Ok(rsp) => match rsp {
+                            ListRolesResponse::TheRolesAndTheirPrincipals(body) => {
+                                *response.status_mut() = StatusCode::from_u16(200)
+                                    .expect("Unable to turn 200 into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for LIST_ROLES_THE_ROLES_AND_THEIR_PRINCIPALS"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                            ListRolesResponse::IndicatesABadRequestError(body) => {
+                                *response.status_mut() = StatusCode::from_u16(400)
+                                    .expect("Unable to turn 400 into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for LIST_ROLES_INDICATES_A_BAD_REQUEST_ERROR"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                            ListRolesResponse::Unauthorized(body) => {
+                                *response.status_mut() = StatusCode::from_u16(401)
+                                    .expect("Unable to turn 401 into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for LIST_ROLES_UNAUTHORIZED"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                            ListRolesResponse::Forbidden(body) => {
+                                *response.status_mut() = StatusCode::from_u16(403)
+                                    .expect("Unable to turn 403 into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for LIST_ROLES_FORBIDDEN"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                            ListRolesResponse::CredentialsHaveTimedOut(body) => {
+                                *response.status_mut() = StatusCode::from_u16(419)
+                                    .expect("Unable to turn 419 into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for LIST_ROLES_CREDENTIALS_HAVE_TIMED_OUT"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                            ListRolesResponse::TheServiceIsNotReadyToHandleTheRequest(body) => {
+                                *response.status_mut() = StatusCode::from_u16(503)
+                                    .expect("Unable to turn 503 into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for LIST_ROLES_THE_SERVICE_IS_NOT_READY_TO_HANDLE_THE_REQUEST"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                            ListRolesResponse::AServer(body) => {
+                                *response.status_mut() = StatusCode::from_u16(500)
+                                    .expect("Unable to turn 5XX into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for LIST_ROLES_A_SERVER"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                        },
+                        Err(_) => {
+                            // Application code returned an error. This should not happen, as the implementation should
+                            // return a valid response.
+                            *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+                            *response.body_mut() = Body::from("An internal error occurred");
+                        }
+                    }
+
+                    Ok(response)
+                }
+
+                // LoadCatalog - GET /v1/admin/catalogs/{catalog}
+                hyper::Method::GET if path.matched(paths::ID_V1_ADMIN_CATALOGS_CATALOG) => {
+                    {
+                        let authorization = match *(&context as &dyn Has<Option<Authorization>>)
+                            .get()
+                        {
+                            Some(ref authorization) => authorization,
+                            None => {
+                                return Ok(Response::builder()
+                                    .status(StatusCode::FORBIDDEN)
+                                    .body(Body::from("Unauthenticated"))
+                                    .expect("Unable to create Authentication Forbidden response"))
+                            }
+                        };
+
+                        // Authorization
+                        if let Scopes::Some(ref scopes) = authorization.scopes {
+                            let required_scopes: std::collections::BTreeSet<String> = vec![
+                                "catalog".to_string(), // Allows interacting with the Config and Catalog APIs
+                            ]
+                            .into_iter()
+                            .collect();
+
+                            if !required_scopes.is_subset(scopes) {
+                                let missing_scopes = required_scopes.difference(scopes);
+                                return Ok(Response::builder()
+                                    .status(StatusCode::FORBIDDEN)
+                                    .body(Body::from(missing_scopes.fold(
+                                        "Insufficient authorization, missing scopes".to_string(),
+                                        |s, scope| format!("{} {}", s, scope),
+                                    )))
+                                    .expect(
+                                        "Unable to create Authentication Insufficient response",
+                                    ));
+                            }
+                        }
+                    }
+
+                    // Path parameters
+                    let path: &str = uri.path();
+                    let path_params =
+                    paths::REGEX_V1_ADMIN_CATALOGS_CATALOG
+                    .captures(path)
+                    .unwrap_or_else(||
+                        panic!("Path {} matched RE V1_ADMIN_CATALOGS_CATALOG in set but failed match against \"{}\"", path, paths::REGEX_V1_ADMIN_CATALOGS_CATALOG.as_str())
+                    );
+
+                    let param_catalog = match percent_encoding::percent_decode(path_params["catalog"].as_bytes()).decode_utf8() {
+                    Ok(param_catalog) => match param_catalog.parse::<String>() {
+                        Ok(param_catalog) => param_catalog,
+                        Err(e) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't parse path parameter catalog: {}", e)))
+                                        .expect("Unable to create Bad Request response for invalid path parameter")),
+                    },
+                    Err(_) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't percent-decode path parameter as UTF-8: {}", &path_params["catalog"])))
+                                        .expect("Unable to create Bad Request response for invalid percent decode"))
+                };
+
+                    let result = api_impl.load_catalog(param_catalog, &context).await;
+                    let mut response = Response::new(Body::empty());
+                    response.headers_mut().insert(
+                        HeaderName::from_static("x-span-id"),
+                        HeaderValue::from_str(
+                            (&context as &dyn Has<XSpanIdString>)
+                                .get()
+                                .0
+                                .clone()
+                                .as_str(),
+                        )
+                        .expect("Unable to create X-Span-ID header value"),
+                    );
+
+                    match result {
+                        Ok(rsp) => match rsp {
+                            LoadCatalogResponse::TheCatalog(body) => {
+                                *response.status_mut() = StatusCode::from_u16(200)
+                                    .expect("Unable to turn 200 into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for LOAD_CATALOG_THE_CATALOG"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                            LoadCatalogResponse::IndicatesABadRequestError(body) => {
+                                *response.status_mut() = StatusCode::from_u16(400)
+                                    .expect("Unable to turn 400 into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for LOAD_CATALOG_INDICATES_A_BAD_REQUEST_ERROR"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                            LoadCatalogResponse::Unauthorized(body) => {
+                                *response.status_mut() = StatusCode::from_u16(401)
+                                    .expect("Unable to turn 401 into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for LOAD_CATALOG_UNAUTHORIZED"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                            LoadCatalogResponse::Forbidden(body) => {
+                                *response.status_mut() = StatusCode::from_u16(403)
+                                    .expect("Unable to turn 403 into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for LOAD_CATALOG_FORBIDDEN"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                            LoadCatalogResponse::NotFound(body) => {
+                                *response.status_mut() = StatusCode::from_u16(404)
+                                    .expect("Unable to turn 404 into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for LOAD_CATALOG_NOT_FOUND"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                            LoadCatalogResponse::CredentialsHaveTimedOut(body) => {
+                                *response.status_mut() = StatusCode::from_u16(419)
+                                    .expect("Unable to turn 419 into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for LOAD_CATALOG_CREDENTIALS_HAVE_TIMED_OUT"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                            LoadCatalogResponse::TheServiceIsNotReadyToHandleTheRequest(body) => {
+                                *response.status_mut() = StatusCode::from_u16(503)
+                                    .expect("Unable to turn 503 into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for LOAD_CATALOG_THE_SERVICE_IS_NOT_READY_TO_HANDLE_THE_REQUEST"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                            LoadCatalogResponse::AServer(body) => {
+                                *response.status_mut() = StatusCode::from_u16(500)
+                                    .expect("Unable to turn 5XX into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for LOAD_CATALOG_A_SERVER"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                        },
+                        Err(_) => {
+                            // Application code returned an error. This should not happen, as the implementation should
+                            // return a valid response.
+                            *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+                            *response.body_mut() = Body::from("An internal error occurred");
+                        }
+                    }
+
+                    Ok(response)
+                }
+
+                // UpdateCatalog - POST /v1/admin/catalogs/{catalog}
+                hyper::Method::POST if path.matched(paths::ID_V1_ADMIN_CATALOGS_CATALOG) => {
+                    {
+                        let authorization = match *(&context as &dyn Has<Option<Authorization>>)
+                            .get()
+                        {
+                            Some(ref authorization) => authorization,
+                            None => {
+                                return Ok(Response::builder()
+                                    .status(StatusCode::FORBIDDEN)
+                                    .body(Body::from("Unauthenticated"))
+                                    .expect("Unable to create Authentication Forbidden response"))
+                            }
+                        };
+
+                        // Authorization
+                        if let Scopes::Some(ref scopes) = authorization.scopes {
+                            let required_scopes: std::collections::BTreeSet<String> = vec![
+                                "catalog".to_string(), // Allows interacting with the Config and Catalog APIs
+                            ]
+                            .into_iter()
+                            .collect();
+
+                            if !required_scopes.is_subset(scopes) {
+                                let missing_scopes = required_scopes.difference(scopes);
+                                return Ok(Response::builder()
+                                    .status(StatusCode::FORBIDDEN)
+                                    .body(Body::from(missing_scopes.fold(
+                                        "Insufficient authorization, missing scopes".to_string(),
+                                        |s, scope| format!("{} {}", s, scope),
+                                    )))
+                                    .expect(
+                                        "Unable to create Authentication Insufficient response",
+                                    ));
+                            }
+                        }
+                    }
+
+                    // Path parameters
+                    let path: &str = uri.path();
+                    let path_params =
+                    paths::REGEX_V1_ADMIN_CATALOGS_CATALOG
+                    .captures(path)
+                    .unwrap_or_else(||
+                        panic!("Path {} matched RE V1_ADMIN_CATALOGS_CATALOG in set but failed match against \"{}\"", path, paths::REGEX_V1_ADMIN_CATALOGS_CATALOG.as_str())
+                    );
+
+                    let param_catalog = match percent_encoding::percent_decode(path_params["catalog"].as_bytes()).decode_utf8() {
+                    Ok(param_catalog) => match param_catalog.parse::<String>() {
+                        Ok(param_catalog) => param_catalog,
+                        Err(e) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't parse path parameter catalog: {}", e)))
+                                        .expect("Unable to create Bad Request response for invalid path parameter")),
+                    },
+                    Err(_) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't percent-decode path parameter as UTF-8: {}", &path_params["catalog"])))
+                                        .expect("Unable to create Bad Request response for invalid percent decode"))
+                };
+
+                    // Body parameters (note that non-required body parameters will ignore garbage
+                    // values, rather than causing a 400 response). Produce warning header and logs for
+                    // any unused fields.
+                    let result = body.into_raw().await;
+                    match result {
+                            Ok(body) => {
+                                let mut unused_elements = Vec::new();
+                                let param_update_catalog_request: Option<models::UpdateCatalogRequest> = if !body.is_empty() {
+                                    let deserializer = &mut serde_json::Deserializer::from_slice(&*body);
+                                    match serde_ignored::deserialize(deserializer, |path| {
+                                            warn!("Ignoring unknown field in body: {}", path);
+                                            unused_elements.push(path.to_string());
+                                    }) {
+                                        Ok(param_update_catalog_request) => param_update_catalog_request,
+                                        Err(e) => return Ok(Response::builder()
+                                                        .status(StatusCode::BAD_REQUEST)
+                                                        .body(Body::from(format!("Couldn't parse body parameter UpdateCatalogRequest - doesn't match schema: {}", e)))
+                                                        .expect("Unable to create Bad Request response for invalid body parameter UpdateCatalogRequest due to schema")),
+                                    }
+                                } else {
+                                    None
+                                };
+                                let param_update_catalog_request = match param_update_catalog_request {
+                                    Some(param_update_catalog_request) => param_update_catalog_request,
+                                    None => return Ok(Response::builder()
+                                                        .status(StatusCode::BAD_REQUEST)
+                                                        .body(Body::from("Missing required body parameter UpdateCatalogRequest"))
+                                                        .expect("Unable to create Bad Request response for missing body parameter UpdateCatalogRequest")),
+                                };
+
+                                let result = api_impl.update_catalog(
+                                            param_catalog,
+                                            param_update_catalog_request,
+                                        &context
+                                    ).await;
+                                let mut response = Response::new(Body::empty());
+                                response.headers_mut().insert(
+                                            HeaderName::from_static("x-span-id"),
+                                            HeaderValue::from_str((&context as &dyn Has<XSpanIdString>).get().0.clone().as_str())
+                                                .expect("Unable to create X-Span-ID header value"));
+
+                                        if !unused_elements.is_empty() {
+                                            response.headers_mut().insert(
+                                                HeaderName::from_static("warning"),
+                                                HeaderValue::from_str(format!("Ignoring unknown fields in body: {:?}", unused_elements).as_str())
+                                                    .expect("Unable to create Warning header value"));
+                                        }
+
+                                        match result {
+                                            Ok(rsp) => match rsp {
+                                                UpdateCatalogResponse::TheCatalog
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(200).expect("Unable to turn 200 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for UPDATE_CATALOG_THE_CATALOG"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                UpdateCatalogResponse::IndicatesABadRequestError
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(400).expect("Unable to turn 400 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for UPDATE_CATALOG_INDICATES_A_BAD_REQUEST_ERROR"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                UpdateCatalogResponse::Unauthorized
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(401).expect("Unable to turn 401 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for UPDATE_CATALOG_UNAUTHORIZED"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                UpdateCatalogResponse::Forbidden
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(403).expect("Unable to turn 403 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for UPDATE_CATALOG_FORBIDDEN"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                UpdateCatalogResponse::NotFound
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(404).expect("Unable to turn 404 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for UPDATE_CATALOG_NOT_FOUND"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                UpdateCatalogResponse::CredentialsHaveTimedOut
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(419).expect("Unable to turn 419 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for UPDATE_CATALOG_CREDENTIALS_HAVE_TIMED_OUT"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                UpdateCatalogResponse::TheServiceIsNotReadyToHandleTheRequest
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(503).expect("Unable to turn 503 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for UPDATE_CATALOG_THE_SERVICE_IS_NOT_READY_TO_HANDLE_THE_REQUEST"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                UpdateCatalogResponse::AServer
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(500).expect("Unable to turn 5XX into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for UPDATE_CATALOG_A_SERVER"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                            },
+                                            Err(_) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+                                                *response.body_mut() = Body::from("An internal error occurred");
+                                            },
+                                        }
+
+                                        Ok(response)
+                            },
+                            Err(e) => Ok(Response::builder()
+                                                .status(StatusCode::BAD_REQUEST)
+                                                .body(Body::from(format!("Couldn't read body parameter UpdateCatalogRequest: {}", e)))
+                                                .expect("Unable to create Bad Request response due to unable to read body parameter UpdateCatalogRequest")),
+                        }
+                }
+
+                // UpdateCatalogConfig - POST /v1/admin/config
+                hyper::Method::POST if path.matched(paths::ID_V1_ADMIN_CONFIG) => {
+                    {
+                        let authorization = match *(&context as &dyn Has<Option<Authorization>>)
+                            .get()
+                        {
+                            Some(ref authorization) => authorization,
+                            None => {
+                                return Ok(Response::builder()
+                                    .status(StatusCode::FORBIDDEN)
+                                    .body(Body::from("Unauthenticated"))
+                                    .expect("Unable to create Authentication Forbidden response"))
+                            }
+                        };
+
+                        // Authorization
+                        if let Scopes::Some(ref scopes) = authorization.scopes {
+                            let required_scopes: std::collections::BTreeSet<String> = vec![
+                                "catalog".to_string(), // Allows interacting with the Config and Catalog APIs
+                            ]
+                            .into_iter()
+                            .collect();
+
+                            if !required_scopes.is_subset(scopes) {
+                                let missing_scopes = required_scopes.difference(scopes);
+                                return Ok(Response::builder()
+                                    .status(StatusCode::FORBIDDEN)
+                                    .body(Body::from(missing_scopes.fold(
+                                        "Insufficient authorization, missing scopes".to_string(),
+                                        |s, scope| format!("{} {}", s, scope),
+                                    )))
+                                    .expect(
+                                        "Unable to create Authentication Insufficient response",
+                                    ));
+                            }
+                        }
+                    }
+
+                    // Body parameters (note that non-required body parameters will ignore garbage
+                    // values, rather than causing a 400 response). Produce warning header and logs for
+                    // any unused fields.
+                    let result = body.into_raw().await;
+                    match result {
+                            Ok(body) => {
+                                let mut unused_elements = Vec::new();
+                                let param_update_catalog_config_request: Option<models::UpdateCatalogConfigRequest> = if !body.is_empty() {
+                                    let deserializer = &mut serde_json::Deserializer::from_slice(&*body);
+                                    match serde_ignored::deserialize(deserializer, |path| {
+                                            warn!("Ignoring unknown field in body: {}", path);
+                                            unused_elements.push(path.to_string());
+                                    }) {
+                                        Ok(param_update_catalog_config_request) => param_update_catalog_config_request,
+                                        Err(e) => return Ok(Response::builder()
+                                                        .status(StatusCode::BAD_REQUEST)
+                                                        .body(Body::from(format!("Couldn't parse body parameter UpdateCatalogConfigRequest - doesn't match schema: {}", e)))
+                                                        .expect("Unable to create Bad Request response for invalid body parameter UpdateCatalogConfigRequest due to schema")),
+                                    }
+                                } else {
+                                    None
+                                };
+                                let param_update_catalog_config_request = match param_update_catalog_config_request {
+                                    Some(param_update_catalog_config_request) => param_update_catalog_config_request,
+                                    None => return Ok(Response::builder()
+                                                        .status(StatusCode::BAD_REQUEST)
+                                                        .body(Body::from("Missing required body parameter UpdateCatalogConfigRequest"))
+                                                        .expect("Unable to create Bad Request response for missing body parameter UpdateCatalogConfigRequest")),
+                                };
+
+                                let result = api_impl.update_catalog_config(
+                                            param_update_catalog_config_request,
+                                        &context
+                                    ).await;
+                                let mut response = Response::new(Body::empty());
+                                response.headers_mut().insert(
+                                            HeaderName::from_static("x-span-id"),
+                                            HeaderValue::from_str((&context as &dyn Has<XSpanIdString>).get().0.clone().as_str())
+                                                .expect("Unable to create X-Span-ID header value"));
+
+                                        if !unused_elements.is_empty() {
+                                            response.headers_mut().insert(
+                                                HeaderName::from_static("warning"),
+                                                HeaderValue::from_str(format!("Ignoring unknown fields in body: {:?}", unused_elements).as_str())
+                                                    .expect("Unable to create Warning header value"));
+                                        }
+
+                                        match result {
+                                            Ok(rsp) => match rsp {
+                                                UpdateCatalogConfigResponse::TheConfigurationOfTheCatalog
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(200).expect("Unable to turn 200 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for UPDATE_CATALOG_CONFIG_THE_CONFIGURATION_OF_THE_CATALOG"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                UpdateCatalogConfigResponse::IndicatesABadRequestError
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(400).expect("Unable to turn 400 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for UPDATE_CATALOG_CONFIG_INDICATES_A_BAD_REQUEST_ERROR"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                UpdateCatalogConfigResponse::Unauthorized
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(401).expect("Unable to turn 401 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for UPDATE_CATALOG_CONFIG_UNAUTHORIZED"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                UpdateCatalogConfigResponse::Forbidden
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(403).expect("Unable to turn 403 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for UPDATE_CATALOG_CONFIG_FORBIDDEN"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                UpdateCatalogConfigResponse::NotFound
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(404).expect("Unable to turn 404 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for UPDATE_CATALOG_CONFIG_NOT_FOUND"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                UpdateCatalogConfigResponse::CredentialsHaveTimedOut
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(419).expect("Unable to turn 419 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for UPDATE_CATALOG_CONFIG_CREDENTIALS_HAVE_TIMED_OUT"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                UpdateCatalogConfigResponse::TheServiceIsNotReadyToHandleTheRequest
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(503).expect("Unable to turn 503 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for UPDATE_CATALOG_CONFIG_THE_SERVICE_IS_NOT_READY_TO_HANDLE_THE_REQUEST"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                UpdateCatalogConfigResponse::AServer
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(500).expect("Unable to turn 5XX into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for UPDATE_CATALOG_CONFIG_A_SERVER"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                            },
+                                            Err(_) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+                                                *response.body_mut() = Body::from("An internal error occurred");
+                                            },
+                                        }
+
+                                        Ok(response)
+                            },
+                            Err(e) => Ok(Response::builder()
+                                                .status(StatusCode::BAD_REQUEST)
+                                                .body(Body::from(format!("Couldn't read body parameter UpdateCatalogConfigRequest: {}", e)))
+                                                .expect("Unable to create Bad Request response due to unable to read body parameter UpdateCatalogConfigRequest")),
+                        }
+                }
+
+                // UpdateGrants - POST /v1/admin/roles/{role}/grants
+                hyper::Method::POST if path.matched(paths::ID_V1_ADMIN_ROLES_ROLE_GRANTS) => {
+                    {
+                        let authorization = match *(&context as &dyn Has<Option<Authorization>>)
+                            .get()
+                        {
+                            Some(ref authorization) => authorization,
+                            None => {
+                                return Ok(Response::builder()
+                                    .status(StatusCode::FORBIDDEN)
+                                    .body(Body::from("Unauthenticated"))
+                                    .expect("Unable to create Authentication Forbidden response"))
+                            }
+                        };
+
+                        // Authorization
+                        if let Scopes::Some(ref scopes) = authorization.scopes {
+                            let required_scopes: std::collections::BTreeSet<String> = vec![
+                                "catalog".to_string(), // Allows interacting with the Config and Catalog APIs
+                            ]
+                            .into_iter()
+                            .collect();
+
+                            if !required_scopes.is_subset(scopes) {
+                                let missing_scopes = required_scopes.difference(scopes);
+                                return Ok(Response::builder()
+                                    .status(StatusCode::FORBIDDEN)
+                                    .body(Body::from(missing_scopes.fold(
+                                        "Insufficient authorization, missing scopes".to_string(),
+                                        |s, scope| format!("{} {}", s, scope),
+                                    )))
+                                    .expect(
+                                        "Unable to create Authentication Insufficient response",
+                                    ));
+                            }
+                        }
+                    }
+
+                    // Path parameters
+                    let path: &str = uri.path();
+                    let path_params =
+                    paths::REGEX_V1_ADMIN_ROLES_ROLE_GRANTS
+                    .captures(path)
+                    .unwrap_or_else(||
+                        panic!("Path {} matched RE V1_ADMIN_ROLES_ROLE_GRANTS in set but failed match against \"{}\"", path, paths::REGEX_V1_ADMIN_ROLES_ROLE_GRANTS.as_str())
+                    );
+
+                    let param_role = match percent_encoding::percent_decode(path_params["role"].as_bytes()).decode_utf8() {
+                    Ok(param_role) => match param_role.parse::<String>() {
+                        Ok(param_role) => param_role,
+                        Err(e) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't parse path parameter role: {}", e)))
+                                        .expect("Unable to create Bad Request response for invalid path parameter")),
+                    },
+                    Err(_) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't percent-decode path parameter as UTF-8: {}", &path_params["role"])))
+                                        .expect("Unable to create Bad Request response for invalid percent decode"))
+                };
+
+                    // Body parameters (note that non-required body parameters will ignore garbage
+                    // values, rather than causing a 400 response). Produce warning header and logs for
+                    // any unused fields.
+                    let result = body.into_raw().await;
+                    match result {
+                            Ok(body) => {
+                                let mut unused_elements = Vec::new();
+                                let param_update_grants_request: Option<models::UpdateGrantsRequest> = if !body.is_empty() {
+                                    let deserializer = &mut serde_json::Deserializer::from_slice(&*body);
+                                    match serde_ignored::deserialize(deserializer, |path| {
+                                            warn!("Ignoring unknown field in body: {}", path);
+                                            unused_elements.push(path.to_string());
+                                    }) {
+                                        Ok(param_update_grants_request) => param_update_grants_request,
+                                        Err(e) => return Ok(Response::builder()
+                                                        .status(StatusCode::BAD_REQUEST)
+                                                        .body(Body::from(format!("Couldn't parse body parameter UpdateGrantsRequest - doesn't match schema: {}", e)))
+                                                        .expect("Unable to create Bad Request response for invalid body parameter UpdateGrantsRequest due to schema")),
+                                    }
+                                } else {
+                                    None
+                                };
+                                let param_update_grants_request = match param_update_grants_request {
+                                    Some(param_update_grants_request) => param_update_grants_request,
+                                    None => return Ok(Response::builder()
+                                                        .status(StatusCode::BAD_REQUEST)
+                                                        .body(Body::from("Missing required body parameter UpdateGrantsRequest"))
+                                                        .expect("Unable to create Bad Request response for missing body parameter UpdateGrantsRequest")),
+                                };
+
+                                let result = api_impl.update_grants(
+                                            param_role,
+                                            param_update_grants_request,
+                                        &context
+                                    ).await;
+                                let mut response = Response::new(Body::empty());
+                                response.headers_mut().insert(
+                                            HeaderName::from_static("x-span-id"),
+                                            HeaderValue::from_str((&context as &dyn Has<XSpanIdString>).get().0.clone().as_str())
+                                                .expect("Unable to create X-Span-ID header value"));
+
+                                        if !unused_elements.is_empty() {
+                                            response.headers_mut().insert(
+                                                HeaderName::from_static("warning"),
+                                                HeaderValue::from_str(format!("Ignoring unknown fields in body: {:?}", unused_elements).as_str())
+                                                    .expect("Unable to create Warning header value"));
+                                        }
+
+                                        match result {
+                                            Ok(rsp) => match rsp {
+                                                UpdateGrantsResponse::TheGrantsOfTheRole
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(200).expect("Unable to turn 200 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for UPDATE_GRANTS_THE_GRANTS_OF_THE_ROLE"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                UpdateGrantsResponse::IndicatesABadRequestError
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(400).expect("Unable to turn 400 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for UPDATE_GRANTS_INDICATES_A_BAD_REQUEST_ERROR"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                UpdateGrantsResponse::Unauthorized
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(401).expect("Unable to turn 401 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for UPDATE_GRANTS_UNAUTHORIZED"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                UpdateGrantsResponse::Forbidden
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(403).expect("Unable to turn 403 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for UPDATE_GRANTS_FORBIDDEN"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                UpdateGrantsResponse::NotFound
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(404).expect("Unable to turn 404 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for UPDATE_GRANTS_NOT_FOUND"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                UpdateGrantsResponse::CredentialsHaveTimedOut
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(419).expect("Unable to turn 419 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for UPDATE_GRANTS_CREDENTIALS_HAVE_TIMED_OUT"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                UpdateGrantsResponse::TheServiceIsNotReadyToHandleTheRequest
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(503).expect("Unable to turn 503 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for UPDATE_GRANTS_THE_SERVICE_IS_NOT_READY_TO_HANDLE_THE_REQUEST"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                UpdateGrantsResponse::AServer
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(500).expect("Unable to turn 5XX into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for UPDATE_GRANTS_A_SERVER"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                            },
+                                            Err(_) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+                                                *response.body_mut() = Body::from("An internal error occurred");
+                                            },
+                                        }
+
+                                        Ok(response)
+                            },
+                            Err(e) => Ok(Response::builder()
+                                                .status(StatusCode::BAD_REQUEST)
+                                                .body(Body::from(format!("Couldn't read body parameter UpdateGrantsRequest: {}", e)))
+                                                .expect("Unable to create Bad Request response due to unable to read body parameter UpdateGrantsRequest")),
+                        }
+                }
+
+                // UpdateRole - PUT /v1/admin/roles/{role}
+                hyper::Method::PUT if path.matched(paths::ID_V1_ADMIN_ROLES_ROLE) => {
+                    {
+                        let authorization = match *(&context as &dyn Has<Option<Authorization>>)
+                            .get()
+                        {
+                            Some(ref authorization) => authorization,
+                            None => {
+                                return Ok(Response::builder()
+                                    .status(StatusCode::FORBIDDEN)
+                                    .body(Body::from("Unauthenticated"))
+                                    .expect("Unable to create Authentication Forbidden response"))
+                            }
+                        };
+
+                        // Authorization
+                        if let Scopes::Some(ref scopes) = authorization.scopes {
+                            let required_scopes: std::collections::BTreeSet<String> = vec![
+                                "catalog".to_string(), // Allows interacting with the Config and Catalog APIs
+                            ]
+                            .into_iter()
+                            .collect();
+
+                            if !required_scopes.is_subset(scopes) {
+                                let missing_scopes = required_scopes.difference(scopes);
+                                return Ok(Response::builder()
+                                    .status(StatusCode::FORBIDDEN)
+                                    .body(Body::from(missing_scopes.fold(
+                                        "Insufficient authorization, missing scopes".to_string(),
+                                        |s, scope| format!("{} {}", s, scope),
+                                    )))
+                                    .expect(
+                                        "Unable to create Authentication Insufficient response",
+                                    ));
+                            }
+                        }
+                    }
+
+                    // Path parameters
+                    let path: &str = uri.path();
+                    let path_params =
+                    paths::REGEX_V1_ADMIN_ROLES_ROLE
+                    .captures(path)
+                    .unwrap_or_else(||
+                        panic!("Path {} matched RE V1_ADMIN_ROLES_ROLE in set but failed match against \"{}\"", path, paths::REGEX_V1_ADMIN_ROLES_ROLE.as_str())
+                    );
+
+                    let param_role = match percent_encoding::percent_decode(path_params["role"].as_bytes()).decode_utf8() {
+                    Ok(param_role) => match param_role.parse::<String>() {
+                        Ok(param_role) => param_role,
+                        Err(e) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't parse path parameter role: {}", e)))
+                                        .expect("Unable to create Bad Request response for invalid path parameter")),
+                    },
+                    Err(_) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't percent-decode path parameter as UTF-8: {}", &path_params["role"])))
+                                        .expect("Unable to create Bad Request response for invalid percent decode"))
+                };
+
+                    // Body parameters (note that non-required body parameters will ignore garbage
+                    // values, rather than causing a 400 response). Produce warning header and logs for
+                    // any unused fields.
+                    let result = body.into_raw().await;
+                    match result {
+                            Ok(body) => {
+                                let mut unused_elements = Vec::new();
+                                let param_update_role_request: Option<models::UpdateRoleRequest> = if !body.is_empty() {
+                                    let deserializer = &mut serde_json::Deserializer::from_slice(&*body);
+                                    match serde_ignored::deserialize(deserializer, |path| {
+                                            warn!("Ignoring unknown field in body: {}", path);
+                                            unused_elements.push(path.to_string());
+                                    }) {
+                                        Ok(param_update_role_request) => param_update_role_request,
+                                        Err(e) => return Ok(Response::builder()
+                                                        .status(StatusCode::BAD_REQUEST)
+                                                        .body(Body::from(format!("Couldn't parse body parameter UpdateRoleRequest - doesn't match schema: {}", e)))
+                                                        .expect("Unable to create Bad Request response for invalid body parameter UpdateRoleRequest due to schema")),
+                                    }
+                                } else {
+                                    None
+                                };
+                                let param_update_role_request = match param_update_role_request {
+                                    Some(param_update_role_request) => param_update_role_request,
+                                    None => return Ok(Response::builder()
+                                                        .status(StatusCode::BAD_REQUEST)
+                                                        .body(Body::from("Missing required body parameter UpdateRoleRequest"))
+                                                        .expect("Unable to create Bad Request response for missing body parameter UpdateRoleRequest")),
+                                };
+
+                                let result = api_impl.update_role(
+                                            param_role,
+                                            param_update_role_request,
+                                        &context
+                                    ).await;
+                                let mut response = Response::new(Body::empty());
+                                response.headers_mut().insert(
+                                            HeaderName::from_static("x-span-id"),
+                                            HeaderValue::from_str((&context as &dyn Has<XSpanIdString>).get().0.clone().as_str())
+                                                .expect("Unable to create X-Span-ID header value"));
+
+                                        if !unused_elements.is_empty() {
+                                            response.headers_mut().insert(
+                                                HeaderName::from_static("warning"),
+                                                HeaderValue::from_str(format!("Ignoring unknown fields in body: {:?}", unused_elements).as_str())
+                                                    .expect("Unable to create Warning header value"));
+                                        }
+
+                                        match result {
+                                            Ok(rsp) => match rsp {
+                                                UpdateRoleResponse::TheRoleAndItsPrincipals
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(200).expect("Unable to turn 200 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for UPDATE_ROLE_THE_ROLE_AND_ITS_PRINCIPALS"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                UpdateRoleResponse::IndicatesABadRequestError
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(400).expect("Unable to turn 400 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for UPDATE_ROLE_INDICATES_A_BAD_REQUEST_ERROR"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                UpdateRoleResponse::Unauthorized
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(401).expect("Unable to turn 401 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for UPDATE_ROLE_UNAUTHORIZED"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                UpdateRoleResponse::Forbidden
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(403).expect("Unable to turn 403 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for UPDATE_ROLE_FORBIDDEN"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                UpdateRoleResponse::CredentialsHaveTimedOut
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(419).expect("Unable to turn 419 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for UPDATE_ROLE_CREDENTIALS_HAVE_TIMED_OUT"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                UpdateRoleResponse::TheServiceIsNotReadyToHandleTheRequest
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(503).expect("Unable to turn 503 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for UPDATE_ROLE_THE_SERVICE_IS_NOT_READY_TO_HANDLE_THE_REQUEST"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                UpdateRoleResponse::AServer
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(500).expect("Unable to turn 5XX into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for UPDATE_ROLE_A_SERVER"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                            },
+                                            Err(_) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+                                                *response.body_mut() = Body::from("An internal error occurred");
+                                            },
+                                        }
+
+                                        Ok(response)
+                            },
+                            Err(e) => Ok(Response::builder()
+                                                .status(StatusCode::BAD_REQUEST)
+                                                .body(Body::from(format!("Couldn't read body parameter UpdateRoleRequest: {}", e)))
+                                                .expect("Unable to create Bad Request response due to unable to read body parameter UpdateRoleRequest")),
+                        }
+                }
+
+                // CreateNamespace - POST /v1/{prefix}/namespaces
+                hyper::Method::POST if path.matched(paths::ID_V1_PREFIX_NAMESPACES) => {
+                    {
+                        let authorization = match *(&context as &dyn Has<Option<Authorization>>)
+                            .get()
+                        {
+                            Some(ref authorization) => authorization,
+                            None => {
+                                return Ok(Response::builder()
+                                    .status(StatusCode::FORBIDDEN)
+                                    .body(Body::from("Unauthenticated"))
+                                    .expect("Unable to create Authentication Forbidden response"))
+                            }
+                        };
+
+                        // Authorization
+                        if let Scopes::Some(ref scopes) = authorization.scopes {
+                            let required_scopes: std::collections::BTreeSet<String> = vec![
+                                "catalog".to_string(), // Allows interacting with the Config and Catalog APIs
+                            ]
+                            .into_iter()
+                            .collect();
+
+                            if !required_scopes.is_subset(scopes) {
+                                let missing_scopes = required_scopes.difference(scopes);
+                                return Ok(Response::builder()
+                                    .status(StatusCode::FORBIDDEN)
+                                    .body(Body::from(missing_scopes.fold(
+                                        "Insufficient authorization, missing scopes".to_string(),
+                                        |s, scope| format!("{} {}", s, scope),
+                                    )))
+                                    .expect(
+                                        "Unable to create Authentication Insufficient response",
+                                    ));
+                            }
+                        }
+                    }
+
+                    // Path parameters
+                    let path: &str = uri.path();
+                    let path_params =
+                    paths::REGEX_V1_PREFIX_NAMESPACES
+                    .captures(path)
+                    .unwrap_or_else(||
+                        panic!("Path {} matched RE V1_PREFIX_NAMESPACES in set but failed match against \"{}\"", path, paths::REGEX_V1_PREFIX_NAMESPACES.as_str())
+                    );
+
+                    let param_prefix = match percent_encoding::percent_decode(path_params["prefix"].as_bytes()).decode_utf8() {
+                    Ok(param_prefix) => match param_prefix.parse::<String>() {
+                        Ok(param_prefix) => param_prefix,
+                        Err(e) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't parse path parameter prefix: {}", e)))
+                                        .expect("Unable to create Bad Request response for invalid path parameter")),
+                    },
+                    Err(_) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't percent-decode path parameter as UTF-8: {}", &path_params["prefix"])))
+                                        .expect("Unable to create Bad Request response for invalid percent decode"))
+                };
+
+                    // Body parameters (note that non-required body parameters will ignore garbage
+                    // values, rather than causing a 400 response). Produce warning header and logs for
+                    // any unused fields.
+                    let result = body.into_raw().await;
+                    match result {
+                            Ok(body) => {
+                                let mut unused_elements = Vec::new();
+                                let param_create_namespace_request: Option<models::CreateNamespaceRequest> = if !body.is_empty() {
+                                    let deserializer = &mut serde_json::Deserializer::from_slice(&*body);
+                                    match serde_ignored::deserialize(deserializer, |path| {
+                                            warn!("Ignoring unknown field in body: {}", path);
+                                            unused_elements.push(path.to_string());
+                                    }) {
+                                        Ok(param_create_namespace_request) => param_create_namespace_request,
+                                        Err(_) => None,
+                                    }
+                                } else {
+                                    None
+                                };
+
+                                let result = api_impl.create_namespace(
+                                            param_prefix,
+                                            param_create_namespace_request,
+                                        &context
+                                    ).await;
+                                let mut response = Response::new(Body::empty());
+                                response.headers_mut().insert(
+                                            HeaderName::from_static("x-span-id"),
+                                            HeaderValue::from_str((&context as &dyn Has<XSpanIdString>).get().0.clone().as_str())
+                                                .expect("Unable to create X-Span-ID header value"));
+
+                                        if !unused_elements.is_empty() {
+                                            response.headers_mut().insert(
+                                                HeaderName::from_static("warning"),
+                                                HeaderValue::from_str(format!("Ignoring unknown fields in body: {:?}", unused_elements).as_str())
+                                                    .expect("Unable to create Warning header value"));
+                                        }
+
+                                        match result {
+                                            Ok(rsp) => match rsp {
+                                                CreateNamespaceResponse::RepresentsASuccessfulCallToCreateANamespace
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(200).expect("Unable to turn 200 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for CREATE_NAMESPACE_REPRESENTS_A_SUCCESSFUL_CALL_TO_CREATE_A_NAMESPACE"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                CreateNamespaceResponse::IndicatesABadRequestError
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(400).expect("Unable to turn 400 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for CREATE_NAMESPACE_INDICATES_A_BAD_REQUEST_ERROR"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                CreateNamespaceResponse::Unauthorized
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(401).expect("Unable to turn 401 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for CREATE_NAMESPACE_UNAUTHORIZED"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                CreateNamespaceResponse::Forbidden
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(403).expect("Unable to turn 403 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for CREATE_NAMESPACE_FORBIDDEN"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                CreateNamespaceResponse::NotAcceptable
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(406).expect("Unable to turn 406 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for CREATE_NAMESPACE_NOT_ACCEPTABLE"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                CreateNamespaceResponse::NotFound
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(404).expect("Unable to turn 404 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for CREATE_NAMESPACE_NOT_FOUND"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                CreateNamespaceResponse::Conflict
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(409).expect("Unable to turn 409 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for CREATE_NAMESPACE_CONFLICT"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                CreateNamespaceResponse::CredentialsHaveTimedOut
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(419).expect("Unable to turn 419 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for CREATE_NAMESPACE_CREDENTIALS_HAVE_TIMED_OUT"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                CreateNamespaceResponse::TheServiceIsNotReadyToHandleTheRequest
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(503).expect("Unable to turn 503 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for CREATE_NAMESPACE_THE_SERVICE_IS_NOT_READY_TO_HANDLE_THE_REQUEST"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                CreateNamespaceResponse::AServer
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(500).expect("Unable to turn 5XX into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for CREATE_NAMESPACE_A_SERVER"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                            },
+                                            Err(_) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+                                                *response.body_mut() = Body::from("An internal error occurred");
+                                            },
+                                        }
+
+                                        Ok(response)
+                            },
+                            Err(e) => Ok(Response::builder()
+                                                .status(StatusCode::BAD_REQUEST)
+                                                .body(Body::from(format!("Couldn't read body parameter CreateNamespaceRequest: {}", e)))
+                                                .expect("Unable to create Bad Request response due to unable to read body parameter CreateNamespaceRequest")),
+                        }
+                }
+
+                // CreateTable - POST /v1/{prefix}/namespaces/{namespace}/tables
+                hyper::Method::POST
+                    if path.matched(paths::ID_V1_PREFIX_NAMESPACES_NAMESPACE_TABLES) =>
+                {
+                    {
+                        let authorization = match *(&context as &dyn Has<Option<Authorization>>)
+                            .get()
+                        {
+                            Some(ref authorization) => authorization,
+                            None => {
+                                return Ok(Response::builder()
+                                    .status(StatusCode::FORBIDDEN)
+                                    .body(Body::from("Unauthenticated"))
+                                    .expect("Unable to create Authentication Forbidden response"))
+                            }
+                        };
+
+                        // Authorization
+                        if let Scopes::Some(ref scopes) = authorization.scopes {
+                            let required_scopes: std::collections::BTreeSet<String> = vec![
+                                "catalog".to_string(), // Allows interacting with the Config and Catalog APIs
+                            ]
+                            .into_iter()
+                            .collect();
+
+                            if !required_scopes.is_subset(scopes) {
+                                let missing_scopes = required_scopes.difference(scopes);
+                                return Ok(Response::builder()
+                                    .status(StatusCode::FORBIDDEN)
+                                    .body(Body::from(missing_scopes.fold(
+                                        "Insufficient authorization, missing scopes".to_string(),
+                                        |s, scope| format!("{} {}", s, scope),
+                                    )))
+                                    .expect(
+                                        "Unable to create Authentication Insufficient response",
+                                    ));
+                            }
+                        }
+                    }
+
+                    // Path parameters
+                    let path: &str = uri.path();
+                    let path_params =
+                    paths::REGEX_V1_PREFIX_NAMESPACES_NAMESPACE_TABLES
+                    .captures(path)
+                    .unwrap_or_else(||
+                        panic!("Path {} matched RE V1_PREFIX_NAMESPACES_NAMESPACE_TABLES in set but failed match against \"{}\"", path, paths::REGEX_V1_PREFIX_NAMESPACES_NAMESPACE_TABLES.as_str())
+                    );
+
+                    let param_prefix = match percent_encoding::percent_decode(path_params["prefix"].as_bytes()).decode_utf8() {
+                    Ok(param_prefix) => match param_prefix.parse::<String>() {
+                        Ok(param_prefix) => param_prefix,
+                        Err(e) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't parse path parameter prefix: {}", e)))
+                                        .expect("Unable to create Bad Request response for invalid path parameter")),
+                    },
+                    Err(_) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't percent-decode path parameter as UTF-8: {}", &path_params["prefix"])))
+                                        .expect("Unable to create Bad Request response for invalid percent decode"))
+                };
+
+                    let param_namespace = match percent_encoding::percent_decode(path_params["namespace"].as_bytes()).decode_utf8() {
+                    Ok(param_namespace) => match param_namespace.parse::<String>() {
+                        Ok(param_namespace) => param_namespace,
+                        Err(e) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't parse path parameter namespace: {}", e)))
+                                        .expect("Unable to create Bad Request response for invalid path parameter")),
+                    },
+                    Err(_) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't percent-decode path parameter as UTF-8: {}", &path_params["namespace"])))
+                                        .expect("Unable to create Bad Request response for invalid percent decode"))
+                };
+
+                    // Body parameters (note that non-required body parameters will ignore garbage
+                    // values, rather than causing a 400 response). Produce warning header and logs for
+                    // any unused fields.
+                    let result = body.into_raw().await;
+                    match result {
+                            Ok(body) => {
+                                let mut unused_elements = Vec::new();
+                                let param_create_table_request: Option<models::CreateTableRequest> = if !body.is_empty() {
+                                    let deserializer = &mut serde_json::Deserializer::from_slice(&*body);
+                                    match serde_ignored::deserialize(deserializer, |path| {
+                                            warn!("Ignoring unknown field in body: {}", path);
+                                            unused_elements.push(path.to_string());
+                                    }) {
+                                        Ok(param_create_table_request) => param_create_table_request,
+                                        Err(_) => None,
+                                    }
+                                } else {
+                                    None
+                                };
+
+                                let result = api_impl.create_table(
+                                            param_prefix,
+                                            param_namespace,
+                                            param_create_table_request,
+                                        &context
+                                    ).await;
+                                let mut response = Response::new(Body::empty());
+                                response.headers_mut().insert(
+                                            HeaderName::from_static("x-span-id"),
+                                            HeaderValue::from_str((&context as &dyn Has<XSpanIdString>).get().0.clone().as_str())
+                                                .expect("Unable to create X-Span-ID header value"));
+
+                                        if !unused_elements.is_empty() {
+                                            response.headers_mut().insert(
+                                                HeaderName::from_static("warning"),
+                                                HeaderValue::from_str(format!("Ignoring unknown fields in body: {:?}", unused_elements).as_str())
+                                                    .expect("Unable to create Warning header value"));
+                                        }
+
+                                        match result {
+                                            Ok(rsp) => match rsp {
+                                                CreateTableResponse::TableMetadataResultAfterCreatingATable
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(200).expect("Unable to turn 200 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for CREATE_TABLE_TABLE_METADATA_RESULT_AFTER_CREATING_A_TABLE"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                CreateTableResponse::IndicatesABadRequestError
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(400).expect("Unable to turn 400 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for CREATE_TABLE_INDICATES_A_BAD_REQUEST_ERROR"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                CreateTableResponse::Unauthorized
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(401).expect("Unable to turn 401 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for CREATE_TABLE_UNAUTHORIZED"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                CreateTableResponse::Forbidden
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(403).expect("Unable to turn 403 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for CREATE_TABLE_FORBIDDEN"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                CreateTableResponse::NotFound
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(404).expect("Unable to turn 404 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for CREATE_TABLE_NOT_FOUND"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                CreateTableResponse::Conflict
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(409).expect("Unable to turn 409 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for CREATE_TABLE_CONFLICT"));
+                                                    let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
+                                                    *response.body_mut() = Body::from(body);
+                                                },
+                                                CreateTableResponse::CredentialsHaveTimedOut
+                                                    (body)
+                                                => {
+                                                    *response.status_mut() = StatusCode::from_u16(419).expect("Unable to turn 419 into a StatusCode");
+                                                    response.headers_mut().insert(
+                                                        CONTENT_TYPE,
                                                         HeaderValue::from_str("application/json")
                                                             .expect("Unable to create Content-Type header for CREATE_TABLE_CREDENTIALS_HAVE_TIMED_OUT"));
                                                     let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
@@ -665,8 +2925,231 @@ where
                         }
                 }
 
-                // DropNamespace - DELETE /v1/{prefix}/namespaces/{namespace}
-                hyper::Method::DELETE if path.matched(paths::ID_V1_PREFIX_NAMESPACES_NAMESPACE) => {
+                // DropNamespace - DELETE /v1/{prefix}/namespaces/{namespace}
+                hyper::Method::DELETE if path.matched(paths::ID_V1_PREFIX_NAMESPACES_NAMESPACE) => {
+                    {
+                        let authorization = match *(&context as &dyn Has<Option<Authorization>>)
+                            .get()
+                        {
+                            Some(ref authorization) => authorization,
+                            None => {
+                                return Ok(Response::builder()
+                                    .status(StatusCode::FORBIDDEN)
+                                    .body(Body::from("Unauthenticated"))
+                                    .expect("Unable to create Authentication Forbidden response"))
+                            }
+                        };
+
+                        // Authorization
+                        if let Scopes::Some(ref scopes) = authorization.scopes {
+                            let required_scopes: std::collections::BTreeSet<String> = vec![
+                                "catalog".to_string(), // Allows interacting with the Config and Catalog APIs
+                            ]
+                            .into_iter()
+                            .collect();
+
+                            if !required_scopes.is_subset(scopes) {
+                                let missing_scopes = required_scopes.difference(scopes);
+                                return Ok(Response::builder()
+                                    .status(StatusCode::FORBIDDEN)
+                                    .body(Body::from(missing_scopes.fold(
+                                        "Insufficient authorization, missing scopes".to_string(),
+                                        |s, scope| format!("{} {}", s, scope),
+                                    )))
+                                    .expect(
+                                        "Unable to create Authentication Insufficient response",
+                                    ));
+                            }
+                        }
+                    }
+
+                    // Path parameters
+                    let path: &str = uri.path();
+                    let path_params =
+                    paths::REGEX_V1_PREFIX_NAMESPACES_NAMESPACE
+                    .captures(path)
+                    .unwrap_or_else(||
+                        panic!("Path {} matched RE V1_PREFIX_NAMESPACES_NAMESPACE in set but failed match against \"{}\"", path, paths::REGEX_V1_PREFIX_NAMESPACES_NAMESPACE.as_str())
+                    );
+
+                    let param_prefix = match percent_encoding::percent_decode(path_params["prefix"].as_bytes()).decode_utf8() {
+                    Ok(param_prefix) => match param_prefix.parse::<String>() {
+                        Ok(param_prefix) => param_prefix,
+                        Err(e) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't parse path parameter prefix: {}", e)))
+                                        .expect("Unable to create Bad Request response for invalid path parameter")),
+                    },
+                    Err(_) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't percent-decode path parameter as UTF-8: {}", &path_params["prefix"])))
+                                        .expect("Unable to create Bad Request response for invalid percent decode"))
+                };
+
+                    let param_namespace = match percent_encoding::percent_decode(path_params["namespace"].as_bytes()).decode_utf8() {
+                    Ok(param_namespace) => match param_namespace.parse::<String>() {
+                        Ok(param_namespace) => param_namespace,
+                        Err(e) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't parse path parameter namespace: {}", e)))
+                                        .expect("Unable to create Bad Request response for invalid path parameter")),
+                    },
+                    Err(_) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't percent-decode path parameter as UTF-8: {}", &path_params["namespace"])))
+                                        .expect("Unable to create Bad Request response for invalid percent decode"))
+                };
+
+                    // Query parameters (note that non-required or collection query parameters will ignore garbage values, rather than causing a 400 response)
+                    let query_params =
+                        form_urlencoded::parse(uri.query().unwrap_or_default().as_bytes())
+                            .collect::<Vec<_>>();
+                    let param_cascade = query_params
+                        .iter()
+                        .filter(|e| e.0 == "cascade")
+                        .map(|e| e.1.to_owned())
+                        .next();
+                    let param_cascade = match param_cascade {
+                        Some(param_cascade) => {
+                            let param_cascade =
+                                <bool as std::str::FromStr>::from_str(&param_cascade);
+                            match param_cascade {
+                            Ok(param_cascade) => Some(param_cascade),
+                            Err(e) => return Ok(Response::builder()
+                                .status(StatusCode::BAD_REQUEST)
+                                .body(Body::from(format!("Couldn't parse query parameter cascade - doesn't match schema: {}", e)))
+                                .expect("Unable to create Bad Request response for invalid query parameter cascade")),
+                        }
+                        }
+                        None => None,
+                    };
+
+                    let result = api_impl
+                        .drop_namespace(param_prefix, param_namespace, param_cascade, &context)
+                        .await;
+                    let mut response = Response::new(Body::empty());
+                    response.headers_mut().insert(
+                        HeaderName::from_static("x-span-id"),
+                        HeaderValue::from_str(
+                            (&context as &dyn Has<XSpanIdString>)
+                                .get()
+                                .0
+                                .clone()
+                                .as_str(),
+                        )
+                        .expect("Unable to create X-Span-ID header value"),
+                    );
+
+                    match result {
+                        Ok(rsp) => match rsp {
+                            DropNamespaceResponse::Success => {
+                                *response.status_mut() = StatusCode::from_u16(204)
+                                    .expect("Unable to turn 204 into a StatusCode");
+                            }
+                            DropNamespaceResponse::IndicatesABadRequestError(body) => {
+                                *response.status_mut() = StatusCode::from_u16(400)
+                                    .expect("Unable to turn 400 into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for DROP_NAMESPACE_INDICATES_A_BAD_REQUEST_ERROR"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                            DropNamespaceResponse::Unauthorized(body) => {
+                                *response.status_mut() = StatusCode::from_u16(401)
+                                    .expect("Unable to turn 401 into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for DROP_NAMESPACE_UNAUTHORIZED"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                            DropNamespaceResponse::Forbidden(body) => {
+                                *response.status_mut() = StatusCode::from_u16(403)
+                                    .expect("Unable to turn 403 into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for DROP_NAMESPACE_FORBIDDEN"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                            DropNamespaceResponse::NotFound(body) => {
+                                *response.status_mut() = StatusCode::from_u16(404)
+                                    .expect("Unable to turn 404 into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for DROP_NAMESPACE_NOT_FOUND"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                            DropNamespaceResponse::Conflict(body) => {
+                                *response.status_mut() = StatusCode::from_u16(409)
+                                    .expect("Unable to turn 409 into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for DROP_NAMESPACE_CONFLICT"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                            DropNamespaceResponse::CredentialsHaveTimedOut(body) => {
+                                *response.status_mut() = StatusCode::from_u16(419)
+                                    .expect("Unable to turn 419 into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for DROP_NAMESPACE_CREDENTIALS_HAVE_TIMED_OUT"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                            DropNamespaceResponse::TheServiceIsNotReadyToHandleTheRequest(body) => {
+                                *response.status_mut() = StatusCode::from_u16(503)
+                                    .expect("Unable to turn 503 into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for DROP_NAMESPACE_THE_SERVICE_IS_NOT_READY_TO_HANDLE_THE_REQUEST"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                            DropNamespaceResponse::AServer(body) => {
+                                *response.status_mut() = StatusCode::from_u16(500)
+                                    .expect("Unable to turn 5XX into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for DROP_NAMESPACE_A_SERVER"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                        },
+                        Err(_) => {
+                            // Application code returned an error. This should not happen, as the implementation should
+                            // return a valid response.
+                            *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+                            *response.body_mut() = Body::from("An internal error occurred");
+                        }
+                    }
+
+                    Ok(response)
+                }
+
+                // DropTable - DELETE /v1/{prefix}/namespaces/{namespace}/tables/{table}
+                hyper::Method::DELETE
+                    if path.matched(paths::ID_V1_PREFIX_NAMESPACES_NAMESPACE_TABLES_TABLE) =>
+                {
                     {
                         let authorization = match *(&context as &dyn Has<Option<Authorization>>)
                             .get()
@@ -706,10 +3189,10 @@ where
                     // Path parameters
                     let path: &str = uri.path();
                     let path_params =
-                    paths::REGEX_V1_PREFIX_NAMESPACES_NAMESPACE
+                    paths::REGEX_V1_PREFIX_NAMESPACES_NAMESPACE_TABLES_TABLE
                     .captures(path)
                     .unwrap_or_else(||
-                        panic!("Path {} matched RE V1_PREFIX_NAMESPACES_NAMESPACE in set but failed match against \"{}\"", path, paths::REGEX_V1_PREFIX_NAMESPACES_NAMESPACE.as_str())
+                        panic!("Path {} matched RE V1_PREFIX_NAMESPACES_NAMESPACE_TABLES_TABLE in set but failed match against \"{}\"", path, paths::REGEX_V1_PREFIX_NAMESPACES_NAMESPACE_TABLES_TABLE.as_str())
                     );
 
                     let param_prefix = match percent_encoding::percent_decode(path_params["prefix"].as_bytes()).decode_utf8() {
@@ -740,8 +3223,259 @@ where
                                         .expect("Unable to create Bad Request response for invalid percent decode"))
                 };
 
+                    let param_table = match percent_encoding::percent_decode(path_params["table"].as_bytes()).decode_utf8() {
+                    Ok(param_table) => match param_table.parse::<String>() {
+                        Ok(param_table) => param_table,
+                        Err(e) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't parse path parameter table: {}", e)))
+                                        .expect("Unable to create Bad Request response for invalid path parameter")),
+                    },
+                    Err(_) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't percent-decode path parameter as UTF-8: {}", &path_params["table"])))
+                                        .expect("Unable to create Bad Request response for invalid percent decode"))
+                };
+
+                    // Query parameters (note that non-required or collection query parameters will ignore garbage values, rather than causing a 400 response)
+                    let query_params =
+                        form_urlencoded::parse(uri.query().unwrap_or_default().as_bytes())
+                            .collect::<Vec<_>>();
+                    let param_purge_requested = query_params
+                        .iter()
+                        .filter(|e| e.0 == "purgeRequested")
+                        .map(|e| e.1.to_owned())
+                        .next();
+                    let param_purge_requested = match param_purge_requested {
+                        Some(param_purge_requested) => {
+                            let param_purge_requested =
+                                <bool as std::str::FromStr>::from_str(&param_purge_requested);
+                            match param_purge_requested {
+                            Ok(param_purge_requested) => Some(param_purge_requested),
+                            Err(e) => return Ok(Response::builder()
+                                .status(StatusCode::BAD_REQUEST)
+                                .body(Body::from(format!("Couldn't parse query parameter purgeRequested - doesn't match schema: {}", e)))
+                                .expect("Unable to create Bad Request response for invalid query parameter purgeRequested")),
+                        }
+                        }
+                        None => None,
+                    };
+
+                    let result = api_impl
+                        .drop_table(
+                            param_prefix,
+                            param_namespace,
+                            param_table,
+                            param_purge_requested,
+                            &context,
+                        )
+                        .await;
+                    let mut response = Response::new(Body::empty());
+                    response.headers_mut().insert(
+                        HeaderName::from_static("x-span-id"),
+                        HeaderValue::from_str(
+                            (&context as &dyn Has<XSpanIdString>)
+                                .get()
+                                .0
+                                .clone()
+                                .as_str(),
+                        )
+                        .expect("Unable to create X-Span-ID header value"),
+                    );
+
+                    match result {
+                        Ok(rsp) => match rsp {
+                            DropTableResponse::Success => {
+                                *response.status_mut() = StatusCode::from_u16(204)
+                                    .expect("Unable to turn 204 into a StatusCode");
+                            }
+                            DropTableResponse::IndicatesABadRequestError(body) => {
+                                *response.status_mut() = StatusCode::from_u16(400)
+                                    .expect("Unable to turn 400 into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for DROP_TABLE_INDICATES_A_BAD_REQUEST_ERROR"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                            DropTableResponse::Unauthorized(body) => {
+                                *response.status_mut() = StatusCode::from_u16(401)
+                                    .expect("Unable to turn 401 into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for DROP_TABLE_UNAUTHORIZED"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                            DropTableResponse::Forbidden(body) => {
+                                *response.status_mut() = StatusCode::from_u16(403)
+                                    .expect("Unable to turn 403 into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for DROP_TABLE_FORBIDDEN"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                            DropTableResponse::NotFound(body) => {
+                                *response.status_mut() = StatusCode::from_u16(404)
+                                    .expect("Unable to turn 404 into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for DROP_TABLE_NOT_FOUND"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                            DropTableResponse::Conflict(body) => {
+                                *response.status_mut() = StatusCode::from_u16(409)
+                                    .expect("Unable to turn 409 into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for DROP_TABLE_CONFLICT"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                            DropTableResponse::CredentialsHaveTimedOut(body) => {
+                                *response.status_mut() = StatusCode::from_u16(419)
+                                    .expect("Unable to turn 419 into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for DROP_TABLE_CREDENTIALS_HAVE_TIMED_OUT"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                            DropTableResponse::TheServiceIsNotReadyToHandleTheRequest(body) => {
+                                *response.status_mut() = StatusCode::from_u16(503)
+                                    .expect("Unable to turn 503 into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for DROP_TABLE_THE_SERVICE_IS_NOT_READY_TO_HANDLE_THE_REQUEST"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                            DropTableResponse::AServer(body) => {
+                                *response.status_mut() = StatusCode::from_u16(500)
+                                    .expect("Unable to turn 5XX into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for DROP_TABLE_A_SERVER"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
+                        },
+                        Err(_) => {
+                            // Application code returned an error. This should not happen, as the implementation should
+                            // return a valid response.
+                            *response.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+                            *response.body_mut() = Body::from("An internal error occurred");
+                        }
+                    }
+
+                    Ok(response)
+                }
+
+                // ListNamespaces - GET /v1/{prefix}/namespaces
+                hyper::Method::GET if path.matched(paths::ID_V1_PREFIX_NAMESPACES) => {
+                    {
+                        let authorization = match *(&context as &dyn Has<Option<Authorization>>)
+                            .get()
+                        {
+                            Some(ref authorization) => authorization,
+                            None => {
+                                return Ok(Response::builder()
+                                    .status(StatusCode::FORBIDDEN)
+                                    .body(Body::from("Unauthenticated"))
+                                    .expect("Unable to create Authentication Forbidden response"))
+                            }
+                        };
+
+                        // Authorization
+                        if let Scopes::Some(ref scopes) = authorization.scopes {
+                            let required_scopes: std::collections::BTreeSet<String> = vec![
+                                "catalog".to_string(), // Allows interacting with the Config and Catalog APIs
+                            ]
+                            .into_iter()
+                            .collect();
+
+                            if !required_scopes.is_subset(scopes) {
+                                let missing_scopes = required_scopes.difference(scopes);
+                                return Ok(Response::builder()
+                                    .status(StatusCode::FORBIDDEN)
+                                    .body(Body::from(missing_scopes.fold(
+                                        "Insufficient authorization, missing scopes".to_string(),
+                                        |s, scope| format!("{} {}", s, scope),
+                                    )))
+                                    .expect(
+                                        "Unable to create Authentication Insufficient response",
+                                    ));
+                            }
+                        }
+                    }
+
+                    // Path parameters
+                    let path: &str = uri.path();
+                    let path_params =
+                    paths::REGEX_V1_PREFIX_NAMESPACES
+                    .captures(path)
+                    .unwrap_or_else(||
+                        panic!("Path {} matched RE V1_PREFIX_NAMESPACES in set but failed match against \"{}\"", path, paths::REGEX_V1_PREFIX_NAMESPACES.as_str())
+                    );
+
+                    let param_prefix = match percent_encoding::percent_decode(path_params["prefix"].as_bytes()).decode_utf8() {
+                    Ok(param_prefix) => match param_prefix.parse::<String>() {
+                        Ok(param_prefix) => param_prefix,
+                        Err(e) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't parse path parameter prefix: {}", e)))
+                                        .expect("Unable to create Bad Request response for invalid path parameter")),
+                    },
+                    Err(_) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't percent-decode path parameter as UTF-8: {}", &path_params["prefix"])))
+                                        .expect("Unable to create Bad Request response for invalid percent decode"))
+                };
+
+                    // Query parameters (note that non-required or collection query parameters will ignore garbage values, rather than causing a 400 response)
+                    let query_params =
+                        form_urlencoded::parse(uri.query().unwrap_or_default().as_bytes())
+                            .collect::<Vec<_>>();
+                    let param_parent = query_params
+                        .iter()
+                        .filter(|e| e.0 == "parent")
+                        .map(|e| e.1.to_owned())
+                        .next();
+                    let param_parent = match param_parent {
+                        Some(param_parent) => {
+                            let param_parent =
+                                <String as std::str::FromStr>::from_str(&param_parent);
+                            match param_parent {
+                            Ok(param_parent) => Some(param_parent),
+                            Err(e) => return Ok(Response::builder()
+                                .status(StatusCode::BAD_REQUEST)
+                                .body(Body::from(format!("Couldn't parse query parameter parent - doesn't match schema: {}", e)))
+                                .expect("Unable to create Bad Request response for invalid query parameter parent")),
+                        }
+                        }
+                        None => None,
+                    };
+
                     let result = api_impl
-                        .drop_namespace(param_prefix, param_namespace, &context)
+                        .list_namespaces(param_prefix, param_parent, &context)
                         .await;
                     let mut response = Response::new(Body::empty());
                     response.headers_mut().insert(
@@ -758,83 +3492,92 @@ where
 
                     match result {
                         Ok(rsp) => match rsp {
-                            DropNamespaceResponse::Success => {
-                                *response.status_mut() = StatusCode::from_u16(204)
-                                    .expect("Unable to turn 204 into a StatusCode");
+                            ListNamespacesResponse::AListOfNamespaces(body) => {
+                                *response.status_mut() = StatusCode::from_u16(200)
+                                    .expect("Unable to turn 200 into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for LIST_NAMESPACES_A_LIST_OF_NAMESPACES"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
                             }
-                            DropNamespaceResponse::IndicatesABadRequestError(body) => {
+                            ListNamespacesResponse::IndicatesABadRequestError(body) => {
                                 *response.status_mut() = StatusCode::from_u16(400)
                                     .expect("Unable to turn 400 into a StatusCode");
                                 response.headers_mut().insert(
                                                         CONTENT_TYPE,
                                                         HeaderValue::from_str("application/json")
-                                                            .expect("Unable to create Content-Type header for DROP_NAMESPACE_INDICATES_A_BAD_REQUEST_ERROR"));
+                                                            .expect("Unable to create Content-Type header for LIST_NAMESPACES_INDICATES_A_BAD_REQUEST_ERROR"));
                                 let body = serde_json::to_string(&body)
                                     .expect("impossible to fail to serialize");
                                 *response.body_mut() = Body::from(body);
                             }
-                            DropNamespaceResponse::Unauthorized(body) => {
+                            ListNamespacesResponse::Unauthorized(body) => {
                                 *response.status_mut() = StatusCode::from_u16(401)
                                     .expect("Unable to turn 401 into a StatusCode");
                                 response.headers_mut().insert(
                                                         CONTENT_TYPE,
                                                         HeaderValue::from_str("application/json")
-                                                            .expect("Unable to create Content-Type header for DROP_NAMESPACE_UNAUTHORIZED"));
+                                                            .expect("Unable to create Content-Type header for LIST_NAMESPACES_UNAUTHORIZED"));
                                 let body = serde_json::to_string(&body)
                                     .expect("impossible to fail to serialize");
                                 *response.body_mut() = Body::from(body);
                             }
-                            DropNamespaceResponse::Forbidden(body) => {
+                            ListNamespacesResponse::Forbidden(body) => {
                                 *response.status_mut() = StatusCode::from_u16(403)
                                     .expect("Unable to turn 403 into a StatusCode");
                                 response.headers_mut().insert(
                                                         CONTENT_TYPE,
                                                         HeaderValue::from_str("application/json")
-                                                            .expect("Unable to create Content-Type header for DROP_NAMESPACE_FORBIDDEN"));
+                                                            .expect("Unable to create Content-Type header for LIST_NAMESPACES_FORBIDDEN"));
                                 let body = serde_json::to_string(&body)
                                     .expect("impossible to fail to serialize");
                                 *response.body_mut() = Body::from(body);
                             }
-                            DropNamespaceResponse::NotFound(body) => {
+                            ListNamespacesResponse::NotFound(body) => {
                                 *response.status_mut() = StatusCode::from_u16(404)
                                     .expect("Unable to turn 404 into a StatusCode");
                                 response.headers_mut().insert(
                                                         CONTENT_TYPE,
                                                         HeaderValue::from_str("application/json")
-                                                            .expect("Unable to create Content-Type header for DROP_NAMESPACE_NOT_FOUND"));
+                                                            .expect("Unable to create Content-Type header for LIST_NAMESPACES_NOT_FOUND"));
                                 let body = serde_json::to_string(&body)
                                     .expect("impossible to fail to serialize");
                                 *response.body_mut() = Body::from(body);
                             }
-                            DropNamespaceResponse::CredentialsHaveTimedOut(body) => {
+                            ListNamespacesResponse::CredentialsHaveTimedOut(body) => {
                                 *response.status_mut() = StatusCode::from_u16(419)
                                     .expect("Unable to turn 419 into a StatusCode");
                                 response.headers_mut().insert(
                                                         CONTENT_TYPE,
                                                         HeaderValue::from_str("application/json")
-                                                            .expect("Unable to create Content-Type header for DROP_NAMESPACE_CREDENTIALS_HAVE_TIMED_OUT"));
+                                                            .expect("Unable to create Content-Type header for LIST_NAMESPACES_CREDENTIALS_HAVE_TIMED_OUT"));
                                 let body = serde_json::to_string(&body)
                                     .expect("impossible to fail to serialize");
                                 *response.body_mut() = Body::from(body);
                             }
-                            DropNamespaceResponse::TheServiceIsNotReadyToHandleTheRequest(body) => {
+                            ListNamespacesResponse::TheServiceIsNotReadyToHandleTheRequest(
+                                body,
+                            ) => {
                                 *response.status_mut() = StatusCode::from_u16(503)
                                     .expect("Unable to turn 503 into a StatusCode");
                                 response.headers_mut().insert(
                                                         CONTENT_TYPE,
                                                         HeaderValue::from_str("application/json")
-                                                            .expect("Unable to create Content-Type header for DROP_NAMESPACE_THE_SERVICE_IS_NOT_READY_TO_HANDLE_THE_REQUEST"));
+                                                            .expect("Unable to create Content-Type header for LIST_NAMESPACES_THE_SERVICE_IS_NOT_READY_TO_HANDLE_THE_REQUEST"));
                                 let body = serde_json::to_string(&body)
                                     .expect("impossible to fail to serialize");
                                 *response.body_mut() = Body::from(body);
                             }
-                            DropNamespaceResponse::AServer(body) => {
+                            ListNamespacesResponse::AServer(body) => {
                                 *response.status_mut() = StatusCode::from_u16(500)
                                     .expect("Unable to turn 5XX into a StatusCode");
                                 response.headers_mut().insert(
                                                         CONTENT_TYPE,
                                                         HeaderValue::from_str("application/json")
-                                                            .expect("Unable to create Content-Type header for DROP_NAMESPACE_A_SERVER"));
+                                                            .expect("Unable to create Content-Type header for LIST_NAMESPACES_A_SERVER"));
                                 let body = serde_json::to_string(&body)
                                     .expect("impossible to fail to serialize");
                                 *response.body_mut() = Body::from(body);
@@ -851,9 +3594,10 @@ where
                     Ok(response)
                 }
 
-                // DropTable - DELETE /v1/{prefix}/namespaces/{namespace}/tables/{table}
-                hyper::Method::DELETE
-                    if path.matched(paths::ID_V1_PREFIX_NAMESPACES_NAMESPACE_TABLES_TABLE) =>
+                // ListTableCommits - GET /v1/{prefix}/namespaces/{namespace}/tables/{table}/commits
+                hyper::Method::GET
+                    if path
+                        .matched(paths::ID_V1_PREFIX_NAMESPACES_NAMESPACE_TABLES_TABLE_COMMITS) =>
                 {
                     {
                         let authorization = match *(&context as &dyn Has<Option<Authorization>>)
@@ -894,10 +3638,10 @@ where
                     // Path parameters
                     let path: &str = uri.path();
                     let path_params =
-                    paths::REGEX_V1_PREFIX_NAMESPACES_NAMESPACE_TABLES_TABLE
+                    paths::REGEX_V1_PREFIX_NAMESPACES_NAMESPACE_TABLES_TABLE_COMMITS
                     .captures(path)
                     .unwrap_or_else(||
-                        panic!("Path {} matched RE V1_PREFIX_NAMESPACES_NAMESPACE_TABLES_TABLE in set but failed match against \"{}\"", path, paths::REGEX_V1_PREFIX_NAMESPACES_NAMESPACE_TABLES_TABLE.as_str())
+                        panic!("Path {} matched RE V1_PREFIX_NAMESPACES_NAMESPACE_TABLES_TABLE_COMMITS in set but failed match against \"{}\"", path, paths::REGEX_V1_PREFIX_NAMESPACES_NAMESPACE_TABLES_TABLE_COMMITS.as_str())
                     );
 
                     let param_prefix = match percent_encoding::percent_decode(path_params["prefix"].as_bytes()).decode_utf8() {
@@ -946,32 +3690,52 @@ where
                     let query_params =
                         form_urlencoded::parse(uri.query().unwrap_or_default().as_bytes())
                             .collect::<Vec<_>>();
-                    let param_purge_requested = query_params
+                    let param_page_token = query_params
                         .iter()
-                        .filter(|e| e.0 == "purgeRequested")
+                        .filter(|e| e.0 == "pageToken")
                         .map(|e| e.1.to_owned())
                         .next();
-                    let param_purge_requested = match param_purge_requested {
-                        Some(param_purge_requested) => {
-                            let param_purge_requested =
-                                <bool as std::str::FromStr>::from_str(&param_purge_requested);
-                            match param_purge_requested {
-                            Ok(param_purge_requested) => Some(param_purge_requested),
+                    let param_page_token = match param_page_token {
+                        Some(param_page_token) => {
+                            let param_page_token =
+                                <String as std::str::FromStr>::from_str(&param_page_token);
+                            match param_page_token {
+                            Ok(param_page_token) => Some(param_page_token),
                             Err(e) => return Ok(Response::builder()
                                 .status(StatusCode::BAD_REQUEST)
-                                .body(Body::from(format!("Couldn't parse query parameter purgeRequested - doesn't match schema: {}", e)))
-                                .expect("Unable to create Bad Request response for invalid query parameter purgeRequested")),
+                                .body(Body::from(format!("Couldn't parse query parameter pageToken - doesn't match schema: {}", e)))
+                                .expect("Unable to create Bad Request response for invalid query parameter pageToken")),
+                        }
+                        }
+                        None => None,
+                    };
+                    let param_page_size = query_params
+                        .iter()
+                        .filter(|e| e.0 == "pageSize")
+                        .map(|e| e.1.to_owned())
+                        .next();
+                    let param_page_size = match param_page_size {
+                        Some(param_page_size) => {
+                            let param_page_size =
+                                <i32 as std::str::FromStr>::from_str(&param_page_size);
+                            match param_page_size {
+                            Ok(param_page_size) => Some(param_page_size),
+                            Err(e) => return Ok(Response::builder()
+                                .status(StatusCode::BAD_REQUEST)
+                                .body(Body::from(format!("Couldn't parse query parameter pageSize - doesn't match schema: {}", e)))
+                                .expect("Unable to create Bad Request response for invalid query parameter pageSize")),
                         }
                         }
                         None => None,
                     };
 
                     let result = api_impl
-                        .drop_table(
+                        .list_table_commits(
                             param_prefix,
                             param_namespace,
                             param_table,
-                            param_purge_requested,
+                            param_page_token,
+                            param_page_size,
                             &context,
                         )
                         .await;
@@ -990,83 +3754,92 @@ where
 
                     match result {
                         Ok(rsp) => match rsp {
-                            DropTableResponse::Success => {
-                                *response.status_mut() = StatusCode::from_u16(204)
-                                    .expect("Unable to turn 204 into a StatusCode");
+                            ListTableCommitsResponse::APageOfTheCommitHistoryOfTheTable(body) => {
+                                *response.status_mut() = StatusCode::from_u16(200)
+                                    .expect("Unable to turn 200 into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for LIST_TABLE_COMMITS_A_PAGE_OF_THE_COMMIT_HISTORY_OF_THE_TABLE"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
                             }
-                            DropTableResponse::IndicatesABadRequestError(body) => {
+                            ListTableCommitsResponse::IndicatesABadRequestError(body) => {
                                 *response.status_mut() = StatusCode::from_u16(400)
                                     .expect("Unable to turn 400 into a StatusCode");
                                 response.headers_mut().insert(
                                                         CONTENT_TYPE,
                                                         HeaderValue::from_str("application/json")
-                                                            .expect("Unable to create Content-Type header for DROP_TABLE_INDICATES_A_BAD_REQUEST_ERROR"));
+                                                            .expect("Unable to create Content-Type header for LIST_TABLE_COMMITS_INDICATES_A_BAD_REQUEST_ERROR"));
                                 let body = serde_json::to_string(&body)
                                     .expect("impossible to fail to serialize");
                                 *response.body_mut() = Body::from(body);
                             }
-                            DropTableResponse::Unauthorized(body) => {
+                            ListTableCommitsResponse::Unauthorized(body) => {
                                 *response.status_mut() = StatusCode::from_u16(401)
                                     .expect("Unable to turn 401 into a StatusCode");
                                 response.headers_mut().insert(
                                                         CONTENT_TYPE,
                                                         HeaderValue::from_str("application/json")
-                                                            .expect("Unable to create Content-Type header for DROP_TABLE_UNAUTHORIZED"));
+                                                            .expect("Unable to create Content-Type header for LIST_TABLE_COMMITS_UNAUTHORIZED"));
                                 let body = serde_json::to_string(&body)
                                     .expect("impossible to fail to serialize");
                                 *response.body_mut() = Body::from(body);
                             }
-                            DropTableResponse::Forbidden(body) => {
+                            ListTableCommitsResponse::Forbidden(body) => {
                                 *response.status_mut() = StatusCode::from_u16(403)
                                     .expect("Unable to turn 403 into a StatusCode");
                                 response.headers_mut().insert(
                                                         CONTENT_TYPE,
                                                         HeaderValue::from_str("application/json")
-                                                            .expect("Unable to create Content-Type header for DROP_TABLE_FORBIDDEN"));
+                                                            .expect("Unable to create Content-Type header for LIST_TABLE_COMMITS_FORBIDDEN"));
                                 let body = serde_json::to_string(&body)
                                     .expect("impossible to fail to serialize");
                                 *response.body_mut() = Body::from(body);
                             }
-                            DropTableResponse::NotFound(body) => {
+                            ListTableCommitsResponse::NotFound(body) => {
                                 *response.status_mut() = StatusCode::from_u16(404)
                                     .expect("Unable to turn 404 into a StatusCode");
                                 response.headers_mut().insert(
                                                         CONTENT_TYPE,
                                                         HeaderValue::from_str("application/json")
-                                                            .expect("Unable to create Content-Type header for DROP_TABLE_NOT_FOUND"));
+                                                            .expect("Unable to create Content-Type header for LIST_TABLE_COMMITS_NOT_FOUND"));
                                 let body = serde_json::to_string(&body)
                                     .expect("impossible to fail to serialize");
                                 *response.body_mut() = Body::from(body);
                             }
-                            DropTableResponse::CredentialsHaveTimedOut(body) => {
+                            ListTableCommitsResponse::CredentialsHaveTimedOut(body) => {
                                 *response.status_mut() = StatusCode::from_u16(419)
                                     .expect("Unable to turn 419 into a StatusCode");
                                 response.headers_mut().insert(
                                                         CONTENT_TYPE,
                                                         HeaderValue::from_str("application/json")
-                                                            .expect("Unable to create Content-Type header for DROP_TABLE_CREDENTIALS_HAVE_TIMED_OUT"));
+                                                            .expect("Unable to create Content-Type header for LIST_TABLE_COMMITS_CREDENTIALS_HAVE_TIMED_OUT"));
                                 let body = serde_json::to_string(&body)
                                     .expect("impossible to fail to serialize");
                                 *response.body_mut() = Body::from(body);
                             }
-                            DropTableResponse::TheServiceIsNotReadyToHandleTheRequest(body) => {
-                                *response.status_mut() = StatusCode::from_u16(503)
-                                    .expect("Unable to turn 503 into a StatusCode");
+                            ListTableCommitsResponse::AServer(body) => {
+                                *response.status_mut() = StatusCode::from_u16(500)
+                                    .expect("Unable to turn 5XX into a StatusCode");
                                 response.headers_mut().insert(
                                                         CONTENT_TYPE,
                                                         HeaderValue::from_str("application/json")
-                                                            .expect("Unable to create Content-Type header for DROP_TABLE_THE_SERVICE_IS_NOT_READY_TO_HANDLE_THE_REQUEST"));
+                                                            .expect("Unable to create Content-Type header for LIST_TABLE_COMMITS_A_SERVER"));
                                 let body = serde_json::to_string(&body)
                                     .expect("impossible to fail to serialize");
                                 *response.body_mut() = Body::from(body);
                             }
-                            DropTableResponse::AServer(body) => {
-                                *response.status_mut() = StatusCode::from_u16(500)
-                                    .expect("Unable to turn 5XX into a StatusCode");
+                            ListTableCommitsResponse::TheServiceIsNotReadyToHandleTheRequest(
+                                body,
+                            ) => {
+                                *response.status_mut() = StatusCode::from_u16(503)
+                                    .expect("Unable to turn 503 into a StatusCode");
                                 response.headers_mut().insert(
                                                         CONTENT_TYPE,
                                                         HeaderValue::from_str("application/json")
-                                                            .expect("Unable to create Content-Type header for DROP_TABLE_A_SERVER"));
+                                                            .expect("Unable to create Content-Type header for LIST_TABLE_COMMITS_THE_SERVICE_IS_NOT_READY_TO_HANDLE_THE_REQUEST"));
                                 let body = serde_json::to_string(&body)
                                     .expect("impossible to fail to serialize");
                                 *response.body_mut() = Body::from(body);
@@ -1083,8 +3856,10 @@ where
                     Ok(response)
                 }
 
-                // ListNamespaces - GET /v1/{prefix}/namespaces
-                hyper::Method::GET if path.matched(paths::ID_V1_PREFIX_NAMESPACES) => {
+                // ListTableMetrics - GET /v1/{prefix}/namespaces/{namespace}/metrics
+                hyper::Method::GET
+                    if path.matched(paths::ID_V1_PREFIX_NAMESPACES_NAMESPACE_METRICS) =>
+                {
                     {
                         let authorization = match *(&context as &dyn Has<Option<Authorization>>)
                             .get()
@@ -1124,10 +3899,10 @@ where
                     // Path parameters
                     let path: &str = uri.path();
                     let path_params =
-                    paths::REGEX_V1_PREFIX_NAMESPACES
+                    paths::REGEX_V1_PREFIX_NAMESPACES_NAMESPACE_METRICS
                     .captures(path)
                     .unwrap_or_else(||
-                        panic!("Path {} matched RE V1_PREFIX_NAMESPACES in set but failed match against \"{}\"", path, paths::REGEX_V1_PREFIX_NAMESPACES.as_str())
+                        panic!("Path {} matched RE V1_PREFIX_NAMESPACES_NAMESPACE_METRICS in set but failed match against \"{}\"", path, paths::REGEX_V1_PREFIX_NAMESPACES_NAMESPACE_METRICS.as_str())
                     );
 
                     let param_prefix = match percent_encoding::percent_decode(path_params["prefix"].as_bytes()).decode_utf8() {
@@ -1144,32 +3919,88 @@ where
                                         .expect("Unable to create Bad Request response for invalid percent decode"))
                 };
 
+                    let param_namespace = match percent_encoding::percent_decode(path_params["namespace"].as_bytes()).decode_utf8() {
+                    Ok(param_namespace) => match param_namespace.parse::<String>() {
+                        Ok(param_namespace) => param_namespace,
+                        Err(e) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't parse path parameter namespace: {}", e)))
+                                        .expect("Unable to create Bad Request response for invalid path parameter")),
+                    },
+                    Err(_) => return Ok(Response::builder()
+                                        .status(StatusCode::BAD_REQUEST)
+                                        .body(Body::from(format!("Couldn't percent-decode path parameter as UTF-8: {}", &path_params["namespace"])))
+                                        .expect("Unable to create Bad Request response for invalid percent decode"))
+                };
+
                     // Query parameters (note that non-required or collection query parameters will ignore garbage values, rather than causing a 400 response)
                     let query_params =
                         form_urlencoded::parse(uri.query().unwrap_or_default().as_bytes())
                             .collect::<Vec<_>>();
-                    let param_parent = query_params
+                    let param_table = query_params
                         .iter()
-                        .filter(|e| e.0 == "parent")
+                        .filter(|e| e.0 == "table")
                         .map(|e| e.1.to_owned())
                         .next();
-                    let param_parent = match param_parent {
-                        Some(param_parent) => {
-                            let param_parent =
-                                <String as std::str::FromStr>::from_str(&param_parent);
-                            match param_parent {
-                            Ok(param_parent) => Some(param_parent),
+                    let param_table = match param_table {
+                        Some(param_table) => {
+                            let param_table = <String as std::str::FromStr>::from_str(&param_table);
+                            match param_table {
+                            Ok(param_table) => Some(param_table),
                             Err(e) => return Ok(Response::builder()
                                 .status(StatusCode::BAD_REQUEST)
-                                .body(Body::from(format!("Couldn't parse query parameter parent - doesn't match schema: {}", e)))
-                                .expect("Unable to create Bad Request response for invalid query parameter parent")),
+                                .body(Body::from(format!("Couldn't parse query parameter table - doesn't match schema: {}", e)))
+                                .expect("Unable to create Bad Request response for invalid query parameter table")),
+                        }
+                        }
+                        None => None,
+                    };
+                    let param_from = query_params
+                        .iter()
+                        .filter(|e| e.0 == "from")
+                        .map(|e| e.1.to_owned())
+                        .next();
+                    let param_from = match param_from {
+                        Some(param_from) => {
+                            let param_from = <i64 as std::str::FromStr>::from_str(&param_from);
+                            match param_from {
+                            Ok(param_from) => Some(param_from),
+                            Err(e) => return Ok(Response::builder()
+                                .status(StatusCode::BAD_REQUEST)
+                                .body(Body::from(format!("Couldn't parse query parameter from - doesn't match schema: {}", e)))
+                                .expect("Unable to create Bad Request response for invalid query parameter from")),
+                        }
+                        }
+                        None => None,
+                    };
+                    let param_to = query_params
+                        .iter()
+                        .filter(|e| e.0 == "to")
+                        .map(|e| e.1.to_owned())
+                        .next();
+                    let param_to = match param_to {
+                        Some(param_to) => {
+                            let param_to = <i64 as std::str::FromStr>::from_str(&param_to);
+                            match param_to {
+                            Ok(param_to) => Some(param_to),
+                            Err(e) => return Ok(Response::builder()
+                                .status(StatusCode::BAD_REQUEST)
+                                .body(Body::from(format!("Couldn't parse query parameter to - doesn't match schema: {}", e)))
+                                .expect("Unable to create Bad Request response for invalid query parameter to")),
                         }
                         }
                         None => None,
                     };
 
                     let result = api_impl
-                        .list_namespaces(param_prefix, param_parent, &context)
+                        .list_table_metrics(
+                            param_prefix,
+                            param_namespace,
+                            param_table,
+                            param_from,
+                            param_to,
+                            &context,
+                        )
                         .await;
                     let mut response = Response::new(Body::empty());
                     response.headers_mut().insert(
@@ -1186,92 +4017,92 @@ where
 
                     match result {
                         Ok(rsp) => match rsp {
-                            ListNamespacesResponse::AListOfNamespaces(body) => {
+                            ListTableMetricsResponse::TheAggregatedMetricsOfTheTables(body) => {
                                 *response.status_mut() = StatusCode::from_u16(200)
                                     .expect("Unable to turn 200 into a StatusCode");
                                 response.headers_mut().insert(
                                                         CONTENT_TYPE,
                                                         HeaderValue::from_str("application/json")
-                                                            .expect("Unable to create Content-Type header for LIST_NAMESPACES_A_LIST_OF_NAMESPACES"));
+                                                            .expect("Unable to create Content-Type header for LIST_TABLE_METRICS_THE_AGGREGATED_METRICS_OF_THE_TABLES"));
                                 let body = serde_json::to_string(&body)
                                     .expect("impossible to fail to serialize");
                                 *response.body_mut() = Body::from(body);
                             }
-                            ListNamespacesResponse::IndicatesABadRequestError(body) => {
+                            ListTableMetricsResponse::IndicatesABadRequestError(body) => {
                                 *response.status_mut() = StatusCode::from_u16(400)
                                     .expect("Unable to turn 400 into a StatusCode");
                                 response.headers_mut().insert(
                                                         CONTENT_TYPE,
                                                         HeaderValue::from_str("application/json")
-                                                            .expect("Unable to create Content-Type header for LIST_NAMESPACES_INDICATES_A_BAD_REQUEST_ERROR"));
+                                                            .expect("Unable to create Content-Type header for LIST_TABLE_METRICS_INDICATES_A_BAD_REQUEST_ERROR"));
                                 let body = serde_json::to_string(&body)
                                     .expect("impossible to fail to serialize");
                                 *response.body_mut() = Body::from(body);
                             }
-                            ListNamespacesResponse::Unauthorized(body) => {
+                            ListTableMetricsResponse::Unauthorized(body) => {
                                 *response.status_mut() = StatusCode::from_u16(401)
                                     .expect("Unable to turn 401 into a StatusCode");
                                 response.headers_mut().insert(
                                                         CONTENT_TYPE,
                                                         HeaderValue::from_str("application/json")
-                                                            .expect("Unable to create Content-Type header for LIST_NAMESPACES_UNAUTHORIZED"));
+                                                            .expect("Unable to create Content-Type header for LIST_TABLE_METRICS_UNAUTHORIZED"));
                                 let body = serde_json::to_string(&body)
                                     .expect("impossible to fail to serialize");
                                 *response.body_mut() = Body::from(body);
                             }
-                            ListNamespacesResponse::Forbidden(body) => {
+                            ListTableMetricsResponse::Forbidden(body) => {
                                 *response.status_mut() = StatusCode::from_u16(403)
                                     .expect("Unable to turn 403 into a StatusCode");
                                 response.headers_mut().insert(
                                                         CONTENT_TYPE,
                                                         HeaderValue::from_str("application/json")
-                                                            .expect("Unable to create Content-Type header for LIST_NAMESPACES_FORBIDDEN"));
+                                                            .expect("Unable to create Content-Type header for LIST_TABLE_METRICS_FORBIDDEN"));
                                 let body = serde_json::to_string(&body)
                                     .expect("impossible to fail to serialize");
                                 *response.body_mut() = Body::from(body);
                             }
-                            ListNamespacesResponse::NotFound(body) => {
+                            ListTableMetricsResponse::NotFound(body) => {
                                 *response.status_mut() = StatusCode::from_u16(404)
                                     .expect("Unable to turn 404 into a StatusCode");
                                 response.headers_mut().insert(
                                                         CONTENT_TYPE,
                                                         HeaderValue::from_str("application/json")
-                                                            .expect("Unable to create Content-Type header for LIST_NAMESPACES_NOT_FOUND"));
+                                                            .expect("Unable to create Content-Type header for LIST_TABLE_METRICS_NOT_FOUND"));
                                 let body = serde_json::to_string(&body)
                                     .expect("impossible to fail to serialize");
                                 *response.body_mut() = Body::from(body);
                             }
-                            ListNamespacesResponse::CredentialsHaveTimedOut(body) => {
+                            ListTableMetricsResponse::CredentialsHaveTimedOut(body) => {
                                 *response.status_mut() = StatusCode::from_u16(419)
                                     .expect("Unable to turn 419 into a StatusCode");
                                 response.headers_mut().insert(
                                                         CONTENT_TYPE,
                                                         HeaderValue::from_str("application/json")
-                                                            .expect("Unable to create Content-Type header for LIST_NAMESPACES_CREDENTIALS_HAVE_TIMED_OUT"));
+                                                            .expect("Unable to create Content-Type header for LIST_TABLE_METRICS_CREDENTIALS_HAVE_TIMED_OUT"));
                                 let body = serde_json::to_string(&body)
                                     .expect("impossible to fail to serialize");
                                 *response.body_mut() = Body::from(body);
                             }
-                            ListNamespacesResponse::TheServiceIsNotReadyToHandleTheRequest(
-                                body,
-                            ) => {
-                                *response.status_mut() = StatusCode::from_u16(503)
-                                    .expect("Unable to turn 503 into a StatusCode");
+                            ListTableMetricsResponse::AServer(body) => {
+                                *response.status_mut() = StatusCode::from_u16(500)
+                                    .expect("Unable to turn 5XX into a StatusCode");
                                 response.headers_mut().insert(
                                                         CONTENT_TYPE,
                                                         HeaderValue::from_str("application/json")
-                                                            .expect("Unable to create Content-Type header for LIST_NAMESPACES_THE_SERVICE_IS_NOT_READY_TO_HANDLE_THE_REQUEST"));
+                                                            .expect("Unable to create Content-Type header for LIST_TABLE_METRICS_A_SERVER"));
                                 let body = serde_json::to_string(&body)
                                     .expect("impossible to fail to serialize");
                                 *response.body_mut() = Body::from(body);
                             }
-                            ListNamespacesResponse::AServer(body) => {
-                                *response.status_mut() = StatusCode::from_u16(500)
-                                    .expect("Unable to turn 5XX into a StatusCode");
+                            ListTableMetricsResponse::TheServiceIsNotReadyToHandleTheRequest(
+                                body,
+                            ) => {
+                                *response.status_mut() = StatusCode::from_u16(503)
+                                    .expect("Unable to turn 503 into a StatusCode");
                                 response.headers_mut().insert(
                                                         CONTENT_TYPE,
                                                         HeaderValue::from_str("application/json")
-                                                            .expect("Unable to create Content-Type header for LIST_NAMESPACES_A_SERVER"));
+                                                            .expect("Unable to create Content-Type header for LIST_TABLE_METRICS_THE_SERVICE_IS_NOT_READY_TO_HANDLE_THE_REQUEST"));
                                 let body = serde_json::to_string(&body)
                                     .expect("impossible to fail to serialize");
                                 *response.body_mut() = Body::from(body);
@@ -2481,6 +5312,10 @@ where
                                 *response.status_mut() = StatusCode::from_u16(401)
                                     .expect("Unable to turn 401 into a StatusCode");
                             }
+                            TableExistsResponse::Forbidden => {
+                                *response.status_mut() = StatusCode::from_u16(403)
+                                    .expect("Unable to turn 403 into a StatusCode");
+                            }
                             TableExistsResponse::NotFound => {
                                 *response.status_mut() = StatusCode::from_u16(404)
                                     .expect("Unable to turn 404 into a StatusCode");
@@ -3086,7 +5921,31 @@ where
                         }
                     }
 
-                    let result = api_impl.get_config(&context).await;
+                    // Query parameters (note that non-required or collection query parameters will ignore garbage values, rather than causing a 400 response)
+                    let query_params =
+                        form_urlencoded::parse(uri.query().unwrap_or_default().as_bytes())
+                            .collect::<Vec<_>>();
+                    let param_warehouse = query_params
+                        .iter()
+                        .filter(|e| e.0 == "warehouse")
+                        .map(|e| e.1.to_owned())
+                        .next();
+                    let param_warehouse = match param_warehouse {
+                        Some(param_warehouse) => {
+                            let param_warehouse =
+                                <String as std::str::FromStr>::from_str(&param_warehouse);
+                            match param_warehouse {
+                            Ok(param_warehouse) => Some(param_warehouse),
+                            Err(e) => return Ok(Response::builder()
+                                .status(StatusCode::BAD_REQUEST)
+                                .body(Body::from(format!("Couldn't parse query parameter warehouse - doesn't match schema: {}", e)))
+                                .expect("Unable to create Bad Request response for invalid query parameter warehouse")),
+                        }
+                        }
+                        None => None,
+                    };
+
+                    let result = api_impl.get_config(param_warehouse, &context).await;
                     let mut response = Response::new(Body::empty());
                     response.headers_mut().insert(
                         HeaderName::from_static("x-span-id"),
@@ -3146,6 +6005,17 @@ where
                                     .expect("impossible to fail to serialize");
                                 *response.body_mut() = Body::from(body);
                             }
+                            GetConfigResponse::NotFound(body) => {
+                                *response.status_mut() = StatusCode::from_u16(404)
+                                    .expect("Unable to turn 404 into a StatusCode");
+                                response.headers_mut().insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json")
+                                                            .expect("Unable to create Content-Type header for GET_CONFIG_NOT_FOUND"));
+                                let body = serde_json::to_string(&body)
+                                    .expect("impossible to fail to serialize");
+                                *response.body_mut() = Body::from(body);
+                            }
                             GetConfigResponse::CredentialsHaveTimedOut(body) => {
                                 *response.status_mut() = StatusCode::from_u16(419)
                                     .expect("Unable to turn 419 into a StatusCode");
@@ -3230,15 +6100,53 @@ where
                     }
 
                     // Form parameters
-                    let param_grant_type = Some("grant_type_example".to_string());
-                    let param_scope = Some("scope_example".to_string());
-                    let param_client_id = Some("client_id_example".to_string());
-                    let param_client_secret = Some("client_secret_example".to_string());
-                    let param_requested_token_type = None;
-                    let param_subject_token = Some("subject_token_example".to_string());
-                    let param_subject_token_type = None;
-                    let param_actor_token = Some("actor_token_example".to_string());
-                    let param_actor_token_type = None;
+                    let body = match body.into_raw().await {
+                        Ok(body) => body,
+                        Err(e) => {
+                            return Ok(Response::builder()
+                                .status(StatusCode::BAD_REQUEST)
+                                .body(Body::from(format!(
+                                    "Couldn't read body parameter OAuthTokenRequest: {}",
+                                    e
+                                )))
+                                .expect("Unable to create Bad Request response due to unable to read body parameter OAuthTokenRequest"))
+                        }
+                    };
+                    let form = form_urlencoded::parse(&body)
+                        .into_owned()
+                        .collect::<std::collections::HashMap<String, String>>();
+                    let token_type = |name: &str| {
+                        form.get(name)
+                            .map(|value| {
+                                value.parse::<models::TokenType>().map_err(|e| {
+                                    format!(
+                                        "Couldn't parse form parameter {} - doesn't match schema: {}",
+                                        name, e
+                                    )
+                                })
+                            })
+                            .transpose()
+                    };
+                    let (param_requested_token_type, param_subject_token_type, param_actor_token_type) =
+                        match (
+                            token_type("requested_token_type"),
+                            token_type("subject_token_type"),
+                            token_type("actor_token_type"),
+                        ) {
+                            (Ok(requested), Ok(subject), Ok(actor)) => (requested, subject, actor),
+                            (Err(message), _, _) | (_, Err(message), _) | (_, _, Err(message)) => {
+                                return Ok(Response::builder()
+                                    .status(StatusCode::BAD_REQUEST)
+                                    .body(Body::from(message))
+                                    .expect("Unable to create Bad Request response for invalid form parameter"))
+                            }
+                        };
+                    let param_grant_type = form.get("grant_type").cloned();
+                    let param_scope = form.get("scope").cloned();
+                    let param_client_id = form.get("client_id").cloned();
+                    let param_client_secret = form.get("client_secret").cloned();
+                    let param_subject_token = form.get("subject_token").cloned();
+                    let param_actor_token = form.get("actor_token").cloned();
 
                     let result = api_impl
                         .get_token(
@@ -3327,10 +6235,19 @@ where
                     Ok(response)
                 }
 
+                _ if path.matched(paths::ID_V1_ADMIN_CATALOGS) => method_not_allowed(),
+                _ if path.matched(paths::ID_V1_ADMIN_CATALOGS_CATALOG) => method_not_allowed(),
+                _ if path.matched(paths::ID_V1_ADMIN_CONFIG) => method_not_allowed(),
+                _ if path.matched(paths::ID_V1_ADMIN_ROLES) => method_not_allowed(),
+                _ if path.matched(paths::ID_V1_ADMIN_ROLES_ROLE) => method_not_allowed(),
+                _ if path.matched(paths::ID_V1_ADMIN_ROLES_ROLE_GRANTS) => method_not_allowed(),
                 _ if path.matched(paths::ID_V1_CONFIG) => method_not_allowed(),
                 _ if path.matched(paths::ID_V1_OAUTH_TOKENS) => method_not_allowed(),
                 _ if path.matched(paths::ID_V1_PREFIX_NAMESPACES) => method_not_allowed(),
                 _ if path.matched(paths::ID_V1_PREFIX_NAMESPACES_NAMESPACE) => method_not_allowed(),
+                _ if path.matched(paths::ID_V1_PREFIX_NAMESPACES_NAMESPACE_METRICS) => {
+                    method_not_allowed()
+                }
                 _ if path.matched(paths::ID_V1_PREFIX_NAMESPACES_NAMESPACE_PROPERTIES) => {
                     method_not_allowed()
                 }
@@ -3340,6 +6257,11 @@ where
                 _ if path.matched(paths::ID_V1_PREFIX_NAMESPACES_NAMESPACE_TABLES_TABLE) => {
                     method_not_allowed()
                 }
+                _ if path
+                    .matched(paths::ID_V1_PREFIX_NAMESPACES_NAMESPACE_TABLES_TABLE_COMMITS) =>
+                {
+                    method_not_allowed()
+                }
                 _ if path
                     .matched(paths::ID_V1_PREFIX_NAMESPACES_NAMESPACE_TABLES_TABLE_METRICS) =>
                 {
@@ -3362,6 +6284,48 @@ impl<T> RequestParser<T> for ApiRequestParser {
     fn parse_operation_id(request: &Request<T>) -> Option<&'static str> {
         let path = paths::GLOBAL_REGEX_SET.matches(request.uri().path());
         match *request.method() {
+            // CreateCatalog - POST /v1/admin/catalogs
+            hyper::Method::POST if path.matched(paths::ID_V1_ADMIN_CATALOGS) => {
+                Some("CreateCatalog")
+            }
+            // DropCatalog - DELETE /v1/admin/catalogs/{catalog}
+            hyper::Method::DELETE if path.matched(paths::ID_V1_ADMIN_CATALOGS_CATALOG) => {
+                Some("DropCatalog")
+            }
+            // DropRole - DELETE /v1/admin/roles/{role}
+            hyper::Method::DELETE if path.matched(paths::ID_V1_ADMIN_ROLES_ROLE) => {
+                Some("DropRole")
+            }
+            // GetCatalogConfig - GET /v1/admin/config
+            hyper::Method::GET if path.matched(paths::ID_V1_ADMIN_CONFIG) => {
+                Some("GetCatalogConfig")
+            }
+            // ListCatalogs - GET /v1/admin/catalogs
+            hyper::Method::GET if path.matched(paths::ID_V1_ADMIN_CATALOGS) => Some("ListCatalogs"),
+            // ListGrants - GET /v1/admin/roles/{role}/grants
+            hyper::Method::GET if path.matched(paths::ID_V1_ADMIN_ROLES_ROLE_GRANTS) => {
+                Some("ListGrants")
+            }
+            // ListRoles - GET /v1/admin/roles
+            hyper::Method::GET if path.matched(paths::ID_V1_ADMIN_ROLES) => Some("ListRoles"),
+            // LoadCatalog - GET /v1/admin/catalogs/{catalog}
+            hyper::Method::GET if path.matched(paths::ID_V1_ADMIN_CATALOGS_CATALOG) => {
+                Some("LoadCatalog")
+            }
+            // UpdateCatalog - POST /v1/admin/catalogs/{catalog}
+            hyper::Method::POST if path.matched(paths::ID_V1_ADMIN_CATALOGS_CATALOG) => {
+                Some("UpdateCatalog")
+            }
+            // UpdateCatalogConfig - POST /v1/admin/config
+            hyper::Method::POST if path.matched(paths::ID_V1_ADMIN_CONFIG) => {
+                Some("UpdateCatalogConfig")
+            }
+            // UpdateGrants - POST /v1/admin/roles/{role}/grants
+            hyper::Method::POST if path.matched(paths::ID_V1_ADMIN_ROLES_ROLE_GRANTS) => {
+                Some("UpdateGrants")
+            }
+            // UpdateRole - PUT /v1/admin/roles/{role}
+            hyper::Method::PUT if path.matched(paths::ID_V1_ADMIN_ROLES_ROLE) => Some("UpdateRole"),
             // CreateNamespace - POST /v1/{prefix}/namespaces
             hyper::Method::POST if path.matched(paths::ID_V1_PREFIX_NAMESPACES) => {
                 Some("CreateNamespace")
@@ -3386,6 +6350,18 @@ impl<T> RequestParser<T> for ApiRequestParser {
             hyper::Method::GET if path.matched(paths::ID_V1_PREFIX_NAMESPACES) => {
                 Some("ListNamespaces")
             }
+            // ListTableCommits - GET /v1/{prefix}/namespaces/{namespace}/tables/{table}/commits
+            hyper::Method::GET
+                if path.matched(paths::ID_V1_PREFIX_NAMESPACES_NAMESPACE_TABLES_TABLE_COMMITS) =>
+            {
+                Some("ListTableCommits")
+            }
+            // ListTableMetrics - GET /v1/{prefix}/namespaces/{namespace}/metrics
+            hyper::Method::GET
+                if path.matched(paths::ID_V1_PREFIX_NAMESPACES_NAMESPACE_METRICS) =>
+            {
+                Some("ListTableMetrics")
+            }
             // ListTables - GET /v1/{prefix}/namespaces/{namespace}/tables
             hyper::Method::GET if path.matched(paths::ID_V1_PREFIX_NAMESPACES_NAMESPACE_TABLES) => {
                 Some("ListTables")